@@ -1,5 +1,6 @@
 use chic::runtime::wasm_executor::instructions::Instruction;
 use chic::runtime::wasm_executor::parser::parse_module;
+use chic::runtime::wasm_executor::source_map::load_source_map;
 use std::env;
 use std::fs;
 
@@ -86,6 +87,9 @@ fn main() {
         module.functions.len()
     );
     if mode_name {
+        if let Some(name) = module.function_name(func_index) {
+            println!("name: {name}");
+        }
         if let Some((export, _)) = module
             .exports
             .iter()
@@ -124,7 +128,10 @@ fn main() {
                 .iter()
                 .any(|instr| matches!(instr, Instruction::Call { func } if *func == func_index))
             {
-                println!("call from func {}", func_idx);
+                match module.function_name(func_idx) {
+                    Some(name) => println!("call from func {} ({name})", func_idx),
+                    None => println!("call from func {}", func_idx),
+                }
             }
         }
         return;
@@ -138,8 +145,31 @@ fn main() {
         .functions
         .get(local_index as usize)
         .expect("function");
+    if let Some(name) = module.function_name(func_index) {
+        println!("func {}: {}", func_index, name);
+    }
     println!("type index: {} locals: {:?}", func.type_index, func.locals);
+    let param_count = module
+        .types
+        .get(func.type_index as usize)
+        .map_or(0, |ty| ty.params.len());
+    for local in 0..(param_count + func.locals.len()) as u32 {
+        if let Some(name) = module.local_name(func_index, local) {
+            println!("  local {}: {}", local, name);
+        }
+    }
+    let source_map = load_source_map(std::path::Path::new(&path));
+    let mut last_location = None;
     for (pc, instr) in func.code.iter().enumerate() {
+        let location = source_map
+            .as_ref()
+            .zip(module.instruction_offset(func_index, pc))
+            .and_then(|(map, offset)| map.lookup(offset))
+            .map(|location| location.to_string());
+        if location.is_some() && location != last_location {
+            println!("  ; {}", location.as_deref().unwrap_or_default());
+            last_location = location;
+        }
         println!("{}: {:?}", pc, instr);
     }
 }
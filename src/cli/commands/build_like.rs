@@ -6,7 +6,8 @@ use crate::codegen::{Backend, CpuIsaConfig};
 use crate::defines::DefineFlag;
 use crate::diagnostics::ErrorFormat;
 use crate::driver::types::{
-    BuildPropertyOverride, TelemetrySetting, TestSelection, Verbosity, WatchdogConfig,
+    BenchOptions, BuildPropertyOverride, GenerativeTestOptions, TelemetrySetting, TestReportFormat,
+    TestReportRequest, TestSelection, Verbosity, WatchdogConfig,
 };
 use crate::logging::{LogFormat, LogLevel, LogOptions, LogSettings};
 use crate::manifest::{Manifest, MissingDocsRule, WorkspaceConfig};
@@ -16,7 +17,7 @@ use crate::target::{Target, TargetArch, TargetOs, TargetRuntime};
 use super::super::{Cli, CliError, CliFfiOptions, Command, FfiDefaultPattern};
 use super::common::{
     is_help_flag, parse_chic_kind, parse_const_eval_fuel, parse_define_flag, parse_error_format,
-    parse_target, partition_inputs_and_flags,
+    parse_jobs, parse_target, partition_inputs_and_flags,
 };
 
 #[derive(Debug, Clone, Copy)]
@@ -25,6 +26,7 @@ pub(super) enum CommandKind {
     Run,
     Test,
     Coverage,
+    Bench,
     Profile,
 }
 
@@ -35,6 +37,7 @@ impl CommandKind {
             CommandKind::Run => "run",
             CommandKind::Test => "test",
             CommandKind::Coverage => "coverage",
+            CommandKind::Bench => "bench",
             CommandKind::Profile => "profile",
         }
     }
@@ -57,7 +60,7 @@ pub(super) fn parse_build_like(args: Vec<String>, kind: CommandKind) -> Result<C
     }
     let (inputs, rest) = if matches!(
         kind,
-        CommandKind::Build | CommandKind::Test | CommandKind::Coverage
+        CommandKind::Build | CommandKind::Test | CommandKind::Coverage | CommandKind::Bench
     ) {
         partition_inputs_and_flags_allowing_empty(args)?
     } else {
@@ -90,6 +93,7 @@ pub(super) fn parse_build_like(args: Vec<String>, kind: CommandKind) -> Result<C
         load_stdlib,
         run_timeout,
         const_eval_fuel,
+        jobs,
         trace_pipeline,
         trait_solver_metrics,
         defines,
@@ -100,6 +104,7 @@ pub(super) fn parse_build_like(args: Vec<String>, kind: CommandKind) -> Result<C
         configuration,
         no_dependencies,
         no_restore,
+        locked,
         no_incremental,
         disable_build_servers,
         source_root,
@@ -115,7 +120,11 @@ pub(super) fn parse_build_like(args: Vec<String>, kind: CommandKind) -> Result<C
         test_selection,
         test_parallelism,
         test_fail_fast,
+        test_generation,
+        update_snapshots,
         watchdog,
+        test_reports,
+        bench,
         coverage,
         coverage_min,
         workspace_mode,
@@ -201,6 +210,7 @@ pub(super) fn parse_build_like(args: Vec<String>, kind: CommandKind) -> Result<C
                 cc1_keep_temps,
                 load_stdlib,
                 const_eval_fuel,
+                jobs,
                 trace_pipeline,
                 trait_solver_metrics,
                 defines,
@@ -209,6 +219,7 @@ pub(super) fn parse_build_like(args: Vec<String>, kind: CommandKind) -> Result<C
                 framework,
                 no_dependencies,
                 no_restore,
+                locked,
                 no_incremental,
                 disable_build_servers,
                 source_root,
@@ -235,6 +246,7 @@ pub(super) fn parse_build_like(args: Vec<String>, kind: CommandKind) -> Result<C
                 cpu_isa,
                 run_timeout,
                 const_eval_fuel,
+                jobs,
                 trace_pipeline,
                 trait_solver_metrics,
                 load_stdlib,
@@ -245,6 +257,7 @@ pub(super) fn parse_build_like(args: Vec<String>, kind: CommandKind) -> Result<C
                 artifacts_path,
                 no_dependencies,
                 no_restore,
+                locked,
                 no_incremental,
                 disable_build_servers,
                 source_root,
@@ -269,6 +282,7 @@ pub(super) fn parse_build_like(args: Vec<String>, kind: CommandKind) -> Result<C
                 runtime_backend,
                 cpu_isa,
                 const_eval_fuel,
+                jobs,
                 trace_pipeline,
                 trait_solver_metrics,
                 load_stdlib,
@@ -280,6 +294,11 @@ pub(super) fn parse_build_like(args: Vec<String>, kind: CommandKind) -> Result<C
                     parallelism: test_parallelism,
                     watchdog,
                     fail_fast: test_fail_fast,
+                    generation: test_generation,
+                    update_snapshots,
+                    reports: test_reports,
+                    events: None,
+                    bench,
                 },
                 coverage,
                 coverage_min,
@@ -289,6 +308,7 @@ pub(super) fn parse_build_like(args: Vec<String>, kind: CommandKind) -> Result<C
                 artifacts_path,
                 no_dependencies,
                 no_restore,
+                locked,
                 no_incremental,
                 disable_build_servers,
                 source_root,
@@ -303,7 +323,7 @@ pub(super) fn parse_build_like(args: Vec<String>, kind: CommandKind) -> Result<C
                 framework,
                 doc_enforcement,
             },
-            CommandKind::Coverage => Command::Test {
+            CommandKind::Coverage | CommandKind::Bench => Command::Test {
                 inputs: project.inputs.clone(),
                 manifest: project.manifest.clone(),
                 workspace: project.workspace.clone(),
@@ -313,6 +333,7 @@ pub(super) fn parse_build_like(args: Vec<String>, kind: CommandKind) -> Result<C
                 runtime_backend,
                 cpu_isa,
                 const_eval_fuel,
+                jobs,
                 trace_pipeline,
                 trait_solver_metrics,
                 load_stdlib,
@@ -324,6 +345,11 @@ pub(super) fn parse_build_like(args: Vec<String>, kind: CommandKind) -> Result<C
                     parallelism: test_parallelism,
                     watchdog,
                     fail_fast: test_fail_fast,
+                    generation: test_generation,
+                    update_snapshots,
+                    reports: test_reports,
+                    events: None,
+                    bench,
                 },
                 coverage,
                 coverage_min,
@@ -333,6 +359,7 @@ pub(super) fn parse_build_like(args: Vec<String>, kind: CommandKind) -> Result<C
                 artifacts_path,
                 no_dependencies,
                 no_restore,
+                locked,
                 no_incremental,
                 disable_build_servers,
                 source_root,
@@ -358,6 +385,7 @@ pub(super) fn parse_build_like(args: Vec<String>, kind: CommandKind) -> Result<C
                 cpu_isa,
                 run_timeout,
                 const_eval_fuel,
+                jobs,
                 trace_pipeline,
                 trait_solver_metrics,
                 load_stdlib,
@@ -368,6 +396,7 @@ pub(super) fn parse_build_like(args: Vec<String>, kind: CommandKind) -> Result<C
                 artifacts_path,
                 no_dependencies,
                 no_restore,
+                locked,
                 no_incremental,
                 disable_build_servers,
                 source_root,
@@ -405,6 +434,7 @@ struct BuildOptions {
     load_stdlib: Option<bool>,
     run_timeout: Option<Duration>,
     const_eval_fuel: Option<usize>,
+    jobs: Option<usize>,
     trace_pipeline: bool,
     trait_solver_metrics: bool,
     defines: Vec<DefineFlag>,
@@ -416,6 +446,7 @@ struct BuildOptions {
     configuration_from_cli: bool,
     no_dependencies: bool,
     no_restore: bool,
+    locked: bool,
     no_incremental: bool,
     disable_build_servers: bool,
     source_root: Option<PathBuf>,
@@ -430,7 +461,11 @@ struct BuildOptions {
     test_selection: TestSelection,
     test_parallelism: Option<usize>,
     test_fail_fast: bool,
+    test_generation: GenerativeTestOptions,
+    update_snapshots: bool,
     watchdog: WatchdogConfig,
+    test_reports: Vec<TestReportRequest>,
+    bench: Option<BenchOptions>,
     coverage: bool,
     coverage_min: Option<u8>,
     workspace_mode: bool,
@@ -465,6 +500,7 @@ struct BuildOptionState {
     cc1_keep_temps: bool,
     run_timeout: Option<Duration>,
     const_eval_fuel: Option<usize>,
+    jobs: Option<usize>,
     trace_pipeline: bool,
     trait_solver_metrics: bool,
     defines: Vec<DefineFlag>,
@@ -480,6 +516,7 @@ struct BuildOptionState {
     configuration: Option<String>,
     no_dependencies: bool,
     no_restore: bool,
+    locked: bool,
     no_incremental: bool,
     disable_build_servers: bool,
     source_root: Option<PathBuf>,
@@ -495,7 +532,16 @@ struct BuildOptionState {
     test_selection: TestSelection,
     test_parallelism: Option<usize>,
     test_fail_fast: bool,
+    test_generation: GenerativeTestOptions,
+    update_snapshots: bool,
     watchdog: WatchdogConfig,
+    test_reports: Vec<TestReportRequest>,
+    bench_warmup: Option<Duration>,
+    bench_measurement: Option<Duration>,
+    bench_samples: Option<usize>,
+    bench_output: Option<PathBuf>,
+    bench_baseline: Option<PathBuf>,
+    bench_tolerance: Option<f64>,
     coverage: bool,
     coverage_min: Option<u8>,
     workspace_mode: bool,
@@ -536,6 +582,7 @@ impl BuildOptionState {
             "--cc1-arg" => self.consume_cc1_arg(args, command),
             "--cc1-keep-input" => self.consume_cc1_keep(command),
             "--consteval-fuel" => self.consume_const_eval_fuel(args),
+            "-j" | "--jobs" => self.consume_jobs(args),
             "--run-timeout" => self.consume_run_timeout(args, command),
             "-D" | "--define" => self.consume_define(args),
             "--ffi-search" => self.consume_ffi_search(args),
@@ -549,6 +596,10 @@ impl BuildOptionState {
                 self.no_restore = true;
                 Ok(1)
             }
+            "--locked" => {
+                self.locked = true;
+                Ok(1)
+            }
             "--no-incremental" => {
                 self.no_incremental = true;
                 Ok(1)
@@ -594,6 +645,12 @@ impl BuildOptionState {
                 self.trait_solver_metrics = true;
                 Ok(1)
             }
+            "--warmup-ms" => self.consume_bench_duration(args, command, "--warmup-ms"),
+            "--measure-ms" => self.consume_bench_duration(args, command, "--measure-ms"),
+            "--samples" => self.consume_bench_samples(args, command),
+            "--bench-out" => self.consume_bench_path(args, command, "--bench-out"),
+            "--baseline" => self.consume_bench_path(args, command, "--baseline"),
+            "--tolerance" => self.consume_bench_tolerance(args, command),
             "--test" => self.consume_test_filter(args, command),
             "--test-group" => self.consume_test_group(args, command),
             "--all" | "--test-all" => self.consume_test_all(command),
@@ -603,7 +660,15 @@ impl BuildOptionState {
                 self.test_fail_fast = true;
                 Ok(1)
             }
+            "--test-seed" => self.consume_test_seed(args, command),
+            "--test-runs" => self.consume_test_runs(args, command),
+            "--update-snapshots" => {
+                self.require_test_command(command, "--update-snapshots")?;
+                self.update_snapshots = true;
+                Ok(1)
+            }
             "--watchdog" => self.consume_watchdog_limit(args, command),
+            "--report" => self.consume_test_report(args, command),
             "--watchdog-timeout" => self.consume_watchdog_timeout(args, command),
             "--coverage" => {
                 self.require_test_command(command, "--coverage")?;
@@ -863,6 +928,12 @@ impl BuildOptionState {
         Ok(2)
     }
 
+    fn consume_jobs(&mut self, args: &[String]) -> Result<usize, CliError> {
+        let value = Self::next_value(args, "expected value after -j/--jobs")?;
+        self.jobs = Some(parse_jobs(value)?);
+        Ok(2)
+    }
+
     fn consume_run_timeout(
         &mut self,
         args: &[String],
@@ -1018,6 +1089,103 @@ impl BuildOptionState {
         Ok(())
     }
 
+    fn require_selection_command(&self, command: CommandKind, flag: &str) -> Result<(), CliError> {
+        if !matches!(
+            command,
+            CommandKind::Test | CommandKind::Coverage | CommandKind::Bench
+        ) {
+            return Err(CliError::with_usage(format!(
+                "{flag} is only supported for chic test/coverage/bench"
+            )));
+        }
+        Ok(())
+    }
+
+    fn require_bench_command(&self, command: CommandKind, flag: &str) -> Result<(), CliError> {
+        if !matches!(command, CommandKind::Bench) {
+            return Err(CliError::with_usage(format!(
+                "{flag} is only supported for chic bench"
+            )));
+        }
+        Ok(())
+    }
+
+    fn consume_bench_duration(
+        &mut self,
+        args: &[String],
+        command: CommandKind,
+        flag: &str,
+    ) -> Result<usize, CliError> {
+        self.require_bench_command(command, flag)?;
+        let value = Self::next_value(args, &format!("expected milliseconds after {flag}"))?;
+        let millis = value
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .filter(|millis| *millis > 0 || flag == "--warmup-ms")
+            .ok_or_else(|| {
+                CliError::with_usage(format!("{flag} expects a number of milliseconds"))
+            })?;
+        let duration = Some(Duration::from_millis(millis));
+        if flag == "--warmup-ms" {
+            self.bench_warmup = duration;
+        } else {
+            self.bench_measurement = duration;
+        }
+        Ok(2)
+    }
+
+    fn consume_bench_samples(
+        &mut self,
+        args: &[String],
+        command: CommandKind,
+    ) -> Result<usize, CliError> {
+        self.require_bench_command(command, "--samples")?;
+        let value = Self::next_value(args, "expected sample count after --samples")?;
+        let samples = value
+            .trim()
+            .parse::<usize>()
+            .ok()
+            .filter(|samples| *samples > 1)
+            .ok_or_else(|| CliError::with_usage("--samples expects an integer of at least 2"))?;
+        self.bench_samples = Some(samples);
+        Ok(2)
+    }
+
+    fn consume_bench_path(
+        &mut self,
+        args: &[String],
+        command: CommandKind,
+        flag: &str,
+    ) -> Result<usize, CliError> {
+        self.require_bench_command(command, flag)?;
+        let value = Self::next_value(args, &format!("expected path after {flag}"))?;
+        let path = Some(PathBuf::from(value));
+        if flag == "--baseline" {
+            self.bench_baseline = path;
+        } else {
+            self.bench_output = path;
+        }
+        Ok(2)
+    }
+
+    fn consume_bench_tolerance(
+        &mut self,
+        args: &[String],
+        command: CommandKind,
+    ) -> Result<usize, CliError> {
+        self.require_bench_command(command, "--tolerance")?;
+        let value = Self::next_value(args, "expected percent after --tolerance")?;
+        let tolerance = value
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|tolerance| tolerance.is_finite() && *tolerance >= 0.0)
+            .ok_or_else(|| CliError::with_usage("--tolerance expects a non-negative percent"))?;
+        self.bench_tolerance = Some(tolerance);
+        Ok(2)
+    }
+
     fn consume_coverage_min(
         &mut self,
         args: &[String],
@@ -1042,7 +1210,7 @@ impl BuildOptionState {
         args: &[String],
         command: CommandKind,
     ) -> Result<usize, CliError> {
-        self.require_test_command(command, "--test")?;
+        self.require_selection_command(command, "--test")?;
         let value = Self::next_value(args, "expected testcase name or pattern after --test")?;
         let trimmed = value.trim();
         if trimmed.is_empty() {
@@ -1059,7 +1227,7 @@ impl BuildOptionState {
         args: &[String],
         command: CommandKind,
     ) -> Result<usize, CliError> {
-        self.require_test_command(command, "--test-group")?;
+        self.require_selection_command(command, "--test-group")?;
         let value = Self::next_value(
             args,
             "expected namespace/category pattern after --test-group",
@@ -1075,7 +1243,7 @@ impl BuildOptionState {
     }
 
     fn consume_test_all(&mut self, command: CommandKind) -> Result<usize, CliError> {
-        self.require_test_command(command, "--all")?;
+        self.require_selection_command(command, "--all")?;
         self.test_selection.run_all = true;
         self.test_selection.tests.clear();
         self.test_selection.groups.clear();
@@ -1102,6 +1270,69 @@ impl BuildOptionState {
         Ok(2)
     }
 
+    fn consume_test_report(
+        &mut self,
+        args: &[String],
+        command: CommandKind,
+    ) -> Result<usize, CliError> {
+        self.require_test_command(command, "--report")?;
+        let value = Self::next_value(args, "expected report format after --report")?;
+        let (format, path) = match value.split_once('=') {
+            Some((format, path)) if !path.trim().is_empty() => {
+                (format, Some(PathBuf::from(path.trim())))
+            }
+            Some(_) => {
+                return Err(CliError::with_usage(
+                    "--report expects a path after '=' (e.g. junit=reports/junit.xml)",
+                ));
+            }
+            None => (value, None),
+        };
+        let format = TestReportFormat::parse(format.trim()).ok_or_else(|| {
+            CliError::with_usage(format!(
+                "unsupported --report format '{format}' (expected junit, tap or jsonl)"
+            ))
+        })?;
+        if path.is_none() && self.test_reports.iter().any(|report| report.path.is_none()) {
+            return Err(CliError::with_usage(
+                "only one --report can write to stdout; give the others a path (format=<path>)",
+            ));
+        }
+        self.test_reports.push(TestReportRequest { format, path });
+        Ok(2)
+    }
+
+    fn consume_test_seed(
+        &mut self,
+        args: &[String],
+        command: CommandKind,
+    ) -> Result<usize, CliError> {
+        self.require_test_command(command, "--test-seed")?;
+        let value = Self::next_value(args, "expected seed after --test-seed")?;
+        let seed = GenerativeTestOptions::parse_seed(value).ok_or_else(|| {
+            CliError::with_usage("--test-seed expects a 128-bit seed (0x-prefixed hex or decimal)")
+        })?;
+        self.test_generation.seed = Some(seed);
+        Ok(2)
+    }
+
+    fn consume_test_runs(
+        &mut self,
+        args: &[String],
+        command: CommandKind,
+    ) -> Result<usize, CliError> {
+        self.require_test_command(command, "--test-runs")?;
+        let value = Self::next_value(args, "expected case count after --test-runs")?;
+        let runs = value
+            .trim()
+            .parse::<u32>()
+            .ok()
+            .filter(|runs| *runs > 0)
+            .ok_or_else(|| CliError::with_usage("--test-runs expects a positive integer"))?;
+        self.test_generation.runs = Some(runs);
+        Ok(2)
+    }
+
     fn consume_watchdog_limit(
         &mut self,
         args: &[String],
@@ -1193,9 +1424,16 @@ impl BuildOptionState {
             .ok_or_else(|| CliError::with_usage(message))
     }
 
-    fn finish(self, command: CommandKind) -> Result<BuildOptions, CliError> {
+    fn finish(mut self, command: CommandKind) -> Result<BuildOptions, CliError> {
         let host_target = Target::host();
         let had_explicit_target = self.target.is_some();
+        if matches!(command, CommandKind::Bench)
+            && self.backend.is_none()
+            && !had_explicit_target
+            && self.target_runtime.is_none()
+        {
+            self.backend = Some(Backend::Wasm);
+        }
         let base_target = if self.use_current_runtime {
             host_target.clone()
         } else {
@@ -1234,6 +1472,11 @@ impl BuildOptionState {
                 ));
             }
         }
+        if matches!(command, CommandKind::Bench) && backend != Backend::Wasm {
+            return Err(CliError::with_usage(
+                "chic bench currently requires the wasm backend (--backend wasm)",
+            ));
+        }
         let kind = self.kind.unwrap_or_default();
         let kind_from_cli = self.kind_from_cli;
 
@@ -1348,7 +1591,10 @@ impl BuildOptionState {
         if load_stdlib.is_none() && matches!(&runtime_flavor, TargetRuntime::NativeNoStd) {
             load_stdlib = Some(false);
         }
-        let test_selection = if matches!(command, CommandKind::Test | CommandKind::Coverage) {
+        let test_selection = if matches!(
+            command,
+            CommandKind::Test | CommandKind::Coverage | CommandKind::Bench
+        ) {
             resolve_test_selection(self.test_selection.clone())
         } else {
             TestSelection::default()
@@ -1363,6 +1609,13 @@ impl BuildOptionState {
         } else {
             false
         };
+        let test_generation = if matches!(command, CommandKind::Test | CommandKind::Coverage) {
+            resolve_test_generation(self.test_generation)
+        } else {
+            GenerativeTestOptions::default()
+        };
+        let update_snapshots = matches!(command, CommandKind::Test | CommandKind::Coverage)
+            && (self.update_snapshots || env_flag_truthy("CHIC_UPDATE_SNAPSHOTS").unwrap_or(false));
         let mut watchdog = if matches!(command, CommandKind::Test | CommandKind::Coverage) {
             self.watchdog
         } else {
@@ -1371,6 +1624,17 @@ impl BuildOptionState {
         if env_flag_truthy("CHIC_TEST_WATCHDOG_ENABLE_RELEASE").unwrap_or(false) {
             watchdog.enable_in_release = true;
         }
+        let bench = matches!(command, CommandKind::Bench).then(|| {
+            let defaults = BenchOptions::default();
+            BenchOptions {
+                warmup: self.bench_warmup.unwrap_or(defaults.warmup),
+                measurement: self.bench_measurement.unwrap_or(defaults.measurement),
+                max_samples: self.bench_samples.unwrap_or(defaults.max_samples),
+                output: self.bench_output.clone().unwrap_or(defaults.output),
+                baseline: self.bench_baseline.clone(),
+                tolerance: self.bench_tolerance.unwrap_or(defaults.tolerance),
+            }
+        });
 
         Ok(BuildOptions {
             output: self.output,
@@ -1389,6 +1653,7 @@ impl BuildOptionState {
             load_stdlib,
             run_timeout: self.run_timeout,
             const_eval_fuel: self.const_eval_fuel,
+            jobs: self.jobs,
             trace_pipeline: self.trace_pipeline,
             trait_solver_metrics: self.trait_solver_metrics,
             defines,
@@ -1404,6 +1669,7 @@ impl BuildOptionState {
             configuration_from_cli: configuration_set,
             no_dependencies: self.no_dependencies,
             no_restore: self.no_restore,
+            locked: self.locked,
             no_incremental: self.no_incremental,
             disable_build_servers: self.disable_build_servers,
             source_root: self.source_root,
@@ -1418,7 +1684,11 @@ impl BuildOptionState {
             test_selection,
             test_parallelism,
             test_fail_fast,
+            test_generation,
+            update_snapshots,
             watchdog,
+            test_reports: self.test_reports,
+            bench,
             coverage: self.coverage || matches!(command, CommandKind::Coverage),
             coverage_min: self.coverage_min,
             workspace_mode: self.workspace_mode,
@@ -1483,7 +1753,10 @@ fn resolve_inputs(
     command: CommandKind,
     workspace_mode_requested: bool,
 ) -> Result<ProjectResolution, CliError> {
-    let include_tests = matches!(command, CommandKind::Test | CommandKind::Coverage);
+    let include_tests = matches!(
+        command,
+        CommandKind::Test | CommandKind::Coverage | CommandKind::Bench
+    );
     let mut resolved_inputs = Vec::new();
     let mut manifest = None;
     let mut workspace = None;
@@ -1850,6 +2123,22 @@ fn resolve_test_parallelism(cli_value: Option<usize>) -> Option<usize> {
     }
 }
 
+fn resolve_test_generation(cli: GenerativeTestOptions) -> GenerativeTestOptions {
+    GenerativeTestOptions {
+        seed: cli.seed.or_else(|| {
+            env::var("CHIC_TEST_SEED")
+                .ok()
+                .and_then(|value| GenerativeTestOptions::parse_seed(&value))
+        }),
+        runs: cli.runs.or_else(|| {
+            env::var("CHIC_TEST_RUNS")
+                .ok()
+                .and_then(|value| value.trim().parse::<u32>().ok())
+                .filter(|runs| *runs > 0)
+        }),
+    }
+}
+
 fn split_list(value: &str) -> Vec<String> {
     value
         .split(|ch| ch == ',' || ch == ';')
@@ -27,6 +27,7 @@ pub(super) fn parse(args: Vec<String>) -> Result<Cli, CliError> {
         target,
         kind,
         const_eval_fuel,
+        jobs,
         trace_pipeline,
         trait_solver_metrics,
         defines,
@@ -39,6 +40,7 @@ pub(super) fn parse(args: Vec<String>) -> Result<Cli, CliError> {
             target,
             kind,
             const_eval_fuel,
+            jobs,
             trace_pipeline,
             trait_solver_metrics,
             defines,
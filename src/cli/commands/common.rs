@@ -59,6 +59,7 @@ pub(crate) fn parse_target_and_kind<I, T>(
         Target,
         ChicKind,
         Option<usize>,
+        Option<usize>,
         bool,
         bool,
         Vec<DefineFlag>,
@@ -76,6 +77,7 @@ where
     let mut target = None;
     let mut kind = None;
     let mut const_eval_fuel = None;
+    let mut jobs = None;
     let mut trace_pipeline = false;
     let mut trait_solver_metrics = false;
     let mut defines = Vec::new();
@@ -111,6 +113,13 @@ where
                 idx += 1;
                 const_eval_fuel = Some(parse_const_eval_fuel(value)?);
             }
+            "-j" | "--jobs" => {
+                let Some(value) = iter.get(idx) else {
+                    return Err(CliError::with_usage("expected value after -j/--jobs"));
+                };
+                idx += 1;
+                jobs = Some(parse_jobs(value)?);
+            }
             "--trace-pipeline" => {
                 trace_pipeline = true;
             }
@@ -168,6 +177,7 @@ where
         target.unwrap_or_else(Target::host),
         kind.unwrap_or_default(),
         const_eval_fuel,
+        jobs,
         trace_pipeline,
         trait_solver_metrics,
         defines,
@@ -210,6 +220,17 @@ pub(crate) fn parse_const_eval_fuel(spec: &str) -> Result<usize, CliError> {
     Ok(value)
 }
 
+pub(crate) fn parse_jobs(spec: &str) -> Result<usize, CliError> {
+    let trimmed = spec.trim();
+    match trimmed.parse::<usize>() {
+        Ok(0) => Err(CliError::with_usage("-j/--jobs must be greater than zero")),
+        Ok(value) => Ok(value),
+        Err(_) => Err(CliError::with_usage(format!(
+            "invalid -j/--jobs value '{trimmed}'; expected a positive integer"
+        ))),
+    }
+}
+
 pub(crate) fn parse_error_format(spec: &str) -> Result<ErrorFormat, CliError> {
     let value = spec.trim().to_ascii_lowercase();
     let format = match value.as_str() {
@@ -1,5 +1,6 @@
 pub(crate) mod common;
 
+mod bench;
 mod build;
 pub(crate) mod build_like;
 mod cc1;
@@ -14,14 +15,19 @@ mod header;
 mod init;
 mod lint;
 mod mir_dump;
+mod pack;
 mod perf_report;
 mod profile;
+mod publish;
 mod run;
 mod seed;
 mod spec;
 mod test;
+mod update;
+mod watch;
 
 use super::{CommandDescriptor, CommandFeature};
+use bench::parse as parse_bench_command;
 use build::parse as parse_build_command;
 use cc1::parse as parse_cc1_command;
 use check::parse as parse_check_command;
@@ -32,12 +38,16 @@ use header::parse as parse_header_command;
 use init::parse as parse_init_command;
 use lint::parse as parse_lint_command;
 use mir_dump::parse as parse_mir_dump_command;
+use pack::parse as parse_pack_command;
 use perf_report::parse as parse_perf_report_command;
 use profile::parse as parse_profile_command;
+use publish::parse as parse_publish_command;
 use run::parse as parse_run_command;
 use seed::parse as parse_seed_command;
 use spec::parse as parse_spec_command;
 use test::parse as parse_test_command;
+use update::parse as parse_update_command;
+use watch::parse as parse_watch_command;
 
 const COMMANDS: &[CommandDescriptor] = &[
     CommandDescriptor {
@@ -54,7 +64,7 @@ const COMMANDS: &[CommandDescriptor] = &[
     },
     CommandDescriptor {
         name: "build",
-        aliases: &["publish", "pack"],
+        aliases: &[],
         parser: parse_build_command,
         feature: None,
     },
@@ -64,6 +74,24 @@ const COMMANDS: &[CommandDescriptor] = &[
         parser: parse_clean_command,
         feature: None,
     },
+    CommandDescriptor {
+        name: "update",
+        aliases: &[],
+        parser: parse_update_command,
+        feature: None,
+    },
+    CommandDescriptor {
+        name: "pack",
+        aliases: &[],
+        parser: parse_pack_command,
+        feature: None,
+    },
+    CommandDescriptor {
+        name: "publish",
+        aliases: &[],
+        parser: parse_publish_command,
+        feature: None,
+    },
     CommandDescriptor {
         name: "init",
         aliases: &[],
@@ -94,12 +122,24 @@ const COMMANDS: &[CommandDescriptor] = &[
         parser: parse_test_command,
         feature: None,
     },
+    CommandDescriptor {
+        name: "watch",
+        aliases: &[],
+        parser: parse_watch_command,
+        feature: None,
+    },
     CommandDescriptor {
         name: "coverage",
         aliases: &[],
         parser: parse_coverage_command,
         feature: None,
     },
+    CommandDescriptor {
+        name: "bench",
+        aliases: &[],
+        parser: parse_bench_command,
+        feature: None,
+    },
     CommandDescriptor {
         name: "format",
         aliases: &["cleanup"],
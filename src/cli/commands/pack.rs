@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+
+use crate::logging::LogOptions;
+
+use super::super::{Cli, CliError, Command};
+use super::common::is_help_flag;
+
+pub(super) fn parse(args: Vec<String>) -> Result<Cli, CliError> {
+    if args.iter().any(|value| is_help_flag(value)) {
+        return Ok(Cli {
+            command: Command::Help {
+                topic: Some("pack".into()),
+            },
+            log_options: LogOptions::from_env(),
+            error_format: None,
+        });
+    }
+
+    let mut project: Option<PathBuf> = None;
+    let mut output: Option<PathBuf> = None;
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-o" | "--output" => {
+                let Some(value) = iter.next() else {
+                    return Err(CliError::with_usage("expected path after --output"));
+                };
+                output = Some(PathBuf::from(value));
+            }
+            other if other.starts_with('-') => {
+                return Err(CliError::with_usage(format!(
+                    "unsupported option '{other}' for command"
+                )));
+            }
+            other => {
+                if project.is_some() {
+                    return Err(CliError::with_usage(
+                        "pack accepts a single project or manifest path",
+                    ));
+                }
+                project = Some(PathBuf::from(other));
+            }
+        }
+    }
+
+    Ok(Cli {
+        command: Command::Pack { project, output },
+        log_options: LogOptions::from_env(),
+        error_format: None,
+    })
+}
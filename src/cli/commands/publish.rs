@@ -0,0 +1,65 @@
+use std::path::PathBuf;
+
+use crate::logging::LogOptions;
+
+use super::super::{Cli, CliError, Command};
+use super::common::is_help_flag;
+
+pub(super) fn parse(args: Vec<String>) -> Result<Cli, CliError> {
+    if args.iter().any(|value| is_help_flag(value)) {
+        return Ok(Cli {
+            command: Command::Help {
+                topic: Some("publish".into()),
+            },
+            log_options: LogOptions::from_env(),
+            error_format: None,
+        });
+    }
+
+    let mut project: Option<PathBuf> = None;
+    let mut registry: Option<String> = None;
+    let mut dry_run = false;
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--registry" => {
+                let Some(value) = iter.next() else {
+                    return Err(CliError::with_usage(
+                        "expected directory or http:// URL after --registry",
+                    ));
+                };
+                if value.trim().is_empty() {
+                    return Err(CliError::with_usage(
+                        "--registry requires a non-empty value",
+                    ));
+                }
+                registry = Some(value);
+            }
+            "--dry-run" => dry_run = true,
+            other if other.starts_with('-') => {
+                return Err(CliError::with_usage(format!(
+                    "unsupported option '{other}' for command"
+                )));
+            }
+            other => {
+                if project.is_some() {
+                    return Err(CliError::with_usage(
+                        "publish accepts a single project or manifest path",
+                    ));
+                }
+                project = Some(PathBuf::from(other));
+            }
+        }
+    }
+
+    Ok(Cli {
+        command: Command::Publish {
+            project,
+            registry,
+            dry_run,
+        },
+        log_options: LogOptions::from_env(),
+        error_format: None,
+    })
+}
@@ -0,0 +1,56 @@
+use std::path::PathBuf;
+
+use crate::logging::LogOptions;
+
+use super::super::{Cli, CliError, Command};
+use super::common::is_help_flag;
+
+pub(super) fn parse(args: Vec<String>) -> Result<Cli, CliError> {
+    if args.iter().any(|value| is_help_flag(value)) {
+        return Ok(Cli {
+            command: Command::Help {
+                topic: Some("update".into()),
+            },
+            log_options: LogOptions::from_env(),
+            error_format: None,
+        });
+    }
+
+    let mut manifest: Option<PathBuf> = None;
+    let mut packages = Vec::new();
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--manifest" => {
+                let Some(value) = iter.next() else {
+                    return Err(CliError::with_usage(
+                        "expected manifest.yaml or project directory after --manifest",
+                    ));
+                };
+                if value.trim().is_empty() {
+                    return Err(CliError::with_usage(
+                        "--manifest requires a non-empty value",
+                    ));
+                }
+                manifest = Some(PathBuf::from(value));
+            }
+            other if other.starts_with('-') => {
+                return Err(CliError::with_usage(format!(
+                    "unsupported option '{other}' for command"
+                )));
+            }
+            other => {
+                if !packages.iter().any(|name| name == other) {
+                    packages.push(other.to_string());
+                }
+            }
+        }
+    }
+
+    Ok(Cli {
+        command: Command::Update { manifest, packages },
+        log_options: LogOptions::from_env(),
+        error_format: None,
+    })
+}
@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use crate::logging::LogOptions;
+
+use super::super::{Cli, CliError, Command, WatchOptions};
+use super::build_like::{CommandKind, parse_build_like};
+use super::common::is_help_flag;
+
+pub(super) fn parse(args: Vec<String>) -> Result<Cli, CliError> {
+    if args.iter().any(|value| is_help_flag(value)) {
+        return Ok(Cli {
+            command: Command::Help {
+                topic: Some("watch".into()),
+            },
+            log_options: LogOptions::from_env(),
+            error_format: None,
+        });
+    }
+
+    let mut args = args.into_iter().peekable();
+    // Global options are merged ahead of the verb; keep them for the watched command.
+    let mut forwarded = Vec::new();
+    while let Some(flag) = args.next_if(|arg| is_global_option(arg)) {
+        let takes_value = !flag.contains(':');
+        forwarded.push(flag);
+        if takes_value {
+            forwarded.extend(args.next());
+        }
+    }
+    let verb = args
+        .next_if(|arg| matches!(arg.as_str(), "build" | "check" | "test" | "run"))
+        .unwrap_or_else(|| "build".to_string());
+
+    let mut options = WatchOptions::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--interval" => {
+                options.interval = parse_millis(args.next(), "--interval")?;
+            }
+            "--debounce" => {
+                options.debounce = parse_millis(args.next(), "--debounce")?;
+            }
+            "--json" => options.json = true,
+            _ => forwarded.push(arg),
+        }
+    }
+
+    let inner = match verb.as_str() {
+        "check" => super::check::parse(forwarded.clone())?,
+        "test" => parse_build_like(forwarded.clone(), CommandKind::Test)?,
+        "run" => parse_build_like(forwarded.clone(), CommandKind::Run)?,
+        _ => parse_build_like(forwarded.clone(), CommandKind::Build)?,
+    };
+    if matches!(inner.command, Command::Help { .. }) {
+        return Ok(inner);
+    }
+    let mut args = vec![verb];
+    args.extend(forwarded);
+    Ok(Cli {
+        command: Command::Watch {
+            args,
+            command: Box::new(inner.command),
+            options,
+        },
+        log_options: inner.log_options,
+        error_format: inner.error_format,
+    })
+}
+
+fn is_global_option(arg: &str) -> bool {
+    arg.starts_with("--property:")
+        || arg.starts_with("-p:")
+        || matches!(
+            arg,
+            "-c" | "--configuration"
+                | "-f"
+                | "--framework"
+                | "-r"
+                | "--runtime"
+                | "-v"
+                | "--verbosity"
+                | "-p"
+                | "--property"
+        )
+}
+
+fn parse_millis(value: Option<String>, flag: &str) -> Result<Duration, CliError> {
+    let Some(value) = value else {
+        return Err(CliError::with_usage(format!(
+            "expected milliseconds after {flag}"
+        )));
+    };
+    match value.trim().parse::<u64>() {
+        Ok(millis) if millis > 0 => Ok(Duration::from_millis(millis)),
+        _ => Err(CliError::with_usage(format!(
+            "{flag} expects a positive number of milliseconds"
+        ))),
+    }
+}
@@ -0,0 +1,216 @@
+use std::fs;
+use std::path::Path;
+
+use crate::cli::CliError;
+use crate::diagnostics::FormatOptions;
+use crate::driver::{BenchResult, BenchStats, BuildRequest, TestOptions, TestStatus};
+use crate::error::{Error, Result};
+use crate::perf::{PerfMetric, PerfRun, PerfSnapshot, trace_id};
+
+use super::super::DispatchDriver;
+use super::super::logging::format_input_list;
+use super::super::reporting::print_report_diagnostics;
+use super::apply_const_eval_config;
+use super::diagnostics_are_fatal;
+use super::perf_report::gate_target_run;
+
+/// perf.json run profile holding `chic bench` results.
+pub(in crate::cli::dispatch) const BENCH_PROFILE: &str = "bench";
+const PERF_SNAPSHOT_VERSION: &str = "0.1";
+
+pub(in crate::cli::dispatch) fn run_benchmarks<D: DispatchDriver>(
+    driver: &D,
+    request: BuildRequest,
+    const_eval_fuel: Option<usize>,
+    test_options: TestOptions,
+    format_options: FormatOptions,
+) -> Result<()> {
+    let Some(bench) = test_options.bench.clone() else {
+        return Err(Error::internal(
+            "chic bench dispatched without bench options",
+        ));
+    };
+    apply_const_eval_config(const_eval_fuel);
+    let target = request.target.triple().to_string();
+    let run = driver.run_tests(request, test_options)?;
+    let fatal = run.report.has_errors() || diagnostics_are_fatal();
+    if run.report.has_diagnostics() {
+        println!("benchcase discovery completed with diagnostics:");
+        print_report_diagnostics(&run.report, format_options);
+        if fatal {
+            return Err(Error::Cli(CliError::new("diagnostics reported; see above")));
+        }
+    }
+    println!(
+        "discovered {} benchcase(s) (selected {}, filtered out {}) in {}",
+        run.discovered,
+        run.benchmarks.len(),
+        run.filtered_out,
+        format_input_list(
+            &run.report
+                .modules
+                .iter()
+                .map(|module| module.input.clone())
+                .collect::<Vec<_>>()
+        )
+    );
+    if run.benchmarks.is_empty() {
+        if run.discovered == 0 {
+            println!("no benchcases found");
+        } else {
+            println!(
+                "no benchcases matched the selection (filtered out {})",
+                run.filtered_out
+            );
+        }
+        return Ok(());
+    }
+
+    for result in &run.benchmarks {
+        println!("{}", format_result(result));
+    }
+    let failed = run
+        .benchmarks
+        .iter()
+        .filter(|result| matches!(result.status, TestStatus::Failed))
+        .count();
+
+    let measured = perf_run(&run.benchmarks, &target);
+    if !measured.metrics.is_empty() {
+        write_bench_run(&bench.output, &target, measured)?;
+        println!("benchmark results written to {}", bench.output.display());
+        if let Some(baseline) = &bench.baseline {
+            gate_target_run(
+                &bench.output,
+                baseline,
+                BENCH_PROFILE,
+                &target,
+                bench.tolerance,
+            )?;
+        }
+    }
+
+    if failed > 0 {
+        Err(Error::Cli(CliError::new("one or more benchcases failed")))
+    } else {
+        Ok(())
+    }
+}
+
+fn format_result(result: &BenchResult) -> String {
+    let Some(stats) = &result.stats else {
+        let status = match result.status {
+            TestStatus::Skipped => "SKIP",
+            _ => "FAIL",
+        };
+        return format!(
+            "[{status}] {} -- id={}; {}",
+            result.name,
+            result.id,
+            result.message.as_deref().unwrap_or("no measurement")
+        );
+    };
+    let BenchStats {
+        samples,
+        iterations,
+        mean_ns,
+        median_ns,
+        std_dev_ns,
+        ci_low_ns,
+        ci_high_ns,
+    } = *stats;
+    format!(
+        "[BENCH] {} -- mean {} ± {} (95% CI {} .. {}); median {}; std dev {}; {samples} samples x {iterations} iterations",
+        result.name,
+        format_ns(mean_ns),
+        format_ns(ci_high_ns - mean_ns),
+        format_ns(ci_low_ns),
+        format_ns(ci_high_ns),
+        format_ns(median_ns),
+        format_ns(std_dev_ns),
+    )
+}
+
+fn format_ns(ns: f64) -> String {
+    if ns >= 1e9 {
+        format!("{:.3}s", ns / 1e9)
+    } else if ns >= 1e6 {
+        format!("{:.3}ms", ns / 1e6)
+    } else if ns >= 1e3 {
+        format!("{:.3}us", ns / 1e3)
+    } else {
+        format!("{ns:.1}ns")
+    }
+}
+
+/// Mean per-iteration times as perf.json metrics, keyed like tracepoints by benchcase name.
+fn perf_run(results: &[BenchResult], target: &str) -> PerfRun {
+    let metrics = results
+        .iter()
+        .filter_map(|result| {
+            let stats = result.stats?;
+            Some(PerfMetric {
+                trace_id: trace_id(&result.qualified_name, BENCH_PROFILE),
+                mir_id: result.qualified_name.clone(),
+                label: result.qualified_name.clone(),
+                cpu_us: stats.mean_ns / 1000.0,
+                budget_cpu_us: None,
+                budget_gpu_us: None,
+                budget_mem_bytes: None,
+            })
+        })
+        .collect();
+    PerfRun {
+        profile: BENCH_PROFILE.into(),
+        metrics,
+        run_log: None,
+        target: Some(target.to_string()),
+    }
+}
+
+/// Store `run` in the perf.json at `path`, replacing an earlier bench run for the same target
+/// and keeping any other profiles recorded there.
+fn write_bench_run(path: &Path, target: &str, run: PerfRun) -> Result<()> {
+    let mut snapshot = match fs::read_to_string(path) {
+        Ok(data) => serde_json::from_str::<PerfSnapshot>(&data).map_err(|err| {
+            Error::Cli(CliError::new(format!(
+                "failed to decode existing perf data {}: {err}; refusing to overwrite it",
+                path.display()
+            )))
+        })?,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => PerfSnapshot {
+            version: PERF_SNAPSHOT_VERSION.into(),
+            target: target.to_string(),
+            runs: Vec::new(),
+            summary: None,
+        },
+        Err(err) => {
+            return Err(Error::Cli(CliError::new(format!(
+                "failed to read existing perf data {}: {err}",
+                path.display()
+            ))));
+        }
+    };
+    let runs = std::mem::take(&mut snapshot.runs);
+    snapshot.runs = runs
+        .into_iter()
+        .filter(|existing| {
+            existing.profile != run.profile || snapshot.run_target(existing) != target
+        })
+        .collect();
+    snapshot.runs.push(run);
+    if let Some(parent) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    let encoded = serde_json::to_string_pretty(&snapshot).map_err(|err| {
+        Error::Cli(CliError::new(format!(
+            "failed to encode benchmark results: {err}"
+        )))
+    })?;
+    fs::write(path, encoded + "\n").map_err(|err| {
+        Error::Cli(CliError::new(format!(
+            "failed to write benchmark results {}: {err}",
+            path.display()
+        )))
+    })
+}
@@ -11,23 +11,31 @@ use crate::extern_bind::ExternBindOptions;
 use crate::logging::LogLevel;
 use crate::manifest::{Manifest, MissingDocsRule, PROJECT_MANIFEST_BASENAME, WorkspaceConfig};
 use crate::target::Target;
+use crate::threading;
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use super::ffi::resolve_cli_ffi_options;
 
+mod bench;
 mod check_build;
 mod doc;
 mod init;
 mod perf_report;
+mod publish;
 mod run_test;
 mod seed;
+mod test_report;
 mod tools;
+mod update;
+mod watch;
 
+pub(super) use bench::run_benchmarks;
 pub(super) use check_build::{run_build, run_check, run_lint};
 pub(super) use doc::run_doc;
 use init::run_init;
 pub(super) use perf_report::run_perf_report;
+use publish::{run_pack, run_publish};
 pub(super) use run_test::{run_run, run_tests};
 use seed::run_seed;
 pub(super) use tools::run_clean;
@@ -39,6 +47,8 @@ pub(super) use tools::{
 pub(super) use tools::{
     FormatCommandOptions, run_cc1, run_extern_bind, run_format, run_header, run_mir_dump,
 };
+use update::run_update;
+use watch::run_watch;
 
 pub(super) fn dispatch_command<D: super::DispatchDriver>(
     driver: &D,
@@ -52,41 +62,49 @@ pub(super) fn dispatch_command<D: super::DispatchDriver>(
             target,
             kind,
             const_eval_fuel,
+            jobs,
             trace_pipeline,
             trait_solver_metrics,
             defines,
-        } => run_check(
-            driver,
-            inputs,
-            &target,
-            kind,
-            const_eval_fuel,
-            trace_pipeline,
-            trait_solver_metrics,
-            defines,
-            effective_level,
-            format_options,
-        ),
+        } => {
+            threading::configure_jobs(jobs);
+            run_check(
+                driver,
+                inputs,
+                &target,
+                kind,
+                const_eval_fuel,
+                trace_pipeline,
+                trait_solver_metrics,
+                defines,
+                effective_level,
+                format_options,
+            )
+        }
         Command::Lint {
             inputs,
             target,
             kind,
             const_eval_fuel,
+            jobs,
             trace_pipeline,
             trait_solver_metrics,
             defines,
-        } => run_lint(
-            driver,
-            inputs,
-            &target,
-            kind,
-            const_eval_fuel,
-            trace_pipeline,
-            trait_solver_metrics,
-            defines,
-            effective_level,
-            format_options,
-        ),
+        } => {
+            threading::configure_jobs(jobs);
+            run_lint(
+                driver,
+                inputs,
+                &target,
+                kind,
+                const_eval_fuel,
+                trace_pipeline,
+                trait_solver_metrics,
+                defines,
+                effective_level,
+                format_options,
+            )
+        }
         Command::Build {
             inputs,
             manifest,
@@ -106,6 +124,7 @@ pub(super) fn dispatch_command<D: super::DispatchDriver>(
             cc1_keep_temps,
             load_stdlib: load_stdlib_override,
             const_eval_fuel,
+            jobs,
             trace_pipeline,
             trait_solver_metrics,
             defines,
@@ -114,6 +133,7 @@ pub(super) fn dispatch_command<D: super::DispatchDriver>(
             framework,
             no_dependencies,
             no_restore,
+            locked,
             no_incremental,
             disable_build_servers,
             source_root,
@@ -129,6 +149,7 @@ pub(super) fn dispatch_command<D: super::DispatchDriver>(
             manifest_path,
             doc_enforcement,
         } => {
+            threading::configure_jobs(jobs);
             let load_stdlib =
                 load_stdlib_override.unwrap_or_else(|| driver.should_load_stdlib(&inputs));
             let ffi = resolve_cli_ffi_options(&cli_ffi, &target)?;
@@ -167,6 +188,7 @@ pub(super) fn dispatch_command<D: super::DispatchDriver>(
                     framework,
                     no_dependencies,
                     no_restore,
+                    locked,
                     no_incremental,
                     rebuild: false,
                     incremental_validate: false,
@@ -223,6 +245,7 @@ pub(super) fn dispatch_command<D: super::DispatchDriver>(
             runtime_backend,
             cpu_isa,
             const_eval_fuel,
+            jobs,
             trace_pipeline,
             trait_solver_metrics,
             defines,
@@ -233,6 +256,7 @@ pub(super) fn dispatch_command<D: super::DispatchDriver>(
             artifacts_path,
             no_dependencies,
             no_restore,
+            locked,
             no_incremental,
             disable_build_servers,
             source_root,
@@ -248,6 +272,7 @@ pub(super) fn dispatch_command<D: super::DispatchDriver>(
             load_stdlib,
             doc_enforcement,
         } => {
+            threading::configure_jobs(jobs);
             let load_stdlib = load_stdlib.unwrap_or_else(|| driver.should_load_stdlib(&inputs));
             let ffi = resolve_cli_ffi_options(&cli_ffi, &target)?;
             run_run(
@@ -283,6 +308,7 @@ pub(super) fn dispatch_command<D: super::DispatchDriver>(
                     bin_dir: None,
                     no_dependencies,
                     no_restore,
+                    locked,
                     no_incremental,
                     rebuild: false,
                     incremental_validate: false,
@@ -314,6 +340,7 @@ pub(super) fn dispatch_command<D: super::DispatchDriver>(
             runtime_backend,
             cpu_isa,
             const_eval_fuel,
+            jobs,
             trace_pipeline,
             trait_solver_metrics,
             defines,
@@ -328,6 +355,7 @@ pub(super) fn dispatch_command<D: super::DispatchDriver>(
             artifacts_path,
             no_dependencies,
             no_restore,
+            locked,
             no_incremental,
             disable_build_servers,
             source_root,
@@ -343,6 +371,7 @@ pub(super) fn dispatch_command<D: super::DispatchDriver>(
             load_stdlib,
             doc_enforcement,
         } => {
+            threading::configure_jobs(jobs);
             let ffi = resolve_cli_ffi_options(&cli_ffi, &target)?;
             if workspace_mode {
                 let Some(workspace) = workspace else {
@@ -372,6 +401,7 @@ pub(super) fn dispatch_command<D: super::DispatchDriver>(
                     artifacts_path,
                     no_dependencies,
                     no_restore,
+                    locked,
                     no_incremental,
                     disable_build_servers,
                     source_root,
@@ -390,55 +420,66 @@ pub(super) fn dispatch_command<D: super::DispatchDriver>(
                 );
             }
             let load_stdlib = load_stdlib.unwrap_or_else(|| driver.should_load_stdlib(&inputs));
+            let request = BuildRequest {
+                inputs,
+                manifest,
+                workspace,
+                target,
+                kind,
+                backend,
+                runtime_backend,
+                output: None,
+                run_timeout: None,
+                emit_wat_text: false,
+                emit_object: false,
+                coverage,
+                cpu_isa,
+                emit_header: false,
+                emit_library_pack: false,
+                cc1_args: Vec::new(),
+                cc1_keep_temps: false,
+                load_stdlib,
+                trace_pipeline,
+                trait_solver_metrics,
+                defines,
+                log_level: effective_level,
+                ffi,
+                configuration,
+                framework,
+                artifacts_path,
+                obj_dir: None,
+                bin_dir: None,
+                no_dependencies,
+                no_restore,
+                locked,
+                no_incremental,
+                rebuild: false,
+                incremental_validate: false,
+                clean_only: false,
+                disable_build_servers,
+                source_root,
+                properties,
+                verbosity,
+                telemetry,
+                version_suffix,
+                nologo,
+                force,
+                interactive,
+                self_contained,
+                doc_enforcement,
+            };
+            if test_options.bench.is_some() {
+                return run_benchmarks(
+                    driver,
+                    request,
+                    const_eval_fuel,
+                    test_options,
+                    format_options,
+                );
+            }
             run_tests(
                 driver,
-                BuildRequest {
-                    inputs,
-                    manifest,
-                    workspace,
-                    target,
-                    kind,
-                    backend,
-                    runtime_backend,
-                    output: None,
-                    run_timeout: None,
-                    emit_wat_text: false,
-                    emit_object: false,
-                    coverage,
-                    cpu_isa,
-                    emit_header: false,
-                    emit_library_pack: false,
-                    cc1_args: Vec::new(),
-                    cc1_keep_temps: false,
-                    load_stdlib,
-                    trace_pipeline,
-                    trait_solver_metrics,
-                    defines,
-                    log_level: effective_level,
-                    ffi,
-                    configuration,
-                    framework,
-                    artifacts_path,
-                    obj_dir: None,
-                    bin_dir: None,
-                    no_dependencies,
-                    no_restore,
-                    no_incremental,
-                    rebuild: false,
-                    incremental_validate: false,
-                    clean_only: false,
-                    disable_build_servers,
-                    source_root,
-                    properties,
-                    verbosity,
-                    telemetry,
-                    version_suffix,
-                    nologo,
-                    force,
-                    interactive,
-                    self_contained,
-                    doc_enforcement,
-                },
+                request,
                 const_eval_fuel,
                 profile,
                 test_options,
@@ -587,6 +628,27 @@ pub(super) fn dispatch_command<D: super::DispatchDriver>(
             all,
             dry_run,
         ),
+        Command::Update { manifest, packages } => {
+            run_update(manifest.as_deref(), packages, format_options)
+        }
+        Command::Pack { project, output } => run_pack(project.as_deref(), output.as_deref()),
+        Command::Publish {
+            project,
+            registry,
+            dry_run,
+        } => run_publish(project.as_deref(), registry.as_deref(), dry_run),
+        Command::Watch {
+            args,
+            command,
+            options,
+        } => run_watch(
+            driver,
+            args,
+            *command,
+            &options,
+            effective_level,
+            format_options,
+        ),
         Command::ShowSpec => {
             let spec = driver.spec();
             println!("Specification: {}", spec.relative_path);
@@ -632,6 +694,7 @@ fn run_workspace_tests<D: super::DispatchDriver>(
     artifacts_path: Option<PathBuf>,
     no_dependencies: bool,
     no_restore: bool,
+    locked: bool,
     no_incremental: bool,
     disable_build_servers: bool,
     source_root: Option<PathBuf>,
@@ -811,6 +874,7 @@ fn run_workspace_tests<D: super::DispatchDriver>(
                 bin_dir: None,
                 no_dependencies,
                 no_restore,
+                locked,
                 no_incremental,
                 rebuild: false,
                 incremental_validate: false,
@@ -46,27 +46,48 @@ pub(in crate::cli::dispatch) fn run_perf_report(
 ) -> Result<()> {
     let snapshot = load_snapshot(perf)?;
     let run = select_run(&snapshot, profile)?;
-    let baseline_snapshot = if let Some(path) = baseline {
-        Some(load_snapshot(path)?)
-    } else {
-        None
-    };
+    let target = snapshot.run_target(run);
+    let baseline_snapshot = baseline.map(load_snapshot).transpose()?;
     let baseline_run = match baseline_snapshot.as_ref() {
-        Some(snapshot) => Some(select_run(snapshot, profile)?),
+        Some(snapshot) => Some(select_baseline_run(snapshot, profile, target)?),
         None => None,
     };
+    let report = build_report(target, run, baseline_run, tolerance);
+    emit_report(&report, json, strict)
+}
 
-    let report = build_report(&snapshot.target, run, baseline_run, tolerance);
+/// Gate the `profile` run measured on `target` against the baseline's run for the same
+/// target.
+pub(in crate::cli::dispatch) fn gate_target_run(
+    perf: &Path,
+    baseline: &Path,
+    profile: &str,
+    target: &str,
+    tolerance: f64,
+) -> Result<()> {
+    let snapshot = load_snapshot(perf)?;
+    let run = snapshot.run_for_target(profile, target).ok_or_else(|| {
+        Error::Cli(CliError::new(format!(
+            "perf data {} has no `{profile}` run for target {target}",
+            perf.display()
+        )))
+    })?;
+    let baseline_snapshot = load_snapshot(baseline)?;
+    let baseline_run = select_baseline_run(&baseline_snapshot, Some(profile), target)?;
+    let report = build_report(target, run, Some(baseline_run), tolerance);
+    emit_report(&report, false, true)
+}
 
+fn emit_report(report: &PerfReportOutput, json: bool, strict: bool) -> Result<()> {
     if json {
-        serde_json::to_writer_pretty(std::io::stdout(), &report).map_err(|err| {
+        serde_json::to_writer_pretty(std::io::stdout(), report).map_err(|err| {
             Error::Cli(CliError::new(format!(
                 "failed to encode report as JSON: {err}"
             )))
         })?;
         println!();
     } else {
-        print_summary(&report);
+        print_summary(report);
     }
 
     if strict && (report.summary.over_budget > 0 || report.summary.regressions > 0) {
@@ -93,6 +114,19 @@ fn load_snapshot(path: &Path) -> Result<PerfSnapshot> {
     })
 }
 
+/// The baseline run for `target`, or the profile's run when the baseline was recorded
+/// without per-run targets.
+fn select_baseline_run<'a>(
+    snapshot: &'a PerfSnapshot,
+    profile: Option<&str>,
+    target: &str,
+) -> Result<&'a PerfRun> {
+    match profile.and_then(|profile| snapshot.run_for_target(profile, target)) {
+        Some(run) => Ok(run),
+        None => select_run(snapshot, profile),
+    }
+}
+
 fn select_run<'a>(snapshot: &'a PerfSnapshot, profile: Option<&str>) -> Result<&'a PerfRun> {
     snapshot.run_by_profile(profile).ok_or_else(|| {
         Error::Cli(CliError::new(format!(
@@ -254,6 +288,7 @@ mod tests {
             profile: "debug".into(),
             metrics,
             run_log: None,
+            target: None,
         }
     }
 
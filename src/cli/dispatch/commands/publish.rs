@@ -0,0 +1,86 @@
+use std::path::{Path, PathBuf};
+
+use crate::cli::CliError;
+use crate::error::{Error, Result};
+use crate::manifest::Manifest;
+use crate::package::registry::{self, PackedPackage, REGISTRY_ENV, Registry};
+
+/// Pack the project into `<name>-<version>.tar` (or `output`).
+pub(in crate::cli::dispatch) fn run_pack(
+    project: Option<&Path>,
+    output: Option<&Path>,
+) -> Result<()> {
+    let packed = pack_project(project)?;
+    let output = output.map_or_else(
+        || PathBuf::from(format!("{}-{}.tar", packed.name, packed.version)),
+        Path::to_path_buf,
+    );
+    if let Some(parent) = output
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+    std::fs::write(&output, &packed.archive).map_err(Error::Io)?;
+    println!(
+        "pack: wrote {} ({} {}, blake3 {})",
+        output.display(),
+        packed.name,
+        packed.version,
+        packed.checksum
+    );
+    Ok(())
+}
+
+/// Pack the project and add it to `registry` (default: `$CHIC_REGISTRY`).
+pub(in crate::cli::dispatch) fn run_publish(
+    project: Option<&Path>,
+    registry: Option<&str>,
+    dry_run: bool,
+) -> Result<()> {
+    let location = registry
+        .map(str::to_string)
+        .or_else(|| {
+            std::env::var(REGISTRY_ENV)
+                .ok()
+                .filter(|value| !value.trim().is_empty())
+        })
+        .ok_or_else(|| {
+            Error::Cli(CliError::new(format!(
+                "no registry to publish to; pass --registry <dir|url> or set {REGISTRY_ENV}"
+            )))
+        })?;
+    let cwd = std::env::current_dir().map_err(Error::Io)?;
+    let target = Registry::locate(&location, &cwd)
+        .map_err(|err| Error::Cli(CliError::new(err.to_string())))?;
+    let packed = pack_project(project)?;
+    if dry_run {
+        println!(
+            "publish: would publish {} {} to {target} (blake3 {})",
+            packed.name, packed.version, packed.checksum
+        );
+        return Ok(());
+    }
+    let entry = target
+        .publish(&packed.name, &packed.version, &packed.archive)
+        .map_err(|err| Error::Cli(CliError::new(format!("publish failed: {err}"))))?;
+    println!(
+        "publish: {} {} -> {target} (blake3 {})",
+        packed.name, entry.version, entry.checksum
+    );
+    Ok(())
+}
+
+fn pack_project(project: Option<&Path>) -> Result<PackedPackage> {
+    let start = match project {
+        Some(path) => path.to_path_buf(),
+        None => std::env::current_dir().map_err(Error::Io)?,
+    };
+    let manifest = Manifest::discover(&start)?.ok_or_else(|| {
+        Error::Cli(CliError::new(format!(
+            "manifest.yaml not found at {}",
+            start.display()
+        )))
+    })?;
+    registry::pack(&manifest).map_err(|err| Error::Cli(CliError::new(err.to_string())))
+}
@@ -1,7 +1,7 @@
 use std::collections::BTreeMap;
 use std::ffi::OsString;
 use std::fs::File;
-use std::io::{BufReader, BufWriter};
+use std::io::{self, BufReader, BufWriter, Write};
 use std::path::{Path, PathBuf};
 
 use crate::Target;
@@ -15,9 +15,12 @@ use inferno::flamegraph;
 
 use super::super::DispatchDriver;
 use super::super::logging::{format_input_list, print_trait_solver_metrics};
-use super::super::reporting::{print_report_diagnostics, relay_run_output};
+use super::super::reporting::{
+    print_report_diagnostics, print_report_diagnostics_to, relay_run_output,
+};
 use super::apply_const_eval_config;
 use super::diagnostics_are_fatal;
+use super::test_report::TestReports;
 use super::tools::{
     build_file_organization_diagnostics, build_format_diagnostics, build_ordering_diagnostics,
     check_formatting,
@@ -131,7 +134,7 @@ pub(in crate::cli::dispatch) fn run_tests<D: DispatchDriver>(
     request: BuildRequest,
     const_eval_fuel: Option<usize>,
     profile: Option<ProfileOptions>,
-    test_options: TestOptions,
+    mut test_options: TestOptions,
     coverage_only: bool,
     coverage_min: Option<u8>,
     format_options: FormatOptions,
@@ -180,6 +183,13 @@ pub(in crate::cli::dispatch) fn run_tests<D: DispatchDriver>(
         .as_ref()
         .and_then(|manifest| manifest.coverage())
         .cloned();
+    let reports = TestReports::open(&test_options.reports)?;
+    test_options.events = reports.events();
+    let mut out: Box<dyn Write> = if reports.writes_stdout() {
+        Box::new(io::stderr())
+    } else {
+        Box::new(io::stdout())
+    };
     let mut run = driver.run_tests(request, test_options)?;
     if let Some(violations) = format_violations {
         let mut diagnostics = build_format_diagnostics(&violations, &mut run.report.files);
@@ -197,15 +207,20 @@ pub(in crate::cli::dispatch) fn run_tests<D: DispatchDriver>(
     }
     let fatal = run.report.has_errors() || diagnostics_are_fatal();
     if run.report.has_diagnostics() {
-        println!("test discovery completed with diagnostics:");
-        print_report_diagnostics(&run.report, format_options);
+        writeln!(out, "test discovery completed with diagnostics:")?;
+        if reports.writes_stdout() {
+            print_report_diagnostics_to(&run.report, format_options, &mut out)?;
+        } else {
+            print_report_diagnostics(&run.report, format_options);
+        }
         if fatal {
             return Err(Error::Cli(crate::cli::CliError::new(
                 "diagnostics reported; see above",
             )));
         }
     } else {
-        println!(
+        writeln!(
+            out,
             "discovered {} test(s) (selected {}, filtered out {}) in {}",
             run.discovered,
             run.cases.len(),
@@ -217,17 +232,20 @@ pub(in crate::cli::dispatch) fn run_tests<D: DispatchDriver>(
                     .map(|module| module.input.clone())
                     .collect::<Vec<_>>()
             )
-        );
+        )?;
     }
 
+    reports.finish(&run.cases)?;
+
     if run.cases.is_empty() {
         if run.discovered == 0 {
-            println!("no testcases found");
+            writeln!(out, "no testcases found")?;
         } else {
-            println!(
+            writeln!(
+                out,
                 "no testcases matched the selection (filtered out {})",
                 run.filtered_out
-            );
+            )?;
         }
         return Ok(());
     }
@@ -255,10 +273,11 @@ pub(in crate::cli::dispatch) fn run_tests<D: DispatchDriver>(
         .filter(|case| matches!(case.status, TestStatus::Skipped))
         .count();
 
-    println!(
+    writeln!(
+        out,
         "discovered {} testcase(s) (filtered out {})",
         discovered, filtered_out
-    );
+    )?;
 
     if !coverage_only {
         for case in &run.cases {
@@ -285,11 +304,12 @@ pub(in crate::cli::dispatch) fn run_tests<D: DispatchDriver>(
             if let Some(time) = duration {
                 details.push(format!("duration={time}"));
             }
-            println!(
+            writeln!(
+                out,
                 "[{status}] {}{async_flag} -- {}",
                 case.name,
                 details.join("; ")
-            );
+            )?;
         }
     }
 
@@ -302,10 +322,11 @@ pub(in crate::cli::dispatch) fn run_tests<D: DispatchDriver>(
                 )))
             })?;
         }
-        println!(
+        writeln!(
+            out,
             "chic coverage: {:.2}% (covered {}, total {})",
             coverage.percent, coverage.covered, coverage.total
-        );
+        )?;
         if coverage_requested {
             if let Some(min) = coverage_min {
                 if coverage.percent + f64::EPSILON < f64::from(min) {
@@ -349,19 +370,21 @@ pub(in crate::cli::dispatch) fn run_tests<D: DispatchDriver>(
         if options.flamegraph {
             render_flamegraph(&options.output)?;
         }
-        println!(
+        writeln!(
+            out,
             "profiling artefacts written to {} (perf.json, perf.summary.json, perf.folded{})",
             options.output.display(),
             if options.flamegraph { ", perf.svg" } else { "" }
-        );
+        )?;
     }
 
-    println!(
+    writeln!(
+        out,
         "summary: {passed} passed, {failed} failed, {skipped} skipped{}",
         total_duration
             .map(|elapsed| format!(" in {:.2}ms", elapsed.as_secs_f64() * 1000.0))
             .unwrap_or_default()
-    );
+    )?;
 
     if failed > 0 {
         Err(Error::Cli(CliError::new("one or more tests failed")))
@@ -0,0 +1,451 @@
+//! Machine-readable `chic test --report` output: JUnit XML, TAP and JSON Lines.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde_json::{Value, json};
+
+use crate::driver::{
+    TestCaseResult, TestEvent, TestEventSink, TestReportFormat, TestReportRequest, TestStatus,
+};
+use crate::error::Result;
+use crate::mir::TestCaseMetadata;
+
+type SharedWriter = Arc<Mutex<Box<dyn Write + Send>>>;
+
+/// Reports requested for one `chic test` run.
+pub(super) struct TestReports {
+    requests: Vec<TestReportRequest>,
+    /// Open JSON Lines stream; events are written as testcases start and finish.
+    stream: Option<SharedWriter>,
+}
+
+impl TestReports {
+    pub(super) fn open(requests: &[TestReportRequest]) -> Result<Self> {
+        let stream = match requests
+            .iter()
+            .find(|request| request.format == TestReportFormat::JsonLines)
+        {
+            Some(request) => Some(Arc::new(Mutex::new(open_writer(request.path.as_deref())?))),
+            None => None,
+        };
+        Ok(Self {
+            requests: requests.to_vec(),
+            stream,
+        })
+    }
+
+    /// Whether a report claims stdout, so human-readable output must move to stderr.
+    pub(super) fn writes_stdout(&self) -> bool {
+        self.requests.iter().any(|request| request.path.is_none())
+    }
+
+    pub(super) fn events(&self) -> Option<TestEventSink> {
+        let stream = self.stream.clone()?;
+        Some(TestEventSink::new(move |event| {
+            let line = match event {
+                TestEvent::Started(meta) => started_event(meta),
+                TestEvent::Finished(case) => finished_event(case),
+            };
+            write_line(&stream, &line);
+        }))
+    }
+
+    /// Write the JUnit and TAP reports and close the JSON Lines stream with a summary.
+    pub(super) fn finish(&self, cases: &[TestCaseResult]) -> Result<()> {
+        for request in &self.requests {
+            let rendered = match request.format {
+                TestReportFormat::Junit => render_junit(cases),
+                TestReportFormat::Tap => render_tap(cases),
+                TestReportFormat::JsonLines => continue,
+            };
+            let mut out = open_writer(request.path.as_deref())?;
+            out.write_all(rendered.as_bytes())?;
+            out.flush()?;
+        }
+        if let Some(stream) = &self.stream {
+            write_line(stream, &summary_event(cases));
+        }
+        Ok(())
+    }
+}
+
+fn open_writer(path: Option<&Path>) -> Result<Box<dyn Write + Send>> {
+    let Some(path) = path else {
+        return Ok(Box::new(io::stdout()));
+    };
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)?;
+    }
+    Ok(Box::new(io::BufWriter::new(fs::File::create(path)?)))
+}
+
+fn write_line(stream: &SharedWriter, event: &Value) {
+    let mut out = stream
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let _ = writeln!(out, "{event}");
+    let _ = out.flush();
+}
+
+/// How a case ended, separating watchdog aborts from ordinary failures.
+fn outcome(case: &TestCaseResult) -> &'static str {
+    match case.status {
+        TestStatus::Passed => "passed",
+        TestStatus::Skipped => "skipped",
+        TestStatus::Failed if case.timed_out => "timed_out",
+        TestStatus::Failed => "failed",
+    }
+}
+
+fn millis(duration: Option<Duration>) -> Option<f64> {
+    duration.map(|elapsed| elapsed.as_secs_f64() * 1000.0)
+}
+
+fn started_event(meta: &TestCaseMetadata) -> Value {
+    json!({
+        "event": "started",
+        "id": meta.id,
+        "name": meta.name,
+        "qualified_name": meta.qualified_name,
+        "namespace": meta.namespace,
+        "categories": meta.categories,
+        "async": meta.is_async,
+    })
+}
+
+fn finished_event(case: &TestCaseResult) -> Value {
+    json!({
+        "event": "finished",
+        "id": case.id,
+        "name": case.name,
+        "qualified_name": case.qualified_name,
+        "namespace": case.namespace,
+        "categories": case.categories,
+        "async": case.is_async,
+        "outcome": outcome(case),
+        "message": case.message,
+        "duration_ms": millis(case.duration),
+    })
+}
+
+fn summary_event(cases: &[TestCaseResult]) -> Value {
+    let count = |name: &str| cases.iter().filter(|case| outcome(case) == name).count();
+    json!({
+        "event": "summary",
+        "total": cases.len(),
+        "passed": count("passed"),
+        "failed": count("failed"),
+        "timed_out": count("timed_out"),
+        "skipped": count("skipped"),
+        "duration_ms": millis(total_duration(cases)),
+    })
+}
+
+fn total_duration(cases: &[TestCaseResult]) -> Option<Duration> {
+    cases
+        .iter()
+        .filter_map(|case| case.duration)
+        .reduce(|a, b| a + b)
+}
+
+fn seconds(duration: Option<Duration>) -> String {
+    format!("{:.3}", duration.unwrap_or_default().as_secs_f64())
+}
+
+/// One `<testsuite>` per namespace, in order of first appearance. Failures become
+/// `<failure>`, watchdog aborts `<error type="timeout">`.
+fn render_junit(cases: &[TestCaseResult]) -> String {
+    let mut suites: Vec<(&str, Vec<&TestCaseResult>)> = Vec::new();
+    for case in cases {
+        let name = case.namespace.as_deref().unwrap_or("chic");
+        match suites.iter_mut().find(|(suite, _)| *suite == name) {
+            Some((_, members)) => members.push(case),
+            None => suites.push((name, vec![case])),
+        }
+    }
+
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        out,
+        "<testsuites name=\"chic\" {}>",
+        junit_counts(cases.iter())
+    );
+    for (name, members) in suites {
+        let _ = writeln!(
+            out,
+            "  <testsuite name=\"{}\" {}>",
+            xml_escape(name),
+            junit_counts(members.iter().copied())
+        );
+        for case in members {
+            write_junit_case(&mut out, case);
+        }
+        out.push_str("  </testsuite>\n");
+    }
+    out.push_str("</testsuites>\n");
+    out
+}
+
+fn junit_counts<'a>(cases: impl Iterator<Item = &'a TestCaseResult> + Clone) -> String {
+    let count = |name: &str| cases.clone().filter(|case| outcome(case) == name).count();
+    let time = cases
+        .clone()
+        .filter_map(|case| case.duration)
+        .reduce(|a, b| a + b);
+    format!(
+        "tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{}\"",
+        cases.clone().count(),
+        count("failed"),
+        count("timed_out"),
+        count("skipped"),
+        seconds(time)
+    )
+}
+
+fn write_junit_case(out: &mut String, case: &TestCaseResult) {
+    let classname = case
+        .qualified_name
+        .rsplit_once("::")
+        .or_else(|| case.qualified_name.rsplit_once('.'))
+        .map_or(case.qualified_name.as_str(), |(owner, _)| owner);
+    let _ = write!(
+        out,
+        "    <testcase name=\"{}\" classname=\"{}\" time=\"{}\"",
+        xml_escape(&case.name),
+        xml_escape(classname),
+        seconds(case.duration)
+    );
+    let message = xml_escape(case.message.as_deref().unwrap_or_default());
+    let detail = match outcome(case) {
+        "failed" => Some(format!(
+            "<failure type=\"failure\" message=\"{message}\">{message}</failure>"
+        )),
+        "timed_out" => Some(format!(
+            "<error type=\"timeout\" message=\"{message}\">{message}</error>"
+        )),
+        "skipped" => Some(format!("<skipped message=\"{message}\"/>")),
+        _ => None,
+    };
+    if detail.is_none() && !case.is_async && case.categories.is_empty() {
+        out.push_str("/>\n");
+        return;
+    }
+    out.push_str(">\n");
+    if case.is_async || !case.categories.is_empty() {
+        out.push_str("      <properties>\n");
+        if case.is_async {
+            out.push_str("        <property name=\"async\" value=\"true\"/>\n");
+        }
+        for category in &case.categories {
+            let _ = writeln!(
+                out,
+                "        <property name=\"category\" value=\"{}\"/>",
+                xml_escape(category)
+            );
+        }
+        out.push_str("      </properties>\n");
+    }
+    if let Some(detail) = detail {
+        let _ = writeln!(out, "      {detail}");
+    }
+    out.push_str("    </testcase>\n");
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            ch if ch.is_control() && ch != '\t' => {}
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// TAP version 13. Skips use the `# SKIP` directive; every case carries a YAML block with
+/// its outcome so timeouts and async cases can be told apart.
+fn render_tap(cases: &[TestCaseResult]) -> String {
+    let mut out = format!("TAP version 13\n1..{}\n", cases.len());
+    for (index, case) in cases.iter().enumerate() {
+        let ok = if matches!(case.status, TestStatus::Failed) {
+            "not ok"
+        } else {
+            "ok"
+        };
+        let _ = write!(
+            out,
+            "{ok} {} - {}",
+            index + 1,
+            tap_text(&case.qualified_name)
+        );
+        if matches!(case.status, TestStatus::Skipped) {
+            let _ = write!(
+                out,
+                " # SKIP {}",
+                tap_text(case.message.as_deref().unwrap_or_default())
+            );
+        }
+        out.push('\n');
+        out.push_str("  ---\n");
+        let _ = writeln!(out, "  id: {}", json!(case.id));
+        let _ = writeln!(out, "  outcome: {}", outcome(case));
+        let _ = writeln!(out, "  async: {}", case.is_async);
+        if let Some(duration) = millis(case.duration) {
+            let _ = writeln!(out, "  duration_ms: {duration:.3}");
+        }
+        if let Some(message) = &case.message {
+            let _ = writeln!(out, "  message: {}", json!(message));
+        }
+        out.push_str("  ...\n");
+    }
+    out
+}
+
+/// TAP descriptions are single-line and `#` starts a directive.
+fn tap_text(text: &str) -> String {
+    text.replace('\n', " ").replace('#', "\\#")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn case(name: &str, status: TestStatus) -> TestCaseResult {
+        TestCaseResult {
+            id: format!("t-{name}"),
+            name: name.into(),
+            qualified_name: format!("Demo::Suite::{name}"),
+            namespace: Some("Demo".into()),
+            categories: Vec::new(),
+            is_async: false,
+            status,
+            timed_out: false,
+            message: None,
+            wasm_trace: None,
+            duration: Some(Duration::from_millis(2)),
+        }
+    }
+
+    fn sample() -> Vec<TestCaseResult> {
+        let mut slow = case("Slow", TestStatus::Failed);
+        slow.timed_out = true;
+        slow.message = Some("watchdog timeout after 5ms".into());
+        let mut broken = case("Broken", TestStatus::Failed);
+        broken.message = Some("expected <1> & got 2".into());
+        let mut pending = case("Pending", TestStatus::Skipped);
+        pending.message = Some("skipped due to --fail-fast".into());
+        pending.duration = None;
+        let mut fetch = case("Fetch", TestStatus::Passed);
+        fetch.is_async = true;
+        vec![
+            case("Adds", TestStatus::Passed),
+            fetch,
+            broken,
+            slow,
+            pending,
+        ]
+    }
+
+    #[test]
+    fn junit_separates_failures_timeouts_and_skips() {
+        let xml = render_junit(&sample());
+        assert!(xml.contains(
+            "<testsuites name=\"chic\" tests=\"5\" failures=\"1\" errors=\"1\" skipped=\"1\" time=\"0.008\">"
+        ));
+        assert!(xml.contains("<testcase name=\"Adds\" classname=\"Demo::Suite\" time=\"0.002\"/>"));
+        assert!(xml.contains("<property name=\"async\" value=\"true\"/>"));
+        assert!(
+            xml.contains("<failure type=\"failure\" message=\"expected &lt;1&gt; &amp; got 2\">")
+        );
+        assert!(xml.contains("<error type=\"timeout\" message=\"watchdog timeout after 5ms\">"));
+        assert!(xml.contains("<skipped message=\"skipped due to --fail-fast\"/>"));
+    }
+
+    #[test]
+    fn tap_marks_skips_and_classifies_outcomes() {
+        let tap = render_tap(&sample());
+        let lines: Vec<&str> = tap.lines().collect();
+        assert_eq!(lines[0], "TAP version 13");
+        assert_eq!(lines[1], "1..5");
+        assert!(tap.contains("ok 1 - Demo::Suite::Adds\n"));
+        assert!(tap.contains(
+            "ok 2 - Demo::Suite::Fetch\n  ---\n  id: \"t-Fetch\"\n  outcome: passed\n  async: true\n"
+        ));
+        assert!(tap.contains("not ok 3 - Demo::Suite::Broken\n"));
+        assert!(tap.contains(
+            "not ok 4 - Demo::Suite::Slow\n  ---\n  id: \"t-Slow\"\n  outcome: timed_out\n"
+        ));
+        assert!(tap.contains("ok 5 - Demo::Suite::Pending # SKIP skipped due to --fail-fast\n"));
+    }
+
+    #[test]
+    fn json_lines_events_carry_outcome_and_summary() {
+        let cases = sample();
+        let finished = finished_event(&cases[3]);
+        assert_eq!(finished["event"], "finished");
+        assert_eq!(finished["outcome"], "timed_out");
+        assert_eq!(finished["async"], false);
+        assert_eq!(finished["duration_ms"], 2.0);
+
+        let summary = summary_event(&cases);
+        assert_eq!(summary["total"], 5);
+        assert_eq!(summary["passed"], 2);
+        assert_eq!(summary["failed"], 1);
+        assert_eq!(summary["timed_out"], 1);
+        assert_eq!(summary["skipped"], 1);
+    }
+
+    #[test]
+    fn reports_write_to_requested_paths() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let junit = dir.path().join("out/junit.xml");
+        let stream = dir.path().join("events.jsonl");
+        let reports = TestReports::open(&[
+            TestReportRequest {
+                format: TestReportFormat::Junit,
+                path: Some(junit.clone()),
+            },
+            TestReportRequest {
+                format: TestReportFormat::JsonLines,
+                path: Some(stream.clone()),
+            },
+        ])
+        .expect("open reports");
+        assert!(!reports.writes_stdout());
+
+        let cases = sample();
+        let events = reports.events().expect("jsonl sink");
+        events.emit(&TestEvent::Finished(&cases[0]));
+        reports.finish(&cases).expect("write reports");
+
+        assert!(
+            fs::read_to_string(&junit)
+                .expect("junit report")
+                .starts_with("<?xml")
+        );
+        drop(events);
+        drop(reports);
+        let lines: Vec<Value> = fs::read_to_string(&stream)
+            .expect("jsonl report")
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("json line"))
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["id"], "t-Adds");
+        assert_eq!(lines[1]["event"], "summary");
+    }
+}
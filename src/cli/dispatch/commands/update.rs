@@ -0,0 +1,59 @@
+use std::path::Path;
+
+use crate::cli::CliError;
+use crate::diagnostics::{FileCache, FormatOptions, format_diagnostics};
+use crate::error::{Error, Result};
+use crate::manifest::Manifest;
+use crate::package::{LockMode, ResolveOptions, ResolvedSource, resolve_dependencies};
+
+/// Re-resolve the named dependencies (all of them when `packages` is empty) and rewrite
+/// `manifest.lock`.
+pub(in crate::cli::dispatch) fn run_update(
+    manifest: Option<&Path>,
+    packages: Vec<String>,
+    format_options: FormatOptions,
+) -> Result<()> {
+    let start = match manifest {
+        Some(path) => path.to_path_buf(),
+        None => std::env::current_dir().map_err(Error::Io)?,
+    };
+    let manifest = Manifest::discover(&start)?.ok_or_else(|| {
+        Error::Cli(CliError::new(format!(
+            "manifest.yaml not found at {}",
+            start.display()
+        )))
+    })?;
+    let Some(manifest_path) = manifest.path().map(Path::to_path_buf) else {
+        return Err(Error::Cli(CliError::new("manifest.yaml has no path")));
+    };
+    let lockfile = manifest_path.with_file_name("manifest.lock");
+    let options =
+        ResolveOptions::from_env(Some(lockfile.clone())).with_lock_mode(LockMode::Update(packages));
+    let outcome = resolve_dependencies(&manifest, &manifest_path, &options);
+
+    if !outcome.diagnostics.is_empty() {
+        let rendered =
+            format_diagnostics(&outcome.diagnostics, &FileCache::default(), format_options);
+        eprintln!("{rendered}");
+    }
+    if outcome
+        .diagnostics
+        .iter()
+        .any(|diag| diag.severity.is_error())
+    {
+        return Err(Error::Cli(CliError::new(
+            "dependency update failed; see diagnostics above",
+        )));
+    }
+
+    for package in &outcome.packages {
+        match &package.source {
+            ResolvedSource::Git { commit, .. } => {
+                println!("update: {} {} ({commit})", package.name, package.version);
+            }
+            _ => println!("update: {} {}", package.name, package.version),
+        }
+    }
+    println!("update: wrote {}", lockfile.display());
+    Ok(())
+}
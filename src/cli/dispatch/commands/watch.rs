@@ -0,0 +1,531 @@
+//! `chic watch`: poll source modification times and re-run a command when they change.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{self, Stdio};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+use serde_json::json;
+
+use crate::cli::{Cli, Command, WatchOptions};
+use crate::diagnostics::FormatOptions;
+use crate::error::Result;
+use crate::logging::LogLevel;
+use crate::manifest::{DependencySource, Manifest};
+
+use super::super::DispatchDriver;
+use super::super::reporting::report_error;
+use super::dispatch_command;
+
+pub(in crate::cli::dispatch) fn run_watch<D: DispatchDriver>(
+    driver: &D,
+    args: Vec<String>,
+    command: Command,
+    options: &WatchOptions,
+    effective_level: LogLevel,
+    format_options: FormatOptions,
+) -> Result<()> {
+    let verb = args.first().cloned().unwrap_or_else(|| "build".into());
+    let events = WatchEvents { json: options.json };
+    let mut command = command;
+    let mut watcher = SourceWatcher::new(watch_roots(&command));
+    events.watching(&verb, watcher.roots());
+    let mut changed = Vec::new();
+    for run in 1usize.. {
+        events.started(&verb, run, &changed);
+        let start = Instant::now();
+        let result = if options.json {
+            run_child(&args, run)
+        } else {
+            dispatch_command(driver, command.clone(), effective_level, format_options).map_err(
+                |err| {
+                    report_error(&err);
+                    err.to_string()
+                },
+            )
+        };
+        events.finished(&verb, run, start.elapsed(), result.err().as_deref());
+
+        changed = watcher.wait_for_change(options.interval, options.debounce);
+        events.changed(&changed);
+        // Re-resolve the project so added files and manifest edits take effect.
+        match Cli::parse_from(std::iter::once("watch".to_string()).chain(args.iter().cloned())) {
+            Ok(Cli {
+                command: Command::Watch { command: next, .. },
+                ..
+            }) => command = *next,
+            Ok(_) => {}
+            Err(err) => eprintln!("warning: keeping the previous project settings: {err}"),
+        }
+        watcher.set_roots(watch_roots(&command));
+    }
+    Ok(())
+}
+
+struct WatchEvents {
+    json: bool,
+}
+
+impl WatchEvents {
+    fn watching(&self, command: &str, roots: &[PathBuf]) {
+        if self.json {
+            emit(json!({
+                "event": "watching",
+                "command": command,
+                "paths": display_paths(roots),
+            }));
+        } else {
+            eprintln!(
+                "[watch] watching {} path(s) for `chic {command}`",
+                roots.len()
+            );
+        }
+    }
+
+    fn started(&self, command: &str, run: usize, changed: &[PathBuf]) {
+        if self.json {
+            emit(json!({
+                "event": "started",
+                "command": command,
+                "run": run,
+                "changed": display_paths(changed),
+            }));
+        }
+    }
+
+    fn finished(&self, command: &str, run: usize, elapsed: Duration, error: Option<&str>) {
+        let elapsed_ms = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+        if self.json {
+            emit(json!({
+                "event": "finished",
+                "command": command,
+                "run": run,
+                "status": if error.is_some() { "failed" } else { "ok" },
+                "elapsed_ms": elapsed_ms,
+                "error": error,
+            }));
+        } else {
+            let status = if error.is_some() { "failed" } else { "ok" };
+            eprintln!("[watch] `chic {command}` {status} in {elapsed_ms} ms; waiting for changes");
+        }
+    }
+
+    fn changed(&self, changed: &[PathBuf]) {
+        if self.json {
+            emit(json!({
+                "event": "changed",
+                "paths": display_paths(changed),
+            }));
+        } else {
+            eprintln!("[watch] {} file(s) changed; re-running", changed.len());
+        }
+    }
+}
+
+fn emit(event: serde_json::Value) {
+    println!("{event}");
+}
+
+/// Run the watched command as a child `chic` process and forward every line it prints as
+/// an `output` event, so stdout carries nothing but JSON events.
+fn run_child(args: &[String], run: usize) -> std::result::Result<(), String> {
+    let exe = std::env::current_exe()
+        .map_err(|err| format!("cannot locate the chic executable: {err}"))?;
+    let mut child = process::Command::new(exe)
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("failed to start `chic {}`: {err}", args.join(" ")))?;
+    let (stdout, stderr) = (child.stdout.take(), child.stderr.take());
+    thread::scope(|scope| {
+        if let Some(stdout) = stdout {
+            scope.spawn(move || forward_lines(BufReader::new(stdout), "stdout", run, &mut emit));
+        }
+        if let Some(stderr) = stderr {
+            forward_lines(BufReader::new(stderr), "stderr", run, &mut emit);
+        }
+    });
+    let status = child
+        .wait()
+        .map_err(|err| format!("failed to wait for `chic {}`: {err}", args.join(" ")))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("`chic {}` exited with {status}", args.join(" ")))
+    }
+}
+
+fn forward_lines(
+    mut reader: impl BufRead,
+    stream: &str,
+    run: usize,
+    emit: &mut dyn FnMut(serde_json::Value),
+) {
+    let mut line = Vec::new();
+    while matches!(reader.read_until(b'\n', &mut line), Ok(read) if read > 0) {
+        let text = String::from_utf8_lossy(&line);
+        emit(json!({
+            "event": "output",
+            "run": run,
+            "stream": stream,
+            "line": text.trim_end_matches(['\r', '\n']),
+        }));
+        line.clear();
+    }
+}
+
+fn display_paths(paths: &[PathBuf]) -> Vec<String> {
+    paths
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect()
+}
+
+/// Files and directories whose contents decide the watched command's result: its inputs,
+/// the manifest, the manifest's source roots and, transitively, those of path dependencies.
+/// `check` takes no manifest option, so its manifest is discovered from the inputs.
+fn watch_roots(command: &Command) -> Vec<PathBuf> {
+    let (inputs, manifest) = match command {
+        Command::Check { inputs, .. } => (
+            inputs,
+            inputs
+                .iter()
+                .find_map(|input| Manifest::discover(input).ok().flatten()),
+        ),
+        Command::Build {
+            inputs, manifest, ..
+        }
+        | Command::Run {
+            inputs, manifest, ..
+        }
+        | Command::Test {
+            inputs, manifest, ..
+        } => (inputs, manifest.clone()),
+        _ => return Vec::new(),
+    };
+    let mut roots = inputs.iter().cloned().collect::<BTreeSet<_>>();
+    let mut visited = BTreeSet::new();
+    let mut pending = manifest.into_iter().collect::<Vec<_>>();
+    while let Some(manifest) = pending.pop() {
+        let Some(path) = manifest.path().map(Path::to_path_buf) else {
+            continue;
+        };
+        if !visited.insert(path.clone()) {
+            continue;
+        }
+        let dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        for source_root in manifest.derived_source_roots() {
+            roots.insert(dir.join(source_root.path));
+        }
+        roots.insert(path);
+        for dependency in manifest.dependencies() {
+            if let DependencySource::Path(dependency_dir) = &dependency.source {
+                if let Ok(Some(dependency)) = Manifest::discover(&dir.join(dependency_dir)) {
+                    pending.push(dependency);
+                }
+            }
+        }
+    }
+    roots.into_iter().collect()
+}
+
+type Snapshot = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+/// Polls modification times and sizes of the `.ch` files under a set of roots, plus any
+/// root that is itself a file.
+struct SourceWatcher {
+    roots: Vec<PathBuf>,
+    current: Snapshot,
+}
+
+impl SourceWatcher {
+    fn new(roots: Vec<PathBuf>) -> Self {
+        let current = snapshot(&roots);
+        Self { roots, current }
+    }
+
+    fn roots(&self) -> &[PathBuf] {
+        &self.roots
+    }
+
+    fn set_roots(&mut self, roots: Vec<PathBuf>) {
+        if roots != self.roots {
+            // Files the previous roots already covered keep the stamps seen before the run,
+            // so edits and removals made during it are still reported by the next poll.
+            let mut current = snapshot(&roots);
+            for (path, stamp) in &self.current {
+                if roots.iter().any(|root| path.starts_with(root)) {
+                    current.insert(path.clone(), *stamp);
+                }
+            }
+            self.roots = roots;
+            self.current = current;
+        }
+    }
+
+    /// Paths added, removed or modified since the previous poll.
+    fn poll(&mut self) -> Vec<PathBuf> {
+        let next = snapshot(&self.roots);
+        let changed = diff(&self.current, &next);
+        self.current = next;
+        changed
+    }
+
+    /// Block until a change is seen and no further change has happened for `debounce`.
+    fn wait_for_change(&mut self, interval: Duration, debounce: Duration) -> Vec<PathBuf> {
+        let mut changed = BTreeSet::new();
+        let mut last_change = None::<Instant>;
+        loop {
+            thread::sleep(interval);
+            let paths = self.poll();
+            if !paths.is_empty() {
+                changed.extend(paths);
+                last_change = Some(Instant::now());
+            } else if last_change.is_some_and(|at| at.elapsed() >= debounce) {
+                return changed.into_iter().collect();
+            }
+        }
+    }
+}
+
+fn snapshot(roots: &[PathBuf]) -> Snapshot {
+    let mut files = Snapshot::new();
+    for root in roots {
+        if root.is_dir() {
+            collect_sources(root, &mut files);
+        } else {
+            record(root, &mut files);
+        }
+    }
+    files
+}
+
+fn collect_sources(dir: &Path, files: &mut Snapshot) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let hidden = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with('.'));
+        if hidden {
+            continue;
+        }
+        if path.is_dir() {
+            collect_sources(&path, files);
+        } else if path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("ch"))
+        {
+            record(&path, files);
+        }
+    }
+}
+
+fn record(path: &Path, files: &mut Snapshot) {
+    if let Ok(metadata) = fs::metadata(path) {
+        let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+        files.insert(path.to_path_buf(), (modified, metadata.len()));
+    }
+}
+
+fn diff(previous: &Snapshot, next: &Snapshot) -> Vec<PathBuf> {
+    let removed = previous.keys().filter(|path| !next.contains_key(*path));
+    let added_or_modified = next
+        .iter()
+        .filter(|(path, stamp)| previous.get(*path) != Some(stamp))
+        .map(|(path, _)| path);
+    removed
+        .chain(added_or_modified)
+        .cloned()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn touch(path: &Path, contents: &str, modified: SystemTime) {
+        fs::write(path, contents).expect("write source");
+        fs::File::options()
+            .write(true)
+            .open(path)
+            .and_then(|file| file.set_modified(modified))
+            .expect("set mtime");
+    }
+
+    #[test]
+    fn watcher_reports_added_modified_and_removed_sources() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let src = dir.path().join("src");
+        fs::create_dir_all(src.join("nested")).expect("create src");
+        let epoch = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let main = src.join("main.ch");
+        touch(&main, "namespace App;", epoch);
+        fs::write(src.join("notes.txt"), "ignored").expect("write notes");
+
+        let mut watcher = SourceWatcher::new(vec![src.clone()]);
+        assert!(watcher.poll().is_empty());
+
+        touch(&main, "namespace App;", epoch + Duration::from_secs(5));
+        let nested = src.join("nested/util.ch");
+        touch(&nested, "namespace App.Util;", epoch);
+        fs::write(src.join("notes.txt"), "still ignored").expect("write notes");
+        assert_eq!(watcher.poll(), vec![main.clone(), nested.clone()]);
+
+        fs::remove_file(&nested).expect("remove source");
+        assert_eq!(watcher.poll(), vec![nested]);
+        assert!(watcher.poll().is_empty());
+    }
+
+    #[test]
+    fn new_roots_keep_edits_made_during_the_run() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let (src, extra) = (dir.path().join("src"), dir.path().join("extra"));
+        fs::create_dir_all(&src).expect("create src");
+        fs::create_dir_all(&extra).expect("create extra");
+        let epoch = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let (main, gone) = (src.join("main.ch"), src.join("gone.ch"));
+        touch(&main, "namespace App;", epoch);
+        touch(&gone, "namespace App;", epoch);
+        touch(&extra.join("more.ch"), "namespace More;", epoch);
+
+        let mut watcher = SourceWatcher::new(vec![src.clone()]);
+        touch(&main, "namespace App;", epoch + Duration::from_secs(5));
+        fs::remove_file(&gone).expect("remove source");
+        watcher.set_roots(vec![extra, src]);
+        assert_eq!(watcher.poll(), vec![gone, main]);
+        assert!(watcher.poll().is_empty());
+    }
+
+    #[test]
+    fn child_output_is_forwarded_as_json_events() {
+        let mut events = Vec::new();
+        forward_lines(
+            &b"Compiling App\r\nerror: \xff\nlast"[..],
+            "stderr",
+            3,
+            &mut |event| events.push(event),
+        );
+        assert_eq!(
+            events,
+            ["Compiling App", "error: \u{fffd}", "last"]
+                .map(|line| json!({"event": "output", "run": 3, "stream": "stderr", "line": line}))
+        );
+    }
+
+    fn write_app_and_lib(dir: &Path) -> (PathBuf, PathBuf) {
+        let app = dir.join("app");
+        let lib = dir.join("lib");
+        fs::create_dir_all(app.join("code")).expect("create app");
+        fs::create_dir_all(&lib).expect("create lib");
+        fs::write(
+            app.join("manifest.yaml"),
+            "package:\n  name: App\n  version: 0.1.0\nsources:\n  - path: code\ndependencies:\n  Lib:\n    path: ../lib\n",
+        )
+        .expect("write app manifest");
+        fs::write(
+            lib.join("manifest.yaml"),
+            "package:\n  name: Lib\n  version: 0.1.0\n",
+        )
+        .expect("write lib manifest");
+        (app, lib)
+    }
+
+    fn assert_project_roots(roots: &[PathBuf]) {
+        for expected in [
+            "app/manifest.yaml",
+            "app/code",
+            "lib/manifest.yaml",
+            "lib/src",
+        ] {
+            assert!(
+                roots.iter().any(|root| root.ends_with(expected)),
+                "missing {expected} in {roots:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn check_roots_follow_the_discovered_manifest() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let (app, _) = write_app_and_lib(dir.path());
+        let main = app.join("code/main.ch");
+        fs::write(&main, "namespace App;").expect("write source");
+        let command = Command::Check {
+            inputs: vec![main.clone()],
+            target: crate::Target::host(),
+            kind: crate::ChicKind::Executable,
+            const_eval_fuel: None,
+            jobs: None,
+            trace_pipeline: false,
+            trait_solver_metrics: false,
+            defines: Vec::new(),
+        };
+
+        let roots = watch_roots(&command);
+        assert!(roots.contains(&main));
+        assert_project_roots(&roots);
+    }
+
+    #[test]
+    fn watch_roots_follow_source_roots_and_path_dependencies() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let (app, _) = write_app_and_lib(dir.path());
+        let manifest = Manifest::discover(&app)
+            .expect("discover manifest")
+            .expect("app manifest");
+        let command = Command::Test {
+            inputs: Vec::new(),
+            manifest: Some(manifest),
+            workspace: None,
+            target: crate::Target::host(),
+            kind: crate::ChicKind::Executable,
+            backend: crate::codegen::Backend::Wasm,
+            cpu_isa: crate::codegen::CpuIsaConfig::default(),
+            const_eval_fuel: None,
+            jobs: None,
+            trace_pipeline: false,
+            trait_solver_metrics: false,
+            runtime_backend: crate::runtime::backend::RuntimeBackend::Chic,
+            load_stdlib: None,
+            defines: Vec::new(),
+            ffi: crate::cli::CliFfiOptions::default(),
+            profile: None,
+            test_options: crate::driver::TestOptions::default(),
+            coverage: false,
+            coverage_min: None,
+            workspace_mode: false,
+            coverage_only: false,
+            configuration: "Debug".into(),
+            artifacts_path: None,
+            no_dependencies: false,
+            no_restore: false,
+            locked: false,
+            no_incremental: false,
+            disable_build_servers: false,
+            source_root: None,
+            properties: Vec::new(),
+            verbosity: crate::driver::types::Verbosity::Normal,
+            telemetry: crate::driver::types::TelemetrySetting::Auto,
+            version_suffix: None,
+            nologo: false,
+            force: false,
+            interactive: false,
+            self_contained: None,
+            framework: None,
+            doc_enforcement: crate::manifest::MissingDocsRule::default(),
+        };
+
+        assert_project_roots(&watch_roots(&command));
+    }
+}
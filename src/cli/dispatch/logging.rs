@@ -17,6 +17,9 @@ pub(super) fn resolve_effective_level(options: &LogOptions, trace_requested: boo
 }
 
 pub(super) fn command_requests_trace(command: &Command) -> bool {
+    if let Command::Watch { command, .. } = command {
+        return command_requests_trace(command);
+    }
     matches!(
         command,
         Command::Check { trace_pipeline, .. }
@@ -207,7 +210,11 @@ fn command_name(command: &Command) -> &'static str {
         Command::Lint { .. } => "lint",
         Command::Build { .. } => "build",
         Command::Clean { .. } => "clean",
+        Command::Update { .. } => "update",
+        Command::Pack { .. } => "pack",
+        Command::Publish { .. } => "publish",
         Command::Run { .. } => "run",
+        Command::Test { test_options, .. } if test_options.bench.is_some() => "bench",
         Command::Test { .. } => "test",
         Command::Init { .. } => "init",
         Command::Doc { .. } => "doc",
@@ -221,6 +228,7 @@ fn command_name(command: &Command) -> &'static str {
         Command::ExternBind { .. } => "extern-bind",
         Command::PerfReport { .. } => "perf-report",
         Command::Seed { .. } => "seed",
+        Command::Watch { .. } => "watch",
     }
 }
 
@@ -362,7 +370,7 @@ fn command_metadata(command: &Command) -> Option<CommandLogMetadata> {
             backend,
             ..
         } => Some(CommandLogMetadata {
-            command: "test",
+            command: command_name(command),
             inputs: stringify_inputs(inputs),
             target: Some(target.triple().to_string()),
             backend: Some(backend.as_str().to_string()),
@@ -417,6 +425,42 @@ fn command_metadata(command: &Command) -> Option<CommandLogMetadata> {
             backend: None,
             kind: None,
         }),
+        Command::Update { manifest, .. } => Some(CommandLogMetadata {
+            command: "update",
+            inputs: vec![
+                manifest
+                    .as_ref()
+                    .cloned()
+                    .unwrap_or_else(|| PathBuf::from("."))
+                    .display()
+                    .to_string(),
+            ],
+            target: None,
+            backend: None,
+            kind: None,
+        }),
+        Command::Pack { project, .. } | Command::Publish { project, .. } => {
+            Some(CommandLogMetadata {
+                command: command_name(command),
+                inputs: vec![
+                    project
+                        .as_ref()
+                        .cloned()
+                        .unwrap_or_else(|| PathBuf::from("."))
+                        .display()
+                        .to_string(),
+                ],
+                target: None,
+                backend: None,
+                kind: None,
+            })
+        }
+        Command::Watch { command, .. } => {
+            command_metadata(command).map(|metadata| CommandLogMetadata {
+                command: "watch",
+                ..metadata
+            })
+        }
         Command::ShowSpec | Command::Help { .. } | Command::Version => None,
     }
 }
@@ -454,6 +498,7 @@ mod tests {
             target: Target::host(),
             kind: ChicKind::Executable,
             const_eval_fuel: None,
+            jobs: None,
             trace_pipeline: true,
             trait_solver_metrics: false,
             defines: vec![DefineFlag::new("DEBUG", None)],
@@ -1,7 +1,7 @@
 use super::DispatchDriver;
 use super::commands::{
-    FormatCommandOptions, dispatch_command, run_build, run_check, run_format, run_header,
-    run_mir_dump, run_run, run_tests,
+    FormatCommandOptions, dispatch_command, run_benchmarks, run_build, run_check, run_format,
+    run_header, run_mir_dump, run_run, run_tests,
 };
 use super::ffi::resolve_cli_ffi_options;
 use super::logging;
@@ -18,9 +18,9 @@ use crate::defines::DefineFlag;
 use crate::diagnostics::{ColorMode, Diagnostic, ErrorFormat, FileCache, FormatOptions, Span};
 use crate::driver::types::{TelemetrySetting, Verbosity};
 use crate::driver::{
-    BuildFfiOptions, BuildRequest, FormatResult, FrontendReport, MirDumpResult,
-    MirVerificationIssue, ModuleReport, RunResult, TestCaseResult, TestOptions, TestRun,
-    TestStatus,
+    BenchOptions, BenchResult, BenchStats, BuildFfiOptions, BuildRequest, FormatResult,
+    FrontendReport, MirDumpResult, MirVerificationIssue, ModuleReport, RunResult, TestCaseResult,
+    TestOptions, TestRun, TestStatus,
 };
 use crate::error::{Error, Result};
 use crate::format::{FormatConfig, FormatEnforcement, MemberSort, TypeMetadata, TypeSort};
@@ -108,6 +108,7 @@ fn sample_report() -> FrontendReport {
         eq_glue: Vec::new(),
         type_metadata: Vec::new(),
         trait_solver_metrics: TraitSolverMetrics::default(),
+        module_states: Vec::new(),
     }
 }
 
@@ -153,6 +154,7 @@ struct StubDriver {
     build_kind: ReportKind,
     run_status: ExitStatus,
     test_statuses: Vec<TestStatus>,
+    bench_means: Vec<f64>,
     mir_kind: ReportKind,
     format_changed: bool,
 }
@@ -187,6 +189,11 @@ impl StubDriver {
         self
     }
 
+    fn with_bench_means(mut self, means: Vec<f64>) -> Self {
+        self.bench_means = means;
+        self
+    }
+
     fn with_format_changed(mut self, changed: bool) -> Self {
         self.format_changed = changed;
         self
@@ -203,6 +210,7 @@ impl Default for StubDriver {
             build_kind: ReportKind::Clean,
             run_status: success_status(),
             test_statuses: vec![TestStatus::Passed],
+            bench_means: Vec::new(),
             mir_kind: ReportKind::Clean,
             format_changed: false,
         }
@@ -257,6 +265,7 @@ impl DispatchDriver for StubDriver {
             discovered: self.test_statuses.len(),
             filtered_out: 0,
             chic_coverage: None,
+            benchmarks: benchmarks_from(&self.bench_means),
         })
     }
 
@@ -342,6 +351,7 @@ fn cases_from(statuses: &[TestStatus]) -> Vec<TestCaseResult> {
             categories: Vec::new(),
             is_async: false,
             status: *status,
+            timed_out: false,
             message: None,
             wasm_trace: None,
             duration: None,
@@ -349,6 +359,22 @@ fn cases_from(statuses: &[TestStatus]) -> Vec<TestCaseResult> {
         .collect()
 }
 
+fn benchmarks_from(means: &[f64]) -> Vec<BenchResult> {
+    means
+        .iter()
+        .enumerate()
+        .map(|(index, mean)| BenchResult {
+            id: format!("b-{index}"),
+            name: format!("bench{index}"),
+            qualified_name: format!("Bench::bench{index}"),
+            namespace: Some("Bench".into()),
+            status: TestStatus::Passed,
+            message: None,
+            stats: Some(BenchStats::from_samples(&[*mean; 10], 100)),
+        })
+        .collect()
+}
+
 fn dispatch_request(inputs: Vec<PathBuf>) -> BuildRequest {
     BuildRequest {
         inputs,
@@ -381,6 +407,7 @@ fn dispatch_request(inputs: Vec<PathBuf>) -> BuildRequest {
         bin_dir: None,
         no_dependencies: false,
         no_restore: false,
+        locked: false,
         no_incremental: false,
         rebuild: false,
         incremental_validate: false,
@@ -424,6 +451,7 @@ fn command_requests_trace_follows_command_flags() {
         cpu_isa: CpuIsaConfig::baseline(),
         run_timeout: None,
         const_eval_fuel: None,
+        jobs: None,
         trace_pipeline: true,
         trait_solver_metrics: false,
         load_stdlib: Some(true),
@@ -434,6 +462,7 @@ fn command_requests_trace_follows_command_flags() {
         artifacts_path: None,
         no_dependencies: false,
         no_restore: false,
+        locked: false,
         no_incremental: false,
         disable_build_servers: false,
         source_root: None,
@@ -519,6 +548,7 @@ fn logging_covers_metadata_variants() {
             cc1_keep_temps: false,
             load_stdlib: None,
             const_eval_fuel: None,
+            jobs: None,
             trace_pipeline: false,
             trait_solver_metrics: false,
             defines: Vec::new(),
@@ -527,6 +557,7 @@ fn logging_covers_metadata_variants() {
             framework: None,
             no_dependencies: false,
             no_restore: false,
+            locked: false,
             no_incremental: false,
             disable_build_servers: false,
             source_root: None,
@@ -553,6 +584,7 @@ fn logging_covers_metadata_variants() {
             cpu_isa: CpuIsaConfig::baseline(),
             run_timeout: None,
             const_eval_fuel: None,
+            jobs: None,
             trace_pipeline: false,
             trait_solver_metrics: false,
             load_stdlib: Some(true),
@@ -563,6 +595,7 @@ fn logging_covers_metadata_variants() {
             artifacts_path: None,
             no_dependencies: false,
             no_restore: false,
+            locked: false,
             no_incremental: false,
             disable_build_servers: false,
             source_root: None,
@@ -587,6 +620,7 @@ fn logging_covers_metadata_variants() {
             runtime_backend: RuntimeBackend::Chic,
             cpu_isa: CpuIsaConfig::baseline(),
             const_eval_fuel: None,
+            jobs: None,
             trace_pipeline: false,
             trait_solver_metrics: false,
             load_stdlib: Some(true),
@@ -602,6 +636,7 @@ fn logging_covers_metadata_variants() {
             artifacts_path: None,
             no_dependencies: false,
             no_restore: false,
+            locked: false,
             no_incremental: false,
             disable_build_servers: false,
             source_root: None,
@@ -884,6 +919,123 @@ fn run_tests_surfaces_failure_and_success() {
     assert_eq!(passing.calls().run_tests, 1);
 }
 
+#[test]
+fn run_benchmarks_records_perf_json_and_gates_on_baseline() {
+    let temp = tempdir().expect("tempdir");
+    let baseline = temp.path().join("baseline.json");
+    let output = temp.path().join("out/perf.json");
+    let options = |baseline: Option<&Path>, tolerance: f64| TestOptions {
+        bench: Some(BenchOptions {
+            output: output.clone(),
+            baseline: baseline.map(Path::to_path_buf),
+            tolerance,
+            ..BenchOptions::default()
+        }),
+        ..TestOptions::default()
+    };
+    let bench = |means: Vec<f64>, options: TestOptions| {
+        run_benchmarks(
+            &StubDriver::default().with_bench_means(means),
+            dispatch_request(vec![PathBuf::from("suite.ch")]),
+            None,
+            options,
+            test_format_options(),
+        )
+    };
+
+    bench(vec![1000.0, 2000.0], options(None, 5.0)).expect("benchmarks should run");
+    let snapshot: crate::perf::PerfSnapshot =
+        serde_json::from_str(&fs::read_to_string(&output).expect("perf.json written"))
+            .expect("perf.json parses");
+    let run = snapshot.run_by_profile(Some("bench")).expect("bench run");
+    assert_eq!(run.metrics.len(), 2);
+    assert_eq!(run.metrics[0].label, "Bench::bench0");
+    assert!((run.metrics[1].cpu_us - 2.0).abs() < 1e-9);
+    fs::copy(&output, &baseline).expect("copy baseline");
+
+    let err = bench(vec![1100.0, 2000.0], options(Some(&baseline), 5.0))
+        .expect_err("10% slowdown exceeds the 5% tolerance");
+    assert!(
+        err.to_string().contains("perf regressions detected"),
+        "{err}"
+    );
+    bench(vec![1100.0, 2000.0], options(Some(&baseline), 15.0))
+        .expect("slowdown within tolerance passes");
+    let snapshot: crate::perf::PerfSnapshot =
+        serde_json::from_str(&fs::read_to_string(&output).expect("perf.json rewritten"))
+            .expect("perf.json parses");
+    assert_eq!(
+        snapshot.runs.len(),
+        1,
+        "bench run is replaced, not appended"
+    );
+}
+
+#[test]
+fn run_benchmarks_keeps_runs_for_other_targets() {
+    let temp = tempdir().expect("tempdir");
+    let output = temp.path().join("perf.json");
+    let bench = |target: &str, means: Vec<f64>| {
+        let mut request = dispatch_request(vec![PathBuf::from("suite.ch")]);
+        request.target = Target::parse(target).expect("target");
+        run_benchmarks(
+            &StubDriver::default().with_bench_means(means),
+            request,
+            None,
+            TestOptions {
+                bench: Some(BenchOptions {
+                    output: output.clone(),
+                    ..BenchOptions::default()
+                }),
+                ..TestOptions::default()
+            },
+            test_format_options(),
+        )
+    };
+
+    bench("x86_64-unknown-linux-gnu", vec![1000.0]).expect("first target runs");
+    bench("aarch64-apple-darwin", vec![3000.0]).expect("second target runs");
+    bench("x86_64-unknown-linux-gnu", vec![2000.0]).expect("first target reruns");
+    let snapshot: crate::perf::PerfSnapshot =
+        serde_json::from_str(&fs::read_to_string(&output).expect("perf.json written"))
+            .expect("perf.json parses");
+    assert_eq!(snapshot.runs.len(), 2, "one bench run per target");
+    let linux = snapshot
+        .run_for_target("bench", "x86_64-unknown-linux-gnu")
+        .expect("linux run");
+    assert!((linux.metrics[0].cpu_us - 2.0).abs() < 1e-9);
+    let darwin = snapshot
+        .run_for_target("bench", "aarch64-apple-darwin")
+        .expect("darwin run");
+    assert!((darwin.metrics[0].cpu_us - 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn run_benchmarks_refuses_to_overwrite_unreadable_perf_json() {
+    let temp = tempdir().expect("tempdir");
+    let output = temp.path().join("perf.json");
+    fs::write(&output, "{ not json").expect("write corrupt perf.json");
+    let err = run_benchmarks(
+        &StubDriver::default().with_bench_means(vec![1000.0]),
+        dispatch_request(vec![PathBuf::from("suite.ch")]),
+        None,
+        TestOptions {
+            bench: Some(BenchOptions {
+                output: output.clone(),
+                ..BenchOptions::default()
+            }),
+            ..TestOptions::default()
+        },
+        test_format_options(),
+    )
+    .expect_err("corrupt perf.json should stop the run");
+    assert!(err.to_string().contains("failed to decode"), "{err}");
+    assert_eq!(
+        fs::read_to_string(&output).expect("perf.json kept"),
+        "{ not json"
+    );
+}
+
 #[test]
 fn run_mir_dump_reports_diagnostics() {
     with_env_lock(|| {
@@ -1193,6 +1345,7 @@ fn run_covers_metadata_logging_paths() {
             target: Target::host(),
             kind: ChicKind::Executable,
             const_eval_fuel: Some(5),
+            jobs: None,
             trace_pipeline: true,
             trait_solver_metrics: true,
             defines: Vec::new(),
@@ -1235,6 +1388,7 @@ fn log_run_complete_reports_error_paths() {
             cc1_keep_temps: false,
             load_stdlib: None,
             const_eval_fuel: None,
+            jobs: None,
             trace_pipeline: false,
             trait_solver_metrics: false,
             defines: Vec::new(),
@@ -1243,6 +1397,7 @@ fn log_run_complete_reports_error_paths() {
             framework: None,
             no_dependencies: false,
             no_restore: false,
+            locked: false,
             no_incremental: false,
             disable_build_servers: false,
             source_root: None,
@@ -1389,6 +1544,7 @@ fn dispatch_command_routes_build_run_and_test_commands() {
             cc1_keep_temps: false,
             load_stdlib: None,
             const_eval_fuel: Some(3),
+            jobs: None,
             trace_pipeline: true,
             trait_solver_metrics: true,
             defines: Vec::new(),
@@ -1397,6 +1553,7 @@ fn dispatch_command_routes_build_run_and_test_commands() {
             framework: None,
             no_dependencies: false,
             no_restore: false,
+            locked: false,
             no_incremental: false,
             disable_build_servers: false,
             source_root: None,
@@ -1429,6 +1586,7 @@ fn dispatch_command_routes_build_run_and_test_commands() {
             cpu_isa: CpuIsaConfig::baseline(),
             run_timeout: None,
             const_eval_fuel: None,
+            jobs: None,
             trace_pipeline: true,
             trait_solver_metrics: true,
             load_stdlib: Some(true),
@@ -1439,6 +1597,7 @@ fn dispatch_command_routes_build_run_and_test_commands() {
             artifacts_path: None,
             no_dependencies: false,
             no_restore: false,
+            locked: false,
             no_incremental: false,
             disable_build_servers: false,
             source_root: None,
@@ -1469,6 +1628,7 @@ fn dispatch_command_routes_build_run_and_test_commands() {
             runtime_backend: RuntimeBackend::Chic,
             cpu_isa: CpuIsaConfig::baseline(),
             const_eval_fuel: None,
+            jobs: None,
             trace_pipeline: true,
             trait_solver_metrics: true,
             load_stdlib: Some(true),
@@ -1484,6 +1644,7 @@ fn dispatch_command_routes_build_run_and_test_commands() {
             artifacts_path: None,
             no_dependencies: false,
             no_restore: false,
+            locked: false,
             no_incremental: false,
             disable_build_servers: false,
             source_root: None,
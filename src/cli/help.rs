@@ -110,6 +110,10 @@ const COMMAND_GUIDES: &[CommandGuide] = &[
                 flag: "--consteval-fuel <n>",
                 description: "Override the const-eval fuel limit for this invocation.",
             },
+            OptionGuide {
+                flag: "-j, --jobs <n>",
+                description: "Use up to <n> compiler threads (defaults to the number of CPUs).",
+            },
             OptionGuide {
                 flag: "--ffi-search <path>",
                 description: "Add <path> to the runtime dynamic library search list (repeatable).",
@@ -166,6 +170,10 @@ const COMMAND_GUIDES: &[CommandGuide] = &[
                 flag: "--consteval-fuel <n>",
                 description: "Override the const-eval fuel limit for this invocation.",
             },
+            OptionGuide {
+                flag: "-j, --jobs <n>",
+                description: "Use up to <n> compiler threads (defaults to the number of CPUs).",
+            },
             OptionGuide {
                 flag: "--log-format <format>",
                 description: "Select log output format (auto, text, json).",
@@ -194,13 +202,9 @@ const COMMAND_GUIDES: &[CommandGuide] = &[
         docs: &["docs/cli/linting.md", "SPEC.md"],
     },
     CommandGuide {
-        names: &["build", "publish", "pack"],
+        names: &["build"],
         summary: "Compile a Chic project (manifest.yaml) using the selected backend.",
-        usage: &[
-            "chic build [project|directory|file] [options]",
-            "chic publish [project|directory|file] [options]",
-            "chic pack [project|directory|file] [options]",
-        ],
+        usage: &["chic build [project|directory|file] [options]"],
         options: &[
             OptionGuide {
                 flag: "-o, --output <path>",
@@ -290,6 +294,10 @@ const COMMAND_GUIDES: &[CommandGuide] = &[
                 flag: "--no-restore",
                 description: "Skip restoring/fetching dependencies (use existing cache only).",
             },
+            OptionGuide {
+                flag: "--locked",
+                description: "Fail if dependency resolution would change manifest.lock.",
+            },
             OptionGuide {
                 flag: "--no-incremental",
                 description: "Force a clean build; disable incremental caches.",
@@ -314,6 +322,10 @@ const COMMAND_GUIDES: &[CommandGuide] = &[
                 flag: "--consteval-fuel <n>",
                 description: "Override the const-eval fuel limit for this invocation.",
             },
+            OptionGuide {
+                flag: "-j, --jobs <n>",
+                description: "Use up to <n> compiler threads (defaults to the number of CPUs).",
+            },
             OptionGuide {
                 flag: "-p, --property:<name>=<value>",
                 description: "Override manifest/build properties (repeatable).",
@@ -419,6 +431,58 @@ const COMMAND_GUIDES: &[CommandGuide] = &[
         ],
         docs: &["docs/cli/README.md", "docs/manifest_manifest.md"],
     },
+    CommandGuide {
+        names: &["update"],
+        summary: "Re-resolve dependencies and rewrite manifest.lock.",
+        usage: &["chic update [package...] [--manifest <path>]"],
+        options: &[
+            OptionGuide {
+                flag: "<package>",
+                description: "Refresh only the named dependencies; others stay pinned (default: all).",
+            },
+            OptionGuide {
+                flag: "--manifest <path>",
+                description: "manifest.yaml or project directory to update (defaults to the current directory).",
+            },
+        ],
+        examples: &[
+            "chic update",
+            "chic update Json",
+            "chic update --manifest path/to/project",
+        ],
+        docs: &["SPEC.md", "docs/manifest_manifest.md"],
+    },
+    CommandGuide {
+        names: &["pack"],
+        summary: "Pack a project's manifest and sources into a registry archive.",
+        usage: &["chic pack [project|directory|manifest] [-o <path>]"],
+        options: &[OptionGuide {
+            flag: "-o, --output <path>",
+            description: "Write the archive to <path> (default: <name>-<version>.tar).",
+        }],
+        examples: &["chic pack", "chic pack path/to/project -o dist/Json.tar"],
+        docs: &["SPEC.md", "docs/manifest_manifest.md"],
+    },
+    CommandGuide {
+        names: &["publish"],
+        summary: "Pack a project and publish it to a package registry.",
+        usage: &["chic publish [project|directory|manifest] [--registry <dir|url>] [--dry-run]"],
+        options: &[
+            OptionGuide {
+                flag: "--registry <dir|url>",
+                description: "Registry directory or http:// URL (defaults to CHIC_REGISTRY).",
+            },
+            OptionGuide {
+                flag: "--dry-run",
+                description: "Pack and report the checksum without publishing.",
+            },
+        ],
+        examples: &[
+            "chic publish --registry ../registry",
+            "chic publish --registry http://127.0.0.1:8620",
+        ],
+        docs: &["SPEC.md", "docs/manifest_manifest.md"],
+    },
     CommandGuide {
         names: &["doc", "docs"],
         summary: "Generate Markdown documentation from XML doc comments.",
@@ -553,6 +617,10 @@ const COMMAND_GUIDES: &[CommandGuide] = &[
                 flag: "--no-restore",
                 description: "Avoid downloading/restoring dependencies; use existing cache only.",
             },
+            OptionGuide {
+                flag: "--locked",
+                description: "Fail if dependency resolution would change manifest.lock.",
+            },
             OptionGuide {
                 flag: "--no-incremental",
                 description: "Force a clean rebuild of the project before executing.",
@@ -601,6 +669,10 @@ const COMMAND_GUIDES: &[CommandGuide] = &[
                 flag: "--consteval-fuel <n>",
                 description: "Override the const-eval fuel limit for this invocation.",
             },
+            OptionGuide {
+                flag: "-j, --jobs <n>",
+                description: "Use up to <n> compiler threads (defaults to the number of CPUs).",
+            },
             OptionGuide {
                 flag: "--run-timeout <ms>",
                 description: "Set a wall-clock timeout for the executed program (0 disables).",
@@ -709,6 +781,10 @@ const COMMAND_GUIDES: &[CommandGuide] = &[
                 flag: "--consteval-fuel <n>",
                 description: "Override the const-eval fuel limit for this invocation.",
             },
+            OptionGuide {
+                flag: "-j, --jobs <n>",
+                description: "Use up to <n> compiler threads (defaults to the number of CPUs).",
+            },
             OptionGuide {
                 flag: "--source <path>",
                 description: "Override the source root when testing a manifest-based project.",
@@ -741,6 +817,10 @@ const COMMAND_GUIDES: &[CommandGuide] = &[
                 flag: "--no-restore",
                 description: "Avoid downloading/restoring dependencies; use existing cache only.",
             },
+            OptionGuide {
+                flag: "--locked",
+                description: "Fail if dependency resolution would change manifest.lock.",
+            },
             OptionGuide {
                 flag: "--no-incremental",
                 description: "Force a clean rebuild of the project before testing.",
@@ -805,6 +885,18 @@ const COMMAND_GUIDES: &[CommandGuide] = &[
                 flag: "--fail-fast",
                 description: "Stop scheduling after the first observed failure.",
             },
+            OptionGuide {
+                flag: "--test-seed <seed>",
+                description: "Seed proptestcase/fuzzcase generation (hex or decimal).",
+            },
+            OptionGuide {
+                flag: "--test-runs <n>",
+                description: "Override generated cases per proptestcase/fuzzcase.",
+            },
+            OptionGuide {
+                flag: "--update-snapshots",
+                description: "Rewrite mismatched or missing .snap files instead of failing.",
+            },
             OptionGuide {
                 flag: "--watchdog <steps>",
                 description: "Set the test watchdog step limit (0 disables).",
@@ -813,6 +905,10 @@ const COMMAND_GUIDES: &[CommandGuide] = &[
                 flag: "--watchdog-timeout <ms>",
                 description: "Set a wall-clock watchdog timeout per test.",
             },
+            OptionGuide {
+                flag: "--report <fmt>[=<path>]",
+                description: "Write a junit, tap or jsonl report to <path> or stdout (repeatable).",
+            },
         ],
         examples: &[
             "chic test                              # tests the project in the current directory",
@@ -844,6 +940,77 @@ const COMMAND_GUIDES: &[CommandGuide] = &[
         examples: &["chic coverage", "chic coverage --coverage-min 90"],
         docs: &["docs/coverage.md"],
     },
+    CommandGuide {
+        names: &["bench"],
+        summary: "Time benchcases and compare them against a perf.json baseline.",
+        usage: &["chic bench [project|directory|file] [options]"],
+        options: &[
+            OptionGuide {
+                flag: "--test <pattern>",
+                description: "Run a single benchcase or a wildcard match (repeatable).",
+            },
+            OptionGuide {
+                flag: "--test-group <pattern>",
+                description: "Filter by category/tag or namespace prefix; accepts wildcards.",
+            },
+            OptionGuide {
+                flag: "--warmup-ms <ms>",
+                description: "Warm-up budget per benchcase before sampling (default: 300).",
+            },
+            OptionGuide {
+                flag: "--measure-ms <ms>",
+                description: "Measurement budget per benchcase (default: 2000).",
+            },
+            OptionGuide {
+                flag: "--samples <n>",
+                description: "Maximum samples per benchcase (default: 100).",
+            },
+            OptionGuide {
+                flag: "--bench-out <path>",
+                description: "perf.json file to record results in (default: perf.json).",
+            },
+            OptionGuide {
+                flag: "--baseline <path>",
+                description: "Fail if a mean regresses past --tolerance against this perf.json.",
+            },
+            OptionGuide {
+                flag: "--tolerance <percent>",
+                description: "Allowed slowdown versus the baseline (default: 5).",
+            },
+        ],
+        examples: &[
+            "chic bench",
+            "chic bench suite.ch --test Parse* --measure-ms 5000",
+            "chic bench --bench-out perf.json --baseline baseline/perf.json --tolerance 10",
+        ],
+        docs: &["docs/cli/testing.md", "docs/tooling/perf_json.md"],
+    },
+    CommandGuide {
+        names: &["watch"],
+        summary: "Re-run build, check, test or run whenever project sources change.",
+        usage: &["chic watch [build|check|test|run] [project|directory|file] [options]"],
+        options: &[
+            OptionGuide {
+                flag: "--interval <ms>",
+                description: "Polling interval for source modification times (default: 250).",
+            },
+            OptionGuide {
+                flag: "--debounce <ms>",
+                description: "Wait until sources are unchanged for <ms> before re-running (default: 200).",
+            },
+            OptionGuide {
+                flag: "--json",
+                description: "Emit watch events and the command's output as JSON lines on stdout.",
+            },
+        ],
+        examples: &[
+            "chic watch                             # rebuilds the project in the current directory",
+            "chic watch test --test Parser",
+            "chic watch run examples/hello.ch --backend wasm",
+            "chic watch check src/main.ch --json",
+        ],
+        docs: &["docs/cli/watch.md", "docs/cli/README.md"],
+    },
     CommandGuide {
         names: &["format"],
         summary: "Normalise Chic source formatting in-place.",
@@ -127,6 +127,27 @@ impl Default for CliFfiOptions {
     }
 }
 
+/// Polling settings for `chic watch`.
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+    /// How often source modification times are polled.
+    pub interval: Duration,
+    /// How long sources must stay unchanged before the command re-runs.
+    pub debounce: Duration,
+    /// Emit one JSON event per line on stdout, wrapping the watched command's output.
+    pub json: bool,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_millis(250),
+            debounce: Duration::from_millis(200),
+            json: false,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ProfileOptions {
     pub output: PathBuf,
@@ -158,6 +179,7 @@ pub enum Command {
         target: Target,
         kind: ChicKind,
         const_eval_fuel: Option<usize>,
+        jobs: Option<usize>,
         trace_pipeline: bool,
         trait_solver_metrics: bool,
         defines: Vec<DefineFlag>,
@@ -167,6 +189,7 @@ pub enum Command {
         target: Target,
         kind: ChicKind,
         const_eval_fuel: Option<usize>,
+        jobs: Option<usize>,
         trace_pipeline: bool,
         trait_solver_metrics: bool,
         defines: Vec<DefineFlag>,
@@ -189,6 +212,7 @@ pub enum Command {
         cc1_keep_temps: bool,
         load_stdlib: Option<bool>,
         const_eval_fuel: Option<usize>,
+        jobs: Option<usize>,
         trace_pipeline: bool,
         trait_solver_metrics: bool,
         defines: Vec<DefineFlag>,
@@ -198,6 +222,7 @@ pub enum Command {
         artifacts_path: Option<PathBuf>,
         no_dependencies: bool,
         no_restore: bool,
+        locked: bool,
         no_incremental: bool,
         disable_build_servers: bool,
         source_root: Option<PathBuf>,
@@ -223,6 +248,7 @@ pub enum Command {
         cpu_isa: CpuIsaConfig,
         run_timeout: Option<Duration>,
         const_eval_fuel: Option<usize>,
+        jobs: Option<usize>,
         trace_pipeline: bool,
         trait_solver_metrics: bool,
         runtime_backend: crate::runtime::backend::RuntimeBackend,
@@ -234,6 +260,7 @@ pub enum Command {
         artifacts_path: Option<PathBuf>,
         no_dependencies: bool,
         no_restore: bool,
+        locked: bool,
         no_incremental: bool,
         disable_build_servers: bool,
         source_root: Option<PathBuf>,
@@ -257,6 +284,7 @@ pub enum Command {
         backend: Backend,
         cpu_isa: CpuIsaConfig,
         const_eval_fuel: Option<usize>,
+        jobs: Option<usize>,
         trace_pipeline: bool,
         trait_solver_metrics: bool,
         runtime_backend: crate::runtime::backend::RuntimeBackend,
@@ -273,6 +301,7 @@ pub enum Command {
         artifacts_path: Option<PathBuf>,
         no_dependencies: bool,
         no_restore: bool,
+        locked: bool,
         no_incremental: bool,
         disable_build_servers: bool,
         source_root: Option<PathBuf>,
@@ -363,6 +392,27 @@ pub enum Command {
         all: bool,
         dry_run: bool,
     },
+    Update {
+        manifest: Option<PathBuf>,
+        packages: Vec<String>,
+    },
+    Pack {
+        project: Option<PathBuf>,
+        output: Option<PathBuf>,
+    },
+    Publish {
+        project: Option<PathBuf>,
+        registry: Option<String>,
+        dry_run: bool,
+    },
+    /// Re-run `command` (build, check, test or run) whenever its sources change. `args`
+    /// is the command line it was parsed from, re-parsed before every run so new files and
+    /// manifest edits are picked up.
+    Watch {
+        args: Vec<String>,
+        command: Box<Command>,
+        options: WatchOptions,
+    },
 }
 
 /// Parsed CLI invocation.
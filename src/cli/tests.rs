@@ -699,6 +699,244 @@ fn parses_chic_runtime_flag_for_build() {
     });
 }
 
+#[test]
+fn parses_jobs_flag_for_check_and_build() {
+    let cli = expect_cli_ok(["check", "main.ch", "-j", "3"]);
+    match cli.command {
+        Command::Check { jobs, .. } => assert_eq!(jobs, Some(3)),
+        other => panic!("expected check command, found {other:?}"),
+    }
+    with_locked_env(|| {
+        let cli = expect_cli_ok(["build", "main.ch", "--jobs", "1"]);
+        match cli.command {
+            Command::Build { jobs, .. } => assert_eq!(jobs, Some(1)),
+            other => panic!("expected build command, found {other:?}"),
+        }
+    });
+}
+
+#[test]
+fn rejects_zero_jobs() {
+    let err = expect_cli_err(["check", "main.ch", "--jobs", "0"]);
+    assert!(
+        err.to_string()
+            .contains("-j/--jobs must be greater than zero"),
+        "unexpected message: {err}"
+    );
+}
+
+#[test]
+fn parses_test_reports() {
+    with_locked_env(|| {
+        let cli = expect_cli_ok([
+            "test",
+            "main.ch",
+            "--report",
+            "junit=obj/junit.xml",
+            "--report",
+            "jsonl",
+        ]);
+        match cli.command {
+            Command::Test { test_options, .. } => assert_eq!(
+                test_options.reports,
+                [
+                    crate::driver::TestReportRequest {
+                        format: crate::driver::TestReportFormat::Junit,
+                        path: Some(PathBuf::from("obj/junit.xml")),
+                    },
+                    crate::driver::TestReportRequest {
+                        format: crate::driver::TestReportFormat::JsonLines,
+                        path: None,
+                    },
+                ]
+            ),
+            other => panic!("expected test command, found {other:?}"),
+        }
+
+        let err = expect_cli_err(["test", "main.ch", "--report", "tap", "--report", "jsonl"]);
+        assert!(
+            err.to_string()
+                .contains("only one --report can write to stdout"),
+            "unexpected message: {err}"
+        );
+        let err = expect_cli_err(["test", "main.ch", "--report", "html"]);
+        assert!(
+            err.to_string()
+                .contains("unsupported --report format 'html'"),
+            "unexpected message: {err}"
+        );
+        let err = expect_cli_err(["build", "main.ch", "--report", "tap"]);
+        assert!(
+            err.to_string()
+                .contains("--report is only supported for chic test/coverage"),
+            "unexpected message: {err}"
+        );
+    });
+}
+
+#[test]
+fn parses_test_seed_and_runs() {
+    with_locked_env(|| {
+        let cli = expect_cli_ok([
+            "test",
+            "main.ch",
+            "--test-seed",
+            "0x00ff_0000",
+            "--test-runs",
+            "25",
+        ]);
+        match cli.command {
+            Command::Test { test_options, .. } => {
+                assert_eq!(test_options.generation.seed, Some(0x00ff_0000));
+                assert_eq!(test_options.generation.runs, Some(25));
+            }
+            other => panic!("expected test command, found {other:?}"),
+        }
+
+        let err = expect_cli_err(["test", "main.ch", "--test-seed", "0xzz"]);
+        assert!(
+            err.to_string()
+                .contains("--test-seed expects a 128-bit seed"),
+            "unexpected message: {err}"
+        );
+        let err = expect_cli_err(["test", "main.ch", "--test-runs", "0"]);
+        assert!(
+            err.to_string()
+                .contains("--test-runs expects a positive integer"),
+            "unexpected message: {err}"
+        );
+    });
+}
+
+#[test]
+fn parses_update_snapshots_flag() {
+    with_locked_env(|| {
+        let cli = expect_cli_ok(["test", "main.ch", "--update-snapshots"]);
+        match cli.command {
+            Command::Test { test_options, .. } => assert!(test_options.update_snapshots),
+            other => panic!("expected test command, found {other:?}"),
+        }
+
+        let cli = expect_cli_ok(["test", "main.ch"]);
+        match cli.command {
+            Command::Test { test_options, .. } => assert!(!test_options.update_snapshots),
+            other => panic!("expected test command, found {other:?}"),
+        }
+
+        let err = expect_cli_err(["build", "main.ch", "--update-snapshots"]);
+        assert!(
+            err.to_string().contains("--update-snapshots"),
+            "unexpected message: {err}"
+        );
+    });
+}
+
+#[test]
+fn parses_bench_options_and_defaults_to_wasm() {
+    with_locked_env(|| {
+        let cli = expect_cli_ok([
+            "bench",
+            "main.ch",
+            "--test",
+            "Parse*",
+            "--warmup-ms",
+            "10",
+            "--measure-ms",
+            "500",
+            "--samples",
+            "20",
+            "--baseline",
+            "base/perf.json",
+            "--tolerance",
+            "12.5",
+        ]);
+        match cli.command {
+            Command::Test {
+                backend,
+                test_options,
+                ..
+            } => {
+                assert_eq!(backend, Backend::Wasm);
+                assert_eq!(test_options.selection.tests, ["Parse*"]);
+                let bench = test_options.bench.expect("bench options");
+                assert_eq!(bench.warmup, Duration::from_millis(10));
+                assert_eq!(bench.measurement, Duration::from_millis(500));
+                assert_eq!(bench.max_samples, 20);
+                assert_eq!(bench.output, PathBuf::from("perf.json"));
+                assert_eq!(bench.baseline, Some(PathBuf::from("base/perf.json")));
+                assert_eq!(bench.tolerance, 12.5);
+            }
+            other => panic!("expected bench to parse as a test command, found {other:?}"),
+        }
+
+        let cli = expect_cli_ok(["test", "main.ch"]);
+        match cli.command {
+            Command::Test { test_options, .. } => assert!(test_options.bench.is_none()),
+            other => panic!("expected test command, found {other:?}"),
+        }
+
+        let err = expect_cli_err(["test", "main.ch", "--baseline", "perf.json"]);
+        assert!(
+            err.to_string()
+                .contains("--baseline is only supported for chic bench"),
+            "unexpected message: {err}"
+        );
+        let err = expect_cli_err(["bench", "main.ch", "--backend", "llvm"]);
+        assert!(
+            err.to_string()
+                .contains("chic bench currently requires the wasm backend"),
+            "unexpected message: {err}"
+        );
+        let err = expect_cli_err(["bench", "main.ch", "--fail-fast"]);
+        assert!(
+            err.to_string()
+                .contains("only supported for chic test/coverage"),
+            "unexpected message: {err}"
+        );
+    });
+}
+
+#[test]
+fn parses_watch_with_inner_command_and_options() {
+    let cli = expect_cli_ok([
+        "watch",
+        "check",
+        "main.ch",
+        "--json",
+        "--interval",
+        "100",
+        "-j",
+        "2",
+    ]);
+    match cli.command {
+        Command::Watch {
+            args,
+            command,
+            options,
+        } => {
+            assert_eq!(args, ["check", "main.ch", "-j", "2"]);
+            assert!(options.json);
+            assert_eq!(options.interval, std::time::Duration::from_millis(100));
+            assert_eq!(options.debounce, WatchOptions::default().debounce);
+            match *command {
+                Command::Check { inputs, jobs, .. } => {
+                    assert_eq!(inputs, [PathBuf::from("main.ch")]);
+                    assert_eq!(jobs, Some(2));
+                }
+                other => panic!("expected check command, found {other:?}"),
+            }
+        }
+        other => panic!("expected watch command, found {other:?}"),
+    }
+
+    let err = expect_cli_err(["watch", "check", "main.ch", "--debounce", "0"]);
+    assert!(
+        err.to_string()
+            .contains("--debounce expects a positive number of milliseconds"),
+        "unexpected message: {err}"
+    );
+}
+
 #[test]
 fn rejects_rust_runtime_flag() {
     with_locked_env(|| {
@@ -1411,3 +1649,67 @@ fn parse_seed_accepts_profile_and_json() {
         other => panic!("expected seed command with options, found {other:?}"),
     }
 }
+
+#[test]
+fn parses_locked_flag_for_build() {
+    with_locked_env(|| {
+        let cli = expect_cli_ok(["build", "main.ch", "--locked"]);
+        match cli.command {
+            Command::Build { locked, .. } => assert!(locked),
+            other => panic!("expected build command, found {other:?}"),
+        }
+    });
+}
+
+#[test]
+fn parse_update_collects_packages_and_manifest() {
+    let cli = expect_cli_ok([
+        "update",
+        "Json",
+        "--manifest",
+        "app/manifest.yaml",
+        "Json",
+        "Http",
+    ]);
+    match cli.command {
+        Command::Update { manifest, packages } => {
+            assert_eq!(manifest, Some(PathBuf::from("app/manifest.yaml")));
+            assert_eq!(packages, vec![String::from("Json"), String::from("Http")]);
+        }
+        other => panic!("expected update command, found {other:?}"),
+    }
+    let err = expect_cli_err(["update", "--offline"]);
+    assert!(err.to_string().contains("unsupported option"), "{err}");
+}
+
+#[test]
+fn parse_pack_and_publish_commands() {
+    let cli = expect_cli_ok(["pack", "app", "-o", "dist/app.tar"]);
+    match cli.command {
+        Command::Pack { project, output } => {
+            assert_eq!(project, Some(PathBuf::from("app")));
+            assert_eq!(output, Some(PathBuf::from("dist/app.tar")));
+        }
+        other => panic!("expected pack command, found {other:?}"),
+    }
+    let cli = expect_cli_ok([
+        "publish",
+        "--registry",
+        "http://127.0.0.1:8620",
+        "--dry-run",
+    ]);
+    match cli.command {
+        Command::Publish {
+            project,
+            registry,
+            dry_run,
+        } => {
+            assert_eq!(project, None);
+            assert_eq!(registry.as_deref(), Some("http://127.0.0.1:8620"));
+            assert!(dry_run);
+        }
+        other => panic!("expected publish command, found {other:?}"),
+    }
+    let err = expect_cli_err(["publish", "--registry"]);
+    assert!(err.to_string().contains("--registry"), "{err}");
+}
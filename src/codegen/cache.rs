@@ -36,6 +36,8 @@ pub(crate) struct CacheHit {
 pub(crate) struct FingerprintInputs<'a> {
     pub source: &'a str,
     pub textual_ir: &'a str,
+    /// Replaces `source` and `textual_ir` when set: a fingerprint of the lowered MIR.
+    pub mir_fingerprint: Option<&'a str>,
     pub target: &'a Target,
     pub kind: ChicKind,
     pub backend: Backend,
@@ -62,8 +64,16 @@ struct CacheMetadata {
 /// Compute a deterministic fingerprint for the given compilation inputs.
 pub(crate) fn compute_fingerprint(inputs: &FingerprintInputs<'_>) -> CacheKey {
     let mut hasher = Hasher::new();
-    hasher.update(inputs.source.as_bytes());
-    hasher.update(inputs.textual_ir.as_bytes());
+    match inputs.mir_fingerprint {
+        Some(mir) => {
+            hasher.update(b"mir\0");
+            hasher.update(mir.as_bytes());
+        }
+        None => {
+            hasher.update(inputs.source.as_bytes());
+            hasher.update(inputs.textual_ir.as_bytes());
+        }
+    }
     hasher.update(inputs.target.triple().as_bytes());
     hasher.update(inputs.kind.as_str().as_bytes());
     hasher.update(inputs.backend.as_str().as_bytes());
@@ -74,6 +84,7 @@ pub(crate) fn compute_fingerprint(inputs: &FingerprintInputs<'_>) -> CacheKey {
     hasher.update(&[u8::from(inputs.options.coverage)]);
     hasher.update(&[u8::from(inputs.options.pgo_generate)]);
     hasher.update(&[u8::from(inputs.options.emit_wat_text)]);
+    hasher.update(&[u8::from(inputs.options.debug_info.is_some())]);
     let isa_bytes = inputs.options.cpu_isa.fingerprint_bytes();
     if !isa_bytes.is_empty() {
         hasher.update(&isa_bytes);
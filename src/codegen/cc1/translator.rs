@@ -63,6 +63,7 @@ fn is_compiler_generated_extern(name: &str) -> bool {
 }
 
 fn translate_function(func: &MirFunction) -> Result<String, Cc1Error> {
+    reject_rectangular_arrays(func)?;
     let locals: BTreeMap<usize, LocalDecl> = func
         .body
         .locals
@@ -294,6 +295,32 @@ fn map_type(ty: &Ty) -> Result<&'static str, Cc1Error> {
     }
 }
 
+/// The translator lowers no array types (see [`map_type`]), so rectangular arrays, which also
+/// need the extent header and flattened indexing from `mir::layout`, are out of scope here;
+/// report them by name instead of a generic type error.
+fn reject_rectangular_arrays(func: &MirFunction) -> Result<(), Cc1Error> {
+    let signature = &func.signature;
+    let declared = func.body.locals.iter().map(|decl| &decl.ty);
+    for ty in signature
+        .params
+        .iter()
+        .chain([&signature.ret])
+        .chain(declared)
+    {
+        if let Ty::Array(array) = ty
+            && array.rank > 1
+        {
+            return Err(error(format!(
+                "cc1 backend does not support rectangular arrays (`{}[{}]` in `{}`); use the LLVM or WASM backend",
+                array.element.canonical_name(),
+                ",".repeat(array.rank - 1),
+                func.name
+            )));
+        }
+    }
+    Ok(())
+}
+
 fn local_name(index: usize) -> String {
     format!("_{}", index)
 }
@@ -444,4 +471,55 @@ mod tests {
             err.message
         );
     }
+
+    #[test]
+    fn translator_rejects_rectangular_arrays() {
+        let grid = Ty::Array(crate::mir::ArrayTy::new(Box::new(Ty::named("int")), 2));
+        let mut body = MirBody::new(0, None);
+        body.locals.push(LocalDecl::new(
+            None,
+            Ty::named("int"),
+            false,
+            None,
+            LocalKind::Return,
+        ));
+        body.locals
+            .push(LocalDecl::new(None, grid, false, None, LocalKind::Local));
+        let mut block = BasicBlock::new(body.entry(), None);
+        block.terminator = Some(Terminator::Return);
+        body.blocks.push(block);
+        let mut module = MirModule::default();
+        module.functions.push(MirFunction {
+            name: "Example::Main".into(),
+            kind: FunctionKind::Function,
+            signature: crate::mir::FnSig {
+                params: Vec::new(),
+                ret: Ty::named("int"),
+                abi: Abi::Chic,
+                effects: Vec::new(),
+
+                lends_to_return: None,
+
+                variadic: false,
+            },
+            body,
+            is_async: false,
+            async_result: None,
+            is_generator: false,
+            span: None,
+            optimization_hints: crate::frontend::attributes::OptimizationHints::default(),
+            extern_spec: None,
+            is_weak: false,
+            is_weak_import: false,
+        });
+
+        let err = translate(&module, ChicKind::Executable).expect_err("translation should fail");
+        assert!(
+            err.message.contains(
+                "cc1 backend does not support rectangular arrays (`int[,]` in `Example::Main`)"
+            ),
+            "unexpected error message: {}",
+            err.message
+        );
+    }
 }
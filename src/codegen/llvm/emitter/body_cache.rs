@@ -0,0 +1,160 @@
+//! Function IR kept from the previous build of a module.
+//!
+//! Bodies are emitted against a [`MetadataRegistry::fork`], so their text, metadata nodes and
+//! runtime declarations are self-contained and can be replayed into a later module. A body is
+//! reused when the function's MIR and the module context its code refers to (MIR interface,
+//! target, entry point, glue symbols, ISA tiers) match the build that emitted it. Debug-info
+//! builds share metadata across functions and are never cached. Entries not used by the
+//! current build are dropped on store.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, OnceLock, PoisonError};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+use crate::fingerprint::{self, Fingerprinter};
+use crate::mir::MirFunction;
+use crate::perf::Tracepoint;
+
+use super::metadata_pool::MetadataRegistry;
+
+const CACHE_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize, Default)]
+struct CacheFile {
+    version: u32,
+    bodies: HashMap<String, CachedBody>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedBody {
+    /// IR text with fork-local metadata ids.
+    text: String,
+    /// Metadata nodes allocated while emitting the body.
+    metadata: Vec<String>,
+    externals: Vec<String>,
+    multiversion: bool,
+}
+
+/// One function's IR, emitted against a metadata fork.
+pub(crate) struct EmittedBody {
+    pub(crate) text: String,
+    pub(crate) externals: BTreeSet<&'static str>,
+    pub(crate) metadata: MetadataRegistry,
+    /// The body needs the CPU dispatch helpers.
+    pub(crate) multiversion: bool,
+}
+
+pub(crate) struct BodyCache {
+    path: PathBuf,
+    /// Fingerprint of the module context emitted code refers to.
+    context: String,
+    previous: HashMap<String, CachedBody>,
+    current: Mutex<HashMap<String, CachedBody>>,
+    hits: AtomicUsize,
+}
+
+impl BodyCache {
+    /// The cache kept beside `output`.
+    pub(crate) fn path_for(output: &Path) -> PathBuf {
+        let mut name = output.as_os_str().to_owned();
+        name.push(".bodies.json");
+        PathBuf::from(name)
+    }
+
+    pub(crate) fn load(path: PathBuf, context: String) -> Self {
+        let previous = fs::read_to_string(&path)
+            .ok()
+            .and_then(|text| serde_json::from_str::<CacheFile>(&text).ok())
+            .filter(|file| file.version == CACHE_VERSION)
+            .map(|file| file.bodies)
+            .unwrap_or_default();
+        Self {
+            path,
+            context,
+            previous,
+            current: Mutex::new(HashMap::new()),
+            hits: AtomicUsize::new(0),
+        }
+    }
+
+    pub(crate) fn key(&self, function: &MirFunction, tracepoint: Option<&Tracepoint>) -> String {
+        Fingerprinter::new()
+            .str(&self.context)
+            .str(&fingerprint::function_fingerprint(function, false))
+            .value(&tracepoint)
+            .finish()
+    }
+
+    /// The cached body for `key`, replayed into a fork of `metadata`.
+    pub(crate) fn get(&self, key: &str, metadata: &MetadataRegistry) -> Option<EmittedBody> {
+        let cached = self.previous.get(key)?;
+        let mut fork = metadata.fork()?;
+        fork.nodes.clone_from(&cached.metadata);
+        let emitted = EmittedBody {
+            text: cached.text.clone(),
+            externals: cached.externals.iter().map(|name| intern(name)).collect(),
+            metadata: fork,
+            multiversion: cached.multiversion,
+        };
+        self.current_bodies()
+            .insert(key.to_string(), cached.clone());
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Some(emitted)
+    }
+
+    pub(crate) fn insert(&self, key: String, emitted: &EmittedBody) {
+        let cached = CachedBody {
+            text: emitted.text.clone(),
+            metadata: emitted.metadata.nodes.clone(),
+            externals: emitted
+                .externals
+                .iter()
+                .map(|name| (*name).to_string())
+                .collect(),
+            multiversion: emitted.multiversion,
+        };
+        self.current_bodies().insert(key, cached);
+    }
+
+    /// Bodies reused from the previous build so far.
+    pub(crate) fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    fn current_bodies(&self) -> MutexGuard<'_, HashMap<String, CachedBody>> {
+        self.current.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub(crate) fn store(&self) -> Result<(), Error> {
+        let file = CacheFile {
+            version: CACHE_VERSION,
+            bodies: self.current_bodies().clone(),
+        };
+        let text = serde_json::to_string(&file)
+            .map_err(|err| Error::internal(format!("failed to serialise body cache: {err}")))?;
+        fs::write(&self.path, text)?;
+        Ok(())
+    }
+}
+
+/// Runtime declarations are tracked as `&'static str`; names read back from the cache are
+/// leaked once each. They come from the fixed set of runtime hooks and intrinsics, so the
+/// set stays small.
+fn intern(name: &str) -> &'static str {
+    static NAMES: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+    let mut names = NAMES
+        .get_or_init(Mutex::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if let Some(existing) = names.get(name) {
+        return existing;
+    }
+    let leaked: &'static str = Box::leak(name.to_owned().into_boxed_str());
+    names.insert(leaked);
+    leaked
+}
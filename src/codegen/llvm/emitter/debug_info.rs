@@ -0,0 +1,579 @@
+//! DWARF debug info for the textual LLVM IR.
+//!
+//! Debug nodes share the module's [`MetadataRegistry`] numbering. Line locations come from MIR
+//! spans resolved against the build's [`FileCache`]; struct, class, enum and union descriptors
+//! are derived from the MIR type layout table.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::diagnostics::{FileCache, FileId, Span};
+use crate::mir::{
+    LocalDecl, LocalKind, MirFunction, Ty, TypeLayout, TypeLayoutTable, pointer_size,
+};
+use crate::primitives::PrimitiveKind;
+
+use super::metadata_pool::{MetadataId, MetadataRegistry};
+
+/// Per-module debug info state, owned by the [`MetadataRegistry`] while it is enabled.
+pub(crate) struct DebugInfo {
+    files: Arc<FileCache>,
+    unit: MetadataId,
+    unit_file: MetadataId,
+    file_nodes: HashMap<FileId, MetadataId>,
+    /// `None` marks types with no DWARF representation (`void`, unresolved types).
+    type_nodes: HashMap<String, Option<MetadataId>>,
+    locations: HashMap<(MetadataId, usize, usize), MetadataId>,
+    subprogram_files: HashMap<MetadataId, FileId>,
+    lexical_files: HashMap<(MetadataId, FileId), MetadataId>,
+}
+
+impl MetadataRegistry {
+    /// Start emitting debug info. `primary` names the file recorded on the compile unit.
+    pub(crate) fn enable_debug_info(&mut self, files: Arc<FileCache>, primary: Option<FileId>) {
+        let file = match primary.and_then(|id| files.get(id)) {
+            Some(source) => file_node(&mut self.nodes, &source.path),
+            None => push(
+                &mut self.nodes,
+                "!DIFile(filename: \"<unknown>\", directory: \"\")".into(),
+            ),
+        };
+        let unit = push(
+            &mut self.nodes,
+            format!(
+                "distinct !DICompileUnit(language: DW_LANG_C99, file: !{}, producer: {}, isOptimized: false, runtimeVersion: 0, emissionKind: FullDebug, nameTableKind: None)",
+                file.0,
+                quote(&format!("chic {}", env!("CARGO_PKG_VERSION")))
+            ),
+        );
+        let mut file_nodes = HashMap::new();
+        if let Some(primary) = primary.filter(|id| files.get(*id).is_some()) {
+            file_nodes.insert(primary, file);
+        }
+        self.debug = Some(Box::new(DebugInfo {
+            files,
+            unit,
+            unit_file: file,
+            file_nodes,
+            type_nodes: HashMap::new(),
+            locations: HashMap::new(),
+            subprogram_files: HashMap::new(),
+            lexical_files: HashMap::new(),
+        }));
+    }
+
+    /// The compile unit to list in `!llvm.dbg.cu`, when debug info is enabled.
+    pub(crate) fn debug_compile_unit(&self) -> Option<MetadataId> {
+        self.debug.as_ref().map(|debug| debug.unit)
+    }
+
+    /// Describe `function` (emitted as `symbol`) with a `DISubprogram`.
+    pub(crate) fn debug_subprogram(
+        &mut self,
+        function: &MirFunction,
+        symbol: &str,
+        local_to_unit: bool,
+        layouts: &TypeLayoutTable,
+    ) -> Option<MetadataId> {
+        let debug = self.debug.as_deref_mut()?;
+        let nodes = &mut self.nodes;
+        let position = function
+            .span
+            .or(function.body.span)
+            .and_then(|span| debug.resolve(span));
+        let file = match position {
+            Some((file_id, _, _)) => debug.file(nodes, file_id),
+            None => debug.unit_file,
+        };
+        let line = position.map_or(0, |(_, line, _)| line);
+
+        let mut types = vec![debug.type_ref(nodes, layouts, &function.signature.ret)];
+        for param in &function.signature.params {
+            types.push(debug.type_ref(nodes, layouts, param));
+        }
+        let subroutine = push(
+            nodes,
+            format!("!DISubroutineType(types: !{{{}}})", types.join(", ")),
+        );
+        let retained = push(nodes, "!{}".into());
+        let sp_flags = if local_to_unit {
+            "DISPFlagLocalToUnit | DISPFlagDefinition"
+        } else {
+            "DISPFlagDefinition"
+        };
+        let subprogram = push(
+            nodes,
+            format!(
+                "distinct !DISubprogram(name: {}, linkageName: {}, scope: !{file}, file: !{file}, line: {line}, type: !{}, scopeLine: {line}, spFlags: {sp_flags}, unit: !{}, retainedNodes: !{})",
+                quote(&display_name(&function.name)),
+                quote(symbol),
+                subroutine.0,
+                debug.unit.0,
+                retained.0,
+                file = file.0,
+            ),
+        );
+        if let Some((file_id, _, _)) = position {
+            debug.subprogram_files.insert(subprogram, file_id);
+        }
+        Some(subprogram)
+    }
+
+    /// A `DILocation` in `scope` for `span`; unknown spans map to line 0 so stepping skips them.
+    pub(crate) fn debug_location(
+        &mut self,
+        scope: MetadataId,
+        span: Option<Span>,
+    ) -> Option<MetadataId> {
+        let debug = self.debug.as_deref_mut()?;
+        let nodes = &mut self.nodes;
+        let (scope, line, column) = match span.and_then(|span| debug.resolve(span)) {
+            Some((file_id, line, column)) => {
+                let scope = debug.scope_in_file(nodes, scope, file_id);
+                (scope, line, column)
+            }
+            None => (scope, 0, 0),
+        };
+        let key = (scope, line, column);
+        if let Some(location) = debug.locations.get(&key) {
+            return Some(*location);
+        }
+        let location = push(
+            nodes,
+            format!(
+                "!DILocation(line: {line}, column: {column}, scope: !{})",
+                scope.0
+            ),
+        );
+        debug.locations.insert(key, location);
+        Some(location)
+    }
+
+    /// A `DILocalVariable` for a named argument or user local; compiler temporaries get none.
+    pub(crate) fn debug_local_variable(
+        &mut self,
+        scope: MetadataId,
+        local: &LocalDecl,
+        layouts: &TypeLayoutTable,
+    ) -> Option<MetadataId> {
+        let debug = self.debug.as_deref_mut()?;
+        let nodes = &mut self.nodes;
+        let name = local.name.as_deref()?;
+        if name.is_empty() || name.starts_with("__") || name.contains('$') {
+            return None;
+        }
+        let arg = match local.kind {
+            LocalKind::Arg(index) => format!("arg: {}, ", index + 1),
+            LocalKind::Local => String::new(),
+            LocalKind::Return | LocalKind::Temp => return None,
+        };
+        let ty = debug.type_node(nodes, layouts, &local.ty)?;
+        let position = local.span.and_then(|span| debug.resolve(span));
+        let file = match position {
+            Some((file_id, _, _)) => debug.file(nodes, file_id),
+            None => debug.unit_file,
+        };
+        let line = position.map_or(0, |(_, line, _)| line);
+        Some(push(
+            nodes,
+            format!(
+                "!DILocalVariable(name: {}, {arg}scope: !{}, file: !{}, line: {line}, type: !{})",
+                quote(name),
+                scope.0,
+                file.0,
+                ty.0
+            ),
+        ))
+    }
+}
+
+impl DebugInfo {
+    fn resolve(&self, span: Span) -> Option<(FileId, usize, usize)> {
+        let source = self.files.get(span.file_id)?;
+        let position = source.line_col(span.start)?;
+        Some((span.file_id, position.line, position.column))
+    }
+
+    fn file(&mut self, nodes: &mut Vec<String>, file_id: FileId) -> MetadataId {
+        if let Some(node) = self.file_nodes.get(&file_id) {
+            return *node;
+        }
+        let Some(source) = self.files.get(file_id) else {
+            return self.unit_file;
+        };
+        let node = file_node(nodes, &source.path);
+        self.file_nodes.insert(file_id, node);
+        node
+    }
+
+    /// Locations outside the subprogram's own file need a `DILexicalBlockFile` scope.
+    fn scope_in_file(
+        &mut self,
+        nodes: &mut Vec<String>,
+        scope: MetadataId,
+        file_id: FileId,
+    ) -> MetadataId {
+        if self.subprogram_files.get(&scope) == Some(&file_id) {
+            return scope;
+        }
+        if let Some(block) = self.lexical_files.get(&(scope, file_id)) {
+            return *block;
+        }
+        let file = self.file(nodes, file_id);
+        let block = push(
+            nodes,
+            format!(
+                "!DILexicalBlockFile(scope: !{}, file: !{}, discriminator: 0)",
+                scope.0, file.0
+            ),
+        );
+        self.lexical_files.insert((scope, file_id), block);
+        block
+    }
+
+    fn type_ref(&mut self, nodes: &mut Vec<String>, layouts: &TypeLayoutTable, ty: &Ty) -> String {
+        self.type_node(nodes, layouts, ty)
+            .map_or_else(|| "null".to_string(), |id| format!("!{}", id.0))
+    }
+
+    fn type_node(
+        &mut self,
+        nodes: &mut Vec<String>,
+        layouts: &TypeLayoutTable,
+        ty: &Ty,
+    ) -> Option<MetadataId> {
+        match ty {
+            Ty::Unit | Ty::Unknown => return None,
+            Ty::Pointer(pointer) => return Some(self.pointer_to(nodes, layouts, &pointer.element)),
+            Ty::Ref(reference) => {
+                return Some(self.pointer_to(nodes, layouts, &reference.element));
+            }
+            _ => {}
+        }
+        let key = ty.canonical_name();
+        if let Some(node) = self.type_nodes.get(&key) {
+            return *node;
+        }
+        if let Some(pointee) = key.strip_suffix('*') {
+            return Some(self.pointer_to(nodes, layouts, &Ty::named(pointee.trim_end())));
+        }
+        if let Some(kind) = layouts.primitive_registry.kind_for_name(&key) {
+            let node = basic_type(&key, kind).map(|body| push(nodes, body));
+            if node.is_some() || matches!(kind, PrimitiveKind::Void) {
+                self.type_nodes.insert(key, node);
+                return node;
+            }
+        }
+        let Some(layout) = layouts.layout_for_name(&key) else {
+            return Some(self.opaque_type(nodes, layouts, &key, ty));
+        };
+        // Short and qualified spellings of a type share one descriptor.
+        let name = match layout {
+            TypeLayout::Struct(data) | TypeLayout::Class(data) => &data.name,
+            TypeLayout::Enum(data) => &data.name,
+            TypeLayout::Union(data) => &data.name,
+        };
+        let node = match self.type_nodes.get(name) {
+            Some(Some(node)) => *node,
+            _ => self.layout_type(nodes, layouts, name, layout),
+        };
+        self.type_nodes.insert(key, Some(node));
+        Some(node)
+    }
+
+    fn pointer_to(
+        &mut self,
+        nodes: &mut Vec<String>,
+        layouts: &TypeLayoutTable,
+        pointee: &Ty,
+    ) -> MetadataId {
+        let key = format!("{}*", pointee.canonical_name());
+        if let Some(Some(node)) = self.type_nodes.get(&key) {
+            return *node;
+        }
+        let base = self.type_ref(nodes, layouts, pointee);
+        let node = push(
+            nodes,
+            format!(
+                "!DIDerivedType(tag: DW_TAG_pointer_type, baseType: {base}, size: {})",
+                pointer_size() * 8
+            ),
+        );
+        self.type_nodes.insert(key, Some(node));
+        node
+    }
+
+    /// Types without a layout (spans, vectors, delegates, ...) are described by name and size.
+    fn opaque_type(
+        &mut self,
+        nodes: &mut Vec<String>,
+        layouts: &TypeLayoutTable,
+        key: &str,
+        ty: &Ty,
+    ) -> MetadataId {
+        let body = match layouts.size_and_align_for_ty(ty) {
+            Some((size, align)) => format!(
+                "!DICompositeType(tag: DW_TAG_structure_type, name: {}, size: {}, align: {}, elements: !{{}}, identifier: {})",
+                quote(&display_name(key)),
+                size * 8,
+                align * 8,
+                quote(key)
+            ),
+            None => format!(
+                "!DICompositeType(tag: DW_TAG_structure_type, name: {}, flags: DIFlagFwdDecl, identifier: {})",
+                quote(&display_name(key)),
+                quote(key)
+            ),
+        };
+        let node = push(nodes, body);
+        self.type_nodes.insert(key.to_string(), Some(node));
+        node
+    }
+
+    fn layout_type(
+        &mut self,
+        nodes: &mut Vec<String>,
+        layouts: &TypeLayoutTable,
+        key: &str,
+        layout: &TypeLayout,
+    ) -> MetadataId {
+        // Reserve the node first so self-referential members resolve to it.
+        let composite = push(nodes, String::new());
+        let name = quote(&display_name(key));
+        let identifier = quote(key);
+        let (result, body) = match layout {
+            TypeLayout::Struct(data) | TypeLayout::Class(data) => {
+                let is_class = matches!(layout, TypeLayout::Class(_));
+                // Class values are references to the object, so the type seen by variables is a
+                // pointer to the class body.
+                let result = if is_class {
+                    push(
+                        nodes,
+                        format!(
+                            "!DIDerivedType(tag: DW_TAG_pointer_type, baseType: !{}, size: {})",
+                            composite.0,
+                            pointer_size() * 8
+                        ),
+                    )
+                } else {
+                    composite
+                };
+                self.type_nodes.insert(key.to_string(), Some(result));
+                let mut members = Vec::new();
+                for field in &data.fields {
+                    let offset = field.offset.unwrap_or(0);
+                    let member = self.member(
+                        nodes,
+                        layouts,
+                        composite,
+                        field.display_name.as_deref().unwrap_or(&field.name),
+                        &field.ty,
+                        field.span,
+                        offset,
+                    );
+                    members.push(format!("!{}", member.0));
+                }
+                let elements = push(nodes, format!("!{{{}}}", members.join(", ")));
+                let tag = if is_class {
+                    "DW_TAG_class_type"
+                } else {
+                    "DW_TAG_structure_type"
+                };
+                let body = format!(
+                    "distinct !DICompositeType(tag: {tag}, name: {name}{}, elements: !{}, identifier: {identifier})",
+                    size_suffix(data.size, data.align),
+                    elements.0
+                );
+                (result, body)
+            }
+            TypeLayout::Enum(data) => {
+                self.type_nodes.insert(key.to_string(), Some(composite));
+                let fieldless = data
+                    .variants
+                    .iter()
+                    .all(|variant| variant.fields.is_empty());
+                let body = if fieldless {
+                    let base = self.type_ref(nodes, layouts, &data.underlying);
+                    let unsigned = data.underlying_info.is_some_and(|info| !info.signed);
+                    let enumerators = data
+                        .variants
+                        .iter()
+                        .map(|variant| {
+                            let node = push(
+                                nodes,
+                                format!(
+                                    "!DIEnumerator(name: {}, value: {}{})",
+                                    quote(&variant.name),
+                                    variant.discriminant as i64,
+                                    if unsigned { ", isUnsigned: true" } else { "" }
+                                ),
+                            );
+                            format!("!{}", node.0)
+                        })
+                        .collect::<Vec<_>>();
+                    let elements = push(nodes, format!("!{{{}}}", enumerators.join(", ")));
+                    format!(
+                        "distinct !DICompositeType(tag: DW_TAG_enumeration_type, name: {name}, baseType: {base}{}, elements: !{}, identifier: {identifier})",
+                        size_suffix(data.size, data.align),
+                        elements.0
+                    )
+                } else {
+                    format!(
+                        "distinct !DICompositeType(tag: DW_TAG_structure_type, name: {name}{}, elements: !{{}}, identifier: {identifier})",
+                        size_suffix(data.size, data.align)
+                    )
+                };
+                (composite, body)
+            }
+            TypeLayout::Union(data) => {
+                self.type_nodes.insert(key.to_string(), Some(composite));
+                let mut members = Vec::new();
+                for view in &data.views {
+                    let member = self.member(
+                        nodes, layouts, composite, &view.name, &view.ty, view.span, 0,
+                    );
+                    members.push(format!("!{}", member.0));
+                }
+                let elements = push(nodes, format!("!{{{}}}", members.join(", ")));
+                let body = format!(
+                    "distinct !DICompositeType(tag: DW_TAG_union_type, name: {name}{}, elements: !{}, identifier: {identifier})",
+                    size_suffix(data.size, data.align),
+                    elements.0
+                );
+                (composite, body)
+            }
+        };
+        nodes[composite.0] = body;
+        result
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn member(
+        &mut self,
+        nodes: &mut Vec<String>,
+        layouts: &TypeLayoutTable,
+        scope: MetadataId,
+        name: &str,
+        ty: &Ty,
+        span: Option<Span>,
+        offset: usize,
+    ) -> MetadataId {
+        let base = self.type_ref(nodes, layouts, ty);
+        let size = layouts
+            .size_and_align_for_ty(ty)
+            .map_or(0, |(size, _)| size * 8);
+        let location = match span.and_then(|span| self.resolve(span)) {
+            Some((file_id, line, _)) => {
+                let file = self.file(nodes, file_id);
+                format!(", file: !{}, line: {line}", file.0)
+            }
+            None => String::new(),
+        };
+        push(
+            nodes,
+            format!(
+                "!DIDerivedType(tag: DW_TAG_member, name: {}, scope: !{}{location}, baseType: {base}, size: {size}, offset: {})",
+                quote(name),
+                scope.0,
+                offset * 8
+            ),
+        )
+    }
+}
+
+fn push(nodes: &mut Vec<String>, body: String) -> MetadataId {
+    nodes.push(body);
+    MetadataId(nodes.len() - 1)
+}
+
+fn file_node(nodes: &mut Vec<String>, path: &std::path::Path) -> MetadataId {
+    let filename = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string());
+    let directory = path
+        .parent()
+        .map(|dir| dir.display().to_string())
+        .unwrap_or_default();
+    push(
+        nodes,
+        format!(
+            "!DIFile(filename: {}, directory: {})",
+            quote(&filename),
+            quote(&directory)
+        ),
+    )
+}
+
+fn basic_type(name: &str, kind: &PrimitiveKind) -> Option<String> {
+    let pointer_bits = pointer_size() as u16 * 8;
+    let (size, encoding) = match *kind {
+        PrimitiveKind::Int {
+            pointer_sized: true,
+            signed,
+            ..
+        } => (
+            pointer_bits,
+            if signed {
+                "DW_ATE_signed"
+            } else {
+                "DW_ATE_unsigned"
+            },
+        ),
+        PrimitiveKind::Int {
+            bits: 8, signed, ..
+        } => (
+            8,
+            if signed {
+                "DW_ATE_signed_char"
+            } else {
+                "DW_ATE_unsigned_char"
+            },
+        ),
+        PrimitiveKind::Int { bits, signed, .. } => (
+            bits,
+            if signed {
+                "DW_ATE_signed"
+            } else {
+                "DW_ATE_unsigned"
+            },
+        ),
+        PrimitiveKind::Float { bits } => (bits, "DW_ATE_float"),
+        PrimitiveKind::Char { bits } => (bits, "DW_ATE_UTF"),
+        PrimitiveKind::Bool => (8, "DW_ATE_boolean"),
+        PrimitiveKind::Decimal => (128, "DW_ATE_decimal_float"),
+        PrimitiveKind::String | PrimitiveKind::Str | PrimitiveKind::Void => return None,
+    };
+    Some(format!(
+        "!DIBasicType(name: {}, size: {size}, encoding: {encoding})",
+        quote(name)
+    ))
+}
+
+fn size_suffix(size: Option<usize>, align: Option<usize>) -> String {
+    match (size, align) {
+        (Some(size), Some(align)) => format!(", size: {}, align: {}", size * 8, align * 8),
+        (Some(size), None) => format!(", size: {}", size * 8),
+        _ => String::new(),
+    }
+}
+
+/// Chic spells qualified names with `.`, which is also what debuggers are asked to break on.
+fn display_name(name: &str) -> String {
+    name.replace("::", ".")
+}
+
+/// Quote a metadata string, escaping bytes LLVM cannot read verbatim.
+fn quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for byte in text.bytes() {
+        match byte {
+            b'"' | b'\\' => quoted.push_str(&format!("\\{byte:02X}")),
+            0x20..=0x7e => quoted.push(byte as char),
+            _ => quoted.push_str(&format!("\\{byte:02X}")),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
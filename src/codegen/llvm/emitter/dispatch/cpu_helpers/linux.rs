@@ -309,6 +309,13 @@ pub(crate) fn emit_external_declarations(out: &mut String, externals: &BTreeSet<
             "llvm.trap" => {
                 writeln!(out, "declare void @llvm.trap()").ok();
             }
+            "llvm.dbg.declare" => {
+                writeln!(
+                    out,
+                    "declare void @llvm.dbg.declare(metadata, metadata, metadata)"
+                )
+                .ok();
+            }
             "chic_rt.mmio_read" => {
                 writeln!(out, "declare i64 @chic_rt.mmio_read(i64, i32, i32)").ok();
             }
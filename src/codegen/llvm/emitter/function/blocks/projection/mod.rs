@@ -1,7 +1,5 @@
 use crate::error::Error;
-use crate::mir::{
-    ArrayTy, FunctionKind, LocalId, ParamMode, Place, ProjectionElem, Ty, TypeLayout,
-};
+use crate::mir::{FunctionKind, LocalId, ParamMode, Place, ProjectionElem, Ty, TypeLayout};
 
 use super::super::builder::FunctionEmitter;
 use super::super::values::ValueRef;
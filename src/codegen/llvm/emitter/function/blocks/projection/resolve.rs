@@ -558,11 +558,8 @@ impl<'a> FunctionEmitter<'a> {
                             element_ty
                         }
                         Ty::Array(array_ty) => {
-                            let element_ty = if array_ty.rank > 1 {
-                                Ty::Array(ArrayTy::new(array_ty.element.clone(), array_ty.rank - 1))
-                            } else {
-                                (*array_ty.element).clone()
-                            };
+                            // MIR flattens rectangular indices into one row-major offset.
+                            let element_ty = (*array_ty.element).clone();
                             let next_ptr = self.inline_index_projection(
                                 &current_ptr,
                                 &Ty::Array(array_ty.clone()),
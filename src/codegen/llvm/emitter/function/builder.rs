@@ -8,13 +8,13 @@ use crate::codegen::llvm::types::map_type_owned;
 use crate::error::Error;
 use crate::mir::{
     BasicBlock, BlockId, ClassVTable, LocalId, LocalKind, MirFunction, Place, ProjectionElem,
-    RoundingMode, StaticId, StaticVar, StrId, TraitVTable, Ty, TypeLayoutTable,
+    RoundingMode, StatementKind, StaticId, StaticVar, StrId, TraitVTable, Ty, TypeLayoutTable,
 };
 use crate::perf::Tracepoint;
 use crate::target::Target;
 use crate::target::TargetArch;
 
-use super::super::metadata_pool::MetadataRegistry;
+use super::super::metadata_pool::{MetadataId, MetadataRegistry};
 use crate::abi::{CAbiPass, CAbiReturn};
 use crate::codegen::llvm::signatures::{LlvmFunctionSignature, sanitise_symbol};
 
@@ -152,9 +152,15 @@ pub(crate) fn emit_function_with_async<'a>(
         format!(" {}", fn_attrs.join(" "))
     };
 
+    let debug_scope =
+        metadata.debug_subprogram(function, symbol, linkage.contains("internal"), type_layouts);
+    let debug_suffix = debug_scope
+        .map(|scope| format!(" !dbg {}", metadata.reference(scope)))
+        .unwrap_or_default();
+
     writeln!(
         out,
-        "define {linkage} {ret_str} @{symbol}({params_repr}){fn_attr_suffix} {{"
+        "define {linkage} {ret_str} @{symbol}({params_repr}){fn_attr_suffix}{debug_suffix} {{"
     )
     .ok();
 
@@ -176,6 +182,7 @@ pub(crate) fn emit_function_with_async<'a>(
         metadata,
         tracepoint,
     );
+    emitter.debug_scope = debug_scope;
     let body_ir = emitter.emit()?;
     out.push_str(&body_ir);
 
@@ -217,6 +224,8 @@ pub(super) struct FunctionEmitter<'a> {
     pub(super) available_tiers: Vec<CpuIsaTier>,
     pub(super) arch: TargetArch,
     pub(super) metadata: &'a mut MetadataRegistry,
+    /// `DISubprogram` for the function when debug info is enabled.
+    pub(super) debug_scope: Option<MetadataId>,
     alias_scopes: Vec<Option<AliasScopeInfo>>,
     aux_label_counter: usize,
     pub(super) tracepoint: Option<&'a Tracepoint>,
@@ -352,6 +361,7 @@ impl<'a> FunctionEmitter<'a> {
             available_tiers: available_tiers.to_vec(),
             arch,
             metadata,
+            debug_scope: None,
             alias_scopes: Vec::new(),
             aux_label_counter: 0,
             tracepoint,
@@ -444,6 +454,7 @@ impl<'a> FunctionEmitter<'a> {
         self.init_alias_metadata();
         self.assign_block_labels();
         self.emit_blocks()?;
+        self.attach_debug_location(0, self.function.span);
         Ok(self.builder.clone())
     }
 
@@ -470,18 +481,39 @@ impl<'a> FunctionEmitter<'a> {
     fn emit_entry_block(&mut self, block: &BasicBlock) -> Result<(), Error> {
         let label = self.block_label(block.id)?;
         writeln!(&mut self.builder, "{label}:").ok();
+        let prologue = self.builder.len();
         self.initialise_locals()?;
         self.initialise_arguments()?;
+        self.emit_debug_declares();
         self.emit_trace_enter()?;
+        self.attach_debug_location(prologue, self.function.span);
         self.emit_block_contents(block)
     }
 
     fn emit_block_contents(&mut self, block: &BasicBlock) -> Result<(), Error> {
         for statement in &block.statements {
+            let start = self.builder.len();
             self.emit_statement(statement)?;
+            self.attach_debug_location(start, statement.span.or(block.span));
         }
         if let Some(term) = &block.terminator {
+            let start = self.builder.len();
             self.emit_terminator(term)?;
+            // Storage markers carry the declaration's span; locate the terminator at the last
+            // statement that does real work.
+            let span = block
+                .statements
+                .iter()
+                .rev()
+                .find(|statement| {
+                    !matches!(
+                        statement.kind,
+                        StatementKind::StorageLive(_) | StatementKind::StorageDead(_)
+                    )
+                })
+                .and_then(|statement| statement.span)
+                .or(block.span);
+            self.attach_debug_location(start, span);
         } else {
             // Safeguard: emit an explicit unreachable so even malformed/unreachable
             // blocks still produce valid LLVM IR.
@@ -0,0 +1,78 @@
+//! Debug location and variable lowering scoped to the function emitter.
+
+use std::fmt::Write;
+
+use super::builder::FunctionEmitter;
+use crate::frontend::diagnostics::Span;
+
+impl<'a> FunctionEmitter<'a> {
+    /// Describe each named argument and user local with `llvm.dbg.declare` on its stack slot.
+    pub(crate) fn emit_debug_declares(&mut self) {
+        let Some(scope) = self.debug_scope else {
+            return;
+        };
+        for (index, local) in self.function.body.locals.iter().enumerate() {
+            let Some(ptr) = self.local_ptrs.get(index).cloned().flatten() else {
+                continue;
+            };
+            let Some(variable) =
+                self.metadata
+                    .debug_local_variable(scope, local, self.type_layouts)
+            else {
+                continue;
+            };
+            let Some(location) = self
+                .metadata
+                .debug_location(scope, local.span.or(self.function.span))
+            else {
+                continue;
+            };
+            self.externals.insert("llvm.dbg.declare");
+            writeln!(
+                &mut self.builder,
+                "  call void @llvm.dbg.declare(metadata ptr {ptr}, metadata {}, metadata !DIExpression()), !dbg {}",
+                self.metadata.reference(variable),
+                self.metadata.reference(location)
+            )
+            .ok();
+        }
+    }
+
+    /// Attach `!dbg` for `span` to the instructions emitted since `start` that do not carry one.
+    pub(crate) fn attach_debug_location(&mut self, start: usize, span: Option<Span>) {
+        let Some(scope) = self.debug_scope else {
+            return;
+        };
+        if start >= self.builder.len() {
+            return;
+        }
+        let Some(location) = self.metadata.debug_location(scope, span) else {
+            return;
+        };
+        let suffix = format!(", !dbg {}", self.metadata.reference(location));
+        let emitted = self.builder.split_off(start);
+        // Multi-line `switch` instructions take the attachment after their closing `]`.
+        let mut in_case_list = false;
+        for line in emitted.split_inclusive('\n') {
+            let (text, newline) = match line.strip_suffix('\n') {
+                Some(text) => (text, "\n"),
+                None => (line, ""),
+            };
+            let trimmed = text.trim();
+            let attach = if in_case_list {
+                in_case_list = !trimmed.starts_with(']');
+                !in_case_list
+            } else if text.starts_with("  ") && !trimmed.is_empty() && !trimmed.starts_with(';') {
+                in_case_list = trimmed.ends_with('[');
+                !in_case_list
+            } else {
+                false
+            };
+            self.builder.push_str(text);
+            if attach && !text.contains("!dbg ") {
+                self.builder.push_str(&suffix);
+            }
+            self.builder.push_str(newline);
+        }
+    }
+}
@@ -1,5 +1,6 @@
 mod blocks;
 mod builder;
+mod debug;
 mod runtime;
 mod statements;
 mod terminators;
@@ -48,6 +48,42 @@ impl<'a> FunctionEmitter<'a> {
             "  call i32 @chic_rt_vec_clone(ptr {dest_ptr}, ptr {src_ptr})"
         )
         .ok();
+        self.copy_array_extents(src, &dest_ptr, &src_ptr)
+    }
+
+    /// The runtime clones only the `VecPtr` prefix; rectangular arrays also carry one extent
+    /// per dimension after it, which is copied field by field.
+    fn copy_array_extents(
+        &mut self,
+        src: &Place,
+        dest_ptr: &str,
+        src_ptr: &str,
+    ) -> Result<(), Error> {
+        let ty = self.mir_ty_of_place(src)?;
+        let Ty::Array(array) = &ty else {
+            return Ok(());
+        };
+        if array.rank <= 1 {
+            return Ok(());
+        }
+        for dimension in 0..array.rank {
+            let (offset, _) = self.field_info_by_name(&ty, &format!("dim{dimension}"))?;
+            let src_field = self.new_temp();
+            let dest_field = self.new_temp();
+            let value = self.new_temp();
+            writeln!(
+                &mut self.builder,
+                "  {src_field} = getelementptr i8, ptr {src_ptr}, i64 {offset}"
+            )
+            .ok();
+            writeln!(&mut self.builder, "  {value} = load i64, ptr {src_field}").ok();
+            writeln!(
+                &mut self.builder,
+                "  {dest_field} = getelementptr i8, ptr {dest_ptr}, i64 {offset}"
+            )
+            .ok();
+            writeln!(&mut self.builder, "  store i64 {value}, ptr {dest_field}").ok();
+        }
         Ok(())
     }
 
@@ -132,7 +168,12 @@ mod tests {
         let class_vtables = Vec::new();
         let statics: Vec<crate::mir::StaticVar> = Vec::new();
         let str_literals: HashMap<crate::mir::StrId, StrLiteralInfo> = HashMap::new();
-        let type_layouts = TypeLayoutTable::default();
+        let mut type_layouts = TypeLayoutTable::default();
+        for ty in &local_tys {
+            if let Ty::Array(array) = ty {
+                type_layouts.ensure_array_layout(array);
+            }
+        }
         let mut metadata = MetadataRegistry::new();
         let target = crate::target::Target::parse("aarch64-unknown-linux-gnu").expect("target");
         let mut emitter = FunctionEmitter::new(
@@ -240,6 +281,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rectangular_array_clone_copies_extents() {
+        let grid_ty = Ty::Array(ArrayTy::new(Box::new(Ty::named("int")), 2));
+        let dest = Place::new(LocalId(0));
+        let src = Place::new(LocalId(1));
+        let (result, ir, _) = with_emitter(
+            vec![grid_ty.clone(), grid_ty],
+            vec![Some("%dest"), Some("%src")],
+            |emitter| emitter.emit_vec_assignment(&dest, &Rvalue::Use(Operand::Copy(src.clone()))),
+        );
+        assert!(result.expect("array assignment should succeed"));
+        let clone_at = ir
+            .find("call i32 @chic_rt_vec_clone(ptr %dest, ptr %src)")
+            .expect("clone call should be emitted");
+        let extent_loads = ir[clone_at..].matches("load i64").count();
+        let extent_stores = ir[clone_at..].matches("store i64").count();
+        assert_eq!(extent_loads, 2, "both extents should be read after cloning");
+        assert_eq!(
+            extent_stores, 2,
+            "both extents should be written to the clone"
+        );
+    }
+
     #[test]
     fn place_is_vec_handles_arrays() {
         let array_ty = Ty::Array(ArrayTy::new(Box::new(Ty::named("int")), 4));
@@ -14,7 +14,7 @@ use crate::mir::{
 
 use super::super::builder::FunctionEmitter;
 use crate::async_flags::{FUTURE_FLAG_CANCELLED, FUTURE_FLAG_COMPLETED, FUTURE_FLAG_READY};
-use crate::codegen::llvm::types::map_type_owned;
+use crate::codegen::llvm::types::{INTERFACE_VALUE_TYPE, map_type_owned};
 
 impl<'a> FunctionEmitter<'a> {
     pub(crate) fn emit_terminator(&mut self, terminator: &Terminator) -> Result<(), Error> {
@@ -174,7 +174,20 @@ impl<'a> FunctionEmitter<'a> {
     ) -> Result<(), Error> {
         let discr_operand = Operand::Copy(value.clone());
         let discr_val = self.emit_operand(&discr_operand, None)?;
-        let discr_repr = discr_val.repr().to_string();
+        // Interface values are `{ data, vtable }` pairs; the type test inspects the wrapped object.
+        let is_interface_value = discr_val.ty() == INTERFACE_VALUE_TYPE;
+        let discr_repr = if is_interface_value {
+            let data = self.new_temp();
+            writeln!(
+                &mut self.builder,
+                "  {data} = extractvalue {INTERFACE_VALUE_TYPE} {}, 0",
+                discr_val.repr()
+            )
+            .ok();
+            data
+        } else {
+            discr_val.repr().to_string()
+        };
 
         let canonical = target_ty.canonical_name();
         let target_name = canonical
@@ -231,6 +244,12 @@ impl<'a> FunctionEmitter<'a> {
                     _ => None,
                 },
             )
+            // Interfaces share the class layout but never head an object, so they have no vtable.
+            .filter(|symbol| {
+                self.class_vtables
+                    .iter()
+                    .any(|table| &table.symbol == symbol)
+            })
             .collect::<Vec<_>>();
         vtables.sort();
         vtables.dedup();
@@ -259,13 +278,20 @@ impl<'a> FunctionEmitter<'a> {
 
         writeln!(&mut self.builder, "{non_null_label}:").ok();
 
-        let mut vtable_place = value.clone();
-        vtable_place.projection.push(ProjectionElem::Deref);
-        vtable_place
-            .projection
-            .push(ProjectionElem::FieldNamed("$vtable".into()));
-        let vtable_val = self.emit_operand(&Operand::Copy(vtable_place), None)?;
-        let vtable_repr = vtable_val.repr().to_string();
+        let vtable_repr = if is_interface_value {
+            // The class vtable pointer heads every object.
+            let vtable = self.new_temp();
+            writeln!(&mut self.builder, "  {vtable} = load ptr, ptr {discr_repr}").ok();
+            vtable
+        } else {
+            let mut vtable_place = value.clone();
+            vtable_place.projection.push(ProjectionElem::Deref);
+            vtable_place
+                .projection
+                .push(ProjectionElem::FieldNamed("$vtable".into()));
+            let vtable_val = self.emit_operand(&Operand::Copy(vtable_place), None)?;
+            vtable_val.repr().to_string()
+        };
 
         let mut predicate = None::<String>;
         for symbol in &vtables {
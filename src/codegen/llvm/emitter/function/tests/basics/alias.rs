@@ -1,14 +1,17 @@
 use super::super::helpers::{function_ir, test_target};
-use crate::codegen::CpuIsaTier;
+use crate::chic_kind::ChicKind;
+use crate::codegen::llvm::emit_module;
 use crate::codegen::llvm::emitter::function::builder::emit_function;
 use crate::codegen::llvm::emitter::metadata_pool::MetadataRegistry;
 use crate::codegen::llvm::signatures::build_signatures;
+use crate::codegen::{CodegenOptions, CpuIsaTier};
 use crate::mir::{
     Abi, AliasContract, BasicBlock, FnSig, FunctionKind, LocalDecl, LocalId, LocalKind, MirBody,
     MirFunction, MirModule, Operand, ParamMode, Place, ProjectionElem, Rvalue, Statement,
     StatementKind, Ty,
 };
 use crate::mir::{PointerQualifiers, PointerTy};
+use crate::perf::PerfMetadata;
 use std::collections::{BTreeSet, HashMap, HashSet};
 
 #[test]
@@ -60,6 +63,46 @@ fn restrict_parameters_emit_alias_metadata() {
     );
 }
 
+#[test]
+fn alias_metadata_is_independent_of_job_count() {
+    let mut module = alias_module();
+    let template = module.functions[0].clone();
+    for index in 0..6 {
+        let mut function = template.clone();
+        function.name = format!("Demo::PtrOps::CopyOne{index}");
+        module.functions.push(function);
+    }
+    let target = test_target();
+    let signatures = build_signatures(&module, None, &target).expect("signatures");
+    let emit = |jobs| {
+        crate::threading::configure_jobs(Some(jobs));
+        let ir = emit_module(
+            &module,
+            None,
+            &PerfMetadata::default(),
+            &signatures,
+            None,
+            ChicKind::StaticLibrary,
+            target.triple(),
+            &target,
+            &CodegenOptions::default(),
+            &[],
+            &[],
+            &[],
+            &[],
+        );
+        crate::threading::configure_jobs(None);
+        ir.expect("emit module")
+    };
+    let serial = emit(1);
+    let parallel = emit(4);
+    assert!(
+        serial.contains("chx.alias.domain.Demo_PtrOps_CopyOne5"),
+        "every copy should allocate an alias domain: {serial}"
+    );
+    assert_eq!(serial, parallel);
+}
+
 fn alias_module() -> MirModule {
     let mut body = MirBody::new(2, None);
     body.locals.push(LocalDecl::new(
@@ -376,3 +376,108 @@ fn pointer_to_int_emits_ptrtoint() {
         fn_ir
     );
 }
+
+fn lowered_function_irs(source: &str, names: &[&str]) -> Vec<String> {
+    let parsed = crate::frontend::parser::parse_module(source).expect("parse");
+    let lowering = crate::mir::lower_module(&parsed.module);
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "lowering diagnostics: {:?}",
+        lowering.diagnostics
+    );
+    let module = lowering.module;
+    let target = test_target();
+    let signatures = build_signatures(&module, None, &target).expect("signatures");
+    let ir = emit_module(
+        &module,
+        None,
+        &PerfMetadata::default(),
+        &signatures,
+        None,
+        ChicKind::StaticLibrary,
+        target.triple(),
+        &target,
+        &CodegenOptions::default(),
+        &[],
+        &[],
+        &[],
+        &[],
+    )
+    .expect("emit module");
+    names
+        .iter()
+        .map(|name| {
+            let symbol = signatures
+                .get(&canonical_function_name(name))
+                .map(|sig| sig.symbol.clone())
+                .expect("function symbol");
+            function_ir(&ir, &symbol).to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn checked_reference_casts_compare_vtables() {
+    let irs = lowered_function_irs(
+        r#"
+namespace Objects {
+    public interface IShape { int Sides(); }
+    public class Base { }
+    public class Square : Base, IShape { public int Sides() { return 4; } }
+    public class Tile : Square { }
+
+    public Square Downcast(Base value) { return (Square)value; }
+    public IShape ToShape(Base value) { return (IShape)value; }
+    public Square FromShape(IShape shape) { return (Square)shape; }
+}
+"#,
+        &[
+            "Objects::Downcast",
+            "Objects::ToShape",
+            "Objects::FromShape",
+        ],
+    );
+    let [downcast, to_shape, from_shape] = irs.as_slice() else {
+        panic!("expected three functions");
+    };
+
+    assert!(
+        downcast.contains("@__class_vtable_Objects__Square"),
+        "downcast should test the derived class vtable: {downcast}"
+    );
+    assert!(
+        downcast.contains("call void @llvm.trap()"),
+        "failed downcast should trap without the standard library: {downcast}"
+    );
+
+    assert!(
+        !to_shape.contains("@__class_vtable_Objects__IShape"),
+        "interfaces have no class vtable to compare against: {to_shape}"
+    );
+    assert!(
+        to_shape.contains("select i1")
+            && to_shape.contains("ptr @__vtable_Objects__IShape__Objects__Square"),
+        "interface cast should pick the implementation vtable at runtime: {to_shape}"
+    );
+    assert!(
+        to_shape.contains("icmp eq ptr %") && to_shape.contains("@__class_vtable_Objects__Tile"),
+        "subclasses should reuse the nearest base's interface vtable: {to_shape}"
+    );
+    assert!(
+        !to_shape.contains("@__vtable_Objects__IShape__Objects__Tile"),
+        "subclasses without their own implementation have no interface vtable: {to_shape}"
+    );
+    assert!(
+        to_shape.contains("insertvalue { ptr, ptr }"),
+        "interface cast should build an interface value: {to_shape}"
+    );
+
+    assert!(
+        from_shape.contains("extractvalue { ptr, ptr }"),
+        "casting out of an interface should unwrap the object: {from_shape}"
+    );
+    assert!(
+        from_shape.contains("@__class_vtable_Objects__Square"),
+        "casting out of an interface should test the object vtable: {from_shape}"
+    );
+}
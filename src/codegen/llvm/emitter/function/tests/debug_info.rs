@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use super::helpers::function_ir;
+use crate::chic_kind::ChicKind;
+use crate::codegen::llvm::emit_module;
+use crate::codegen::llvm::signatures::build_signatures;
+use crate::codegen::{CodegenOptions, CpuIsaConfig};
+use crate::diagnostics::FileCache;
+use crate::frontend::parser::parse_module_in_file;
+use crate::mir::lower_module;
+use crate::perf::PerfMetadata;
+use crate::target::Target;
+
+const SOURCE: &str = r#"namespace Demo;
+
+public struct Point
+{
+    public int X;
+    public int Y;
+}
+
+public int Sum(Point p)
+{
+    var total = p.X + p.Y;
+    return total;
+}
+"#;
+
+fn emit(source: &str, debug_info: bool) -> String {
+    let mut files = FileCache::default();
+    let file_id = files.add_file("/work/demo/src/point.ch", source);
+    let parsed = parse_module_in_file(source, file_id).expect("parse");
+    let lowering = lower_module(&parsed.module);
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "lowering diagnostics: {:?}",
+        lowering.diagnostics
+    );
+    let module = lowering.module;
+    let target = Target::parse("x86_64-unknown-linux-gnu").expect("target");
+    let signatures = build_signatures(&module, None, &target).expect("signatures");
+    let mut options = CodegenOptions::default();
+    options.cpu_isa = CpuIsaConfig::baseline();
+    if debug_info {
+        options.debug_info = Some(Arc::new(files));
+    }
+    emit_module(
+        &module,
+        None,
+        &PerfMetadata::default(),
+        &signatures,
+        None,
+        ChicKind::StaticLibrary,
+        target.triple(),
+        &target,
+        &options,
+        &[],
+        &[],
+        &[],
+        &[],
+    )
+    .expect("emit module")
+}
+
+#[test]
+fn debug_builds_describe_functions_lines_and_locals() {
+    let ir = emit(SOURCE, true);
+    assert!(
+        ir.contains("!llvm.dbg.cu = !{"),
+        "compile unit should be listed:\n{ir}"
+    );
+    assert!(
+        ir.contains("!DIFile(filename: \"point.ch\", directory: \"/work/demo/src\")"),
+        "source file should be described:\n{ir}"
+    );
+    assert!(
+        ir.contains("distinct !DISubprogram(name: \"Demo.Sum\", linkageName: \"Demo__Sum\""),
+        "subprogram should use the Chic name:\n{ir}"
+    );
+
+    let function = function_ir(&ir, "Demo__Sum");
+    let define = function.lines().next().expect("define line");
+    assert!(
+        define.contains(" !dbg !"),
+        "define should carry !dbg: {define}"
+    );
+    for line in function.lines().skip(1) {
+        if line.starts_with("  ") && !line.trim_start().starts_with(';') {
+            assert!(
+                line.contains(", !dbg !"),
+                "instruction without location: {line}"
+            );
+        }
+    }
+    assert!(
+        ir.contains("!DILocation(line: 11, column: ") && function.contains("ret i32 %"),
+        "statements should be located:\n{ir}"
+    );
+    let ret = function
+        .lines()
+        .find(|line| line.trim_start().starts_with("ret "))
+        .expect("ret");
+    let ret_location = ret.rsplit("!dbg ").next().expect("ret location");
+    assert!(
+        ir.contains(&format!("{ret_location} = !DILocation(line: 12, ")),
+        "return should be located on the return statement: {ret}\n{ir}"
+    );
+    assert!(
+        function.contains("call void @llvm.dbg.declare(metadata ptr %l1, metadata !"),
+        "parameter slot should be declared:\n{function}"
+    );
+    assert!(
+        ir.contains("!DILocalVariable(name: \"p\", arg: 1, ")
+            && ir.contains("!DILocalVariable(name: \"total\", "),
+        "argument and local should be described:\n{ir}"
+    );
+    assert!(
+        ir.contains("declare void @llvm.dbg.declare(metadata, metadata, metadata)"),
+        "dbg intrinsic should be declared:\n{ir}"
+    );
+    assert!(
+        ir.contains("!DICompositeType(tag: DW_TAG_structure_type, name: \"Demo.Point\"")
+            && ir.contains("!DIDerivedType(tag: DW_TAG_member, name: \"Y\"")
+            && ir.contains("offset: 32)"),
+        "struct fields should be described from the layout table:\n{ir}"
+    );
+    assert!(
+        ir.contains("!DIBasicType(name: \"int\", size: 32, encoding: DW_ATE_signed)"),
+        "primitive fields should map to basic types:\n{ir}"
+    );
+}
+
+#[test]
+fn release_builds_carry_no_debug_info() {
+    let ir = emit(SOURCE, false);
+    assert!(!ir.contains("!dbg"), "unexpected debug info:\n{ir}");
+    assert!(
+        !ir.contains("llvm.dbg.cu"),
+        "unexpected compile unit:\n{ir}"
+    );
+}
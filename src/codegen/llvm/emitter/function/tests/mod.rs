@@ -4,6 +4,7 @@ mod attributes;
 mod basics;
 mod calls;
 mod casts;
+mod debug_info;
 mod decimal;
 mod fixtures;
 mod helpers;
@@ -1,10 +1,13 @@
+use std::collections::{HashSet, VecDeque};
 use std::fmt::Write;
 
-use crate::codegen::llvm::types::{constrained_rounding_string, map_type_owned};
+use crate::codegen::llvm::types::{
+    INTERFACE_VALUE_TYPE, constrained_rounding_string, map_type_owned,
+};
 use crate::error::Error;
-use crate::mir::TypeLayout;
 use crate::mir::casts::{IntInfo, float_info, int_info};
 use crate::mir::{CastKind, Operand, RoundingMode, Ty};
+use crate::mir::{TraitVTable, TypeLayout};
 
 use super::super::builder::FunctionEmitter;
 use super::value_ref::ValueRef;
@@ -280,6 +283,11 @@ impl<'a> FunctionEmitter<'a> {
                 )));
             }
             CastKind::Unknown => {
+                if let Some(converted) =
+                    self.emit_interface_cast(&operand_value, source, target, &source_ty, &target_ty)
+                {
+                    return Ok(converted);
+                }
                 if source_ty == target_ty {
                     return Ok(operand_value);
                 }
@@ -343,6 +351,133 @@ impl<'a> FunctionEmitter<'a> {
         Ok(result)
     }
 
+    /// Converts between class references and interface values. Leaving an interface yields the
+    /// wrapped object; entering one pairs the object with the interface vtable of its class,
+    /// chosen from the object's class vtable when the static source type does not decide it.
+    #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+    fn emit_interface_cast(
+        &mut self,
+        value: &ValueRef,
+        source: &Ty,
+        target: &Ty,
+        source_ty: &str,
+        target_ty: &str,
+    ) -> Option<ValueRef> {
+        let source_is_interface = source_ty == INTERFACE_VALUE_TYPE;
+        let target_is_interface = target_ty == INTERFACE_VALUE_TYPE;
+        if !source_is_interface && !target_is_interface {
+            return None;
+        }
+        if source_is_interface
+            && target_is_interface
+            && source.canonical_name() == target.canonical_name()
+        {
+            return None;
+        }
+        let object = if source_is_interface {
+            let data = self.new_temp();
+            writeln!(
+                &mut self.builder,
+                "  {data} = extractvalue {INTERFACE_VALUE_TYPE} {}, 0",
+                value.repr()
+            )
+            .ok();
+            data
+        } else if source_ty == "ptr" {
+            value.repr().to_string()
+        } else {
+            return None;
+        };
+        if !target_is_interface {
+            if target_ty != "ptr" {
+                return None;
+            }
+            return Some(ValueRef::new(object, target_ty));
+        }
+
+        let resolve = |ty: &Ty| {
+            let name = ty.canonical_name();
+            self.type_layouts
+                .resolve_type_key(&name)
+                .map(str::to_string)
+                .unwrap_or(name)
+        };
+        let interface_name = resolve(target);
+        let source_name = resolve(source);
+        let static_table = (!source_is_interface)
+            .then(|| self.interface_table_for(&source_name, &interface_name))
+            .flatten();
+        let vtable = if let Some(table) = static_table {
+            format!("@{}", table.symbol)
+        } else {
+            let header = self.new_temp();
+            writeln!(&mut self.builder, "  {header} = load ptr, ptr {object}").ok();
+            let mut selected = "null".to_string();
+            for class_table in self.class_vtables {
+                let Some(table) = self.interface_table_for(&class_table.type_name, &interface_name)
+                else {
+                    continue;
+                };
+                let matches = self.new_temp();
+                writeln!(
+                    &mut self.builder,
+                    "  {matches} = icmp eq ptr {header}, @{}",
+                    class_table.symbol
+                )
+                .ok();
+                let next = self.new_temp();
+                writeln!(
+                    &mut self.builder,
+                    "  {next} = select i1 {matches}, ptr @{}, ptr {selected}",
+                    table.symbol
+                )
+                .ok();
+                selected = next;
+            }
+            selected
+        };
+        let partial = self.new_temp();
+        writeln!(
+            &mut self.builder,
+            "  {partial} = insertvalue {INTERFACE_VALUE_TYPE} undef, ptr {object}, 0"
+        )
+        .ok();
+        let pair = self.new_temp();
+        writeln!(
+            &mut self.builder,
+            "  {pair} = insertvalue {INTERFACE_VALUE_TYPE} {partial}, ptr {vtable}, 1"
+        )
+        .ok();
+        Some(ValueRef::new(pair, INTERFACE_VALUE_TYPE))
+    }
+
+    /// Interface vtable used for objects of `class`: the table of the class itself or of the
+    /// nearest base class that implements `interface`.
+    fn interface_table_for(&self, class: &str, interface: &str) -> Option<&'a TraitVTable> {
+        let trait_vtables = self.trait_vtables;
+        let mut visited = HashSet::new();
+        let mut pending = VecDeque::from([class.to_string()]);
+        while let Some(candidate) = pending.pop_front() {
+            if !visited.insert(candidate.clone()) {
+                continue;
+            }
+            if let Some(table) = trait_vtables
+                .iter()
+                .find(|table| table.trait_name == interface && table.impl_type == candidate)
+            {
+                return Some(table);
+            }
+            if let Some(info) = self.type_layouts.class_layout_info(&candidate) {
+                pending.extend(info.bases.iter().map(|base| {
+                    self.type_layouts
+                        .resolve_type_key(base)
+                        .map_or_else(|| base.clone(), str::to_string)
+                }));
+            }
+        }
+        None
+    }
+
     pub(crate) fn bitcast_value(
         &mut self,
         value: &ValueRef,
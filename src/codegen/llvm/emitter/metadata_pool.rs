@@ -1,14 +1,46 @@
+use super::debug_info::DebugInfo;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub(crate) struct MetadataId(pub(crate) usize);
 
+/// Delimits a node id allocated by a [`MetadataRegistry::fork`]; LLVM IR text escapes
+/// control characters, so the marker never occurs in emitted code.
+const LOCAL_ID_MARK: char = '\u{1}';
+
 #[derive(Default)]
 pub(crate) struct MetadataRegistry {
-    nodes: Vec<String>,
+    pub(super) nodes: Vec<String>,
+    pub(super) debug: Option<Box<DebugInfo>>,
+    /// Set on forks: ids render as placeholders until [`Self::absorb`] renumbers them.
+    local: bool,
 }
 
 impl MetadataRegistry {
     pub(crate) fn new() -> Self {
-        Self { nodes: Vec::new() }
+        Self {
+            nodes: Vec::new(),
+            debug: None,
+            local: false,
+        }
+    }
+
+    /// Empty registry for emitting one function on a worker thread, or `None` when debug
+    /// info is enabled: its type, file and location caches are shared across functions.
+    pub(crate) fn fork(&self) -> Option<Self> {
+        self.debug.is_none().then(|| Self {
+            local: true,
+            ..Self::new()
+        })
+    }
+
+    /// Append the nodes of `fork` and return `text` (emitted against the fork) with its
+    /// placeholder ids renumbered to the merged positions.
+    pub(crate) fn absorb(&mut self, fork: Self, text: &str) -> String {
+        let base = self.nodes.len();
+        for body in fork.nodes {
+            self.nodes.push(rebase_local_ids(&body, base));
+        }
+        rebase_local_ids(text, base)
     }
 
     pub(crate) fn allocate_node(&mut self, body: String) -> MetadataId {
@@ -20,7 +52,10 @@ impl MetadataRegistry {
     pub(crate) fn alias_scope_domain(&mut self, label: &str) -> MetadataId {
         let sanitized = sanitize_label(label);
         let id = self.nodes.len();
-        let body = format!("distinct !{{!{id}, !\"{sanitized}\"}}");
+        let body = format!(
+            "distinct !{{{}, !\"{sanitized}\"}}",
+            self.reference(MetadataId(id))
+        );
         self.nodes.push(body);
         MetadataId(id)
     }
@@ -28,7 +63,11 @@ impl MetadataRegistry {
     pub(crate) fn alias_scope(&mut self, domain: MetadataId, label: &str) -> MetadataId {
         let sanitized = sanitize_label(label);
         let id = self.nodes.len();
-        let body = format!("distinct !{{!{id}, !{}, !\"{sanitized}\"}}", domain.0);
+        let body = format!(
+            "distinct !{{{}, {}, !\"{sanitized}\"}}",
+            self.reference(MetadataId(id)),
+            self.reference(domain)
+        );
         self.nodes.push(body);
         MetadataId(id)
     }
@@ -42,14 +81,18 @@ impl MetadataRegistry {
             if index > 0 {
                 body.push_str(", ");
             }
-            body.push_str(&format!("!{}", scope.0));
+            body.push_str(&self.reference(*scope));
         }
         body.push('}');
         Some(self.allocate_node(body))
     }
 
     pub(crate) fn reference(&self, id: MetadataId) -> String {
-        format!("!{}", id.0)
+        if self.local {
+            format!("!{LOCAL_ID_MARK}{}{LOCAL_ID_MARK}", id.0)
+        } else {
+            format!("!{}", id.0)
+        }
     }
 
     pub(crate) fn emit(&self, out: &mut String) {
@@ -62,6 +105,19 @@ impl MetadataRegistry {
     }
 }
 
+fn rebase_local_ids(text: &str, base: usize) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut parts = text.split(LOCAL_ID_MARK);
+    out.push_str(parts.next().unwrap_or_default());
+    while let (Some(id), Some(rest)) = (parts.next(), parts.next()) {
+        match id.parse::<usize>() {
+            Ok(id) => write!(out, "{}{rest}", base + id).ok(),
+            Err(_) => write!(out, "{LOCAL_ID_MARK}{id}{LOCAL_ID_MARK}{rest}").ok(),
+        };
+    }
+    out
+}
+
 fn sanitize_label(label: &str) -> String {
     label
         .chars()
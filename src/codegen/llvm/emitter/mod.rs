@@ -1,4 +1,6 @@
+pub(crate) mod body_cache;
 mod context;
+mod debug_info;
 mod dispatch;
 mod drop_table;
 mod eq_table;
@@ -10,4 +12,6 @@ pub(crate) mod metadata_pool;
 mod module;
 mod type_metadata;
 
+#[cfg(test)]
 pub(crate) use module::emit_module;
+pub(crate) use module::emit_module_with_cache;
@@ -12,12 +12,14 @@ use crate::hash_glue::SynthesisedHashGlue;
 use crate::mir::{MirFunction, MirModule};
 use crate::runtime::startup;
 use crate::target::Target;
+use crate::threading;
 use crate::type_metadata::SynthesisedTypeMetadata;
 
 use crate::codegen::llvm::signatures::{
     DynamicExternSignature, LlvmFunctionSignature, sanitise_symbol,
 };
 
+use super::body_cache::{BodyCache, EmittedBody};
 use super::context::LlvmEmitContext;
 use super::dispatch::{
     emit_cpu_dispatch_helpers, emit_external_declarations, emit_multiversion_variants,
@@ -79,6 +81,7 @@ fn collect_async_vtable_symbols(
     Ok(symbols)
 }
 
+#[cfg(test)]
 pub(crate) fn emit_module(
     mir: &MirModule,
     global_mir: Option<&MirModule>,
@@ -93,9 +96,53 @@ pub(crate) fn emit_module(
     hash_glue: &[SynthesisedHashGlue],
     eq_glue: &[SynthesisedEqGlue],
     type_metadata: &[SynthesisedTypeMetadata],
+) -> Result<String, Error> {
+    emit_module_with_cache(
+        mir,
+        global_mir,
+        perf_metadata,
+        signatures,
+        entry,
+        kind,
+        target_triple,
+        target,
+        options,
+        drop_glue,
+        hash_glue,
+        eq_glue,
+        type_metadata,
+        None,
+    )
+}
+
+/// Emit the module's IR, reusing function bodies from `body_cache` whose MIR is unchanged.
+pub(crate) fn emit_module_with_cache(
+    mir: &MirModule,
+    global_mir: Option<&MirModule>,
+    perf_metadata: &crate::perf::PerfMetadata,
+    signatures: &HashMap<String, LlvmFunctionSignature>,
+    entry: Option<&str>,
+    kind: ChicKind,
+    target_triple: &str,
+    target: &Target,
+    options: &CodegenOptions,
+    drop_glue: &[SynthesisedDropGlue],
+    hash_glue: &[SynthesisedHashGlue],
+    eq_glue: &[SynthesisedEqGlue],
+    type_metadata: &[SynthesisedTypeMetadata],
+    body_cache: Option<&BodyCache>,
 ) -> Result<String, Error> {
     let async_vtables = collect_async_vtable_symbols(mir, signatures)?;
     let mut metadata = MetadataRegistry::new();
+    if let Some(files) = options.debug_info.as_ref() {
+        let primary = mir
+            .functions
+            .iter()
+            .filter_map(|function| function.span)
+            .map(|span| span.file_id)
+            .find(|file_id| files.get(*file_id).is_some());
+        metadata.enable_debug_info(files.clone(), primary);
+    }
     let ctx = LlvmEmitContext::new(
         mir,
         global_mir,
@@ -195,6 +242,7 @@ pub(crate) fn emit_module(
         .map(|trace| (trace.function.as_str(), trace))
         .collect();
 
+    let mut planned = Vec::with_capacity(ctx.mir.functions.len());
     for function in &ctx.mir.functions {
         let sig = ctx.signatures.get(&function.name).ok_or_else(|| {
             Error::Codegen(format!("missing LLVM signature for {}", function.name))
@@ -208,22 +256,32 @@ pub(crate) fn emit_module(
             }
             continue;
         }
+        if sig.dynamic.is_none() {
+            emitted_symbols.insert(sig.symbol.clone());
+        }
+        planned.push((function, sig));
+    }
 
+    let emit_planned = |(function, sig): &(&MirFunction, &LlvmFunctionSignature),
+                        out: &mut String,
+                        externals: &mut BTreeSet<&'static str>,
+                        metadata: &mut MetadataRegistry|
+     -> Result<bool, Error> {
         if let Some(dynamic) = sig.dynamic.as_ref() {
             externals.insert("chic_rt_ffi_resolve");
-            emit_dynamic_ffi_stub(&mut out, function, sig, dynamic, &mut externals)?;
-            writeln!(&mut out).ok();
-            continue;
+            emit_dynamic_ffi_stub(out, function, sig, dynamic, externals)?;
+            writeln!(out).ok();
+            return Ok(false);
         }
 
         if ctx.multiversion_enabled() && should_multiversion(function) {
             emit_multiversion_variants(
-                &mut out,
+                out,
                 function,
                 sig,
                 ctx.signatures,
                 trace_index.get(function.name.as_str()).copied(),
-                &mut externals,
+                externals,
                 &vtable_symbols,
                 &ctx.async_vtables,
                 ctx.mir.trait_vtables.as_slice(),
@@ -234,51 +292,92 @@ pub(crate) fn emit_module(
                 ctx.mir.statics.as_slice(),
                 &str_literals,
                 &ctx.mir.type_layouts,
-                &mut metadata,
+                metadata,
             )?;
-            emitted_multiversion = true;
-            emitted_symbols.insert(sig.symbol.clone());
+            return Ok(true);
+        }
+
+        let active_tier = isa_tiers.last().copied().unwrap_or(CpuIsaTier::Baseline);
+        let linkage = if function.name.starts_with("__cl_drop__")
+            || function.name.starts_with("__cl_clone__")
+            || function.name.starts_with("__cl_hash__")
+            || function.name.starts_with("__cl_eq__")
+        {
+            "linkonce_odr dso_local"
+        } else if function.is_local() {
+            "internal"
         } else {
-            let active_tier = isa_tiers.last().copied().unwrap_or(CpuIsaTier::Baseline);
-            let linkage = if function.name.starts_with("__cl_drop__")
-                || function.name.starts_with("__cl_clone__")
-                || function.name.starts_with("__cl_hash__")
-                || function.name.starts_with("__cl_eq__")
-            {
-                "linkonce_odr dso_local"
-            } else if function.is_local() {
-                "internal"
+            "dso_local"
+        };
+        emit_function_with_async(
+            out,
+            function,
+            sig,
+            &sig.symbol,
+            if function.is_weak {
+                "weak dso_local"
             } else {
-                "dso_local"
+                linkage
+            },
+            ctx.signatures,
+            externals,
+            &vtable_symbols,
+            &ctx.async_vtables,
+            ctx.mir.trait_vtables.as_slice(),
+            ctx.mir.class_vtables.as_slice(),
+            active_tier,
+            isa_tiers,
+            arch,
+            ctx.target,
+            ctx.mir.statics.as_slice(),
+            &str_literals,
+            &ctx.mir.type_layouts,
+            metadata,
+            trace_index.get(function.name.as_str()).copied(),
+        )?;
+        writeln!(out).ok();
+        Ok(false)
+    };
+
+    // Function bodies are independent unless debug info shares its caches across them;
+    // each worker emits into its own buffer and metadata fork (or replays a cached one),
+    // merged back in MIR order so the module text does not depend on the job count.
+    if metadata.fork().is_some() && (threading::jobs() > 1 || body_cache.is_some()) {
+        let emitted = threading::parallel_map(&planned, |planned| {
+            let tracepoint = trace_index.get(planned.0.name.as_str()).copied();
+            let key = body_cache.map(|cache| cache.key(planned.0, tracepoint));
+            if let Some(hit) = body_cache
+                .zip(key.as_deref())
+                .and_then(|(cache, key)| cache.get(key, &metadata))
+            {
+                return Ok(hit);
+            }
+            let mut body = EmittedBody {
+                text: String::new(),
+                externals: BTreeSet::new(),
+                metadata: metadata.fork().unwrap_or_default(),
+                multiversion: false,
             };
-            emit_function_with_async(
-                &mut out,
-                function,
-                sig,
-                &sig.symbol,
-                if function.is_weak {
-                    "weak dso_local"
-                } else {
-                    linkage
-                },
-                ctx.signatures,
-                &mut externals,
-                &vtable_symbols,
-                &ctx.async_vtables,
-                ctx.mir.trait_vtables.as_slice(),
-                ctx.mir.class_vtables.as_slice(),
-                active_tier,
-                isa_tiers,
-                arch,
-                ctx.target,
-                ctx.mir.statics.as_slice(),
-                &str_literals,
-                &ctx.mir.type_layouts,
-                &mut metadata,
-                trace_index.get(function.name.as_str()).copied(),
+            body.multiversion = emit_planned(
+                planned,
+                &mut body.text,
+                &mut body.externals,
+                &mut body.metadata,
             )?;
-            emitted_symbols.insert(sig.symbol.clone());
-            writeln!(&mut out).ok();
+            if let Some((cache, key)) = body_cache.zip(key) {
+                cache.insert(key, &body);
+            }
+            Ok::<_, Error>(body)
+        });
+        for result in emitted {
+            let body = result?;
+            out.push_str(&metadata.absorb(body.metadata, &body.text));
+            externals.extend(body.externals);
+            emitted_multiversion |= body.multiversion;
+        }
+    } else {
+        for planned in &planned {
+            emitted_multiversion |= emit_planned(planned, &mut out, &mut externals, &mut metadata)?;
         }
     }
 
@@ -370,6 +469,9 @@ pub(crate) fn emit_module(
     )
     .ok();
     writeln!(&mut out, "!llvm.ident = !{{!{}}}", ident_metadata.0).ok();
+    if let Some(unit) = metadata.debug_compile_unit() {
+        writeln!(&mut out, "!llvm.dbg.cu = !{{!{}}}", unit.0).ok();
+    }
     metadata.emit(&mut out);
 
     Ok(out)
@@ -1640,4 +1742,77 @@ mod tests {
             "stub should invoke resolver: {ir}"
         );
     }
+
+    fn emit_with_cache(module: &MirModule, cache: Option<&BodyCache>) -> String {
+        let mut signatures = HashMap::new();
+        for function in &module.functions {
+            signatures.insert(
+                function.name.clone(),
+                LlvmFunctionSignature {
+                    symbol: function.name.replace("::", "__"),
+                    ret: None,
+                    params: Vec::new(),
+                    param_attrs: Vec::new(),
+                    dynamic: None,
+                    c_abi: None,
+                    variadic: false,
+                    weak: false,
+                },
+            );
+        }
+        let target = Target::host();
+        emit_module_with_cache(
+            module,
+            None,
+            &PerfMetadata::default(),
+            &signatures,
+            None,
+            ChicKind::StaticLibrary,
+            target.triple(),
+            &target,
+            &CodegenOptions::default(),
+            &[],
+            &[],
+            &[],
+            &[],
+            cache,
+        )
+        .expect("emit module")
+    }
+
+    #[test]
+    fn body_cache_replays_unchanged_functions() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = BodyCache::path_for(&dir.path().join("demo.o"));
+        let mut module = MirModule::default();
+        module.functions.push(simple_function("Demo::First"));
+        module.functions.push(simple_function("Demo::Second"));
+
+        let cache = BodyCache::load(path.clone(), "ctx".into());
+        let first = emit_with_cache(&module, Some(&cache));
+        assert_eq!(cache.hits(), 0);
+        cache.store().expect("store cache");
+
+        let cache = BodyCache::load(path.clone(), "ctx".into());
+        let second = emit_with_cache(&module, Some(&cache));
+        assert_eq!(cache.hits(), 2);
+        assert_eq!(first, second);
+        cache.store().expect("store cache");
+
+        module.functions[1].body.locals.push(LocalDecl::new(
+            Some("flag".into()),
+            Ty::named("bool"),
+            true,
+            None,
+            LocalKind::Local,
+        ));
+        let cache = BodyCache::load(path.clone(), "ctx".into());
+        let edited = emit_with_cache(&module, Some(&cache));
+        assert_eq!(cache.hits(), 1, "only the unchanged function is replayed");
+        assert_eq!(edited, emit_with_cache(&module, None));
+
+        let cache = BodyCache::load(path, "other".into());
+        emit_with_cache(&module, Some(&cache));
+        assert_eq!(cache.hits(), 0, "a different module context misses");
+    }
 }
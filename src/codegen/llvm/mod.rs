@@ -18,6 +18,7 @@ use crate::chic_kind::ChicKind;
 use crate::drop_glue::SynthesisedDropGlue;
 use crate::eq_glue::SynthesisedEqGlue;
 use crate::error::Error;
+use crate::fingerprint::{self, Fingerprinter};
 use crate::frontend::ast::Module as AstModule;
 use crate::hash_glue::SynthesisedHashGlue;
 use crate::mir::MirModule;
@@ -34,7 +35,10 @@ use super::{
 };
 use std::env;
 
+use emitter::body_cache::BodyCache;
+#[cfg(test)]
 pub(crate) use emitter::emit_module;
+use emitter::emit_module_with_cache;
 use object::emit_object;
 pub(crate) use signatures::{
     build_signatures, ensure_drop_glue_signatures, ensure_eq_glue_signatures,
@@ -81,7 +85,29 @@ pub fn compile(
     ensure_hash_glue_signatures(&mut signatures, hash_glue);
     ensure_eq_glue_signatures(&mut signatures, eq_glue);
     let target_triple = canonical_toolchain_triple(target);
-    let llvm_ir = emit_module(
+    // Debug info shares metadata across function bodies, so only builds without it can
+    // replay cached bodies.
+    let body_cache_path = BodyCache::path_for(output);
+    let reuse_bodies = env::var("CHIC_DISABLE_CODEGEN_CACHE").is_err();
+    let body_cache = options
+        .mir_interface
+        .as_deref()
+        .filter(|_| reuse_bodies && options.debug_info.is_none())
+        .map(|interface| {
+            let context = body_cache_context(
+                interface,
+                mir,
+                extern_mir,
+                entry_name.as_deref(),
+                &target_triple,
+                options,
+                drop_glue,
+                hash_glue,
+                eq_glue,
+            );
+            BodyCache::load(body_cache_path.clone(), context)
+        });
+    let llvm_ir = emit_module_with_cache(
         mir,
         extern_mir,
         perf_metadata,
@@ -95,7 +121,28 @@ pub fn compile(
         hash_glue,
         eq_glue,
         type_metadata,
+        body_cache.as_ref(),
     )?;
+    match &body_cache {
+        Some(cache) => {
+            if env::var_os("CHIC_DEBUG_LLVM_BODY_CACHE").is_some() {
+                eprintln!(
+                    "[chic-debug] reused {} cached LLVM function bodies",
+                    cache.hits()
+                );
+            }
+            if let Some(parent) = body_cache_path
+                .parent()
+                .filter(|dir| !dir.as_os_str().is_empty())
+            {
+                fs::create_dir_all(parent)?;
+            }
+            cache.store()?;
+        }
+        None => {
+            let _ = fs::remove_file(&body_cache_path);
+        }
+    }
 
     if let Ok(dir) = env::var("CHIC_DUMP_LLVM_DIR") {
         let mut dump_path = PathBuf::from(dir);
@@ -213,6 +260,45 @@ pub fn compile(
     })
 }
 
+/// Fingerprint of everything a function body's IR refers to besides its own MIR: the MIR
+/// interface (signatures, layouts, statics, vtables, strings), the target, the entry point,
+/// glue symbols and the CPU ISA configuration.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Mirrors the emit_module inputs that shape function bodies."
+)]
+fn body_cache_context(
+    interface: &str,
+    mir: &MirModule,
+    extern_mir: Option<&MirModule>,
+    entry: Option<&str>,
+    target_triple: &str,
+    options: &CodegenOptions,
+    drop_glue: &[SynthesisedDropGlue],
+    hash_glue: &[SynthesisedHashGlue],
+    eq_glue: &[SynthesisedEqGlue],
+) -> String {
+    let mut context = Fingerprinter::new();
+    context
+        .str(interface)
+        .str(target_triple)
+        .value(&entry)
+        .value(&options.coverage)
+        .value(&options.sve_vector_bits)
+        .str(&format!("{:?}", options.cpu_isa));
+    if let Some(global) = extern_mir.filter(|global| !std::ptr::eq(*global, mir)) {
+        context.str(&fingerprint::interface_fingerprint(global));
+    }
+    for symbol in drop_glue
+        .iter()
+        .map(|glue| &glue.symbol)
+        .chain(hash_glue.iter().map(|glue| &glue.symbol))
+        .chain(eq_glue.iter().map(|glue| &glue.symbol))
+    {
+        context.str(symbol);
+    }
+    context.finish()
+}
 fn ensure_backend(options: &CodegenOptions) -> Result<(), Error> {
     if options.backend != Backend::Llvm {
         return Err(Error::Codegen(
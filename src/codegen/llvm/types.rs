@@ -74,6 +74,9 @@ fn int_type_for_size(size: usize) -> Result<&'static str, Error> {
     }
 }
 
+/// LLVM representation of an interface value: the object pointer and the interface vtable.
+pub(crate) const INTERFACE_VALUE_TYPE: &str = "{ ptr, ptr }";
+
 pub(crate) fn map_type_owned(
     ty: &Ty,
     layouts: Option<&TypeLayoutTable>,
@@ -101,7 +104,7 @@ pub(crate) fn map_type_owned(
                         && class_layout.size == Some(crate::mir::pointer_size())
                         && class_layout.align == Some(crate::mir::pointer_align());
                     if is_marker_interface {
-                        Some("{ ptr, ptr }".into())
+                        Some(INTERFACE_VALUE_TYPE.into())
                     } else {
                         Some("ptr".into())
                     }
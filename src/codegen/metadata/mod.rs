@@ -23,7 +23,7 @@ pub(crate) use builder::{
 #[allow(unused_imports)]
 pub(crate) use emit::{metadata_object_path, write_metadata_object};
 #[allow(unused_imports)]
-pub(crate) use populate::{MetadataCaches, MetadataTelemetry};
+pub(crate) use populate::{MetadataCaches, MetadataTelemetry, ast_metadata};
 pub(crate) use reflection::write_reflection_manifest;
 
 #[cfg(test)]
@@ -4,6 +4,7 @@ use std::fmt::Write;
 
 use crate::chic_kind::ChicKind;
 use crate::frontend::ast::Module;
+use crate::frontend::metadata::{collect_reflection_tables, serialize_reflection_tables};
 use crate::mir::{MirModule, TypeLayout, TypeLayoutTable};
 use crate::perf::PerfMetadata;
 use crate::runtime_package::RuntimeMetadata;
@@ -75,6 +76,19 @@ pub(crate) fn build_metadata_payload(
     Ok(MetadataPayload::new(header, writer))
 }
 
+/// The parts of a unit's metadata object read from its AST rather than its MIR: doc
+/// comments, inline hints and reflection descriptors.
+pub(crate) fn ast_metadata(module: &Module) -> String {
+    let mut writer = MetadataWriter::default();
+    attributes::append_doc_metadata(&mut writer, module, MetadataCaches::default().types_mut());
+    attributes::append_inline_metadata(&mut writer, module);
+    let mut text = writer.into_body();
+    if let Ok(reflection) = serialize_reflection_tables(&collect_reflection_tables(module)) {
+        text.push_str(&reflection);
+    }
+    text
+}
+
 fn append_view_metadata(payload: &mut MetadataWriter, layouts: &TypeLayoutTable) {
     for layout in layouts.types.values() {
         if let TypeLayout::Struct(struct_layout) | TypeLayout::Class(struct_layout) = layout {
@@ -5,8 +5,8 @@ use crate::frontend::ast::{
     Block, ClassDecl, ClassMember, DocComment, EnumDecl, EnumVariant, ExtensionDecl,
     ExtensionMember, ExtensionMethodDecl, ExternBinding, FieldDecl, FunctionDecl, InlineAttr,
     InterfaceDecl, InterfaceMember, Item, MemberDispatch, Module, NamespaceDecl, Signature,
-    StructDecl, TestCaseDecl, TypeExpr, UnionDecl, UnionField, UnionMember, UnionViewDecl,
-    UsingDirective, UsingKind, Visibility,
+    StructDecl, TestCaseDecl, TestCaseKind, TypeExpr, UnionDecl, UnionField, UnionMember,
+    UnionViewDecl, UsingDirective, UsingKind, Visibility,
 };
 use crate::mir::module_metadata::{Export, GlobalAllocator, StdProfile};
 use crate::mir::{
@@ -343,6 +343,7 @@ fn sample_module() -> Module {
 
     namespace.items.push(Item::TestCase(TestCaseDecl {
         name: "SampleTest".into(),
+        kind: TestCaseKind::Unit,
         signature: None,
         body: Block {
             statements: Vec::new(),
@@ -351,6 +352,7 @@ fn sample_module() -> Module {
         is_async: false,
         doc: Some(doc(&["TestDoc"])),
         attributes: Vec::new(),
+        expansion: None,
     }));
 
     namespace.items.push(Item::Import(UsingDirective {
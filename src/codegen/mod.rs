@@ -21,11 +21,14 @@ pub use text::stream::{TextStreamConfig, TextStreamMetrics, stream_module};
 use std::env;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::Arc;
 
 use crate::chic_kind::ChicKind;
+use crate::diagnostics::FileCache;
 use crate::drop_glue::SynthesisedDropGlue;
 use crate::eq_glue::SynthesisedEqGlue;
 use crate::error::Error;
+use crate::fingerprint::{self, Fingerprinter};
 use crate::frontend::ast::Module;
 use crate::hash_glue::SynthesisedHashGlue;
 use crate::mir::MirModule;
@@ -199,6 +202,12 @@ pub struct CodegenOptions {
     pub ffi_config: Option<FfiConfig>,
     pub ffi_packages: Vec<PathBuf>,
     pub runtime: Option<RuntimeMetadata>,
+    /// Emit debug info (DWARF for LLVM, a source map for WASM); MIR spans resolve
+    /// against these files.
+    pub debug_info: Option<Arc<FileCache>>,
+    /// Fingerprint of the MIR module without function bodies. When set, cached code is
+    /// keyed by MIR rather than source text, so edits that lower to the same MIR reuse it.
+    pub mir_interface: Option<String>,
 }
 
 impl Default for CodegenOptions {
@@ -219,6 +228,8 @@ impl Default for CodegenOptions {
             ffi_config: None,
             ffi_packages: Vec::new(),
             runtime: None,
+            debug_info: None,
+            mir_interface: None,
         }
     }
 }
@@ -336,9 +347,27 @@ pub fn compile_module(
         extra_hasher.update(&[0]);
     }
     let extra_fingerprint = Some(extra_hasher.finalize().to_hex().to_string());
+    let mir_fingerprint = options
+        .mir_interface
+        .as_deref()
+        .filter(|_| matches!(options.backend, Backend::Llvm))
+        .map(|interface| {
+            // The object is built from the unit's MIR; the AST only contributes the entry
+            // point and the metadata object, so those are hashed as they are emitted.
+            Fingerprinter::new()
+                .str(interface)
+                .str(&fingerprint::mir_fingerprint(
+                    mir,
+                    options.debug_info.is_some(),
+                ))
+                .value(&llvm::find_entry_function(ast))
+                .str(&metadata::ast_metadata(ast))
+                .finish()
+        });
     let fingerprint_inputs = cache::FingerprintInputs {
         source,
         textual_ir: &textual_ir,
+        mir_fingerprint: mir_fingerprint.as_deref(),
         target,
         kind,
         backend: options.backend,
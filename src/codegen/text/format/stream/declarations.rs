@@ -479,12 +479,13 @@ fn write_testcase<W: Write>(out: &mut W, test: &TestCaseDecl, indent: usize) ->
     write_doc(out, test.doc.as_ref(), indent)?;
     write_indent(out, indent)?;
     let async_kw = if test.is_async { "async " } else { "" };
-    writeln!(out, "{}testcase {} {{", async_kw, test.name)?;
+    let keyword = test.kind.keyword();
+    writeln!(out, "{}{keyword} {} {{", async_kw, test.name)?;
     for statement in &test.body.statements {
         write_statement(out, statement, indent + 1)?;
     }
     write_indent(out, indent)?;
-    writeln!(out, "}} // testcase")?;
+    writeln!(out, "}} // {keyword}")?;
     Ok(())
 }
 
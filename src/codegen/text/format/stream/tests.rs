@@ -134,6 +134,7 @@ fn writes_properties_and_constructors() {
         doc: doc(&["Ctor"]),
         span: None,
         attributes: Vec::new(),
+        name_span: None,
         di_inject: None,
     };
 
@@ -331,8 +332,8 @@ fn renders_full_module_with_docs_and_modifiers() {
         BindingModifier, Block, ClassDecl, ClassMember, DocComment, EnumDecl, EnumVariant,
         ExtensionDecl, ExtensionMember, ExtensionMethodDecl, FieldDecl, FunctionDecl,
         InterfaceDecl, InterfaceMember, Item, Module, NamespaceDecl, Parameter, Statement,
-        StatementKind, StructDecl, TestCaseDecl, TypeExpr, UnionDecl, UnionField, UnionMember,
-        UnionViewDecl, UsingDirective, UsingKind, Visibility,
+        StatementKind, StructDecl, TestCaseDecl, TestCaseKind, TypeExpr, UnionDecl, UnionField,
+        UnionMember, UnionViewDecl, UsingDirective, UsingKind, Visibility,
     };
 
     fn doc(lines: &[&str]) -> Option<DocComment> {
@@ -730,11 +731,13 @@ fn renders_full_module_with_docs_and_modifiers() {
                 }),
                 Item::TestCase(TestCaseDecl {
                     name: "Runs".into(),
+                    kind: TestCaseKind::Unit,
                     signature: None,
                     body: method_body.clone(),
                     is_async: true,
                     doc: doc(&["Testcase documentation"]),
                     attributes: Vec::new(),
+                    expansion: None,
                 }),
             ],
             doc: doc(&["Inner namespace", ""]),
@@ -778,9 +781,9 @@ fn writes_all_item_variants_and_branches() {
         ConstructorInitTarget, ConstructorInitializer, ConstructorKind, EnumDecl, EnumVariant,
         ExtensionDecl, ExtensionMember, ExtensionMethodDecl, FieldDecl, FunctionDecl,
         GenericParams, ImplDecl, ImplMember, InterfaceDecl, InterfaceMember, Item, NamespaceDecl,
-        Parameter, Signature, Statement, StatementKind, StructDecl, TestCaseDecl, TraitDecl,
-        TraitMember, TypeExpr, UnionDecl, UnionField, UnionMember, UnionViewDecl, UsingDirective,
-        UsingKind, Visibility,
+        Parameter, Signature, Statement, StatementKind, StructDecl, TestCaseDecl, TestCaseKind,
+        TraitDecl, TraitMember, TypeExpr, UnionDecl, UnionField, UnionMember, UnionViewDecl,
+        UsingDirective, UsingKind, Visibility,
     };
 
     let mut module = Module::new(Some("Pkg.Core".into()));
@@ -1074,6 +1077,7 @@ fn writes_all_item_variants_and_branches() {
                 doc: doc(&["Ctor doc"]),
                 span: None,
                 attributes: Vec::new(),
+                name_span: None,
                 di_inject: None,
             }),
             ClassMember::Field(FieldDecl {
@@ -1203,6 +1207,7 @@ fn writes_all_item_variants_and_branches() {
     module.items.push(Item::TestCase(TestCaseDecl {
         doc: doc(&["test doc"]),
         name: "Smoke".into(),
+        kind: TestCaseKind::Unit,
         signature: None,
         body: Block {
             statements: vec![Statement::new(
@@ -1213,6 +1218,7 @@ fn writes_all_item_variants_and_branches() {
         },
         is_async: false,
         attributes: Vec::new(),
+        expansion: None,
     }));
 
     let mut out = String::new();
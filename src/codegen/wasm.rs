@@ -7,7 +7,7 @@
 
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 
 use crate::chic_kind::ChicKind;
 use crate::drop_glue::SynthesisedDropGlue;
@@ -20,6 +20,7 @@ use crate::hash_glue::SynthesisedHashGlue;
 use crate::mir::{BlockId, Operand};
 use crate::mir::{GenericArg, MirFunction, MirModule, Ty, TypeLayout};
 use crate::perf::PerfMetadata;
+use crate::runtime::wasm_executor::source_map::source_map_path;
 use crate::target::Target;
 use crate::type_metadata::SynthesisedTypeMetadata;
 #[cfg(test)]
@@ -80,7 +81,7 @@ pub(crate) use layout::{
     lookup_layout,
 };
 pub(crate) use limits::ensure_u32;
-use module_builder::ModuleBuilder;
+use module_builder::{BodyCache, ModuleBuilder};
 pub(crate) use runtime_hooks::RuntimeHook;
 pub(crate) use types::{ValueType, map_type};
 
@@ -147,7 +148,7 @@ pub fn compile(
         ));
     }
 
-    let builder = ModuleBuilder::new(
+    let mut builder = ModuleBuilder::new(
         mir,
         entry,
         kind,
@@ -158,18 +159,58 @@ pub fn compile(
         perf_metadata,
         options.coverage,
     )?;
+    let source_map_path = source_map_path(output);
+    if let Some(files) = &options.debug_info {
+        let url = source_map_path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        builder = builder.with_source_map(Arc::clone(files), url);
+    }
+    let body_cache_path = BodyCache::path_for(output);
+    let reuse_bodies = std::env::var("CHIC_DISABLE_CODEGEN_CACHE").is_err();
+    if let Some(interface) = options.mir_interface.as_deref().filter(|_| reuse_bodies) {
+        builder = builder.with_body_cache(body_cache_path.clone(), interface);
+    }
     wasm_debug!(
         "compile: emitting module with {} functions (entry = {:?}, kind = {:?})",
         mir.functions.len(),
         builder.entry.as_deref().unwrap_or("<none>"),
         builder.kind
     );
-    let bytes = builder.emit()?;
+    let (bytes, source_map) = builder.emit_with_source_map()?;
+    match builder.body_cache() {
+        Some(cache) => {
+            wasm_debug!("compile: reused {} cached function bodies", cache.hits());
+            if let Some(parent) = body_cache_path
+                .parent()
+                .filter(|dir| !dir.as_os_str().is_empty())
+            {
+                fs::create_dir_all(parent)?;
+            }
+            cache.store()?;
+        }
+        None => {
+            let _ = fs::remove_file(&body_cache_path);
+        }
+    }
 
     if let Some(parent) = output.parent().filter(|dir| !dir.as_os_str().is_empty()) {
         fs::create_dir_all(parent)?;
     }
     fs::write(output, &bytes)?;
+    match source_map {
+        Some(map) => {
+            let file = output
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned());
+            fs::write(&source_map_path, map.to_json(file.as_deref()))?;
+        }
+        // Drop maps left by an earlier Debug build so they never describe the wrong module.
+        None => {
+            let _ = fs::remove_file(&source_map_path);
+        }
+    }
 
     if options.emit_wat_text {
         let wat = builder.emit_wat(&bytes)?;
@@ -28,6 +28,7 @@ impl<'a> FunctionEmitter<'a> {
     ) -> Result<(), Error> {
         self.emit_block_selector(code, idx)?;
         self.emit_block_statements(code, block)?;
+        self.record_source_location(code, Self::terminator_span(block));
         self.emit_block_terminator(code, block)?;
         emit_instruction(code, Op::End);
         Ok(())
@@ -3,6 +3,7 @@ use std::sync::OnceLock;
 use std::time::Instant;
 
 use crate::error::Error;
+use crate::frontend::diagnostics::Span;
 use crate::mir::{
     ArrayTy, BlockId, ClassVTable, EnumLayout, EnumVariantLayout, FieldLayout, FnTy, GenericArg,
     LocalKind, MirFunction, Operand, ParamMode, PointerTy, RefTy, StaticId, StaticVar, StrId,
@@ -85,6 +86,16 @@ pub(crate) fn emit_function<'a>(
         false,
         0,
     )
+    .map(|emitted| emitted.body)
+}
+
+/// A lowered function body plus the debug data for the module's name and source-map sections.
+pub(crate) struct EmittedFunction {
+    pub(crate) body: Vec<u8>,
+    /// Wasm local index and Chic name of each named argument and local.
+    pub(crate) local_names: Vec<(u32, String)>,
+    /// Body offsets where code for a new MIR span (or for no span) begins.
+    pub(crate) locations: Vec<(u32, Option<Span>)>,
 }
 
 pub(crate) fn emit_function_with_async<'a>(
@@ -106,7 +117,7 @@ pub(crate) fn emit_function_with_async<'a>(
     tracepoint: Option<&'a Tracepoint>,
     coverage_enabled: bool,
     coverage_function_index: u32,
-) -> Result<Vec<u8>, Error> {
+) -> Result<EmittedFunction, Error> {
     let mut emitter = FunctionEmitter::new_with_async(
         function,
         functions,
@@ -127,7 +138,12 @@ pub(crate) fn emit_function_with_async<'a>(
         coverage_enabled,
         coverage_function_index,
     )?;
-    emitter.emit_body()
+    let body = emitter.emit_body()?;
+    Ok(EmittedFunction {
+        body,
+        local_names: emitter.local_names(),
+        locations: std::mem::take(&mut emitter.source_locations),
+    })
 }
 
 fn empty_string_literal_map() -> &'static HashMap<StrId, WasmStrLiteral> {
@@ -229,6 +245,8 @@ pub(crate) struct FunctionEmitter<'a> {
     pub(super) coverage_function_index: u32,
     pub(super) coverage_statement_index: u32,
     pub(super) coverage_statement_indices: HashMap<usize, u32>,
+    /// Body offsets where code for a new MIR span (or for no span) begins.
+    pub(crate) source_locations: Vec<(u32, Option<Span>)>,
 }
 impl<'a> FunctionEmitter<'a> {
     #[cfg(test)]
@@ -573,6 +591,7 @@ impl<'a> FunctionEmitter<'a> {
             coverage_function_index,
             coverage_statement_index: 0,
             coverage_statement_indices,
+            source_locations: Vec::new(),
         })
     }
 
@@ -1334,11 +1353,17 @@ impl<'a> FunctionEmitter<'a> {
         self.emit_local_decls(&mut out)?;
 
         let mut code = Vec::new();
+        self.source_locations.clear();
+        self.record_source_location(&code, self.function.span);
         self.emit_prelude(&mut code)?;
         self.emit_trace_enter(&mut code)?;
         self.emit_blocks(&mut code)?;
         self.emit_epilogue(&mut code);
 
+        let code_start = ensure_u32(out.len(), "code body exceeds WebAssembly limits")?;
+        for (offset, _) in &mut self.source_locations {
+            *offset += code_start;
+        }
         out.extend_from_slice(&code);
         wasm_debug!(
             "    emit_body `{}`: finished ({} bytes) in {:?}",
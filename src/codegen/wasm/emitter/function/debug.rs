@@ -0,0 +1,55 @@
+//! Local names and source locations feeding the module's `name` and source-map sections.
+
+use std::collections::BTreeMap;
+
+use crate::frontend::diagnostics::Span;
+use crate::mir::{BasicBlock, LocalKind, StatementKind};
+
+use super::FunctionEmitter;
+
+impl<'a> FunctionEmitter<'a> {
+    /// Note that code emitted from here on belongs to `span` (or to no source).
+    pub(super) fn record_source_location(&mut self, code: &[u8], span: Option<Span>) {
+        let offset = code.len() as u32;
+        match self.source_locations.last_mut() {
+            Some((_, last)) if *last == span => {}
+            Some((last_offset, last)) if *last_offset == offset => *last = span,
+            _ => self.source_locations.push((offset, span)),
+        }
+    }
+
+    /// Storage markers carry the declaration's span; terminators belong to the last
+    /// statement that does real work.
+    pub(super) fn terminator_span(block: &BasicBlock) -> Option<Span> {
+        block
+            .statements
+            .iter()
+            .rev()
+            .find(|statement| {
+                !matches!(
+                    statement.kind,
+                    StatementKind::StorageLive(_) | StatementKind::StorageDead(_)
+                )
+            })
+            .and_then(|statement| statement.span)
+            .or(block.span)
+    }
+
+    /// Wasm local index and Chic name of each named argument and user local, by index.
+    pub(crate) fn local_names(&self) -> Vec<(u32, String)> {
+        let mut names = BTreeMap::new();
+        for (decl, slot) in self.function.body.locals.iter().zip(&self.locals) {
+            if !matches!(decl.kind, LocalKind::Arg(_) | LocalKind::Local) {
+                continue;
+            }
+            let (Some(name), Some(index)) = (decl.name.as_deref(), slot) else {
+                continue;
+            };
+            if name.is_empty() || name.starts_with("__") || name.contains('$') {
+                continue;
+            }
+            names.entry(*index).or_insert_with(|| name.to_string());
+        }
+        names.into_iter().collect()
+    }
+}
@@ -1,6 +1,7 @@
 mod blocks;
 mod borrows;
 mod builder;
+mod debug;
 mod locals;
 mod ops;
 mod runtime;
@@ -15,7 +16,7 @@ pub(crate) use builder::FunctionEmitter;
 #[allow(unused_imports)]
 pub(crate) use builder::emit_function;
 #[allow(unused_imports)]
-pub(crate) use builder::emit_function_with_async;
+pub(crate) use builder::{EmittedFunction, emit_function_with_async};
 #[allow(unused_imports)]
 pub(crate) use locals::{LocalPlan, LocalRepresentation, plan_locals};
 #[allow(unused_imports)]
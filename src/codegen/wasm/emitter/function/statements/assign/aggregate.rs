@@ -37,11 +37,65 @@ impl<'a> FunctionEmitter<'a> {
                 Ok(())
             }
             AggregateKind::Adt { name, variant } => {
-                if variant.is_some() {
-                    return Err(Error::Codegen(
-                        "enum variant aggregate assignment is not yet supported in WASM backend"
-                            .into(),
-                    ));
+                if let Some(variant) = variant {
+                    // Variants store their discriminant ahead of the payload, then assign each
+                    // payload field through a downcast of the destination place.
+                    let Some(TypeLayout::Enum(layout)) = self.layouts.types.get(name.as_str())
+                    else {
+                        return Err(Error::Codegen(format!(
+                            "missing enum layout for variant aggregate `{name}::{variant}` in WASM backend"
+                        )));
+                    };
+                    let Some(variant_layout) = layout.variants.iter().find(|v| v.name == *variant)
+                    else {
+                        return Err(Error::Codegen(format!(
+                            "enum `{name}` does not have variant `{variant}`"
+                        )));
+                    };
+                    if variant_layout.fields.len() != fields.len() {
+                        return Err(Error::Codegen(format!(
+                            "aggregate for `{name}::{variant}` provided {} fields but layout expects {}",
+                            fields.len(),
+                            variant_layout.fields.len()
+                        )));
+                    }
+                    let discriminant =
+                        i32::try_from(variant_layout.discriminant).map_err(|_| {
+                            Error::Codegen(
+                                "enum discriminant exceeds 32-bit range in WASM backend".into(),
+                            )
+                        })?;
+                    if self
+                        .layouts
+                        .size_and_align_for_ty(&layout.underlying)
+                        .map(|(size, _)| size)
+                        != Some(4)
+                    {
+                        return Err(Error::Codegen(format!(
+                            "enum `{name}` with payloads must use a 32-bit discriminant in WASM backend"
+                        )));
+                    }
+                    let variant_index = variant_layout.index;
+                    let field_indices: Vec<u32> =
+                        variant_layout.fields.iter().map(|f| f.index).collect();
+
+                    let access = self.resolve_memory_access(place)?;
+                    self.emit_pointer_expression(buf, &access)?;
+                    emit_instruction(buf, Op::I32Const(discriminant));
+                    emit_instruction(buf, Op::I32Store(0));
+
+                    for (field_index, value) in field_indices.into_iter().zip(fields.iter()) {
+                        let mut field_place = place.clone();
+                        field_place.projection.push(ProjectionElem::Downcast {
+                            variant: variant_index,
+                        });
+                        field_place
+                            .projection
+                            .push(ProjectionElem::Field(field_index));
+                        let field_rvalue = Rvalue::Use(value.clone());
+                        self.emit_assign(buf, &field_place, &field_rvalue)?;
+                    }
+                    return Ok(());
                 }
                 let Some(layout) = self.layouts.types.get(name.as_str()) else {
                     return Err(Error::Codegen(format!(
@@ -252,7 +252,12 @@ impl<'a> FunctionEmitter<'a> {
                 .copied()
         };
         match value {
-            Rvalue::Cast { operand, .. } => {
+            Rvalue::Cast {
+                operand, source, ..
+            } => {
+                if self.emit_runtime_trait_object_cast(buf, access, trait_ty, operand, source)? {
+                    return Ok(true);
+                }
                 let lowered = Rvalue::Use(operand.clone());
                 return self
                     .emit_trait_object_assignment_to_access(buf, access, trait_ty, &lowered);
@@ -872,6 +877,107 @@ impl<'a> FunctionEmitter<'a> {
         Ok(())
     }
 
+    /// Checked casts into an interface cannot rely on the static source type to pick the
+    /// implementation vtable, so the object's class vtable selects it at runtime.
+    #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+    fn emit_runtime_trait_object_cast(
+        &mut self,
+        buf: &mut Vec<u8>,
+        access: &MemoryAccess,
+        trait_ty: &Ty,
+        operand: &Operand,
+        source: &Ty,
+    ) -> Result<bool, Error> {
+        let trait_name = match trait_ty {
+            Ty::Nullable(inner) => inner.canonical_name(),
+            other => other.canonical_name(),
+        };
+        let source_name = match source {
+            Ty::Nullable(inner) => inner.canonical_name(),
+            other => other.canonical_name(),
+        };
+        if !matches!(
+            self.layouts.layout_for_name(&source_name),
+            Some(TypeLayout::Class(_))
+        ) {
+            return Ok(false);
+        }
+        let source_is_trait_object = self.ty_is_trait_object_like(source);
+        let tables = self
+            .trait_vtables
+            .iter()
+            .filter(|table| Self::names_equivalent(&table.trait_name, &trait_name))
+            .collect::<Vec<_>>();
+        let statically_known = if source_is_trait_object {
+            Self::names_equivalent(&source_name, &trait_name)
+        } else {
+            tables
+                .iter()
+                .any(|table| Self::names_equivalent(&table.impl_type, &source_name))
+        };
+        if statically_known {
+            return Ok(false);
+        }
+        let remap = tables
+            .iter()
+            .filter_map(|table| {
+                let trait_off = *self.trait_vtable_offsets.get(&table.symbol)?;
+                let class_symbol = class_vtable_symbol_name(&table.impl_type);
+                let class_off = *self.class_vtable_offsets.get(&class_symbol)?;
+                Some((class_off, trait_off))
+            })
+            .map(|(class_off, trait_off)| {
+                let convert = |offset: u32| {
+                    i32::try_from(offset).map_err(|_| {
+                        Error::Codegen("vtable offset exceeds i32 range in WASM backend".into())
+                    })
+                };
+                Ok((convert(class_off)?, convert(trait_off)?))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        if remap.is_empty() {
+            return Ok(false);
+        }
+
+        self.emit_pointer_expression(buf, access)?;
+        emit_instruction(buf, Op::LocalSet(self.temp_local));
+        let object_ty = self.emit_operand(buf, operand)?;
+        Self::ensure_operand_type(object_ty, ValueType::I32, "interface cast source")?;
+        if source_is_trait_object {
+            emit_instruction(buf, Op::I32Load(0));
+        }
+        emit_instruction(buf, Op::LocalSet(self.stack_temp_local));
+
+        // Null objects keep a zero header so no implementation table matches below.
+        emit_instruction(buf, Op::I32Const(0));
+        emit_instruction(buf, Op::LocalSet(self.scratch_local));
+        emit_instruction(buf, Op::LocalGet(self.stack_temp_local));
+        emit_instruction(buf, Op::If);
+        emit_instruction(buf, Op::LocalGet(self.stack_temp_local));
+        emit_instruction(buf, Op::I32Load(0));
+        emit_instruction(buf, Op::LocalSet(self.scratch_local));
+        emit_instruction(buf, Op::End);
+
+        emit_instruction(buf, Op::LocalGet(self.temp_local));
+        emit_instruction(buf, Op::LocalGet(self.stack_temp_local));
+        emit_instruction(buf, Op::I32Store(0));
+        emit_instruction(buf, Op::LocalGet(self.temp_local));
+        emit_instruction(buf, Op::I32Const(4));
+        emit_instruction(buf, Op::I32Add);
+        // Class vtables are distinct, so at most one `(header == class) * table` term is non-zero.
+        emit_instruction(buf, Op::I32Const(0));
+        for (class_off, trait_off) in remap {
+            emit_instruction(buf, Op::LocalGet(self.scratch_local));
+            emit_instruction(buf, Op::I32Const(class_off));
+            emit_instruction(buf, Op::I32Eq);
+            emit_instruction(buf, Op::I32Const(trait_off));
+            emit_instruction(buf, Op::I32Mul);
+            emit_instruction(buf, Op::I32Add);
+        }
+        emit_instruction(buf, Op::I32Store(0));
+        Ok(true)
+    }
+
     pub(super) fn copy_trait_object(
         &self,
         buf: &mut Vec<u8>,
@@ -20,6 +20,7 @@ impl<'a> FunctionEmitter<'a> {
                 block.id,
                 statement.kind
             );
+            self.record_source_location(code, statement.span.or(block.span));
             self.emit_statement(code, statement)?;
         }
         Ok(())
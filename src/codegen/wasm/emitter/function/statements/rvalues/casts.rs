@@ -237,6 +237,12 @@ impl<'a> FunctionEmitter<'a> {
                             value_ty, target_ty
                         )));
                     }
+                    if self.ty_is_trait_object_like(source) && !self.ty_is_trait_object_like(target)
+                    {
+                        // Interface values are `{ context, vtable }` pairs; the checked
+                        // downcast yields the object reference stored in the context slot.
+                        emit_instruction(buf, Op::I32Load(0));
+                    }
                     return Ok(target_ty);
                 }
                 Err(Error::Codegen(format!(
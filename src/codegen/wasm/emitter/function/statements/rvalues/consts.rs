@@ -152,8 +152,14 @@ impl<'a> FunctionEmitter<'a> {
                 emit_instruction(buf, Op::LocalGet(self.stack_temp_local));
                 Ok(ValueType::I32)
             }
-            ConstValue::Enum { .. }
-            | ConstValue::Struct { .. }
+            ConstValue::Enum { discriminant, .. } => {
+                let value = i32::try_from(*discriminant).map_err(|_| {
+                    Error::Codegen("enum discriminant exceeds 32-bit range in WASM backend".into())
+                })?;
+                emit_instruction(buf, Op::I32Const(value));
+                Ok(ValueType::I32)
+            }
+            ConstValue::Struct { .. }
             | ConstValue::RawStr(_)
             | ConstValue::Unit
             | ConstValue::Unknown => {
@@ -1,6 +1,6 @@
 use crate::codegen::wasm::RuntimeHook;
 use crate::error::Error;
-use crate::mir::{BinOp, ConstValue, Operand, Place, Rvalue, StrId, Ty};
+use crate::mir::{BinOp, ConstValue, Operand, Place, ProjectionElem, Rvalue, StrId, Ty};
 
 use crate::codegen::wasm::emitter::function::FunctionEmitter;
 use crate::codegen::wasm::emitter::function::LocalRepresentation;
@@ -224,6 +224,32 @@ impl<'a> FunctionEmitter<'a> {
         let hook = self.runtime_hook_index(RuntimeHook::VecClone)?;
         emit_instruction(buf, Op::Call(hook));
         emit_instruction(buf, Op::Drop);
+        self.copy_array_extents(buf, dest, src)
+    }
+
+    /// `vec_clone` only copies the `VecPtr` prefix; rectangular arrays also carry one extent per
+    /// dimension after it.
+    fn copy_array_extents(
+        &mut self,
+        buf: &mut Vec<u8>,
+        dest: &Place,
+        src: &Place,
+    ) -> Result<(), Error> {
+        let Ty::Array(array) = self.mir_place_ty(src)? else {
+            return Ok(());
+        };
+        if array.rank <= 1 {
+            return Ok(());
+        }
+        for dimension in 0..array.rank {
+            let field = ProjectionElem::FieldNamed(format!("dim{dimension}"));
+            let mut src_field = src.clone();
+            src_field.projection.push(field.clone());
+            let mut dest_field = dest.clone();
+            dest_field.projection.push(field);
+            let value_ty = self.emit_operand(buf, &Operand::Copy(src_field))?;
+            self.store_value_into_place(buf, &dest_field, value_ty)?;
+        }
         Ok(())
     }
 
@@ -9,7 +9,8 @@ use crate::drop_glue::drop_type_identity;
 use crate::error::Error;
 use crate::mir::{
     BlockId, BorrowKind, CallDispatch, ConstOperand, ConstValue, FnTy, Operand, ParamMode,
-    PendingOperandInfo, Place, StructLayout, TraitObjectDispatch, Ty, VirtualDispatch,
+    PendingOperandInfo, Place, ProjectionElem, StructLayout, TraitObjectDispatch, Ty,
+    VirtualDispatch,
 };
 use crate::syntax::numeric::NumericLiteralType;
 use std::convert::TryFrom;
@@ -246,6 +247,11 @@ impl<'a> FunctionEmitter<'a> {
                     }
                     self.initialised_borrow_locals.remove(&borrow.place.local.0);
                 }
+                // Places reached through a raw pointer (e.g. the value pointer handed to
+                // hash/eq glue) are passed by address without taking a runtime borrow.
+                Operand::Copy(place) | Operand::Move(place)
+                    if place.projection.first() == Some(&ProjectionElem::Deref)
+                        && !self.borrow_destinations.contains_key(&place.local.0) => {}
                 Operand::Copy(place) | Operand::Move(place) => {
                     let local_index = place.local.0;
                     let meta = self
@@ -1,6 +1,6 @@
 use super::super::FunctionEmitter;
 use super::super::ops::{Op, emit_instruction};
-use crate::codegen::wasm::ValueType;
+use crate::codegen::wasm::{ValueType, local_requires_memory};
 use crate::error::Error;
 use crate::mir::{
     BlockId, ClassLayoutKind, MatchArm, Pattern, Place, Ty, TypeLayout, VariantPatternFields,
@@ -33,9 +33,20 @@ impl<'a> FunctionEmitter<'a> {
                 "match discriminant must lower to i32 in WASM backend".into(),
             ));
         }
-        emit_instruction(buf, Op::LocalSet(self.temp_local));
-
         let enum_ty = self.local_tys.get(value.local.0).cloned();
+        if enum_ty
+            .as_ref()
+            .is_some_and(|ty| self.ty_is_trait_object_like(ty))
+        {
+            // Type tests on interface values inspect the object held in the context slot.
+            emit_instruction(buf, Op::I32Load(0));
+        } else if enum_ty.as_ref().is_some_and(|ty| {
+            self.lookup_enum_layout(ty).is_some() && local_requires_memory(ty, self.layouts)
+        }) {
+            // Payload enums live in memory; their discriminant is stored ahead of the payload.
+            emit_instruction(buf, Op::I32Load(0));
+        }
+        emit_instruction(buf, Op::LocalSet(self.temp_local));
 
         for arm in arms {
             if self.emit_match_arm(buf, arm, enum_ty.as_ref())? {
@@ -138,9 +149,7 @@ impl<'a> FunctionEmitter<'a> {
 
                 // Skip null values: type patterns only match non-null references.
                 emit_instruction(buf, Op::LocalGet(self.temp_local));
-                emit_instruction(buf, Op::I32Eqz);
                 emit_instruction(buf, Op::If);
-                emit_instruction(buf, Op::Else);
 
                 // Load vtable pointer from the object header.
                 emit_instruction(buf, Op::LocalGet(self.temp_local));
@@ -223,9 +232,18 @@ impl<'a> FunctionEmitter<'a> {
                     emit_instruction(buf, Op::Br(1));
                     return Ok(true);
                 };
-                if !matches!(fields, VariantPatternFields::Unit) {
+                let payload_irrefutable = match fields {
+                    VariantPatternFields::Unit => true,
+                    VariantPatternFields::Tuple(items) => {
+                        items.iter().all(Self::pattern_is_irrefutable)
+                    }
+                    VariantPatternFields::Struct(items) => items
+                        .iter()
+                        .all(|field| Self::pattern_is_irrefutable(&field.pattern)),
+                };
+                if !payload_irrefutable {
                     return Err(Error::Codegen(
-                        "enum patterns with payloads are not yet supported by the WASM backend"
+                        "refutable enum payload patterns are not yet supported by the WASM backend"
                             .into(),
                     ));
                 }
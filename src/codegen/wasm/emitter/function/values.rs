@@ -4,8 +4,8 @@ use crate::codegen::wasm::{
 };
 use crate::error::Error;
 use crate::mir::{
-    AtomicOrdering, AtomicRmwOp, EnumVariantLayout, GenericArg, LocalId, Operand, Place,
-    ProjectionElem, Ty,
+    AtomicOrdering, AtomicRmwOp, EnumLayout, EnumVariantLayout, GenericArg, LocalId, Operand,
+    Place, ProjectionElem, Ty,
 };
 
 use super::{FunctionEmitter, LocalRepresentation};
@@ -519,6 +519,13 @@ impl<'a> FunctionEmitter<'a> {
                                 ))
                             },
                         )?;
+                    offset = offset
+                        .checked_add(self.enum_payload_offset(enum_layout)?)
+                        .ok_or_else(|| {
+                            Error::Codegen(
+                                "enum payload offset overflowed while lowering to WASM".into(),
+                            )
+                        })?;
                     enum_variant = Some(variant_layout);
                     has_prefix_projection = true;
                 }
@@ -838,6 +845,20 @@ impl<'a> FunctionEmitter<'a> {
         ensure_u32(size, "char size exceeds 32-bit range in WASM backend")
     }
 
+    /// Payload fields are laid out from zero within their variant; in memory they follow the
+    /// discriminant, rounded up to the enum's alignment.
+    pub(crate) fn enum_payload_offset(&self, layout: &EnumLayout) -> Result<u32, Error> {
+        let (discr_size, _) = self
+            .layouts
+            .size_and_align_for_ty(&layout.underlying)
+            .unwrap_or((4, 4));
+        let align = layout.align.unwrap_or(discr_size).max(1);
+        ensure_u32(
+            discr_size.div_ceil(align) * align,
+            "enum payload offset exceeds 32-bit range in WASM backend",
+        )
+    }
+
     pub(crate) fn emit_pointer_expression(
         &self,
         buf: &mut Vec<u8>,
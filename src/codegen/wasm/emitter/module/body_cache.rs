@@ -0,0 +1,213 @@
+//! Function bodies kept from the previous build of a module.
+//!
+//! A body is reused when the function's MIR and every module table its code refers to
+//! (function indices, signatures, string and static offsets, vtables, layouts) match the
+//! build that emitted it. Entries not used by the current build are dropped on store.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+use crate::codegen::wasm::emitter::function::EmittedFunction;
+use crate::diagnostics::FileId;
+use crate::error::Error;
+use crate::fingerprint::{self, Fingerprinter};
+use crate::frontend::diagnostics::Span;
+use crate::mir::MirFunction;
+use crate::perf::Tracepoint;
+
+use super::builder::ModuleBuilder;
+
+const CACHE_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize, Default)]
+struct CacheFile {
+    version: u32,
+    bodies: HashMap<String, CachedBody>,
+}
+
+/// File id, start and end of a span.
+type SpanRange = (usize, usize, usize);
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedBody {
+    /// Hex-encoded body bytes.
+    body: String,
+    local_names: Vec<(u32, String)>,
+    locations: Vec<(u32, Option<SpanRange>)>,
+}
+
+pub(crate) struct BodyCache {
+    path: PathBuf,
+    /// Fingerprint of the module tables emitted code refers to.
+    context: String,
+    spans: bool,
+    previous: HashMap<String, CachedBody>,
+    current: Mutex<HashMap<String, CachedBody>>,
+    hits: AtomicUsize,
+}
+
+impl BodyCache {
+    /// The cache kept beside `output`.
+    pub(crate) fn path_for(output: &Path) -> PathBuf {
+        let mut name = output.as_os_str().to_owned();
+        name.push(".bodies.json");
+        PathBuf::from(name)
+    }
+
+    pub(crate) fn load(path: PathBuf, context: String, spans: bool) -> Self {
+        let previous = fs::read_to_string(&path)
+            .ok()
+            .and_then(|text| serde_json::from_str::<CacheFile>(&text).ok())
+            .filter(|file| file.version == CACHE_VERSION)
+            .map(|file| file.bodies)
+            .unwrap_or_default();
+        Self {
+            path,
+            context,
+            spans,
+            previous,
+            current: Mutex::new(HashMap::new()),
+            hits: AtomicUsize::new(0),
+        }
+    }
+
+    pub(crate) fn key(
+        &self,
+        function: &MirFunction,
+        function_index: u32,
+        tracepoint: Option<&Tracepoint>,
+    ) -> String {
+        Fingerprinter::new()
+            .str(&self.context)
+            .str(&fingerprint::function_fingerprint(function, self.spans))
+            .value(&function_index)
+            .value(&tracepoint)
+            .finish()
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<EmittedFunction> {
+        let cached = self.previous.get(key)?;
+        let emitted = EmittedFunction {
+            body: decode_hex(&cached.body)?,
+            local_names: cached.local_names.clone(),
+            locations: cached
+                .locations
+                .iter()
+                .map(|(offset, span)| {
+                    let span =
+                        span.map(|(file, start, end)| Span::in_file(FileId(file), start, end));
+                    (*offset, span)
+                })
+                .collect(),
+        };
+        self.current_bodies()
+            .insert(key.to_string(), cached.clone());
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Some(emitted)
+    }
+
+    pub(crate) fn insert(&self, key: String, emitted: &EmittedFunction) {
+        let cached = CachedBody {
+            body: encode_hex(&emitted.body),
+            local_names: emitted.local_names.clone(),
+            locations: emitted
+                .locations
+                .iter()
+                .map(|(offset, span)| {
+                    (
+                        *offset,
+                        span.map(|span| (span.file_id.0, span.start, span.end)),
+                    )
+                })
+                .collect(),
+        };
+        self.current_bodies().insert(key, cached);
+    }
+
+    /// Bodies reused from the previous build so far.
+    pub(crate) fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    fn current_bodies(&self) -> std::sync::MutexGuard<'_, HashMap<String, CachedBody>> {
+        self.current
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    pub(crate) fn store(&self) -> Result<(), Error> {
+        let file = CacheFile {
+            version: CACHE_VERSION,
+            bodies: self.current_bodies().clone(),
+        };
+        let text = serde_json::to_string(&file)
+            .map_err(|err| Error::internal(format!("failed to serialise body cache: {err}")))?;
+        fs::write(&self.path, text)?;
+        Ok(())
+    }
+}
+
+impl ModuleBuilder<'_> {
+    /// Reuse bodies cached at `path` whose function MIR is unchanged. `interface`
+    /// fingerprints the MIR module without function bodies.
+    pub(crate) fn with_body_cache(mut self, path: PathBuf, interface: &str) -> Self {
+        let context = Fingerprinter::new()
+            .str(interface)
+            .value(&self.coverage_enabled)
+            .value(&self.index_by_name)
+            .value(&self.function_signatures)
+            .value(&self.function_return_tys)
+            .value(&self.function_param_tys)
+            .value(&self.signature_indices)
+            .value(&self.string_literals)
+            .value(&self.trait_vtable_offsets)
+            .value(&self.class_vtable_offsets)
+            .value(&self.async_vtable_offsets)
+            .value(&self.static_offsets)
+            .finish();
+        let spans = self.source_map.is_some();
+        self.body_cache = Some(BodyCache::load(path, context, spans));
+        self
+    }
+
+    pub(crate) fn body_cache(&self) -> Option<&BodyCache> {
+        self.body_cache.as_ref()
+    }
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    use std::fmt::Write;
+    let mut text = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        let _ = write!(text, "{byte:02x}");
+    }
+    text
+}
+
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(text.get(index..index + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_round_trips() {
+        let bytes = vec![0x00, 0x0f, 0xa5, 0xff];
+        assert_eq!(encode_hex(&bytes), "000fa5ff");
+        assert_eq!(decode_hex(&encode_hex(&bytes)), Some(bytes));
+        assert_eq!(decode_hex("0g"), None);
+    }
+}
@@ -1,4 +1,6 @@
+use serde::Serialize;
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use std::time::Instant;
 
 use crate::chic_kind::ChicKind;
@@ -6,6 +8,7 @@ use crate::codegen::wasm::encoding::{push_string, write_u32};
 use crate::codegen::wasm::metadata::build_metadata_payload;
 use crate::codegen::wasm::runtime_hooks::{ALL_RUNTIME_HOOKS, RuntimeHook};
 use crate::codegen::wasm::{STACK_BASE, ValueType, WASM_MAGIC, WASM_VERSION, ensure_u32, map_type};
+use crate::diagnostics::FileCache;
 use crate::eq_glue::SynthesisedEqGlue;
 use crate::error::Error;
 use crate::frontend::parser::parse_type_expression_text;
@@ -15,13 +18,15 @@ use crate::mir::{
     AliasContract, ConstValue, LocalKind, MirFunction, MirModule, StaticId, StaticVar, StrId, Ty,
 };
 use crate::perf::{PerfMetadata, Tracepoint};
+use crate::runtime::wasm_executor::WasmSourceMap;
 use crate::type_metadata::SynthesisedTypeMetadata;
 
+use super::body_cache::BodyCache;
 use super::sections::Section;
 use super::signature::FunctionSignature;
 use super::support::{DataSegment, RuntimeImport, align_u32};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub(crate) struct WasmStrLiteral {
     pub(crate) offset: u32,
     pub(crate) len: u32,
@@ -53,6 +58,10 @@ pub(crate) struct ModuleBuilder<'a> {
     pub(super) async_vtable_offsets: HashMap<String, u32>,
     pub(super) static_offsets: HashMap<StaticId, u32>,
     pub(super) tracepoints: HashMap<String, &'a Tracepoint>,
+    /// Files MIR spans resolve against and the URL of the `.wasm.map` to reference.
+    pub(super) source_map: Option<(Arc<FileCache>, String)>,
+    /// Function bodies reused from the previous build of this module.
+    pub(super) body_cache: Option<BodyCache>,
     linear_memory_offset: u32,
 }
 
@@ -94,6 +103,8 @@ impl<'a> ModuleBuilder<'a> {
             async_vtable_offsets: HashMap::new(),
             static_offsets: HashMap::new(),
             tracepoints: HashMap::new(),
+            source_map: None,
+            body_cache: None,
             linear_memory_offset: 0,
         };
 
@@ -241,6 +252,12 @@ impl<'a> ModuleBuilder<'a> {
         Ok(builder)
     }
 
+    /// Map code offsets back to MIR spans and reference the map as `url`.
+    pub(crate) fn with_source_map(mut self, files: Arc<FileCache>, url: impl Into<String>) -> Self {
+        self.source_map = Some((files, url.into()));
+        self
+    }
+
     fn intern_signature(&mut self, sig: FunctionSignature) -> Result<u32, Error> {
         if let Some(&index) = self.signature_indices.get(&sig) {
             return Ok(index);
@@ -482,7 +499,13 @@ impl<'a> ModuleBuilder<'a> {
         &self.class_vtable_offsets
     }
 
+    #[cfg(test)]
     pub(crate) fn emit(&self) -> Result<Vec<u8>, Error> {
+        self.emit_with_source_map().map(|(bytes, _)| bytes)
+    }
+
+    /// Emit the module along with its source map when one was requested.
+    pub(crate) fn emit_with_source_map(&self) -> Result<(Vec<u8>, Option<WasmSourceMap>), Error> {
         let module_start = Instant::now();
         let mut bytes = Vec::new();
         bytes.extend_from_slice(&WASM_MAGIC);
@@ -573,8 +596,12 @@ impl<'a> ModuleBuilder<'a> {
         }
 
         let code_start = Instant::now();
-        let code_section = self.emit_code_section()?;
+        let (code_section, function_debug) = self.emit_code_section_with_debug()?;
         wasm_debug!("emit_code_section completed in {:?}", code_start.elapsed());
+        let code_payload_start = ensure_u32(
+            bytes.len() + code_section.header_len(),
+            "module size exceeds WebAssembly limits",
+        )?;
         code_section.encode_into(&mut bytes)?;
 
         if let Some(data_section) = self.emit_data_section()? {
@@ -644,8 +671,10 @@ impl<'a> ModuleBuilder<'a> {
             section.encode_into(&mut bytes)?;
         }
 
-        if let Some(section) = self.emit_function_names_section()? {
-            wasm_debug!("emit_function_names_section emitted metadata");
+        self.emit_name_section(&function_debug)?
+            .encode_into(&mut bytes)?;
+        let source_map = self.build_source_map(&function_debug, code_payload_start);
+        if let Some(section) = self.emit_source_mapping_url_section()? {
             section.encode_into(&mut bytes)?;
         }
 
@@ -654,7 +683,7 @@ impl<'a> ModuleBuilder<'a> {
             module_start.elapsed()
         );
 
-        Ok(bytes)
+        Ok((bytes, source_map))
     }
 
     pub(crate) fn emit_metadata_section(&self) -> Result<Section, Error> {
@@ -808,25 +837,6 @@ impl<'a> ModuleBuilder<'a> {
         Ok(Some(Section::new(0, payload)))
     }
 
-    pub(crate) fn emit_function_names_section(&self) -> Result<Option<Section>, Error> {
-        if self.module.functions.is_empty() {
-            return Ok(None);
-        }
-        let mut payload = Vec::new();
-        push_string(&mut payload, "chic.fn.names")?;
-        write_u32(
-            &mut payload,
-            ensure_u32(
-                self.module.functions.len(),
-                "function count exceeds WebAssembly limits",
-            )?,
-        );
-        for func in &self.module.functions {
-            push_string(&mut payload, &func.name)?;
-        }
-        Ok(Some(Section::new(0, payload)))
-    }
-
     pub(crate) fn emit_interface_defaults_section(&self) -> Result<Option<Section>, Error> {
         if self.module.interface_defaults.is_empty() {
             return Ok(None);
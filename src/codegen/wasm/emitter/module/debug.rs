@@ -0,0 +1,133 @@
+//! Standard `name` section and source maps for engines, debuggers and trap reports.
+
+use std::collections::HashMap;
+
+use crate::codegen::wasm::encoding::{push_string, write_u32};
+use crate::codegen::wasm::ensure_u32;
+use crate::diagnostics::FileId;
+use crate::error::Error;
+use crate::runtime::wasm_executor::source_map::{
+    MappedLocation, SOURCE_MAPPING_URL_SECTION, SourceMapping, WasmSourceMap,
+};
+
+use super::builder::ModuleBuilder;
+use super::sections::{FunctionDebug, Section};
+
+const FUNCTION_NAMES: u8 = 1;
+const LOCAL_NAMES: u8 = 2;
+
+impl<'a> ModuleBuilder<'a> {
+    /// The `name` custom section: import and function names, plus named locals.
+    pub(crate) fn emit_name_section(&self, functions: &[FunctionDebug]) -> Result<Section, Error> {
+        let import_count = self.imports.len();
+        let function_index = |position: usize| {
+            ensure_u32(
+                import_count + position,
+                "function index exceeds WebAssembly limits",
+            )
+        };
+
+        let mut names = Vec::new();
+        write_u32(
+            &mut names,
+            ensure_u32(
+                import_count + self.module.functions.len(),
+                "function count exceeds WebAssembly limits",
+            )?,
+        );
+        for (index, import) in self.imports.iter().enumerate() {
+            write_u32(
+                &mut names,
+                ensure_u32(index, "import index exceeds WebAssembly limits")?,
+            );
+            push_string(&mut names, &format!("{}.{}", import.module, import.name))?;
+        }
+        for (position, function) in self.module.functions.iter().enumerate() {
+            write_u32(&mut names, function_index(position)?);
+            push_string(&mut names, &function.name)?;
+        }
+
+        let named: Vec<_> = functions
+            .iter()
+            .enumerate()
+            .filter(|(_, debug)| !debug.local_names.is_empty())
+            .collect();
+        let mut locals = Vec::new();
+        write_u32(
+            &mut locals,
+            ensure_u32(named.len(), "function count exceeds WebAssembly limits")?,
+        );
+        for (position, debug) in named {
+            write_u32(&mut locals, function_index(position)?);
+            write_u32(
+                &mut locals,
+                ensure_u32(
+                    debug.local_names.len(),
+                    "local count exceeds WebAssembly limits",
+                )?,
+            );
+            for (index, name) in &debug.local_names {
+                write_u32(&mut locals, *index);
+                push_string(&mut locals, name)?;
+            }
+        }
+
+        let mut payload = Vec::new();
+        push_string(&mut payload, "name")?;
+        for (id, subsection) in [(FUNCTION_NAMES, names), (LOCAL_NAMES, locals)] {
+            payload.push(id);
+            write_u32(
+                &mut payload,
+                ensure_u32(subsection.len(), "name section exceeds WebAssembly limits")?,
+            );
+            payload.extend_from_slice(&subsection);
+        }
+        Ok(Section::new(0, payload))
+    }
+
+    /// Map the code section (whose payload starts at module byte `code_start`) back to MIR
+    /// spans, or `None` when source maps are disabled.
+    pub(crate) fn build_source_map(
+        &self,
+        functions: &[FunctionDebug],
+        code_start: u32,
+    ) -> Option<WasmSourceMap> {
+        let (files, _) = self.source_map.as_ref()?;
+        let mut map = WasmSourceMap::default();
+        let mut sources: HashMap<FileId, u32> = HashMap::new();
+        for function in functions {
+            let body_start = code_start + function.payload_offset;
+            for (offset, span) in &function.locations {
+                let location = span.and_then(|span| {
+                    let file = files.get(span.file_id)?;
+                    let position = file.line_col(span.start)?;
+                    let source = *sources.entry(span.file_id).or_insert_with(|| {
+                        map.sources.push(file.path.display().to_string());
+                        map.sources.len() as u32 - 1
+                    });
+                    Some(MappedLocation {
+                        source,
+                        line: position.line as u32,
+                        column: position.column as u32,
+                    })
+                });
+                map.mappings.push(SourceMapping {
+                    offset: body_start + offset,
+                    location,
+                });
+            }
+        }
+        Some(map)
+    }
+
+    /// The `sourceMappingURL` custom section pointing engines at the `.wasm.map` file.
+    pub(crate) fn emit_source_mapping_url_section(&self) -> Result<Option<Section>, Error> {
+        let Some((_, url)) = &self.source_map else {
+            return Ok(None);
+        };
+        let mut payload = Vec::new();
+        push_string(&mut payload, SOURCE_MAPPING_URL_SECTION)?;
+        push_string(&mut payload, url)?;
+        Ok(Some(Section::new(0, payload)))
+    }
+}
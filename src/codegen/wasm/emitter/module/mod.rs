@@ -1,9 +1,12 @@
+mod body_cache;
 mod builder;
+mod debug;
 mod sections;
 mod signature;
 mod support;
 mod wat;
 
+pub(crate) use body_cache::BodyCache;
 pub(crate) use builder::{ModuleBuilder, WasmStrLiteral};
 #[cfg(test)]
 pub(crate) use sections::Section;
@@ -3,11 +3,14 @@ use std::time::Instant;
 
 use crate::chic_kind::ChicKind;
 use crate::codegen::wasm::{
-    LINEAR_MEMORY_MIN_PAGES, STACK_BASE, ValueType, emitter::function::emit_function_with_async,
+    LINEAR_MEMORY_MIN_PAGES, STACK_BASE, ValueType,
+    emitter::function::{EmittedFunction, emit_function_with_async},
     ensure_u32, push_i32_const_expr, push_string, write_u32,
 };
 use crate::error::Error;
-use crate::mir::FunctionKind;
+use crate::frontend::diagnostics::Span;
+use crate::mir::{FunctionKind, MirFunction};
+use crate::threading;
 
 use super::builder::ModuleBuilder;
 use super::signature::ensure_supported_function;
@@ -33,6 +36,13 @@ impl Section {
         &self.payload
     }
 
+    /// Bytes preceding the payload once encoded (id plus LEB128 size).
+    pub(crate) fn header_len(&self) -> usize {
+        let mut size = Vec::new();
+        write_u32(&mut size, self.payload.len() as u32);
+        1 + size.len()
+    }
+
     pub(crate) fn encode_into(&self, out: &mut Vec<u8>) -> Result<(), Error> {
         out.push(self.id);
         let mut temp = Vec::new();
@@ -47,6 +57,15 @@ impl Section {
     }
 }
 
+/// Debug data gathered while lowering one function body.
+pub(crate) struct FunctionDebug {
+    /// Offset of the body within the code section payload.
+    pub(crate) payload_offset: u32,
+    pub(crate) local_names: Vec<(u32, String)>,
+    /// Body offsets where code for a new MIR span (or for no span) begins.
+    pub(crate) locations: Vec<(u32, Option<Span>)>,
+}
+
 pub(super) struct ExportEntry {
     pub(super) name: String,
     pub(super) kind: u8,
@@ -249,7 +268,16 @@ impl<'a> ModuleBuilder<'a> {
         Ok(Some(Section::new(9, payload)))
     }
 
+    #[cfg(test)]
     pub(crate) fn emit_code_section(&self) -> Result<Section, Error> {
+        self.emit_code_section_with_debug()
+            .map(|(section, _)| section)
+    }
+
+    /// Lower every function body, also returning the debug data each one produced.
+    pub(crate) fn emit_code_section_with_debug(
+        &self,
+    ) -> Result<(Section, Vec<FunctionDebug>), Error> {
         wasm_debug!(
             "emit_code_section: lowering {} functions",
             self.module.functions.len()
@@ -262,57 +290,98 @@ impl<'a> ModuleBuilder<'a> {
                 "function count exceeds WebAssembly limits",
             )?,
         );
-        for (function_index, function) in self.module.functions.iter().enumerate() {
-            let fn_start = Instant::now();
-            wasm_debug!("  function `{}`: ensure supported", function.name);
-            ensure_supported_function(function)?;
-            let emit_body_start = Instant::now();
-            let tracepoint = self.tracepoints.get(function.name.as_str()).copied();
-            let body = emit_function_with_async(
-                function,
-                &self.index_by_name,
-                &self.function_return_tys,
-                Some(&self.function_param_tys),
-                &self.module.type_layouts,
-                Some(&self.string_literals),
-                Some(&self.trait_vtable_offsets),
-                Some(&self.class_vtable_offsets),
-                Some(&self.async_vtable_offsets),
-                Some(&self.signature_indices),
-                Some(&self.function_signatures),
-                &self.module.trait_vtables,
-                &self.module.class_vtables,
-                Some(&self.module.statics),
-                Some(&self.static_offsets),
-                tracepoint,
-                self.coverage_enabled,
-                ensure_u32(
-                    function_index,
-                    "coverage function index exceeds wasm limits",
-                )?,
-            )?;
-            wasm_debug!(
-                "  function `{}`: emit_body completed in {:?}",
-                function.name,
-                emit_body_start.elapsed()
-            );
+        let functions = self.module.functions.iter().enumerate().collect::<Vec<_>>();
+        let bodies = threading::parallel_map(
+            &functions,
+            |&(function_index, function)| -> Result<EmittedFunction, Error> {
+                let emit_body_start = Instant::now();
+                wasm_debug!("  function `{}`: ensure supported", function.name);
+                ensure_supported_function(function)?;
+                let emitted = self.emit_code_body(function_index, function)?;
+                wasm_debug!(
+                    "  function `{}`: emit_body completed in {:?}",
+                    function.name,
+                    emit_body_start.elapsed()
+                );
+                Ok(emitted)
+            },
+        );
+        let mut debug = Vec::with_capacity(self.module.functions.len());
+        for (function, emitted) in self.module.functions.iter().zip(bodies) {
+            let EmittedFunction {
+                body,
+                local_names,
+                locations,
+            } = emitted?;
             write_u32(
                 &mut payload,
                 ensure_u32(body.len(), "code body exceeds WebAssembly limits")?,
             );
+            debug.push(FunctionDebug {
+                payload_offset: ensure_u32(
+                    payload.len(),
+                    "code section exceeds WebAssembly limits",
+                )?,
+                local_names,
+                locations,
+            });
             payload.extend_from_slice(&body);
             wasm_debug!(
                 "  function `{}`: body emitted ({} bytes)",
                 function.name,
                 body.len()
             );
-            wasm_debug!(
-                "  function `{}`: total lowering time {:?}",
-                function.name,
-                fn_start.elapsed()
-            );
         }
-        Ok(Section::new(10, payload))
+        Ok((Section::new(10, payload), debug))
+    }
+
+    /// Emit one function body, reusing the cached body when its inputs are unchanged.
+    fn emit_code_body(
+        &self,
+        function_index: usize,
+        function: &MirFunction,
+    ) -> Result<EmittedFunction, Error> {
+        let tracepoint = self.tracepoints.get(function.name.as_str()).copied();
+        let index = ensure_u32(
+            function_index,
+            "coverage function index exceeds wasm limits",
+        )?;
+        let cache_key = self
+            .body_cache
+            .as_ref()
+            .map(|cache| cache.key(function, index, tracepoint));
+        if let Some(cached) = self
+            .body_cache
+            .as_ref()
+            .zip(cache_key.as_deref())
+            .and_then(|(cache, key)| cache.get(key))
+        {
+            return Ok(cached);
+        }
+        let emitted = emit_function_with_async(
+            function,
+            &self.index_by_name,
+            &self.function_return_tys,
+            Some(&self.function_param_tys),
+            &self.module.type_layouts,
+            Some(&self.string_literals),
+            Some(&self.trait_vtable_offsets),
+            Some(&self.class_vtable_offsets),
+            Some(&self.async_vtable_offsets),
+            Some(&self.signature_indices),
+            Some(&self.function_signatures),
+            &self.module.trait_vtables,
+            &self.module.class_vtables,
+            Some(&self.module.statics),
+            Some(&self.static_offsets),
+            tracepoint,
+            self.coverage_enabled,
+            index,
+        )?;
+        if let (Some(cache), Some(key)) = (&self.body_cache, cache_key) {
+            cache.insert(key, &emitted);
+        }
+        Ok(emitted)
     }
 
     pub(crate) fn emit_data_section(&self) -> Result<Option<Section>, Error> {
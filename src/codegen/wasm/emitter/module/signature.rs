@@ -1,8 +1,9 @@
 use crate::codegen::wasm::{ValueType, local_requires_memory, map_type};
 use crate::error::Error;
 use crate::mir::{FnTy, LocalKind, MirFunction, ParamMode, Terminator, Ty, TypeLayoutTable};
+use serde::Serialize;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 pub(crate) struct FunctionSignature {
     pub(crate) params: Vec<crate::codegen::wasm::ValueType>,
     pub(crate) results: Vec<crate::codegen::wasm::ValueType>,
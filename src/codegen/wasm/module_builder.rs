@@ -1,5 +1,5 @@
 #[cfg(test)]
 pub(crate) use crate::codegen::wasm::emitter::module::Section;
 pub(crate) use crate::codegen::wasm::emitter::module::{
-    FunctionSignature, ModuleBuilder, WasmStrLiteral,
+    BodyCache, FunctionSignature, ModuleBuilder, WasmStrLiteral,
 };
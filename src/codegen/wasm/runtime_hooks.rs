@@ -39,6 +39,7 @@ pub(crate) enum RuntimeHook {
     TraceExit,
     TraceFlush,
     CoverageHit,
+    TestSnapshot,
     Alloc,
     AllocZeroed,
     Realloc,
@@ -277,6 +278,7 @@ pub(crate) const ALL_RUNTIME_HOOKS: &[RuntimeHook] = &[
     RuntimeHook::TraceExit,
     RuntimeHook::TraceFlush,
     RuntimeHook::CoverageHit,
+    RuntimeHook::TestSnapshot,
     RuntimeHook::Alloc,
     RuntimeHook::AllocZeroed,
     RuntimeHook::Realloc,
@@ -527,6 +529,7 @@ impl RuntimeHook {
             RuntimeHook::TraceExit => "trace_exit",
             RuntimeHook::TraceFlush => "trace_flush",
             RuntimeHook::CoverageHit => "coverage_hit",
+            RuntimeHook::TestSnapshot => "test_snapshot",
             RuntimeHook::Alloc => "alloc",
             RuntimeHook::AllocZeroed => "alloc_zeroed",
             RuntimeHook::Realloc => "realloc",
@@ -777,6 +780,12 @@ impl RuntimeHook {
             RuntimeHook::TraceExit => vec![ValueType::I64],
             RuntimeHook::TraceFlush => vec![ValueType::I32, ValueType::I64],
             RuntimeHook::CoverageHit => vec![ValueType::I64],
+            RuntimeHook::TestSnapshot => vec![
+                ValueType::I32,
+                ValueType::I32,
+                ValueType::I32,
+                ValueType::I32,
+            ],
             RuntimeHook::Alloc | RuntimeHook::AllocZeroed => {
                 vec![ValueType::I32, ValueType::I32, ValueType::I32]
             }
@@ -257,7 +257,7 @@ pub(crate) fn enum_layout_table() -> TypeLayoutTable {
         }),
         explicit_underlying: false,
         variants: vec![variant],
-        size: Some(4),
+        size: Some(8),
         align: Some(4),
         auto_traits: AutoTraitSet::all_yes(),
         overrides: AutoTraitOverride::default(),
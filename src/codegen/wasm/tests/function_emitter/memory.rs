@@ -259,6 +259,40 @@ fn compute_projection_offset_sets_vec_index_for_arrays() {
     );
 }
 
+#[test]
+fn compute_projection_offset_resolves_rectangular_extents_and_flat_elements() {
+    let (mut layouts, function) = array_index_fixture();
+    let grid = crate::mir::ArrayTy::new(Box::new(Ty::named("int")), 2);
+    layouts.ensure_array_layout(&grid);
+    let grid_ty = Ty::Array(grid);
+    with_emitter_using_layouts(
+        layouts,
+        function,
+        |_| None,
+        |emitter| {
+            let dim0 = emitter
+                .compute_projection_offset(&grid_ty, &[ProjectionElem::FieldNamed("dim0".into())])
+                .expect("dim0 projection");
+            let dim1 = emitter
+                .compute_projection_offset(&grid_ty, &[ProjectionElem::FieldNamed("dim1".into())])
+                .expect("dim1 projection");
+            assert_eq!(dim0.value_ty.canonical_name(), "usize");
+            assert!(
+                dim1.offset > dim0.offset,
+                "extents should be laid out in dimension order"
+            );
+            let element = emitter
+                .compute_projection_offset(&grid_ty, &[ProjectionElem::Index(LocalId(2))])
+                .expect("element projection");
+            assert_eq!(
+                element.value_ty.canonical_name(),
+                "int",
+                "flattened indexing should yield the element type"
+            );
+        },
+    );
+}
+
 #[test]
 fn compute_projection_offset_sets_vec_index_for_strings() {
     let (layouts, function) = string_index_fixture();
@@ -337,8 +371,8 @@ fn compute_projection_offset_supports_enum_variant_field() {
                 .compute_projection_offset(&base_ty, &projection)
                 .expect("enum projection lowering should succeed");
             assert_eq!(
-                plan.offset, 0,
-                "enum variant fields should be positioned at offset zero in this fixture"
+                plan.offset, 4,
+                "enum variant fields should follow the 32-bit discriminant"
             );
             assert_eq!(
                 plan.value_ty,
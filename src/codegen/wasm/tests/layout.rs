@@ -99,7 +99,7 @@ fn compute_aggregate_allocation_supports_all_layouts() {
     let enum_layouts = enum_layout_table();
     let enum_alloc = super::compute_aggregate_allocation(&Ty::named("Demo::Choice"), &enum_layouts)
         .expect("enum allocation");
-    assert_eq!(enum_alloc.size, 4);
+    assert_eq!(enum_alloc.size, 8);
     assert_eq!(enum_alloc.align, 4);
 
     let union_layouts = union_layout_table();
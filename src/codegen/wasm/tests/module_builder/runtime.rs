@@ -2,8 +2,11 @@
 
 use super::super::common::*;
 use crate::chic_kind::ChicKind;
+use crate::codegen::wasm::module_builder::ModuleBuilder;
 use crate::codegen::wasm::test_emit_module;
+use crate::perf::PerfMetadata;
 use crate::runtime::wasm_executor::execute_wasm;
+use crate::type_metadata::synthesise_type_metadata;
 
 #[test]
 fn module_builder_guarded_match_executes_true_branch() {
@@ -71,3 +74,130 @@ fn module_builder_emits_borrow_lowering() {
         "borrow lowering should succeed and emit a non-empty wasm module"
     );
 }
+
+const REFERENCE_CAST_PROGRAM: &str = r#"
+namespace Casts;
+
+public interface IShape { int Sides(); }
+
+public class Animal { public virtual int Speak() { return 1; } }
+
+public class Dog : Animal, IShape
+{
+    public override int Speak() { return 2; }
+    public int Sides() { return 4; }
+}
+
+public class Cat : Animal { public override int Speak() { return 3; } }
+
+public Animal Adopt(bool dog)
+{
+    if (dog) { return new Dog(); }
+    return new Cat();
+}
+
+public int Main()
+{
+    let dog = Adopt(true);
+    let cat = Adopt(false);
+    if (!(dog is Dog named) || named.Sides() != 4) { return 11; }
+    if (cat is Dog) { return 12; }
+    if (cat is IShape) { return 13; }
+    if (dog is IShape shape)
+    {
+        if (shape.Sides() != 4) { return 14; }
+        let back = (Dog)shape;
+        if (back.Speak() != 2) { return 15; }
+    }
+    else
+    {
+        return 16;
+    }
+    let checkedDog = (Dog)dog;
+    return checkedDog.Sides() - 4;
+}
+"#;
+
+#[test]
+fn module_builder_reference_casts_test_class_vtables() {
+    let bytes = emit_casts_module(REFERENCE_CAST_PROGRAM);
+    let outcome = execute_wasm(&bytes, "chic_main").expect("reference casts should execute");
+    assert_eq!(outcome.exit_code, 0, "unexpected cast outcome");
+}
+
+const FAILED_CAST_PROGRAM: &str = r#"
+namespace Std
+{
+    public class Exception
+    {
+        public string Message;
+        public init(string message) { Message = message; }
+    }
+    public class InvalidCastException : Exception
+    {
+        public init(str message) : base(message) { }
+    }
+}
+
+namespace Casts
+{
+    public class Animal { }
+    public class Dog : Animal { }
+    public class Cat : Animal { }
+
+    public Animal Adopt() { return new Cat(); }
+
+    public int Main()
+    {
+        try
+        {
+            let dog = (Dog)Adopt();
+            return 1;
+        }
+        catch (Std.InvalidCastException)
+        {
+            return 0;
+        }
+        catch (Std.Exception)
+        {
+            return 2;
+        }
+    }
+}
+"#;
+
+#[test]
+fn module_builder_failed_reference_cast_throws_invalid_cast_exception() {
+    let bytes = emit_casts_module(FAILED_CAST_PROGRAM);
+    let outcome = execute_wasm(&bytes, "chic_main").expect("failed cast should be catchable");
+    assert_eq!(
+        outcome.exit_code, 0,
+        "casting a Cat to Dog should throw InvalidCastException"
+    );
+}
+
+fn emit_casts_module(source: &str) -> Vec<u8> {
+    let parsed = crate::frontend::parser::parse_module(source).expect("parse");
+    let lowering = crate::mir::lower_module(&parsed.module);
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "lowering diagnostics: {:?}",
+        lowering.diagnostics
+    );
+    let module = lowering.module;
+    let type_metadata = synthesise_type_metadata(&module, &[]);
+    let perf = PerfMetadata::default();
+    ModuleBuilder::new(
+        &module,
+        Some("Casts::Main".into()),
+        ChicKind::Executable,
+        &type_metadata,
+        &[],
+        &[],
+        None,
+        &perf,
+        false,
+    )
+    .and_then(|builder| builder.emit())
+    .expect("emit module")
+}
@@ -7,4 +7,5 @@ mod hints;
 mod imports;
 mod interface_defaults;
 mod metadata;
+mod names;
 mod types;
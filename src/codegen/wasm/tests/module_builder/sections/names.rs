@@ -0,0 +1,70 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use crate::chic_kind::ChicKind;
+use crate::codegen::wasm::tests::common::*;
+use crate::diagnostics::{FileCache, Span};
+use crate::mir::*;
+use crate::runtime::wasm_executor::parser::parse_module;
+
+fn entry_with_named_local() -> MirFunction {
+    let mut function = simple_function("Demo::Entry", FunctionKind::Function, Ty::Unit);
+    function.body.locals.push(LocalDecl::new(
+        Some("count".into()),
+        Ty::named("int"),
+        false,
+        None,
+        LocalKind::Local,
+    ));
+    function
+}
+
+#[test]
+fn name_section_records_function_and_local_names() {
+    let harness =
+        WasmFunctionHarness::from_module(module_with_functions(vec![entry_with_named_local()]));
+    let bytes = harness
+        .emit_module(None, ChicKind::StaticLibrary)
+        .expect("emit module");
+    let module = parse_module(&bytes).expect("parse module");
+    let index = module.imports.len() as u32;
+    assert_eq!(module.function_name(index), Some("Demo::Entry"));
+    // A unit return needs no slot, so `count` is the first wasm local.
+    assert_eq!(module.local_name(index, 0), Some("count"));
+    assert!(module.debug.source_mapping_url.is_none());
+}
+
+#[test]
+fn source_map_points_code_offsets_at_mir_spans() {
+    let mut files = FileCache::default();
+    let file_id = files.add_file(
+        "src/demo.ch",
+        "namespace Demo;\n\npublic void Entry() {\n}\n",
+    );
+    let mut function = entry_with_named_local();
+    function.span = Some(Span::in_file(file_id, 17, 40));
+    function.body.blocks[0].span = Some(Span::in_file(file_id, 39, 40));
+
+    let harness = WasmFunctionHarness::from_module(module_with_functions(vec![function]));
+    let builder = harness
+        .module_builder(None, ChicKind::StaticLibrary)
+        .expect("builder")
+        .with_source_map(Arc::new(files), "demo.wasm.map");
+    let (bytes, map) = builder.emit_with_source_map().expect("emit module");
+    let map = map.expect("source map requested");
+    assert_eq!(map.sources, vec!["src/demo.ch".to_string()]);
+
+    let module = parse_module(&bytes).expect("parse module");
+    assert_eq!(
+        module.debug.source_mapping_url.as_deref(),
+        Some("demo.wasm.map")
+    );
+    let index = module.imports.len() as u32;
+    let last = module.functions[0].code.len() - 1;
+    let return_offset = module
+        .instruction_offset(index, last)
+        .expect("instruction offset");
+    let location = map.lookup(return_offset).expect("mapped return");
+    assert_eq!(location.to_string(), "src/demo.ch:4:1");
+}
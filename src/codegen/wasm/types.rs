@@ -1,6 +1,7 @@
 use crate::mir::Ty;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub(crate) enum ValueType {
     I32,
     I64,
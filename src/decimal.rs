@@ -119,6 +119,12 @@ impl DecimalRoundingMode {
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Decimal128(Decimal);
 
+impl serde::Serialize for Decimal128 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u128(self.to_encoding())
+    }
+}
+
 impl Decimal128 {
     pub fn new(decimal: Decimal) -> Result<Self, DecimalError> {
         if decimal.scale() > DECIMAL_MAX_SCALE {
@@ -3,20 +3,21 @@ use crate::frontend::ast::{
     Attribute, ClassDecl, ClassMember, ConstructorDecl, Module, PropertyDecl,
 };
 use crate::frontend::diagnostics::Span;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct DiManifest {
     pub services: Vec<DiService>,
     pub modules: Vec<DiModule>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct DiModule {
     pub name: String,
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct DiService {
     pub name: String,
     pub lifetime: DiLifetime,
@@ -25,7 +26,7 @@ pub struct DiService {
     pub dependencies: Vec<DiDependency>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct DiDependency {
     pub target: String,
     pub optional: bool,
@@ -35,7 +36,7 @@ pub struct DiDependency {
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum DiInjectionSite {
     ConstructorParameter {
         constructor: String,
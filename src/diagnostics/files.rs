@@ -1,7 +1,8 @@
+use serde::Serialize;
 use std::path::{Path, PathBuf};
 
 /// Identifier for source files used when formatting diagnostics.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
 pub struct FileId(pub usize);
 
 impl FileId {
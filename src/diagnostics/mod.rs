@@ -12,7 +12,7 @@ use serde::Serialize;
 use std::fmt;
 
 /// Span into a source file (byte offsets).
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
 pub struct Span {
     pub file_id: FileId,
     pub start: usize,
@@ -0,0 +1,295 @@
+//! Runner support for `benchcase` declarations (`chic bench`).
+//!
+//! Each benchcase gets its own wasm instance and is timed in batches of iterations. Warm-up
+//! doubles the batch size until the warm-up budget is spent, which also estimates the cost of
+//! one iteration; sampling then sizes batches so `max_samples` of them fill the measurement
+//! budget, and stops early once the 95% confidence interval of the mean is within
+//! [`TARGET_PRECISION`] of it.
+
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::mir::TestCaseMetadata;
+use crate::runtime::{WasmExecutionOptions, WasmProgram};
+
+use super::types::{BenchOptions, BenchResult, BenchStats, TestOptions};
+use super::wasm::{
+    WASM_TEST_THREAD_STACK_SIZE, case_execution_options, declared_within_roots,
+    evaluate_wasm_testcase_result, export_candidates, should_skip_for_executor,
+};
+use super::{FrontendReport, TestStatus};
+
+/// Samples always collected before sampling may stop, unless `max_samples` is lower.
+const MIN_SAMPLES: usize = 10;
+/// Relative half-width of the confidence interval at which sampling stops early.
+const TARGET_PRECISION: f64 = 0.01;
+
+/// Time every selected benchcase in `report`, one after another.
+///
+/// Returns the results together with the number of benchcases discovered and filtered out.
+pub(crate) fn collect_wasm_benchmarks(
+    report: &FrontendReport,
+    program_bytes: &[u8],
+    options: &WasmExecutionOptions,
+    test_options: &TestOptions,
+    bench: &BenchOptions,
+    allowed_roots: Option<&[PathBuf]>,
+) -> (Vec<BenchResult>, usize, usize) {
+    let mut all_metadata: Vec<_> = crate::mir::collect_test_metadata(&report.mir_module)
+        .into_iter()
+        .filter(|meta| meta.kind.is_bench())
+        .collect();
+    all_metadata.sort_by(|a, b| a.qualified_name.cmp(&b.qualified_name));
+    let discovered = all_metadata.len();
+    let selected: Vec<_> = all_metadata
+        .into_iter()
+        .filter(|meta| {
+            test_options.selection.matches(meta)
+                && !should_skip_for_executor(meta, true)
+                && declared_within_roots(report, meta, allowed_roots)
+        })
+        .collect();
+    let filtered_out = discovered - selected.len();
+    if selected.is_empty() {
+        return (Vec::new(), discovered, filtered_out);
+    }
+
+    let bytes = program_bytes.to_vec();
+    let module = report.mir_module.clone();
+    let options = options.clone();
+    let bench = bench.clone();
+    let (tx, rx) = mpsc::channel();
+    // Benchcases run sequentially on one worker so they do not perturb each other's timings.
+    let _ = thread::Builder::new()
+        .name("wasm-bench-worker".into())
+        .stack_size(WASM_TEST_THREAD_STACK_SIZE)
+        .spawn(move || {
+            let program = WasmProgram::from_bytes(&bytes);
+            let results = selected
+                .iter()
+                .map(|meta| match &program {
+                    Ok(program) => run_benchcase(meta, program, &module, &options, &bench),
+                    Err(err) => failed(meta, err.message.clone()),
+                })
+                .collect::<Vec<_>>();
+            let _ = tx.send(results);
+        });
+    let results = rx.recv().unwrap_or_default();
+    (results, discovered, filtered_out)
+}
+
+fn run_benchcase(
+    meta: &TestCaseMetadata,
+    program: &WasmProgram,
+    module: &crate::mir::MirModule,
+    options: &WasmExecutionOptions,
+    bench: &BenchOptions,
+) -> BenchResult {
+    if meta.runner_parameters().next().is_some() {
+        return BenchResult {
+            status: TestStatus::Skipped,
+            message: Some("parameters need a @fixture, @cases row or default value".into()),
+            ..failed(meta, String::new())
+        };
+    }
+    let Some(function) = module.functions.get(meta.function_index) else {
+        return failed(
+            meta,
+            format!(
+                "benchcase metadata refers to missing function index {}",
+                meta.function_index
+            ),
+        );
+    };
+    let Some(export) = export_candidates(meta)
+        .into_iter()
+        .find(|export| program.has_export(export))
+    else {
+        return failed(meta, "benchcase is not exported in wasm artifact".into());
+    };
+    let case_options = case_execution_options(function, &module.type_layouts, options);
+    let run_batch = |iterations: u64| {
+        let (elapsed, value) = program
+            .execute_export_repeated(&export, &[], &case_options, iterations)
+            .map_err(|err| err.message)?;
+        match evaluate_wasm_testcase_result(&function.signature.ret, value) {
+            (TestStatus::Failed, message) => {
+                Err(message.unwrap_or_else(|| "benchcase failed".into()))
+            }
+            _ => Ok(elapsed),
+        }
+    };
+    match sample(bench, run_batch) {
+        Ok(stats) => BenchResult {
+            status: TestStatus::Passed,
+            message: None,
+            stats: Some(stats),
+            ..failed(meta, String::new())
+        },
+        Err(message) => failed(meta, message),
+    }
+}
+
+fn failed(meta: &TestCaseMetadata, message: String) -> BenchResult {
+    BenchResult {
+        id: meta.id.clone(),
+        name: meta.name.clone(),
+        qualified_name: meta.qualified_name.clone(),
+        namespace: meta.namespace.clone(),
+        status: TestStatus::Failed,
+        message: Some(message),
+        stats: None,
+    }
+}
+
+/// Warm up, then sample `run_batch` (which times the given number of iterations) until the
+/// measurement budget is spent, `max_samples` is reached or the estimate is precise enough.
+pub(crate) fn sample(
+    bench: &BenchOptions,
+    mut run_batch: impl FnMut(u64) -> Result<Duration, String>,
+) -> Result<BenchStats, String> {
+    let mut iterations = 1u64;
+    let mut warmed = Duration::ZERO;
+    let per_iteration_ns = loop {
+        let elapsed = run_batch(iterations)?;
+        warmed += elapsed;
+        if warmed >= bench.warmup || iterations >= 1 << 40 {
+            break (elapsed.as_nanos() as f64 / iterations as f64).max(1.0);
+        }
+        iterations *= 2;
+    };
+
+    let max_samples = bench.max_samples.max(1);
+    let min_samples = MIN_SAMPLES.min(max_samples);
+    let sample_ns = bench.measurement.as_nanos() as f64 / max_samples as f64;
+    let iterations = (sample_ns / per_iteration_ns).ceil().max(1.0) as u64;
+    let mut samples = Vec::with_capacity(max_samples);
+    let mut measured = Duration::ZERO;
+    while samples.len() < max_samples {
+        let elapsed = run_batch(iterations)?;
+        measured += elapsed;
+        samples.push(elapsed.as_nanos() as f64 / iterations as f64);
+        if samples.len() < min_samples {
+            continue;
+        }
+        let stats = BenchStats::from_samples(&samples, iterations);
+        let precise = stats.ci_high_ns - stats.mean_ns <= stats.mean_ns * TARGET_PRECISION;
+        if measured >= bench.measurement || precise {
+            return Ok(stats);
+        }
+    }
+    Ok(BenchStats::from_samples(&samples, iterations))
+}
+
+impl BenchStats {
+    /// Summarise per-iteration sample times (nanoseconds); `samples` must not be empty.
+    #[must_use]
+    pub fn from_samples(samples: &[f64], iterations: u64) -> Self {
+        let count = samples.len();
+        let mean = samples.iter().sum::<f64>() / count as f64;
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let median = if count % 2 == 0 {
+            (sorted[count / 2 - 1] + sorted[count / 2]) / 2.0
+        } else {
+            sorted[count / 2]
+        };
+        let variance = if count > 1 {
+            samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (count - 1) as f64
+        } else {
+            0.0
+        };
+        let std_dev = variance.sqrt();
+        let half_width = student_t_95(count.saturating_sub(1)) * std_dev / (count as f64).sqrt();
+        Self {
+            samples: count,
+            iterations,
+            mean_ns: mean,
+            median_ns: median,
+            std_dev_ns: std_dev,
+            ci_low_ns: mean - half_width,
+            ci_high_ns: mean + half_width,
+        }
+    }
+}
+
+/// Two-sided 95% critical value of Student's t distribution.
+fn student_t_95(degrees_of_freedom: usize) -> f64 {
+    const TABLE: [f64; 30] = [
+        12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201, 2.179, 2.160,
+        2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074, 2.069, 2.064, 2.060, 2.056,
+        2.052, 2.048, 2.045, 2.042,
+    ];
+    match degrees_of_freedom {
+        0 => 0.0,
+        df @ 1..=30 => TABLE[df - 1],
+        31..=40 => 2.021,
+        41..=60 => 2.000,
+        61..=120 => 1.980,
+        _ => 1.960,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(max_samples: usize) -> BenchOptions {
+        BenchOptions {
+            warmup: Duration::from_micros(50),
+            measurement: Duration::from_millis(10),
+            max_samples,
+            ..BenchOptions::default()
+        }
+    }
+
+    #[test]
+    fn stats_report_mean_median_and_interval() {
+        let stats = BenchStats::from_samples(&[10.0, 12.0, 11.0, 9.0, 13.0], 4);
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.iterations, 4);
+        assert!((stats.mean_ns - 11.0).abs() < 1e-9);
+        assert!((stats.median_ns - 11.0).abs() < 1e-9);
+        assert!((stats.std_dev_ns - 2.5f64.sqrt()).abs() < 1e-9);
+        let half_width = 2.776 * 2.5f64.sqrt() / 5f64.sqrt();
+        assert!((stats.ci_high_ns - (11.0 + half_width)).abs() < 1e-9);
+        assert!((stats.ci_low_ns - (11.0 - half_width)).abs() < 1e-9);
+
+        let even = BenchStats::from_samples(&[4.0, 1.0, 3.0, 2.0], 1);
+        assert!((even.median_ns - 2.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn sampling_sizes_batches_from_warmup_estimate() {
+        let mut batches = Vec::new();
+        let stats = sample(&options(20), |iterations| {
+            batches.push(iterations);
+            Ok(Duration::from_nanos(iterations * 250))
+        })
+        .expect("sampling succeeds");
+        assert!((stats.mean_ns - 250.0).abs() < 1e-9);
+        assert_eq!(stats.ci_low_ns, stats.ci_high_ns);
+        // Warm-up doubles until 50us are spent, then each sample targets 10ms / 20 = 500us.
+        assert_eq!(&batches[..3], &[1, 2, 4]);
+        assert_eq!(stats.iterations, 2000);
+        // Identical samples are precise after the minimum sample count.
+        assert_eq!(stats.samples, MIN_SAMPLES);
+    }
+
+    #[test]
+    fn sampling_stops_at_the_sample_cap_and_reports_failures() {
+        let mut calls = 0u64;
+        let stats = sample(&options(3), |iterations| {
+            calls += 1;
+            Ok(Duration::from_nanos(iterations * (100 + calls % 7)))
+        })
+        .expect("sampling succeeds");
+        assert_eq!(stats.samples, 3);
+
+        let err =
+            sample(&options(3), |_| Err("test returned false".into())).expect_err("failing batch");
+        assert_eq!(err, "test returned false");
+    }
+}
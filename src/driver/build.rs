@@ -2,11 +2,13 @@ use std::collections::{HashMap, HashSet};
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Instant;
 
 use crate::chic_kind::ChicKind;
 use crate::codegen::{self, Backend, FfiConfig, FileRole, wasm};
 use crate::error::{Error, Result};
+use crate::fingerprint;
 use crate::header::{HeaderOptions, generate_header};
 use crate::lint;
 use crate::mir::FunctionKind;
@@ -60,6 +62,7 @@ pub(crate) fn execute(request: BuildRequest) -> Result<FrontendReport> {
         bin_dir,
         no_dependencies,
         no_restore,
+        locked,
         no_incremental,
         rebuild,
         incremental_validate: _,
@@ -172,6 +175,117 @@ pub(crate) fn execute(request: BuildRequest) -> Result<FrontendReport> {
         );
     }
 
+    let default_input = inputs
+        .first()
+        .expect("checked earlier: inputs is not empty");
+    let artifacts_root = artifacts_path
+        .as_deref()
+        .or_else(|| Some(workspace_root.as_path()));
+    let layout = resolve_artifact_path(
+        default_input,
+        &target,
+        kind,
+        backend,
+        emit_object,
+        &output,
+        obj_dir.as_deref(),
+        bin_dir.as_deref(),
+        artifacts_root,
+        runtime_identity.as_str(),
+        &configuration,
+        version_suffix.as_deref(),
+    );
+    let runtime_abi = runtime_metadata
+        .as_ref()
+        .map(|rt| rt.abi.clone())
+        .unwrap_or_else(|| "none".to_string());
+    let runtime_manifest_hash = runtime_metadata
+        .as_ref()
+        .map(|rt| rt.manifest_hash.clone())
+        .unwrap_or_else(|| "none".to_string());
+    fs::create_dir_all(&layout.obj_dir)?;
+    if let Some(parent) = layout.artifact_path.parent()
+        && !parent.as_os_str().is_empty()
+        && !emit_object
+    {
+        fs::create_dir_all(parent)?;
+    }
+    let cache_manifest_path = layout.cache_manifest_path();
+    let previous_manifest = incremental::load_manifest(&cache_manifest_path);
+
+    if clean_only {
+        purge_all_artifacts(&layout)?;
+    }
+    if no_incremental || rebuild {
+        purge_incremental_artifacts(&layout)?;
+    } else if force {
+        let _ = fs::remove_file(&layout.artifact_path);
+    }
+
+    if let Some(prev) = &previous_manifest {
+        let current_inputs: HashSet<String> = inputs
+            .iter()
+            .map(|path| {
+                relative_to(&workspace_root, path)
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect();
+        for file in &prev.files {
+            if !current_inputs.contains(&file.path) {
+                if let Some(object) = &file.object {
+                    let stale = workspace_root.join(object);
+                    let _ = fs::remove_file(stale);
+                }
+            }
+        }
+    }
+
+    let mut option_parts = Vec::new();
+    option_parts.push(configuration.as_str().to_string());
+    option_parts.push(kind.as_str().to_string());
+    option_parts.push(backend.as_str().to_string());
+    option_parts.push(target.triple().to_string());
+    option_parts.push(if emit_object { "emit_object" } else { "link" }.to_string());
+    option_parts.push(if emit_header { "header" } else { "noheader" }.to_string());
+    option_parts.push(if emit_library_pack { "pack" } else { "nopack" }.to_string());
+    option_parts.push(if load_stdlib { "stdlib" } else { "nostd" }.to_string());
+    option_parts.push(
+        if no_dependencies {
+            "nodeps"
+        } else {
+            "withdeps"
+        }
+        .to_string(),
+    );
+    option_parts.push(format!("runtime:{}", runtime_identity));
+    option_parts.push(format!("runtime-abi:{}", runtime_abi));
+    option_parts.push(format!("runtime-hash:{}", runtime_manifest_hash));
+    option_parts.push(format!("cpuisa:{cpu_isa:?}"));
+    if let Some(suffix) = version_suffix.as_ref() {
+        option_parts.push(format!("suffix:{suffix}"));
+    }
+    for define in &defines {
+        let formatted = if let Some(value) = &define.value {
+            format!("{}={}", define.name, value)
+        } else {
+            define.name.clone()
+        };
+        option_parts.push(format!("define:{formatted}"));
+    }
+    let options_fingerprint = incremental::options_fingerprint(&option_parts);
+
+    // Module fingerprints are only comparable between builds with the same options.
+    let previous_modules = previous_manifest
+        .as_ref()
+        .filter(|prev| {
+            !(no_incremental || rebuild)
+                && prev.schema_version == incremental::MANIFEST_VERSION
+                && prev.options_fingerprint == options_fingerprint
+        })
+        .map(|prev| prev.modules.clone())
+        .unwrap_or_default();
+
     let frontend_start = Instant::now();
     let corelib_files = super::collect_core_files()?;
     let foundationlib_files = if no_dependencies {
@@ -209,6 +323,7 @@ pub(crate) fn execute(request: BuildRequest) -> Result<FrontendReport> {
         .workspace(workspace)
         .coverage_enabled(request.coverage)
         .restore_enabled(!no_restore)
+        .locked(locked)
         .load_stdlib(load_stdlib && !no_dependencies)
         .corelib_files(&corelib_files)
         .foundationlib_files(&foundationlib_files)
@@ -220,6 +335,7 @@ pub(crate) fn execute(request: BuildRequest) -> Result<FrontendReport> {
         .trait_solver_metrics(solver_metrics_enabled)
         .lint_config(lint_config)
         .doc_enforcement(request.doc_enforcement)
+        .incremental(Some(&previous_modules))
         .build();
     let frontend = pipeline.execute()?;
     if trace_enabled {
@@ -337,74 +453,16 @@ pub(crate) fn execute(request: BuildRequest) -> Result<FrontendReport> {
     let mut header_path = None;
     let final_artifact_path;
 
-    let default_input = inputs
-        .first()
-        .expect("checked earlier: inputs is not empty");
-    let artifacts_root = artifacts_path
-        .as_deref()
-        .or_else(|| Some(workspace_root.as_path()));
-    let layout = resolve_artifact_path(
-        default_input,
-        &target,
-        kind,
-        backend,
-        emit_object,
-        &output,
-        obj_dir.as_deref(),
-        bin_dir.as_deref(),
-        artifacts_root,
-        runtime_identity.as_str(),
-        &configuration,
-        version_suffix.as_deref(),
-    );
-    let runtime_abi = runtime_metadata
-        .as_ref()
-        .map(|rt| rt.abi.clone())
-        .unwrap_or_else(|| "none".to_string());
-    let runtime_manifest_hash = runtime_metadata
-        .as_ref()
-        .map(|rt| rt.manifest_hash.clone())
-        .unwrap_or_else(|| "none".to_string());
-    fs::create_dir_all(&layout.obj_dir)?;
-    if let Some(parent) = layout.artifact_path.parent()
-        && !parent.as_os_str().is_empty()
-        && !emit_object
-    {
-        fs::create_dir_all(parent)?;
-    }
-    let cache_manifest_path = layout.cache_manifest_path();
-    let previous_manifest = incremental::load_manifest(&cache_manifest_path);
-
-    if clean_only {
-        purge_all_artifacts(&layout)?;
-    }
-    if no_incremental || rebuild {
-        purge_incremental_artifacts(&layout)?;
-    } else if force {
-        let _ = fs::remove_file(&layout.artifact_path);
-    }
-
-    if let Some(prev) = &previous_manifest {
-        let current_inputs: HashSet<String> = inputs
-            .iter()
-            .map(|path| {
-                relative_to(&workspace_root, path)
-                    .to_string_lossy()
-                    .to_string()
-            })
-            .collect();
-        for file in &prev.files {
-            if !current_inputs.contains(&file.path) {
-                if let Some(object) = &file.object {
-                    let stale = workspace_root.join(object);
-                    let _ = fs::remove_file(stale);
-                }
-            }
-        }
-    }
-
     let artifact_path = layout.artifact_path.clone();
     let object_base = layout.object_base.clone();
+    // Debug builds carry DWARF (native) or a source map (WASM) so traps and debuggers can
+    // name Chic sources.
+    let debug_files = configuration
+        .eq_ignore_ascii_case("debug")
+        .then(|| Arc::new(frontend.files.clone()));
+    // Lets codegen reuse objects and function bodies whose MIR did not change.
+    let mir_interface = (!(no_incremental || rebuild))
+        .then(|| fingerprint::interface_fingerprint(&frontend.mir_module));
 
     match backend {
         Backend::Wasm => {
@@ -417,6 +475,8 @@ pub(crate) fn execute(request: BuildRequest) -> Result<FrontendReport> {
             options.ffi_config = ffi_config.clone();
             options.ffi_packages = ffi_package_files.clone();
             options.runtime = runtime_metadata.clone();
+            options.debug_info = debug_files.clone();
+            options.mir_interface = mir_interface.clone();
 
             let codegen_start = Instant::now();
             let codegen_artifact = codegen::compile_module(
@@ -514,9 +574,16 @@ pub(crate) fn execute(request: BuildRequest) -> Result<FrontendReport> {
                 .iter()
                 .filter(|module| !(module.is_stdlib && !module.requires_codegen))
                 .count();
+            // Units are prepared and collected in module order; only the per-unit codegen
+            // runs across jobs, so object files and reports match a serial build.
+            let module_refs = frontend
+                .modules
+                .iter()
+                .map(|module| module.parse.module_ref())
+                .collect::<Vec<_>>();
+            let mut units = Vec::with_capacity(codegen_unit_count);
             for (index, module) in frontend.modules.iter().enumerate() {
                 if module.is_stdlib && !module.requires_codegen {
-                    module_artifacts.push(ModuleArtifact::default());
                     continue;
                 }
                 let object_path = if emit_object && codegen_unit_count == 1 {
@@ -535,6 +602,8 @@ pub(crate) fn execute(request: BuildRequest) -> Result<FrontendReport> {
                 options.ffi_config = ffi_config.clone();
                 options.ffi_packages = ffi_package_files.clone();
                 options.runtime = runtime_metadata.clone();
+                options.debug_info = debug_files.clone();
+                options.mir_interface = mir_interface.clone();
 
                 let mut mir_unit =
                     slice_mir_module(&frontend.mir_module, &frontend.unit_functions[index]);
@@ -571,23 +640,45 @@ pub(crate) fn execute(request: BuildRequest) -> Result<FrontendReport> {
                         );
                     }
                 }
-                let module_ref = module.parse.module_ref();
-                let artifact = codegen::compile_module(
-                    &module.source,
-                    &module_ref,
-                    &mir_unit,
-                    Some(&frontend.mir_module),
-                    &frontend.perf_metadata,
-                    &target,
-                    kind,
-                    &object_path,
-                    &options,
-                    &frontend.drop_glue,
-                    &frontend.hash_glue,
-                    &frontend.eq_glue,
-                    &frontend.type_metadata,
-                )?;
-                drop(module_ref);
+                units.push((
+                    index,
+                    module.source.as_str(),
+                    &*module_refs[index],
+                    object_path,
+                    options,
+                    mir_unit,
+                ));
+            }
+            let artifacts = crate::threading::parallel_map(
+                &units,
+                |(_, source, ast, object_path, options, mir_unit)| {
+                    codegen::compile_module(
+                        source,
+                        ast,
+                        mir_unit,
+                        Some(&frontend.mir_module),
+                        &frontend.perf_metadata,
+                        &target,
+                        kind,
+                        object_path,
+                        options,
+                        &frontend.drop_glue,
+                        &frontend.hash_glue,
+                        &frontend.eq_glue,
+                        &frontend.type_metadata,
+                    )
+                },
+            );
+            let unit_indices = units.iter().map(|(index, ..)| *index).collect::<Vec<_>>();
+            drop(units);
+            drop(module_refs);
+            let mut artifacts = unit_indices.into_iter().zip(artifacts).peekable();
+            for (index, module) in frontend.modules.iter().enumerate() {
+                let Some(artifact) = artifacts.next_if(|(unit, _)| *unit == index) else {
+                    module_artifacts.push(ModuleArtifact::default());
+                    continue;
+                };
+                let artifact = artifact.1?;
 
                 link_inputs.push(artifact.object_path.clone());
                 if let Some(metadata) = &artifact.metadata_path {
@@ -884,40 +975,6 @@ pub(crate) fn execute(request: BuildRequest) -> Result<FrontendReport> {
             }),
     };
 
-    let mut option_parts = Vec::new();
-    option_parts.push(configuration.as_str().to_string());
-    option_parts.push(kind.as_str().to_string());
-    option_parts.push(backend.as_str().to_string());
-    option_parts.push(target.triple().to_string());
-    option_parts.push(if emit_object { "emit_object" } else { "link" }.to_string());
-    option_parts.push(if emit_header { "header" } else { "noheader" }.to_string());
-    option_parts.push(if emit_library_pack { "pack" } else { "nopack" }.to_string());
-    option_parts.push(if load_stdlib { "stdlib" } else { "nostd" }.to_string());
-    option_parts.push(
-        if no_dependencies {
-            "nodeps"
-        } else {
-            "withdeps"
-        }
-        .to_string(),
-    );
-    option_parts.push(format!("runtime:{}", runtime_identity));
-    option_parts.push(format!("runtime-abi:{}", runtime_abi));
-    option_parts.push(format!("runtime-hash:{}", runtime_manifest_hash));
-    option_parts.push(format!("cpuisa:{cpu_isa:?}"));
-    if let Some(suffix) = version_suffix.as_ref() {
-        option_parts.push(format!("suffix:{suffix}"));
-    }
-    for define in &defines {
-        let formatted = if let Some(value) = &define.value {
-            format!("{}={}", define.name, value)
-        } else {
-            define.name.clone()
-        };
-        option_parts.push(format!("define:{formatted}"));
-    }
-    let options_fingerprint = incremental::options_fingerprint(&option_parts);
-
     let manifest = BuildManifest {
         schema_version: incremental::MANIFEST_VERSION,
         toolchain: crate::codegen::compiler_cache_identity().to_string(),
@@ -930,6 +987,7 @@ pub(crate) fn execute(request: BuildRequest) -> Result<FrontendReport> {
         runtime_manifest_hash,
         files: manifest_files,
         artifacts: artifact_state,
+        modules: frontend.module_states.clone(),
     };
     incremental::write_manifest(&cache_manifest_path, &manifest)?;
 
@@ -1,5 +1,5 @@
 use crate::defines::DefineFlag;
-use crate::frontend::conditional::{ConditionalDefines, DefineValue};
+use crate::frontend::conditional::{ConditionalDefines, DefineValue, feature_define};
 use crate::target::Target;
 
 pub(crate) fn resolve_conditional_defines(
@@ -74,13 +74,9 @@ pub(crate) fn resolve_conditional_defines(
                 let value = value.to_string();
                 if store_key.eq_ignore_ascii_case("feature") {
                     for feature in value.split(',').map(str::trim).filter(|s| !s.is_empty()) {
-                        let normalized = feature
-                            .chars()
-                            .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '_' })
-                            .collect::<String>();
                         insert_define(
                             &mut defines,
-                            &format!("feature_{normalized}"),
+                            &feature_define(feature),
                             DefineValue::Bool(true),
                         );
                     }
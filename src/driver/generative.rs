@@ -0,0 +1,592 @@
+//! Runner support for `proptestcase` and `fuzzcase` declarations.
+//!
+//! Arguments are drawn from each parameter's [`TestValueDomain`] with the xoroshiro128+ generator
+//! used by `Std.Random.RNG` (SplitMix64-seeded from a 128-bit seed, as reported by `chic seed`).
+//! Every generated case has its own seed so a failure can be replayed exactly; failing seeds are
+//! appended to a `.regressions` file next to the testcase source and replayed before new cases.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::driver::types::GenerativeTestOptions;
+use crate::driver::wasm::WasmCaseOutcome;
+use crate::mir::{TestCaseKind, TestCaseMetadata, TestValueDomain};
+use crate::runtime::WasmValue;
+
+use super::TestStatus;
+
+const DEFAULT_PROPERTY_RUNS: u32 = 100;
+const DEFAULT_FUZZ_RUNS: u32 = 256;
+/// Upper bound on extra executions spent minimising a failing case.
+const MAX_SHRINK_RUNS: usize = 512;
+/// Run seed used when neither `--test-seed` nor `CHIC_TEST_SEED` is set, keeping runs reproducible.
+const DEFAULT_RUN_SEED: u128 = 0;
+
+const REGRESSIONS_HEADER: &str = "# Seeds of failing proptestcase/fuzzcase inputs; `chic test` replays them before new cases.\n# Commit this file so every run re-checks these inputs.\n";
+
+static REGRESSIONS_LOCK: Mutex<()> = Mutex::new(());
+
+/// Shared settings for every generative testcase in a run.
+#[derive(Debug, Default)]
+pub(crate) struct GenerativeSettings {
+    pub(crate) options: GenerativeTestOptions,
+    /// Regression files keyed by testcase id.
+    pub(crate) regression_files: HashMap<String, PathBuf>,
+}
+
+impl GenerativeSettings {
+    /// Regression file for a testcase declared in `source`: `tests/math.ch` -> `tests/math.regressions`.
+    pub(crate) fn regression_file_for(source: &Path) -> PathBuf {
+        source.with_extension("regressions")
+    }
+}
+
+/// xoroshiro128+ exactly as implemented by `Std.Random.RNG`.
+#[derive(Debug, Clone)]
+pub(crate) struct CaseRng {
+    state0: u64,
+    state1: u64,
+}
+
+impl CaseRng {
+    pub(crate) fn from_seed(seed: u128) -> Self {
+        let hi = (seed >> 64) as u64;
+        let lo = seed as u64;
+        Self {
+            state0: split_mix64(lo),
+            state1: split_mix64(hi),
+        }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        let result = self
+            .state0
+            .wrapping_add(self.state1)
+            .rotate_left(17)
+            .wrapping_add(self.state0);
+        let s1 = self.state1 ^ self.state0;
+        self.state0 = self.state0.rotate_left(49) ^ s1 ^ (s1 << 21);
+        self.state1 = s1.rotate_left(28);
+        result
+    }
+
+    pub(crate) fn next_u128(&mut self) -> u128 {
+        let hi = u128::from(self.next_u64());
+        let lo = u128::from(self.next_u64());
+        (hi << 64) | lo
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound.max(1)
+    }
+
+    fn pick<T: Copy>(&mut self, values: &[T]) -> T {
+        values[self.below(values.len() as u64) as usize]
+    }
+}
+
+fn split_mix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// A generated argument; integers are widened to `i128` so every domain fits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum GeneratedValue {
+    Bool(bool),
+    Int(i128),
+    Float(f64),
+    Char(u32),
+}
+
+impl GeneratedValue {
+    fn to_wasm(self, domain: TestValueDomain) -> WasmValue {
+        match (self, domain) {
+            (Self::Bool(value), _) => WasmValue::I32(i32::from(value)),
+            (Self::Int(value), TestValueDomain::Int { bits: 64, .. }) => {
+                WasmValue::I64(value as i64)
+            }
+            (Self::Int(value), _) => WasmValue::I32(value as i32),
+            (Self::Float(value), TestValueDomain::Float { bits: 32 }) => {
+                WasmValue::F32(value as f32)
+            }
+            (Self::Float(value), _) => WasmValue::F64(value),
+            (Self::Char(value), _) => WasmValue::I32(value as i32),
+        }
+    }
+
+    fn same_as(self, other: Self) -> bool {
+        match (self, other) {
+            (Self::Float(a), Self::Float(b)) => a.to_bits() == b.to_bits(),
+            _ => self == other,
+        }
+    }
+}
+
+impl std::fmt::Display for GeneratedValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Float(value) => write!(f, "{value:?}"),
+            Self::Char(value) => match char::from_u32(value) {
+                Some(ch) if !ch.is_control() => write!(f, "'{ch}'"),
+                _ => write!(f, "U+{value:04X}"),
+            },
+        }
+    }
+}
+
+fn int_bounds(bits: u16, signed: bool) -> (i128, i128) {
+    if signed {
+        let max = (1i128 << (bits - 1)) - 1;
+        (-max - 1, max)
+    } else {
+        (0, (1i128 << bits) - 1)
+    }
+}
+
+fn raw_int(rng: &mut CaseRng, bits: u16, signed: bool) -> i128 {
+    let raw = rng.next_u64();
+    let masked = if bits >= 64 {
+        raw
+    } else {
+        raw & ((1u64 << bits) - 1)
+    };
+    if signed {
+        let shift = 64 - u32::from(bits);
+        i128::from(((masked << shift) as i64) >> shift)
+    } else {
+        i128::from(masked)
+    }
+}
+
+fn char_limit(bits: u16) -> u32 {
+    match bits {
+        8 => 0xFF,
+        16 => 0xFFFF,
+        _ => 0x10_FFFF,
+    }
+}
+
+fn valid_char(rng: &mut CaseRng, bits: u16) -> u32 {
+    loop {
+        let value = (rng.next_u64() % (u64::from(char_limit(bits)) + 1)) as u32;
+        if !(0xD800..=0xDFFF).contains(&value) {
+            return value;
+        }
+    }
+}
+
+fn float_from_bits(rng: &mut CaseRng, bits: u16) -> f64 {
+    if bits == 32 {
+        f64::from(f32::from_bits(rng.next_u64() as u32))
+    } else {
+        f64::from_bits(rng.next_u64())
+    }
+}
+
+fn fit_float(value: f64, bits: u16) -> f64 {
+    if bits == 32 {
+        f64::from(value as f32)
+    } else {
+        value
+    }
+}
+
+/// Property generation favours small, readable values while still covering each full domain.
+fn generate_property(rng: &mut CaseRng, domain: TestValueDomain) -> GeneratedValue {
+    match domain {
+        TestValueDomain::Bool => GeneratedValue::Bool(rng.next_u64() & 1 == 1),
+        TestValueDomain::Int { bits, signed } => {
+            let (min, max) = int_bounds(bits, signed);
+            let value = match rng.below(4) {
+                0 => i128::from(rng.below(33)) - if signed { 16 } else { 0 },
+                1 => i128::from(rng.below(2001)) - if signed { 1000 } else { 0 },
+                _ => raw_int(rng, bits, signed),
+            };
+            GeneratedValue::Int(value.clamp(min, max))
+        }
+        TestValueDomain::Float { bits } => {
+            let value = match rng.below(3) {
+                0 => rng.below(201) as f64 - 100.0,
+                1 => {
+                    let unit = (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+                    (unit * 2.0 - 1.0) * 10f64.powi(rng.below(7) as i32)
+                }
+                _ => loop {
+                    let candidate = float_from_bits(rng, bits);
+                    if candidate.is_finite() {
+                        break candidate;
+                    }
+                },
+            };
+            GeneratedValue::Float(fit_float(value, bits))
+        }
+        TestValueDomain::Char { bits } => {
+            let value = if rng.below(4) == 0 {
+                valid_char(rng, bits)
+            } else {
+                0x20 + rng.below(0x5F) as u32
+            };
+            GeneratedValue::Char(value)
+        }
+    }
+}
+
+/// Fuzz generation mixes boundary values with raw bit patterns, including NaN and surrogates.
+fn generate_fuzz(rng: &mut CaseRng, domain: TestValueDomain) -> GeneratedValue {
+    let boundary = rng.below(2) == 0;
+    match domain {
+        TestValueDomain::Bool => GeneratedValue::Bool(rng.next_u64() & 1 == 1),
+        TestValueDomain::Int { bits, signed } => {
+            let (min, max) = int_bounds(bits, signed);
+            if !boundary {
+                return GeneratedValue::Int(raw_int(rng, bits, signed));
+            }
+            let power = 1i128 << rng.below(u64::from(bits) - u64::from(signed));
+            let value = rng.pick(&[
+                0,
+                1,
+                -1,
+                min,
+                max,
+                min + 1,
+                max - 1,
+                power,
+                power - 1,
+                -power,
+            ]);
+            GeneratedValue::Int(value.clamp(min, max))
+        }
+        TestValueDomain::Float { bits } => {
+            if !boundary {
+                return GeneratedValue::Float(float_from_bits(rng, bits));
+            }
+            let value = if bits == 32 {
+                f64::from(rng.pick(&[
+                    0.0,
+                    -0.0,
+                    1.0,
+                    -1.0,
+                    f32::NAN,
+                    f32::INFINITY,
+                    f32::NEG_INFINITY,
+                    f32::MIN_POSITIVE,
+                    f32::MAX,
+                    f32::MIN,
+                    f32::EPSILON,
+                    f32::from_bits(1),
+                ]))
+            } else {
+                rng.pick(&[
+                    0.0,
+                    -0.0,
+                    1.0,
+                    -1.0,
+                    f64::NAN,
+                    f64::INFINITY,
+                    f64::NEG_INFINITY,
+                    f64::MIN_POSITIVE,
+                    f64::MAX,
+                    f64::MIN,
+                    f64::EPSILON,
+                    f64::from_bits(1),
+                ])
+            };
+            GeneratedValue::Float(value)
+        }
+        TestValueDomain::Char { bits } => {
+            let limit = char_limit(bits);
+            let value = if boundary {
+                rng.pick(&[
+                    0, 0x7F, 0x80, 0xFF, 0xD7FF, 0xD800, 0xDFFF, 0xE000, 0xFFFD, 0xFFFF, 0x10_FFFF,
+                ])
+                .min(limit)
+            } else {
+                (rng.next_u64() % (u64::from(limit) + 1)) as u32
+            };
+            GeneratedValue::Char(value)
+        }
+    }
+}
+
+/// Regenerate the arguments of the case identified by `case_seed`.
+pub(crate) fn generate_case(
+    kind: TestCaseKind,
+    domains: &[TestValueDomain],
+    case_seed: u128,
+) -> Vec<GeneratedValue> {
+    let mut rng = CaseRng::from_seed(case_seed);
+    domains
+        .iter()
+        .map(|domain| match kind {
+            TestCaseKind::Fuzz => generate_fuzz(&mut rng, *domain),
+            _ => generate_property(&mut rng, *domain),
+        })
+        .collect()
+}
+
+/// Simpler variants of `value`, most aggressive first.
+pub(crate) fn shrink_candidates(
+    value: GeneratedValue,
+    domain: TestValueDomain,
+) -> Vec<GeneratedValue> {
+    let mut candidates = Vec::new();
+    match value {
+        GeneratedValue::Bool(true) => candidates.push(GeneratedValue::Bool(false)),
+        GeneratedValue::Bool(false) => {}
+        GeneratedValue::Int(v) => {
+            let (min, max) = match domain {
+                TestValueDomain::Int { bits, signed } => int_bounds(bits, signed),
+                _ => (i128::MIN, i128::MAX),
+            };
+            let mut ints = vec![0, v / 2, v - v.signum()];
+            if v < 0 {
+                ints.insert(1, -v);
+            }
+            candidates.extend(
+                ints.into_iter()
+                    .filter(|candidate| (min..=max).contains(candidate))
+                    .map(GeneratedValue::Int),
+            );
+        }
+        GeneratedValue::Float(v) => {
+            let floats = if v.is_finite() {
+                vec![0.0, -v, v.trunc(), v / 2.0]
+            } else {
+                vec![0.0, 1.0]
+            };
+            candidates.extend(
+                floats
+                    .into_iter()
+                    .filter(|candidate| v.is_nan() || candidate.abs() < v.abs() || *candidate > v)
+                    .map(GeneratedValue::Float),
+            );
+        }
+        GeneratedValue::Char(c) => {
+            const A: u32 = 'a' as u32;
+            if c > A {
+                candidates.extend([A, A + (c - A) / 2, c - 1].map(GeneratedValue::Char));
+            } else if c < A {
+                candidates.extend([A, c + (A - c).div_ceil(2)].map(GeneratedValue::Char));
+            }
+        }
+    }
+    let mut unique: Vec<GeneratedValue> = Vec::with_capacity(candidates.len());
+    for candidate in candidates {
+        if !candidate.same_as(value) && !unique.iter().any(|seen| seen.same_as(candidate)) {
+            unique.push(candidate);
+        }
+    }
+    unique
+}
+
+/// Minimise a failing input one argument at a time; `fails` reports whether a trial still fails.
+pub(crate) fn shrink(
+    domains: &[TestValueDomain],
+    mut current: Vec<GeneratedValue>,
+    mut fails: impl FnMut(&[GeneratedValue]) -> bool,
+) -> (Vec<GeneratedValue>, usize) {
+    let mut steps = 0;
+    let mut budget = MAX_SHRINK_RUNS;
+    'improve: loop {
+        for index in 0..current.len() {
+            for candidate in shrink_candidates(current[index], domains[index]) {
+                if budget == 0 {
+                    break 'improve;
+                }
+                budget -= 1;
+                let mut trial = current.clone();
+                trial[index] = candidate;
+                if fails(&trial) {
+                    current = trial;
+                    steps += 1;
+                    continue 'improve;
+                }
+            }
+        }
+        break;
+    }
+    (current, steps)
+}
+
+fn master_seed(run_seed: u128, qualified_name: &str) -> u128 {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&run_seed.to_le_bytes());
+    hasher.update(qualified_name.as_bytes());
+    let digest = hasher.finalize();
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest.as_bytes()[..16]);
+    u128::from_le_bytes(bytes)
+}
+
+pub(crate) fn format_seed(seed: u128) -> String {
+    format!("0x{seed:032x}")
+}
+
+fn describe_inputs(meta: &TestCaseMetadata, values: &[GeneratedValue]) -> String {
+    meta.runner_parameters()
+        .zip(values)
+        .map(|(param, value)| format!("{} = {value}", param.name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Seeds recorded for `qualified_name` in a regressions file.
+pub(crate) fn load_regressions(path: &Path, qualified_name: &str) -> Vec<u128> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    contents
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default())
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let name = fields.next()?;
+            let seed = GenerativeTestOptions::parse_seed(fields.next()?)?;
+            (name == qualified_name).then_some(seed)
+        })
+        .collect()
+}
+
+/// Append a failing case seed unless it is already recorded.
+pub(crate) fn record_regression(
+    path: &Path,
+    qualified_name: &str,
+    seed: u128,
+    inputs: &str,
+) -> std::io::Result<()> {
+    let _guard = REGRESSIONS_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if load_regressions(path, qualified_name).contains(&seed) {
+        return Ok(());
+    }
+    let exists = path.exists();
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    if !exists {
+        file.write_all(REGRESSIONS_HEADER.as_bytes())?;
+    }
+    writeln!(file, "{qualified_name} {}  # {inputs}", format_seed(seed))
+}
+
+/// Run a property or fuzz testcase, calling `execute` once per generated (or shrunk) input.
+/// Returns an error message when the testcase cannot be run at all.
+pub(crate) fn run_generative_case(
+    meta: &TestCaseMetadata,
+    settings: &GenerativeSettings,
+    mut execute: impl FnMut(&[WasmValue]) -> WasmCaseOutcome,
+) -> Result<WasmCaseOutcome, String> {
+    let keyword = meta.kind.keyword();
+    let domains: Option<Vec<TestValueDomain>> =
+        meta.runner_parameters().map(|param| param.domain).collect();
+    let Some(domains) = domains.filter(|domains| !domains.is_empty()) else {
+        return Err(format!(
+            "{keyword} needs bool, char, integer or float parameters to generate"
+        ));
+    };
+    let to_wasm = |values: &[GeneratedValue]| -> Vec<WasmValue> {
+        values
+            .iter()
+            .zip(&domains)
+            .map(|(value, domain)| value.to_wasm(*domain))
+            .collect()
+    };
+
+    let runs = settings
+        .options
+        .runs
+        .or(meta.runs)
+        .unwrap_or(match meta.kind {
+            TestCaseKind::Fuzz => DEFAULT_FUZZ_RUNS,
+            _ => DEFAULT_PROPERTY_RUNS,
+        });
+    let run_seed = settings.options.seed.unwrap_or(DEFAULT_RUN_SEED);
+    let regression_file = settings.regression_files.get(&meta.id);
+    let replayed = regression_file
+        .map(|path| load_regressions(path, &meta.qualified_name))
+        .unwrap_or_default();
+    let replay_count = replayed.len();
+    let mut master = CaseRng::from_seed(master_seed(run_seed, &meta.qualified_name));
+    let seeds = replayed
+        .into_iter()
+        .chain((0..runs).map(|_| master.next_u128()));
+
+    let mut last: Option<WasmCaseOutcome> = None;
+    for (ordinal, case_seed) in seeds.enumerate() {
+        let inputs = generate_case(meta.kind, &domains, case_seed);
+        let outcome = execute(&to_wasm(&inputs));
+        if outcome.status != TestStatus::Failed {
+            last = Some(outcome);
+            continue;
+        }
+
+        let original = describe_inputs(meta, &inputs);
+        let (minimal, steps, mut failure) = if outcome.timed_out {
+            (inputs, 0, outcome)
+        } else {
+            let mut failure = outcome;
+            let (minimal, steps) = shrink(&domains, inputs, |trial| {
+                let trial_outcome = execute(&to_wasm(trial));
+                let fails = trial_outcome.status == TestStatus::Failed && !trial_outcome.timed_out;
+                if fails {
+                    failure = trial_outcome;
+                }
+                fails
+            });
+            (minimal, steps, failure)
+        };
+
+        let mut message = if ordinal < replay_count {
+            format!("{keyword} failed replaying a recorded regression")
+        } else {
+            format!(
+                "{keyword} failed on generated case {} of {runs}",
+                ordinal - replay_count + 1
+            )
+        };
+        if let Some(reason) = failure.message.as_deref() {
+            let _ = write!(message, ": {reason}");
+        }
+        let _ = write!(message, "\n  input: {}", describe_inputs(meta, &minimal));
+        if steps > 0 {
+            let _ = write!(message, " (shrunk in {steps} steps from {original})");
+        }
+        let _ = write!(message, "\n  case seed {}", format_seed(case_seed));
+        if ordinal >= replay_count {
+            match regression_file {
+                Some(path) => {
+                    match record_regression(path, &meta.qualified_name, case_seed, &original) {
+                        Ok(()) => {
+                            let _ = write!(message, ", saved to {}", path.display());
+                        }
+                        Err(err) => {
+                            let _ =
+                                write!(message, " (could not save to {}: {err})", path.display());
+                        }
+                    }
+                }
+                None => message.push_str(" (no source file to save regressions next to)"),
+            }
+        } else if let Some(path) = regression_file {
+            let _ = write!(message, " from {}", path.display());
+        }
+        let _ = write!(message, "\n  run seed {}", format_seed(run_seed));
+        failure.message = Some(message);
+        return Ok(failure);
+    }
+
+    let mut outcome = last.ok_or_else(|| format!("{keyword} ran no generated cases"))?;
+    outcome.message = None;
+    Ok(outcome)
+}
@@ -1,3 +1,4 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fs;
 use std::path::Path;
 use std::time::UNIX_EPOCH;
@@ -7,8 +8,11 @@ use serde::{Deserialize, Serialize};
 
 use crate::codegen::compiler_cache_identity;
 use crate::error::{Error, Result};
+use crate::fingerprint::{self, Fingerprinter};
+use crate::frontend::ast::Module;
+use crate::frontend::diagnostics::{Diagnostic, FileId};
 
-pub(crate) const MANIFEST_VERSION: u32 = 2;
+pub(crate) const MANIFEST_VERSION: u32 = 3;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub(crate) struct FileState {
@@ -44,6 +48,143 @@ pub(crate) struct BuildManifest {
     pub runtime_manifest_hash: String,
     pub files: Vec<FileState>,
     pub artifacts: ArtifactState,
+    #[serde(default)]
+    pub modules: Vec<ModuleState>,
+}
+
+/// Fingerprints of one parsed file from the build that last compiled it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct ModuleState {
+    pub path: String,
+    pub ast: String,
+    pub surface: String,
+    /// Combined surface of every file whose declarations this file can see.
+    pub dependencies: String,
+    /// The file's function bodies type-checked without diagnostics.
+    pub clean: bool,
+}
+
+/// A parsed file as the incremental planner sees it.
+pub(crate) struct ModuleInput<'a> {
+    pub path: String,
+    pub ast: &'a Module,
+    /// Stdlib sources only change with the toolchain; hash their text instead of the AST.
+    pub source: Option<&'a str>,
+    pub package: Option<String>,
+    pub package_dependencies: Vec<String>,
+}
+
+/// Fingerprint every module and pick the ones whose bodies need no re-check: the file's
+/// AST and the surfaces it depends on match the previous build, which checked it cleanly.
+/// A file's dependencies are the files of its own package and of the packages that
+/// package (transitively) depends on; files outside any package see everything.
+pub(crate) fn plan_modules(
+    inputs: &[ModuleInput<'_>],
+    previous: &[ModuleState],
+    defines_fingerprint: &str,
+) -> (Vec<ModuleState>, HashSet<usize>) {
+    let fingerprints: Vec<(String, String)> = inputs
+        .iter()
+        .map(|input| match input.source {
+            Some(source) => {
+                let hash = Fingerprinter::new()
+                    .str(defines_fingerprint)
+                    .str(source)
+                    .finish();
+                (hash.clone(), hash)
+            }
+            None => (
+                fingerprint::ast_fingerprint(input.ast),
+                fingerprint::surface_fingerprint(input.ast),
+            ),
+        })
+        .collect();
+
+    let mut package_dependencies: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for input in inputs {
+        if let Some(package) = input.package.as_deref() {
+            package_dependencies
+                .entry(package)
+                .or_default()
+                .extend(input.package_dependencies.iter().map(String::as_str));
+        }
+    }
+    let previous: BTreeMap<&str, &ModuleState> = previous
+        .iter()
+        .map(|state| (state.path.as_str(), state))
+        .collect();
+    let mut states = Vec::with_capacity(inputs.len());
+    let mut unchanged = HashSet::new();
+    for (index, input) in inputs.iter().enumerate() {
+        let visible = input
+            .package
+            .as_deref()
+            .map(|package| visible_packages(&package_dependencies, package));
+        let mut dependencies = Fingerprinter::new();
+        for (other, (_, surface)) in inputs.iter().zip(&fingerprints) {
+            let seen = match (&visible, other.package.as_deref()) {
+                (Some(visible), Some(package)) => visible.contains(package),
+                _ => true,
+            };
+            if seen {
+                dependencies.str(&other.path).str(surface);
+            }
+        }
+        let (ast, surface) = fingerprints[index].clone();
+        let state = ModuleState {
+            path: input.path.clone(),
+            ast,
+            surface,
+            dependencies: dependencies.finish(),
+            clean: true,
+        };
+        if let Some(before) = previous.get(input.path.as_str())
+            && before.clean
+            && before.ast == state.ast
+            && before.dependencies == state.dependencies
+        {
+            unchanged.insert(index);
+        }
+        states.push(state);
+    }
+    (states, unchanged)
+}
+
+/// `package` and every package it depends on, directly or transitively.
+fn visible_packages<'a>(
+    package_dependencies: &BTreeMap<&'a str, BTreeSet<&'a str>>,
+    package: &'a str,
+) -> BTreeSet<&'a str> {
+    let mut visible = BTreeSet::from([package]);
+    let mut pending = vec![package];
+    while let Some(current) = pending.pop() {
+        for dependency in package_dependencies.get(current).into_iter().flatten() {
+            if visible.insert(*dependency) {
+                pending.push(*dependency);
+            }
+        }
+    }
+    visible
+}
+
+/// Mark the modules `diagnostics` point into as not clean. A diagnostic outside every
+/// module could belong to any of them.
+pub(crate) fn record_diagnostics(
+    states: &mut [ModuleState],
+    file_ids: &[FileId],
+    diagnostics: &[Diagnostic],
+) {
+    for diagnostic in diagnostics {
+        let module = diagnostic.primary_label.as_ref().and_then(|label| {
+            file_ids
+                .iter()
+                .position(|file_id| *file_id == label.span.file_id)
+        });
+        match module {
+            Some(index) => states[index].clean = false,
+            None => states.iter_mut().for_each(|state| state.clean = false),
+        }
+    }
 }
 
 pub(crate) fn load_manifest(path: &Path) -> Option<BuildManifest> {
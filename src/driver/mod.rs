@@ -12,28 +12,35 @@ use crate::codegen::Backend;
 use crate::defines::DefineFlag;
 use crate::error::Result;
 use crate::logging::LogLevel;
-use crate::mir::{FunctionKind, TestCaseMetadata, format_module};
+use crate::mir::{FunctionKind, TestCaseKind, TestCaseMetadata, format_module};
 use crate::runtime::{WasmProgram, execute_wasm_with_options, hooks};
 use crate::runtime_package::{ResolvedRuntime, RuntimeKind, resolve_runtime};
 use crate::spec::Spec;
 use crate::target::Target;
+mod bench;
 mod build;
 mod defines;
+mod generative;
 pub mod graph_registry;
 mod incremental;
 mod pipeline;
 pub mod profile_loader;
 mod report;
+mod snapshot;
 pub mod types;
 mod wasm;
+pub(crate) use self::incremental::ModuleState;
 use self::pipeline::CompilerPipelineBuilder;
 use self::report::ModuleArtifact;
 pub use self::report::{
     FrontendReport, GeneratedModuleIr, MirDumpResult, MirVerificationIssue, ModuleReport,
 };
+pub(crate) use self::snapshot::{SnapshotObservation, check_snapshots};
 pub use self::types::{
-    BuildFfiOptions, BuildRequest, FormatResult, RunResult, TestCaseResult, TestOptions, TestRun,
-    TestSelection, TestStatus, WatchdogConfig, resolve_trace_enabled, trait_solver_metrics_enabled,
+    BenchOptions, BenchResult, BenchStats, BuildFfiOptions, BuildRequest, CheckProject,
+    FormatResult, GenerativeTestOptions, RunResult, TestCaseResult, TestEvent, TestEventSink,
+    TestOptions, TestReportFormat, TestReportRequest, TestRun, TestSelection, TestStatus,
+    WatchdogConfig, resolve_trace_enabled, trait_solver_metrics_enabled,
 };
 
 pub(super) fn summarize_inputs(inputs: &[PathBuf]) -> String {
@@ -255,10 +262,14 @@ where
     F: FnOnce() -> Result<T> + Send + 'static,
 {
     if let Some(bytes) = stack_bytes {
+        let jobs = crate::threading::configured_jobs();
         let handle = thread::Builder::new()
             .name("chic-driver-worker".into())
             .stack_size(bytes)
-            .spawn(f)
+            .spawn(move || {
+                crate::threading::configure_jobs(jobs);
+                f()
+            })
             .map_err(|err| {
                 crate::error::Error::internal(format!("failed to spawn worker thread: {err}"))
             })?;
@@ -338,6 +349,44 @@ impl CompilerDriver {
                 trait_solver_metrics,
                 defines,
                 log_level,
+                CheckProject::default(),
+            )
+        })
+    }
+
+    /// Check `inputs` as part of a manifest project, resolving and loading its dependency
+    /// graph and reading overlaid sources from memory instead of disk.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::check`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn check_project(
+        &self,
+        inputs: &[PathBuf],
+        target: &Target,
+        kind: ChicKind,
+        load_stdlib: bool,
+        trace_pipeline: bool,
+        defines: &[DefineFlag],
+        log_level: LogLevel,
+        project: CheckProject,
+    ) -> Result<FrontendReport> {
+        let driver = self.clone();
+        let inputs = inputs.to_vec();
+        let target = target.clone();
+        let defines = defines.to_vec();
+        run_with_stack(driver_stack_size(), move || {
+            driver.check_inner(
+                inputs,
+                target,
+                kind,
+                load_stdlib,
+                trace_pipeline,
+                false,
+                defines,
+                log_level,
+                project,
             )
         })
     }
@@ -353,6 +402,7 @@ impl CompilerDriver {
         trait_solver_metrics: bool,
         defines: Vec<DefineFlag>,
         log_level: LogLevel,
+        project: CheckProject,
     ) -> Result<FrontendReport> {
         let trace_enabled = resolve_trace_enabled(trace_pipeline, log_level);
         let solver_metrics_enabled =
@@ -397,6 +447,11 @@ impl CompilerDriver {
         let pipeline = CompilerPipelineBuilder::new("check", &inputs, &target, conditional_defines)
             .backend(Backend::Llvm)
             .kind(kind)
+            .manifest(project.manifest)
+            .workspace(project.workspace)
+            .source_overlays(Some(&project.overlays))
+            .read_only_packages(project.read_only_packages)
+            .incremental(project.incremental.as_deref())
             .load_stdlib(load_stdlib)
             .corelib_files(&corelib_files)
             .foundationlib_files(&foundationlib_files)
@@ -538,13 +593,14 @@ impl CompilerDriver {
                 crate::error::Error::internal("wasm build did not produce an artifact path")
             })?;
             let bytes = std::fs::read(artifact_path)?;
-            let wasm_options = wasm::resolve_wasm_options(
+            let mut wasm_options = wasm::resolve_wasm_options(
                 inputs
                     .first()
                     .map(PathBuf::as_path)
                     .unwrap_or_else(|| Path::new(".")),
                 &target,
             )?;
+            wasm::attach_source_map(&mut wasm_options, artifact_path);
             let outcome = if let Some(timeout) = run_timeout {
                 let (tx, rx) = std::sync::mpsc::channel();
                 let bytes_clone = bytes.clone();
@@ -771,6 +827,11 @@ impl CompilerDriver {
 
         let result = match backend {
             Backend::Wasm => self.run_tests_wasm(request, test_options.clone()),
+            Backend::Llvm if test_options.bench.is_some() => {
+                Err(crate::error::Error::Cli(crate::cli::CliError::new(
+                    "chic bench times benchcases on the wasm executor; use --backend wasm",
+                )))
+            }
             Backend::Llvm => self.run_tests_native(request, test_options.clone()),
             Backend::Cc1 => Err(crate::error::Error::internal(
                 "chic test does not support the cc1 backend",
@@ -943,13 +1004,35 @@ impl CompilerDriver {
             crate::error::Error::internal("wasm build did not produce an artifact path")
         })?;
         let bytes = fs::read(artifact_path)?;
+        wasm::attach_source_map(&mut wasm_options, artifact_path);
         WasmProgram::from_bytes(&bytes).map_err(|err| {
             crate::error::Error::internal(format!(
                 "failed to parse wasm module for test execution: {err}"
             ))
         })?;
 
-        let discovered = crate::mir::collect_test_metadata(&report.mir_module).len();
+        if let Some(bench) = &test_options.bench {
+            let (benchmarks, discovered, filtered_out) = bench::collect_wasm_benchmarks(
+                &report,
+                &bytes,
+                &wasm_options,
+                &test_options,
+                bench,
+                testcase_roots.as_deref(),
+            );
+            return Ok(TestRun {
+                report,
+                cases: Vec::new(),
+                discovered,
+                filtered_out,
+                chic_coverage: None,
+                benchmarks,
+            });
+        }
+        let discovered = crate::mir::collect_test_metadata(&report.mir_module)
+            .iter()
+            .filter(|meta| !meta.kind.is_bench())
+            .count();
         if let Some(settings) = &tests_settings {
             if settings.enabled
                 && settings.require_testcases
@@ -1028,6 +1111,7 @@ impl CompilerDriver {
             discovered,
             filtered_out,
             chic_coverage,
+            benchmarks: Vec::new(),
         })
     }
 
@@ -1109,7 +1193,10 @@ impl CompilerDriver {
         }
         let report = self.build(request)?;
 
-        let all_entries = collect_native_testcases(&report);
+        let all_entries: Vec<_> = collect_native_testcases(&report)
+            .into_iter()
+            .filter(|entry| !entry.meta.kind.is_bench())
+            .collect();
         let discovered = all_entries.len();
         if let Some(settings) = &tests_settings {
             if settings.enabled
@@ -1160,7 +1247,7 @@ impl CompilerDriver {
 
         let mut runnable_indices = Vec::new();
         for entry in &selected {
-            if entry.meta.parameters.is_empty()
+            if entry.meta.runner_parameters().next().is_none()
                 && report
                     .mir_module
                     .functions
@@ -1171,7 +1258,7 @@ impl CompilerDriver {
             }
         }
 
-        let mut native_results: HashMap<usize, NativeTestcaseReport> = HashMap::new();
+        let mut native_output = NativeTestOutput::default();
         let mut native_status: Option<ExitStatus> = None;
         let mut native_timed_out = false;
         if !runnable_indices.is_empty() {
@@ -1242,17 +1329,20 @@ impl CompilerDriver {
                 }
             }
             native_status = Some(output.status);
-            native_results = parse_native_test_output(&output.stdout);
+            native_output = parse_native_test_output(&output.stdout);
         }
 
         let mut cases: Vec<TestCaseResult> = Vec::new();
+        // Metadata of the cases the runner started, parallel to `cases`, for event replay.
+        let mut started: Vec<Option<TestCaseMetadata>> = Vec::new();
         let mut saw_failure = false;
         let missing_message =
             missing_native_result_message(native_status, native_timed_out, test_options.watchdog);
         for entry in selected {
             let meta = entry.meta;
-            if !meta.parameters.is_empty() {
+            if meta.runner_parameters().next().is_some() {
                 cases.push(skip_parameterized(&meta));
+                started.push(None);
                 continue;
             }
             if report
@@ -1263,44 +1353,77 @@ impl CompilerDriver {
             {
                 saw_failure = true;
                 cases.push(missing_function_result(&meta));
+                started.push(None);
                 continue;
             }
-            if let Some(result) = native_results.remove(&entry.index) {
-                let status = result.status;
-                if matches!(status, TestStatus::Failed) {
-                    saw_failure = true;
-                }
-                cases.push(TestCaseResult {
+            let was_started = native_output.started.contains(&entry.index);
+            started.push(was_started.then(|| meta.clone()));
+            if let Some(result) = native_output.results.remove(&entry.index) {
+                let source = meta.span.and_then(|span| report.files.path(span.file_id));
+                let mut case = TestCaseResult {
                     id: meta.id,
                     name: meta.name,
                     qualified_name: meta.qualified_name,
                     namespace: meta.namespace,
                     categories: meta.categories,
                     is_async: meta.is_async,
-                    status,
+                    status: result.status,
+                    timed_out: false,
                     message: result.message,
                     wasm_trace: None,
-                    duration: None,
-                });
+                    duration: result.duration,
+                };
+                if let Some(observations) = native_output.snapshots.remove(&entry.index) {
+                    snapshot::check_snapshots(
+                        &mut case,
+                        source,
+                        &observations,
+                        test_options.update_snapshots,
+                    );
+                }
+                if matches!(case.status, TestStatus::Failed) {
+                    saw_failure = true;
+                }
+                cases.push(case);
                 continue;
             }
             if test_options.fail_fast && saw_failure {
                 cases.push(skip_after_fail_fast(&meta));
                 continue;
             }
+            if native_timed_out && !was_started && !native_output.started.is_empty() {
+                // The runner was killed while an earlier testcase was still running.
+                cases.push(skip_after_runner_timeout(&meta));
+                continue;
+            }
             saw_failure = true;
-            cases.push(missing_native_result(&meta, missing_message.as_deref()));
+            cases.push(missing_native_result(
+                &meta,
+                missing_message.as_deref(),
+                native_timed_out,
+            ));
         }
 
         let mut chic_coverage = None;
         if let Some(mut wasm_request) = wasm_request.take() {
             wasm_request.coverage = coverage_required;
-            let wasm_run = self.run_tests_wasm(wasm_request, test_options)?;
+            let wasm_options = TestOptions {
+                events: None,
+                ..test_options.clone()
+            };
+            let wasm_run = self.run_tests_wasm(wasm_request, wasm_options)?;
             if let Some(coverage) = wasm_run.chic_coverage {
                 chic_coverage = Some(coverage);
             }
             merge_wasm_results(&mut cases, &wasm_run.cases);
         }
+        // The runner's output is only available once it exits; replay its events in run order.
+        for (case, meta) in cases.iter().zip(&started) {
+            if let Some(meta) = meta {
+                test_options.emit(&TestEvent::Started(meta));
+            }
+            test_options.emit(&TestEvent::Finished(case));
+        }
 
         if trace_enabled {
             tracing::info!(
@@ -1325,6 +1448,7 @@ impl CompilerDriver {
             discovered,
             filtered_out,
             chic_coverage,
+            benchmarks: Vec::new(),
         })
     }
 
@@ -1404,6 +1528,16 @@ struct NativeTestcaseEntry {
 struct NativeTestcaseReport {
     status: TestStatus,
     message: Option<String>,
+    duration: Option<Duration>,
+}
+
+#[derive(Debug, Default)]
+struct NativeTestOutput {
+    results: HashMap<usize, NativeTestcaseReport>,
+    /// Testcase indexes in the order the runner announced them via `CHIC_TEST_BEGIN`.
+    started: Vec<usize>,
+    /// Values recorded by snapshot assertions, keyed by testcase index.
+    snapshots: HashMap<usize, Vec<snapshot::SnapshotObservation>>,
 }
 
 fn collect_native_testcases(report: &FrontendReport) -> Vec<NativeTestcaseEntry> {
@@ -1426,6 +1560,9 @@ fn collect_native_testcases(report: &FrontendReport) -> Vec<NativeTestcaseEntry>
                 namespace,
                 categories: Vec::new(),
                 parameters: Vec::new(),
+                kind: TestCaseKind::Unit,
+                runs: None,
+                row: None,
                 is_async: function.is_async,
                 span: function.span,
             }
@@ -1438,10 +1575,39 @@ fn collect_native_testcases(report: &FrontendReport) -> Vec<NativeTestcaseEntry>
     entries
 }
 
-fn parse_native_test_output(stdout: &[u8]) -> HashMap<usize, NativeTestcaseReport> {
-    let mut results = HashMap::new();
+fn parse_native_test_output(stdout: &[u8]) -> NativeTestOutput {
+    let mut output = NativeTestOutput::default();
     let text = String::from_utf8_lossy(stdout);
     for line in text.lines() {
+        if let Some(rest) = line.strip_prefix("CHIC_TEST_BEGIN\t") {
+            let index = rest
+                .split('\t')
+                .next()
+                .and_then(|raw| raw.parse::<usize>().ok());
+            if let Some(index) = index {
+                output.started.push(index);
+            }
+            continue;
+        }
+        if let Some(rest) = line.strip_prefix("CHIC_SNAPSHOT\t") {
+            let mut fields = rest.splitn(3, '\t');
+            let (Some(index), Some(name), Some(value)) = (
+                fields.next().and_then(|raw| raw.parse::<usize>().ok()),
+                fields.next(),
+                fields.next(),
+            ) else {
+                continue;
+            };
+            output
+                .snapshots
+                .entry(index)
+                .or_default()
+                .push(snapshot::SnapshotObservation {
+                    name: snapshot::unescape_status_field(name),
+                    value: snapshot::unescape_status_field(value),
+                });
+            continue;
+        }
         if !line.starts_with("CHIC_TESTCASE\t") {
             continue;
         }
@@ -1459,15 +1625,30 @@ fn parse_native_test_output(stdout: &[u8]) -> HashMap<usize, NativeTestcaseRepor
             Some("SKIP") => TestStatus::Skipped,
             _ => continue,
         };
-        let _ = parts.next();
+        // The runtime executor reports milliseconds here; the std startup shim reports the name.
+        let duration = parts
+            .next()
+            .and_then(|raw| raw.trim().parse::<f64>().ok())
+            .filter(|millis| millis.is_finite() && *millis >= 0.0)
+            .map(|millis| Duration::from_secs_f64(millis / 1000.0));
         let message = parts
             .next()
             .map(str::trim)
             .filter(|value| !value.is_empty())
             .map(|value| value.to_string());
-        results.insert(index, NativeTestcaseReport { status, message });
+        if !output.started.contains(&index) {
+            output.started.push(index);
+        }
+        output.results.insert(
+            index,
+            NativeTestcaseReport {
+                status,
+                message,
+                duration: duration.filter(|_| status != TestStatus::Skipped),
+            },
+        );
     }
-    results
+    output
 }
 
 fn missing_native_result_message(
@@ -1644,7 +1825,11 @@ fn wait_for_child_timeout(
     }
 }
 
-fn missing_native_result(meta: &TestCaseMetadata, message: Option<&str>) -> TestCaseResult {
+fn missing_native_result(
+    meta: &TestCaseMetadata,
+    message: Option<&str>,
+    timed_out: bool,
+) -> TestCaseResult {
     TestCaseResult {
         id: meta.id.clone(),
         name: meta.name.clone(),
@@ -1653,6 +1838,7 @@ fn missing_native_result(meta: &TestCaseMetadata, message: Option<&str>) -> Test
         categories: meta.categories.clone(),
         is_async: meta.is_async,
         status: TestStatus::Failed,
+        timed_out,
         message: Some(
             message
                 .unwrap_or("missing testcase result from native runner")
@@ -1681,6 +1867,7 @@ fn merge_wasm_results(cases: &mut [TestCaseResult], wasm_cases: &[TestCaseResult
             None => format!("wasm: {wasm_message}"),
         };
         case.status = TestStatus::Failed;
+        case.timed_out |= wasm_case.timed_out;
         case.message = Some(message);
     }
 }
@@ -1714,12 +1901,29 @@ fn skip_after_fail_fast(meta: &TestCaseMetadata) -> TestCaseResult {
         categories: meta.categories.clone(),
         is_async: meta.is_async,
         status: TestStatus::Skipped,
+        timed_out: false,
         message: Some("skipped due to --fail-fast".into()),
         wasm_trace: None,
         duration: None,
     }
 }
 
+fn skip_after_runner_timeout(meta: &TestCaseMetadata) -> TestCaseResult {
+    TestCaseResult {
+        id: meta.id.clone(),
+        name: meta.name.clone(),
+        qualified_name: meta.qualified_name.clone(),
+        namespace: meta.namespace.clone(),
+        categories: meta.categories.clone(),
+        is_async: meta.is_async,
+        status: TestStatus::Skipped,
+        timed_out: false,
+        message: Some("not run: native test runner timed out on an earlier testcase".into()),
+        wasm_trace: None,
+        duration: None,
+    }
+}
+
 fn missing_function_result(meta: &TestCaseMetadata) -> TestCaseResult {
     TestCaseResult {
         id: meta.id.clone(),
@@ -1729,6 +1933,7 @@ fn missing_function_result(meta: &TestCaseMetadata) -> TestCaseResult {
         categories: meta.categories.clone(),
         is_async: meta.is_async,
         status: TestStatus::Failed,
+        timed_out: false,
         message: Some(format!(
             "test metadata refers to missing function index {}",
             meta.function_index
@@ -1738,7 +1943,8 @@ fn missing_function_result(meta: &TestCaseMetadata) -> TestCaseResult {
     }
 }
 
-fn skip_parameterized(meta: &TestCaseMetadata) -> TestCaseResult {
+/// The result reported for a testcase whose parameters need a runner the backend lacks.
+pub(crate) fn skip_parameterized(meta: &TestCaseMetadata) -> TestCaseResult {
     TestCaseResult {
         id: meta.id.clone(),
         name: meta.name.clone(),
@@ -1747,7 +1953,15 @@ fn skip_parameterized(meta: &TestCaseMetadata) -> TestCaseResult {
         categories: meta.categories.clone(),
         is_async: meta.is_async,
         status: TestStatus::Skipped,
-        message: Some("parameterized testcases are not supported yet".into()),
+        timed_out: false,
+        message: Some(if meta.kind.is_generative() {
+            format!(
+                "{} runs on the wasm backend (use --backend wasm)",
+                meta.kind.keyword()
+            )
+        } else {
+            "parameters need a @fixture, @cases row or default value".into()
+        }),
         wasm_trace: None,
         duration: None,
     }
@@ -11,13 +11,12 @@ use crate::drop_glue::{SynthesisedDropGlue, synthesise_drop_glue};
 use crate::eq_glue::{SynthesisedEqGlue, synthesise_eq_glue};
 use crate::error::Result;
 use crate::frontend::ast::{CrateStdSetting, Item, Module as AstModule, NamespaceDecl};
-use crate::frontend::cfg::apply_cfg;
-use crate::frontend::conditional::{ConditionalDefines, preprocess};
+use crate::frontend::conditional::ConditionalDefines;
 use crate::frontend::diagnostics::{Diagnostic, FileCache, Span};
 use crate::frontend::import_resolver::ImportResolver;
 use crate::frontend::lexer::{TokenKind, lex};
-use crate::frontend::macro_expander::{MacroRegistry, expand_module as expand_macros};
-use crate::frontend::parser::{ParseResult, parse_module_in_file};
+use crate::frontend::macro_expander::MacroRegistry;
+use crate::frontend::parser::ParseResult;
 use crate::hash_glue::{SynthesisedHashGlue, synthesise_hash_glue};
 use crate::language::{features_from_defines, set_language_features};
 use crate::lint::{LintConfig, LintDiagnostic, LintModuleInfo, run_lints};
@@ -29,12 +28,13 @@ use crate::mir::{
     intern_raw_strings, lower_module_with_units_and_hook, verify_body,
 };
 use crate::monomorphize::{MonomorphizationSummary, analyse_module};
-use crate::package::resolver::{ResolveOptions, resolve_dependencies};
+use crate::package::features::{self, FeatureRequest};
+use crate::package::resolver::{LockMode, ResolveOptions, resolve_dependencies};
 use crate::perf::PerfMetadata;
 use crate::primitives::PrimitiveDescriptor;
 use crate::runtime_package::ResolvedRuntime;
 use crate::target::Target;
-use crate::threading::{ThreadRuntimeMode, configure_thread_runtime};
+use crate::threading::{self, ThreadRuntimeMode, configure_thread_runtime};
 use crate::type_metadata::{SynthesisedTypeMetadata, synthesise_type_metadata};
 use crate::typeck::{
     AsyncSignatureInfo, PackageContext, TraitSolverMetrics, TypeCheckResult, TypeConstraint,
@@ -42,6 +42,7 @@ use crate::typeck::{
 };
 use tracing::info;
 
+use super::incremental::{self, ModuleInput, ModuleState};
 use super::report::{ModuleArtifact, ModuleReport, slice_mir_module};
 use super::{FrontendReport, GeneratedModuleIr};
 
@@ -50,7 +51,7 @@ mod docs;
 mod package_rules;
 use package_rules::{
     append_external_diagnostics, attach_manifest_issues, attach_package_resolution_status,
-    enforce_namespace_rules, validate_package_imports,
+    enforce_namespace_rules, report_unknown_features, validate_package_imports,
 };
 mod execute;
 mod logging;
@@ -81,6 +82,10 @@ pub(crate) struct CompilerPipelineBuilder<'a> {
     extra_primitives_hook: Option<fn() -> Vec<PrimitiveDescriptor>>,
     doc_enforcement: MissingDocsRule,
     restore_enabled: bool,
+    locked: bool,
+    read_only_packages: bool,
+    source_overlays: Option<&'a HashMap<PathBuf, String>>,
+    incremental: Option<&'a [ModuleState]>,
 }
 
 impl<'a> CompilerPipelineBuilder<'a> {
@@ -113,6 +118,10 @@ impl<'a> CompilerPipelineBuilder<'a> {
             extra_primitives_hook: None,
             doc_enforcement: MissingDocsRule::default(),
             restore_enabled: true,
+            locked: false,
+            read_only_packages: false,
+            source_overlays: None,
+            incremental: None,
         }
     }
 
@@ -131,6 +140,32 @@ impl<'a> CompilerPipelineBuilder<'a> {
         self
     }
 
+    /// Require dependency resolution to match `manifest.lock` exactly.
+    pub fn locked(mut self, locked: bool) -> Self {
+        self.locked = locked;
+        self
+    }
+
+    /// Resolve dependencies from `manifest.lock` and the offline cache without writing the
+    /// lock or fetching anything.
+    pub fn read_only_packages(mut self, read_only: bool) -> Self {
+        self.read_only_packages = read_only;
+        self
+    }
+
+    /// Sources that replace the on-disk contents of the keyed paths (editor buffers).
+    pub fn source_overlays(mut self, overlays: Option<&'a HashMap<PathBuf, String>>) -> Self {
+        self.source_overlays = overlays;
+        self
+    }
+
+    /// Fingerprint every module, skipping body checks for the ones unchanged since the
+    /// build that recorded `previous`.
+    pub fn incremental(mut self, previous: Option<&'a [ModuleState]>) -> Self {
+        self.incremental = previous;
+        self
+    }
+
     pub fn backend(mut self, backend: Backend) -> Self {
         self.backend = backend;
         self
@@ -213,6 +248,14 @@ impl<'a> CompilerPipelineBuilder<'a> {
         defines.set_string("backend", self.backend.as_str());
         defines.set_string("KIND", self.kind.as_str());
         defines.set_string("kind", self.kind.as_str());
+        // Unknown features are reported as a `PKG0105` diagnostic once modules are loaded.
+        if let Some(manifest) = &self.manifest
+            && let Ok(request) = FeatureRequest::for_root(manifest, &defines)
+        {
+            for feature in features::activate(manifest, &request).features {
+                defines.set_feature(&feature, true);
+            }
+        }
         CompilerPipeline {
             config: PipelineConfig {
                 command: self.command,
@@ -237,6 +280,10 @@ impl<'a> CompilerPipelineBuilder<'a> {
                 extra_primitives_hook: self.extra_primitives_hook,
                 doc_enforcement: self.doc_enforcement,
                 restore_enabled: self.restore_enabled,
+                locked: self.locked,
+                read_only_packages: self.read_only_packages,
+                source_overlays: self.source_overlays,
+                incremental: self.incremental,
             },
         }
     }
@@ -265,6 +312,10 @@ struct PipelineConfig<'a> {
     extra_primitives_hook: Option<fn() -> Vec<PrimitiveDescriptor>>,
     doc_enforcement: MissingDocsRule,
     restore_enabled: bool,
+    locked: bool,
+    read_only_packages: bool,
+    source_overlays: Option<&'a HashMap<PathBuf, String>>,
+    incremental: Option<&'a [ModuleState]>,
 }
 
 pub(crate) struct CompilerPipeline<'a> {
@@ -520,6 +571,7 @@ impl FrontendState {
             eq_glue: self.eq_glue,
             type_metadata: self.type_metadata,
             trait_solver_metrics: self.trait_solver_metrics,
+            module_states: self.module_states,
         }
     }
 }
@@ -621,6 +673,8 @@ pub(crate) struct FrontendState {
     pub(crate) perf_metadata: PerfMetadata,
     pub(crate) lint_diagnostics: Vec<LintDiagnostic>,
     pub(crate) doc_diagnostics: Vec<Diagnostic>,
+    /// Module fingerprints for the next incremental build; empty unless requested.
+    pub(crate) module_states: Vec<ModuleState>,
 }
 
 const PKG_FRIEND_SELF_PREFIX: &str = "PKG0001";
@@ -632,6 +686,7 @@ const PKG_PACKAGE_UNRESOLVED: &str = "PKG0101";
 const PKG_PACKAGE_VERSION_MISMATCH: &str = "PKG0102";
 const PKG_PACKAGE_STD_MISSING: &str = "PKG0103";
 const PKG_PACKAGE_DIRECTIVES_DISALLOWED: &str = "PKG0104";
+const PKG_FEATURE_UNKNOWN: &str = "PKG0105";
 
 fn package_error(code: &'static str, message: impl Into<String>, span: Option<Span>) -> Diagnostic {
     let mut message = message.into();
@@ -91,6 +91,7 @@ impl<'a> CompilerPipeline<'a> {
             }
 
             let mut injected: HashSet<PathBuf> = HashSet::new();
+            let mut paths = Vec::new();
             for path in [startup_override, async_override] {
                 if !injected.insert(path.clone()) {
                     continue;
@@ -99,67 +100,26 @@ impl<'a> CompilerPipeline<'a> {
                     continue;
                 }
                 let canonical = fs::canonicalize(&path).unwrap_or_else(|_| path.clone());
-                if !loaded_modules.insert(canonical) {
-                    continue;
+                if loaded_modules.insert(canonical) {
+                    paths.push(path);
                 }
-                let read_start = Instant::now();
-                let mut source = fs::read_to_string(&path)?;
-                logging::log_stage_with_path(
-                    trace_enabled,
-                    &metadata,
-                    "frontend.stdlib.read_source",
-                    &path,
-                    read_start,
-                );
-
-                let parse_start = Instant::now();
-                let mut preprocess_result = preprocess(&source, &self.config.defines);
-                if let Some(rewritten) = preprocess_result.rewritten {
-                    source = rewritten;
-                }
-                let file_id = files.add_file(path.clone(), source.clone());
-                module_loader::stamp_file_id(&mut preprocess_result.diagnostics, file_id);
-                let mut parse = match parse_module_in_file(&source, file_id) {
-                    Ok(parsed) => parsed,
-                    Err(err) => {
-                        log_stdlib_parse_error(&path, &source, &err);
-                        return Err(err.with_file(path.clone(), source).into());
-                    }
-                };
-                parse.diagnostics.extend(preprocess_result.diagnostics);
-                let mut cfg_diags = {
-                    let mut module = parse.module_mut();
-                    apply_cfg(&mut module, &self.config.defines)
-                };
-                parse.diagnostics.append(&mut cfg_diags);
-                logging::log_stage_with_path(
-                    trace_enabled,
-                    &metadata,
-                    "frontend.stdlib.parse",
-                    &path,
-                    parse_start,
-                );
-
-                let macro_start = Instant::now();
-                let expansion = {
-                    let mut module = parse.module_mut();
-                    expand_macros(&mut module, &macro_registry)
-                };
-                parse.diagnostics.extend(expansion.diagnostics);
-                let mut cfg_diags = {
-                    let mut module = parse.module_mut();
-                    apply_cfg(&mut module, &self.config.defines)
-                };
-                parse.diagnostics.append(&mut cfg_diags);
-                parse.module = parse.module_owned();
-                logging::log_stage_with_path(
-                    trace_enabled,
-                    &metadata,
-                    "frontend.stdlib.expand_macros",
-                    &path,
-                    macro_start,
-                );
-
+            }
+            let parsed = module_loader::parse_source_files(
+                paths,
+                |path| Ok(fs::read_to_string(path)?),
+                "frontend.stdlib",
+                &mut files,
+                &self.config.defines,
+                &macro_registry,
+                trace_enabled,
+                &metadata,
+            )?;
+            for module_loader::ParsedFile {
+                path,
+                source,
+                parse,
+            } in parsed
+            {
                 let manifest = override_manifests.get(&path).cloned();
                 modules.push(FrontendModuleState {
                     input: path,
@@ -183,77 +143,38 @@ impl<'a> CompilerPipeline<'a> {
 
         modules.reserve(self.config.inputs.len());
 
+        let mut paths = Vec::new();
         for path in self.config.inputs {
             let canonical = fs::canonicalize(path).unwrap_or_else(|_| path.clone());
-            if !loaded_modules.insert(canonical) {
-                continue;
+            if loaded_modules.insert(canonical) {
+                paths.push(path.clone());
             }
-            let read_start = Instant::now();
-            let mut source = fs::read_to_string(path)?;
-            logging::log_stage_with_path(
-                trace_enabled,
-                &metadata,
-                "frontend.read_source",
-                path,
-                read_start,
-            );
-
-            let parse_start = Instant::now();
-            let mut preprocess_result = preprocess(&source, &self.config.defines);
-            if let Some(rewritten) = preprocess_result.rewritten {
-                source = rewritten;
-            }
-            let file_id = files.add_file(path.clone(), source.clone());
-            module_loader::stamp_file_id(&mut preprocess_result.diagnostics, file_id);
-            let mut parse = match parse_module_in_file(&source, file_id) {
-                Ok(parsed) => parsed,
-                Err(err) => {
-                    log_stdlib_parse_error(path, &source, &err);
-                    return Err(err.with_file(path.clone(), source).into());
-                }
-            };
-            parse.diagnostics.extend(preprocess_result.diagnostics);
-            let mut cfg_diags = {
-                let mut module = parse.module_mut();
-                apply_cfg(&mut module, &self.config.defines)
-            };
-            parse.diagnostics.append(&mut cfg_diags);
-            logging::log_stage_with_path(
-                trace_enabled,
-                &metadata,
-                "frontend.parse",
-                path,
-                parse_start,
-            );
-
-            let macro_start = Instant::now();
-            let expansion = {
-                let mut module = parse.module_mut();
-                expand_macros(&mut module, &macro_registry)
-            };
-            parse.diagnostics.extend(expansion.diagnostics);
-            let mut cfg_diags = {
-                let mut module = parse.module_mut();
-                apply_cfg(&mut module, &self.config.defines)
-            };
-            parse.diagnostics.append(&mut cfg_diags);
-            parse.module = parse.module_owned();
-            logging::log_stage_with_path(
-                trace_enabled,
-                &metadata,
-                "frontend.expand_macros",
-                path,
-                macro_start,
-            );
-
+        }
+        let parsed = module_loader::parse_source_files(
+            paths,
+            |path| module_loader::read_source(path, self.config.source_overlays),
+            "frontend",
+            &mut files,
+            &self.config.defines,
+            &macro_registry,
+            trace_enabled,
+            &metadata,
+        )?;
+        for module_loader::ParsedFile {
+            path,
+            source,
+            parse,
+        } in parsed
+        {
+            let manifest = override_manifests
+                .get(&path)
+                .cloned()
+                .or_else(|| self.config.manifest.clone());
             modules.push(FrontendModuleState {
-                input: path.clone(),
+                input: path,
                 source,
                 parse,
-                manifest: override_manifests
-                    .get(path)
-                    .cloned()
-                    .or_else(|| self.config.manifest.clone()),
+                manifest,
                 is_stdlib: false,
                 requires_codegen: true,
             });
@@ -375,6 +296,7 @@ impl<'a> CompilerPipeline<'a> {
 
         if let Some(manifest) = &self.config.manifest {
             attach_manifest_issues(manifest, &mut modules);
+            report_unknown_features(manifest, &self.config.defines, &mut modules);
             validate_package_imports(manifest, &mut modules);
             enforce_namespace_rules(manifest, self.config.workspace.as_ref(), &mut modules);
             package_std::enforce_std_dependency(manifest, self.config.load_stdlib, &mut modules);
@@ -386,7 +308,19 @@ impl<'a> CompilerPipeline<'a> {
             if let Some(manifest) = &self.config.manifest {
                 if let Some(manifest_path) = manifest.path() {
                     let lockfile = manifest_path.parent().map(|dir| dir.join("manifest.lock"));
-                    let options = ResolveOptions::from_env(lockfile);
+                    let lock_mode = if self.config.read_only_packages {
+                        LockMode::ReadOnly
+                    } else if self.config.locked {
+                        LockMode::Locked
+                    } else {
+                        LockMode::Prefer
+                    };
+                    let options = ResolveOptions::from_env(lockfile)
+                        .with_lock_mode(lock_mode)
+                        .with_features(
+                            FeatureRequest::for_root(manifest, &self.config.defines)
+                                .unwrap_or_else(|_| FeatureRequest::defaults()),
+                        );
                     if std::env::var_os("CHIC_DEBUG_PACKAGE_TRIM").is_some() {
                         let deps: Vec<String> = manifest
                             .dependencies()
@@ -452,15 +386,17 @@ impl<'a> CompilerPipeline<'a> {
                         }
                         if let Some(package) = resolved.remove(&name) {
                             let is_stdlib = package_std::is_std_name(&package.name);
+                            let defines = self.config.defines.with_features(&package.features);
                             let mut dep_modules = module_loader::parse_dependency_modules(
                                 &package,
                                 &mut files,
-                                &self.config.defines,
+                                &defines,
                                 &macro_registry,
                                 &mut loaded_modules,
                                 is_stdlib,
                                 trace_enabled,
                                 &metadata,
+                                self.config.source_overlays,
                             )?;
                             attach_manifest_issues(&package.manifest, &mut dep_modules);
                             validate_package_imports(&package.manifest, &mut dep_modules);
@@ -754,10 +690,56 @@ impl<'a> CompilerPipeline<'a> {
                 ImportResolver::build(&module_ref)
             })
             .collect();
+        let (mut module_states, unchanged_units) = match self.config.incremental {
+            Some(previous) => {
+                let asts: Vec<_> = modules
+                    .iter()
+                    .map(|module| module.parse.module_ref())
+                    .collect();
+                let inputs: Vec<ModuleInput<'_>> = modules
+                    .iter()
+                    .zip(&asts)
+                    .map(|(module, ast)| ModuleInput {
+                        path: module.input.display().to_string(),
+                        ast,
+                        source: module.is_stdlib.then_some(module.source.as_str()),
+                        package: module
+                            .manifest
+                            .as_ref()
+                            .and_then(|manifest| manifest.package())
+                            .and_then(|pkg| pkg.name.clone()),
+                        package_dependencies: module
+                            .manifest
+                            .as_ref()
+                            .map(|manifest| {
+                                manifest
+                                    .dependencies()
+                                    .iter()
+                                    .map(|dep| dep.name.clone())
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                    })
+                    .collect();
+                let defines = format!("{:?}", self.config.defines);
+                incremental::plan_modules(&inputs, previous, &defines)
+            }
+            None => (Vec::new(), HashSet::new()),
+        };
+        if trace_enabled && self.config.incremental.is_some() {
+            info!(
+                target: "pipeline",
+                stage = "frontend.incremental",
+                command = metadata.command,
+                modules = modules.len(),
+                unchanged = unchanged_units.len()
+            );
+        }
         let package_context = PackageContext {
             item_units: Some(item_units.clone()),
             unit_packages: unit_packages.clone(),
             unit_import_resolvers: Some(unit_import_resolvers),
+            unchanged_units,
         };
         let TypeCheckResult {
             diagnostics: mut type_diagnostics,
@@ -812,14 +794,17 @@ impl<'a> CompilerPipeline<'a> {
         let verify_start = Instant::now();
         let mut mir_verification = Vec::new();
         if !suppress_bootstrap_diagnostics {
-            for function in &mir_module.functions {
-                if let Err(errors) = verify_body(&function.body) {
-                    mir_verification.push(super::super::MirVerificationIssue {
+            mir_verification = threading::parallel_map(&mir_module.functions, |function| {
+                verify_body(&function.body)
+                    .err()
+                    .map(|errors| super::super::MirVerificationIssue {
                         function: function.name.clone(),
                         errors,
-                    });
-                }
-            }
+                    })
+            })
+            .into_iter()
+            .flatten()
+            .collect();
         }
         logging::log_stage(
             trace_enabled,
@@ -1005,6 +990,10 @@ impl<'a> CompilerPipeline<'a> {
             type_diagnostics.clear();
             mir_lowering_diagnostics.clear();
         }
+        if !module_states.is_empty() {
+            let file_ids: Vec<_> = modules.iter().map(|module| module.parse.file_id).collect();
+            incremental::record_diagnostics(&mut module_states, &file_ids, &type_diagnostics);
+        }
 
         Ok(FrontendState {
             modules,
@@ -1033,6 +1022,7 @@ impl<'a> CompilerPipeline<'a> {
             perf_metadata,
             lint_diagnostics,
             doc_diagnostics,
+            module_states,
         })
     }
 }
@@ -10,9 +10,11 @@ use crate::frontend::cfg::apply_cfg;
 use crate::frontend::conditional::{ConditionalDefines, preprocess};
 use crate::frontend::diagnostics::{Diagnostic, FileCache, FileId};
 use crate::frontend::macro_expander::{MacroRegistry, expand_module as expand_macros};
-use crate::frontend::parser::parse_module_in_file;
+use crate::frontend::parser::{ParseError, ParseResult, parse_module_in_file};
+use crate::frontend::test_expansion::{TestProviders, expand_testcases};
 use crate::manifest::Manifest;
 use crate::package::resolver::ResolvedPackage;
+use crate::threading;
 
 use super::logging;
 use super::{FrontendModuleState, log_stdlib_parse_error};
@@ -37,71 +39,30 @@ pub(super) fn load_standard_library(
     let mut manifest_cache: HashMap<PathBuf, Manifest> = HashMap::new();
     let stage_prefix = format!("frontend.{library_label}");
     let filtered_files = filter_std_bootstrap_files(stdlib_files.to_vec());
+    let mut paths = Vec::new();
     for relative in filtered_files {
-        let requires_codegen = true;
         let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(relative);
         let canonical = fs::canonicalize(&path).unwrap_or_else(|_| path.clone());
-        if !loaded_modules.insert(canonical) {
-            continue;
+        if loaded_modules.insert(canonical) {
+            paths.push(path);
         }
-        let read_start = Instant::now();
-        let mut source = fs::read_to_string(&path)?;
-        logging::log_stage_with_path(
-            trace_enabled,
-            metadata,
-            &format!("{stage_prefix}.read_source"),
-            &path,
-            read_start,
-        );
-
-        let parse_start = Instant::now();
-        let mut preprocess_result = preprocess(&source, defines);
-        if let Some(rewritten) = preprocess_result.rewritten {
-            source = rewritten;
-        }
-        let file_id = files.add_file(path.clone(), source.clone());
-        stamp_file_id(&mut preprocess_result.diagnostics, file_id);
-        let mut parse = match parse_module_in_file(&source, file_id) {
-            Ok(parsed) => parsed,
-            Err(err) => {
-                log_stdlib_parse_error(&path, &source, &err);
-                return Err(err.with_file(path.clone(), source).into());
-            }
-        };
-        parse.diagnostics.extend(preprocess_result.diagnostics);
-        let mut cfg_diags = {
-            let mut module = parse.module_mut();
-            apply_cfg(&mut module, defines)
-        };
-        parse.diagnostics.append(&mut cfg_diags);
-        logging::log_stage_with_path(
-            trace_enabled,
-            metadata,
-            &format!("{stage_prefix}.parse"),
-            &path,
-            parse_start,
-        );
-
-        let macro_start = Instant::now();
-        let expansion = {
-            let mut module = parse.module_mut();
-            expand_macros(&mut module, macro_registry)
-        };
-        parse.diagnostics.extend(expansion.diagnostics);
-        let mut cfg_diags = {
-            let mut module = parse.module_mut();
-            apply_cfg(&mut module, defines)
-        };
-        parse.diagnostics.append(&mut cfg_diags);
-        parse.module = parse.module_owned();
-        logging::log_stage_with_path(
-            trace_enabled,
-            metadata,
-            &format!("{stage_prefix}.expand_macros"),
-            &path,
-            macro_start,
-        );
-
+    }
+    let parsed = parse_source_files(
+        paths,
+        |path| Ok(fs::read_to_string(path)?),
+        &stage_prefix,
+        files,
+        defines,
+        macro_registry,
+        trace_enabled,
+        metadata,
+    )?;
+    for ParsedFile {
+        path,
+        source,
+        parse,
+    } in parsed
+    {
         let manifest = Manifest::discover(&path)?.and_then(|manifest| {
             let Some(manifest_path) = manifest.path().map(PathBuf::from) else {
                 return Some(manifest);
@@ -119,12 +80,151 @@ pub(super) fn load_standard_library(
             parse,
             manifest,
             is_stdlib: true,
-            requires_codegen,
+            requires_codegen: true,
         });
     }
     Ok(modules)
 }
 
+/// A source file after parsing, macro expansion and `@cfg` filtering.
+pub(super) struct ParsedFile {
+    pub(super) path: PathBuf,
+    pub(super) source: String,
+    pub(super) parse: ParseResult,
+}
+
+struct PreprocessedFile {
+    source: String,
+    diagnostics: Vec<Diagnostic>,
+}
+
+/// Read, preprocess, parse and macro-expand `paths` across the configured jobs.
+///
+/// File ids are assigned in input order on the calling thread, and the first error in
+/// input order is returned, so the outcome matches processing the files one by one.
+pub(super) fn parse_source_files(
+    paths: Vec<PathBuf>,
+    read: impl Fn(&Path) -> Result<String> + Sync,
+    stage_prefix: &str,
+    files: &mut FileCache,
+    defines: &ConditionalDefines,
+    macro_registry: &MacroRegistry,
+    trace_enabled: bool,
+    metadata: &logging::PipelineLogMetadata,
+) -> Result<Vec<ParsedFile>> {
+    let preprocessed = threading::parallel_map(&paths, |path| -> Result<PreprocessedFile> {
+        let read_start = Instant::now();
+        let source = read(path)?;
+        logging::log_stage_with_path(
+            trace_enabled,
+            metadata,
+            &format!("{stage_prefix}.read_source"),
+            path,
+            read_start,
+        );
+        let preprocess_result = preprocess(&source, defines);
+        Ok(PreprocessedFile {
+            source: preprocess_result.rewritten.unwrap_or(source),
+            diagnostics: preprocess_result.diagnostics,
+        })
+    });
+
+    let mut pending = Vec::with_capacity(paths.len());
+    let mut read_error = None;
+    for (path, preprocessed) in paths.into_iter().zip(preprocessed) {
+        match preprocessed {
+            Ok(mut preprocessed) => {
+                let file_id = files.add_file(path.clone(), preprocessed.source.clone());
+                stamp_file_id(&mut preprocessed.diagnostics, file_id);
+                pending.push((path, preprocessed, file_id));
+            }
+            Err(err) => {
+                read_error = Some(err);
+                break;
+            }
+        }
+    }
+
+    let parsed = threading::parallel_map(
+        &pending,
+        |(path, preprocessed, file_id)| -> std::result::Result<ParseResult, ParseError> {
+            let parse_start = Instant::now();
+            let mut parse = parse_module_in_file(&preprocessed.source, *file_id)?;
+            parse
+                .diagnostics
+                .extend(preprocessed.diagnostics.iter().cloned());
+            let mut cfg_diags = {
+                let mut module = parse.module_mut();
+                apply_cfg(&mut module, defines)
+            };
+            parse.diagnostics.append(&mut cfg_diags);
+            logging::log_stage_with_path(
+                trace_enabled,
+                metadata,
+                &format!("{stage_prefix}.parse"),
+                path,
+                parse_start,
+            );
+
+            let macro_start = Instant::now();
+            let expansion = {
+                let mut module = parse.module_mut();
+                expand_macros(&mut module, macro_registry)
+            };
+            parse.diagnostics.extend(expansion.diagnostics);
+            let mut cfg_diags = {
+                let mut module = parse.module_mut();
+                apply_cfg(&mut module, defines)
+            };
+            parse.diagnostics.append(&mut cfg_diags);
+            parse.module = parse.module_owned();
+            logging::log_stage_with_path(
+                trace_enabled,
+                metadata,
+                &format!("{stage_prefix}.expand_macros"),
+                path,
+                macro_start,
+            );
+            Ok(parse)
+        },
+    );
+
+    let mut modules = Vec::with_capacity(pending.len());
+    for ((path, preprocessed, _), parse) in pending.into_iter().zip(parsed) {
+        let source = preprocessed.source;
+        match parse {
+            Ok(parse) => modules.push(ParsedFile {
+                path,
+                source,
+                parse,
+            }),
+            Err(err) => {
+                log_stdlib_parse_error(&path, &source, &err);
+                return Err(err.with_file(path, source).into());
+            }
+        }
+    }
+    if let Some(err) = read_error {
+        return Err(err);
+    }
+
+    // Hooks and fixtures apply to their namespace in every file of the set.
+    let mut providers = TestProviders::default();
+    for file in &mut modules {
+        let mut diagnostics = providers.collect(&file.parse.module_ref());
+        file.parse.diagnostics.append(&mut diagnostics);
+    }
+    for file in &mut modules {
+        let mut diagnostics = {
+            let mut module = file.parse.module_mut();
+            expand_testcases(&mut module, &providers)
+        };
+        file.parse.diagnostics.append(&mut diagnostics);
+        file.parse.module = file.parse.module_owned();
+    }
+    Ok(modules)
+}
+
 pub(super) fn stamp_file_id(diagnostics: &mut [Diagnostic], file_id: FileId) {
     for diagnostic in diagnostics {
         if let Some(label) = diagnostic.primary_label.as_mut() {
@@ -140,6 +240,25 @@ pub(super) fn stamp_file_id(diagnostics: &mut [Diagnostic], file_id: FileId) {
     }
 }
 
+/// Read a module source, preferring an in-memory overlay registered for the path.
+pub(super) fn read_source(
+    path: &Path,
+    overlays: Option<&HashMap<PathBuf, String>>,
+) -> Result<String> {
+    if let Some(overlays) = overlays {
+        if let Some(source) = overlays.get(path) {
+            return Ok(source.clone());
+        }
+        if let Some(source) = fs::canonicalize(path)
+            .ok()
+            .and_then(|canonical| overlays.get(&canonical))
+        {
+            return Ok(source.clone());
+        }
+    }
+    Ok(fs::read_to_string(path)?)
+}
+
 pub(super) fn append_workspace_source(workspace: &mut String, path: &PathBuf, source: &str) {
     if !workspace.is_empty() {
         workspace.push_str("\n\n");
@@ -192,6 +311,7 @@ pub(super) fn parse_dependency_modules(
     is_stdlib: bool,
     trace_enabled: bool,
     metadata: &logging::PipelineLogMetadata,
+    overlays: Option<&HashMap<PathBuf, String>>,
 ) -> Result<Vec<FrontendModuleState>> {
     let mut modules = Vec::new();
     let sources = collect_package_source_files(&package.manifest, &package.root)?;
@@ -203,69 +323,29 @@ pub(super) fn parse_dependency_modules(
             sources.len()
         );
     }
+    let mut paths = Vec::new();
     for path in sources {
         let canonical = fs::canonicalize(&path).unwrap_or_else(|_| path.clone());
-        if !loaded_modules.insert(canonical) {
-            continue;
-        }
-        let read_start = Instant::now();
-        let mut source = fs::read_to_string(&path)?;
-        logging::log_stage_with_path(
-            trace_enabled,
-            metadata,
-            "frontend.package.read_source",
-            &path,
-            read_start,
-        );
-
-        let parse_start = Instant::now();
-        let mut preprocess_result = preprocess(&source, defines);
-        if let Some(rewritten) = preprocess_result.rewritten {
-            source = rewritten;
+        if loaded_modules.insert(canonical) {
+            paths.push(path);
         }
-        let file_id = files.add_file(path.clone(), source.clone());
-        stamp_file_id(&mut preprocess_result.diagnostics, file_id);
-        let mut parse = match parse_module_in_file(&source, file_id) {
-            Ok(parsed) => parsed,
-            Err(err) => {
-                log_stdlib_parse_error(&path, &source, &err);
-                return Err(err.with_file(path.clone(), source).into());
-            }
-        };
-        parse.diagnostics.extend(preprocess_result.diagnostics);
-        let mut cfg_diags = {
-            let mut module = parse.module_mut();
-            apply_cfg(&mut module, defines)
-        };
-        parse.diagnostics.append(&mut cfg_diags);
-        logging::log_stage_with_path(
-            trace_enabled,
-            metadata,
-            "frontend.package.parse",
-            &path,
-            parse_start,
-        );
-
-        let macro_start = Instant::now();
-        let expansion = {
-            let mut module = parse.module_mut();
-            expand_macros(&mut module, macro_registry)
-        };
-        parse.diagnostics.extend(expansion.diagnostics);
-        let mut cfg_diags = {
-            let mut module = parse.module_mut();
-            apply_cfg(&mut module, defines)
-        };
-        parse.diagnostics.append(&mut cfg_diags);
-        parse.module = parse.module_owned();
-        logging::log_stage_with_path(
-            trace_enabled,
-            metadata,
-            "frontend.package.expand_macros",
-            &path,
-            macro_start,
-        );
-
+    }
+    let parsed = parse_source_files(
+        paths,
+        |path| read_source(path, overlays),
+        "frontend.package",
+        files,
+        defines,
+        macro_registry,
+        trace_enabled,
+        metadata,
+    )?;
+    for ParsedFile {
+        path,
+        source,
+        parse,
+    } in parsed
+    {
         modules.push(FrontendModuleState {
             input: path,
             source,
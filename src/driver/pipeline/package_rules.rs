@@ -3,15 +3,17 @@ use std::path::{Path, PathBuf};
 
 use crate::diagnostics::DiagnosticCode;
 use crate::frontend::ast::Item;
+use crate::frontend::conditional::ConditionalDefines;
 use crate::frontend::diagnostics::{Diagnostic, Span, Suggestion};
 use crate::manifest::{Manifest, SourceRoot, WorkspaceConfig};
+use crate::package::features::FeatureRequest;
 use crate::package::resolver::ResolvedPackage;
 use crate::unicode::identifier;
 
 use super::{
-    FrontendModuleState, PKG_FRIEND_DUPLICATE, PKG_FRIEND_INVALID_PREFIX, PKG_FRIEND_SELF_PREFIX,
-    PKG_NAMESPACE_OUT_OF_SCOPE, PKG_PACKAGE_DIRECTIVES_DISALLOWED, PKG_PACKAGE_UNKNOWN,
-    PKG_PACKAGE_UNRESOLVED, PKG_PACKAGE_VERSION_MISMATCH, package_error,
+    FrontendModuleState, PKG_FEATURE_UNKNOWN, PKG_FRIEND_DUPLICATE, PKG_FRIEND_INVALID_PREFIX,
+    PKG_FRIEND_SELF_PREFIX, PKG_NAMESPACE_OUT_OF_SCOPE, PKG_PACKAGE_DIRECTIVES_DISALLOWED,
+    PKG_PACKAGE_UNKNOWN, PKG_PACKAGE_UNRESOLVED, PKG_PACKAGE_VERSION_MISMATCH, package_error,
 };
 
 enum FriendSource {
@@ -169,6 +171,20 @@ pub(super) fn attach_manifest_issues(manifest: &Manifest, modules: &mut [Fronten
     }
 }
 
+/// Report root features requested with `-D feature=...` that the manifest does not declare.
+pub(super) fn report_unknown_features(
+    manifest: &Manifest,
+    defines: &ConditionalDefines,
+    modules: &mut [FrontendModuleState],
+) {
+    if let Err(err) = FeatureRequest::for_root(manifest, defines) {
+        append_external_diagnostics(
+            modules,
+            vec![package_error(PKG_FEATURE_UNKNOWN, err.to_string(), None)],
+        );
+    }
+}
+
 pub(super) fn append_external_diagnostics(
     modules: &mut [FrontendModuleState],
     mut diagnostics: Vec<Diagnostic>,
@@ -6,6 +6,7 @@ use crate::driver::{
     collect_runtime_package_files, driver_stack_size, run_with_stack, stdlib_files_for,
 };
 use crate::frontend::conditional::ConditionalDefines;
+use crate::frontend::parser::parse_module_in_file;
 use crate::manifest::Manifest;
 use crate::runtime_package::{RuntimeKind, resolve_runtime};
 use crate::target::Target;
@@ -19,6 +19,8 @@ use crate::target::Target;
 use crate::type_metadata::SynthesisedTypeMetadata;
 use crate::typeck::{TraitSolverMetrics, TypeConstraint};
 
+use super::incremental::ModuleState;
+
 #[derive(Clone, Default)]
 pub(crate) struct ModuleArtifact {
     pub(crate) object_path: Option<PathBuf>,
@@ -76,6 +78,8 @@ pub struct FrontendReport {
     pub eq_glue: Vec<SynthesisedEqGlue>,
     pub type_metadata: Vec<SynthesisedTypeMetadata>,
     pub trait_solver_metrics: TraitSolverMetrics,
+    /// Per-file fingerprints when the run was incremental.
+    pub(crate) module_states: Vec<ModuleState>,
 }
 
 impl FrontendReport {
@@ -231,6 +235,9 @@ pub(crate) fn slice_mir_module(base: &MirModule, indices: &[usize]) -> MirModule
                     namespace: meta.namespace.clone(),
                     categories: meta.categories.clone(),
                     parameters: meta.parameters.clone(),
+                    kind: meta.kind,
+                    runs: meta.runs,
+                    row: meta.row.clone(),
                     is_async: meta.is_async,
                     span: meta.span,
                 })
@@ -0,0 +1,155 @@
+//! Snapshot assertions for `chic test`.
+//!
+//! `Assert.That(text).MatchesSnapshot()` hands the value to the runner through
+//! `chic_rt_test_snapshot` (a host import on WASM, a `CHIC_SNAPSHOT` status line from the native
+//! runner, a snapshot report to in-process hosts of the native executor). Once the testcase finishes, each value is compared with a `.snap` file stored next to
+//! the testcase source; mismatches fail the testcase with a unified diff, or rewrite the file
+//! under `--update-snapshots`.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use similar::TextDiff;
+
+use super::{TestCaseResult, TestStatus};
+
+/// Shared snapshot settings for every testcase in a run.
+#[derive(Debug, Default)]
+pub(crate) struct SnapshotSettings {
+    pub(crate) update: bool,
+    /// Declaring source file keyed by testcase id.
+    pub(crate) sources: HashMap<String, PathBuf>,
+}
+
+/// A value recorded by a snapshot assertion while a testcase ran.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SnapshotObservation {
+    /// Name passed to `MatchesSnapshot(name)`; empty for unnamed snapshots.
+    pub(crate) name: String,
+    pub(crate) value: String,
+}
+
+/// Snapshot file for the `ordinal`-th (1-based) unnamed snapshot, or for a named one.
+///
+/// `tests/render.ch` + `Renders` -> `tests/render.Renders.snap`, `tests/render.Renders-2.snap`,
+/// `tests/render.Renders.header.snap`.
+pub(crate) fn snapshot_path(source: &Path, test_name: &str, name: &str, ordinal: usize) -> PathBuf {
+    let stem = source
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    // `@cases` rows are named `Adds(1, 2)`; keep the file name portable.
+    let test_name: String = test_name
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || matches!(ch, '_' | '.' | '-') {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let file = if !name.is_empty() {
+        format!("{stem}.{test_name}.{name}.snap")
+    } else if ordinal > 1 {
+        format!("{stem}.{test_name}-{ordinal}.snap")
+    } else {
+        format!("{stem}.{test_name}.snap")
+    };
+    source.with_file_name(file)
+}
+
+fn render_diff(path: &Path, expected: &str, actual: &str) -> String {
+    let label = path.display().to_string();
+    TextDiff::from_lines(expected, actual)
+        .unified_diff()
+        .header(&label, "actual")
+        .to_string()
+}
+
+/// Compare the snapshots recorded by a testcase and fold the outcome into `result`.
+pub(crate) fn check_snapshots(
+    result: &mut TestCaseResult,
+    source: Option<&Path>,
+    observations: &[SnapshotObservation],
+    update: bool,
+) {
+    if observations.is_empty() {
+        return;
+    }
+    let mut failures = Vec::new();
+    let mut updated = Vec::new();
+    match source {
+        None => {
+            failures.push("snapshot assertions need a testcase declared in a source file".into())
+        }
+        Some(source) => {
+            let mut unnamed = 0;
+            for observation in observations {
+                if observation.name.is_empty() {
+                    unnamed += 1;
+                }
+                let path = snapshot_path(source, &result.name, &observation.name, unnamed);
+                let expected = fs::read_to_string(&path)
+                    .ok()
+                    .map(|text| text.replace("\r\n", "\n"));
+                if expected.as_deref() == Some(observation.value.as_str()) {
+                    continue;
+                }
+                if update {
+                    match fs::write(&path, &observation.value) {
+                        Ok(()) => updated.push(path.display().to_string()),
+                        Err(err) => failures.push(format!(
+                            "could not write snapshot {}: {err}",
+                            path.display()
+                        )),
+                    }
+                    continue;
+                }
+                failures.push(match expected {
+                    Some(expected) => format!(
+                        "snapshot {} does not match (run `chic test --update-snapshots` to accept):\n{}",
+                        path.display(),
+                        render_diff(&path, &expected, &observation.value).trim_end()
+                    ),
+                    None => format!(
+                        "snapshot {} does not exist (run `chic test --update-snapshots` to create it); actual:\n{}",
+                        path.display(),
+                        observation.value.trim_end()
+                    ),
+                });
+            }
+        }
+    }
+
+    let mut notes: Vec<String> = result.message.take().into_iter().collect();
+    if !updated.is_empty() {
+        notes.push(format!("updated snapshot {}", updated.join(", ")));
+    }
+    if !failures.is_empty() {
+        result.status = TestStatus::Failed;
+        notes.extend(failures);
+    }
+    result.message = (!notes.is_empty()).then(|| notes.join("\n"));
+}
+
+/// Decode a value from a `CHIC_SNAPSHOT` status line (`\\`, `\n`, `\r` and `\t` escapes).
+pub(crate) fn unescape_status_field(field: &str) -> String {
+    let mut text = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            text.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => text.push('\n'),
+            Some('r') => text.push('\r'),
+            Some('t') => text.push('\t'),
+            Some(other) => text.push(other),
+            None => text.push('\\'),
+        }
+    }
+    text
+}
@@ -280,6 +280,7 @@ fn build_request_for(inputs: Vec<PathBuf>) -> BuildRequest {
         bin_dir: None,
         no_dependencies: false,
         no_restore: false,
+        locked: false,
         no_incremental: false,
         rebuild: false,
         incremental_validate: false,
@@ -379,6 +380,7 @@ public int Add(int a, int b)
         bin_dir: None,
         no_dependencies: false,
         no_restore: false,
+        locked: false,
         no_incremental: false,
         rebuild: false,
         incremental_validate: false,
@@ -1101,6 +1103,764 @@ testcase Third()
     );
 }
 
+#[test]
+fn native_runner_output_reports_durations_and_started_cases() {
+    let stdout = b"CHIC_TEST_BEGIN\t0\tDemo::Adds\n\
+CHIC_TESTCASE\t0\tPASS\t1.250\t\n\
+CHIC_TEST_BEGIN\t2\tDemo::Fails\n\
+CHIC_TESTCASE\t2\tFAIL\t0.004\ttestcase returned false\n\
+CHIC_TESTCASE\t3\tSKIP\t0.000\tskipped due to --fail-fast\n\
+CHIC_TESTCASE\t4\tPASS\tDemo::Legacy\t\n\
+CHIC_TEST_BEGIN\t5\tDemo::Hangs\n";
+    let output = parse_native_test_output(stdout);
+    assert_eq!(output.started, vec![0, 2, 3, 4, 5]);
+
+    let passed = &output.results[&0];
+    assert_eq!(passed.status, TestStatus::Passed);
+    assert_eq!(passed.duration, Some(Duration::from_micros(1250)));
+    assert!(passed.message.is_none());
+
+    let failed = &output.results[&2];
+    assert_eq!(failed.status, TestStatus::Failed);
+    assert_eq!(failed.message.as_deref(), Some("testcase returned false"));
+
+    let skipped = &output.results[&3];
+    assert_eq!(skipped.status, TestStatus::Skipped);
+    assert!(skipped.duration.is_none());
+
+    assert!(
+        output.results[&4].duration.is_none(),
+        "startup shim lines carry the name, not a duration"
+    );
+    assert!(!output.results.contains_key(&5));
+}
+
+#[test]
+fn native_runner_output_collects_escaped_snapshot_values() {
+    let stdout = b"CHIC_TEST_BEGIN\t1\tDemo::Renders\n\
+CHIC_SNAPSHOT\t1\t\tline one\\nline\\ttwo\\\\\\n\n\
+CHIC_SNAPSHOT\t1\theader\tTitle\n\
+CHIC_TESTCASE\t1\tPASS\t0.100\t\n";
+    let output = parse_native_test_output(stdout);
+    let snapshots = &output.snapshots[&1];
+    assert_eq!(
+        snapshots.as_slice(),
+        [
+            super::snapshot::SnapshotObservation {
+                name: String::new(),
+                value: "line one\nline\ttwo\\\n".into(),
+            },
+            super::snapshot::SnapshotObservation {
+                name: "header".into(),
+                value: "Title".into(),
+            },
+        ]
+    );
+    assert_eq!(output.results[&1].status, TestStatus::Passed);
+}
+
+#[test]
+fn snapshot_checks_fail_with_diffs_and_update_files() {
+    use super::snapshot::{SnapshotObservation, check_snapshots, snapshot_path};
+
+    let dir = tempdir_or_panic();
+    let source = dir.path().join("render.ch");
+    let result = || TestCaseResult {
+        id: "t-1".into(),
+        name: "Renders".into(),
+        qualified_name: "Demo::Renders".into(),
+        namespace: Some("Demo".into()),
+        categories: Vec::new(),
+        is_async: false,
+        status: TestStatus::Passed,
+        timed_out: false,
+        message: None,
+        wasm_trace: None,
+        duration: None,
+    };
+    let observations = [
+        SnapshotObservation {
+            name: String::new(),
+            value: "a\nb\n".into(),
+        },
+        SnapshotObservation {
+            name: String::new(),
+            value: "second\n".into(),
+        },
+        SnapshotObservation {
+            name: "header".into(),
+            value: "Title\n".into(),
+        },
+    ];
+    let first = dir.path().join("render.Renders.snap");
+    assert_eq!(snapshot_path(&source, "Renders", "", 1), first);
+    assert_eq!(
+        snapshot_path(&source, "Renders", "", 2),
+        dir.path().join("render.Renders-2.snap")
+    );
+    assert_eq!(
+        snapshot_path(&source, "Renders", "header", 3),
+        dir.path().join("render.Renders.header.snap")
+    );
+    assert_eq!(
+        snapshot_path(&source, "Adds(1, 2)", "", 1),
+        dir.path().join("render.Adds_1__2_.snap")
+    );
+
+    let mut missing = result();
+    check_snapshots(&mut missing, Some(&source), &observations[..1], false);
+    assert_eq!(missing.status, TestStatus::Failed);
+    let message = missing.message.unwrap_or_default();
+    assert!(message.contains("does not exist"), "message: {message}");
+    assert!(!first.exists());
+
+    let mut updated = result();
+    check_snapshots(&mut updated, Some(&source), &observations, true);
+    assert_eq!(updated.status, TestStatus::Passed);
+    let message = updated.message.unwrap_or_default();
+    assert!(
+        message.starts_with("updated snapshot"),
+        "message: {message}"
+    );
+    assert_eq!(fs::read_to_string(&first).expect("read snapshot"), "a\nb\n");
+    assert_eq!(
+        fs::read_to_string(dir.path().join("render.Renders.header.snap")).expect("read snapshot"),
+        "Title\n"
+    );
+
+    fs::write(&first, "a\r\nb\r\n").expect("write snapshot");
+    let mut matching = result();
+    check_snapshots(&mut matching, Some(&source), &observations, false);
+    assert_eq!(matching.status, TestStatus::Passed);
+    assert!(matching.message.is_none());
+
+    fs::write(&first, "a\nc\n").expect("write snapshot");
+    let mut mismatch = result();
+    check_snapshots(&mut mismatch, Some(&source), &observations, false);
+    assert_eq!(mismatch.status, TestStatus::Failed);
+    let message = mismatch.message.unwrap_or_default();
+    assert!(message.contains("does not match"), "message: {message}");
+    assert!(
+        message.contains("-c\n+b"),
+        "missing unified diff: {message}"
+    );
+}
+
+#[test]
+fn wasm_runner_compares_recorded_snapshots() {
+    let dir = tempdir_or_panic();
+    let src_path = dir.path().join("render.ch");
+    let source = r#"
+namespace Snap;
+
+internal static class Hook
+{
+    @extern("C") internal static extern void chic_rt_test_snapshot(* const @expose_address byte namePtr, usize nameLen,
+    * const @expose_address byte valuePtr, usize valueLen);
+}
+
+public struct Buf
+{
+    public byte A;
+    public byte B;
+    public byte C;
+}
+
+testcase Renders()
+{
+    var buf = new Buf { A = (byte) 'h', B = (byte) 'i', C = (byte) '\n' };
+    unsafe {
+        let ptr = (* const @expose_address byte) & buf.A;
+        Hook.chic_rt_test_snapshot(ptr, 0usize, ptr, 3usize);
+        Hook.chic_rt_test_snapshot(ptr, 1usize, ptr, 2usize);
+    }
+    return true;
+}
+"#;
+    write_source_or_panic(&src_path, source);
+
+    let driver = CompilerDriver::new();
+    let mut request = build_request_for(vec![src_path.clone()]);
+    request.backend = Backend::Wasm;
+    request.load_stdlib = false;
+    request.kind = ChicKind::StaticLibrary;
+    let report = driver.build(request).expect("build");
+    let wasm_options =
+        super::wasm::resolve_wasm_options(src_path.parent().unwrap(), &Target::host())
+            .expect("resolve wasm options");
+    let artifact = report.artifact.as_ref().expect("artifact path");
+    let bytes = fs::read(artifact).expect("read wasm");
+
+    let run = |update_snapshots: bool| {
+        let options = TestOptions {
+            update_snapshots,
+            ..TestOptions::default()
+        };
+        let cases = super::wasm::collect_wasm_testcases(
+            &report,
+            &bytes,
+            &wasm_options,
+            false,
+            &options,
+            &Target::host(),
+            None,
+        )
+        .0;
+        assert_eq!(cases.len(), 1);
+        cases.into_iter().next().unwrap()
+    };
+
+    let missing = run(false);
+    assert_eq!(missing.status, TestStatus::Failed);
+    let updated = run(true);
+    assert_eq!(updated.status, TestStatus::Passed, "{:?}", updated.message);
+    assert_eq!(
+        fs::read_to_string(dir.path().join("render.Renders.snap")).expect("read snapshot"),
+        "hi\n"
+    );
+    assert_eq!(
+        fs::read_to_string(dir.path().join("render.Renders.h.snap")).expect("read snapshot"),
+        "hi"
+    );
+    assert_eq!(run(false).status, TestStatus::Passed);
+
+    fs::write(dir.path().join("render.Renders.snap"), "ho\n").expect("write snapshot");
+    let mismatch = run(false);
+    assert_eq!(mismatch.status, TestStatus::Failed);
+    let message = mismatch.message.unwrap_or_default();
+    assert!(message.contains("-ho\n+hi"), "message: {message}");
+}
+
+#[test]
+fn generated_cases_are_reproducible_from_their_seed() {
+    use super::generative::{GeneratedValue, generate_case};
+    use crate::mir::{TestCaseKind, TestValueDomain};
+
+    let domains = [
+        TestValueDomain::Int {
+            bits: 8,
+            signed: false,
+        },
+        TestValueDomain::Int {
+            bits: 32,
+            signed: true,
+        },
+        TestValueDomain::Bool,
+        TestValueDomain::Char { bits: 16 },
+    ];
+    for kind in [TestCaseKind::Property, TestCaseKind::Fuzz] {
+        for seed in 0..64u128 {
+            let case_seed = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15_F39C_C060_5CED_C835);
+            let values = generate_case(kind, &domains, case_seed);
+            assert_eq!(values, generate_case(kind, &domains, case_seed));
+            let GeneratedValue::Int(byte) = values[0] else {
+                panic!("expected integer, found {:?}", values[0]);
+            };
+            assert!((0..=255).contains(&byte), "byte out of range: {byte}");
+            let GeneratedValue::Char(ch) = values[3] else {
+                panic!("expected char, found {:?}", values[3]);
+            };
+            assert!(ch <= 0xFFFF, "char out of range: {ch:#x}");
+        }
+    }
+}
+
+#[test]
+fn shrinking_finds_minimal_failing_input() {
+    use super::generative::{GeneratedValue, shrink};
+    use crate::mir::TestValueDomain;
+
+    let domains = [
+        TestValueDomain::Int {
+            bits: 32,
+            signed: true,
+        },
+        TestValueDomain::Bool,
+    ];
+    let (minimal, steps) = shrink(
+        &domains,
+        vec![
+            GeneratedValue::Int(-720_801_834),
+            GeneratedValue::Bool(true),
+        ],
+        |values| matches!(values[0], GeneratedValue::Int(value) if value.abs() >= 100),
+    );
+    assert_eq!(
+        minimal,
+        [GeneratedValue::Int(100), GeneratedValue::Bool(false)]
+    );
+    assert!(steps > 0);
+}
+
+#[test]
+fn regressions_file_round_trips_seeds() {
+    use super::generative::{load_regressions, record_regression};
+
+    let dir = tempdir_or_panic();
+    let path = dir.path().join("props.regressions");
+    assert!(load_regressions(&path, "Props::Small").is_empty());
+
+    record_regression(&path, "Props::Small", 0xABCD, "a = 720801834").expect("record");
+    record_regression(&path, "Props::Small", 0xABCD, "a = 720801834").expect("record");
+    record_regression(&path, "Props::Other", 7, "x = 0").expect("record");
+
+    assert_eq!(load_regressions(&path, "Props::Small"), [0xABCD]);
+    assert_eq!(load_regressions(&path, "Props::Other"), [7]);
+    let contents = fs::read_to_string(&path).expect("read regressions");
+    assert!(contents.starts_with('#'), "missing header: {contents}");
+    assert!(
+        contents.contains("Props::Small 0x0000000000000000000000000000abcd  # a = 720801834"),
+        "unexpected contents: {contents}"
+    );
+}
+
+#[test]
+fn wasm_runner_generates_shrinks_and_records_property_failures() {
+    let dir = tempdir_or_panic();
+    let src_path = dir.path().join("props.ch");
+    let source = r"
+namespace Props;
+
+proptestcase Commutes(int a, int b)
+{
+    return a + b == b + a;
+}
+
+@runs(20)
+proptestcase Small(int a)
+{
+    return a < 100;
+}
+";
+    write_source_or_panic(&src_path, source);
+
+    let driver = CompilerDriver::new();
+    let mut request = build_request_for(vec![src_path.clone()]);
+    request.backend = Backend::Wasm;
+    request.load_stdlib = false;
+    request.kind = ChicKind::StaticLibrary;
+    let report = driver.build(request).expect("build");
+    let wasm_options =
+        super::wasm::resolve_wasm_options(src_path.parent().unwrap(), &Target::host())
+            .expect("resolve wasm options");
+    let artifact = report.artifact.as_ref().expect("artifact path");
+    let bytes = fs::read(artifact).expect("read wasm");
+
+    let run = |options: &TestOptions| {
+        super::wasm::collect_wasm_testcases(
+            &report,
+            &bytes,
+            &wasm_options,
+            false,
+            options,
+            &Target::host(),
+            None,
+        )
+        .0
+    };
+    let mut options = TestOptions::default();
+    options.generation.runs = Some(10);
+    let cases = run(&options);
+    assert_eq!(cases.len(), 2);
+    let commutes = cases
+        .iter()
+        .find(|case| case.name.ends_with("Commutes"))
+        .expect("Commutes result");
+    assert_eq!(commutes.status, TestStatus::Passed);
+    let small = cases
+        .iter()
+        .find(|case| case.name.ends_with("Small"))
+        .expect("Small result");
+    assert_eq!(small.status, TestStatus::Failed);
+    let message = small.message.clone().unwrap_or_default();
+    assert!(message.contains("input: a = 100"), "message: {message}");
+
+    let regressions = dir.path().join("props.regressions");
+    let recorded = super::generative::load_regressions(&regressions, "Props::Small");
+    assert_eq!(recorded.len(), 1, "regressions: {recorded:?}");
+
+    let cases = run(&options);
+    let small = cases
+        .iter()
+        .find(|case| case.name.ends_with("Small"))
+        .expect("Small result");
+    let message = small.message.clone().unwrap_or_default();
+    assert!(
+        message.contains("failed replaying a recorded regression"),
+        "message: {message}"
+    );
+}
+
+#[test]
+fn wasm_runner_expands_fixtures_hooks_and_case_rows() {
+    let dir = tempdir_or_panic();
+    let src_path = dir.path().join("rows.ch");
+    let source = r"
+namespace Rows;
+
+public static class State
+{
+    public static int Opened;
+    public static int Zero;
+}
+
+@setup
+public void Open() { State.Opened = State.Opened + 1; }
+
+@fixture
+public int Seed() { return 40; }
+
+@id(adds)
+@cases(1, 2)
+@cases(b: 3, a: 1)
+testcase Adds(int a, int b)
+{
+    return a + 1 == b;
+}
+
+testcase UsesSeed(int seed)
+{
+    return seed + State.Opened == 41;
+}
+
+public static class Broken
+{
+    @teardown
+    public static void Explode() { State.Zero = 1 / State.Zero; }
+
+    testcase TearsDown()
+    {
+        return true;
+    }
+}
+";
+    write_source_or_panic(&src_path, source);
+
+    let driver = CompilerDriver::new();
+    let mut request = build_request_for(vec![src_path.clone()]);
+    request.backend = Backend::Wasm;
+    request.load_stdlib = false;
+    request.kind = ChicKind::StaticLibrary;
+    let report = driver.build(request).expect("build");
+    let wasm_options =
+        super::wasm::resolve_wasm_options(src_path.parent().unwrap(), &Target::host())
+            .expect("resolve wasm options");
+    let artifact = report.artifact.as_ref().expect("artifact path");
+    let bytes = fs::read(artifact).expect("read wasm");
+
+    let run = |tests: &[&str]| {
+        super::wasm::collect_wasm_testcases(
+            &report,
+            &bytes,
+            &wasm_options,
+            false,
+            &TestOptions {
+                selection: TestSelection {
+                    tests: tests.iter().map(|test| (*test).to_string()).collect(),
+                    groups: Vec::new(),
+                    run_all: false,
+                },
+                ..Default::default()
+            },
+            &Target::host(),
+            None,
+        )
+    };
+    let (cases, filtered_out) = run(&[]);
+    assert_eq!(filtered_out, 0);
+    let status = |name: &str| {
+        cases
+            .iter()
+            .find(|case| case.name == name)
+            .unwrap_or_else(|| panic!("missing result for {name}"))
+    };
+    assert_eq!(status("Adds(1, 2)").status, TestStatus::Passed);
+    assert_eq!(status("Adds(b: 3, a: 1)").status, TestStatus::Failed);
+    assert_eq!(status("Adds(1, 2)").id, "adds[0]");
+    assert_eq!(status("UsesSeed").status, TestStatus::Passed);
+    let torn_down = status("TearsDown");
+    assert_eq!(torn_down.status, TestStatus::Failed);
+    assert!(
+        torn_down
+            .message
+            .as_ref()
+            .is_some_and(|message| message.contains("division")),
+        "teardown should run after the body: {:?}",
+        torn_down.message
+    );
+
+    let (rows, filtered_out) = run(&["Adds"]);
+    assert_eq!((rows.len(), filtered_out), (2, 2));
+    let (row, filtered_out) = run(&["Rows::Adds(1, 2)"]);
+    assert_eq!((row.len(), filtered_out), (1, 3));
+    assert_eq!(row[0].name, "Adds(1, 2)");
+}
+
+#[test]
+fn wasm_runner_runs_teardowns_when_the_body_throws() {
+    let dir = tempdir_or_panic();
+    let src_path = dir.path().join("teardown.ch");
+    let source = r#"
+namespace Std
+{
+    public class Exception
+    {
+        public string Message;
+
+        public init(string message)
+        {
+            Message = message;
+        }
+    }
+}
+
+namespace Suite
+{
+    public static class State
+    {
+        public static int Zero;
+    }
+
+    @teardown
+    public void Explode() { State.Zero = 1 / State.Zero; }
+
+    testcase Throws()
+    {
+        throw new Std.Exception("body failed");
+    }
+}
+"#;
+    write_source_or_panic(&src_path, source);
+
+    let driver = CompilerDriver::new();
+    let mut request = build_request_for(vec![src_path.clone()]);
+    request.backend = Backend::Wasm;
+    request.load_stdlib = false;
+    request.kind = ChicKind::StaticLibrary;
+    let report = driver.build(request).expect("build");
+    let wasm_options =
+        super::wasm::resolve_wasm_options(src_path.parent().unwrap(), &Target::host())
+            .expect("resolve wasm options");
+    let artifact = report.artifact.as_ref().expect("artifact path");
+    let bytes = fs::read(artifact).expect("read wasm");
+
+    let (cases, _) = super::wasm::collect_wasm_testcases(
+        &report,
+        &bytes,
+        &wasm_options,
+        false,
+        &TestOptions::default(),
+        &Target::host(),
+        None,
+    );
+    assert_eq!(cases.len(), 1);
+    assert_eq!(cases[0].status, TestStatus::Failed);
+    assert!(
+        cases[0]
+            .message
+            .as_ref()
+            .is_some_and(|message| message.contains("division")),
+        "the teardown should run after the body threw: {:?}",
+        cases[0].message
+    );
+}
+
+#[test]
+fn wasm_bench_runner_times_benchcases_kept_out_of_tests() {
+    let dir = tempdir_or_panic();
+    let src_path = dir.path().join("bench.ch");
+    let source = r"
+namespace Bench;
+
+public static int Sum(int n)
+{
+    var total = 0;
+    for (var i = 0; i < n; i += 1)
+    {
+        total += i;
+    }
+    return total;
+}
+
+testcase SumWorks()
+{
+    return Sum(4) == 6;
+}
+
+benchcase SumSmall()
+{
+    return Sum(16) == 120;
+}
+
+@cases(32, 496)
+benchcase SumRows(int n, int expected)
+{
+    return Sum(n) == expected;
+}
+
+benchcase Wrong()
+{
+    return Sum(3) == 0;
+}
+";
+    write_source_or_panic(&src_path, source);
+
+    let driver = CompilerDriver::new();
+    let mut request = build_request_for(vec![src_path.clone()]);
+    request.backend = Backend::Wasm;
+    request.load_stdlib = false;
+    request.kind = ChicKind::StaticLibrary;
+    let report = driver.build(request).expect("build");
+    let wasm_options =
+        super::wasm::resolve_wasm_options(src_path.parent().unwrap(), &Target::host())
+            .expect("resolve wasm options");
+    let artifact = report.artifact.as_ref().expect("artifact path");
+    let bytes = fs::read(artifact).expect("read wasm");
+
+    let (cases, _) = super::wasm::collect_wasm_testcases(
+        &report,
+        &bytes,
+        &wasm_options,
+        false,
+        &TestOptions::default(),
+        &Target::host(),
+        None,
+    );
+    let names: Vec<_> = cases.iter().map(|case| case.name.as_str()).collect();
+    assert_eq!(names, ["SumWorks"], "chic test must not run benchcases");
+
+    let bench = BenchOptions {
+        warmup: Duration::from_millis(1),
+        measurement: Duration::from_millis(20),
+        max_samples: 10,
+        ..BenchOptions::default()
+    };
+    let (results, discovered, filtered_out) = super::bench::collect_wasm_benchmarks(
+        &report,
+        &bytes,
+        &wasm_options,
+        &TestOptions::default(),
+        &bench,
+        None,
+    );
+    assert_eq!((discovered, filtered_out), (3, 0));
+    let result = |name: &str| {
+        results
+            .iter()
+            .find(|result| result.name == name)
+            .unwrap_or_else(|| panic!("missing result for {name}"))
+    };
+    for name in ["SumSmall", "SumRows(32, 496)"] {
+        let stats = result(name).stats.expect("measured benchcase");
+        assert_eq!(result(name).status, TestStatus::Passed);
+        assert!(stats.samples >= 2 && stats.iterations >= 1, "{stats:?}");
+        assert!(stats.ci_low_ns <= stats.mean_ns && stats.mean_ns <= stats.ci_high_ns);
+    }
+    let wrong = result("Wrong");
+    assert_eq!(wrong.status, TestStatus::Failed);
+    assert!(wrong.stats.is_none());
+}
+
+#[test]
+fn wasm_runner_streams_events_and_flags_watchdog_timeouts() {
+    let dir = tempdir_or_panic();
+    let src_path = dir.path().join("suite.ch");
+    write_source_or_panic(
+        &src_path,
+        r"
+namespace Runner.Events;
+
+testcase Adds()
+{
+    return 1 + 1 == 2;
+}
+
+testcase Spins()
+{
+    var i = 0;
+    while (true) { i = i + 1; }
+    return true;
+}
+
+testcase Parameterized(int value)
+{
+    return value == 0;
+}
+",
+    );
+
+    let driver = CompilerDriver::new();
+    let mut request = build_request_for(vec![src_path.clone()]);
+    request.backend = Backend::Wasm;
+    request.load_stdlib = false;
+    request.kind = ChicKind::StaticLibrary;
+    let report = driver.build(request).expect("build");
+    let wasm_options =
+        super::wasm::resolve_wasm_options(src_path.parent().unwrap(), &Target::host())
+            .expect("resolve wasm options");
+    let artifact = report.artifact.as_ref().expect("artifact path");
+    let bytes = fs::read(artifact).expect("read wasm");
+
+    let events = std::sync::Arc::new(Mutex::new(Vec::new()));
+    let sink = {
+        let events = events.clone();
+        crate::driver::TestEventSink::new(move |event| {
+            let entry = match event {
+                crate::driver::TestEvent::Started(meta) => format!("start {}", meta.name),
+                crate::driver::TestEvent::Finished(case) => format!("finish {}", case.name),
+            };
+            events.lock().unwrap().push(entry);
+        })
+    };
+    let (cases, _) = super::wasm::collect_wasm_testcases(
+        &report,
+        &bytes,
+        &wasm_options,
+        false,
+        &TestOptions {
+            parallelism: Some(1),
+            watchdog: WatchdogConfig {
+                timeout: Some(Duration::from_millis(100)),
+                ..WatchdogConfig::default()
+            },
+            events: Some(sink),
+            ..Default::default()
+        },
+        &Target::host(),
+        None,
+    );
+
+    let spins = cases
+        .iter()
+        .find(|case| case.name == "Spins")
+        .expect("spinning testcase");
+    assert!(matches!(spins.status, TestStatus::Failed));
+    assert!(
+        spins.timed_out,
+        "watchdog abort not flagged: {:?}",
+        spins.message
+    );
+    assert!(
+        cases
+            .iter()
+            .filter(|case| case.name != "Spins")
+            .all(|case| !case.timed_out)
+    );
+
+    let mut events = events.lock().unwrap().clone();
+    events.sort();
+    assert_eq!(
+        events,
+        [
+            "finish Adds",
+            "finish Parameterized",
+            "finish Spins",
+            "start Adds",
+            "start Spins",
+        ]
+    );
+}
+
 #[test]
 fn wasm_run_applies_manifest_options() {
     let dir = tempdir_or_panic();
@@ -1334,6 +2094,7 @@ public int Main()
         bin_dir: None,
         no_dependencies: false,
         no_restore: false,
+        locked: false,
         no_incremental: false,
         rebuild: false,
         incremental_validate: false,
@@ -1422,6 +2183,7 @@ public int Main()
         bin_dir: None,
         no_dependencies: false,
         no_restore: false,
+        locked: false,
         no_incremental: false,
         rebuild: false,
         incremental_validate: false,
@@ -1500,6 +2262,7 @@ public int Main()
         bin_dir: None,
         no_dependencies: false,
         no_restore: false,
+        locked: false,
         no_incremental: false,
         rebuild: false,
         incremental_validate: false,
@@ -1537,3 +2300,123 @@ public int Main()
         "expected cache manifest to remain unchanged when cache hit"
     );
 }
+
+#[test]
+fn plan_modules_skips_files_whose_inputs_are_unchanged() {
+    use crate::driver::incremental::{ModuleInput, plan_modules};
+    use crate::frontend::parser::parse_module;
+
+    let parse = |source: &str| parse_module(source).expect("parse").module;
+    let lib = parse("namespace Demo;\npublic int Add(int a, int b) { return a + b; }\n");
+    let app = parse("namespace Demo;\npublic int Twice(int a) { return Add(a, a); }\n");
+    let plan = |lib: &crate::frontend::ast::Module, previous: &[_]| {
+        let inputs = [
+            ModuleInput {
+                path: "lib.ch".into(),
+                ast: lib,
+                source: None,
+                package: None,
+                package_dependencies: Vec::new(),
+            },
+            ModuleInput {
+                path: "app.ch".into(),
+                ast: &app,
+                source: None,
+                package: None,
+                package_dependencies: Vec::new(),
+            },
+        ];
+        plan_modules(&inputs, previous, "defines")
+    };
+
+    let (first, unchanged) = plan(&lib, &[]);
+    assert!(
+        unchanged.is_empty(),
+        "nothing is reusable on the first build"
+    );
+
+    let (_, unchanged) = plan(&lib, &first);
+    assert_eq!(unchanged, [0, 1].into_iter().collect());
+
+    let body = parse("namespace Demo;\npublic int Add(int a, int b) { return b + a; }\n");
+    let (_, unchanged) = plan(&body, &first);
+    assert_eq!(
+        unchanged,
+        [1].into_iter().collect(),
+        "a body edit only re-checks its own file"
+    );
+
+    let signature = parse("namespace Demo;\npublic long Add(int a, int b) { return a + b; }\n");
+    let (_, unchanged) = plan(&signature, &first);
+    assert!(
+        unchanged.is_empty(),
+        "a signature edit re-checks dependents"
+    );
+
+    let mut dirty = first.clone();
+    dirty[1].clean = false;
+    let (_, unchanged) = plan(&lib, &dirty);
+    assert_eq!(
+        unchanged,
+        [0].into_iter().collect(),
+        "files with diagnostics are always re-checked"
+    );
+}
+
+#[test]
+fn parallel_builds_match_serial_builds() {
+    let dir = tempdir_or_panic();
+    let lib = dir.path().join("lib.ch");
+    let app = dir.path().join("app.ch");
+    write_source_or_panic(
+        &lib,
+        "namespace Jobs;\n\npublic int Add(int a, int b)\n{\n    return a + b;\n}\n\npublic int Mul(int a, int b)\n{\n    return a * b;\n}\n",
+    );
+    write_source_or_panic(
+        &app,
+        "namespace Jobs;\n\npublic int Main()\n{\n    let unused = 3;\n    return Mul(Add(1, 2), 4) - 12;\n}\n",
+    );
+
+    let build = |jobs: usize| {
+        crate::threading::configure_jobs(Some(jobs));
+        let mut request = build_request_for(vec![lib.clone(), app.clone()]);
+        request.backend = Backend::Wasm;
+        request.load_stdlib = false;
+        request.no_incremental = true;
+        let out_dir = dir.path().join(format!("jobs{jobs}"));
+        fs::create_dir_all(&out_dir).unwrap_or_else(|err| panic!("create out dir: {err}"));
+        request.output = Some(out_dir.join("app.wasm"));
+        let report = CompilerDriver::new()
+            .build(request)
+            .unwrap_or_else(|err| panic!("build with {jobs} jobs: {err}"));
+        crate::threading::configure_jobs(None);
+        let artifact = report
+            .artifact
+            .as_ref()
+            .unwrap_or_else(|| panic!("build with {jobs} jobs produced no artifact"));
+        let bytes = fs::read(artifact).unwrap_or_else(|err| panic!("read artifact: {err}"));
+        let diagnostics = format!(
+            "{:?}",
+            (
+                &report.type_diagnostics,
+                &report.lint_diagnostics,
+                &report.reachability_diagnostics,
+                &report.borrow_diagnostics,
+                &report.fallible_diagnostics,
+                report.mir_verification.len(),
+            )
+        );
+        (bytes, diagnostics)
+    };
+
+    let serial = build(1);
+    let parallel = build(4);
+    assert_eq!(
+        serial.1, parallel.1,
+        "diagnostics differ between job counts"
+    );
+    assert!(
+        serial.0 == parallel.0,
+        "artifacts differ between job counts"
+    );
+}
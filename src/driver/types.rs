@@ -1,7 +1,9 @@
+use std::collections::HashMap;
 use std::env;
 use std::fmt;
 use std::path::PathBuf;
 use std::process::ExitStatus;
+use std::sync::Arc;
 use std::time::Duration;
 
 use crate::chic_kind::ChicKind;
@@ -15,6 +17,23 @@ use crate::target::Target;
 use regex::Regex;
 
 use super::FrontendReport;
+use super::incremental::ModuleState;
+
+/// Project context for [`super::CompilerDriver::check_project`].
+///
+/// Carries the manifest that owns the inputs (so dependencies are resolved and loaded) and
+/// in-memory sources that shadow the on-disk contents of their paths.
+#[derive(Clone, Default)]
+pub struct CheckProject {
+    pub manifest: Option<Manifest>,
+    pub workspace: Option<WorkspaceConfig>,
+    pub overlays: HashMap<PathBuf, String>,
+    /// Resolve dependencies without writing `manifest.lock` or fetching (editor analysis).
+    pub read_only_packages: bool,
+    /// Module fingerprints of the previous check; `Some` enables incremental body checks
+    /// and makes the report carry the new fingerprints.
+    pub(crate) incremental: Option<Vec<ModuleState>>,
+}
 
 pub struct TestRun {
     pub report: FrontendReport,
@@ -22,6 +41,8 @@ pub struct TestRun {
     pub discovered: usize,
     pub filtered_out: usize,
     pub chic_coverage: Option<crate::chic_coverage::ChicCoverageReport>,
+    /// Benchcase results; only populated when [`TestOptions::bench`] is set.
+    pub benchmarks: Vec<BenchResult>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -39,11 +60,40 @@ pub struct TestCaseResult {
     pub categories: Vec<String>,
     pub is_async: bool,
     pub status: TestStatus,
+    /// The case was aborted by the watchdog; `status` is [`TestStatus::Failed`].
+    pub timed_out: bool,
     pub message: Option<String>,
     pub wasm_trace: Option<WasmExecutionTrace>,
     pub duration: Option<Duration>,
 }
 
+/// Outcome of timing one `benchcase` under `chic bench`.
+pub struct BenchResult {
+    pub id: String,
+    pub name: String,
+    pub qualified_name: String,
+    pub namespace: Option<String>,
+    /// [`TestStatus::Passed`] when `stats` holds a measurement.
+    pub status: TestStatus,
+    pub message: Option<String>,
+    pub stats: Option<BenchStats>,
+}
+
+/// Per-iteration timing statistics for a benchcase, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchStats {
+    pub samples: usize,
+    /// Iterations timed together in each sample.
+    pub iterations: u64,
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    pub std_dev_ns: f64,
+    /// Lower bound of the 95% confidence interval for the mean.
+    pub ci_low_ns: f64,
+    /// Upper bound of the 95% confidence interval for the mean.
+    pub ci_high_ns: f64,
+}
+
 pub struct RunResult {
     pub report: FrontendReport,
     pub status: ExitStatus,
@@ -105,6 +155,8 @@ pub struct BuildRequest {
     pub bin_dir: Option<PathBuf>,
     pub no_dependencies: bool,
     pub no_restore: bool,
+    /// Fail instead of resolving dependencies differently from `manifest.lock`.
+    pub locked: bool,
     pub no_incremental: bool,
     pub rebuild: bool,
     pub incremental_validate: bool,
@@ -254,12 +306,143 @@ impl Default for WatchdogConfig {
     }
 }
 
+/// Machine-readable report format accepted by `chic test --report`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestReportFormat {
+    Junit,
+    Tap,
+    JsonLines,
+}
+
+impl TestReportFormat {
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "junit" | "xml" => Some(Self::Junit),
+            "tap" => Some(Self::Tap),
+            "jsonl" | "json-lines" => Some(Self::JsonLines),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Junit => "junit",
+            Self::Tap => "tap",
+            Self::JsonLines => "jsonl",
+        }
+    }
+}
+
+/// A report to write after (or, for JSON Lines, while) running tests; `None` means stdout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestReportRequest {
+    pub format: TestReportFormat,
+    pub path: Option<PathBuf>,
+}
+
+/// Progress notification raised while testcases execute.
+pub enum TestEvent<'a> {
+    Started(&'a TestCaseMetadata),
+    Finished(&'a TestCaseResult),
+}
+
+/// Callback receiving [`TestEvent`]s; invoked from test worker threads.
+#[derive(Clone)]
+pub struct TestEventSink(Arc<dyn Fn(&TestEvent<'_>) + Send + Sync>);
+
+impl TestEventSink {
+    pub fn new(callback: impl Fn(&TestEvent<'_>) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+
+    pub fn emit(&self, event: &TestEvent<'_>) {
+        (self.0)(event);
+    }
+}
+
+impl fmt::Debug for TestEventSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TestEventSink")
+    }
+}
+
+/// Overrides for `proptestcase`/`fuzzcase` argument generation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GenerativeTestOptions {
+    /// Run seed (`--test-seed`); defaults to one derived from each testcase's name.
+    pub seed: Option<u128>,
+    /// Generated cases per testcase (`--test-runs`); overrides `@runs(N)`.
+    pub runs: Option<u32>,
+}
+
+impl GenerativeTestOptions {
+    /// Parse a seed as printed by `chic seed` (`0x`-prefixed hex) or as a decimal integer.
+    #[must_use]
+    pub fn parse_seed(value: &str) -> Option<u128> {
+        let value = value.trim().replace('_', "");
+        match value
+            .strip_prefix("0x")
+            .or_else(|| value.strip_prefix("0X"))
+        {
+            Some(hex) => u128::from_str_radix(hex, 16).ok(),
+            None => value.parse::<u128>().ok(),
+        }
+    }
+}
+
+/// Settings for `chic bench`: how benchcases are sampled and where results are compared.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchOptions {
+    /// Time spent running each benchcase before sampling (`--warmup-ms`).
+    pub warmup: Duration,
+    /// Sampling budget per benchcase (`--measure-ms`).
+    pub measurement: Duration,
+    /// Upper bound on samples per benchcase (`--samples`).
+    pub max_samples: usize,
+    /// perf.json the results are written to (`--bench-out`).
+    pub output: PathBuf,
+    /// perf.json holding earlier results to compare against (`--baseline`).
+    pub baseline: Option<PathBuf>,
+    /// Percentage slowdown against the baseline that counts as a regression (`--tolerance`).
+    pub tolerance: f64,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_millis(300),
+            measurement: Duration::from_secs(2),
+            max_samples: 100,
+            output: PathBuf::from("perf.json"),
+            baseline: None,
+            tolerance: 5.0,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TestOptions {
     pub selection: TestSelection,
     pub parallelism: Option<usize>,
     pub watchdog: WatchdogConfig,
     pub fail_fast: bool,
+    pub generation: GenerativeTestOptions,
+    /// Rewrite mismatched or missing `.snap` files instead of failing the testcase.
+    pub update_snapshots: bool,
+    pub reports: Vec<TestReportRequest>,
+    pub events: Option<TestEventSink>,
+    /// Run benchcases under `chic bench` instead of testcases.
+    pub bench: Option<BenchOptions>,
+}
+
+impl TestOptions {
+    pub(crate) fn emit(&self, event: &TestEvent<'_>) {
+        if let Some(events) = &self.events {
+            events.emit(event);
+        }
+    }
 }
 
 impl Default for TestOptions {
@@ -269,17 +452,28 @@ impl Default for TestOptions {
             parallelism: None,
             watchdog: WatchdogConfig::default(),
             fail_fast: false,
+            generation: GenerativeTestOptions::default(),
+            update_snapshots: false,
+            reports: Vec::new(),
+            events: None,
+            bench: None,
         }
     }
 }
 
 fn matches_test_pattern(pattern: &str, meta: &TestCaseMetadata) -> bool {
     let needle = pattern.to_ascii_lowercase();
-    let candidates = [
-        meta.id.as_str(),
-        meta.qualified_name.as_str(),
-        meta.name.as_str(),
+    let mut candidates = vec![
+        meta.id.clone(),
+        meta.qualified_name.clone(),
+        meta.name.clone(),
     ];
+    // `@cases` rows answer to the declared testcase (every row) and to `Name(args)` (one row).
+    if let Some(row) = &meta.row {
+        candidates.push(format!("{}({})", row.testcase, row.arguments));
+        candidates.push(row.testcase.clone());
+        candidates.push(TestCaseMetadata::split_namespace(&row.testcase).1);
+    }
     candidates
         .iter()
         .any(|candidate| wildcard_match(&needle, &candidate.to_ascii_lowercase()))
@@ -8,17 +8,20 @@ use crate::manifest::Manifest;
 use crate::mir::async_types::task_result_ty;
 use crate::mir::{GenericArg, MirFunction, MirModule, Ty, TypeLayout, TypeLayoutTable};
 use crate::runtime::wasm_executor::AsyncLayoutOverrides;
+use crate::runtime::wasm_executor::source_map::load_source_map;
 use crate::runtime::{
     WasmExecutionOptions, WasmExecutionTrace, WasmProgram, WasmProgramExportOutcome, WasmValue,
 };
 use crate::target::Target;
 use tracing::info;
 
-use super::{FrontendReport, TestCaseResult, TestStatus};
+use super::generative::{GenerativeSettings, run_generative_case};
+use super::snapshot::{SnapshotObservation, SnapshotSettings, check_snapshots};
+use super::{FrontendReport, TestCaseResult, TestEvent, TestEventSink, TestStatus};
 use crate::driver::types::TestOptions;
 use crate::mir::TestCaseMetadata;
 
-const WASM_TEST_THREAD_STACK_SIZE: usize = 128 * 1024 * 1024;
+pub(crate) const WASM_TEST_THREAD_STACK_SIZE: usize = 128 * 1024 * 1024;
 
 pub(crate) fn resolve_wasm_options(path: &Path, target: &Target) -> Result<WasmExecutionOptions> {
     let manifest = match Manifest::discover(path)? {
@@ -32,6 +35,11 @@ pub(crate) fn resolve_wasm_options(path: &Path, target: &Target) -> Result<WasmE
     Ok(settings.to_execution_options())
 }
 
+/// Report traps against Chic sources when the build left a `.wasm.map` next to `artifact`.
+pub(crate) fn attach_source_map(options: &mut WasmExecutionOptions, artifact: &Path) {
+    options.source_map = load_source_map(artifact).map(Arc::new);
+}
+
 pub(crate) fn collect_wasm_testcases(
     report: &FrontendReport,
     program_bytes: &[u8],
@@ -53,35 +61,17 @@ pub(crate) fn collect_wasm_testcases(
             eprintln!("[wasm-dump] wrote {}", dump_path.display());
         }
     }
-    let mut all_metadata = crate::mir::collect_test_metadata(&report.mir_module);
+    let mut all_metadata: Vec<_> = crate::mir::collect_test_metadata(&report.mir_module)
+        .into_iter()
+        .filter(|meta| !meta.kind.is_bench())
+        .collect();
     all_metadata.sort_by(|a, b| a.id.cmp(&b.id));
     let selected: Vec<_> = all_metadata
         .iter()
         .filter(|meta| {
-            if !test_options.selection.matches(meta) || should_skip_for_executor(meta, true) {
-                return false;
-            }
-            let Some(roots) = allowed_roots else {
-                return true;
-            };
-            let Some(span) = meta.span else {
-                return true;
-            };
-            let Some(path) = report.files.path(span.file_id) else {
-                return true;
-            };
-            let cwd = std::env::current_dir().ok();
-            let to_abs = |p: &Path| -> PathBuf {
-                if p.is_absolute() {
-                    p.to_path_buf()
-                } else if let Some(cwd) = &cwd {
-                    cwd.join(p)
-                } else {
-                    p.to_path_buf()
-                }
-            };
-            let abs_path = to_abs(path);
-            roots.iter().any(|root| abs_path.starts_with(to_abs(root)))
+            test_options.selection.matches(meta)
+                && !should_skip_for_executor(meta, true)
+                && declared_within_roots(report, meta, allowed_roots)
         })
         .cloned()
         .collect();
@@ -89,6 +79,30 @@ pub(crate) fn collect_wasm_testcases(
     if selected.is_empty() {
         return (Vec::new(), filtered_out);
     }
+    let generative = Arc::new(GenerativeSettings {
+        options: test_options.generation,
+        regression_files: selected
+            .iter()
+            .filter(|meta| meta.kind.is_generative())
+            .filter_map(|meta| {
+                let path = report.files.path(meta.span?.file_id)?;
+                Some((
+                    meta.id.clone(),
+                    GenerativeSettings::regression_file_for(path),
+                ))
+            })
+            .collect(),
+    });
+    let snapshots = Arc::new(SnapshotSettings {
+        update: test_options.update_snapshots,
+        sources: selected
+            .iter()
+            .filter_map(|meta| {
+                let path = report.files.path(meta.span?.file_id)?;
+                Some((meta.id.clone(), path.to_path_buf()))
+            })
+            .collect(),
+    });
 
     let bytes = Arc::new(program_bytes.to_vec());
     let layouts = Arc::new(report.mir_module.type_layouts.clone());
@@ -116,17 +130,20 @@ pub(crate) fn collect_wasm_testcases(
         std::iter::repeat_with(|| None)
             .take(selected.len())
             .collect();
+    let events = test_options.events.clone();
     for (index, meta) in selected.into_iter().enumerate() {
-        if !meta.parameters.is_empty() {
-            slots[index] = Some(skip_parameterized(&meta));
+        let result = if meta.runner_parameters().next().is_some() && !meta.kind.is_generative() {
+            skip_parameterized(&meta)
+        } else if let Some(function) = functions.get(meta.function_index).cloned() {
+            scheduled[index] = Some((meta, function));
             continue;
+        } else {
+            missing_function_result(&meta)
+        };
+        if let Some(events) = &events {
+            events.emit(&TestEvent::Finished(&result));
         }
-        match functions.get(meta.function_index).cloned() {
-            Some(function) => scheduled[index] = Some((meta, function)),
-            None => {
-                slots[index] = Some(missing_function_result(&meta));
-            }
-        }
+        slots[index] = Some(result);
     }
 
     if test_options.fail_fast || parallelism == 1 {
@@ -149,18 +166,26 @@ pub(crate) fn collect_wasm_testcases(
                             continue;
                         };
                         if saw_failure {
-                            cases.push(skip_after_fail_fast(&meta));
+                            let result = skip_after_fail_fast(&meta);
+                            if let Some(events) = &events {
+                                events.emit(&TestEvent::Finished(&result));
+                            }
+                            cases.push(result);
                             continue;
                         }
-                        let result = execute_wasm_case(
-                            &meta,
-                            function,
-                            bytes.clone(),
-                            layouts.clone(),
-                            options.clone(),
-                            watchdog,
-                            trace_enabled,
-                        );
+                        let result = with_events(events.as_ref(), &meta, || {
+                            execute_wasm_case(
+                                &meta,
+                                function,
+                                bytes.clone(),
+                                layouts.clone(),
+                                options.clone(),
+                                &generative,
+                                &snapshots,
+                                watchdog,
+                                trace_enabled,
+                            )
+                        });
                         if matches!(result.status, TestStatus::Failed) {
                             saw_failure = true;
                         }
@@ -173,15 +198,19 @@ pub(crate) fn collect_wasm_testcases(
                             continue;
                         }
                         if let Some((meta, function)) = scheduled[index].take() {
-                            cases.push(execute_wasm_case(
-                                &meta,
-                                function,
-                                bytes.clone(),
-                                layouts.clone(),
-                                options.clone(),
-                                watchdog,
-                                trace_enabled,
-                            ));
+                            cases.push(with_events(events.as_ref(), &meta, || {
+                                execute_wasm_case(
+                                    &meta,
+                                    function,
+                                    bytes.clone(),
+                                    layouts.clone(),
+                                    options.clone(),
+                                    &generative,
+                                    &snapshots,
+                                    watchdog,
+                                    trace_enabled,
+                                )
+                            }));
                         }
                     }
                 }
@@ -205,6 +234,9 @@ pub(crate) fn collect_wasm_testcases(
         let bytes = bytes.clone();
         let layouts = layouts.clone();
         let options = options.clone();
+        let events = events.clone();
+        let generative = generative.clone();
+        let snapshots = snapshots.clone();
         let _ = thread::Builder::new()
             .name("wasm-test-worker".into())
             .stack_size(WASM_TEST_THREAD_STACK_SIZE)
@@ -217,15 +249,19 @@ pub(crate) fn collect_wasm_testcases(
                     let Some((index, meta, function)) = task else {
                         break;
                     };
-                    let result = execute_wasm_case(
-                        &meta,
-                        function,
-                        bytes.clone(),
-                        layouts.clone(),
-                        options.clone(),
-                        watchdog,
-                        false,
-                    );
+                    let result = with_events(events.as_ref(), &meta, || {
+                        execute_wasm_case(
+                            &meta,
+                            function,
+                            bytes.clone(),
+                            layouts.clone(),
+                            options.clone(),
+                            &generative,
+                            &snapshots,
+                            watchdog,
+                            false,
+                        )
+                    });
                     let _ = tx.send((index, result));
                 }
             });
@@ -245,7 +281,52 @@ pub(crate) fn collect_wasm_testcases(
     (cases, filtered_out)
 }
 
-fn should_skip_for_executor(meta: &TestCaseMetadata, is_wasm_executor: bool) -> bool {
+/// Whether `meta` is declared under one of `allowed_roots`; cases without a known source pass.
+pub(crate) fn declared_within_roots(
+    report: &FrontendReport,
+    meta: &TestCaseMetadata,
+    allowed_roots: Option<&[PathBuf]>,
+) -> bool {
+    let Some(roots) = allowed_roots else {
+        return true;
+    };
+    let Some(span) = meta.span else {
+        return true;
+    };
+    let Some(path) = report.files.path(span.file_id) else {
+        return true;
+    };
+    let cwd = std::env::current_dir().ok();
+    let to_abs = |p: &Path| -> PathBuf {
+        if p.is_absolute() {
+            p.to_path_buf()
+        } else if let Some(cwd) = &cwd {
+            cwd.join(p)
+        } else {
+            p.to_path_buf()
+        }
+    };
+    let abs_path = to_abs(path);
+    roots.iter().any(|root| abs_path.starts_with(to_abs(root)))
+}
+
+/// Run one case, bracketing it with start and finish events.
+fn with_events(
+    events: Option<&TestEventSink>,
+    meta: &TestCaseMetadata,
+    run: impl FnOnce() -> TestCaseResult,
+) -> TestCaseResult {
+    if let Some(events) = events {
+        events.emit(&TestEvent::Started(meta));
+    }
+    let result = run();
+    if let Some(events) = events {
+        events.emit(&TestEvent::Finished(&result));
+    }
+    result
+}
+
+pub(crate) fn should_skip_for_executor(meta: &TestCaseMetadata, is_wasm_executor: bool) -> bool {
     if is_wasm_executor {
         return meta
             .categories
@@ -266,6 +347,7 @@ fn skip_after_fail_fast(meta: &TestCaseMetadata) -> TestCaseResult {
         categories: meta.categories.clone(),
         is_async: meta.is_async,
         status: TestStatus::Skipped,
+        timed_out: false,
         message: Some("skipped due to --fail-fast".into()),
         wasm_trace: None,
         duration: None,
@@ -281,6 +363,7 @@ fn missing_function_result(meta: &TestCaseMetadata) -> TestCaseResult {
         categories: meta.categories.clone(),
         is_async: meta.is_async,
         status: TestStatus::Failed,
+        timed_out: false,
         message: Some(format!(
             "test metadata refers to missing function index {}",
             meta.function_index
@@ -299,7 +382,8 @@ fn skip_parameterized(meta: &TestCaseMetadata) -> TestCaseResult {
         categories: meta.categories.clone(),
         is_async: meta.is_async,
         status: TestStatus::Skipped,
-        message: Some("parameterized testcases are not supported yet".into()),
+        timed_out: false,
+        message: Some("parameters need a @fixture, @cases row or default value".into()),
         wasm_trace: None,
         duration: None,
     }
@@ -311,6 +395,8 @@ fn execute_wasm_case(
     bytes: Arc<Vec<u8>>,
     layouts: Arc<TypeLayoutTable>,
     mut options: WasmExecutionOptions,
+    generative: &GenerativeSettings,
+    snapshots: &SnapshotSettings,
     watchdog: crate::driver::types::WatchdogConfig,
     trace_enabled: bool,
 ) -> TestCaseResult {
@@ -320,13 +406,20 @@ fn execute_wasm_case(
             meta.qualified_name, meta.function_index, function.name, function.signature.ret
         );
     }
-    let export_candidates = [
-        format!("test::{}", meta.qualified_name),
-        meta.qualified_name.clone(),
-    ];
+    let export_candidates = export_candidates(meta);
     let start = Instant::now();
     options.watchdog_timeout = watchdog.timeout;
     options.watchdog_step_limit = watchdog.step_limit;
+    let observations: Arc<Mutex<Vec<SnapshotObservation>>> = Arc::default();
+    let recorder = observations.clone();
+    options.snapshot_hook = Some(Arc::new(move |name: &str, value: &str| {
+        if let Ok(mut recorded) = recorder.lock() {
+            recorded.push(SnapshotObservation {
+                name: name.to_string(),
+                value: value.to_string(),
+            });
+        }
+    }));
     let program = match WasmProgram::from_bytes(&bytes) {
         Ok(program) => program,
         Err(err) => {
@@ -338,14 +431,39 @@ fn execute_wasm_case(
                 categories: meta.categories.clone(),
                 is_async: meta.is_async,
                 status: TestStatus::Failed,
+                timed_out: false,
                 message: Some(err.message),
                 wasm_trace: Some(WasmExecutionTrace::from_options(&options)),
                 duration: Some(start.elapsed()),
             };
         }
     };
-    let (status, message, trace) =
-        wasm_case_status(&layouts, &program, &function, &export_candidates, &options);
+    let run_case = |args: &[WasmValue]| {
+        wasm_case_status(
+            &layouts,
+            &program,
+            &function,
+            &export_candidates,
+            &options,
+            args,
+        )
+    };
+    let outcome = if meta.kind.is_generative() {
+        run_generative_case(meta, generative, run_case).unwrap_or_else(|message| WasmCaseOutcome {
+            status: TestStatus::Failed,
+            message: Some(message),
+            trace: WasmExecutionTrace::from_options(&options),
+            timed_out: false,
+        })
+    } else {
+        run_case(&[])
+    };
+    let WasmCaseOutcome {
+        status,
+        message,
+        trace,
+        timed_out,
+    } = outcome;
 
     if trace_enabled {
         info!(
@@ -356,7 +474,7 @@ fn execute_wasm_case(
         );
     }
 
-    TestCaseResult {
+    let mut result = TestCaseResult {
         id: meta.id.clone(),
         name: meta.name.clone(),
         qualified_name: meta.qualified_name.clone(),
@@ -364,10 +482,63 @@ fn execute_wasm_case(
         categories: meta.categories.clone(),
         is_async: meta.is_async,
         status,
+        timed_out,
         message,
         wasm_trace: Some(trace),
         duration: Some(start.elapsed()),
+    };
+    let observations =
+        std::mem::take(&mut *observations.lock().unwrap_or_else(|err| err.into_inner()));
+    if meta.kind.is_generative() {
+        if !observations.is_empty() {
+            result.status = TestStatus::Failed;
+            result.message = Some(format!(
+                "{} cannot use snapshot assertions",
+                meta.kind.keyword()
+            ));
+        }
+    } else {
+        check_snapshots(
+            &mut result,
+            snapshots.sources.get(&meta.id).map(PathBuf::as_path),
+            &observations,
+            snapshots.update,
+        );
     }
+    result
+}
+
+/// Export names a testcase may be emitted under, in lookup order.
+pub(crate) fn export_candidates(meta: &TestCaseMetadata) -> [String; 2] {
+    [
+        format!("test::{}", meta.qualified_name),
+        meta.qualified_name.clone(),
+    ]
+}
+
+/// Execution options for calling `function`, with async task layouts resolved when it returns one.
+pub(crate) fn case_execution_options(
+    function: &MirFunction,
+    layouts: &TypeLayoutTable,
+    options: &WasmExecutionOptions,
+) -> WasmExecutionOptions {
+    let mut case_options = options.clone();
+    if let Some(overrides) = async_layout_overrides(&function.signature.ret, layouts) {
+        case_options.async_layout = Some(overrides);
+    }
+    if let Some((len, align)) = expected_async_result_layout(&function.signature.ret, layouts) {
+        case_options.async_result_len = Some(len);
+        case_options.async_result_align = Some(align);
+    }
+    case_options
+}
+
+pub(crate) struct WasmCaseOutcome {
+    pub(crate) status: TestStatus,
+    pub(crate) message: Option<String>,
+    pub(crate) trace: WasmExecutionTrace,
+    /// Execution was aborted by the watchdog step limit or timeout.
+    pub(crate) timed_out: bool,
 }
 
 pub(crate) fn wasm_case_status(
@@ -376,7 +547,9 @@ pub(crate) fn wasm_case_status(
     function: &MirFunction,
     export_candidates: &[String; 2],
     options: &WasmExecutionOptions,
-) -> (TestStatus, Option<String>, WasmExecutionTrace) {
+    args: &[WasmValue],
+) -> WasmCaseOutcome {
+    let args: Vec<_> = args.iter().copied().map(Into::into).collect();
     for export in export_candidates {
         if !program.has_export(export) {
             continue;
@@ -386,36 +559,36 @@ pub(crate) fn wasm_case_status(
                 eprintln!("[wasm-export] {} -> {}", export, index);
             }
         }
-        let mut case_options = options.clone();
-        if let Some(overrides) = async_layout_overrides(&function.signature.ret, layouts) {
-            case_options.async_layout = Some(overrides);
-        }
-        if let Some((len, align)) = expected_async_result_layout(&function.signature.ret, layouts) {
-            case_options.async_result_len = Some(len);
-            case_options.async_result_align = Some(align);
-        }
-        match program.execute_export_with_options(export, &[], &case_options) {
+        let case_options = case_execution_options(function, layouts, options);
+        match program.execute_export_with_options(export, &args, &case_options) {
             Ok(outcome) => {
                 let WasmProgramExportOutcome { value, trace } = outcome;
                 let (status, message) =
                     evaluate_wasm_testcase_result(&function.signature.ret, value);
-                return (status, message, trace);
+                return WasmCaseOutcome {
+                    status,
+                    message,
+                    trace,
+                    timed_out: false,
+                };
             }
             Err(err) => {
-                return (
-                    TestStatus::Failed,
-                    Some(err.message),
-                    WasmExecutionTrace::from_options(&case_options),
-                );
+                return WasmCaseOutcome {
+                    status: TestStatus::Failed,
+                    timed_out: err.is_watchdog_abort(),
+                    message: Some(err.message),
+                    trace: WasmExecutionTrace::from_options(&case_options),
+                };
             }
         }
     }
 
-    (
-        TestStatus::Failed,
-        Some("testcase is not exported in wasm artifact".into()),
-        WasmExecutionTrace::from_options(options),
-    )
+    WasmCaseOutcome {
+        status: TestStatus::Failed,
+        message: Some("testcase is not exported in wasm artifact".into()),
+        trace: WasmExecutionTrace::from_options(options),
+        timed_out: false,
+    }
 }
 
 pub(crate) fn find_entry_function(module: &MirModule) -> Option<&str> {
@@ -552,7 +725,7 @@ fn expected_async_result_layout(ret_ty: &Ty, layouts: &TypeLayoutTable) -> Optio
         })
 }
 
-fn evaluate_wasm_testcase_result(
+pub(crate) fn evaluate_wasm_testcase_result(
     ret_ty: &Ty,
     result: Option<WasmValue>,
 ) -> (TestStatus, Option<String>) {
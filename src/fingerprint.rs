@@ -0,0 +1,653 @@
+//! Stable fingerprints of ASTs and MIR for incremental builds.
+//!
+//! Values are hashed through their `Serialize` implementations, so a fingerprint follows the
+//! structure of the value rather than any rendering of it. Source spans are left out unless
+//! asked for (moving code or editing comments keeps the hash), fields marked with [`body`]
+//! can be reduced to their shape for a file's surface, and map entries are combined in an
+//! order that does not depend on how the map iterates.
+
+use std::fmt;
+
+use blake3::Hasher;
+use serde::ser::{self, Serialize, Serializer};
+
+use crate::frontend::ast::{Block, Module, PropertyAccessorBody};
+use crate::mir::{MirFunction, MirModule};
+
+/// Name under which [`body`] hands a function body to the serializer.
+const BODY: &str = "chic::fingerprint::body";
+
+/// Name of the struct that carries source positions.
+const SPAN: &str = "Span";
+
+/// Accumulates values into one fingerprint.
+#[derive(Clone)]
+pub struct Fingerprinter {
+    hasher: Hasher,
+    spans: bool,
+    bodies: bool,
+}
+
+impl Fingerprinter {
+    /// A fingerprinter that ignores source spans.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            hasher: Hasher::new(),
+            spans: false,
+            bodies: true,
+        }
+    }
+
+    /// Keep source spans in the hash (debug info depends on them).
+    #[must_use]
+    pub fn with_spans(mut self, spans: bool) -> Self {
+        self.spans = spans;
+        self
+    }
+
+    /// Reduce every field marked with [`body`] to its shape.
+    #[must_use]
+    pub fn without_bodies(mut self) -> Self {
+        self.bodies = false;
+        self
+    }
+
+    pub fn str(&mut self, text: &str) -> &mut Self {
+        self.bytes(text.as_bytes());
+        self
+    }
+
+    pub fn value<T: Serialize + ?Sized>(&mut self, value: &T) -> &mut Self {
+        // Hashing never fails; the error type only exists to satisfy `Serializer`.
+        let _ = value.serialize(&mut *self);
+        self
+    }
+
+    #[must_use]
+    pub fn finish(&self) -> String {
+        self.hasher.finalize().to_hex().to_string()
+    }
+
+    /// An empty fingerprinter with the same settings, for hashing one map entry.
+    fn child(&self) -> Self {
+        Self {
+            hasher: Hasher::new(),
+            spans: self.spans,
+            bodies: self.bodies,
+        }
+    }
+
+    fn tag(&mut self, tag: u8) {
+        self.hasher.update(&[tag]);
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.hasher.update(&(bytes.len() as u64).to_le_bytes());
+        self.hasher.update(bytes);
+    }
+}
+
+impl Default for Fingerprinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The coarse form of a function body that other files can observe: whether there is one
+/// and which kind it is, but not what it contains.
+pub trait BodyShape {
+    fn shape(&self) -> &'static str;
+}
+
+impl BodyShape for Block {
+    fn shape(&self) -> &'static str {
+        "block"
+    }
+}
+
+impl<T: BodyShape> BodyShape for Option<T> {
+    fn shape(&self) -> &'static str {
+        self.as_ref().map_or("none", BodyShape::shape)
+    }
+}
+
+impl BodyShape for PropertyAccessorBody {
+    fn shape(&self) -> &'static str {
+        match self {
+            PropertyAccessorBody::Auto => "auto",
+            PropertyAccessorBody::Block(_) => "block",
+            PropertyAccessorBody::Expression(_) => "expression",
+        }
+    }
+}
+
+/// `serialize_with` hook for function body fields: surface fingerprints keep only the
+/// body's [`BodyShape`].
+///
+/// # Errors
+///
+/// Propagates errors from `serializer`.
+pub fn body<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize + BodyShape,
+    S: Serializer,
+{
+    serializer.serialize_newtype_variant(BODY, 0, value.shape(), value)
+}
+
+impl<'a> Serializer for &'a mut Fingerprinter {
+    type Ok = ();
+    type Error = fmt::Error;
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Entries<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn serialize_bool(self, value: bool) -> Result<(), fmt::Error> {
+        self.tag(b'b');
+        self.tag(u8::from(value));
+        Ok(())
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<(), fmt::Error> {
+        self.serialize_i128(value.into())
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<(), fmt::Error> {
+        self.serialize_i128(value.into())
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<(), fmt::Error> {
+        self.serialize_i128(value.into())
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<(), fmt::Error> {
+        self.serialize_i128(value.into())
+    }
+
+    fn serialize_i128(self, value: i128) -> Result<(), fmt::Error> {
+        self.tag(b'i');
+        self.hasher.update(&value.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<(), fmt::Error> {
+        self.serialize_u128(value.into())
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<(), fmt::Error> {
+        self.serialize_u128(value.into())
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<(), fmt::Error> {
+        self.serialize_u128(value.into())
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<(), fmt::Error> {
+        self.serialize_u128(value.into())
+    }
+
+    fn serialize_u128(self, value: u128) -> Result<(), fmt::Error> {
+        self.tag(b'u');
+        self.hasher.update(&value.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<(), fmt::Error> {
+        self.serialize_f64(value.into())
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<(), fmt::Error> {
+        self.tag(b'f');
+        self.hasher.update(&value.to_bits().to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_char(self, value: char) -> Result<(), fmt::Error> {
+        self.tag(b'c');
+        self.hasher.update(&u32::from(value).to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_str(self, value: &str) -> Result<(), fmt::Error> {
+        self.tag(b's');
+        self.bytes(value.as_bytes());
+        Ok(())
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<(), fmt::Error> {
+        self.tag(b'y');
+        self.bytes(value);
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<(), fmt::Error> {
+        self.tag(b'n');
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), fmt::Error> {
+        self.tag(b'S');
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), fmt::Error> {
+        self.tag(b'0');
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<(), fmt::Error> {
+        self.tag(b'U');
+        self.bytes(name.as_bytes());
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<(), fmt::Error> {
+        self.tag(b'v');
+        self.bytes(variant.as_bytes());
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), fmt::Error> {
+        self.tag(b'N');
+        self.bytes(name.as_bytes());
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), fmt::Error> {
+        self.tag(b'V');
+        self.bytes(variant.as_bytes());
+        if name == BODY && !self.bodies {
+            return Ok(());
+        }
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Compound<'a>, fmt::Error> {
+        self.tag(b'[');
+        if let Some(len) = len {
+            self.hasher.update(&(len as u64).to_le_bytes());
+        }
+        Ok(Compound::new(self, b']'))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Compound<'a>, fmt::Error> {
+        self.tag(b'(');
+        Ok(Compound::new(self, b')'))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>, fmt::Error> {
+        self.tag(b'(');
+        self.bytes(name.as_bytes());
+        Ok(Compound::new(self, b')'))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>, fmt::Error> {
+        self.tag(b'(');
+        self.bytes(variant.as_bytes());
+        Ok(Compound::new(self, b')'))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Entries<'a>, fmt::Error> {
+        Ok(Entries {
+            entry: self.child(),
+            parent: self,
+            digests: Vec::new(),
+        })
+    }
+
+    fn serialize_struct(self, name: &'static str, _len: usize) -> Result<Compound<'a>, fmt::Error> {
+        let skip = name == SPAN && !self.spans;
+        self.tag(b'{');
+        self.bytes(name.as_bytes());
+        let mut compound = Compound::new(self, b'}');
+        compound.skip = skip;
+        Ok(compound)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>, fmt::Error> {
+        self.tag(b'{');
+        self.bytes(variant.as_bytes());
+        Ok(Compound::new(self, b'}'))
+    }
+}
+
+/// Sequences, tuples and structs: elements are hashed in order.
+pub struct Compound<'a> {
+    fingerprint: &'a mut Fingerprinter,
+    close: u8,
+    /// Set for spans the fingerprint ignores.
+    skip: bool,
+}
+
+impl<'a> Compound<'a> {
+    fn new(fingerprint: &'a mut Fingerprinter, close: u8) -> Self {
+        Self {
+            fingerprint,
+            close,
+            skip: false,
+        }
+    }
+
+    fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), fmt::Error> {
+        if self.skip {
+            return Ok(());
+        }
+        value.serialize(&mut *self.fingerprint)
+    }
+
+    fn field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), fmt::Error> {
+        if self.skip {
+            return Ok(());
+        }
+        self.fingerprint.bytes(key.as_bytes());
+        value.serialize(&mut *self.fingerprint)
+    }
+
+    fn close(self) {
+        self.fingerprint.tag(self.close);
+    }
+}
+
+impl ser::SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = fmt::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), fmt::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), fmt::Error> {
+        self.close();
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = fmt::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), fmt::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), fmt::Error> {
+        self.close();
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = fmt::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), fmt::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), fmt::Error> {
+        self.close();
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = fmt::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), fmt::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), fmt::Error> {
+        self.close();
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = fmt::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), fmt::Error> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), fmt::Error> {
+        self.close();
+        Ok(())
+    }
+}
+
+impl ser::SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = fmt::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), fmt::Error> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), fmt::Error> {
+        self.close();
+        Ok(())
+    }
+}
+
+/// Map entries: each entry is hashed on its own and the entry hashes are combined in sorted
+/// order, so `HashMap` iteration order does not leak into the fingerprint.
+pub struct Entries<'a> {
+    parent: &'a mut Fingerprinter,
+    entry: Fingerprinter,
+    digests: Vec<[u8; 32]>,
+}
+
+impl ser::SerializeMap for Entries<'_> {
+    type Ok = ();
+    type Error = fmt::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), fmt::Error> {
+        key.serialize(&mut self.entry)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), fmt::Error> {
+        value.serialize(&mut self.entry)?;
+        let entry = std::mem::replace(&mut self.entry, self.parent.child());
+        self.digests.push(*entry.hasher.finalize().as_bytes());
+        Ok(())
+    }
+
+    fn end(mut self) -> Result<(), fmt::Error> {
+        self.digests.sort_unstable();
+        self.parent.tag(b'<');
+        self.parent
+            .hasher
+            .update(&(self.digests.len() as u64).to_le_bytes());
+        for digest in &self.digests {
+            self.parent.hasher.update(digest);
+        }
+        self.parent.tag(b'>');
+        Ok(())
+    }
+}
+
+/// Everything a parsed file declares and contains, ignoring where it sits in the file.
+#[must_use]
+pub fn ast_fingerprint(module: &Module) -> String {
+    module_fingerprint(module, Fingerprinter::new())
+}
+
+/// What other files can observe of a parsed file: its declarations without their bodies.
+#[must_use]
+pub fn surface_fingerprint(module: &Module) -> String {
+    module_fingerprint(module, Fingerprinter::new().without_bodies())
+}
+
+fn module_fingerprint(module: &Module, mut fingerprint: Fingerprinter) -> String {
+    fingerprint
+        .value(&module.namespace)
+        .value(&module.crate_attributes)
+        .value(&module.namespace_attributes)
+        .value(&module.friend_declarations)
+        .value(&module.package_imports)
+        .value(&module.items);
+    fingerprint.finish()
+}
+
+/// A lowered function, including its body. Spans are kept when `spans` is set.
+#[must_use]
+pub fn function_fingerprint(function: &MirFunction, spans: bool) -> String {
+    Fingerprinter::new()
+        .with_spans(spans)
+        .value(function)
+        .finish()
+}
+
+/// Everything in a MIR module except function bodies: signatures, layouts, statics,
+/// vtables and module metadata.
+#[must_use]
+pub fn interface_fingerprint(module: &MirModule) -> String {
+    let mut fingerprint = Fingerprinter::new();
+    for function in &module.functions {
+        fingerprint
+            .str(&function.name)
+            .value(&function.kind)
+            .value(&function.signature)
+            .value(&(function.is_async, function.is_generator))
+            .value(&function.async_result)
+            .value(&function.optimization_hints)
+            .value(&function.extern_spec)
+            .value(&(function.is_weak, function.is_weak_import));
+    }
+    fingerprint
+        .value(&module.test_cases)
+        .value(&module.statics)
+        .value(&module.interned_strs)
+        .value(&module.exports)
+        .value(&module.attributes)
+        .value(&module.trait_vtables)
+        .value(&module.class_vtables)
+        .value(&module.interface_defaults)
+        .value(&module.default_arguments)
+        .value(&module.async_plans)
+        .value(&module.type_variance)
+        .value(&module.type_layouts);
+    fingerprint.finish()
+}
+
+/// A MIR module including its function bodies, as codegen sees it.
+#[must_use]
+pub fn mir_fingerprint(module: &MirModule, spans: bool) -> String {
+    let mut fingerprint = Fingerprinter::new();
+    fingerprint.str(&interface_fingerprint(module));
+    for function in &module.functions {
+        fingerprint.str(&function_fingerprint(function, spans));
+    }
+    fingerprint.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::parse_module;
+    use std::collections::HashMap;
+
+    fn parse(source: &str) -> Module {
+        parse_module(source).expect("parse").module
+    }
+
+    #[test]
+    fn ignores_spans_and_comments() {
+        let original = parse("namespace Demo;\npublic int Add(int a, int b) { return a + b; }\n");
+        let moved = parse(
+            "namespace Demo;\n\n// adds\n\npublic int Add(int a, int b)\n{\n    return a + b;\n}\n",
+        );
+        assert_eq!(ast_fingerprint(&original), ast_fingerprint(&moved));
+    }
+
+    #[test]
+    fn surface_ignores_bodies() {
+        let original = parse("namespace Demo;\npublic int Add(int a, int b) { return a + b; }\n");
+        let body = parse("namespace Demo;\npublic int Add(int a, int b) { return b + a; }\n");
+        let signature = parse("namespace Demo;\npublic long Add(int a, int b) { return a + b; }\n");
+        assert_ne!(ast_fingerprint(&original), ast_fingerprint(&body));
+        assert_eq!(surface_fingerprint(&original), surface_fingerprint(&body));
+        assert_ne!(
+            surface_fingerprint(&original),
+            surface_fingerprint(&signature)
+        );
+    }
+
+    #[test]
+    fn surface_tracks_accessor_shapes() {
+        let auto = parse("namespace Demo;\npublic class C { public int X { get; } }\n");
+        let block =
+            parse("namespace Demo;\npublic class C { public int X { get { return 1; } } }\n");
+        let other_block =
+            parse("namespace Demo;\npublic class C { public int X { get { return 2; } } }\n");
+        assert_ne!(surface_fingerprint(&auto), surface_fingerprint(&block));
+        assert_eq!(
+            surface_fingerprint(&block),
+            surface_fingerprint(&other_block)
+        );
+    }
+
+    #[test]
+    fn map_order_does_not_matter() {
+        let forward: HashMap<String, u32> = (0..64).map(|n| (n.to_string(), n)).collect();
+        let mut backward = HashMap::<String, u32>::new();
+        for n in (0..64).rev() {
+            backward.insert(n.to_string(), n);
+        }
+        assert_eq!(
+            Fingerprinter::new().value(&forward).finish(),
+            Fingerprinter::new().value(&backward).finish()
+        );
+    }
+}
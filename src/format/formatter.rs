@@ -295,7 +295,10 @@ impl<'a> TokenFormatter<'a> {
             | Keyword::Extension
             | Keyword::Trait
             | Keyword::Impl
-            | Keyword::Testcase => {
+            | Keyword::Testcase
+            | Keyword::Proptestcase
+            | Keyword::Fuzzcase
+            | Keyword::Benchcase => {
                 self.ensure_separation_for_word();
                 self.push_text(lexeme);
                 self.control_keyword = Some(keyword);
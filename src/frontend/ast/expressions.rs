@@ -4,9 +4,10 @@ use super::patterns::{CasePattern, PatternGuard};
 use super::types::TypeExpr;
 use crate::frontend::diagnostics::Span;
 use crate::syntax::expr::{ExprNode, NewExpr};
+use serde::Serialize;
 
 /// Local expression captured as source text.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Expression {
     pub text: String,
     pub span: Option<Span>,
@@ -39,14 +40,14 @@ impl Expression {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Block {
     pub statements: Vec<Statement>,
     pub span: Option<Span>,
 }
 
 /// Statement node with span metadata.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Statement {
     pub span: Option<Span>,
     pub kind: StatementKind,
@@ -74,7 +75,7 @@ impl Statement {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum StatementKind {
     Block(Block),
     Empty,
@@ -137,7 +138,7 @@ pub enum StatementKind {
     },
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct VariableDeclaration {
     pub modifier: VariableModifier,
     pub type_annotation: Option<TypeExpr>,
@@ -145,27 +146,27 @@ pub struct VariableDeclaration {
     pub is_pinned: bool,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub enum VariableModifier {
     #[default]
     Let,
     Var,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct VariableDeclarator {
     pub name: String,
     pub initializer: Option<Expression>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IfStatement {
     pub condition: Expression,
     pub then_branch: Box<Statement>,
     pub else_branch: Option<Box<Statement>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ForStatement {
     pub initializer: Option<ForInitializer>,
     pub condition: Option<Expression>,
@@ -173,14 +174,14 @@ pub struct ForStatement {
     pub body: Box<Statement>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum ForInitializer {
     Declaration(VariableDeclaration),
     Const(ConstStatement),
     Expressions(Vec<Expression>),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ForeachStatement {
     /// Raw binding text between `foreach (` and `in`.
     pub binding: String,
@@ -189,38 +190,38 @@ pub struct ForeachStatement {
     pub body: Box<Statement>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SwitchStatement {
     pub expression: Expression,
     pub sections: Vec<SwitchSection>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SwitchSection {
     pub labels: Vec<SwitchLabel>,
     pub statements: Vec<Statement>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum SwitchLabel {
     Case(SwitchCaseLabel),
     Default,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SwitchCaseLabel {
     pub pattern: CasePattern,
     pub guards: Vec<PatternGuard>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TryStatement {
     pub body: Block,
     pub catches: Vec<CatchClause>,
     pub finally: Option<Block>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CatchClause {
     pub type_annotation: Option<TypeExpr>,
     pub identifier: Option<String>,
@@ -228,24 +229,24 @@ pub struct CatchClause {
     pub body: Block,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct UsingStatement {
     pub resource: UsingResource,
     pub body: Option<Box<Statement>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum UsingResource {
     Expression(Expression),
     Declaration(VariableDeclaration),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct GotoStatement {
     pub target: GotoTarget,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum GotoTarget {
     Label(String),
     Case {
@@ -255,7 +256,7 @@ pub enum GotoTarget {
     Default,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FixedStatement {
     pub declaration: VariableDeclaration,
     pub body: Box<Statement>,
@@ -1,25 +1,26 @@
 use super::{
     Attribute, ConstMemberDecl, DiInjectAttr, DiServiceAttr, DocComment, FunctionDecl,
-    GenericParams, Item, MemberDispatch, Parameter, Signature, Visibility,
+    GenericParam, GenericParams, Item, MemberDispatch, Parameter, Signature, Visibility,
 };
 use crate::frontend::ast::expressions::{Block, Expression};
 use crate::frontend::ast::types::TypeExpr;
 use crate::frontend::attributes::LayoutHints;
 use crate::frontend::diagnostics::Span;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum InlineAttr {
     Local,
     Cross,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct RecordPositionalField {
     pub name: String,
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StructDecl {
     pub visibility: Visibility,
     pub name: String,
@@ -45,7 +46,7 @@ pub struct StructDecl {
     pub record_positional_fields: Vec<RecordPositionalField>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct UnionDecl {
     pub visibility: Visibility,
     pub name: String,
@@ -58,13 +59,13 @@ pub struct UnionDecl {
     pub attributes: Vec<Attribute>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum UnionMember {
     Field(UnionField),
     View(UnionViewDecl),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct UnionField {
     pub visibility: Visibility,
     pub name: String,
@@ -74,7 +75,7 @@ pub struct UnionField {
     pub attributes: Vec<Attribute>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct UnionViewDecl {
     pub visibility: Visibility,
     pub name: String,
@@ -84,7 +85,7 @@ pub struct UnionViewDecl {
     pub attributes: Vec<Attribute>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct EnumDecl {
     pub visibility: Visibility,
     pub name: String,
@@ -99,7 +100,7 @@ pub struct EnumDecl {
     pub attributes: Vec<Attribute>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct DelegateDecl {
     pub visibility: Visibility,
     pub name: String,
@@ -112,14 +113,14 @@ pub struct DelegateDecl {
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
 pub enum ClassKind {
     #[default]
     Class,
     Error,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ClassDecl {
     pub visibility: Visibility,
     pub kind: ClassKind,
@@ -140,39 +141,42 @@ pub struct ClassDecl {
     pub is_sealed: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ConstructorDecl {
     pub visibility: Visibility,
     pub kind: ConstructorKind,
     pub parameters: Vec<Parameter>,
+    #[serde(serialize_with = "crate::fingerprint::body")]
     pub body: Option<Block>,
     pub initializer: Option<ConstructorInitializer>,
     pub doc: Option<DocComment>,
     pub span: Option<Span>,
     pub attributes: Vec<Attribute>,
+    /// The `init` keyword, or the type name of a rejected type-named constructor.
+    pub name_span: Option<Span>,
     pub di_inject: Option<DiInjectAttr>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum ConstructorKind {
     Designated,
     Convenience,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum ConstructorInitTarget {
     SelfType,
     Super,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ConstructorInitializer {
     pub target: ConstructorInitTarget,
     pub arguments: Vec<Expression>,
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct InterfaceDecl {
     pub visibility: Visibility,
     pub name: String,
@@ -186,7 +190,7 @@ pub struct InterfaceDecl {
     pub attributes: Vec<Attribute>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TraitDecl {
     pub visibility: Visibility,
     pub name: String,
@@ -201,14 +205,14 @@ pub struct TraitDecl {
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum TraitMember {
     Method(FunctionDecl),
     AssociatedType(TraitAssociatedType),
     Const(ConstMemberDecl),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TraitAssociatedType {
     pub name: String,
     pub generics: Option<GenericParams>,
@@ -217,7 +221,7 @@ pub struct TraitAssociatedType {
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ImplDecl {
     pub visibility: Visibility,
     pub trait_ref: Option<TypeExpr>,
@@ -229,14 +233,54 @@ pub struct ImplDecl {
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+impl ImplDecl {
+    /// The generic parameter targeted by a blanket impl (`impl<T> Trait for T`), if any.
+    #[must_use]
+    pub fn blanket_param(&self) -> Option<&GenericParam> {
+        self.trait_ref.as_ref()?;
+        self.generics.as_ref()?.type_param_named_by(&self.target)
+    }
+
+    /// Returns the extension an inherent `impl Type { ... }` block lowers to, or `None` for
+    /// trait impls. Only methods carry over; other members are rejected during type checking.
+    #[must_use]
+    pub fn inherent_extension(&self) -> Option<ExtensionDecl> {
+        if self.trait_ref.is_some() {
+            return None;
+        }
+        let members = self
+            .members
+            .iter()
+            .filter_map(|member| match member {
+                ImplMember::Method(function) => {
+                    Some(ExtensionMember::Method(ExtensionMethodDecl {
+                        function: function.clone(),
+                        is_default: false,
+                    }))
+                }
+                ImplMember::AssociatedType(_) | ImplMember::Const(_) => None,
+            })
+            .collect();
+        Some(ExtensionDecl {
+            visibility: self.visibility,
+            target: self.target.clone(),
+            generics: self.generics.clone(),
+            members,
+            doc: self.doc.clone(),
+            attributes: self.attributes.clone(),
+            conditions: Vec::new(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub enum ImplMember {
     Method(FunctionDecl),
     AssociatedType(TraitAssociatedType),
     Const(ConstMemberDecl),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ExtensionDecl {
     pub visibility: Visibility,
     pub target: TypeExpr,
@@ -247,7 +291,7 @@ pub struct ExtensionDecl {
     pub conditions: Vec<ExtensionCondition>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FieldDecl {
     pub visibility: Visibility,
     pub name: String,
@@ -263,7 +307,7 @@ pub struct FieldDecl {
     pub view_of: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PropertyDecl {
     pub visibility: Visibility,
     pub modifiers: Vec<String>,
@@ -282,10 +326,11 @@ pub struct PropertyDecl {
     pub explicit_interface: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PropertyAccessor {
     pub kind: PropertyAccessorKind,
     pub visibility: Option<Visibility>,
+    #[serde(serialize_with = "crate::fingerprint::body")]
     pub body: PropertyAccessorBody,
     pub doc: Option<DocComment>,
     pub span: Option<Span>,
@@ -293,14 +338,14 @@ pub struct PropertyAccessor {
     pub dispatch: MemberDispatch,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum PropertyAccessorKind {
     Get,
     Set,
     Init,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum PropertyAccessorBody {
     Auto,
     Block(Block),
@@ -359,7 +404,7 @@ impl PropertyAccessorBody {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MmioStructAttr {
     pub base_address: u64,
     pub size: Option<u64>,
@@ -368,27 +413,27 @@ pub struct MmioStructAttr {
     pub requires_unsafe: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MmioFieldAttr {
     pub offset: u32,
     pub width_bits: u16,
     pub access: MmioAccess,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum MmioAccess {
     ReadOnly,
     WriteOnly,
     ReadWrite,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum MmioEndianness {
     Little,
     Big,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct EnumVariant {
     pub name: String,
     pub fields: Vec<FieldDecl>,
@@ -396,7 +441,7 @@ pub struct EnumVariant {
     pub doc: Option<DocComment>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ImportDirective {
     pub doc: Option<DocComment>,
     pub is_global: bool,
@@ -404,7 +449,7 @@ pub struct ImportDirective {
     pub kind: ImportKind,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum ImportKind {
     Namespace { path: String },
     Alias { alias: String, target: String },
@@ -415,20 +460,22 @@ pub enum ImportKind {
 pub type UsingDirective = ImportDirective;
 pub type UsingKind = ImportKind;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct OperatorDecl {
     pub kind: OperatorKind,
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum OperatorKind {
     Unary(UnaryOperator),
     Binary(BinaryOperator),
+    /// Dedicated compound assignment (`operator +=`) for the wrapped binary operator.
+    CompoundAssignment(BinaryOperator),
     Conversion(ConversionKind),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum UnaryOperator {
     Negate,
     UnaryPlus,
@@ -438,7 +485,7 @@ pub enum UnaryOperator {
     Decrement,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum BinaryOperator {
     Add,
     Subtract,
@@ -458,13 +505,13 @@ pub enum BinaryOperator {
     GreaterThanOrEqual,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum ConversionKind {
     Implicit,
     Explicit,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum ClassMember {
     Field(FieldDecl),
     Method(FunctionDecl),
@@ -473,7 +520,7 @@ pub enum ClassMember {
     Const(ConstMemberDecl),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum InterfaceMember {
     Method(FunctionDecl),
     Property(PropertyDecl),
@@ -481,18 +528,18 @@ pub enum InterfaceMember {
     Const(ConstMemberDecl),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum ExtensionMember {
     Method(ExtensionMethodDecl),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ExtensionMethodDecl {
     pub function: FunctionDecl,
     pub is_default: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ExtensionCondition {
     pub target: TypeExpr,
     pub constraint: TypeExpr,
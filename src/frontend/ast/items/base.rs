@@ -7,9 +7,10 @@ use crate::frontend::ast::overloads::OverloadCatalog;
 use crate::frontend::ast::types::TypeExpr;
 use crate::frontend::diagnostics::Span;
 use crate::frontend::lexer::Token;
+use serde::Serialize;
 use std::fmt;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
 pub struct MemberDispatch {
     pub is_virtual: bool,
     pub is_override: bool,
@@ -18,26 +19,26 @@ pub struct MemberDispatch {
 }
 
 /// Crate-level attribute settings captured during parsing.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum CrateStdSetting {
     Unspecified,
     Std { span: Option<Span> },
     NoStd { span: Option<Span> },
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum CrateMainSetting {
     Unspecified,
     NoMain { span: Option<Span> },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FriendDirective {
     pub prefix: String,
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PackageImport {
     pub name: String,
     pub span: Option<Span>,
@@ -90,7 +91,7 @@ impl Default for CrateMainSetting {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct CrateAttributes {
     pub std_setting: CrateStdSetting,
     pub main_setting: CrateMainSetting,
@@ -172,7 +173,7 @@ impl Module {
 }
 
 /// Items permitted at namespace scope.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum Item {
     Function(FunctionDecl),
     Struct(StructDecl),
@@ -193,7 +194,7 @@ pub enum Item {
 }
 
 /// XML documentation comment captured from leading `///` trivia.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct DocComment {
     pub lines: Vec<String>,
 }
@@ -224,14 +225,14 @@ impl DocComment {
 }
 
 /// Classification for surface attributes.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum AttributeKind {
     Builtin,
     Macro,
 }
 
 /// Macro-specific metadata recorded alongside an attribute.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct AttributeMacroMetadata {
     /// Whether the attribute should be considered during macro expansion.
     pub expandable: bool,
@@ -247,7 +248,7 @@ impl AttributeMacroMetadata {
 }
 
 /// Supported dependency-injection lifetimes.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum DiLifetime {
     Transient,
     Scoped,
@@ -256,7 +257,7 @@ pub enum DiLifetime {
 }
 
 /// Metadata captured from `@service` attributes.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct DiServiceAttr {
     pub lifetime: Option<DiLifetime>,
     pub named: Option<String>,
@@ -270,7 +271,7 @@ impl DiServiceAttr {
 }
 
 /// Metadata captured from `@inject` attributes.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct DiInjectAttr {
     pub lifetime: Option<DiLifetime>,
     pub named: Option<String>,
@@ -289,7 +290,7 @@ impl DiInjectAttr {
 }
 
 /// Attribute applied to a declaration or statement.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Attribute {
     pub name: String,
     pub arguments: Vec<AttributeArgument>,
@@ -326,7 +327,7 @@ impl Attribute {
 }
 
 /// Individual argument supplied to an attribute.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AttributeArgument {
     pub name: Option<String>,
     pub value: String,
@@ -344,7 +345,7 @@ impl AttributeArgument {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct NamespaceDecl {
     pub name: String,
     pub items: Vec<Item>,
@@ -353,7 +354,7 @@ pub struct NamespaceDecl {
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TypeAliasDecl {
     pub visibility: Visibility,
     pub name: String,
@@ -364,19 +365,19 @@ pub struct TypeAliasDecl {
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum StaticMutability {
     Const,
     Mutable,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StaticItemDecl {
     pub visibility: Visibility,
     pub declaration: StaticDeclaration,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StaticDeclaration {
     pub mutability: StaticMutability,
     pub ty: TypeExpr,
@@ -391,32 +392,32 @@ pub struct StaticDeclaration {
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StaticDeclarator {
     pub name: String,
     pub initializer: Option<Expression>,
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ConstItemDecl {
     pub visibility: Visibility,
     pub declaration: ConstDeclaration,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ConstMemberDecl {
     pub visibility: Visibility,
     pub modifiers: Vec<String>,
     pub declaration: ConstDeclaration,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ConstStatement {
     pub declaration: ConstDeclaration,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ConstDeclaration {
     pub ty: TypeExpr,
     pub declarators: Vec<ConstDeclarator>,
@@ -424,7 +425,7 @@ pub struct ConstDeclaration {
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ConstDeclarator {
     pub name: String,
     pub initializer: Expression,
@@ -432,7 +433,7 @@ pub struct ConstDeclarator {
 }
 
 /// Type parameter list declared on a generic item.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct GenericParams {
     pub span: Option<Span>,
     pub params: Vec<GenericParam>,
@@ -448,10 +449,21 @@ impl GenericParams {
     pub fn is_empty(&self) -> bool {
         self.params.is_empty()
     }
+
+    /// Returns the type parameter that `ty` names directly (a bare `T` with no suffixes).
+    #[must_use]
+    pub fn type_param_named_by(&self, ty: &TypeExpr) -> Option<&GenericParam> {
+        if ty.base.len() != 1 || !ty.suffixes.is_empty() {
+            return None;
+        }
+        self.params
+            .iter()
+            .find(|param| param.as_type().is_some() && param.name == ty.base[0])
+    }
 }
 
 /// Individual parameter declared on a generic item.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct GenericParam {
     pub name: String,
     pub span: Option<Span>,
@@ -512,13 +524,13 @@ impl GenericParam {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum GenericParamKind {
     Type(TypeParamData),
     Const(ConstParamData),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
 pub enum Variance {
     #[default]
     Invariant,
@@ -537,7 +549,7 @@ impl Variance {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TypeParamData {
     pub constraints: Vec<GenericConstraint>,
     pub variance: Variance,
@@ -552,7 +564,7 @@ impl Default for TypeParamData {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ConstParamData {
     pub ty: TypeExpr,
     pub constraints: Vec<ConstWherePredicate>,
@@ -569,7 +581,7 @@ impl ConstParamData {
 }
 
 /// Constraint attached to a const generic parameter.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ConstWherePredicate {
     pub expr: Expression,
     pub span: Option<Span>,
@@ -583,7 +595,7 @@ impl ConstWherePredicate {
 }
 
 /// Constraint applied to a generic parameter.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct GenericConstraint {
     pub kind: GenericConstraintKind,
     pub span: Option<Span>,
@@ -597,7 +609,7 @@ impl GenericConstraint {
 }
 
 /// Supported generic constraint kinds.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum GenericConstraintKind {
     Type(TypeExpr),
     Struct,
@@ -607,7 +619,7 @@ pub enum GenericConstraintKind {
     AutoTrait(AutoTraitConstraint),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum AutoTraitConstraint {
     ThreadSafe,
     Shareable,
@@ -624,12 +636,13 @@ impl AutoTraitConstraint {
 }
 
 /// Function declaration (free or associated).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FunctionDecl {
     pub visibility: Visibility,
     pub name: String,
     pub name_span: Option<Span>,
     pub signature: Signature,
+    #[serde(serialize_with = "crate::fingerprint::body")]
     pub body: Option<Block>,
     pub is_async: bool,
     pub is_constexpr: bool,
@@ -649,7 +662,7 @@ pub struct FunctionDecl {
 }
 
 /// Additional metadata describing an extern function binding.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ExternOptions {
     pub convention: String,
     pub library: Option<String>,
@@ -660,7 +673,7 @@ pub struct ExternOptions {
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum VectorizeHint {
     Decimal,
 }
@@ -703,7 +716,7 @@ impl ExternOptions {
 }
 
 /// Binding strategy for extern functions.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum ExternBinding {
     /// Symbol resolved by the static linker (current behaviour).
     Static,
@@ -730,17 +743,91 @@ impl fmt::Display for ExternBinding {
     }
 }
 
-#[derive(Debug, Clone)]
+/// Declaration form of a testcase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum TestCaseKind {
+    /// `testcase`: runs once with its declared arguments.
+    #[default]
+    Unit,
+    /// `proptestcase`: runs against generated parameter values and shrinks failures.
+    Property,
+    /// `fuzzcase`: like a property testcase, but generation favours boundary values and raw bit patterns.
+    Fuzz,
+    /// `benchcase`: not run by `chic test`; `chic bench` times it over many iterations.
+    Bench,
+}
+
+impl TestCaseKind {
+    #[must_use]
+    pub fn keyword(self) -> &'static str {
+        match self {
+            Self::Unit => "testcase",
+            Self::Property => "proptestcase",
+            Self::Fuzz => "fuzzcase",
+            Self::Bench => "benchcase",
+        }
+    }
+
+    /// Whether the runner generates the testcase's arguments.
+    #[must_use]
+    pub fn is_generative(self) -> bool {
+        matches!(self, Self::Property | Self::Fuzz)
+    }
+
+    /// Whether the declaration is a benchmark rather than a test.
+    #[must_use]
+    pub fn is_bench(self) -> bool {
+        matches!(self, Self::Bench)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct TestCaseDecl {
     pub name: String,
+    pub kind: TestCaseKind,
     pub signature: Option<Signature>,
+    #[serde(serialize_with = "crate::fingerprint::body")]
     pub body: Block,
     pub is_async: bool,
     pub doc: Option<DocComment>,
     pub attributes: Vec<Attribute>,
-}
-
-#[derive(Debug, Clone)]
+    /// Set on testcases synthesised from a declaration with fixtures, hooks or `@cases` rows.
+    pub expansion: Option<TestCaseExpansion>,
+}
+
+/// Links a testcase generated by fixture/`@cases` expansion back to its declaration.
+#[derive(Debug, Clone, Serialize)]
+pub struct TestCaseExpansion {
+    /// Name of the declared testcase.
+    pub declared_name: String,
+    /// Declared parameters and where each one's argument comes from; `None` means the runner
+    /// supplies it (generated values for `proptestcase`/`fuzzcase`).
+    pub parameters: Vec<(Parameter, Option<TestArgumentSource>)>,
+    /// `@cases` row this testcase runs, if any.
+    pub row: Option<TestCaseRowDecl>,
+}
+
+/// One `@cases(...)` data row of a declared testcase.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TestCaseRowDecl {
+    /// Zero-based position among the declaration's `@cases` attributes.
+    pub index: usize,
+    /// Row arguments as written, e.g. `1, 2` or `name: "x"`.
+    pub arguments: String,
+}
+
+/// Where an expanded testcase takes a parameter's argument from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum TestArgumentSource {
+    /// Returned by a `@fixture` provider; holds the qualified call, e.g. `Suite.Offset()`.
+    Fixture(String),
+    /// Supplied by the `@cases` row, as written.
+    Case(String),
+    /// Left to the parameter's default value.
+    Default,
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct Signature {
     pub parameters: Vec<Parameter>,
     pub return_type: TypeExpr,
@@ -749,7 +836,7 @@ pub struct Signature {
     pub variadic: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ThrowsClause {
     pub types: Vec<TypeExpr>,
     pub span: Option<Span>,
@@ -767,7 +854,7 @@ impl ThrowsClause {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Parameter {
     pub binding: BindingModifier,
     pub binding_nullable: bool,
@@ -782,7 +869,7 @@ pub struct Parameter {
     pub is_extension_this: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct LendsClause {
     pub targets: Vec<String>,
     pub span: Option<Span>,
@@ -795,7 +882,7 @@ impl LendsClause {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum Visibility {
     Public,
     Internal,
@@ -811,7 +898,7 @@ impl Default for Visibility {
     }
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, Serialize)]
 pub enum BindingModifier {
     In,
     Ref,
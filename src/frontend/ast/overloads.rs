@@ -775,6 +775,7 @@ mod tests {
                 None,
                 AttributeKind::Builtin,
             )],
+            name_span: None,
             di_inject: None,
         };
 
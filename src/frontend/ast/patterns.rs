@@ -3,9 +3,10 @@
 use super::expressions::Expression;
 use crate::frontend::diagnostics::Span;
 use crate::syntax::pattern::PatternAst;
+use serde::Serialize;
 
 /// Pattern captured from the source text plus its parsed representation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CasePattern {
     /// Raw source text as captured by the parser (sans guards).
     pub raw: Expression,
@@ -21,7 +22,7 @@ impl CasePattern {
 }
 
 /// Guard expression attached to a pattern (`when` clause).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PatternGuard {
     pub expression: Expression,
     pub depth: usize,
@@ -2,9 +2,10 @@
 
 use super::expressions::Expression;
 use crate::frontend::diagnostics::Span;
-use std::cell::RefCell;
+use serde::Serialize;
+use std::sync::RwLock;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TypeExpr {
     pub name: String,
     pub base: Vec<String>,
@@ -157,13 +158,13 @@ impl TypeExpr {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum RefKind {
     Ref,
     ReadOnly,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum PointerModifier {
     Restrict,
     NoAlias,
@@ -172,7 +173,7 @@ pub enum PointerModifier {
     ExposeAddress,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum TypeSuffix {
     GenericArgs(Vec<GenericArgument>),
     Array(ArrayRankSpecifier),
@@ -184,11 +185,23 @@ pub enum TypeSuffix {
     Qualifier(String),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Serialize)]
 pub struct GenericArgument {
     pub ty: Option<TypeExpr>,
     pub expr: Expression,
-    evaluated: RefCell<Option<String>>,
+    /// Lock rather than `RefCell` so parsed modules can be shared with codegen jobs.
+    #[serde(skip)]
+    evaluated: RwLock<Option<String>>,
+}
+
+impl Clone for GenericArgument {
+    fn clone(&self) -> Self {
+        Self {
+            ty: self.ty.clone(),
+            expr: self.expr.clone(),
+            evaluated: RwLock::new(self.evaluated_value()),
+        }
+    }
 }
 
 impl GenericArgument {
@@ -197,7 +210,7 @@ impl GenericArgument {
         Self {
             ty,
             expr,
-            evaluated: RefCell::new(None),
+            evaluated: RwLock::new(None),
         }
     }
 
@@ -207,7 +220,7 @@ impl GenericArgument {
         Self {
             ty: Some(ty),
             expr,
-            evaluated: RefCell::new(None),
+            evaluated: RwLock::new(None),
         }
     }
 
@@ -222,16 +235,23 @@ impl GenericArgument {
     }
 
     pub fn set_evaluated_value(&self, value: impl Into<String>) {
-        *self.evaluated.borrow_mut() = Some(value.into());
+        let mut evaluated = self
+            .evaluated
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        *evaluated = Some(value.into());
     }
 
     #[must_use]
     pub fn evaluated_value(&self) -> Option<String> {
-        self.evaluated.borrow().clone()
+        self.evaluated
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub struct ArrayRankSpecifier {
     pub dimensions: usize,
 }
@@ -243,7 +263,7 @@ impl ArrayRankSpecifier {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FnTypeExpr {
     pub abi: FnTypeAbi,
     pub params: Vec<TypeExpr>,
@@ -263,13 +283,13 @@ impl FnTypeExpr {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TraitObjectTypeExpr {
     pub bounds: Vec<TypeExpr>,
     pub opaque_impl: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum FnTypeAbi {
     Chic,
     Extern(String),
@@ -6,9 +6,10 @@ use crate::frontend::ast::{
     Module, Parameter, PropertyDecl, StructDecl, TestCaseDecl,
 };
 use crate::frontend::diagnostics::{Diagnostic, Span};
+use serde::Serialize;
 
 /// Layout-related hints derived from `@repr`/`@align` attributes.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
 pub struct LayoutHints {
     pub repr_c: bool,
     pub packing: Option<PackingHint>,
@@ -22,13 +23,13 @@ impl LayoutHints {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub struct PackingHint {
     pub value: Option<u32>,
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub struct AlignHint {
     pub value: u32,
     pub span: Option<Span>,
@@ -59,7 +60,7 @@ pub struct ConditionalAttribute {
 }
 
 /// Code generation hints applied to a function or testcase.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
 pub struct OptimizationHints {
     pub hot: bool,
     pub cold: bool,
@@ -68,6 +68,40 @@ impl ConditionalDefines {
     pub fn iter(&self) -> impl Iterator<Item = (&String, &DefineValue)> {
         self.values.iter()
     }
+
+    /// Turn the package feature `name` on or off for `@cfg(feature = "name")`.
+    pub fn set_feature(&mut self, name: &str, enabled: bool) {
+        self.set_bool(feature_define(name), enabled);
+    }
+
+    #[must_use]
+    pub fn has_feature(&self, name: &str) -> bool {
+        self.is_true(&feature_define(name))
+    }
+
+    /// These defines with exactly `features` switched on, for compiling another package.
+    #[must_use]
+    pub fn with_features<'f>(&self, features: impl IntoIterator<Item = &'f String>) -> Self {
+        let mut defines = self.clone();
+        defines
+            .values
+            .retain(|key, _| key != "feature" && !key.starts_with("feature_"));
+        for feature in features {
+            defines.set_feature(feature, true);
+        }
+        defines
+    }
+}
+
+/// The boolean define backing package feature `name` (`feature_<name>`, with every
+/// character other than ASCII letters and digits replaced by `_`).
+#[must_use]
+pub fn feature_define(name: &str) -> String {
+    let normalized: String = name
+        .chars()
+        .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '_' })
+        .collect();
+    format!("feature_{normalized}")
 }
 
 static ACTIVE_DEFINES: LazyLock<RwLock<ConditionalDefines>> =
@@ -846,6 +880,8 @@ struct Value<'a> {
 enum ValueKind<'a> {
     Bool(bool),
     Str(Cow<'a, str>),
+    /// `feature`, which only compares against a feature name.
+    Features(&'a ConditionalDefines),
 }
 
 impl<'a> Value<'a> {
@@ -870,6 +906,10 @@ impl<'a> Value<'a> {
                 "string value used where boolean was expected",
                 self.span,
             )),
+            ValueKind::Features(_) => Err(ConditionError::new(
+                "`feature` must be compared with a feature name, e.g. `feature = \"std\"`",
+                self.span,
+            )),
         }
     }
 }
@@ -884,6 +924,10 @@ fn compare_values<'a>(
             Ok(if eq { lhs == rhs } else { lhs != rhs })
         }
         (ValueKind::Str(lhs), ValueKind::Str(rhs)) => Ok(if eq { lhs == rhs } else { lhs != rhs }),
+        (ValueKind::Features(defines), ValueKind::Str(name))
+        | (ValueKind::Str(name), ValueKind::Features(defines)) => {
+            Ok(defines.has_feature(name) == eq)
+        }
         _ => Err(ConditionError::new(
             "cannot compare boolean and string values",
             Span::new(left.span.start, right.span.end),
@@ -896,6 +940,12 @@ fn value_from_identifier<'a>(
     span: Span,
     defines: &'a ConditionalDefines,
 ) -> Value<'a> {
+    if name.eq_ignore_ascii_case("feature") {
+        return Value {
+            kind: ValueKind::Features(defines),
+            span,
+        };
+    }
     match defines.get(&name) {
         Some(DefineValue::Bool(value)) => Value::new_bool(*value, span),
         Some(DefineValue::String(value)) => Value::new_str(Cow::Borrowed(value.as_str()), span),
@@ -949,6 +999,21 @@ mod tests {
         assert!(value, "single '=' should behave like equality");
     }
 
+    #[test]
+    fn feature_comparisons_test_membership() {
+        let mut defines = ConditionalDefines::default();
+        defines.set_feature("std", true);
+        defines.set_feature("fast-math", true);
+        let eval = |expr: &str| evaluate_condition_with_diagnostics(expr, 0, &defines);
+        assert_eq!(eval("feature = \"std\"").ok(), Some(true));
+        assert_eq!(
+            eval("feature == \"fast-math\" && feature != \"simd\"").ok(),
+            Some(true)
+        );
+        assert_eq!(eval("feature = \"simd\"").ok(), Some(false));
+        assert!(eval("feature").is_err(), "bare `feature` is not a boolean");
+    }
+
     #[test]
     fn preserves_crate_level_attributes() {
         let defines = ConditionalDefines::default();
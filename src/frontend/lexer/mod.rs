@@ -14,9 +14,10 @@ pub use token::{Token, TokenKind};
 
 mod keyword {
     use super::TokenKind;
+    use serde::Serialize;
 
     /// Reserved keywords recognised by the lexer.
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
     pub enum Keyword {
         Namespace,
         Public,
@@ -79,6 +80,9 @@ mod keyword {
         Await,
         When,
         Testcase,
+        Proptestcase,
+        Fuzzcase,
+        Benchcase,
         Readonly,
         Is,
         As,
@@ -114,6 +118,11 @@ mod keyword {
         pub fn token_kind(self) -> TokenKind {
             TokenKind::Keyword(self)
         }
+
+        /// Source spelling of every reserved and contextual keyword.
+        pub fn spellings() -> impl Iterator<Item = &'static str> {
+            KEYWORDS.iter().map(|(name, _)| *name)
+        }
     }
 
     const KEYWORDS: &[(&str, Keyword)] = &[
@@ -180,6 +189,9 @@ mod keyword {
         ("await", Keyword::Await),
         ("when", Keyword::When),
         ("testcase", Keyword::Testcase),
+        ("proptestcase", Keyword::Proptestcase),
+        ("fuzzcase", Keyword::Fuzzcase),
+        ("benchcase", Keyword::Benchcase),
         ("readonly", Keyword::Readonly),
         ("is", Keyword::Is),
         ("as", Keyword::As),
@@ -204,9 +216,10 @@ mod token {
     use super::keyword::Keyword;
     use super::numeric::NumericLiteral;
     use crate::frontend::literals::{CharLiteral, StringLiteral};
+    use serde::Serialize;
 
     /// Token emitted by the lexer.
-    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
     pub struct Token {
         pub kind: TokenKind,
         pub lexeme: String,
@@ -214,7 +227,7 @@ mod token {
     }
 
     /// Token categories understood by the parser scaffold.
-    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
     pub enum TokenKind {
         Identifier,
         NumberLiteral(NumericLiteral),
@@ -1,8 +1,9 @@
 use super::diagnostics;
 use super::state::Lexer;
 use crate::frontend::diagnostics::Span;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum NumericBase {
     Binary,
     Decimal,
@@ -19,14 +20,14 @@ impl NumericBase {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum NumericLiteralKind {
     Integer,
     Float,
     Decimal,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum NumericLiteralSuffix {
     I8,
     I16,
@@ -71,13 +72,13 @@ impl NumericLiteralSuffix {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct NumericExponent {
     pub sign: Option<char>,
     pub digits: String,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct NumericLiteral {
     pub base: NumericBase,
     pub kind: NumericLiteralKind,
@@ -114,13 +115,13 @@ impl NumericLiteral {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct NumericLiteralError {
     pub span: Span,
     pub kind: NumericLiteralErrorKind,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum NumericLiteralErrorKind {
     MissingDigits,
     InvalidDigit {
@@ -149,7 +150,7 @@ pub enum NumericLiteralErrorKind {
     },
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum SuffixRestriction {
     FractionalLiteral,
     ExponentPresent,
@@ -1,7 +1,8 @@
 use super::escape::{LiteralError, LiteralErrorKind, decode_escape};
+use serde::Serialize;
 
 /// Parsed representation of a character literal.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct CharLiteral {
     pub value: u16,
 }
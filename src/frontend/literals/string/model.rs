@@ -1,5 +1,6 @@
+use serde::Serialize;
 /// Specific flavour of string literal encountered in source.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum StringLiteralKind {
     Regular,
     Verbatim,
@@ -8,28 +9,28 @@ pub enum StringLiteralKind {
 }
 
 /// Parsed string literal with decoded contents.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct StringLiteral {
     pub kind: StringLiteralKind,
     pub contents: StringLiteralContents,
 }
 
 /// Contents of a parsed string literal.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum StringLiteralContents {
     Simple(String),
     Interpolated(Vec<StringSegment>),
 }
 
 /// Segment composing an interpolated string literal.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum StringSegment {
     Text(String),
     Interpolation(InterpolationSegment),
 }
 
 /// Interpolation entry with optional alignment and format specifiers.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct InterpolationSegment {
     pub expression: String,
     pub alignment: Option<i32>,
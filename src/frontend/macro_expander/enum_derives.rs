@@ -0,0 +1,506 @@
+//! Enum implementations of the built-in derives.
+//!
+//! Payload-free (and `@flags`) enums delegate to their underlying integral value. Enums with
+//! payload variants get per-variant `switch` bodies so equality, hashing, ordering and cloning
+//! follow the variant tag first and the payload fields second. Enums cannot be extended, so
+//! every generated member lives in an inherent `impl` block.
+
+use crate::frontend::ast::{
+    BinaryOperator, BindingModifier, EnumDecl, EnumVariant, FunctionDecl, ImplDecl, ImplMember,
+    Item, TypeExpr, Visibility,
+};
+use crate::frontend::diagnostics::Diagnostic;
+use std::fmt::Write;
+
+use super::handlers::{
+    build_clone_impl_with_body, comparison_operator_methods, convert_visibility,
+    default_field_value, derived_method, derived_parameter, generated_body, return_block,
+};
+use super::model::MacroInvocation;
+use super::registry::DeriveOutput;
+
+#[allow(clippy::too_many_lines)]
+pub(super) fn derive_equatable_for_enum(
+    enm: &mut EnumDecl,
+    invocation: &MacroInvocation,
+) -> DeriveOutput {
+    if let Some(output) = reject_generic_enum("Equatable", enm, invocation) {
+        return output;
+    }
+    let visibility = convert_visibility(enm.visibility);
+    let equality = if has_payload(enm) {
+        match generated_body(
+            "Equatable",
+            &enm.name,
+            &payload_equality_body(enm),
+            invocation,
+        ) {
+            Ok(body) => body,
+            Err(output) => return output,
+        }
+    } else {
+        let underlying = underlying_type(enm);
+        return_block(format!("({underlying})left == ({underlying})right"))
+    };
+
+    let operands = || {
+        vec![
+            derived_parameter(
+                "left",
+                TypeExpr::simple(&enm.name),
+                BindingModifier::Value,
+                false,
+            ),
+            derived_parameter(
+                "right",
+                TypeExpr::simple(&enm.name),
+                BindingModifier::Value,
+                false,
+            ),
+        ]
+    };
+    let methods = vec![
+        derived_method(
+            visibility,
+            "op_Equality",
+            operands(),
+            TypeExpr::simple("bool"),
+            equality,
+            Some(BinaryOperator::Equal),
+        ),
+        derived_method(
+            visibility,
+            "op_Inequality",
+            operands(),
+            TypeExpr::simple("bool"),
+            return_block("!op_Equality(left, right)".to_string()),
+            Some(BinaryOperator::NotEqual),
+        ),
+    ];
+    inherent_impl_output(&enm.name, visibility, methods)
+}
+
+pub(super) fn derive_hashable_for_enum(
+    enm: &mut EnumDecl,
+    invocation: &MacroInvocation,
+) -> DeriveOutput {
+    if let Some(output) = reject_generic_enum("Hashable", enm, invocation) {
+        return output;
+    }
+    let visibility = convert_visibility(enm.visibility);
+    let body = if has_payload(enm) {
+        match generated_body("Hashable", &enm.name, &payload_hash_body(enm), invocation) {
+            Ok(body) => body,
+            Err(output) => return output,
+        }
+    } else {
+        return_block(format!("(({})value).GetHashCode()", underlying_type(enm)))
+    };
+    let method = derived_method(
+        visibility,
+        "GetHashCode",
+        vec![receiver(&enm.name)],
+        TypeExpr::simple("int"),
+        body,
+        None,
+    );
+    inherent_impl_output(&enm.name, visibility, vec![method])
+}
+
+pub(super) fn derive_clone_for_enum(
+    enm: &mut EnumDecl,
+    invocation: &MacroInvocation,
+) -> DeriveOutput {
+    if let Some(output) = reject_generic_enum("Clone", enm, invocation) {
+        return output;
+    }
+    let body = if has_payload(enm) {
+        match generated_body("Clone", &enm.name, &payload_clone_body(enm), invocation) {
+            Ok(body) => body,
+            Err(output) => return output,
+        }
+    } else {
+        return_block("this".to_string())
+    };
+    let impl_decl = build_clone_impl_with_body(&enm.name, convert_visibility(enm.visibility), body);
+    let mut output = DeriveOutput::empty();
+    output.new_items.push(Item::Impl(impl_decl));
+    output
+}
+
+pub(super) fn derive_comparable_for_enum(
+    enm: &mut EnumDecl,
+    invocation: &MacroInvocation,
+) -> DeriveOutput {
+    if let Some(output) = reject_generic_enum("Comparable", enm, invocation) {
+        return output;
+    }
+    let visibility = convert_visibility(enm.visibility);
+    let body = if has_payload(enm) {
+        match generated_body(
+            "Comparable",
+            &enm.name,
+            &payload_compare_body(enm),
+            invocation,
+        ) {
+            Ok(body) => body,
+            Err(output) => return output,
+        }
+    } else {
+        let underlying = underlying_type(enm);
+        return_block(format!(
+            "(({underlying})value).CompareTo(({underlying})other)"
+        ))
+    };
+    let mut methods = vec![derived_method(
+        visibility,
+        "CompareTo",
+        vec![
+            receiver(&enm.name),
+            derived_parameter(
+                "other",
+                TypeExpr::simple(&enm.name),
+                BindingModifier::Value,
+                false,
+            ),
+        ],
+        TypeExpr::simple("int"),
+        body,
+        None,
+    )];
+    methods.extend(comparison_operator_methods(&enm.name, visibility));
+    inherent_impl_output(&enm.name, visibility, methods)
+}
+
+pub(super) fn derive_debug_for_enum(
+    enm: &mut EnumDecl,
+    invocation: &MacroInvocation,
+) -> DeriveOutput {
+    if let Some(output) = reject_generic_enum("Debug", enm, invocation) {
+        return output;
+    }
+    let visibility = convert_visibility(enm.visibility);
+    let body = match generated_body("Debug", &enm.name, &debug_body(enm), invocation) {
+        Ok(body) => body,
+        Err(output) => return output,
+    };
+    let method = derived_method(
+        visibility,
+        "ToString",
+        vec![receiver(&enm.name)],
+        TypeExpr::simple("string"),
+        body,
+        None,
+    );
+    inherent_impl_output(&enm.name, visibility, vec![method])
+}
+
+pub(super) fn derive_default_for_enum(
+    enm: &mut EnumDecl,
+    invocation: &MacroInvocation,
+) -> DeriveOutput {
+    if let Some(output) = reject_generic_enum("Default", enm, invocation) {
+        return output;
+    }
+    let Some(first) = enm.variants.first() else {
+        return DeriveOutput::error(Diagnostic::error(
+            "`@derive(Default)` requires at least one enum variant",
+            invocation.span,
+        ));
+    };
+    let visibility = convert_visibility(enm.visibility);
+    let body = match generated_body(
+        "Default",
+        &enm.name,
+        &default_body(&enm.name, first),
+        invocation,
+    ) {
+        Ok(body) => body,
+        Err(output) => return output,
+    };
+    let mut method = derived_method(
+        visibility,
+        "Default",
+        Vec::new(),
+        TypeExpr::simple(&enm.name),
+        body,
+        None,
+    );
+    method.modifiers.push("static".to_string());
+    inherent_impl_output(&enm.name, visibility, vec![method])
+}
+
+fn reject_generic_enum(
+    derive: &str,
+    enm: &EnumDecl,
+    invocation: &MacroInvocation,
+) -> Option<DeriveOutput> {
+    enm.generics
+        .as_ref()
+        .is_some_and(|params| !params.params.is_empty())
+        .then(|| {
+            DeriveOutput::error(Diagnostic::error(
+                format!("`@derive({derive})` does not currently support generic enums"),
+                invocation.span,
+            ))
+        })
+}
+
+fn has_payload(enm: &EnumDecl) -> bool {
+    enm.variants
+        .iter()
+        .any(|variant| !variant.fields.is_empty())
+}
+
+fn underlying_type(enm: &EnumDecl) -> String {
+    enm.underlying_type
+        .as_ref()
+        .map_or_else(|| "int".to_string(), |ty| ty.name.clone())
+}
+
+fn receiver(type_name: &str) -> crate::frontend::ast::Parameter {
+    derived_parameter(
+        "value",
+        TypeExpr::simple(type_name),
+        BindingModifier::In,
+        true,
+    )
+}
+
+fn inherent_impl_output(
+    type_name: &str,
+    visibility: Visibility,
+    methods: Vec<FunctionDecl>,
+) -> DeriveOutput {
+    let mut output = DeriveOutput::empty();
+    output.new_items.push(Item::Impl(ImplDecl {
+        visibility,
+        trait_ref: None,
+        target: TypeExpr::simple(type_name),
+        generics: None,
+        members: methods.into_iter().map(ImplMember::Method).collect(),
+        doc: None,
+        attributes: Vec::new(),
+        span: None,
+    }));
+    output
+}
+
+/// Switch bindings share one scope per `switch`, so every payload binding carries the variant
+/// name to stay unique across cases.
+fn binding_name(prefix: &str, variant: &EnumVariant, field: &str) -> String {
+    format!("__{prefix}_{}_{field}", variant.name)
+}
+
+/// `Enum.Variant { Field: var __prefix_Variant_Field, ... }`, or `_` wildcards without a prefix.
+fn variant_pattern(enum_name: &str, variant: &EnumVariant, prefix: Option<&str>) -> String {
+    if variant.fields.is_empty() {
+        return format!("{enum_name}.{}", variant.name);
+    }
+    let fields: Vec<String> = variant
+        .fields
+        .iter()
+        .map(|field| match prefix {
+            Some(prefix) => format!(
+                "{}: var {}",
+                field.name,
+                binding_name(prefix, variant, &field.name)
+            ),
+            None => format!("{}: _", field.name),
+        })
+        .collect();
+    format!("{enum_name}.{} {{ {} }}", variant.name, fields.join(", "))
+}
+
+fn payload_equality_body(enm: &EnumDecl) -> String {
+    let mut body = String::from("{ switch (left) {");
+    for variant in &enm.variants {
+        let terms: Vec<String> = variant
+            .fields
+            .iter()
+            .map(|field| {
+                format!(
+                    "{} == {}",
+                    binding_name("left", variant, &field.name),
+                    binding_name("right", variant, &field.name)
+                )
+            })
+            .collect();
+        let result = if terms.is_empty() {
+            "true".to_string()
+        } else {
+            terms.join(" && ")
+        };
+        let _ = write!(
+            body,
+            " case {}: switch (right) {{ case {}: return {result}; default: return false; }}",
+            variant_pattern(&enm.name, variant, Some("left")),
+            variant_pattern(&enm.name, variant, Some("right")),
+        );
+    }
+    body.push_str(" default: return false; } }");
+    body
+}
+
+/// Folds the payload fields in order as `h = h * 31 + field.GetHashCode()`, seeded with the
+/// variant's index, so swapping two payload values changes the hash.
+fn payload_hash_body(enm: &EnumDecl) -> String {
+    let mut body = String::from("{ unchecked { switch (value) {");
+    for (index, variant) in enm.variants.iter().enumerate() {
+        let hash = variant
+            .fields
+            .iter()
+            .fold(index.to_string(), |hash, field| {
+                format!(
+                    "({hash}) * 31 + {}.GetHashCode()",
+                    binding_name("value", variant, &field.name)
+                )
+            });
+        let _ = write!(
+            body,
+            " case {}: return {hash};",
+            variant_pattern(&enm.name, variant, Some("value"))
+        );
+    }
+    body.push_str(" default: return 0; } } }");
+    body
+}
+
+fn payload_clone_body(enm: &EnumDecl) -> String {
+    let mut body = String::from("{ switch (this) {");
+    for variant in enm
+        .variants
+        .iter()
+        .filter(|variant| !variant.fields.is_empty())
+    {
+        let assignments: Vec<String> = variant
+            .fields
+            .iter()
+            .map(|field| {
+                format!(
+                    "{} = Std.Clone.Runtime.CloneField({})",
+                    field.name,
+                    binding_name("value", variant, &field.name)
+                )
+            })
+            .collect();
+        let _ = write!(
+            body,
+            " case {}: return new {}.{} {{ {} }};",
+            variant_pattern(&enm.name, variant, Some("value")),
+            enm.name,
+            variant.name,
+            assignments.join(", ")
+        );
+    }
+    body.push_str(" default: return this; } }");
+    body
+}
+
+/// Orders by declaration position first, then lexicographically by payload fields.
+fn payload_compare_body(enm: &EnumDecl) -> String {
+    let mut body = String::from("{");
+    for (subject, index) in [("value", "__valueIndex"), ("other", "__otherIndex")] {
+        let _ = write!(body, " var {index} = 0; switch ({subject}) {{");
+        for (position, variant) in enm.variants.iter().enumerate().skip(1) {
+            let _ = write!(
+                body,
+                " case {}: {index} = {position}; break;",
+                variant_pattern(&enm.name, variant, None)
+            );
+        }
+        body.push_str(" default: break; }");
+    }
+    body.push_str(" if (__valueIndex < __otherIndex) { return -1; }");
+    body.push_str(" if (__valueIndex > __otherIndex) { return 1; }");
+    body.push_str(" switch (value) {");
+    for variant in enm
+        .variants
+        .iter()
+        .filter(|variant| !variant.fields.is_empty())
+    {
+        let mut comparisons = String::new();
+        for field in &variant.fields {
+            let order = binding_name("order", variant, &field.name);
+            let _ = write!(
+                comparisons,
+                " var {order} = {}.CompareTo({}); if ({order} != 0) {{ return {order}; }}",
+                binding_name("value", variant, &field.name),
+                binding_name("other", variant, &field.name)
+            );
+        }
+        let _ = write!(
+            body,
+            " case {}: switch (other) {{ case {}: {{{comparisons} return 0; }} default: return 0; }}",
+            variant_pattern(&enm.name, variant, Some("value")),
+            variant_pattern(&enm.name, variant, Some("other")),
+        );
+    }
+    body.push_str(" default: return 0; } }");
+    body
+}
+
+fn debug_body(enm: &EnumDecl) -> String {
+    let mut body = String::from("{ switch (value) {");
+    for variant in &enm.variants {
+        let rendered = if variant.fields.is_empty() {
+            format!("\"{}.{}\"", enm.name, variant.name)
+        } else {
+            let fields: Vec<String> = variant
+                .fields
+                .iter()
+                .map(|field| {
+                    format!(
+                        "{} = {{{}.ToString()}}",
+                        field.name,
+                        binding_name("value", variant, &field.name)
+                    )
+                })
+                .collect();
+            format!(
+                "$\"{}.{} {{{{ {} }}}}\"",
+                enm.name,
+                variant.name,
+                fields.join(", ")
+            )
+        };
+        let _ = write!(
+            body,
+            " case {}: return {rendered};",
+            variant_pattern(&enm.name, variant, Some("value"))
+        );
+    }
+    if has_payload(enm) {
+        let _ = write!(body, " default: return \"{}\"; }} }}", enm.name);
+    } else {
+        // Flag combinations and out-of-range values fall back to the raw underlying value.
+        let _ = write!(
+            body,
+            " default: var __raw = ({})value; return $\"{}({{__raw.ToString()}})\"; }} }}",
+            underlying_type(enm),
+            enm.name
+        );
+    }
+    body
+}
+
+fn default_body(enum_name: &str, variant: &EnumVariant) -> String {
+    if variant.fields.is_empty() {
+        return format!("{{ return {enum_name}.{}; }}", variant.name);
+    }
+    let mut body = String::from("{");
+    let mut assignments = Vec::new();
+    for field in &variant.fields {
+        let local = format!("__default_{}", field.name);
+        let value = default_field_value(field);
+        let _ = write!(body, " var {local} = {value};");
+        assignments.push(format!("{} = {local}", field.name));
+    }
+    let _ = write!(
+        body,
+        " return new {enum_name}.{} {{ {} }}; }}",
+        variant.name,
+        assignments.join(", ")
+    );
+    body
+}
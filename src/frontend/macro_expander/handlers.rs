@@ -1,12 +1,15 @@
 use crate::frontend::ast::{
-    BindingModifier, Block, ClassDecl, ClassMember, EnumDecl, ExtensionDecl, ExtensionMember,
-    ExtensionMethodDecl, FunctionDecl, ImplDecl, ImplMember, Item, MemberDispatch, OperatorDecl,
-    OperatorKind, Parameter, Signature, Statement, StatementKind, StaticDeclaration,
+    BinaryOperator, BindingModifier, Block, ClassDecl, ClassMember, ExtensionDecl, ExtensionMember,
+    ExtensionMethodDecl, FieldDecl, FunctionDecl, ImplDecl, ImplMember, Item, MemberDispatch,
+    OperatorDecl, OperatorKind, Parameter, Signature, Statement, StatementKind, StaticDeclaration,
     StaticDeclarator, StaticItemDecl, StaticMutability, StructDecl, TypeExpr, VariableDeclaration,
     VariableDeclarator, VariableModifier, Visibility,
 };
 use crate::frontend::diagnostics::Diagnostic;
+use crate::frontend::parser::parse_block_text;
+use std::fmt::Write;
 
+use super::enum_derives;
 use super::model::MacroInvocation;
 use super::registry::{
     AttributeInput, AttributeOutput, AttributeTarget, DeriveInput, DeriveOutput, DeriveTarget,
@@ -16,7 +19,7 @@ pub fn derive_equatable(input: DeriveInput<'_>) -> DeriveOutput {
     match input.target {
         DeriveTarget::Struct(strct) => derive_equatable_for_struct(strct, input.invocation),
         DeriveTarget::Class(class) => derive_equatable_for_class(class, input.invocation),
-        DeriveTarget::Enum(enm) => derive_equatable_for_enum(enm, input.invocation),
+        DeriveTarget::Enum(enm) => enum_derives::derive_equatable_for_enum(enm, input.invocation),
     }
 }
 
@@ -24,13 +27,7 @@ pub fn derive_hashable(input: DeriveInput<'_>) -> DeriveOutput {
     match input.target {
         DeriveTarget::Struct(strct) => derive_hashable_for_struct(strct, input.invocation),
         DeriveTarget::Class(class) => derive_hashable_for_class(class, input.invocation),
-        DeriveTarget::Enum(_enm) => DeriveOutput {
-            new_items: Vec::new(),
-            diagnostics: vec![Diagnostic::error(
-                "`@derive(Hashable)` is not supported on enums yet",
-                input.invocation.span,
-            )],
-        },
+        DeriveTarget::Enum(enm) => enum_derives::derive_hashable_for_enum(enm, input.invocation),
     }
 }
 
@@ -38,13 +35,31 @@ pub fn derive_clone(input: DeriveInput<'_>) -> DeriveOutput {
     match input.target {
         DeriveTarget::Struct(strct) => derive_clone_for_struct(strct, input.invocation),
         DeriveTarget::Class(class) => derive_clone_for_class(class, input.invocation),
-        DeriveTarget::Enum(_enm) => DeriveOutput {
-            new_items: Vec::new(),
-            diagnostics: vec![Diagnostic::error(
-                "`@derive(Clone)` is not supported on enums yet",
-                input.invocation.span,
-            )],
-        },
+        DeriveTarget::Enum(enm) => enum_derives::derive_clone_for_enum(enm, input.invocation),
+    }
+}
+
+pub fn derive_comparable(input: DeriveInput<'_>) -> DeriveOutput {
+    match input.target {
+        DeriveTarget::Struct(strct) => derive_comparable_for_struct(strct, input.invocation),
+        DeriveTarget::Class(class) => derive_comparable_for_class(class, input.invocation),
+        DeriveTarget::Enum(enm) => enum_derives::derive_comparable_for_enum(enm, input.invocation),
+    }
+}
+
+pub fn derive_debug(input: DeriveInput<'_>) -> DeriveOutput {
+    match input.target {
+        DeriveTarget::Struct(strct) => derive_debug_for_struct(strct, input.invocation),
+        DeriveTarget::Class(class) => derive_debug_for_class(class, input.invocation),
+        DeriveTarget::Enum(enm) => enum_derives::derive_debug_for_enum(enm, input.invocation),
+    }
+}
+
+pub fn derive_default(input: DeriveInput<'_>) -> DeriveOutput {
+    match input.target {
+        DeriveTarget::Struct(strct) => derive_default_for_struct(strct, input.invocation),
+        DeriveTarget::Class(class) => derive_default_for_class(class, input.invocation),
+        DeriveTarget::Enum(enm) => enum_derives::derive_default_for_enum(enm, input.invocation),
     }
 }
 
@@ -118,54 +133,32 @@ fn derive_clone_for_class(class: &mut ClassDecl, invocation: &MacroInvocation) -
 
 fn build_clone_impl(type_name: &str, visibility: Visibility, fields: &[String]) -> ImplDecl {
     let initializer = build_clone_expression(type_name, fields);
+    build_clone_impl_with_body(type_name, visibility, return_block(initializer))
+}
+
+pub(super) fn build_clone_impl_with_body(
+    type_name: &str,
+    visibility: Visibility,
+    body: Block,
+) -> ImplDecl {
     ImplDecl {
         visibility,
         trait_ref: Some(TypeExpr::simple("Std.Clone")),
         target: TypeExpr::simple(type_name),
         generics: None,
-        members: vec![ImplMember::Method(FunctionDecl {
+        members: vec![ImplMember::Method(derived_method(
             visibility,
-            name: "Clone".into(),
-            name_span: None,
-            signature: Signature {
-                parameters: vec![Parameter {
-                    binding: BindingModifier::In,
-                    binding_nullable: false,
-                    name: "this".into(),
-                    name_span: None,
-                    ty: TypeExpr::self_type(),
-                    attributes: Vec::new(),
-                    di_inject: None,
-                    default: None,
-                    default_span: None,
-                    lends: None,
-                    is_extension_this: true,
-                }],
-                return_type: TypeExpr::self_type(),
-                lends_to_return: None,
-                variadic: false,
-                throws: None,
-            },
-            body: Some(Block {
-                statements: vec![make_return_statement(initializer)],
-                span: None,
-            }),
-            is_async: false,
-            is_constexpr: false,
-            doc: None,
-            modifiers: Vec::new(),
-            is_unsafe: false,
-            attributes: Vec::new(),
-            is_extern: false,
-            extern_abi: None,
-            extern_options: None,
-            link_name: None,
-            link_library: None,
-            operator: None,
-            generics: None,
-            vectorize_hint: None,
-            dispatch: MemberDispatch::default(),
-        })],
+            "Clone",
+            vec![derived_parameter(
+                "this",
+                TypeExpr::self_type(),
+                BindingModifier::In,
+                true,
+            )],
+            TypeExpr::self_type(),
+            body,
+            None,
+        ))],
         doc: None,
         attributes: Vec::new(),
         span: None,
@@ -418,16 +411,6 @@ fn derive_equatable_for_class(class: &mut ClassDecl, invocation: &MacroInvocatio
     output
 }
 
-fn derive_equatable_for_enum(_enm: &mut EnumDecl, invocation: &MacroInvocation) -> DeriveOutput {
-    DeriveOutput {
-        new_items: Vec::new(),
-        diagnostics: vec![Diagnostic::error(
-            "`@derive(Equatable)` is not supported on enums yet",
-            invocation.span,
-        )],
-    }
-}
-
 fn derive_hashable_for_struct(
     strct: &mut StructDecl,
     invocation: &MacroInvocation,
@@ -492,6 +475,224 @@ fn derive_hashable_for_class(class: &mut ClassDecl, invocation: &MacroInvocation
     output
 }
 
+fn derive_comparable_for_struct(
+    strct: &mut StructDecl,
+    invocation: &MacroInvocation,
+) -> DeriveOutput {
+    if strct
+        .generics
+        .as_ref()
+        .is_some_and(|params| !params.params.is_empty())
+    {
+        return DeriveOutput::error(Diagnostic::error(
+            "`@derive(Comparable)` does not currently support generic structs",
+            invocation.span,
+        ));
+    }
+
+    let fields = collect_struct_fields(strct);
+    let visibility = convert_visibility(strct.visibility);
+    match build_comparable_extension(&strct.name, visibility, &fields, invocation) {
+        Ok(extension) => {
+            let mut output = DeriveOutput::empty();
+            output.new_items.push(Item::Extension(extension));
+            output
+        }
+        Err(output) => output,
+    }
+}
+
+fn derive_comparable_for_class(
+    class: &mut ClassDecl,
+    invocation: &MacroInvocation,
+) -> DeriveOutput {
+    if class
+        .generics
+        .as_ref()
+        .is_some_and(|params| !params.params.is_empty())
+    {
+        return DeriveOutput::error(Diagnostic::error(
+            "`@derive(Comparable)` does not currently support generic classes",
+            invocation.span,
+        ));
+    }
+
+    if !class.bases.is_empty() {
+        return DeriveOutput::error(Diagnostic::error(
+            "`@derive(Comparable)` does not yet support classes with base types",
+            invocation.span,
+        ));
+    }
+
+    let accessors = collect_class_accessors(class);
+    if accessors.is_empty() {
+        return DeriveOutput::error(Diagnostic::error(
+            "`@derive(Comparable)` requires at least one public field or property",
+            invocation.span,
+        ));
+    }
+
+    let visibility = convert_visibility(class.visibility);
+    match build_comparable_extension(&class.name, visibility, &accessors, invocation) {
+        Ok(extension) => {
+            let mut output = DeriveOutput::empty();
+            output.new_items.push(Item::Extension(extension));
+            output
+        }
+        Err(output) => output,
+    }
+}
+
+fn derive_debug_for_struct(strct: &mut StructDecl, invocation: &MacroInvocation) -> DeriveOutput {
+    let mut output = DeriveOutput::empty();
+    if strct
+        .generics
+        .as_ref()
+        .is_some_and(|params| !params.params.is_empty())
+    {
+        output.diagnostics.push(Diagnostic::error(
+            "`@derive(Debug)` does not currently support generic structs",
+            invocation.span,
+        ));
+        return output;
+    }
+
+    let fields = collect_struct_fields(strct);
+    let extension =
+        build_debug_extension(&strct.name, convert_visibility(strct.visibility), &fields);
+    output.new_items.push(Item::Extension(extension));
+    output
+}
+
+fn derive_debug_for_class(class: &mut ClassDecl, invocation: &MacroInvocation) -> DeriveOutput {
+    let mut output = DeriveOutput::empty();
+    if class
+        .generics
+        .as_ref()
+        .is_some_and(|params| !params.params.is_empty())
+    {
+        output.diagnostics.push(Diagnostic::error(
+            "`@derive(Debug)` does not currently support generic classes",
+            invocation.span,
+        ));
+        return output;
+    }
+
+    let accessors = collect_class_accessors(class);
+    let extension = build_debug_extension(
+        &class.name,
+        convert_visibility(class.visibility),
+        &accessors,
+    );
+    output.new_items.push(Item::Extension(extension));
+    output
+}
+
+/// Struct defaults honour declared field initializers and fall back to `default(T)`.
+#[allow(clippy::too_many_lines)]
+fn derive_default_for_struct(strct: &mut StructDecl, invocation: &MacroInvocation) -> DeriveOutput {
+    if strct
+        .generics
+        .as_ref()
+        .is_some_and(|params| !params.params.is_empty())
+    {
+        return DeriveOutput::error(Diagnostic::error(
+            "`@derive(Default)` does not currently support generic structs",
+            invocation.span,
+        ));
+    }
+
+    if !strct.constructors.is_empty()
+        && !strct
+            .constructors
+            .iter()
+            .any(|ctor| ctor.parameters.is_empty())
+    {
+        return DeriveOutput::error(Diagnostic::error(
+            "`@derive(Default)` requires a parameterless constructor",
+            invocation.span,
+        ));
+    }
+
+    let mut statements = Vec::new();
+    let mut assignments = Vec::new();
+    for field in strct.fields.iter().filter(|field| !field.is_static) {
+        let value = default_field_value(field);
+        statements.push(format!("var __default_{} = {value};", field.name));
+        assignments.push(format!("{0} = __default_{0}", field.name));
+    }
+    if assignments.is_empty() {
+        statements.push(format!("return new {}();", strct.name));
+    } else {
+        statements.push(format!(
+            "return new {} {{ {} }};",
+            strct.name,
+            assignments.join(", ")
+        ));
+    }
+    let source = format!("{{ {} }}", statements.join(" "));
+    let body = match generated_body("Default", &strct.name, &source, invocation) {
+        Ok(body) => body,
+        Err(output) => return output,
+    };
+
+    let mut output = DeriveOutput::empty();
+    output
+        .new_items
+        .push(Item::Extension(build_default_extension(
+            &strct.name,
+            convert_visibility(strct.visibility),
+            body,
+        )));
+    output
+}
+
+/// Class defaults run the parameterless constructor, so field initializers still apply.
+fn derive_default_for_class(class: &mut ClassDecl, invocation: &MacroInvocation) -> DeriveOutput {
+    if class
+        .generics
+        .as_ref()
+        .is_some_and(|params| !params.params.is_empty())
+    {
+        return DeriveOutput::error(Diagnostic::error(
+            "`@derive(Default)` does not currently support generic classes",
+            invocation.span,
+        ));
+    }
+
+    if class.is_abstract || class.is_static {
+        return DeriveOutput::error(Diagnostic::error(
+            "`@derive(Default)` cannot be applied to abstract or static classes",
+            invocation.span,
+        ));
+    }
+
+    let constructors: Vec<_> = class
+        .members
+        .iter()
+        .filter_map(|member| match member {
+            ClassMember::Constructor(ctor) => Some(ctor),
+            _ => None,
+        })
+        .collect();
+    if !constructors.is_empty() && !constructors.iter().any(|ctor| ctor.parameters.is_empty()) {
+        return DeriveOutput::error(Diagnostic::error(
+            "`@derive(Default)` requires a parameterless constructor",
+            invocation.span,
+        ));
+    }
+
+    let mut output = DeriveOutput::empty();
+    output
+        .new_items
+        .push(Item::Extension(build_default_extension(
+            &class.name,
+            convert_visibility(class.visibility),
+            return_block(format!("new {}()", class.name)),
+        )));
+    output
+}
+
 fn collect_struct_fields(strct: &StructDecl) -> Vec<String> {
     strct
         .fields
@@ -530,150 +731,27 @@ pub(super) fn build_equatable_extension(
     }
     let equality_expression = equality_terms.join(" && ");
 
-    let equality_method = FunctionDecl {
+    let equality_method = derived_method(
         visibility,
-        name: "op_Equality".into(),
-        name_span: None,
-        signature: Signature {
-            parameters: vec![
-                Parameter {
-                    binding: BindingModifier::Value,
-                    binding_nullable: false,
-                    name: "left".into(),
-                    name_span: None,
-                    ty: TypeExpr::simple(type_name),
-                    attributes: Vec::new(),
-                    di_inject: None,
-                    default: None,
-                    default_span: None,
-                    lends: None,
-                    is_extension_this: false,
-                },
-                Parameter {
-                    binding: BindingModifier::Value,
-                    binding_nullable: false,
-                    name: "right".into(),
-                    name_span: None,
-                    ty: TypeExpr::simple(type_name),
-                    attributes: Vec::new(),
-                    di_inject: None,
-                    default: None,
-                    default_span: None,
-                    lends: None,
-                    is_extension_this: false,
-                },
-            ],
-            return_type: TypeExpr::simple("bool"),
-            lends_to_return: None,
-            variadic: false,
-            throws: None,
-        },
-        body: Some(Block {
-            statements: vec![make_return_statement(equality_expression)],
-            span: None,
-        }),
-        is_async: false,
-        is_constexpr: false,
-        doc: None,
-        modifiers: Vec::new(),
-        is_unsafe: false,
-        attributes: Vec::new(),
-        is_extern: false,
-        extern_abi: None,
-        extern_options: None,
-        link_name: None,
-        link_library: None,
-        operator: Some(OperatorDecl {
-            kind: OperatorKind::Binary(crate::frontend::ast::BinaryOperator::Equal),
-            span: None,
-        }),
-        generics: None,
-        vectorize_hint: None,
-        dispatch: MemberDispatch::default(),
-    };
-
-    let inequality_method = FunctionDecl {
+        "op_Equality",
+        binary_operands(type_name),
+        TypeExpr::simple("bool"),
+        return_block(equality_expression),
+        Some(BinaryOperator::Equal),
+    );
+    let inequality_method = derived_method(
         visibility,
-        name: "op_Inequality".into(),
-        name_span: None,
-        signature: Signature {
-            parameters: vec![
-                Parameter {
-                    binding: BindingModifier::Value,
-                    binding_nullable: false,
-                    name: "left".into(),
-                    name_span: None,
-                    ty: TypeExpr::simple(type_name),
-                    attributes: Vec::new(),
-                    di_inject: None,
-                    default: None,
-                    default_span: None,
-                    lends: None,
-                    is_extension_this: false,
-                },
-                Parameter {
-                    binding: BindingModifier::Value,
-                    binding_nullable: false,
-                    name: "right".into(),
-                    name_span: None,
-                    ty: TypeExpr::simple(type_name),
-                    attributes: Vec::new(),
-                    di_inject: None,
-                    default: None,
-                    default_span: None,
-                    lends: None,
-                    is_extension_this: false,
-                },
-            ],
-            return_type: TypeExpr::simple("bool"),
-            lends_to_return: None,
-            variadic: false,
-            throws: None,
-        },
-        body: Some(Block {
-            statements: vec![make_return_statement(
-                "!op_Equality(left, right)".to_string(),
-            )],
-            span: None,
-        }),
-        is_async: false,
-        is_constexpr: false,
-        doc: None,
-        modifiers: Vec::new(),
-        is_unsafe: false,
-        attributes: Vec::new(),
-        is_extern: false,
-        extern_abi: None,
-        extern_options: None,
-        link_name: None,
-        link_library: None,
-        operator: Some(OperatorDecl {
-            kind: OperatorKind::Binary(crate::frontend::ast::BinaryOperator::NotEqual),
-            span: None,
-        }),
-        generics: None,
-        vectorize_hint: None,
-        dispatch: MemberDispatch::default(),
-    };
-
-    ExtensionDecl {
+        "op_Inequality",
+        binary_operands(type_name),
+        TypeExpr::simple("bool"),
+        return_block("!op_Equality(left, right)".to_string()),
+        Some(BinaryOperator::NotEqual),
+    );
+    extension_with_methods(
+        type_name,
         visibility,
-        target: extension_target(type_name),
-        generics: None,
-        members: vec![
-            ExtensionMember::Method(ExtensionMethodDecl {
-                function: equality_method,
-                is_default: false,
-            }),
-            ExtensionMember::Method(ExtensionMethodDecl {
-                function: inequality_method,
-                is_default: false,
-            }),
-        ],
-        doc: None,
-        attributes: Vec::new(),
-        conditions: Vec::new(),
-    }
+        vec![equality_method, inequality_method],
+    )
 }
 
 pub(super) fn build_hashable_extension(
@@ -690,37 +768,221 @@ pub(super) fn build_hashable_extension(
     }
     let body_expression = terms.join(" ^ ");
 
-    let method = FunctionDecl {
+    let method = derived_method(
+        visibility,
+        "GetHashCode",
+        vec![extension_receiver(type_name)],
+        TypeExpr::simple("int"),
+        return_block(body_expression),
+        None,
+    );
+    extension_with_methods(type_name, visibility, vec![method])
+}
+
+/// `CompareTo` walks the fields in declaration order and returns the first non-zero ordering.
+fn build_comparable_extension(
+    type_name: &str,
+    visibility: Visibility,
+    fields: &[String],
+    invocation: &MacroInvocation,
+) -> Result<ExtensionDecl, DeriveOutput> {
+    let mut source = String::from("{");
+    for field in fields {
+        let _ = write!(
+            source,
+            " var __order_{field} = value.{field}.CompareTo(other.{field}); \
+             if (__order_{field} != 0) {{ return __order_{field}; }}"
+        );
+    }
+    source.push_str(" return 0; }");
+    let body = generated_body("Comparable", type_name, &source, invocation)?;
+
+    let mut methods = vec![derived_method(
         visibility,
-        name: "GetHashCode".into(),
+        "CompareTo",
+        vec![
+            extension_receiver(type_name),
+            derived_parameter(
+                "other",
+                TypeExpr::simple(type_name),
+                BindingModifier::Value,
+                false,
+            ),
+        ],
+        TypeExpr::simple("int"),
+        body,
+        None,
+    )];
+    methods.extend(comparison_operator_methods(type_name, visibility));
+    Ok(extension_with_methods(type_name, visibility, methods))
+}
+
+/// The four relational operators, each delegating to `CompareTo`.
+pub(super) fn comparison_operator_methods(
+    type_name: &str,
+    visibility: Visibility,
+) -> Vec<FunctionDecl> {
+    [
+        ("op_LessThan", BinaryOperator::LessThan, "<"),
+        ("op_GreaterThan", BinaryOperator::GreaterThan, ">"),
+        ("op_LessThanOrEqual", BinaryOperator::LessThanOrEqual, "<="),
+        (
+            "op_GreaterThanOrEqual",
+            BinaryOperator::GreaterThanOrEqual,
+            ">=",
+        ),
+    ]
+    .into_iter()
+    .map(|(name, operator, symbol)| {
+        derived_method(
+            visibility,
+            name,
+            binary_operands(type_name),
+            TypeExpr::simple("bool"),
+            return_block(format!("left.CompareTo(right) {symbol} 0")),
+            Some(operator),
+        )
+    })
+    .collect()
+}
+
+fn build_debug_extension(
+    type_name: &str,
+    visibility: Visibility,
+    fields: &[String],
+) -> ExtensionDecl {
+    let rendered = if fields.is_empty() {
+        format!("\"{type_name} {{ }}\"")
+    } else {
+        let fields: Vec<String> = fields
+            .iter()
+            .map(|field| format!("{field} = {{value.{field}.ToString()}}"))
+            .collect();
+        format!("$\"{type_name} {{{{ {} }}}}\"", fields.join(", "))
+    };
+    let method = derived_method(
+        visibility,
+        "ToString",
+        vec![extension_receiver(type_name)],
+        TypeExpr::simple("string"),
+        return_block(rendered),
+        None,
+    );
+    extension_with_methods(type_name, visibility, vec![method])
+}
+
+fn build_default_extension(type_name: &str, visibility: Visibility, body: Block) -> ExtensionDecl {
+    let mut method = derived_method(
+        visibility,
+        "Default",
+        Vec::new(),
+        TypeExpr::simple(type_name),
+        body,
+        None,
+    );
+    method.modifiers.push("static".to_string());
+    extension_with_methods(type_name, visibility, vec![method])
+}
+
+fn extension_with_methods(
+    type_name: &str,
+    visibility: Visibility,
+    methods: Vec<FunctionDecl>,
+) -> ExtensionDecl {
+    ExtensionDecl {
+        visibility,
+        target: extension_target(type_name),
+        generics: None,
+        members: methods
+            .into_iter()
+            .map(|function| {
+                ExtensionMember::Method(ExtensionMethodDecl {
+                    function,
+                    is_default: false,
+                })
+            })
+            .collect(),
+        doc: None,
+        attributes: Vec::new(),
+        conditions: Vec::new(),
+    }
+}
+
+fn binary_operands(type_name: &str) -> Vec<Parameter> {
+    ["left", "right"]
+        .into_iter()
+        .map(|name| {
+            derived_parameter(
+                name,
+                TypeExpr::simple(type_name),
+                BindingModifier::Value,
+                false,
+            )
+        })
+        .collect()
+}
+
+fn extension_receiver(type_name: &str) -> Parameter {
+    derived_parameter(
+        "value",
+        TypeExpr::simple(type_name),
+        BindingModifier::In,
+        true,
+    )
+}
+
+pub(super) fn derived_parameter(
+    name: &str,
+    ty: TypeExpr,
+    binding: BindingModifier,
+    is_extension_this: bool,
+) -> Parameter {
+    Parameter {
+        binding,
+        binding_nullable: false,
+        name: name.into(),
+        name_span: None,
+        ty,
+        attributes: Vec::new(),
+        di_inject: None,
+        default: None,
+        default_span: None,
+        lends: None,
+        is_extension_this,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn derived_method(
+    visibility: Visibility,
+    name: &str,
+    parameters: Vec<Parameter>,
+    return_type: TypeExpr,
+    body: Block,
+    operator: Option<BinaryOperator>,
+) -> FunctionDecl {
+    // Operators are static members; without the modifier lowering would add a `self` receiver.
+    let modifiers = if operator.is_some() {
+        vec!["static".to_string()]
+    } else {
+        Vec::new()
+    };
+    FunctionDecl {
+        visibility,
+        name: name.into(),
         name_span: None,
         signature: Signature {
-            parameters: vec![Parameter {
-                binding: BindingModifier::In,
-                binding_nullable: false,
-                name: "value".into(),
-                name_span: None,
-                ty: TypeExpr::simple(type_name),
-                attributes: Vec::new(),
-                di_inject: None,
-                default: None,
-                default_span: None,
-                lends: None,
-                is_extension_this: true,
-            }],
-            return_type: TypeExpr::simple("int"),
+            parameters,
+            return_type,
             lends_to_return: None,
             variadic: false,
             throws: None,
         },
-        body: Some(Block {
-            statements: vec![make_return_statement(body_expression)],
-            span: None,
-        }),
+        body: Some(body),
         is_async: false,
         is_constexpr: false,
         doc: None,
-        modifiers: Vec::new(),
+        modifiers,
         is_unsafe: false,
         attributes: Vec::new(),
         is_extern: false,
@@ -728,23 +990,51 @@ pub(super) fn build_hashable_extension(
         extern_options: None,
         link_name: None,
         link_library: None,
-        operator: None,
+        operator: operator.map(|operator| OperatorDecl {
+            kind: OperatorKind::Binary(operator),
+            span: None,
+        }),
         generics: None,
         vectorize_hint: None,
         dispatch: MemberDispatch::default(),
-    };
+    }
+}
 
-    ExtensionDecl {
-        visibility,
-        target: extension_target(type_name),
-        generics: None,
-        members: vec![ExtensionMember::Method(ExtensionMethodDecl {
-            function: method,
-            is_default: false,
-        })],
-        doc: None,
-        attributes: Vec::new(),
-        conditions: Vec::new(),
+/// Parses a generated multi-statement body; a failure here is a generator bug, so it is
+/// reported against the derive invocation rather than silently dropped.
+pub(super) fn generated_body(
+    derive: &str,
+    type_name: &str,
+    source: &str,
+    invocation: &MacroInvocation,
+) -> Result<Block, DeriveOutput> {
+    parse_block_text(source).map_err(|error| {
+        let detail = error
+            .diagnostics()
+            .first()
+            .map_or_else(String::new, |diag| format!(": {}", diag.message));
+        DeriveOutput::error(Diagnostic::error(
+            format!("`@derive({derive})` generated an invalid body for `{type_name}`{detail}"),
+            invocation.span,
+        ))
+    })
+}
+
+/// The declared initializer, `""` for strings (which have no `default`), else `default(T)`.
+pub(super) fn default_field_value(field: &FieldDecl) -> String {
+    if let Some(initializer) = field.initializer.as_ref() {
+        return initializer.text.clone();
+    }
+    match field.ty.name.as_str() {
+        "string" | "String" | "Std.String" | "str" => "\"\"".to_string(),
+        name => format!("default({name})"),
+    }
+}
+
+pub(super) fn return_block(expr: String) -> Block {
+    Block {
+        statements: vec![make_return_statement(expr)],
+        span: None,
     }
 }
 
@@ -761,7 +1051,7 @@ fn make_return_statement(expr: String) -> Statement {
     )
 }
 
-fn convert_visibility(visibility: Visibility) -> Visibility {
+pub(super) fn convert_visibility(visibility: Visibility) -> Visibility {
     visibility
 }
 
@@ -835,3 +1125,31 @@ pub(crate) fn make_return_statement_block(expr: &str) -> Block {
         span: None,
     }
 }
+
+#[cfg(test)]
+pub(crate) fn enum_with_variants(
+    name: &str,
+    variants: &[(&str, &[(&str, &str)])],
+) -> crate::frontend::ast::EnumDecl {
+    crate::frontend::ast::EnumDecl {
+        visibility: Visibility::Public,
+        name: name.into(),
+        underlying_type: None,
+        variants: variants
+            .iter()
+            .map(|(variant, fields)| crate::frontend::ast::EnumVariant {
+                name: (*variant).into(),
+                fields: struct_with_fields(variant, fields).fields,
+                discriminant: None,
+                doc: None,
+            })
+            .collect(),
+        thread_safe_override: None,
+        shareable_override: None,
+        copy_override: None,
+        is_flags: false,
+        doc: None,
+        generics: None,
+        attributes: Vec::new(),
+    }
+}
@@ -2,6 +2,7 @@ mod cache;
 mod collector;
 mod diagnostics;
 mod engine;
+mod enum_derives;
 mod handlers;
 mod model;
 mod origin;
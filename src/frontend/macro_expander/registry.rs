@@ -28,6 +28,10 @@ impl MacroRegistry {
         registry.register_derive("Clone", handlers::derive_clone);
         registry.register_derive("Equatable", handlers::derive_equatable);
         registry.register_derive("Hashable", handlers::derive_hashable);
+        registry.register_derive("Debug", handlers::derive_debug);
+        registry.register_derive("Comparable", handlers::derive_comparable);
+        registry.register_derive("Ord", handlers::derive_comparable);
+        registry.register_derive("Default", handlers::derive_default);
         registry.register_attribute("memoize", handlers::memoize_attribute);
         for name in [
             "allow",
@@ -87,6 +91,14 @@ impl DeriveOutput {
             diagnostics: Vec::new(),
         }
     }
+
+    #[must_use]
+    pub fn error(diagnostic: Diagnostic) -> Self {
+        Self {
+            new_items: Vec::new(),
+            diagnostics: vec![diagnostic],
+        }
+    }
 }
 
 pub struct AttributeInput<'i> {
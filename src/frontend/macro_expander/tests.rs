@@ -2,7 +2,8 @@ use super::registry::{AttributeInput, AttributeOutput, AttributeTarget};
 use super::{MacroRegistry, handlers};
 use crate::frontend::ast::expressions::StatementKind;
 use crate::frontend::ast::{
-    ExtensionMember, FunctionDecl, Item, MemberDispatch, Module, Signature, TypeExpr, Visibility,
+    ExtensionMember, FunctionDecl, ImplMember, Item, MemberDispatch, Module, Signature, TypeExpr,
+    Visibility,
 };
 use crate::frontend::diagnostics::Span;
 use crate::frontend::lexer::{Token, TokenKind};
@@ -104,13 +105,228 @@ fn derive_clone_on_struct_generates_impl() {
     );
 }
 
+fn module_with_enum(name: &str, variants: &[(&str, &[(&str, &str)])], derive: &str) -> Module {
+    let mut module = Module::new(Some("Geometry".into()));
+    let mut enm = handlers::enum_with_variants(name, variants);
+    enm.attributes.push(macro_attribute("derive", &[derive]));
+    module.push_item(Item::Enum(enm));
+    module
+}
+
+fn generated_method_names(module: &Module) -> Vec<String> {
+    module
+        .items
+        .iter()
+        .flat_map(|item| match item {
+            Item::Impl(impl_decl) => impl_decl
+                .members
+                .iter()
+                .filter_map(|member| match member {
+                    ImplMember::Method(method) => Some(method.name.clone()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+            Item::Extension(ext) => ext
+                .members
+                .iter()
+                .map(|ExtensionMember::Method(method)| method.function.name.clone())
+                .collect(),
+            _ => Vec::new(),
+        })
+        .collect()
+}
+
+#[test]
+fn derive_equatable_on_payload_enum_generates_inherent_switch() {
+    let mut module = module_with_enum(
+        "Shape",
+        &[("Empty", &[]), ("Circle", &[("Radius", "int")])],
+        "Equatable",
+    );
+    let result = expand_module(&mut module, &MacroRegistry::with_builtins());
+    assert!(
+        result.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        result.diagnostics
+    );
+    let Some(Item::Impl(impl_decl)) = module.items.get(1) else {
+        panic!("expected an inherent impl, found {:?}", module.items.get(1));
+    };
+    assert!(impl_decl.trait_ref.is_none(), "enums cannot be extended");
+    let ImplMember::Method(equality) = &impl_decl.members[0] else {
+        panic!("expected op_Equality method");
+    };
+    assert_eq!(equality.name, "op_Equality");
+    assert!(
+        equality
+            .modifiers
+            .iter()
+            .any(|modifier| modifier == "static")
+    );
+    let body = equality.body.as_ref().expect("op_Equality body");
+    assert!(
+        matches!(body.statements[0].kind, StatementKind::Switch(_)),
+        "payload enums compare per variant"
+    );
+}
+
+#[test]
+fn derive_hashable_and_clone_on_fieldless_enum_use_the_underlying_value() {
+    for derive in ["Hashable", "Clone"] {
+        let mut module = module_with_enum("Color", &[("Red", &[]), ("Green", &[])], derive);
+        let result = expand_module(&mut module, &MacroRegistry::with_builtins());
+        assert!(
+            result.diagnostics.is_empty(),
+            "unexpected diagnostics for {derive}: {:?}",
+            result.diagnostics
+        );
+        let Some(Item::Impl(impl_decl)) = module.items.get(1) else {
+            panic!("expected {derive} to generate an impl");
+        };
+        let ImplMember::Method(method) = &impl_decl.members[0] else {
+            panic!("expected a generated method");
+        };
+        let body = method.body.as_ref().expect("generated body");
+        let StatementKind::Return {
+            expression: Some(expr),
+        } = &body.statements[0].kind
+        else {
+            panic!("expected a single return statement");
+        };
+        let expected = if derive == "Hashable" {
+            "((int)value).GetHashCode()"
+        } else {
+            "this"
+        };
+        assert_eq!(expr.text, expected);
+    }
+}
+
+#[test]
+fn derive_on_generic_enum_reports_error() {
+    let mut module = module_with_enum("Option", &[("None", &[])], "Hashable");
+    if let Item::Enum(ref mut enm) = module.items[0] {
+        enm.generics = Some(crate::frontend::ast::GenericParams::new(
+            None,
+            vec![crate::frontend::ast::GenericParam::type_param("T", None)],
+        ));
+    }
+    let result = expand_module(&mut module, &MacroRegistry::with_builtins());
+    assert!(
+        result.diagnostics.iter().any(|diag| diag
+            .message
+            .contains("does not currently support generic enums")),
+        "expected generic enum diagnostic: {:?}",
+        result.diagnostics
+    );
+}
+
+#[test]
+fn derive_comparable_and_ord_generate_compare_to_and_relational_operators() {
+    for derive in ["Comparable", "Ord"] {
+        let mut module = module_with_struct("Point", &[("X", "int"), ("Y", "int")]);
+        if let Item::Struct(ref mut point) = module.items[0] {
+            point.attributes.push(macro_attribute("derive", &[derive]));
+        }
+        let result = expand_module(&mut module, &MacroRegistry::with_builtins());
+        assert!(
+            result.diagnostics.is_empty(),
+            "unexpected diagnostics for {derive}: {:?}",
+            result.diagnostics
+        );
+        assert_eq!(
+            generated_method_names(&module),
+            [
+                "CompareTo",
+                "op_LessThan",
+                "op_GreaterThan",
+                "op_LessThanOrEqual",
+                "op_GreaterThanOrEqual",
+            ]
+        );
+    }
+}
+
+#[test]
+fn derive_debug_on_struct_formats_each_field() {
+    let mut module = module_with_struct("Point", &[("X", "int"), ("Y", "int")]);
+    if let Item::Struct(ref mut point) = module.items[0] {
+        point.attributes.push(macro_attribute("derive", &["Debug"]));
+    }
+    let result = expand_module(&mut module, &MacroRegistry::with_builtins());
+    assert!(
+        result.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        result.diagnostics
+    );
+    let Some(Item::Extension(ext)) = module.items.get(1) else {
+        panic!("expected Debug extension");
+    };
+    let ExtensionMember::Method(method) = &ext.members[0];
+    assert_eq!(method.function.name, "ToString");
+    let body = method.function.body.as_ref().expect("ToString body");
+    let StatementKind::Return {
+        expression: Some(expr),
+    } = &body.statements[0].kind
+    else {
+        panic!("expected a single return statement");
+    };
+    assert_eq!(
+        expr.text,
+        "$\"Point {{ X = {value.X.ToString()}, Y = {value.Y.ToString()} }}\""
+    );
+}
+
+#[test]
+fn derive_default_generates_static_factory() {
+    let mut module = module_with_struct("Point", &[("X", "int"), ("Label", "string")]);
+    if let Item::Struct(ref mut point) = module.items[0] {
+        point
+            .attributes
+            .push(macro_attribute("derive", &["Default"]));
+    }
+    let result = expand_module(&mut module, &MacroRegistry::with_builtins());
+    assert!(
+        result.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        result.diagnostics
+    );
+    let Some(Item::Extension(ext)) = module.items.get(1) else {
+        panic!("expected Default extension");
+    };
+    let ExtensionMember::Method(method) = &ext.members[0];
+    assert_eq!(method.function.name, "Default");
+    assert!(method.function.signature.parameters.is_empty());
+    assert!(
+        method
+            .function
+            .modifiers
+            .iter()
+            .any(|modifier| modifier == "static")
+    );
+}
+
+#[test]
+fn derive_default_on_empty_enum_reports_error() {
+    let mut module = module_with_enum("Never", &[], "Default");
+    let result = expand_module(&mut module, &MacroRegistry::with_builtins());
+    assert!(
+        result
+            .diagnostics
+            .iter()
+            .any(|diag| diag.message.contains("requires at least one enum variant")),
+        "expected empty enum diagnostic: {:?}",
+        result.diagnostics
+    );
+}
+
 #[test]
 fn unknown_derive_reports_error() {
     let mut module = module_with_struct("Point", &[("X", "int")]);
     if let Item::Struct(ref mut point) = module.items[0] {
         point
             .attributes
-            .push(macro_attribute("derive", &["Comparable"]));
+            .push(macro_attribute("derive", &["Serializable"]));
     }
     let registry = MacroRegistry::with_builtins();
     let result = expand_module(&mut module, &registry);
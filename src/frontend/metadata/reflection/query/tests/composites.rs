@@ -73,6 +73,7 @@ fn captures_class_trait_impl_and_extension_descriptors() {
         doc: doc("ctor"),
         span: None,
         attributes: Vec::new(),
+        name_span: None,
         di_inject: None,
     };
     let mut method = FunctionDecl {
@@ -12,5 +12,6 @@ pub mod local_functions;
 pub mod macro_expander;
 pub mod metadata;
 pub mod parser;
+pub mod test_expansion;
 pub mod type_alias;
 pub mod type_utils;
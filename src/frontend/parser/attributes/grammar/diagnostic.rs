@@ -1,5 +1,5 @@
-//! Diagnostic/marker attributes such as thread-safety toggles, copyability, and
-//! fallibility markers.
+//! Diagnostic/marker attributes such as thread-safety toggles, copyability,
+//! fallibility markers, and test hook/fixture markers.
 
 use super::super::CollectedAttributes;
 use super::*;
@@ -124,6 +124,17 @@ pub(super) fn handle_diagnostic_attribute(
             attrs.builtin.record_fallible(Some(span));
             true
         }
+        // Test hooks and fixtures are resolved by `frontend::test_expansion`.
+        "setup" | "teardown" | "fixture" => {
+            if parser.consume_punctuation('(') {
+                parser.push_error(
+                    format!("`@{lowered}` attribute does not accept arguments"),
+                    Some(span),
+                );
+                parser.skip_balanced('(', ')');
+            }
+            true
+        }
         _ => false,
     }
 }
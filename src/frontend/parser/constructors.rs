@@ -103,6 +103,7 @@ parser_impl! {
             doc,
             span,
             attributes: Vec::new(),
+            name_span: init_span,
             di_inject: None,
         })
     }
@@ -204,6 +205,7 @@ parser_impl! {
             doc,
             span,
             attributes: Vec::new(),
+            name_span,
             di_inject: None,
         })
     }
@@ -1,5 +1,5 @@
 use super::super::*;
-use crate::frontend::ast::{Attribute, ImplDecl, TestCaseDecl};
+use crate::frontend::ast::{Attribute, ImplDecl, TestCaseDecl, TestCaseKind};
 
 parser_impl! {
     pub(crate) fn parse_impl(
@@ -55,6 +55,7 @@ parser_impl! {
 
     pub(crate) fn parse_testcase(
         &mut self,
+        kind: TestCaseKind,
         is_async: bool,
         doc: Option<DocComment>,
         attributes: Vec<Attribute>,
@@ -97,11 +98,13 @@ parser_impl! {
 
         Some(Item::TestCase(TestCaseDecl {
             name,
+            kind,
             signature: Some(signature),
             body,
             is_async,
             doc,
             attributes,
+            expansion: None,
         }))
     }
 }
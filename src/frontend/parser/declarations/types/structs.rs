@@ -154,6 +154,7 @@ parser_impl! {
                     doc: None,
                     span: None,
                     attributes: Vec::new(),
+                    name_span: None,
                     di_inject: None,
                 });
             }
@@ -543,13 +543,24 @@ parser_impl! {
         mut attrs: CollectedAttributes,
         ctx: &mut ItemDispatch,
     ) -> Option<Item> {
-        if !self.match_keyword(Keyword::Testcase) {
-            return None;
-        }
-
+        let kind = self.match_testcase_keyword()?;
         let attributes = attrs.take_list();
         let doc = ctx.doc.take();
-        self.parse_testcase(ctx.is_async, doc, attributes)
+        self.parse_testcase(kind, ctx.is_async, doc, attributes)
+    }
+
+    pub(in crate::frontend::parser) fn match_testcase_keyword(&mut self) -> Option<TestCaseKind> {
+        if self.match_keyword(Keyword::Testcase) {
+            Some(TestCaseKind::Unit)
+        } else if self.match_keyword(Keyword::Proptestcase) {
+            Some(TestCaseKind::Property)
+        } else if self.match_keyword(Keyword::Fuzzcase) {
+            Some(TestCaseKind::Fuzz)
+        } else if self.match_keyword(Keyword::Benchcase) {
+            Some(TestCaseKind::Bench)
+        } else {
+            None
+        }
     }
 
     fn ensure_attributes_absent(&mut self, attrs: CollectedAttributes, message: &str) {
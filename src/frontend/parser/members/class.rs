@@ -108,6 +108,14 @@ parser_impl! {
             return None;
         }
 
+        if let Some(kind) = self.match_testcase_keyword() {
+            let attributes = attrs.take_list();
+            if let Some(item) = self.parse_testcase(kind, is_async, doc.take(), attributes) {
+                nested_types.push(item);
+            }
+            return None;
+        }
+
         if let Some(member) =
             self.parse_const_member(visibility, &mut doc, &attrs, &modifiers)
         {
@@ -586,6 +594,7 @@ parser_impl! {
             doc: doc.take(),
             span,
             attributes: Vec::new(),
+            name_span: constructor_span,
             di_inject: None,
         };
         constructor.attributes = attrs.take_list();
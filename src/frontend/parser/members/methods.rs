@@ -348,6 +348,7 @@ parser_impl! {
             (OperatorTokenKind::Binary(op), 2) => OperatorKind::Binary(op),
             (OperatorTokenKind::UnaryOrBinary { unary, .. }, 1) => OperatorKind::Unary(unary),
             (OperatorTokenKind::UnaryOrBinary { binary, .. }, 2) => OperatorKind::Binary(binary),
+            (OperatorTokenKind::CompoundAssignment(op), 2) => OperatorKind::CompoundAssignment(op),
             (OperatorTokenKind::Unary(_), _) => {
                 self.push_error(
                     "unary operator overloads must declare exactly one parameter",
@@ -362,6 +363,13 @@ parser_impl! {
                                     );
                 return None;
             }
+            (OperatorTokenKind::CompoundAssignment(_), _) => {
+                self.push_error(
+                    "compound assignment operator overloads must declare exactly two parameters",
+                    span,
+                );
+                return None;
+            }
             (OperatorTokenKind::UnaryOrBinary { .. }, _) => {
                 self.push_error(
                     "this operator requires one or two parameters to determine its arity",
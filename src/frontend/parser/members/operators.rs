@@ -8,6 +8,7 @@ pub(super) enum OperatorTokenKind {
         unary: UnaryOperator,
         binary: BinaryOperator,
     },
+    CompoundAssignment(BinaryOperator),
 }
 
 pub(super) fn operator_token_kind(token: &Token) -> Option<OperatorTokenKind> {
@@ -41,6 +42,32 @@ pub(super) fn operator_token_kind(token: &Token) -> Option<OperatorTokenKind> {
             "~" => Some(OperatorTokenKind::Unary(UnaryOperator::OnesComplement)),
             "++" => Some(OperatorTokenKind::Unary(UnaryOperator::Increment)),
             "--" => Some(OperatorTokenKind::Unary(UnaryOperator::Decrement)),
+            "+=" => Some(OperatorTokenKind::CompoundAssignment(BinaryOperator::Add)),
+            "-=" => Some(OperatorTokenKind::CompoundAssignment(
+                BinaryOperator::Subtract,
+            )),
+            "*=" => Some(OperatorTokenKind::CompoundAssignment(
+                BinaryOperator::Multiply,
+            )),
+            "/=" => Some(OperatorTokenKind::CompoundAssignment(
+                BinaryOperator::Divide,
+            )),
+            "%=" => Some(OperatorTokenKind::CompoundAssignment(
+                BinaryOperator::Remainder,
+            )),
+            "&=" => Some(OperatorTokenKind::CompoundAssignment(
+                BinaryOperator::BitAnd,
+            )),
+            "|=" => Some(OperatorTokenKind::CompoundAssignment(BinaryOperator::BitOr)),
+            "^=" => Some(OperatorTokenKind::CompoundAssignment(
+                BinaryOperator::BitXor,
+            )),
+            "<<=" => Some(OperatorTokenKind::CompoundAssignment(
+                BinaryOperator::ShiftLeft,
+            )),
+            ">>=" => Some(OperatorTokenKind::CompoundAssignment(
+                BinaryOperator::ShiftRight,
+            )),
             _ => None,
         },
         TokenKind::Punctuation('<') => Some(OperatorTokenKind::Binary(BinaryOperator::LessThan)),
@@ -53,6 +80,7 @@ pub(super) fn canonical_operator_name(kind: &OperatorKind, return_type: &TypeExp
     match kind {
         OperatorKind::Unary(op) => canonical_unary_name(*op).to_string(),
         OperatorKind::Binary(op) => canonical_binary_name(*op).to_string(),
+        OperatorKind::CompoundAssignment(op) => canonical_compound_name(*op).to_string(),
         OperatorKind::Conversion(conv) => canonical_conversion_name(*conv, &return_type.name),
     }
 }
@@ -97,6 +125,27 @@ fn canonical_binary_name(op: BinaryOperator) -> &'static str {
     }
 }
 
+fn canonical_compound_name(op: BinaryOperator) -> &'static str {
+    match op {
+        BinaryOperator::Add => "op_AdditionAssignment",
+        BinaryOperator::Subtract => "op_SubtractionAssignment",
+        BinaryOperator::Multiply => "op_MultiplicationAssignment",
+        BinaryOperator::Divide => "op_DivisionAssignment",
+        BinaryOperator::Remainder => "op_ModulusAssignment",
+        BinaryOperator::BitAnd => "op_BitwiseAndAssignment",
+        BinaryOperator::BitOr => "op_BitwiseOrAssignment",
+        BinaryOperator::BitXor => "op_ExclusiveOrAssignment",
+        BinaryOperator::ShiftLeft => "op_LeftShiftAssignment",
+        BinaryOperator::ShiftRight => "op_RightShiftAssignment",
+        BinaryOperator::Equal
+        | BinaryOperator::NotEqual
+        | BinaryOperator::LessThan
+        | BinaryOperator::LessThanOrEqual
+        | BinaryOperator::GreaterThan
+        | BinaryOperator::GreaterThanOrEqual => canonical_binary_name(op),
+    }
+}
+
 fn sanitize_type_fragment(name: &str) -> String {
     let mut fragment = String::with_capacity(name.len());
     for ch in name.chars() {
@@ -20,9 +20,9 @@ pub use crate::frontend::ast::{
     PropertyAccessor, PropertyAccessorBody, PropertyAccessorKind, PropertyDecl, Signature,
     Statement, StatementKind, StaticDeclaration, StaticDeclarator, StaticItemDecl,
     StaticMutability, StructDecl, SwitchLabel, SwitchSection, SwitchStatement, TestCaseDecl,
-    ThrowsClause, TryStatement, TypeExpr, UnaryOperator, UnionDecl, UnionField, UnionMember,
-    UnionViewDecl, UsingDirective, UsingKind, UsingResource, UsingStatement, VariableDeclaration,
-    VariableDeclarator, VariableModifier, Visibility,
+    TestCaseKind, ThrowsClause, TryStatement, TypeExpr, UnaryOperator, UnionDecl, UnionField,
+    UnionMember, UnionViewDecl, UsingDirective, UsingKind, UsingResource, UsingStatement,
+    VariableDeclaration, VariableDeclarator, VariableModifier, Visibility,
 };
 use crate::frontend::attributes::stage_builtin_attributes;
 use crate::frontend::conditional::{ConditionalDefines, preprocess};
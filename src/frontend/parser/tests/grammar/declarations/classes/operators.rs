@@ -186,3 +186,77 @@ public class Broken
         err.diagnostics()
     );
 }
+
+#[test]
+fn parses_compound_assignment_operator_overloads() {
+    let source = r#"
+namespace Numbers;
+
+public struct MyNumber { }
+
+public class Accumulators
+{
+    public static MyNumber operator +=(MyNumber target, MyNumber delta);
+    public static MyNumber operator <<=(MyNumber target, int bits);
+}
+"#;
+
+    let parse = parse_ok(source);
+    assert!(
+        parse.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        parse.diagnostics
+    );
+
+    let class = match &parse.module.items[1] {
+        Item::Class(class) => class,
+        other => panic!("expected class, found {other:?}"),
+    };
+    let operators: Vec<(&str, &OperatorKind)> = class
+        .members
+        .iter()
+        .filter_map(|member| match member {
+            ClassMember::Method(func) => func
+                .operator
+                .as_ref()
+                .map(|op| (func.name.as_str(), &op.kind)),
+            _ => None,
+        })
+        .collect();
+    assert!(matches!(
+        operators.as_slice(),
+        [
+            (
+                "op_AdditionAssignment",
+                OperatorKind::CompoundAssignment(BinaryOperator::Add)
+            ),
+            (
+                "op_LeftShiftAssignment",
+                OperatorKind::CompoundAssignment(BinaryOperator::ShiftLeft)
+            ),
+        ]
+    ));
+}
+
+#[test]
+fn rejects_compound_assignment_operator_with_wrong_arity() {
+    let source = r#"
+namespace Numbers;
+
+public struct MyNumber { }
+
+public class Broken
+{
+    public static MyNumber operator +=(MyNumber target);
+}
+"#;
+
+    let err = parse_module(source).expect_err("expected compound operator arity diagnostic");
+    assert!(
+        err.diagnostics().iter().any(|diag| diag.message.contains(
+            "compound assignment operator overloads must declare exactly two parameters"
+        )),
+        "expected arity diagnostic, found {:?}",
+        err.diagnostics()
+    );
+}
@@ -1,5 +1,5 @@
 use super::*;
-use crate::frontend::ast::RefKind;
+use crate::frontend::ast::{ClassMember, RefKind, TestCaseKind};
 use crate::syntax::expr::ExprNode;
 
 #[test]
@@ -548,6 +548,82 @@ testcase Runs(int count, string name)
     assert_eq!(param_names, ["count", "name"]);
 }
 
+#[test]
+fn testcase_keywords_record_kind() {
+    let source = r"
+proptestcase Commutes(int a, int b)
+{
+    return a + b == b + a;
+}
+
+fuzzcase Decodes(uint word)
+{
+}
+
+testcase Plain()
+{
+}
+
+benchcase Sums()
+{
+}
+";
+
+    let parse = parse_ok(source);
+    assert!(parse.diagnostics.is_empty());
+    let kinds: Vec<_> = parse
+        .module
+        .items
+        .iter()
+        .map(|item| match item {
+            Item::TestCase(case) => (case.name.as_str(), case.kind),
+            other => panic!("expected testcase, found {other:?}"),
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            ("Commutes", TestCaseKind::Property),
+            ("Decodes", TestCaseKind::Fuzz),
+            ("Plain", TestCaseKind::Unit),
+            ("Sums", TestCaseKind::Bench),
+        ]
+    );
+}
+
+#[test]
+fn testcases_inside_classes_become_nested_items() {
+    let source = r"
+public static class Suite
+{
+    @setup
+    public static void Reset()
+    {
+    }
+
+    @cases(1)
+    testcase Runs(int count)
+    {
+    }
+}
+";
+
+    let parse = parse_ok(source);
+    assert!(parse.diagnostics.is_empty(), "{:?}", parse.diagnostics);
+    let Item::Class(class) = &parse.module.items[0] else {
+        panic!("expected class, found {:?}", parse.module.items[0]);
+    };
+    let Some(Item::TestCase(case)) = class.nested_types.first() else {
+        panic!("expected nested testcase, found {:?}", class.nested_types);
+    };
+    assert_eq!(case.name, "Runs");
+    assert_eq!(case.attributes[0].name, "cases");
+    let Some(ClassMember::Method(reset)) = class.members.first() else {
+        panic!("expected hook method, found {:?}", class.members);
+    };
+    assert_eq!(reset.attributes[0].name, "setup");
+}
+
 #[test]
 fn testcase_missing_body_reports_error() {
     let source = r"
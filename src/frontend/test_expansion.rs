@@ -0,0 +1,1074 @@
+//! Expand testcases that use `@setup`/`@teardown` hooks, `@fixture` providers or `@cases` rows.
+//!
+//! A testcase that needs any of these keeps its body in a private `__testcase_<Name>` function
+//! and is replaced by generated testcases that run the setup hooks, call the body with the bound
+//! arguments, run the teardown hooks in a `finally` block and return the body's result. Each
+//! `@cases` row becomes its own testcase so runners can report and select rows individually.
+//!
+//! Hooks and fixtures apply to their namespace or class in every file of a compilation, so
+//! [`TestProviders`] is collected from all files before any of them is expanded.
+
+use crate::frontend::ast::expressions::{
+    Block, Expression, Statement, StatementKind, TryStatement, VariableDeclaration,
+    VariableDeclarator, VariableModifier,
+};
+use crate::frontend::ast::items::{
+    Attribute, AttributeArgument, ClassMember, FunctionDecl, Item, MemberDispatch, Module,
+    Parameter, Signature, TestArgumentSource, TestCaseDecl, TestCaseExpansion, TestCaseRowDecl,
+    Visibility,
+};
+use crate::frontend::ast::types::TypeExpr;
+use crate::frontend::diagnostics::{Diagnostic, Span};
+use crate::frontend::parser::parse_type_expression_text;
+
+const RESULT_LOCAL: &str = "__result";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProviderKind {
+    Setup,
+    Teardown,
+    Fixture,
+}
+
+impl ProviderKind {
+    fn from_attributes(attributes: &[Attribute]) -> Option<Self> {
+        attributes.iter().find_map(|attr| {
+            let name = attr.name.as_str();
+            if name.eq_ignore_ascii_case("setup") {
+                Some(Self::Setup)
+            } else if name.eq_ignore_ascii_case("teardown") {
+                Some(Self::Teardown)
+            } else if name.eq_ignore_ascii_case("fixture") {
+                Some(Self::Fixture)
+            } else {
+                None
+            }
+        })
+    }
+
+    fn attribute(self) -> &'static str {
+        match self {
+            Self::Setup => "@setup",
+            Self::Teardown => "@teardown",
+            Self::Fixture => "@fixture",
+        }
+    }
+}
+
+/// A hook or fixture function and the namespace/class path it applies to.
+#[derive(Debug, Clone)]
+struct Provider {
+    kind: ProviderKind,
+    scope: Vec<String>,
+    name: String,
+}
+
+impl Provider {
+    fn applies_to(&self, scope: &[String]) -> bool {
+        scope.starts_with(&self.scope)
+    }
+
+    fn call(&self) -> String {
+        format!("{}()", qualified_path(&self.scope, &self.name))
+    }
+}
+
+/// The hooks and fixtures declared across a set of files.
+#[derive(Debug, Clone, Default)]
+pub struct TestProviders {
+    providers: Vec<Provider>,
+}
+
+impl TestProviders {
+    /// Register the hooks and fixtures `module` declares, returning diagnostics for the
+    /// invalid ones.
+    pub fn collect(&mut self, module: &Module) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        collect_providers(
+            &module.items,
+            &module_scope(module),
+            &mut self.providers,
+            &mut diagnostics,
+        );
+        diagnostics
+    }
+}
+
+/// Expand hooks, fixtures and `@cases` rows for every testcase in `module`.
+pub fn expand_testcases(module: &mut Module, providers: &TestProviders) -> Vec<Diagnostic> {
+    let root = module_scope(module);
+    let mut expander = Expander {
+        providers: &providers.providers,
+        diagnostics: Vec::new(),
+        changed: false,
+    };
+    expander.expand_items(&mut module.items, &root, None);
+    if expander.changed {
+        module.rebuild_overloads();
+    }
+    expander.diagnostics
+}
+
+fn module_scope(module: &Module) -> Vec<String> {
+    module
+        .namespace
+        .as_deref()
+        .map(split_path)
+        .unwrap_or_default()
+}
+
+fn split_path(path: &str) -> Vec<String> {
+    path.split('.')
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn qualified_path(scope: &[String], name: &str) -> String {
+    if scope.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{name}", scope.join("."))
+    }
+}
+
+fn collect_providers(
+    items: &[Item],
+    scope: &[String],
+    providers: &mut Vec<Provider>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for item in items {
+        match item {
+            Item::Function(func) => register_provider(func, scope, false, providers, diagnostics),
+            Item::Namespace(ns) => {
+                let mut nested = scope.to_vec();
+                nested.extend(split_path(&ns.name));
+                collect_providers(&ns.items, &nested, providers, diagnostics);
+            }
+            Item::Class(class) => {
+                let mut nested = scope.to_vec();
+                nested.push(class.name.clone());
+                for member in &class.members {
+                    if let ClassMember::Method(method) = member {
+                        register_provider(method, &nested, true, providers, diagnostics);
+                    }
+                }
+                collect_providers(&class.nested_types, &nested, providers, diagnostics);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn register_provider(
+    func: &FunctionDecl,
+    scope: &[String],
+    is_method: bool,
+    providers: &mut Vec<Provider>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let Some(kind) = ProviderKind::from_attributes(&func.attributes) else {
+        return;
+    };
+    let attribute = kind.attribute();
+    let mut valid = true;
+    let mut reject = |message: String| {
+        diagnostics.push(Diagnostic::error(message, func.name_span));
+        valid = false;
+    };
+    if is_method && !func.modifiers.iter().any(|modifier| modifier == "static") {
+        reject(format!("{attribute} method `{}` must be static", func.name));
+    }
+    if !func.signature.parameters.is_empty() {
+        reject(format!(
+            "{attribute} function `{}` cannot declare parameters",
+            func.name
+        ));
+    }
+    if func.is_async {
+        reject(format!(
+            "{attribute} function `{}` cannot be async",
+            func.name
+        ));
+    }
+    let returns_void = func.signature.return_type.name.eq_ignore_ascii_case("void");
+    if kind == ProviderKind::Fixture && returns_void {
+        reject(format!(
+            "@fixture function `{}` must return the value it provides",
+            func.name
+        ));
+    }
+    if valid {
+        providers.push(Provider {
+            kind,
+            scope: scope.to_vec(),
+            name: func.name.clone(),
+        });
+    }
+}
+
+/// A `@cases` attribute taken off a testcase.
+struct CaseRow {
+    decl: TestCaseRowDecl,
+    arguments: Vec<AttributeArgument>,
+    span: Option<Span>,
+}
+
+struct Expander<'p> {
+    providers: &'p [Provider],
+    diagnostics: Vec<Diagnostic>,
+    changed: bool,
+}
+
+impl Expander<'_> {
+    fn expand_items(
+        &mut self,
+        items: &mut Vec<Item>,
+        scope: &[String],
+        mut class_members: Option<&mut Vec<ClassMember>>,
+    ) {
+        let mut index = 0;
+        while index < items.len() {
+            match &mut items[index] {
+                Item::Namespace(ns) => {
+                    let mut nested = scope.to_vec();
+                    nested.extend(split_path(&ns.name));
+                    self.expand_items(&mut ns.items, &nested, None);
+                }
+                Item::Class(class) => {
+                    let mut nested = scope.to_vec();
+                    nested.push(class.name.clone());
+                    self.expand_items(&mut class.nested_types, &nested, Some(&mut class.members));
+                }
+                Item::TestCase(test) => {
+                    let is_method = class_members.is_some();
+                    if let Some((body, generated)) = self.expand_testcase(test, scope, is_method) {
+                        self.changed = true;
+                        let count = generated.len();
+                        items.splice(index..=index, generated.into_iter().map(Item::TestCase));
+                        if let Some(members) = class_members.as_deref_mut() {
+                            members.push(ClassMember::Method(body));
+                        } else {
+                            items.insert(index, Item::Function(body));
+                            index += 1;
+                        }
+                        index += count;
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+            index += 1;
+        }
+    }
+
+    /// Returns the body function and the testcases replacing `test`, or `None` when the testcase
+    /// runs as declared.
+    fn expand_testcase(
+        &mut self,
+        test: &mut TestCaseDecl,
+        scope: &[String],
+        is_method: bool,
+    ) -> Option<(FunctionDecl, Vec<TestCaseDecl>)> {
+        let rows = self.take_rows(test);
+        let parameters = test
+            .signature
+            .as_ref()
+            .map(|signature| signature.parameters.clone())
+            .unwrap_or_default();
+        let fixtures: Vec<Option<String>> = parameters
+            .iter()
+            .map(|param| self.fixture_for(&param.name, scope).map(Provider::call))
+            .collect();
+        let setups = self.hook_calls(ProviderKind::Setup, scope);
+        let mut teardowns = self.hook_calls(ProviderKind::Teardown, scope);
+        teardowns.reverse();
+
+        if rows.is_empty()
+            && setups.is_empty()
+            && teardowns.is_empty()
+            && fixtures.iter().all(Option::is_none)
+        {
+            return None;
+        }
+
+        let mut bindings = Vec::new();
+        if rows.is_empty() {
+            let binding: Vec<Option<TestArgumentSource>> = parameters
+                .iter()
+                .zip(&fixtures)
+                .map(|(param, fixture)| match fixture {
+                    Some(call) => Some(TestArgumentSource::Fixture(call.clone())),
+                    None if param.default.is_some() && !test.kind.is_generative() => {
+                        Some(TestArgumentSource::Default)
+                    }
+                    None => None,
+                })
+                .collect();
+            // Unit testcases with values nobody supplies are left for the runner to skip.
+            if !test.kind.is_generative() && binding.iter().any(Option::is_none) {
+                return None;
+            }
+            bindings.push((None, binding));
+        } else {
+            let mut complete = true;
+            for row in &rows {
+                match self.bind_row(test, row, &parameters, &fixtures) {
+                    Some(binding) => bindings.push((Some(&row.decl), binding)),
+                    None => complete = false,
+                }
+            }
+            if !complete {
+                return None;
+            }
+        }
+
+        let declared_name = test.name.clone();
+        let body_name = format!("__testcase_{declared_name}");
+        let returns_value = block_returns_value(&test.body);
+        let body = body_function(
+            test,
+            &body_name,
+            parameters.clone(),
+            returns_value,
+            is_method,
+        );
+
+        let body_path = qualified_path(scope, &body_name);
+        let generated = bindings
+            .into_iter()
+            .map(|(row, binding)| {
+                let arguments: Vec<String> = parameters
+                    .iter()
+                    .zip(&binding)
+                    .map(|(param, source)| match source {
+                        Some(TestArgumentSource::Fixture(call)) => call.clone(),
+                        Some(TestArgumentSource::Case(value)) => value.clone(),
+                        Some(TestArgumentSource::Default) => param
+                            .default
+                            .as_ref()
+                            .map(|default| default.text.clone())
+                            .unwrap_or_default(),
+                        None => param.name.clone(),
+                    })
+                    .collect();
+                let call = format!("{body_path}({})", arguments.join(", "));
+                let call = if test.is_async {
+                    format!("await {call}")
+                } else {
+                    call
+                };
+                let runner_parameters: Vec<Parameter> = parameters
+                    .iter()
+                    .zip(&binding)
+                    .filter(|(_, source)| source.is_none())
+                    .map(|(param, _)| param.clone())
+                    .collect();
+                let name = match row {
+                    Some(row) => format!("{declared_name}__case{}", row.index),
+                    None => declared_name.clone(),
+                };
+                TestCaseDecl {
+                    name,
+                    kind: test.kind,
+                    signature: test.signature.clone().map(|signature| Signature {
+                        parameters: runner_parameters,
+                        ..signature
+                    }),
+                    body: harness_body(&setups, &call, returns_value, &teardowns, test.body.span),
+                    is_async: test.is_async,
+                    doc: test.doc.clone(),
+                    attributes: test.attributes.clone(),
+                    expansion: Some(TestCaseExpansion {
+                        declared_name: declared_name.clone(),
+                        parameters: parameters.iter().cloned().zip(binding).collect(),
+                        row: row.cloned(),
+                    }),
+                }
+            })
+            .collect();
+        Some((body, generated))
+    }
+
+    /// Removes the `@cases` attributes from `test`, returning one row per attribute.
+    fn take_rows(&mut self, test: &mut TestCaseDecl) -> Vec<CaseRow> {
+        let (cases, attributes): (Vec<Attribute>, Vec<Attribute>) = test
+            .attributes
+            .drain(..)
+            .partition(|attr| attr.name.eq_ignore_ascii_case("cases"));
+        test.attributes = attributes;
+        if !cases.is_empty() && test.kind.is_generative() {
+            self.diagnostics.push(Diagnostic::error(
+                format!(
+                    "`@cases` cannot be used on `{}` `{}`; its parameters are generated",
+                    test.kind.keyword(),
+                    test.name
+                ),
+                cases[0].span,
+            ));
+            return Vec::new();
+        }
+        cases
+            .into_iter()
+            .enumerate()
+            .map(|(index, attr)| {
+                let decl = TestCaseRowDecl {
+                    index,
+                    arguments: attr
+                        .arguments
+                        .iter()
+                        .map(|arg| match &arg.name {
+                            Some(name) => format!("{name}: {}", arg.value),
+                            None => arg.value.clone(),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                };
+                CaseRow {
+                    decl,
+                    arguments: attr.arguments,
+                    span: attr.span,
+                }
+            })
+            .collect()
+    }
+
+    fn fixture_for(&self, parameter: &str, scope: &[String]) -> Option<&Provider> {
+        // The innermost matching scope wins; declaration order breaks ties.
+        self.providers
+            .iter()
+            .filter(|provider| {
+                provider.kind == ProviderKind::Fixture
+                    && provider.applies_to(scope)
+                    && provider.name.eq_ignore_ascii_case(parameter)
+            })
+            .fold(None, |best: Option<&Provider>, provider| match best {
+                Some(best) if best.scope.len() >= provider.scope.len() => Some(best),
+                _ => Some(provider),
+            })
+    }
+
+    /// Calls to the hooks of `kind` that apply to `scope`, outermost scope first.
+    fn hook_calls(&self, kind: ProviderKind, scope: &[String]) -> Vec<String> {
+        let mut hooks: Vec<&Provider> = self
+            .providers
+            .iter()
+            .filter(|provider| provider.kind == kind && provider.applies_to(scope))
+            .collect();
+        hooks.sort_by_key(|provider| provider.scope.len());
+        hooks.into_iter().map(Provider::call).collect()
+    }
+
+    /// Binds one `@cases` row: named values by name, positional values to the remaining
+    /// non-fixture parameters in order, then fixtures and defaults.
+    fn bind_row(
+        &mut self,
+        test: &TestCaseDecl,
+        row: &CaseRow,
+        parameters: &[Parameter],
+        fixtures: &[Option<String>],
+    ) -> Option<Vec<Option<TestArgumentSource>>> {
+        let span = row.span.or(test.body.span);
+        let mut binding: Vec<Option<TestArgumentSource>> = vec![None; parameters.len()];
+        let mut positional = Vec::new();
+        let mut ok = true;
+        for argument in &row.arguments {
+            let Some(name) = &argument.name else {
+                positional.push(argument.value.clone());
+                continue;
+            };
+            if let Some(slot) = parameters.iter().position(|param| &param.name == name) {
+                binding[slot] = Some(TestArgumentSource::Case(argument.value.clone()));
+            } else {
+                self.diagnostics.push(Diagnostic::error(
+                    format!(
+                        "`@cases` row {} of testcase `{}` names unknown parameter `{name}`",
+                        row.decl.index, test.name
+                    ),
+                    span,
+                ));
+                ok = false;
+            }
+        }
+        let open_slots: Vec<usize> = (0..parameters.len())
+            .filter(|slot| binding[*slot].is_none() && fixtures[*slot].is_none())
+            .collect();
+        let mut open_slots = open_slots.into_iter();
+        for value in positional {
+            let Some(slot) = open_slots.next() else {
+                self.diagnostics.push(Diagnostic::error(
+                    format!(
+                        "`@cases` row {} of testcase `{}` has more values than parameters",
+                        row.decl.index, test.name
+                    ),
+                    span,
+                ));
+                return None;
+            };
+            binding[slot] = Some(TestArgumentSource::Case(value));
+        }
+        for (slot, param) in parameters.iter().enumerate() {
+            if binding[slot].is_some() {
+                continue;
+            }
+            if let Some(call) = &fixtures[slot] {
+                binding[slot] = Some(TestArgumentSource::Fixture(call.clone()));
+            } else if param.default.is_some() {
+                binding[slot] = Some(TestArgumentSource::Default);
+            } else {
+                self.diagnostics.push(Diagnostic::error(
+                    format!(
+                        "`@cases` row {} of testcase `{}` does not bind parameter `{}`",
+                        row.decl.index, test.name, param.name
+                    ),
+                    span,
+                ));
+                ok = false;
+            }
+        }
+        ok.then_some(binding)
+    }
+}
+
+fn body_function(
+    test: &TestCaseDecl,
+    name: &str,
+    parameters: Vec<Parameter>,
+    returns_value: bool,
+    is_method: bool,
+) -> FunctionDecl {
+    let return_type = match (test.is_async, returns_value) {
+        (false, false) => TypeExpr::simple("void"),
+        (false, true) => TypeExpr::simple("bool"),
+        (true, false) => TypeExpr::simple("Std.Async.Task"),
+        (true, true) => parse_type_expression_text("Std.Async.Task<bool>")
+            .unwrap_or_else(|| TypeExpr::simple("Std.Async.Task")),
+    };
+    FunctionDecl {
+        visibility: Visibility::Private,
+        name: name.to_string(),
+        name_span: None,
+        signature: Signature {
+            parameters,
+            return_type,
+            lends_to_return: None,
+            variadic: false,
+            throws: None,
+        },
+        body: Some(test.body.clone()),
+        is_async: test.is_async,
+        is_constexpr: false,
+        doc: None,
+        modifiers: if is_method {
+            vec!["static".into()]
+        } else {
+            Vec::new()
+        },
+        is_unsafe: false,
+        attributes: Vec::new(),
+        is_extern: false,
+        extern_abi: None,
+        extern_options: None,
+        link_name: None,
+        link_library: None,
+        operator: None,
+        generics: None,
+        vectorize_hint: None,
+        dispatch: MemberDispatch::default(),
+    }
+}
+
+/// Builds `setup(); var __result = body(args); return __result;`, attributing every generated
+/// statement to the declaration so diagnostics and debug info point at user code. With
+/// teardowns the call becomes `bool __result = false; try { __result = body(args); } finally
+/// { teardown(); }`, so they also run when the body throws.
+fn harness_body(
+    setups: &[String],
+    call: &str,
+    returns_value: bool,
+    teardowns: &[String],
+    span: Option<Span>,
+) -> Block {
+    let expression =
+        |text: &str| Statement::new(span, StatementKind::Expression(Expression::new(text, span)));
+    let declare_result = |type_annotation: Option<TypeExpr>, initializer: &str| {
+        Statement::new(
+            span,
+            StatementKind::VariableDeclaration(VariableDeclaration {
+                modifier: VariableModifier::Var,
+                type_annotation,
+                declarators: vec![VariableDeclarator {
+                    name: RESULT_LOCAL.into(),
+                    initializer: Some(Expression::new(initializer, span)),
+                }],
+                is_pinned: false,
+            }),
+        )
+    };
+    let mut statements: Vec<Statement> = setups.iter().map(|call| expression(call)).collect();
+    if teardowns.is_empty() {
+        statements.push(if returns_value {
+            declare_result(None, call)
+        } else {
+            expression(call)
+        });
+    } else {
+        // `return` does not run `finally` blocks, so the result leaves the `try` in a local.
+        let body = if returns_value {
+            statements.push(declare_result(Some(TypeExpr::simple("bool")), "false"));
+            expression(&format!("{RESULT_LOCAL} = {call}"))
+        } else {
+            expression(call)
+        };
+        statements.push(Statement::new(
+            span,
+            StatementKind::Try(TryStatement {
+                body: Block {
+                    statements: vec![body],
+                    span,
+                },
+                catches: Vec::new(),
+                finally: Some(Block {
+                    statements: teardowns.iter().map(|call| expression(call)).collect(),
+                    span,
+                }),
+            }),
+        ));
+    }
+    if returns_value {
+        statements.push(Statement::new(
+            span,
+            StatementKind::Return {
+                expression: Some(Expression::new(RESULT_LOCAL, span)),
+            },
+        ));
+    }
+    Block { statements, span }
+}
+
+fn block_returns_value(block: &Block) -> bool {
+    block.statements.iter().any(statement_returns_value)
+}
+
+fn statement_returns_value(statement: &Statement) -> bool {
+    match &statement.kind {
+        StatementKind::Return { expression } => expression.is_some(),
+        StatementKind::Block(inner) => block_returns_value(inner),
+        StatementKind::If(if_stmt) => {
+            statement_returns_value(&if_stmt.then_branch)
+                || if_stmt
+                    .else_branch
+                    .as_deref()
+                    .is_some_and(statement_returns_value)
+        }
+        StatementKind::While { body, .. }
+        | StatementKind::DoWhile { body, .. }
+        | StatementKind::Lock { body, .. }
+        | StatementKind::Unsafe { body } => statement_returns_value(body),
+        StatementKind::For(for_stmt) => statement_returns_value(&for_stmt.body),
+        StatementKind::Foreach(foreach_stmt) => statement_returns_value(&foreach_stmt.body),
+        StatementKind::Region { body, .. }
+        | StatementKind::Atomic { body, .. }
+        | StatementKind::Checked { body }
+        | StatementKind::Unchecked { body } => block_returns_value(body),
+        StatementKind::Switch(switch_stmt) => switch_stmt
+            .sections
+            .iter()
+            .any(|section| section.statements.iter().any(statement_returns_value)),
+        StatementKind::Try(try_stmt) => {
+            block_returns_value(&try_stmt.body)
+                || try_stmt
+                    .catches
+                    .iter()
+                    .any(|catch| block_returns_value(&catch.body))
+                || try_stmt.finally.as_ref().is_some_and(block_returns_value)
+        }
+        StatementKind::Using(using_stmt) => using_stmt
+            .body
+            .as_deref()
+            .is_some_and(statement_returns_value),
+        StatementKind::Fixed(fixed_stmt) => statement_returns_value(&fixed_stmt.body),
+        StatementKind::Labeled { statement, .. } => statement_returns_value(statement),
+        // Returns inside local functions belong to those functions.
+        StatementKind::LocalFunction(_)
+        | StatementKind::YieldReturn { .. }
+        | StatementKind::YieldBreak
+        | StatementKind::Break
+        | StatementKind::Continue
+        | StatementKind::Goto(_)
+        | StatementKind::Throw { .. }
+        | StatementKind::Expression(_)
+        | StatementKind::VariableDeclaration(_)
+        | StatementKind::ConstDeclaration(_)
+        | StatementKind::Empty => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::parse_module;
+
+    fn parse(source: &str) -> Module {
+        let parsed = parse_module(source).expect("parse module");
+        assert!(
+            parsed.diagnostics.is_empty(),
+            "expected clean parse, found {:?}",
+            parsed.diagnostics
+        );
+        parsed.module
+    }
+
+    fn expand(source: &str) -> (Module, Vec<Diagnostic>) {
+        let mut module = parse(source);
+        let mut providers = TestProviders::default();
+        let mut diagnostics = providers.collect(&module);
+        diagnostics.extend(expand_testcases(&mut module, &providers));
+        (module, diagnostics)
+    }
+
+    fn testcases(items: &[Item]) -> Vec<&TestCaseDecl> {
+        items
+            .iter()
+            .filter_map(|item| match item {
+                Item::TestCase(test) => Some(test),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn statement_texts(test: &TestCaseDecl) -> Vec<String> {
+        block_texts(&test.body)
+    }
+
+    fn block_texts(block: &Block) -> Vec<String> {
+        block
+            .statements
+            .iter()
+            .map(|statement| match &statement.kind {
+                StatementKind::Expression(expr) => expr.text.clone(),
+                StatementKind::Try(try_stmt) => format!(
+                    "try {{ {} }} finally {{ {} }}",
+                    block_texts(&try_stmt.body).join("; "),
+                    try_stmt
+                        .finally
+                        .as_ref()
+                        .map(|finally| block_texts(finally).join("; "))
+                        .unwrap_or_default()
+                ),
+                StatementKind::VariableDeclaration(decl) => format!(
+                    "{} {} = {}",
+                    decl.type_annotation
+                        .as_ref()
+                        .map_or("var", |ty| ty.name.as_str()),
+                    decl.declarators[0].name,
+                    decl.declarators[0]
+                        .initializer
+                        .as_ref()
+                        .map(|init| init.text.clone())
+                        .unwrap_or_default()
+                ),
+                StatementKind::Return { expression } => format!(
+                    "return {}",
+                    expression
+                        .as_ref()
+                        .map(|expr| expr.text.clone())
+                        .unwrap_or_default()
+                ),
+                other => panic!("unexpected generated statement {other:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn cases_expand_into_one_testcase_per_row() {
+        let source = r#"
+namespace Math;
+
+@fixture
+public int Offset() { return 1; }
+
+@id(adds)
+@cases(1, 2)
+@cases(b: 5, a: 4)
+testcase Adds(int a, int b, int offset, int scale = 2)
+{
+    return a + offset == b;
+}
+"#;
+        let (module, diagnostics) = expand(source);
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+        let tests = testcases(&module.items);
+        let names: Vec<&str> = tests.iter().map(|test| test.name.as_str()).collect();
+        assert_eq!(names, ["Adds__case0", "Adds__case1"]);
+        assert!(tests.iter().all(|test| test.attributes.len() == 1
+            && test.attributes[0].name == "id"
+            && test.signature.as_ref().unwrap().parameters.is_empty()));
+        assert_eq!(
+            statement_texts(tests[1]),
+            [
+                "var __result = Math.__testcase_Adds(4, 5, Math.Offset(), 2)",
+                "return __result"
+            ]
+        );
+        let expansion = tests[1].expansion.as_ref().expect("expansion metadata");
+        assert_eq!(expansion.declared_name, "Adds");
+        assert_eq!(
+            expansion.row,
+            Some(TestCaseRowDecl {
+                index: 1,
+                arguments: "b: 5, a: 4".into()
+            })
+        );
+        let sources: Vec<_> = expansion
+            .parameters
+            .iter()
+            .map(|(_, source)| source.clone())
+            .collect();
+        assert_eq!(
+            sources,
+            [
+                Some(TestArgumentSource::Case("4".into())),
+                Some(TestArgumentSource::Case("5".into())),
+                Some(TestArgumentSource::Fixture("Math.Offset()".into())),
+                Some(TestArgumentSource::Default),
+            ]
+        );
+        let body = module
+            .items
+            .iter()
+            .find_map(|item| match item {
+                Item::Function(func) if func.name == "__testcase_Adds" => Some(func),
+                _ => None,
+            })
+            .expect("body function");
+        assert_eq!(body.signature.parameters.len(), 4);
+        assert_eq!(body.signature.return_type.name, "bool");
+    }
+
+    #[test]
+    fn hooks_wrap_the_body_from_outer_to_inner_scope() {
+        let source = r"
+namespace Suite;
+
+@setup
+public void Open() { }
+
+@teardown
+public void Close() { }
+
+public static class Inner
+{
+    @setup
+    public static void Prepare() { }
+
+    @teardown
+    public static void Release() { }
+
+    testcase Runs()
+    {
+    }
+}
+
+testcase Outer()
+{
+}
+";
+        let (module, diagnostics) = expand(source);
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+        let Some(Item::Class(class)) = module
+            .items
+            .iter()
+            .find(|item| matches!(item, Item::Class(_)))
+        else {
+            panic!("expected class");
+        };
+        let inner = testcases(&class.nested_types);
+        assert_eq!(
+            statement_texts(inner[0]),
+            [
+                "Suite.Open()",
+                "Suite.Inner.Prepare()",
+                "try { Suite.Inner.__testcase_Runs() } finally { Suite.Inner.Release(); Suite.Close() }",
+            ]
+        );
+        assert!(class.members.iter().any(|member| matches!(
+            member,
+            ClassMember::Method(method)
+                if method.name == "__testcase_Runs" && method.modifiers == ["static"]
+        )));
+        let outer = testcases(&module.items);
+        assert_eq!(
+            statement_texts(outer[0]),
+            [
+                "Suite.Open()",
+                "try { Suite.__testcase_Outer() } finally { Suite.Close() }"
+            ]
+        );
+    }
+
+    #[test]
+    fn teardowns_keep_the_body_result_outside_the_try() {
+        let source = r"
+@teardown
+public void Close() { }
+
+testcase Checks()
+{
+    return true;
+}
+";
+        let (module, diagnostics) = expand(source);
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+        assert_eq!(
+            statement_texts(testcases(&module.items)[0]),
+            [
+                "bool __result = false",
+                "try { __result = __testcase_Checks() } finally { Close() }",
+                "return __result",
+            ]
+        );
+    }
+
+    #[test]
+    fn namespace_hooks_apply_across_files() {
+        let hooks = parse(
+            r"
+namespace Suite;
+
+@setup
+public void Open() { }
+
+@teardown
+public void Close() { }
+",
+        );
+        let other = parse(
+            r"
+namespace Other;
+
+@setup
+public void Unrelated() { }
+",
+        );
+        let mut tests = parse(
+            r"
+namespace Suite;
+
+testcase Runs()
+{
+}
+",
+        );
+        let mut providers = TestProviders::default();
+        for module in [&hooks, &other, &tests] {
+            assert!(providers.collect(module).is_empty());
+        }
+        let diagnostics = expand_testcases(&mut tests, &providers);
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+        assert_eq!(
+            statement_texts(testcases(&tests.items)[0]),
+            [
+                "Suite.Open()",
+                "try { Suite.__testcase_Runs() } finally { Suite.Close() }"
+            ]
+        );
+    }
+
+    #[test]
+    fn testcases_without_hooks_or_bindings_are_untouched() {
+        let source = r"
+testcase Plain()
+{
+}
+
+testcase Unbound(int value)
+{
+}
+";
+        let (module, diagnostics) = expand(source);
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+        let tests = testcases(&module.items);
+        assert_eq!(module.items.len(), 2);
+        assert!(tests.iter().all(|test| test.expansion.is_none()));
+    }
+
+    #[test]
+    fn generative_testcases_keep_runner_supplied_parameters() {
+        let source = r"
+@fixture
+public int Offset() { return 1; }
+
+proptestcase Shifts(int offset, int value)
+{
+    return value + offset > value;
+}
+";
+        let (module, diagnostics) = expand(source);
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+        let tests = testcases(&module.items);
+        let params: Vec<&str> = tests[0]
+            .signature
+            .as_ref()
+            .unwrap()
+            .parameters
+            .iter()
+            .map(|param| param.name.as_str())
+            .collect();
+        assert_eq!(params, ["value"]);
+        assert_eq!(
+            statement_texts(tests[0]),
+            [
+                "var __result = __testcase_Shifts(Offset(), value)",
+                "return __result"
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_hooks_and_rows_report_diagnostics() {
+        let source = r"
+public class Suite
+{
+    @setup
+    public void Instance() { }
+}
+
+@fixture
+public void Nothing() { }
+
+@teardown
+public void Needs(int value) { }
+
+@cases(1)
+proptestcase Generated(int value)
+{
+}
+
+@cases(1, 2)
+@cases(missing: 1)
+testcase Rows(int value)
+{
+}
+";
+        let (_, diagnostics) = expand(source);
+        let messages: Vec<&str> = diagnostics
+            .iter()
+            .map(|diag| diag.message.as_str())
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "@setup method `Instance` must be static",
+                "@fixture function `Nothing` must return the value it provides",
+                "@teardown function `Needs` cannot declare parameters",
+                "`@cases` cannot be used on `proptestcase` `Generated`; its parameters are generated",
+                "`@cases` row 0 of testcase `Rows` has more values than parameters",
+                "`@cases` row 1 of testcase `Rows` names unknown parameter `missing`",
+                "`@cases` row 1 of testcase `Rows` does not bind parameter `value`",
+            ]
+        );
+    }
+}
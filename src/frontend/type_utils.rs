@@ -154,11 +154,15 @@ pub fn type_expr_surface(expr: &TypeExpr) -> String {
     }
 }
 
-fn is_self_type(expr: &TypeExpr) -> bool {
-    expr.base.len() == 1 && expr.base[0] == "Self"
+fn is_named_type(expr: &TypeExpr, name: &str) -> bool {
+    expr.base.len() == 1 && expr.base[0] == name
 }
 
-fn substitute_suffixes(suffixes: &[TypeSuffix], replacement: &TypeExpr) -> Vec<TypeSuffix> {
+fn substitute_suffixes(
+    suffixes: &[TypeSuffix],
+    name: &str,
+    replacement: &TypeExpr,
+) -> Vec<TypeSuffix> {
     suffixes
         .iter()
         .map(|suffix| match suffix {
@@ -167,7 +171,7 @@ fn substitute_suffixes(suffixes: &[TypeSuffix], replacement: &TypeExpr) -> Vec<T
                     .map(|arg| {
                         let substituted_ty = arg
                             .ty()
-                            .map(|inner| substitute_self_type(inner, replacement));
+                            .map(|inner| substitute_named_type(inner, name, replacement));
                         GenericArgument::new(substituted_ty, arg.expression().clone())
                     })
                     .collect(),
@@ -183,64 +187,83 @@ fn substitute_suffixes(suffixes: &[TypeSuffix], replacement: &TypeExpr) -> Vec<T
         .collect()
 }
 
-fn substitute_fn_signature(signature: &FnTypeExpr, replacement: &TypeExpr) -> FnTypeExpr {
+fn substitute_fn_signature(
+    signature: &FnTypeExpr,
+    name: &str,
+    replacement: &TypeExpr,
+) -> FnTypeExpr {
     FnTypeExpr {
         abi: signature.abi.clone(),
         params: signature
             .params
             .iter()
-            .map(|param| substitute_self_type(param, replacement))
+            .map(|param| substitute_named_type(param, name, replacement))
             .collect(),
-        return_type: Box::new(substitute_self_type(&signature.return_type, replacement)),
+        return_type: Box::new(substitute_named_type(
+            &signature.return_type,
+            name,
+            replacement,
+        )),
         variadic: signature.variadic,
     }
 }
 
-fn substitute_trait_object(bounds: &[TypeExpr], replacement: &TypeExpr) -> Vec<TypeExpr> {
+fn substitute_trait_object(
+    bounds: &[TypeExpr],
+    name: &str,
+    replacement: &TypeExpr,
+) -> Vec<TypeExpr> {
     bounds
         .iter()
-        .map(|bound| substitute_self_type(bound, replacement))
+        .map(|bound| substitute_named_type(bound, name, replacement))
         .collect()
 }
 
 #[must_use]
 pub fn substitute_self_type(expr: &TypeExpr, replacement: &TypeExpr) -> TypeExpr {
-    if is_self_type(expr) {
+    substitute_named_type(expr, "Self", replacement)
+}
+
+/// Replaces every occurrence of the bare type `name` (such as `Self` or a generic parameter)
+/// inside `expr` with `replacement`.
+#[must_use]
+pub fn substitute_named_type(expr: &TypeExpr, name: &str, replacement: &TypeExpr) -> TypeExpr {
+    if is_named_type(expr, name) {
         let mut substituted = replacement.clone();
         substituted
             .suffixes
-            .extend(substitute_suffixes(&expr.suffixes, replacement));
+            .extend(substitute_suffixes(&expr.suffixes, name, replacement));
         if let Some(elements) = &substituted.tuple_elements {
             substituted.tuple_elements = Some(
                 elements
                     .iter()
-                    .map(|element| substitute_self_type(element, replacement))
+                    .map(|element| substitute_named_type(element, name, replacement))
                     .collect(),
             );
         }
         if let Some(signature) = &substituted.fn_signature {
-            substituted.fn_signature = Some(substitute_fn_signature(signature, replacement));
+            substituted.fn_signature = Some(substitute_fn_signature(signature, name, replacement));
         }
         if let Some(object) = &mut substituted.trait_object {
-            object.bounds = substitute_trait_object(&object.bounds, replacement);
+            object.bounds = substitute_trait_object(&object.bounds, name, replacement);
         }
         substituted
     } else {
         let mut cloned = expr.clone();
-        cloned.suffixes = substitute_suffixes(&expr.suffixes, replacement);
+        cloned.suffixes = substitute_suffixes(&expr.suffixes, name, replacement);
         if let Some(elements) = &expr.tuple_elements {
             cloned.tuple_elements = Some(
                 elements
                     .iter()
-                    .map(|element| substitute_self_type(element, replacement))
+                    .map(|element| substitute_named_type(element, name, replacement))
                     .collect(),
             );
         }
         if let Some(signature) = &expr.fn_signature {
-            cloned.fn_signature = Some(substitute_fn_signature(signature, replacement));
+            cloned.fn_signature = Some(substitute_fn_signature(signature, name, replacement));
         }
         if let Some(object) = &mut cloned.trait_object {
-            object.bounds = substitute_trait_object(&object.bounds, replacement);
+            object.bounds = substitute_trait_object(&object.bounds, name, replacement);
         }
         cloned
     }
@@ -249,29 +272,45 @@ pub fn substitute_self_type(expr: &TypeExpr, replacement: &TypeExpr) -> TypeExpr
 #[must_use]
 pub fn instantiate_extension_method(method: &FunctionDecl, target: &TypeExpr) -> FunctionDecl {
     let mut cloned = method.clone();
-    cloned.signature = Signature {
-        parameters: method
-            .signature
+    cloned.signature = substitute_signature(&method.signature, "Self", target);
+    cloned
+}
+
+/// Instantiates a method from a blanket impl (`impl<T> Trait for T`) for `target`, replacing
+/// both `Self` and the impl's type parameter in the signature.
+#[must_use]
+pub fn instantiate_blanket_method(
+    method: &FunctionDecl,
+    param: &str,
+    target: &TypeExpr,
+) -> FunctionDecl {
+    let mut cloned = instantiate_extension_method(method, target);
+    cloned.signature = substitute_signature(&cloned.signature, param, target);
+    cloned
+}
+
+fn substitute_signature(signature: &Signature, name: &str, target: &TypeExpr) -> Signature {
+    Signature {
+        parameters: signature
             .parameters
             .iter()
             .map(|param| Parameter {
-                ty: substitute_self_type(&param.ty, target),
+                ty: substitute_named_type(&param.ty, name, target),
                 ..param.clone()
             })
             .collect(),
-        return_type: substitute_self_type(&method.signature.return_type, target),
-        lends_to_return: method.signature.lends_to_return.clone(),
-        variadic: method.signature.variadic,
-        throws: method.signature.throws.as_ref().map(|clause| ThrowsClause {
+        return_type: substitute_named_type(&signature.return_type, name, target),
+        lends_to_return: signature.lends_to_return.clone(),
+        variadic: signature.variadic,
+        throws: signature.throws.as_ref().map(|clause| ThrowsClause {
             types: clause
                 .types
                 .iter()
-                .map(|ty| substitute_self_type(ty, target))
+                .map(|ty| substitute_named_type(ty, name, target))
                 .collect(),
             span: clause.span,
         }),
-    };
-    cloned
+    }
 }
 
 #[must_use]
@@ -26,6 +26,7 @@ pub mod eq_glue;
 pub mod error;
 pub mod export;
 pub mod extern_bind;
+pub mod fingerprint;
 pub mod format;
 pub mod frontend;
 pub mod hash_glue;
@@ -20,6 +20,8 @@ use super::{LintModuleInfo, find_descriptor};
 struct FunctionAstInfo {
     name: String,
     span: Option<Span>,
+    /// Span of the declared name, when the declaration has one.
+    name_span: Option<Span>,
     allowance: LintAllowance,
     param_allowances: Vec<LintAllowance>,
     param_names: Vec<String>,
@@ -266,6 +268,7 @@ fn collect_items(
                 index.insert(FunctionAstInfo {
                     name,
                     span,
+                    name_span: None,
                     allowance,
                     param_allowances,
                     param_names,
@@ -331,6 +334,7 @@ fn collect_function(
     index.insert(FunctionAstInfo {
         name,
         span,
+        name_span: func.name_span,
         allowance,
         param_allowances,
         param_names,
@@ -353,6 +357,7 @@ fn collect_method(
     index.insert(FunctionAstInfo {
         name,
         span,
+        name_span: method.name_span,
         allowance,
         param_allowances,
         param_names,
@@ -375,6 +380,7 @@ fn collect_constructor(
     index.insert(FunctionAstInfo {
         name,
         span,
+        name_span: ctor.name_span,
         allowance,
         param_allowances,
         param_names,
@@ -750,10 +756,21 @@ fn scan_operand(op: &Operand, node: &mut FunctionGraphNode) {
                 node.symbol_edges.insert(symbol.to_string());
             }
         }
+        // Qualified calls such as `Ns.Helper()` stay pending until codegen.
+        Operand::Pending(pending) if is_path_like(&pending.repr) => {
+            node.symbol_edges.insert(pending.repr.clone());
+        }
         Operand::Pending(_) => {}
     }
 }
 
+fn is_path_like(repr: &str) -> bool {
+    !repr.is_empty()
+        && repr
+            .chars()
+            .all(|ch| ch.is_alphanumeric() || matches!(ch, '_' | '.' | ':'))
+}
+
 fn record_place(place: &Place, node: &mut FunctionGraphNode) {
     record_local(place.local, node);
     for proj in &place.projection {
@@ -1107,9 +1124,10 @@ fn run_type_named_constructors(
             ),
             file: module.path.to_path_buf(),
             span,
+            // Only the name is rewritten; without a name span the fix-it stays advisory.
             suggestions: vec![LintSuggestion::new(
                 "rename this constructor to `init`",
-                span,
+                info.name_span,
                 Some("init".to_string()),
             )],
         });
@@ -0,0 +1,243 @@
+//! `textDocument/codeAction` support.
+//!
+//! Fixes are computed when diagnostics are published: every suggestion with a span and a
+//! replacement becomes a quick fix, and span-less `PKG0100` suggestions become an edit to
+//! the project manifest. Requests answer from those fixes, plus a "fix all" source action
+//! that applies every non-overlapping fix for the document at once.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::diagnostics::{FileCache, Suggestion};
+
+use super::types::{
+    CodeAction, CodeActionParams, Diagnostic as LspDiagnostic, NumberOrString, Position, Range,
+    TextEdit, Uri, WorkspaceEdit, code_action_kind,
+};
+use super::{DocumentStore, file_path_to_uri, span_to_range};
+
+const PKG_PACKAGE_UNKNOWN: &str = "PKG0100";
+
+/// A quick fix attached to a published diagnostic.
+#[derive(Clone, Debug)]
+pub(super) struct Fix {
+    pub diagnostic: LspDiagnostic,
+    pub title: String,
+    pub edit: WorkspaceEdit,
+}
+
+/// Build the fixes offered by the `suggestions` of the published diagnostic `converted`.
+pub(super) fn fixes_for(
+    suggestions: &[Suggestion],
+    converted: &LspDiagnostic,
+    files: &FileCache,
+    manifest: Option<&Path>,
+    open_text: impl Fn(&Uri) -> Option<String>,
+) -> Vec<Fix> {
+    let code = match &converted.code {
+        Some(NumberOrString::String(code)) => Some(code.as_str()),
+        _ => None,
+    };
+    suggestions
+        .iter()
+        .filter_map(|suggestion| {
+            let edit = match (suggestion.span, code) {
+                (Some(_), _) => span_edit(suggestion, files)?,
+                (None, Some(PKG_PACKAGE_UNKNOWN)) => {
+                    manifest_edit(suggestion, manifest?, &open_text)?
+                }
+                (None, _) => return None,
+            };
+            Some(Fix {
+                diagnostic: converted.clone(),
+                title: suggestion.message.clone(),
+                edit,
+            })
+        })
+        .collect()
+}
+
+fn span_edit(suggestion: &Suggestion, files: &FileCache) -> Option<WorkspaceEdit> {
+    let span = suggestion.span?;
+    let replacement = suggestion.replacement.clone()?;
+    let uri = files.path(span.file_id).and_then(file_path_to_uri)?;
+    let range = span_to_range(span, files)?;
+    Some(single_edit(uri, range, replacement))
+}
+
+/// Insert the suggested dependency entry under `dependencies:` in the manifest, adding the
+/// section when the manifest has none.
+fn manifest_edit(
+    suggestion: &Suggestion,
+    manifest: &Path,
+    open_text: &impl Fn(&Uri) -> Option<String>,
+) -> Option<WorkspaceEdit> {
+    let entry = suggestion.replacement.as_deref()?;
+    let uri = file_path_to_uri(manifest)?;
+    let text = open_text(&uri).or_else(|| fs::read_to_string(manifest).ok())?;
+    let section = text
+        .lines()
+        .position(|line| line.trim_end() == "dependencies:");
+    let (position, new_text) = match section {
+        Some(line) => (
+            Position {
+                line: u32::try_from(line + 1).ok()?,
+                character: 0,
+            },
+            format!("  {entry}\n"),
+        ),
+        None => {
+            let line = text.lines().count();
+            let separator = if text.is_empty() || text.ends_with('\n') {
+                ""
+            } else {
+                "\n"
+            };
+            let last = text.lines().last().unwrap_or_default();
+            let (line, character) = if separator.is_empty() {
+                (line, 0)
+            } else {
+                (line.saturating_sub(1), last.encode_utf16().count())
+            };
+            (
+                Position {
+                    line: u32::try_from(line).ok()?,
+                    character: u32::try_from(character).ok()?,
+                },
+                format!("{separator}\ndependencies:\n  {entry}\n"),
+            )
+        }
+    };
+    Some(single_edit(
+        uri,
+        Range {
+            start: position,
+            end: position,
+        },
+        new_text,
+    ))
+}
+
+fn single_edit(uri: Uri, range: Range, new_text: String) -> WorkspaceEdit {
+    let mut changes = BTreeMap::new();
+    changes.insert(uri, vec![TextEdit { range, new_text }]);
+    WorkspaceEdit { changes }
+}
+
+fn overlaps(left: Range, right: Range) -> bool {
+    let key = |position: Position| (position.line, position.character);
+    key(left.start) <= key(right.end) && key(right.start) <= key(left.end)
+}
+
+/// Whether the client asked for `kind` (an absent `only` filter accepts every kind).
+fn requested(only: Option<&[String]>, kind: &str) -> bool {
+    only.is_none_or(|only| {
+        only.iter().any(|wanted| {
+            kind == wanted
+                || kind
+                    .strip_prefix(wanted.as_str())
+                    .is_some_and(|rest| rest.starts_with('.'))
+        })
+    })
+}
+
+pub(super) fn code_actions(store: &DocumentStore, params: &CodeActionParams) -> Vec<CodeAction> {
+    let fixes = store.fixes(&params.text_document.uri);
+    let only = params.context.only.as_deref();
+    let mut actions = Vec::new();
+
+    if requested(only, code_action_kind::QUICK_FIX) {
+        for fix in fixes
+            .iter()
+            .filter(|fix| overlaps(fix.diagnostic.range, params.range))
+        {
+            actions.push(CodeAction {
+                title: fix.title.clone(),
+                kind: code_action_kind::QUICK_FIX.to_string(),
+                diagnostics: vec![fix.diagnostic.clone()],
+                is_preferred: fixes
+                    .iter()
+                    .filter(|other| other.diagnostic.range == fix.diagnostic.range)
+                    .count()
+                    == 1,
+                edit: fix.edit.clone(),
+            });
+        }
+    }
+
+    if requested(only, code_action_kind::SOURCE_FIX_ALL) {
+        let mut changes: BTreeMap<Uri, Vec<TextEdit>> = BTreeMap::new();
+        for fix in fixes {
+            let clashes = fix.edit.changes.iter().any(|(uri, edits)| {
+                changes.get(uri).is_some_and(|accepted| {
+                    edits.iter().any(|edit| {
+                        accepted
+                            .iter()
+                            .any(|other| other == edit || overlaps(other.range, edit.range))
+                    })
+                })
+            });
+            if clashes {
+                continue;
+            }
+            for (uri, edits) in &fix.edit.changes {
+                changes
+                    .entry(uri.clone())
+                    .or_default()
+                    .extend(edits.iter().cloned());
+            }
+        }
+        if !changes.is_empty() {
+            actions.push(CodeAction {
+                title: String::from("Fix all auto-fixable problems"),
+                kind: code_action_kind::SOURCE_FIX_ALL.to_string(),
+                diagnostics: Vec::new(),
+                is_preferred: false,
+                edit: WorkspaceEdit { changes },
+            });
+        }
+    }
+    actions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kind_filter_accepts_hierarchical_prefixes() {
+        assert!(requested(None, code_action_kind::QUICK_FIX));
+        let source = vec![String::from("source")];
+        assert!(requested(Some(&source), code_action_kind::SOURCE_FIX_ALL));
+        assert!(!requested(Some(&source), code_action_kind::QUICK_FIX));
+        let partial = vec![String::from("source.fix")];
+        assert!(!requested(Some(&partial), code_action_kind::SOURCE_FIX_ALL));
+    }
+
+    #[test]
+    fn manifest_fix_inserts_under_existing_dependencies() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let manifest = dir.path().join("manifest.yaml");
+        fs::write(
+            &manifest,
+            "package:\n  name: app\n\ndependencies:\n  std:\n    path: ../std\n",
+        )
+        .expect("write manifest");
+        let suggestion = Suggestion::new(
+            "add dependency to manifest",
+            None,
+            Some(String::from("json: \"<version>\"")),
+        );
+        let edit = manifest_edit(&suggestion, &manifest, &|_| None).expect("manifest edit");
+        let edits = edit.changes.values().next().expect("manifest edits");
+        assert_eq!(
+            edits[0].range.start,
+            Position {
+                line: 4,
+                character: 0
+            }
+        );
+        assert_eq!(edits[0].new_text, "  json: \"<version>\"\n");
+    }
+}
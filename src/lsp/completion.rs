@@ -0,0 +1,323 @@
+//! `textDocument/completion` support.
+//!
+//! Member completion (`receiver.`) resolves the receiver through MIR locals, the enclosing
+//! type's fields/properties, or a type name, then lists members from the declarations of
+//! the analysed files and the symbol index (which also sees extension members). Plain
+//! completion offers visible locals, members of the enclosing type, declared items, known
+//! types and keywords.
+
+use std::collections::HashSet;
+
+use crate::diagnostics::Span;
+use crate::frontend::lexer::Keyword;
+use crate::frontend::type_utils::type_expr_surface;
+
+use super::symbols::{Declaration, type_leaf};
+use super::types::{CompletionItem, CompletionList, Position, Uri, completion_kind, symbol_kind};
+use super::{DocumentAnalysis, DocumentStore, offset_at};
+
+const SORT_LOCAL: char = '0';
+const SORT_MEMBER: char = '1';
+const SORT_ITEM: char = '2';
+const SORT_KEYWORD: char = '3';
+
+struct Items {
+    seen: HashSet<String>,
+    prefix: String,
+    items: Vec<CompletionItem>,
+}
+
+impl Items {
+    fn new(prefix: &str) -> Self {
+        Self {
+            seen: HashSet::new(),
+            prefix: prefix.to_ascii_lowercase(),
+            items: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, label: &str, kind: u32, detail: Option<String>, group: char) {
+        if label.is_empty()
+            || !label.to_ascii_lowercase().starts_with(&self.prefix)
+            || !self.seen.insert(label.to_string())
+        {
+            return;
+        }
+        self.items.push(CompletionItem {
+            label: label.to_string(),
+            kind: Some(kind),
+            detail,
+            sort_text: Some(format!("{group}{label}")),
+        });
+    }
+}
+
+pub(super) fn completion(store: &DocumentStore, uri: &Uri, position: Position) -> CompletionList {
+    let Some(doc) = store.document(uri) else {
+        return CompletionList {
+            is_incomplete: false,
+            items: Vec::new(),
+        };
+    };
+    let text = doc.text.as_str();
+    let offset = offset_at(text, position).min(text.len());
+    let prefix_start = identifier_start(text, offset);
+    let mut items = Items::new(&text[prefix_start..offset]);
+    let analysis = store.analysis(uri);
+
+    if let Some(receiver) = member_receiver(text, prefix_start) {
+        if let Some(analysis) = analysis {
+            let at = analysis_span(analysis, uri, offset);
+            match receiver_type(analysis, receiver, at) {
+                Some(ty) => members_of(analysis, &ty, &mut items),
+                None => all_members(analysis, &mut items),
+            }
+        }
+    } else {
+        if let Some(analysis) = analysis {
+            let at = analysis_span(analysis, uri, offset);
+            for local in &analysis.locals {
+                if contains(local.scope, at) {
+                    items.push(
+                        &local.name,
+                        completion_kind::VARIABLE,
+                        Some(local.ty.clone()),
+                        SORT_LOCAL,
+                    );
+                }
+            }
+            if let Some(owner) = enclosing_type(analysis, at) {
+                let owner_name = owner.name.clone();
+                members_of(analysis, &owner_name, &mut items);
+            }
+            for decl in &analysis.declarations {
+                let top_level = decl.parent.is_none_or(|parent| {
+                    analysis.declarations[parent].kind == symbol_kind::NAMESPACE
+                });
+                if top_level && decl.kind != symbol_kind::NAMESPACE {
+                    items.push(
+                        &decl.name,
+                        item_kind(decl.kind),
+                        decl.detail.clone(),
+                        SORT_ITEM,
+                    );
+                }
+            }
+            for index in &analysis.indices {
+                for ty in index.type_names() {
+                    items.push(
+                        type_leaf(ty),
+                        completion_kind::CLASS,
+                        Some(ty.clone()),
+                        SORT_ITEM,
+                    );
+                }
+            }
+        }
+        for keyword in Keyword::spellings() {
+            items.push(keyword, completion_kind::KEYWORD, None, SORT_KEYWORD);
+        }
+    }
+
+    items
+        .items
+        .sort_by(|left, right| left.sort_text.cmp(&right.sort_text));
+    CompletionList {
+        is_incomplete: false,
+        items: items.items,
+    }
+}
+
+fn is_identifier_char(ch: char) -> bool {
+    ch == '_' || ch.is_alphanumeric()
+}
+
+fn identifier_start(text: &str, offset: usize) -> usize {
+    text[..offset]
+        .char_indices()
+        .rev()
+        .take_while(|(_, ch)| is_identifier_char(*ch))
+        .last()
+        .map_or(offset, |(idx, _)| idx)
+}
+
+/// When the identifier being typed follows `receiver.`, return the receiver identifier.
+fn member_receiver(text: &str, prefix_start: usize) -> Option<&str> {
+    let before = text[..prefix_start].strip_suffix('.')?;
+    let start = identifier_start(before, before.len());
+    let receiver = &before[start..];
+    (!receiver.is_empty()).then_some(receiver)
+}
+
+/// The analysed text may lag behind the buffer by a few characters while the user types
+/// (failed parses keep the previous analysis), so offsets are mapped as-is.
+fn analysis_span(analysis: &DocumentAnalysis, uri: &Uri, offset: usize) -> Span {
+    let file_id = analysis.file_id(uri).unwrap_or_default();
+    Span::in_file(file_id, offset, offset)
+}
+
+pub(super) fn contains(scope: Span, at: Span) -> bool {
+    scope.file_id == at.file_id && scope.start <= at.start && at.end <= scope.end
+}
+
+pub(super) fn enclosing_type(analysis: &DocumentAnalysis, at: Span) -> Option<&Declaration> {
+    analysis
+        .declarations
+        .iter()
+        .filter(|decl| decl.is_type() && contains(decl.extent, at))
+        .min_by_key(|decl| decl.extent.end - decl.extent.start)
+}
+
+pub(super) fn receiver_type(
+    analysis: &DocumentAnalysis,
+    receiver: &str,
+    at: Span,
+) -> Option<String> {
+    if matches!(receiver, "this" | "self") {
+        return enclosing_type(analysis, at).map(|decl| decl.name.clone());
+    }
+    if let Some(local) = analysis
+        .locals
+        .iter()
+        .filter(|local| local.name == receiver && contains(local.scope, at))
+        .min_by_key(|local| local.scope.end - local.scope.start)
+    {
+        return Some(type_leaf(&local.ty).to_string());
+    }
+    if let Some(owner) = enclosing_type(analysis, at) {
+        let owner_index = analysis
+            .declarations
+            .iter()
+            .position(|decl| std::ptr::eq(decl, owner));
+        let member = analysis.declarations.iter().find(|decl| {
+            decl.parent == owner_index
+                && decl.name == receiver
+                && matches!(decl.kind, symbol_kind::FIELD | symbol_kind::PROPERTY)
+        });
+        if let Some(detail) = member.and_then(|decl| decl.detail.as_deref()) {
+            return Some(type_leaf(detail).to_string());
+        }
+    }
+    let is_type = analysis
+        .declarations
+        .iter()
+        .any(|decl| decl.is_type() && decl.name == receiver)
+        || analysis.indices.iter().any(|index| {
+            index
+                .type_names()
+                .iter()
+                .any(|ty| type_leaf(ty) == receiver)
+        });
+    is_type.then(|| receiver.to_string())
+}
+
+fn members_of(analysis: &DocumentAnalysis, ty: &str, items: &mut Items) {
+    let leaf = type_leaf(ty);
+    for (index, decl) in analysis.declarations.iter().enumerate() {
+        if !(decl.is_type() && decl.name == leaf) {
+            continue;
+        }
+        for member in &analysis.declarations {
+            if member.parent == Some(index) && member.kind != symbol_kind::CONSTRUCTOR {
+                items.push(
+                    &member.name,
+                    item_kind(member.kind),
+                    member.detail.clone(),
+                    SORT_MEMBER,
+                );
+            }
+        }
+    }
+    for index in &analysis.indices {
+        for (owner, fields) in &index.type_fields {
+            if type_leaf(owner) == leaf {
+                for (name, field) in fields {
+                    items.push(
+                        name,
+                        completion_kind::FIELD,
+                        Some(type_expr_surface(&field.ty)),
+                        SORT_MEMBER,
+                    );
+                }
+            }
+        }
+        for (owner, properties) in &index.type_properties {
+            if type_leaf(owner) == leaf {
+                for (name, property) in properties {
+                    items.push(
+                        name,
+                        completion_kind::PROPERTY,
+                        Some(property.ty.clone()),
+                        SORT_MEMBER,
+                    );
+                }
+            }
+        }
+        for (owner, methods) in &index.type_methods {
+            if type_leaf(owner) == leaf {
+                for name in methods.keys() {
+                    items.push(name, completion_kind::METHOD, None, SORT_MEMBER);
+                }
+            }
+        }
+        for (owner, variants) in &index.enum_variants {
+            if type_leaf(owner) == leaf {
+                for name in variants {
+                    items.push(name, completion_kind::ENUM_MEMBER, None, SORT_MEMBER);
+                }
+            }
+        }
+    }
+}
+
+fn all_members(analysis: &DocumentAnalysis, items: &mut Items) {
+    for decl in &analysis.declarations {
+        let is_member = decl
+            .parent
+            .is_some_and(|parent| analysis.declarations[parent].is_type());
+        if is_member && decl.kind != symbol_kind::CONSTRUCTOR {
+            items.push(
+                &decl.name,
+                item_kind(decl.kind),
+                decl.detail.clone(),
+                SORT_MEMBER,
+            );
+        }
+    }
+}
+
+fn item_kind(kind: u32) -> u32 {
+    match kind {
+        symbol_kind::NAMESPACE => completion_kind::MODULE,
+        symbol_kind::CLASS => completion_kind::CLASS,
+        symbol_kind::METHOD => completion_kind::METHOD,
+        symbol_kind::PROPERTY => completion_kind::PROPERTY,
+        symbol_kind::FIELD => completion_kind::FIELD,
+        symbol_kind::CONSTRUCTOR => completion_kind::CONSTRUCTOR,
+        symbol_kind::ENUM => completion_kind::ENUM,
+        symbol_kind::INTERFACE => completion_kind::INTERFACE,
+        symbol_kind::CONSTANT => completion_kind::CONSTANT,
+        symbol_kind::ENUM_MEMBER => completion_kind::ENUM_MEMBER,
+        symbol_kind::STRUCT => completion_kind::STRUCT,
+        symbol_kind::OPERATOR => completion_kind::OPERATOR,
+        symbol_kind::TYPE_PARAMETER => completion_kind::TYPE_PARAMETER,
+        symbol_kind::VARIABLE => completion_kind::VARIABLE,
+        _ => completion_kind::FUNCTION,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn member_receiver_requires_dot_before_prefix() {
+        let text = "counter.Ne";
+        let start = identifier_start(text, text.len());
+        assert_eq!(&text[start..], "Ne");
+        assert_eq!(member_receiver(text, start), Some("counter"));
+        let plain = "let x = Ne";
+        let start = identifier_start(plain, plain.len());
+        assert_eq!(member_receiver(plain, start), None);
+    }
+}
@@ -0,0 +1,211 @@
+//! `textDocument/formatting`, `rangeFormatting` and `onTypeFormatting` support.
+//!
+//! Every request formats the whole buffer with `format::format_source` under the
+//! `FormatConfig` of the nearest `manifest.yaml`, then diffs the result against the buffer
+//! character by character so only the changed runs are sent back; untouched text (and the
+//! client's cursors in it) stays put. Range and on-type requests keep the edits that fall
+//! inside the requested lines.
+
+use std::time::Duration;
+
+use similar::{DiffTag, TextDiff};
+
+use crate::format::{FormatConfig, IndentStyle, format_source};
+use crate::manifest::Manifest;
+
+use super::types::{FormattingOptions, Position, Range, TextEdit, Uri};
+use super::{DocumentStore, offset_at, uri_path};
+
+/// Beyond this the diff falls back to coarser (still correct) edits.
+const DIFF_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Edits formatting the whole document, or `None` when the document is unknown or the
+/// formatter rejects it.
+pub(super) fn format_document(
+    store: &DocumentStore,
+    uri: &Uri,
+    options: FormattingOptions,
+) -> Option<Vec<TextEdit>> {
+    let text = &store.document(uri)?.text;
+    let config = format_config(uri, options);
+    let formatted = format_source(text, &config).ok()?.formatted;
+    Some(minimal_edits(text, &formatted))
+}
+
+/// Edits of the whole-document format that lie within `range`.
+pub(super) fn format_range(
+    store: &DocumentStore,
+    uri: &Uri,
+    range: Range,
+    options: FormattingOptions,
+) -> Option<Vec<TextEdit>> {
+    let edits = format_document(store, uri, options)?;
+    Some(
+        edits
+            .into_iter()
+            .filter(|edit| contains(range, edit.range))
+            .collect(),
+    )
+}
+
+/// Format the lines affected by typing `ch` at `position`: the block closed by `}`, or the
+/// previous and current line otherwise.
+pub(super) fn format_on_type(
+    store: &DocumentStore,
+    uri: &Uri,
+    position: Position,
+    ch: &str,
+    options: FormattingOptions,
+) -> Option<Vec<TextEdit>> {
+    let text = &store.document(uri)?.text;
+    let first_line = if ch == "}" {
+        let offset = offset_at(text, position).min(text.len());
+        block_start_line(text, offset).unwrap_or(position.line)
+    } else {
+        position.line.saturating_sub(1)
+    };
+    let range = Range::new(
+        Position::new(first_line, 0),
+        Position::new(position.line.saturating_add(1), 0),
+    );
+    format_range(store, uri, range, options)
+}
+
+/// The manifest's format settings; documents outside a project use the defaults with the
+/// client's indentation.
+fn format_config(uri: &Uri, options: FormattingOptions) -> FormatConfig {
+    if let Some(manifest) = Manifest::discover(&uri_path(uri)).ok().flatten() {
+        return manifest.format().clone();
+    }
+    let mut config = FormatConfig::default();
+    if options.tab_size > 0 {
+        config.indent = IndentStyle {
+            size: u8::try_from(options.tab_size.min(16)).unwrap_or(4),
+            use_tabs: !options.insert_spaces,
+        };
+    }
+    config
+}
+
+/// Line of the `{` matching the `}` just typed before `offset`.
+fn block_start_line(text: &str, offset: usize) -> Option<u32> {
+    let before = &text[..offset];
+    let close = before.rfind('}')?;
+    let mut depth = 0usize;
+    for (index, ch) in before[..=close].char_indices().rev() {
+        match ch {
+            '}' => depth += 1,
+            '{' => {
+                depth -= 1;
+                if depth == 0 {
+                    let line = before[..index].matches('\n').count();
+                    return u32::try_from(line).ok();
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn contains(outer: Range, inner: Range) -> bool {
+    let key = |position: Position| (position.line, position.character);
+    key(outer.start) <= key(inner.start) && key(inner.end) <= key(outer.end)
+}
+
+/// Character-level edits turning `old` into `new`, with adjacent changes merged.
+pub(super) fn minimal_edits(old: &str, new: &str) -> Vec<TextEdit> {
+    if old == new {
+        return Vec::new();
+    }
+    let diff = TextDiff::configure()
+        .timeout(DIFF_TIMEOUT)
+        .diff_chars(old, new);
+    let old_positions = char_positions(old);
+    let new_offsets: Vec<usize> = new
+        .char_indices()
+        .map(|(offset, _)| offset)
+        .chain(std::iter::once(new.len()))
+        .collect();
+
+    let mut edits = Vec::new();
+    let mut pending: Option<(usize, usize, usize, usize)> = None;
+    let mut flush = |pending: &mut Option<(usize, usize, usize, usize)>| {
+        if let Some((old_start, old_end, new_start, new_end)) = pending.take() {
+            edits.push(TextEdit {
+                range: Range::new(old_positions[old_start], old_positions[old_end]),
+                new_text: new[new_offsets[new_start]..new_offsets[new_end]].to_string(),
+            });
+        }
+    };
+    for op in diff.ops() {
+        let (tag, old_range, new_range) = op.as_tag_tuple();
+        if tag == DiffTag::Equal {
+            flush(&mut pending);
+            continue;
+        }
+        pending = Some(match pending {
+            Some((old_start, _, new_start, _)) => {
+                (old_start, old_range.end, new_start, new_range.end)
+            }
+            None => (
+                old_range.start,
+                old_range.end,
+                new_range.start,
+                new_range.end,
+            ),
+        });
+    }
+    flush(&mut pending);
+    edits
+}
+
+/// LSP position of every char boundary in `text`, including the end.
+fn char_positions(text: &str) -> Vec<Position> {
+    let mut positions = Vec::with_capacity(text.len() + 1);
+    let mut position = Position::new(0, 0);
+    for ch in text.chars() {
+        positions.push(position);
+        if ch == '\n' {
+            position = Position::new(position.line + 1, 0);
+        } else {
+            let units = u32::try_from(ch.len_utf16()).unwrap_or(1);
+            position = Position::new(position.line, position.character + units);
+        }
+    }
+    positions.push(position);
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(text: &str, edits: &[TextEdit]) -> String {
+        let mut result = text.to_string();
+        for edit in edits.iter().rev() {
+            let start = offset_at(&result, edit.range.start);
+            let end = offset_at(&result, edit.range.end);
+            result.replace_range(start..end, &edit.new_text);
+        }
+        result
+    }
+
+    #[test]
+    fn minimal_edits_touch_only_changed_runs() {
+        let old = "class A\n{\n  int x;\n    int y;\n}\n";
+        let new = "class A\n{\n    int x;\n    int y;\n}\n";
+        let edits = minimal_edits(old, new);
+        assert_eq!(edits.len(), 1, "{edits:?}");
+        assert_eq!(edits[0].range.start.line, 2);
+        assert_eq!(edits[0].new_text, "  ");
+        assert_eq!(apply(old, &edits), new);
+    }
+
+    #[test]
+    fn block_start_follows_nested_braces() {
+        let text = "fn f()\n{\n    if (x) { y(); }\n}";
+        assert_eq!(block_start_line(text, text.len()), Some(1));
+        assert_eq!(block_start_line("no braces", 9), None);
+    }
+}
@@ -0,0 +1,227 @@
+//! `textDocument/inlayHint` support.
+//!
+//! Type hints follow `let`/`var` bindings declared without a type, using the type the
+//! local was lowered with. Parameter hints precede call arguments when every overload
+//! that accepts the call's arity agrees on the parameter name; arguments that are
+//! already named, or spelled like the parameter, get no hint.
+
+use std::collections::HashMap;
+
+use crate::frontend::lexer::{Keyword, Token, TokenKind, lex_with_file};
+
+use super::signature_help::{CallKind, Overload, overloads};
+use super::types::{InlayHint, Range, Uri, inlay_hint_kind};
+use super::{DocumentAnalysis, DocumentStore, offset_at, position_at};
+
+pub(super) fn inlay_hints(
+    store: &DocumentStore,
+    uri: &Uri,
+    range: Range,
+) -> Option<Vec<InlayHint>> {
+    let text = &store.document(uri)?.text;
+    let analysis = store.analysis(uri)?;
+    let file_id = analysis.file_id(uri)?;
+    let start = offset_at(text, range.start);
+    let end = offset_at(text, range.end);
+    let tokens: Vec<Token> = lex_with_file(text, file_id)
+        .tokens
+        .into_iter()
+        .filter(|token| {
+            !matches!(
+                token.kind,
+                TokenKind::Whitespace | TokenKind::Comment | TokenKind::DocComment
+            )
+        })
+        .collect();
+
+    let mut hints = Vec::new();
+    for (offset, label) in type_hints(analysis, &tokens) {
+        hints.push((offset, label, inlay_hint_kind::TYPE));
+    }
+    for (offset, label) in parameter_hints(analysis, &tokens) {
+        hints.push((offset, label, inlay_hint_kind::PARAMETER));
+    }
+    hints.retain(|(offset, ..)| (start..=end).contains(offset));
+    hints.sort_by_key(|(offset, ..)| *offset);
+    Some(
+        hints
+            .into_iter()
+            .map(|(offset, label, kind)| InlayHint {
+                position: position_at(text, offset),
+                padding_left: kind == inlay_hint_kind::TYPE,
+                padding_right: kind == inlay_hint_kind::PARAMETER,
+                label,
+                kind,
+            })
+            .collect(),
+    )
+}
+
+fn is_binding_keyword(token: &Token) -> bool {
+    matches!(token.kind, TokenKind::Keyword(Keyword::Let | Keyword::Var))
+}
+
+/// `: Type` after the name of every `let`/`var` binding without a written type.
+fn type_hints(analysis: &DocumentAnalysis, tokens: &[Token]) -> Vec<(usize, String)> {
+    let by_start: HashMap<usize, usize> = tokens
+        .iter()
+        .enumerate()
+        .map(|(index, token)| (token.span.start, index))
+        .collect();
+    let mut hints = Vec::new();
+    for local in &analysis.locals {
+        let Some(span) = local.span.filter(|span| {
+            !local.parameter
+                && tokens
+                    .first()
+                    .is_some_and(|first| first.span.file_id == span.file_id)
+        }) else {
+            continue;
+        };
+        // Local spans cover the whole declaration statement.
+        let Some(&first) = by_start.get(&span.start) else {
+            continue;
+        };
+        if !is_binding_keyword(&tokens[first]) || local.ty.is_empty() {
+            continue;
+        }
+        let declarator = tokens[first + 1..]
+            .iter()
+            .enumerate()
+            .take_while(|(_, token)| token.span.end <= span.end)
+            .find(|(offset, token)| {
+                let previous = &tokens[first + offset];
+                token.kind == TokenKind::Identifier
+                    && token.lexeme.trim_matches('`') == local.name
+                    && (is_binding_keyword(previous)
+                        || previous.kind == TokenKind::Punctuation(','))
+            });
+        let Some((offset, name)) = declarator else {
+            continue;
+        };
+        let annotated = tokens
+            .get(first + offset + 2)
+            .is_some_and(|next| next.kind == TokenKind::Punctuation(':'));
+        if !annotated {
+            hints.push((name.span.end, format!(": {}", local.ty)));
+        }
+    }
+    hints
+}
+
+/// `name:` before call arguments.
+fn parameter_hints(analysis: &DocumentAnalysis, tokens: &[Token]) -> Vec<(usize, String)> {
+    let declaration_sites: Vec<usize> = analysis
+        .declarations
+        .iter()
+        .filter(|decl| {
+            tokens
+                .first()
+                .is_some_and(|first| first.span.file_id == decl.selection.file_id)
+        })
+        .map(|decl| decl.selection.start)
+        .collect();
+    let mut cache: HashMap<(String, CallKind), Vec<Overload>> = HashMap::new();
+    let mut hints = Vec::new();
+    for (index, callee) in tokens.iter().enumerate() {
+        if callee.kind != TokenKind::Identifier
+            || tokens.get(index + 1).map(|token| &token.kind) != Some(&TokenKind::Punctuation('('))
+            || declaration_sites.contains(&callee.span.start)
+        {
+            continue;
+        }
+        let kind = match index.checked_sub(1).map(|previous| &tokens[previous].kind) {
+            Some(TokenKind::Punctuation('.') | TokenKind::Operator("?.")) => CallKind::Method,
+            Some(TokenKind::Keyword(Keyword::New)) => CallKind::Constructor,
+            // A preceding type name makes this a declaration rather than a call.
+            Some(TokenKind::Identifier | TokenKind::Punctuation('>' | ']' | '?')) => continue,
+            _ => CallKind::Function,
+        };
+        let arguments = arguments(&tokens[index + 2..]);
+        if arguments.is_empty() {
+            continue;
+        }
+        let name = callee.lexeme.trim_matches('`').to_string();
+        let candidates = cache
+            .entry((name.clone(), kind))
+            .or_insert_with(|| overloads(analysis, &name, kind));
+        let matching: Vec<&Overload> = candidates
+            .iter()
+            .filter(|overload| overload.accepts(arguments.len()))
+            .collect();
+        if matching.is_empty() {
+            continue;
+        }
+        for (position, argument) in arguments.iter().enumerate() {
+            let mut names = matching
+                .iter()
+                .map(|overload| overload.params.get(position).map(|(name, _)| name.as_str()));
+            let Some(Some(param)) = names.next() else {
+                continue;
+            };
+            if !names.all(|other| other == Some(param)) {
+                continue;
+            }
+            let named = argument.len() > 1
+                && argument[0].kind == TokenKind::Identifier
+                && argument[1].kind == TokenKind::Punctuation(':');
+            let same_name = argument.len() == 1 && argument[0].lexeme.trim_matches('`') == param;
+            if !named && !same_name {
+                hints.push((argument[0].span.start, format!("{param}:")));
+            }
+        }
+    }
+    hints
+}
+
+/// Split the tokens after an opening parenthesis into top-level arguments.
+fn arguments(tokens: &[Token]) -> Vec<&[Token]> {
+    let mut arguments = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, token) in tokens.iter().enumerate() {
+        match token.kind {
+            TokenKind::Punctuation('(' | '[' | '{') => depth += 1,
+            TokenKind::Punctuation(')' | ']' | '}') if depth > 0 => depth -= 1,
+            TokenKind::Punctuation(')') => {
+                if index > start {
+                    arguments.push(&tokens[start..index]);
+                }
+                return arguments;
+            }
+            TokenKind::Punctuation(',') if depth == 0 => {
+                arguments.push(&tokens[start..index]);
+                start = index + 1;
+            }
+            TokenKind::Punctuation(';') if depth == 0 => break,
+            _ => {}
+        }
+    }
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::lexer::lex;
+
+    fn significant(source: &str) -> Vec<Token> {
+        lex(source)
+            .tokens
+            .into_iter()
+            .filter(|token| token.kind != TokenKind::Whitespace)
+            .collect()
+    }
+
+    #[test]
+    fn arguments_split_at_top_level_commas() {
+        let tokens = significant("Add(1, Scale(2, 3), [4, 5]);");
+        let arguments = arguments(&tokens[2..]);
+        let texts: Vec<String> = arguments
+            .iter()
+            .map(|argument| argument.iter().map(|token| token.lexeme.as_str()).collect())
+            .collect();
+        assert_eq!(texts, vec!["1", "Scale(2,3)", "[4,5]"]);
+        assert!(super::arguments(&significant("Add(1, 2")[2..]).is_empty());
+    }
+}
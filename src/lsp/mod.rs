@@ -2,35 +2,57 @@
 #![allow(clippy::module_name_repetitions)]
 #![allow(clippy::needless_pass_by_value)]
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io::{BufReader, BufWriter, Write};
 use std::path::{Path, PathBuf};
 
+mod code_actions;
+mod completion;
+mod formatting;
+mod inlay_hints;
+mod navigation;
 mod rpc;
+mod semantic_tokens;
+mod signature_help;
+mod symbols;
 mod types;
+mod workspace;
 
+use self::code_actions::Fix;
 use self::rpc::{IncomingMessage, Notification as RpcNotification, Request as RpcRequest};
+use self::symbols::{Declaration, LocalBinding};
 use self::types::{
+    CodeActionOptions, CodeActionParams, CompletionOptions, CompletionParams,
     Diagnostic as LspDiagnostic, DiagnosticRelatedInformation, DidChangeTextDocumentParams,
-    DidCloseTextDocumentParams, DidOpenTextDocumentParams, GotoDefinitionParams, Hover,
-    HoverParams, InitializeResult, Location, MarkupContent, MarkupKind, NumberOrString, Position,
-    PublishDiagnosticsParams, Range, ServerCapabilities, ServerInfo, Uri,
+    DidChangeWatchedFilesParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    DocumentFormattingParams, DocumentOnTypeFormattingOptions, DocumentOnTypeFormattingParams,
+    DocumentRangeFormattingParams, DocumentSymbolParams, GotoDefinitionParams, Hover, HoverParams,
+    InitializeResult, InlayHintParams, Location, MarkupContent, MarkupKind, NumberOrString,
+    Position, PrepareRenameParams, PublishDiagnosticsParams, Range, ReferenceParams, RenameOptions,
+    RenameParams, SemanticTokens, SemanticTokensDelta, SemanticTokensDeltaParams,
+    SemanticTokensDeltaResult, SemanticTokensFullOptions, SemanticTokensOptions,
+    SemanticTokensParams, ServerCapabilities, ServerInfo, SignatureHelpOptions,
+    SignatureHelpParams, Uri, WorkspaceSymbolParams,
 };
+use self::workspace::Project;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 use url::Url;
 
 use crate::chic_kind::ChicKind;
 use crate::defines::DefineFlag;
-use crate::diagnostics::{Diagnostic, FileCache, FileId, LineCol, Severity, Span};
-use crate::driver::{CompilerDriver, FrontendReport};
+use crate::diagnostics::{Diagnostic, FileCache, FileId, LineCol, Severity, Span, Suggestion};
+use crate::driver::{CheckProject, CompilerDriver, FrontendReport, ModuleState};
 use crate::frontend::lexer::{TokenKind, lex_with_file};
 use crate::lint::{LintDiagnostic, LintLevel};
 use crate::logging::LogLevel;
-use crate::mir::MirFunction;
+use crate::manifest::Manifest;
+use crate::mir::{MirFunction, SymbolIndex};
 use crate::target::Target;
 
+const INVALID_REQUEST: i32 = -32600;
 const METHOD_NOT_FOUND: i32 = -32601;
+const INVALID_PARAMS: i32 = -32602;
 
 struct Document {
     text: String,
@@ -81,27 +103,94 @@ struct SemanticSymbol {
 struct DocumentAnalysis {
     files: FileCache,
     symbols: Vec<SemanticSymbol>,
+    /// Files that were parsed as part of this analysis.
+    sources: Vec<FileId>,
+    declarations: Vec<Declaration>,
+    locals: Vec<LocalBinding>,
+    indices: Vec<SymbolIndex>,
+    /// Per-file fingerprints of this run; the next run skips body checks of the files
+    /// the edit did not affect.
+    module_states: Vec<ModuleState>,
 }
 
+impl DocumentAnalysis {
+    fn file_id(&self, uri: &Uri) -> Option<FileId> {
+        self.files.find_id_by_path(&uri_path(uri))
+    }
+
+    fn source(&self, file_id: FileId) -> Option<&str> {
+        self.files.get(file_id).map(|file| file.source.as_str())
+    }
+
+    fn uri(&self, file_id: FileId) -> Option<Uri> {
+        self.files.path(file_id).and_then(file_path_to_uri)
+    }
+}
+
+/// Key of an analysis unit: the project manifest path, or the document path for
+/// documents outside any project.
+type UnitKey = PathBuf;
+
 #[derive(Default)]
 struct DocumentStore {
     documents: HashMap<Uri, Document>,
-    analysis: HashMap<Uri, DocumentAnalysis>,
+    /// Analysis unit of every open document.
+    units: HashMap<Uri, UnitKey>,
+    projects: HashMap<UnitKey, Project>,
+    analysis: HashMap<UnitKey, DocumentAnalysis>,
+    /// Documents that received diagnostics from each unit's last run.
+    published: HashMap<UnitKey, HashSet<Uri>>,
+    /// Quick fixes for the diagnostics last published to each document.
+    fixes: HashMap<Uri, Vec<Fix>>,
+    /// Last semantic tokens sent per document, keyed by result id, for delta requests.
+    semantic_tokens: HashMap<Uri, (String, Vec<u32>)>,
+    next_result_id: u64,
     files: FileCache,
 }
 
 impl DocumentStore {
     fn open(&mut self, uri: Uri, text: String, version: i32) {
         let path = uri_path(&uri);
-        let file_id = self.files.add_file(path, text.clone());
+        let file_id = self.files.add_file(path.clone(), text.clone());
         let document = Document::new(text, version, file_id);
         self.documents.insert(uri.clone(), document);
-        self.analysis.remove(&uri);
+        let unit = self.unit_for(&path);
+        self.units.insert(uri, unit);
     }
 
-    fn close(&mut self, uri: &Uri) {
+    /// Close a document, returning the documents whose diagnostics must be cleared.
+    fn close(&mut self, uri: &Uri) -> Vec<Uri> {
         self.documents.remove(uri);
-        self.analysis.remove(uri);
+        let mut cleared = vec![uri.clone()];
+        if let Some(unit) = self.units.remove(uri) {
+            if !self.units.values().any(|other| *other == unit) {
+                self.analysis.remove(&unit);
+                cleared.extend(self.published.remove(&unit).unwrap_or_default());
+            }
+        }
+        cleared.sort();
+        cleared.dedup();
+        for uri in &cleared {
+            self.fixes.remove(uri);
+        }
+        self.semantic_tokens.remove(uri);
+        cleared
+    }
+
+    fn unit_for(&mut self, path: &Path) -> UnitKey {
+        let Some(manifest) = Manifest::discover(path).ok().flatten() else {
+            return path.to_path_buf();
+        };
+        let Some(key) = manifest.path().map(Path::to_path_buf) else {
+            return path.to_path_buf();
+        };
+        if !self.projects.contains_key(&key) {
+            let Some(project) = Project::load(manifest) else {
+                return path.to_path_buf();
+            };
+            self.projects.insert(key.clone(), project);
+        }
+        key
     }
 
     fn with_document_mut<F>(&mut self, uri: &Uri, op: F)
@@ -114,16 +203,187 @@ impl DocumentStore {
         }
     }
 
-    fn diagnostics(&mut self, uri: &Uri) -> Option<Vec<LspDiagnostic>> {
-        let doc = self.documents.get(uri)?;
-        let (diags, files, symbols) = pipeline_diagnostics_for(uri, doc);
-        let converted: Vec<LspDiagnostic> = diags
-            .into_iter()
-            .map(|diag| convert_diagnostic(diag, &files))
+    /// Reload projects whose manifest or lock changed on disk.
+    fn reload_stale_projects(&mut self) {
+        let stale: Vec<UnitKey> = self
+            .projects
+            .iter()
+            .filter(|(_, project)| project.is_stale())
+            .map(|(key, _)| key.clone())
             .collect();
-        let analysis = DocumentAnalysis { files, symbols };
-        self.analysis.insert(uri.clone(), analysis);
-        Some(converted)
+        for key in stale {
+            self.reload_project(&key);
+        }
+    }
+
+    /// Re-resolve a project; its incremental state is dropped because the dependency
+    /// graph (and every surface it contributes) may have changed. Documents of a project
+    /// whose manifest disappeared fall back to standalone units.
+    fn reload_project(&mut self, key: &UnitKey) {
+        if let Some(analysis) = self.analysis.get_mut(key) {
+            analysis.module_states.clear();
+        }
+        if let Some(project) = self.projects.get(key).and_then(Project::reload) {
+            self.projects.insert(key.clone(), project);
+            return;
+        }
+        self.projects.remove(key);
+        self.analysis.remove(key);
+        for (uri, unit) in &mut self.units {
+            if unit == key {
+                *unit = uri_path(uri);
+            }
+        }
+    }
+
+    /// A watched manifest or lock changed: reload the projects loaded from it and return
+    /// one open document per affected unit to re-analyse.
+    fn project_file_changed(&mut self, path: &Path) -> Vec<Uri> {
+        let affected: Vec<UnitKey> = self
+            .projects
+            .iter()
+            .filter(|(_, project)| project.is_project_file(path))
+            .map(|(key, _)| key.clone())
+            .collect();
+        let mut triggers = Vec::new();
+        for key in affected {
+            let documents = self.unit_documents(&key);
+            self.reload_project(&key);
+            triggers.extend(documents.into_iter().next());
+        }
+        triggers
+    }
+
+    /// Re-analyse the unit owning `uri` and every project that depends on it, returning
+    /// the diagnostics to publish per document.
+    fn refresh(&mut self, uri: &Uri) -> Vec<(Uri, Vec<LspDiagnostic>)> {
+        self.reload_stale_projects();
+        let Some(unit) = self.units.get(uri).cloned() else {
+            return Vec::new();
+        };
+        let path = uri_path(uri);
+        let mut dependents: Vec<UnitKey> = self
+            .units
+            .values()
+            .filter(|other| {
+                **other != unit
+                    && self
+                        .projects
+                        .get(*other)
+                        .is_some_and(|project| project.depends_on(&path))
+            })
+            .cloned()
+            .collect();
+        dependents.sort();
+        dependents.dedup();
+
+        let mut publications = self.analyse_unit(&unit, uri);
+        for dependent in dependents {
+            let Some(trigger) = self.unit_documents(&dependent).into_iter().next() else {
+                continue;
+            };
+            publications.extend(self.analyse_unit(&dependent, &trigger));
+        }
+        publications
+    }
+
+    fn unit_documents(&self, unit: &UnitKey) -> Vec<Uri> {
+        let mut uris: Vec<Uri> = self
+            .units
+            .iter()
+            .filter(|(_, other)| *other == unit)
+            .map(|(uri, _)| uri.clone())
+            .collect();
+        uris.sort();
+        uris
+    }
+
+    fn analyse_unit(&mut self, unit: &UnitKey, trigger: &Uri) -> Vec<(Uri, Vec<LspDiagnostic>)> {
+        let open = self.unit_documents(unit);
+        let previous = self
+            .analysis
+            .get(unit)
+            .map(|analysis| analysis.module_states.clone())
+            .unwrap_or_default();
+        let (diags, analysis) = analyse(
+            self.projects.get(unit),
+            unit,
+            &open,
+            &self.documents,
+            previous,
+        );
+
+        let project_root = self.projects.get(unit).map(|project| project.root.clone());
+        let manifest = self
+            .projects
+            .get(unit)
+            .and_then(|project| project.manifest.path());
+        let open_text = |uri: &Uri| self.documents.get(uri).map(|doc| doc.text.clone());
+        let mut fixes: HashMap<Uri, Vec<Fix>> = HashMap::new();
+        let mut grouped: BTreeMap<Uri, Vec<LspDiagnostic>> =
+            open.iter().map(|uri| (uri.clone(), Vec::new())).collect();
+        for diag in diags {
+            let file_id = diag
+                .primary_label
+                .as_ref()
+                .map(|label| label.span.file_id)
+                .filter(|file_id| analysis.files.get(*file_id).is_some());
+            let target = match file_id {
+                None => Some(trigger.clone()),
+                Some(file_id) => analysis.uri(file_id).filter(|uri| {
+                    // Diagnostics in dependencies and the standard library are reported
+                    // by their own units; unopened project files are reported here.
+                    match self.units.get(uri) {
+                        Some(owner) => owner == unit,
+                        None => project_root.as_ref().is_some_and(|root| {
+                            uri_to_file_path(uri).is_some_and(|path| path.starts_with(root))
+                        }),
+                    }
+                }),
+            };
+            if let Some(target) = target {
+                let suggestions = diag.suggestions.clone();
+                let converted = convert_diagnostic(diag, &analysis.files);
+                fixes
+                    .entry(target.clone())
+                    .or_default()
+                    .extend(code_actions::fixes_for(
+                        &suggestions,
+                        &converted,
+                        &analysis.files,
+                        manifest,
+                        open_text,
+                    ));
+                grouped.entry(target).or_default().push(converted);
+            }
+        }
+
+        let previous = self.published.remove(unit).unwrap_or_default();
+        for stale in previous {
+            grouped.entry(stale).or_default();
+        }
+        let published = grouped
+            .iter()
+            .filter(|(_, diags)| !diags.is_empty())
+            .map(|(uri, _)| uri.clone())
+            .collect();
+        self.published.insert(unit.clone(), published);
+        for uri in grouped.keys() {
+            let fixes = fixes.remove(uri).unwrap_or_default();
+            self.fixes.insert(uri.clone(), fixes);
+        }
+
+        // A failed frontend run carries no semantic data; keep answering navigation
+        // queries from the last successful analysis until the unit parses again.
+        let keep_previous = analysis.indices.is_empty()
+            && self
+                .analysis
+                .get(unit)
+                .is_some_and(|previous| !previous.indices.is_empty());
+        if !keep_previous {
+            self.analysis.insert(unit.clone(), analysis);
+        }
+        grouped.into_iter().collect()
     }
 
     fn document(&self, uri: &Uri) -> Option<&Document> {
@@ -135,7 +395,31 @@ impl DocumentStore {
     }
 
     fn analysis(&self, uri: &Uri) -> Option<&DocumentAnalysis> {
-        self.analysis.get(uri)
+        self.analysis.get(self.units.get(uri)?)
+    }
+
+    fn analyses(&self) -> impl Iterator<Item = &DocumentAnalysis> {
+        self.analysis.values()
+    }
+
+    /// Record the tokens sent for `uri`, returning their result id.
+    fn remember_tokens(&mut self, uri: &Uri, data: Vec<u32>) -> String {
+        self.next_result_id += 1;
+        let result_id = self.next_result_id.to_string();
+        self.semantic_tokens
+            .insert(uri.clone(), (result_id.clone(), data));
+        result_id
+    }
+
+    fn previous_tokens(&self, uri: &Uri, result_id: &str) -> Option<&[u32]> {
+        self.semantic_tokens
+            .get(uri)
+            .filter(|(id, _)| id == result_id)
+            .map(|(_, data)| data.as_slice())
+    }
+
+    fn fixes(&self, uri: &Uri) -> &[Fix] {
+        self.fixes.get(uri).map_or(&[], Vec::as_slice)
     }
 
     fn files(&self) -> &FileCache {
@@ -165,6 +449,19 @@ fn offset_at(text: &str, position: Position) -> usize {
     offset.saturating_add(line.len())
 }
 
+/// Inverse of [`offset_at`]: the UTF-16 position of byte `offset` in `text`.
+fn position_at(text: &str, offset: usize) -> Position {
+    let offset = offset.min(text.len());
+    let before = &text[..offset];
+    let line = before.matches('\n').count();
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    let character = before[line_start..].encode_utf16().count();
+    Position::new(
+        u32::try_from(line).unwrap_or(u32::MAX),
+        u32::try_from(character).unwrap_or(u32::MAX),
+    )
+}
+
 fn find_symbol<'a>(lexeme: &str, symbols: &'a [SemanticSymbol]) -> Option<&'a SemanticSymbol> {
     let trimmed = lexeme.trim_matches('`');
     symbols.iter().find(|symbol| {
@@ -238,42 +535,87 @@ fn collect_semantic_symbols(report: &FrontendReport) -> Vec<SemanticSymbol> {
     symbols
 }
 
-fn pipeline_diagnostics_for(
-    uri: &Uri,
-    doc: &Document,
-) -> (Vec<Diagnostic>, FileCache, Vec<SemanticSymbol>) {
-    let mut pre_files = FileCache::default();
-    let original_path = uri_to_file_path(uri).unwrap_or_else(|| PathBuf::from(uri.as_str()));
-    let _file_id = pre_files.add_file(original_path.clone(), doc.text.clone());
+/// Run the frontend for one analysis unit. Project units check every source of the root
+/// package and load its resolved dependencies; open buffers overlay the files on disk.
+/// `previous` holds the fingerprints of the unit's last run, so only the files an edit
+/// affects get their bodies checked again.
+fn analyse(
+    project: Option<&Project>,
+    unit: &UnitKey,
+    open: &[Uri],
+    documents: &HashMap<Uri, Document>,
+    previous: Vec<ModuleState>,
+) -> (Vec<Diagnostic>, DocumentAnalysis) {
+    let overlays: HashMap<PathBuf, String> = documents
+        .iter()
+        .map(|(uri, doc)| (workspace::canonical(&uri_path(uri)), doc.text.clone()))
+        .collect();
+    // Pipeline paths are canonical; map them back to the spelling the client uses.
+    let client_paths: HashMap<PathBuf, PathBuf> = documents
+        .keys()
+        .map(|uri| {
+            let path = uri_path(uri);
+            (workspace::canonical(&path), path)
+        })
+        .collect();
 
-    let tempdir = tempfile::tempdir().expect("create temp dir for lsp analysis");
-    let filename = uri_filename(uri).unwrap_or_else(|| PathBuf::from("main.ch"));
-    let path = tempdir.path().join(filename);
-    std::fs::write(&path, &doc.text).unwrap_or_default();
+    let mut inputs = match project {
+        Some(project) => project.source_files(),
+        None => vec![workspace::canonical(unit)],
+    };
+    for uri in open {
+        let path = workspace::canonical(&uri_path(uri));
+        if !inputs.contains(&path) {
+            inputs.push(path);
+        }
+    }
+    let check_project = CheckProject {
+        manifest: project.map(|project| project.manifest.clone()),
+        workspace: project.and_then(|project| project.workspace.clone()),
+        overlays,
+        read_only_packages: true,
+        incremental: Some(previous),
+    };
+    let kind = project.map_or(ChicKind::Executable, Project::kind);
 
     let driver = CompilerDriver::new();
     let target = Target::host();
-    let report = driver.check(
-        &[path.clone()],
+    let report = driver.check_project(
+        &inputs,
         &target,
-        ChicKind::Executable,
-        false,
+        kind,
         false,
         false,
         &Vec::<DefineFlag>::new(),
         LogLevel::Error,
+        check_project,
     );
 
     match report {
         Ok(report) => {
             let symbols = collect_semantic_symbols(&report);
+            let declarations = collect_module_declarations(&report);
+            let locals = symbols::collect_local_bindings(&report.mir_module, &report.files);
+            let indices = report
+                .modules
+                .iter()
+                .map(|module| SymbolIndex::build(&module.parse.module))
+                .collect();
+            // Only user code takes part in references and rename; the standard library
+            // modules the frontend loads alongside it are left out.
+            let sources = report
+                .modules
+                .iter()
+                .filter(|module| {
+                    let input = workspace::canonical(&module.input);
+                    inputs.contains(&input)
+                        || project.is_some_and(|project| project.depends_on(&input))
+                })
+                .map(|module| module.parse.file_id)
+                .collect();
+            let module_states = report.module_states;
             let mut files = report.files;
-            if let Some(id) = files.find_id_by_path(&path) {
-                files.update_path(
-                    id,
-                    uri_to_file_path(uri).unwrap_or_else(|| PathBuf::from(uri.as_str())),
-                );
-            }
+            remap_client_paths(&mut files, &client_paths);
             let mut diags = Vec::new();
             for module in report.modules {
                 diags.extend(module.parse.diagnostics);
@@ -291,31 +633,90 @@ fn pipeline_diagnostics_for(
             diags.extend(report.format_diagnostics);
             diags.extend(report.doc_diagnostics);
             diags.extend(report.lint_diagnostics.into_iter().map(lint_to_diagnostic));
-            (diags, files, symbols)
+            let analysis = DocumentAnalysis {
+                files,
+                symbols,
+                sources,
+                declarations,
+                locals,
+                indices,
+                module_states,
+            };
+            (diags, analysis)
         }
         Err(err) => {
-            if let crate::error::Error::Parse(parse) = &err {
-                return (parse.diagnostics().to_vec(), pre_files, Vec::new());
+            let (mut files, diags) = match &err {
+                crate::error::Error::Parse(parse) => {
+                    (parse.files().clone(), parse.diagnostics().to_vec())
+                }
+                _ => {
+                    let mut diag = Diagnostic::error(
+                        format!("lsp analysis failed: {err}"),
+                        Some(Span::new(0, 0)),
+                    );
+                    diag.severity = Severity::Error;
+                    (FileCache::default(), vec![diag])
+                }
+            };
+            remap_client_paths(&mut files, &client_paths);
+            for uri in open {
+                let path = uri_path(uri);
+                if files.find_id_by_path(&path).is_none() {
+                    if let Some(doc) = documents.get(uri) {
+                        files.add_file(path, doc.text.clone());
+                    }
+                }
             }
-            let mut diag =
-                Diagnostic::error(format!("lsp analysis failed: {err}"), Some(Span::new(0, 0)));
-            diag.severity = Severity::Error;
-            (vec![diag], pre_files, Vec::new())
+            let sources = (0..)
+                .map(FileId)
+                .take_while(|file_id| files.get(*file_id).is_some())
+                .collect();
+            let analysis = DocumentAnalysis {
+                files,
+                sources,
+                ..DocumentAnalysis::default()
+            };
+            (diags, analysis)
         }
     }
 }
 
-fn uri_path(uri: &Uri) -> PathBuf {
-    uri_to_file_path(uri).unwrap_or_else(|| PathBuf::from(uri.as_str()))
+fn remap_client_paths(files: &mut FileCache, client_paths: &HashMap<PathBuf, PathBuf>) {
+    let mut file_id = FileId(0);
+    while let Some(path) = files.path(file_id) {
+        if let Some(client) = client_paths.get(&workspace::canonical(path)) {
+            let client = client.clone();
+            files.update_path(file_id, client);
+        }
+        file_id = FileId(file_id.0 + 1);
+    }
 }
 
-fn uri_filename(uri: &Uri) -> Option<PathBuf> {
-    let parsed = Url::parse(uri.as_str()).ok()?;
-    parsed
-        .path_segments()
-        .and_then(|segments| segments.last())
-        .filter(|name| !name.is_empty())
-        .map(PathBuf::from)
+fn collect_module_declarations(report: &FrontendReport) -> Vec<Declaration> {
+    let mut declarations = Vec::new();
+    for module in &report.modules {
+        let Some(source) = report.files.get(module.parse.file_id) else {
+            continue;
+        };
+        let offset = declarations.len();
+        declarations.extend(
+            symbols::collect_declarations(
+                &module.parse.module,
+                &source.source,
+                module.parse.file_id,
+            )
+            .into_iter()
+            .map(|mut decl| {
+                decl.parent = decl.parent.map(|parent| parent + offset);
+                decl
+            }),
+        );
+    }
+    declarations
+}
+
+fn uri_path(uri: &Uri) -> PathBuf {
+    uri_to_file_path(uri).unwrap_or_else(|| PathBuf::from(uri.as_str()))
 }
 
 fn uri_to_file_path(uri: &Uri) -> Option<PathBuf> {
@@ -394,6 +795,13 @@ fn lint_to_diagnostic(lint: LintDiagnostic) -> Diagnostic {
         lint.code(),
         Some("lint".into()),
     ));
+    for suggestion in lint.suggestions {
+        diagnostic.add_suggestion(Suggestion::new(
+            suggestion.message,
+            suggestion.span,
+            suggestion.replacement,
+        ));
+    }
     diagnostic
 }
 
@@ -495,6 +903,20 @@ where
     serde_json::from_value(value).ok()
 }
 
+/// Serialise an optional request result, mapping `None` to a JSON `null` response.
+fn to_result<T>(result: Option<T>, what: &str) -> Result<Value, String>
+where
+    T: serde::Serialize,
+{
+    result
+        .map(|value| {
+            serde_json::to_value(value)
+                .map_err(|err| format!("failed to serialise {what} response: {err}"))
+        })
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
 fn publish_diagnostics(
     writer: &mut impl Write,
     uri: &Uri,
@@ -509,9 +931,21 @@ fn publish_diagnostics(
     rpc::send_notification(writer, types::methods::PUBLISH_DIAGNOSTICS, &params)
 }
 
+fn publish_refresh(
+    writer: &mut impl Write,
+    store: &mut DocumentStore,
+    uri: &Uri,
+) -> Result<(), String> {
+    for (target, diagnostics) in store.refresh(uri) {
+        let version = store.version(&target);
+        publish_diagnostics(writer, &target, version, diagnostics)?;
+    }
+    Ok(())
+}
+
 fn handle_request(
     writer: &mut impl Write,
-    store: &DocumentStore,
+    store: &mut DocumentStore,
     request: RpcRequest,
 ) -> Result<bool, String> {
     match request.method.as_str() {
@@ -547,6 +981,167 @@ fn handle_request(
             rpc::send_response(writer, request.id, result)?;
             Ok(false)
         }
+        types::methods::COMPLETION => {
+            let result = parse_params::<CompletionParams>(request.params).map(|params| {
+                completion::completion(store, &params.text_document.uri, params.position)
+            });
+            rpc::send_response(writer, request.id, to_result(result, "completion")?)?;
+            Ok(false)
+        }
+        types::methods::REFERENCES => {
+            let result = parse_params::<ReferenceParams>(request.params).map(|params| {
+                navigation::references(
+                    store,
+                    &params.text_document.uri,
+                    params.position,
+                    params.context.include_declaration,
+                )
+            });
+            rpc::send_response(writer, request.id, to_result(result, "references")?)?;
+            Ok(false)
+        }
+        types::methods::PREPARE_RENAME => {
+            let result = parse_params::<PrepareRenameParams>(request.params).and_then(|params| {
+                navigation::prepare_rename(store, &params.text_document.uri, params.position)
+            });
+            rpc::send_response(writer, request.id, to_result(result, "prepareRename")?)?;
+            Ok(false)
+        }
+        types::methods::RENAME => {
+            let Some(params) = parse_params::<RenameParams>(request.params) else {
+                rpc::send_error_response(
+                    writer,
+                    request.id,
+                    INVALID_PARAMS,
+                    String::from("invalid rename parameters"),
+                )?;
+                return Ok(false);
+            };
+            match navigation::rename(
+                store,
+                &params.text_document.uri,
+                params.position,
+                &params.new_name,
+            ) {
+                Ok(edit) => {
+                    rpc::send_response(writer, request.id, to_result(edit, "rename")?)?;
+                }
+                Err(message) => {
+                    rpc::send_error_response(writer, request.id, INVALID_PARAMS, message)?;
+                }
+            }
+            Ok(false)
+        }
+        types::methods::DOCUMENT_SYMBOL => {
+            let result = parse_params::<DocumentSymbolParams>(request.params).map(|params| {
+                store
+                    .analysis(&params.text_document.uri)
+                    .map(|analysis| {
+                        navigation::document_symbols(analysis, &params.text_document.uri)
+                    })
+                    .unwrap_or_default()
+            });
+            rpc::send_response(writer, request.id, to_result(result, "documentSymbol")?)?;
+            Ok(false)
+        }
+        types::methods::WORKSPACE_SYMBOL => {
+            let result = parse_params::<WorkspaceSymbolParams>(request.params)
+                .map(|params| navigation::workspace_symbols(store, &params.query));
+            rpc::send_response(writer, request.id, to_result(result, "workspace/symbol")?)?;
+            Ok(false)
+        }
+        types::methods::CODE_ACTION => {
+            let result = parse_params::<CodeActionParams>(request.params)
+                .map(|params| code_actions::code_actions(store, &params));
+            rpc::send_response(writer, request.id, to_result(result, "codeAction")?)?;
+            Ok(false)
+        }
+        types::methods::FORMATTING => {
+            let result =
+                parse_params::<DocumentFormattingParams>(request.params).and_then(|params| {
+                    formatting::format_document(store, &params.text_document.uri, params.options)
+                });
+            rpc::send_response(writer, request.id, to_result(result, "formatting")?)?;
+            Ok(false)
+        }
+        types::methods::RANGE_FORMATTING => {
+            let result =
+                parse_params::<DocumentRangeFormattingParams>(request.params).and_then(|params| {
+                    formatting::format_range(
+                        store,
+                        &params.text_document.uri,
+                        params.range,
+                        params.options,
+                    )
+                });
+            rpc::send_response(writer, request.id, to_result(result, "rangeFormatting")?)?;
+            Ok(false)
+        }
+        types::methods::ON_TYPE_FORMATTING => {
+            let result =
+                parse_params::<DocumentOnTypeFormattingParams>(request.params).and_then(|params| {
+                    formatting::format_on_type(
+                        store,
+                        &params.text_document.uri,
+                        params.position,
+                        &params.ch,
+                        params.options,
+                    )
+                });
+            rpc::send_response(writer, request.id, to_result(result, "onTypeFormatting")?)?;
+            Ok(false)
+        }
+        types::methods::SEMANTIC_TOKENS_FULL => {
+            let result = parse_params::<SemanticTokensParams>(request.params).and_then(|params| {
+                let uri = params.text_document.uri;
+                let data = semantic_tokens::encode(store, &uri)?;
+                let result_id = store.remember_tokens(&uri, data.clone());
+                Some(SemanticTokens {
+                    result_id: Some(result_id),
+                    data,
+                })
+            });
+            rpc::send_response(writer, request.id, to_result(result, "semanticTokens")?)?;
+            Ok(false)
+        }
+        types::methods::SEMANTIC_TOKENS_DELTA => {
+            let result =
+                parse_params::<SemanticTokensDeltaParams>(request.params).and_then(|params| {
+                    let uri = params.text_document.uri;
+                    let data = semantic_tokens::encode(store, &uri)?;
+                    let edits = store
+                        .previous_tokens(&uri, &params.previous_result_id)
+                        .map(|previous| semantic_tokens::delta(previous, &data));
+                    let result_id = Some(store.remember_tokens(&uri, data.clone()));
+                    Some(match edits {
+                        Some(edits) => SemanticTokensDeltaResult::Delta(SemanticTokensDelta {
+                            result_id,
+                            edits,
+                        }),
+                        None => SemanticTokensDeltaResult::Full(SemanticTokens { result_id, data }),
+                    })
+                });
+            rpc::send_response(
+                writer,
+                request.id,
+                to_result(result, "semanticTokens/delta")?,
+            )?;
+            Ok(false)
+        }
+        types::methods::INLAY_HINT => {
+            let result = parse_params::<InlayHintParams>(request.params).and_then(|params| {
+                inlay_hints::inlay_hints(store, &params.text_document.uri, params.range)
+            });
+            rpc::send_response(writer, request.id, to_result(result, "inlayHint")?)?;
+            Ok(false)
+        }
+        types::methods::SIGNATURE_HELP => {
+            let result = parse_params::<SignatureHelpParams>(request.params).and_then(|params| {
+                signature_help::signature_help(store, &params.text_document.uri, params.position)
+            });
+            rpc::send_response(writer, request.id, to_result(result, "signatureHelp")?)?;
+            Ok(false)
+        }
         _ => {
             rpc::send_error_response(
                 writer,
@@ -571,10 +1166,7 @@ fn handle_notification(
                 let version = params.text_document.version;
                 store.open(params.text_document.uri.clone(), text, version);
                 let uri = params.text_document.uri;
-                if let Some(diags) = store.diagnostics(&uri) {
-                    let version = store.version(&uri);
-                    publish_diagnostics(writer, &uri, version, diags)?;
-                }
+                publish_refresh(writer, store, &uri)?;
             }
             Ok(false)
         }
@@ -584,17 +1176,25 @@ fn handle_notification(
                 store.with_document_mut(&uri, |doc| {
                     doc.apply_change(&params);
                 });
-                if let Some(diags) = store.diagnostics(&uri) {
-                    let version = store.version(&uri);
-                    publish_diagnostics(writer, &uri, version, diags)?;
-                }
+                publish_refresh(writer, store, &uri)?;
             }
             Ok(false)
         }
         types::methods::DID_CLOSE => {
             if let Some(params) = parse_params::<DidCloseTextDocumentParams>(notification.params) {
-                store.close(&params.text_document.uri);
-                publish_diagnostics(writer, &params.text_document.uri, None, Vec::new())?;
+                for uri in store.close(&params.text_document.uri) {
+                    publish_diagnostics(writer, &uri, None, Vec::new())?;
+                }
+            }
+            Ok(false)
+        }
+        types::methods::DID_CHANGE_WATCHED_FILES => {
+            if let Some(params) = parse_params::<DidChangeWatchedFilesParams>(notification.params) {
+                for change in params.changes {
+                    for uri in store.project_file_changed(&uri_path(&change.uri)) {
+                        publish_refresh(writer, store, &uri)?;
+                    }
+                }
             }
             Ok(false)
         }
@@ -637,11 +1237,20 @@ pub fn run_stdio(initialization: InitializeResult) -> Result<(), String> {
     let mut store = DocumentStore::default();
     let mut shutdown_requested = false;
 
+    // After `shutdown` the client still sends `exit`; keep reading until it arrives so the
+    // client never writes into a closed pipe.
     while let Some(message) = rpc::read_message(&mut reader)? {
         match message {
+            IncomingMessage::Request(request) if shutdown_requested => {
+                rpc::send_error_response(
+                    &mut writer,
+                    request.id,
+                    INVALID_REQUEST,
+                    format!("request after shutdown: {}", request.method),
+                )?;
+            }
             IncomingMessage::Request(request) => {
-                shutdown_requested =
-                    handle_request(&mut writer, &store, request)? || shutdown_requested;
+                shutdown_requested = handle_request(&mut writer, &mut store, request)?;
             }
             IncomingMessage::Notification(notification) => {
                 if handle_notification(&mut writer, &mut store, notification)? {
@@ -650,9 +1259,6 @@ pub fn run_stdio(initialization: InitializeResult) -> Result<(), String> {
             }
             IncomingMessage::Response => {}
         }
-        if shutdown_requested {
-            break;
-        }
     }
 
     Ok(())
@@ -665,6 +1271,36 @@ pub fn capabilities() -> InitializeResult {
         text_document_sync: Some(2),
         hover_provider: Some(true),
         definition_provider: Some(true),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: vec![String::from(".")],
+        }),
+        references_provider: Some(true),
+        rename_provider: Some(RenameOptions {
+            prepare_provider: true,
+        }),
+        document_symbol_provider: Some(true),
+        workspace_symbol_provider: Some(true),
+        code_action_provider: Some(CodeActionOptions {
+            code_action_kinds: vec![
+                String::from(types::code_action_kind::QUICK_FIX),
+                String::from(types::code_action_kind::SOURCE_FIX_ALL),
+            ],
+        }),
+        document_formatting_provider: Some(true),
+        document_range_formatting_provider: Some(true),
+        document_on_type_formatting_provider: Some(DocumentOnTypeFormattingOptions {
+            first_trigger_character: String::from("}"),
+            more_trigger_character: vec![String::from(";"), String::from("\n")],
+        }),
+        semantic_tokens_provider: Some(SemanticTokensOptions {
+            legend: semantic_tokens::legend(),
+            full: SemanticTokensFullOptions { delta: true },
+        }),
+        inlay_hint_provider: Some(true),
+        signature_help_provider: Some(SignatureHelpOptions {
+            trigger_characters: vec![String::from("("), String::from(",")],
+            retrigger_characters: vec![String::from(")")],
+        }),
     };
     InitializeResult {
         capabilities,
@@ -0,0 +1,552 @@
+//! References, rename and symbol queries over analysed documents.
+//!
+//! An identifier bound to a MIR local only matches inside the block that declares it, and
+//! never as the member of a `receiver.member` access. Anything
+//! else is resolved to the qualified symbol it names: declaration sites map through the
+//! collected declarations, `receiver.member` accesses through the receiver's type (MIR
+//! locals, enclosing members, type names) and the symbol index, and bare names through the
+//! enclosing type's members before top-level items. Only occurrences that resolve to the
+//! same symbol as the one under the cursor are reported.
+
+use std::collections::{BTreeMap, HashSet};
+
+use crate::diagnostics::{FileId, Span};
+use crate::frontend::lexer::{Keyword, Token, TokenKind, lex_with_file};
+
+use super::completion::{enclosing_type, receiver_type};
+use super::symbols::{Declaration, LocalBinding, type_leaf};
+use super::types::{
+    DocumentSymbol, Location, PrepareRenameResult, Range, SymbolInformation, TextEdit, Uri,
+    WorkspaceEdit, symbol_kind,
+};
+use super::{DocumentAnalysis, DocumentStore, offset_at, span_to_range};
+
+/// Base classes are followed this many levels when looking up inherited members.
+const MAX_BASE_DEPTH: usize = 16;
+
+/// An identifier occurrence resolved to a document location.
+struct Occurrence {
+    uri: Uri,
+    range: Range,
+    is_declaration: bool,
+}
+
+/// Identifier under the cursor together with what it refers to.
+struct Resolved<'a> {
+    analysis: &'a DocumentAnalysis,
+    file_id: FileId,
+    token: Token,
+    local: Option<&'a LocalBinding>,
+    /// Qualified symbol for identifiers that are not locals.
+    symbol: Option<String>,
+}
+
+fn resolve<'a>(
+    store: &'a DocumentStore,
+    uri: &Uri,
+    position: super::Position,
+) -> Option<Resolved<'a>> {
+    let analysis = store.analysis(uri)?;
+    let file_id = analysis.file_id(uri)?;
+    let source = analysis.source(file_id)?;
+    let offset = offset_at(source, position).min(source.len());
+    let tokens = significant_tokens(source, file_id);
+    let index = tokens.iter().position(|token| {
+        token.kind == TokenKind::Identifier
+            && token.span.start <= offset
+            && offset <= token.span.end
+    })?;
+    let token = tokens[index].clone();
+    let local = if is_member_access(&tokens, index) {
+        None
+    } else {
+        enclosing_local(&analysis.locals, token.lexeme.trim_matches('`'), token.span)
+    };
+    let symbol = match local {
+        Some(_) => None,
+        None => symbol_at(analysis, &tokens, index),
+    };
+    Some(Resolved {
+        analysis,
+        file_id,
+        token,
+        local,
+        symbol,
+    })
+}
+
+pub(super) fn enclosing_local<'a>(
+    locals: &'a [LocalBinding],
+    name: &str,
+    at: Span,
+) -> Option<&'a LocalBinding> {
+    locals
+        .iter()
+        .filter(|local| {
+            local.name == name
+                && local.scope.file_id == at.file_id
+                && local.scope.start <= at.start
+                && at.end <= local.scope.end
+        })
+        .min_by_key(|local| local.scope.end - local.scope.start)
+}
+
+/// Whether `tokens[index]` is the member of a `receiver.member` or `receiver?.member` access.
+fn is_member_access(tokens: &[Token], index: usize) -> bool {
+    index >= 2
+        && matches!(
+            tokens[index - 1].kind,
+            TokenKind::Punctuation('.') | TokenKind::Operator("?.")
+        )
+}
+
+fn significant_tokens(source: &str, file_id: FileId) -> Vec<Token> {
+    lex_with_file(source, file_id)
+        .tokens
+        .into_iter()
+        .filter(|token| {
+            !matches!(
+                token.kind,
+                TokenKind::Whitespace | TokenKind::Comment | TokenKind::DocComment
+            )
+        })
+        .collect()
+}
+
+/// Resolve the identifier at `tokens[index]` to the qualified symbol it names.
+fn symbol_at(analysis: &DocumentAnalysis, tokens: &[Token], index: usize) -> Option<String> {
+    let token = &tokens[index];
+    let name = token.lexeme.trim_matches('`');
+    let at = token.span;
+    if let Some(decl) = analysis
+        .declarations
+        .iter()
+        .find(|decl| decl.selection.file_id == at.file_id && decl.selection.start == at.start)
+    {
+        return Some(decl.qualified.clone());
+    }
+
+    if is_member_access(tokens, index) {
+        let receiver = &tokens[index - 2];
+        let receiver_name = receiver.lexeme.trim_matches('`');
+        if !matches!(receiver.kind, TokenKind::Identifier | TokenKind::Keyword(_)) {
+            return None;
+        }
+        if let Some(ty) = receiver_type(analysis, receiver_name, receiver.span) {
+            return member_symbol(analysis, &ty, name);
+        }
+        // `Namespace.Item` qualification.
+        let suffix = format!("{receiver_name}::{name}");
+        return analysis
+            .declarations
+            .iter()
+            .find(|decl| {
+                decl.qualified == suffix || decl.qualified.ends_with(&format!("::{suffix}"))
+            })
+            .map(|decl| decl.qualified.clone());
+    }
+
+    if let Some(owner) = enclosing_type(analysis, at) {
+        let owner = owner.name.clone();
+        if let Some(symbol) = member_symbol(analysis, &owner, name) {
+            return Some(symbol);
+        }
+    }
+    top_level_symbol(analysis, name, at)
+}
+
+/// The qualified member `name` of type `ty`, following base classes for inherited members.
+fn member_symbol(analysis: &DocumentAnalysis, ty: &str, name: &str) -> Option<String> {
+    let mut owner = type_leaf(ty).to_string();
+    for _ in 0..MAX_BASE_DEPTH {
+        for (index, decl) in analysis.declarations.iter().enumerate() {
+            if !(decl.is_type() && decl.name == owner) {
+                continue;
+            }
+            if let Some(member) = analysis
+                .declarations
+                .iter()
+                .find(|member| member.parent == Some(index) && member.name == name)
+            {
+                return Some(member.qualified.clone());
+            }
+        }
+        for index in &analysis.indices {
+            let declares = |owner_name: &String| type_leaf(owner_name) == owner;
+            let found = index
+                .type_fields
+                .iter()
+                .find(|(ty, members)| declares(ty) && members.contains_key(name))
+                .map(|(ty, _)| ty)
+                .or_else(|| {
+                    index
+                        .type_properties
+                        .iter()
+                        .find(|(ty, members)| declares(ty) && members.contains_key(name))
+                        .map(|(ty, _)| ty)
+                })
+                .or_else(|| {
+                    index
+                        .type_methods
+                        .iter()
+                        .find(|(ty, members)| declares(ty) && members.contains_key(name))
+                        .map(|(ty, _)| ty)
+                })
+                .or_else(|| {
+                    index
+                        .enum_variants
+                        .iter()
+                        .find(|(ty, variants)| declares(ty) && variants.contains(name))
+                        .map(|(ty, _)| ty)
+                });
+            if let Some(ty) = found {
+                return Some(format!("{ty}::{name}"));
+            }
+        }
+        owner = base_of(analysis, &owner)?;
+    }
+    None
+}
+
+fn base_of(analysis: &DocumentAnalysis, owner: &str) -> Option<String> {
+    analysis.indices.iter().find_map(|index| {
+        index
+            .types()
+            .find(|ty| type_leaf(ty) == owner)
+            .and_then(|ty| {
+                let descriptor = index
+                    .reflection_descriptor(ty)
+                    .or_else(|| index.reflection_descriptor(&ty.replace("::", ".")))?;
+                descriptor
+                    .bases
+                    .first()
+                    .map(|base| type_leaf(&base.name).to_string())
+            })
+    })
+}
+
+/// Top-level items (types, free functions, constants) named `name`, preferring the ones
+/// declared in the namespace that encloses `at`.
+fn top_level_symbol(analysis: &DocumentAnalysis, name: &str, at: Span) -> Option<String> {
+    let namespace = analysis
+        .declarations
+        .iter()
+        .filter(|decl| {
+            decl.kind == symbol_kind::NAMESPACE
+                && decl.extent.file_id == at.file_id
+                && decl.extent.start <= at.start
+                && at.end <= decl.extent.end
+        })
+        .min_by_key(|decl| decl.extent.end - decl.extent.start)
+        .map(|decl| decl.qualified.as_str());
+    let mut candidates = analysis.declarations.iter().filter(|decl| {
+        decl.name == name
+            && decl.kind != symbol_kind::NAMESPACE
+            && decl
+                .parent
+                .is_none_or(|parent| analysis.declarations[parent].kind == symbol_kind::NAMESPACE)
+    });
+    let first = candidates.next()?;
+    let in_namespace = |decl: &&Declaration| {
+        namespace.is_some_and(|namespace| {
+            decl.qualified
+                .strip_prefix(namespace)
+                .is_some_and(|rest| rest.starts_with("::"))
+        })
+    };
+    if in_namespace(&first) {
+        return Some(first.qualified.clone());
+    }
+    Some(
+        candidates
+            .find(in_namespace)
+            .unwrap_or(first)
+            .qualified
+            .clone(),
+    )
+}
+
+fn occurrences(store: &DocumentStore, resolved: &Resolved<'_>) -> Vec<Occurrence> {
+    let name = resolved.token.lexeme.trim_matches('`');
+    let mut found = Vec::new();
+    if let Some(local) = resolved.local {
+        let analysis = resolved.analysis;
+        let Some(source) = analysis.source(resolved.file_id) else {
+            return found;
+        };
+        let Some(uri) = analysis.uri(resolved.file_id) else {
+            return found;
+        };
+        let tokens = significant_tokens(source, resolved.file_id);
+        for (index, token) in tokens.iter().enumerate() {
+            if token.kind != TokenKind::Identifier || token.lexeme.trim_matches('`') != name {
+                continue;
+            }
+            if token.span.start < local.scope.start || token.span.end > local.scope.end {
+                continue;
+            }
+            if is_member_access(&tokens, index) {
+                continue;
+            }
+            if !enclosing_local(&analysis.locals, name, token.span)
+                .is_some_and(|found| std::ptr::eq(found, local))
+            {
+                continue;
+            }
+            if let Some(range) = span_to_range(token.span, &analysis.files) {
+                found.push(Occurrence {
+                    uri: uri.clone(),
+                    range,
+                    is_declaration: local
+                        .span
+                        .is_some_and(|span| span.start == token.span.start),
+                });
+            }
+        }
+        return found;
+    }
+    let Some(symbol) = resolved.symbol.as_deref() else {
+        return found;
+    };
+
+    let mut seen = HashSet::new();
+    for analysis in store.analyses() {
+        for &file_id in &analysis.sources {
+            let (Some(source), Some(uri)) = (analysis.source(file_id), analysis.uri(file_id))
+            else {
+                continue;
+            };
+            let declared = analysis
+                .declarations
+                .iter()
+                .filter(|decl| decl.qualified == symbol && decl.selection.file_id == file_id)
+                .map(|decl| decl.selection.start)
+                .collect::<HashSet<_>>();
+            let tokens = significant_tokens(source, file_id);
+            for (index, token) in tokens.iter().enumerate() {
+                if token.kind != TokenKind::Identifier || token.lexeme.trim_matches('`') != name {
+                    continue;
+                }
+                if !is_member_access(&tokens, index)
+                    && enclosing_local(&analysis.locals, name, token.span).is_some()
+                {
+                    continue;
+                }
+                if symbol_at(analysis, &tokens, index).as_deref() != Some(symbol) {
+                    continue;
+                }
+                let Some(range) = span_to_range(token.span, &analysis.files) else {
+                    continue;
+                };
+                if !seen.insert((uri.clone(), range.start.line, range.start.character)) {
+                    continue;
+                }
+                found.push(Occurrence {
+                    uri: uri.clone(),
+                    range,
+                    is_declaration: declared.contains(&token.span.start),
+                });
+            }
+        }
+    }
+    found
+}
+
+pub(super) fn references(
+    store: &DocumentStore,
+    uri: &Uri,
+    position: super::Position,
+    include_declaration: bool,
+) -> Vec<Location> {
+    let Some(resolved) = resolve(store, uri, position) else {
+        return Vec::new();
+    };
+    occurrences(store, &resolved)
+        .into_iter()
+        .filter(|occurrence| include_declaration || !occurrence.is_declaration)
+        .map(|occurrence| Location::new(occurrence.uri, occurrence.range))
+        .collect()
+}
+
+/// Locals and symbols declared in the analysed sources can be renamed; symbols that only
+/// exist in the standard library or the symbol index cannot.
+fn is_declared(store: &DocumentStore, resolved: &Resolved<'_>) -> bool {
+    if resolved.local.is_some() {
+        return true;
+    }
+    let Some(symbol) = resolved.symbol.as_deref() else {
+        return false;
+    };
+    store.analyses().any(|analysis| {
+        analysis.declarations.iter().any(|decl| {
+            decl.qualified == symbol && analysis.sources.contains(&decl.selection.file_id)
+        })
+    })
+}
+
+pub(super) fn prepare_rename(
+    store: &DocumentStore,
+    uri: &Uri,
+    position: super::Position,
+) -> Option<PrepareRenameResult> {
+    let resolved = resolve(store, uri, position)?;
+    if !is_declared(store, &resolved) {
+        return None;
+    }
+    let range = span_to_range(resolved.token.span, &resolved.analysis.files)?;
+    Some(PrepareRenameResult {
+        range,
+        placeholder: resolved.token.lexeme.trim_matches('`').to_string(),
+    })
+}
+
+pub(super) fn rename(
+    store: &DocumentStore,
+    uri: &Uri,
+    position: super::Position,
+    new_name: &str,
+) -> Result<Option<WorkspaceEdit>, String> {
+    if !is_valid_identifier(new_name) {
+        return Err(format!("`{new_name}` is not a valid Chic identifier"));
+    }
+    let Some(resolved) = resolve(store, uri, position) else {
+        return Ok(None);
+    };
+    if !is_declared(store, &resolved) {
+        return Err(format!(
+            "`{}` is not declared in this workspace and cannot be renamed",
+            resolved.token.lexeme
+        ));
+    }
+    let mut changes: BTreeMap<Uri, Vec<TextEdit>> = BTreeMap::new();
+    for occurrence in occurrences(store, &resolved) {
+        changes.entry(occurrence.uri).or_default().push(TextEdit {
+            range: occurrence.range,
+            new_text: new_name.to_string(),
+        });
+    }
+    Ok(Some(WorkspaceEdit { changes }))
+}
+
+fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    (first == '_' || first.is_alphabetic())
+        && chars.all(|ch| ch == '_' || ch.is_alphanumeric())
+        && Keyword::from_ident(name).is_none()
+}
+
+pub(super) fn document_symbols(analysis: &DocumentAnalysis, uri: &Uri) -> Vec<DocumentSymbol> {
+    let Some(file_id) = analysis.file_id(uri) else {
+        return Vec::new();
+    };
+    let decls = &analysis.declarations;
+    let mut nodes: Vec<Option<DocumentSymbol>> = decls
+        .iter()
+        .map(|decl| {
+            if decl.selection.file_id != file_id {
+                return None;
+            }
+            let selection_range = span_to_range(decl.selection, &analysis.files)?;
+            let range = span_to_range(decl.extent, &analysis.files).unwrap_or(selection_range);
+            Some(DocumentSymbol {
+                name: decl.name.clone(),
+                detail: decl.detail.clone(),
+                kind: decl.kind,
+                range,
+                selection_range,
+                children: Vec::new(),
+            })
+        })
+        .collect();
+    // Children always follow their parent, so folding from the back attaches every
+    // subtree before its parent is moved.
+    let mut roots = Vec::new();
+    for index in (0..decls.len()).rev() {
+        let Some(node) = nodes[index].take() else {
+            continue;
+        };
+        match decls[index]
+            .parent
+            .filter(|parent| nodes[*parent].is_some())
+        {
+            Some(parent) => {
+                if let Some(parent) = nodes[parent].as_mut() {
+                    parent.children.insert(0, node);
+                }
+            }
+            None => roots.insert(0, node),
+        }
+    }
+    roots
+}
+
+pub(super) fn workspace_symbols(store: &DocumentStore, query: &str) -> Vec<SymbolInformation> {
+    let mut seen = HashSet::new();
+    let mut symbols = Vec::new();
+    for analysis in store.analyses() {
+        for decl in &analysis.declarations {
+            if !fuzzy_matches(query, &decl.name) {
+                continue;
+            }
+            let (Some(uri), Some(range)) = (
+                analysis.uri(decl.selection.file_id),
+                span_to_range(decl.selection, &analysis.files),
+            ) else {
+                continue;
+            };
+            if !seen.insert((uri.clone(), range.start.line, range.start.character)) {
+                continue;
+            }
+            symbols.push(SymbolInformation {
+                name: decl.name.clone(),
+                kind: decl.kind,
+                location: Location::new(uri, range),
+                container_name: container_name(&analysis.declarations, decl),
+            });
+        }
+    }
+    symbols.sort_by(|left, right| {
+        left.name
+            .cmp(&right.name)
+            .then_with(|| left.location.uri.cmp(&right.location.uri))
+    });
+    symbols
+}
+
+fn container_name(decls: &[Declaration], decl: &Declaration) -> Option<String> {
+    let parent = decls.get(decl.parent?)?;
+    Some(parent.qualified.clone())
+}
+
+/// Case-insensitive subsequence match, the usual fuzzy rule for workspace symbol search.
+fn fuzzy_matches(query: &str, name: &str) -> bool {
+    let mut candidate = name.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|wanted| candidate.any(|ch| ch == wanted))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_match_accepts_subsequences() {
+        assert!(fuzzy_matches("", "Counter"));
+        assert!(fuzzy_matches("cnt", "Counter"));
+        assert!(fuzzy_matches("COUNTER", "Counter"));
+        assert!(!fuzzy_matches("ctx", "Counter"));
+    }
+
+    #[test]
+    fn rename_rejects_keywords_and_invalid_names() {
+        assert!(is_valid_identifier("total_count"));
+        assert!(!is_valid_identifier("class"));
+        assert!(!is_valid_identifier("1st"));
+        assert!(!is_valid_identifier(""));
+    }
+}
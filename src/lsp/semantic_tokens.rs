@@ -0,0 +1,370 @@
+//! `textDocument/semanticTokens/full` and `/full/delta` support.
+//!
+//! Tokens come from lexing the current buffer; identifiers are classified against the
+//! unit's analysis: MIR locals (parameters, `let` versus `var` bindings), declarations of
+//! the analysed files, and the symbol index (members, enum variants and extension
+//! methods). Identifiers that cannot be classified are left to the client's grammar.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::diagnostics::FileId;
+use crate::frontend::lexer::{Keyword, Token, TokenKind, lex_with_file};
+
+use super::navigation::enclosing_local;
+use super::symbols::type_leaf;
+use super::types::{SemanticTokensEdit, SemanticTokensLegend, Uri, symbol_kind};
+use super::{DocumentAnalysis, DocumentStore};
+
+/// Token types, in legend order.
+const TOKEN_TYPES: [&str; 19] = [
+    "namespace",
+    "type",
+    "class",
+    "enum",
+    "interface",
+    "struct",
+    "typeParameter",
+    "parameter",
+    "variable",
+    "property",
+    "enumMember",
+    "function",
+    "method",
+    "keyword",
+    "modifier",
+    "comment",
+    "string",
+    "number",
+    "operator",
+];
+
+const NAMESPACE: u32 = 0;
+const TYPE: u32 = 1;
+const CLASS: u32 = 2;
+const ENUM: u32 = 3;
+const INTERFACE: u32 = 4;
+const STRUCT: u32 = 5;
+const TYPE_PARAMETER: u32 = 6;
+const PARAMETER: u32 = 7;
+const VARIABLE: u32 = 8;
+const PROPERTY: u32 = 9;
+const ENUM_MEMBER: u32 = 10;
+const FUNCTION: u32 = 11;
+const METHOD: u32 = 12;
+const KEYWORD: u32 = 13;
+/// Borrow qualifiers (`in`, `ref`, `out`, `mut`).
+const MODIFIER: u32 = 14;
+const COMMENT: u32 = 15;
+const STRING: u32 = 16;
+const NUMBER: u32 = 17;
+const OPERATOR: u32 = 18;
+
+/// Token modifiers, in legend order; `mutable` and `extension` are Chic-specific.
+const TOKEN_MODIFIERS: [&str; 4] = ["declaration", "readonly", "mutable", "extension"];
+
+const DECLARATION: u32 = 1 << 0;
+const READONLY: u32 = 1 << 1;
+const MUTABLE: u32 = 1 << 2;
+const EXTENSION: u32 = 1 << 3;
+
+pub(super) fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: TOKEN_TYPES.iter().map(ToString::to_string).collect(),
+        token_modifiers: TOKEN_MODIFIERS.iter().map(ToString::to_string).collect(),
+    }
+}
+
+/// Names known to the analysis, grouped by the token type they highlight as.
+#[derive(Default)]
+struct Names<'a> {
+    declared: HashMap<&'a str, u32>,
+    declaration_sites: HashSet<usize>,
+    types: HashSet<&'a str>,
+    members: HashMap<&'a str, u32>,
+    extension_methods: HashSet<&'a str>,
+}
+
+impl<'a> Names<'a> {
+    fn new(analysis: &'a DocumentAnalysis, file_id: Option<FileId>) -> Self {
+        let mut names = Self::default();
+        for decl in &analysis.declarations {
+            let Some(token_type) = declaration_type(decl.kind) else {
+                continue;
+            };
+            names
+                .declared
+                .entry(decl.name.as_str())
+                .or_insert(token_type);
+            if Some(decl.selection.file_id) == file_id {
+                names.declaration_sites.insert(decl.selection.start);
+            }
+        }
+        for index in &analysis.indices {
+            names
+                .types
+                .extend(index.type_names().iter().map(|ty| type_leaf(ty)));
+            for members in index.type_fields.values() {
+                for name in members.keys() {
+                    names.members.entry(name.as_str()).or_insert(PROPERTY);
+                }
+            }
+            for members in index.type_properties.values() {
+                for name in members.keys() {
+                    names.members.entry(name.as_str()).or_insert(PROPERTY);
+                }
+            }
+            for members in index.type_methods.values() {
+                for name in members.keys() {
+                    names.members.entry(name.as_str()).or_insert(METHOD);
+                }
+            }
+            for variants in index.enum_variants.values() {
+                for name in variants {
+                    names.members.entry(name.as_str()).or_insert(ENUM_MEMBER);
+                }
+            }
+            for overloads in index.functions.values() {
+                for function in overloads {
+                    if function.params.iter().any(|param| param.is_extension_this) {
+                        names
+                            .extension_methods
+                            .insert(type_leaf(&function.qualified));
+                    }
+                }
+            }
+        }
+        names
+    }
+}
+
+fn declaration_type(kind: u32) -> Option<u32> {
+    Some(match kind {
+        symbol_kind::NAMESPACE => NAMESPACE,
+        symbol_kind::CLASS => CLASS,
+        symbol_kind::STRUCT => STRUCT,
+        symbol_kind::ENUM => ENUM,
+        symbol_kind::INTERFACE => INTERFACE,
+        symbol_kind::TYPE_PARAMETER => TYPE_PARAMETER,
+        symbol_kind::METHOD | symbol_kind::OPERATOR => METHOD,
+        symbol_kind::FUNCTION => FUNCTION,
+        symbol_kind::PROPERTY | symbol_kind::FIELD => PROPERTY,
+        symbol_kind::ENUM_MEMBER => ENUM_MEMBER,
+        symbol_kind::CONSTANT => VARIABLE,
+        _ => return None,
+    })
+}
+
+fn is_punctuation(token: Option<&Token>, ch: char) -> bool {
+    token.is_some_and(|token| token.kind == TokenKind::Punctuation(ch))
+}
+
+/// Encode the semantic tokens of the open document `uri`.
+pub(super) fn encode(store: &DocumentStore, uri: &Uri) -> Option<Vec<u32>> {
+    let text = &store.document(uri)?.text;
+    let analysis = store.analysis(uri);
+    let file_id = analysis.and_then(|analysis| analysis.file_id(uri));
+    let names = analysis.map(|analysis| Names::new(analysis, file_id));
+    let tokens = lex_with_file(text, file_id.unwrap_or_default()).tokens;
+    let significant: Vec<usize> = tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, token)| {
+            !matches!(
+                token.kind,
+                TokenKind::Whitespace | TokenKind::Comment | TokenKind::DocComment
+            )
+        })
+        .map(|(index, _)| index)
+        .collect();
+
+    let mut classified = Vec::new();
+    for (position, &index) in significant.iter().enumerate() {
+        let token = &tokens[index];
+        let previous = position
+            .checked_sub(1)
+            .and_then(|previous| significant.get(previous))
+            .map(|&previous| &tokens[previous]);
+        let next = significant.get(position + 1).map(|&next| &tokens[next]);
+        let class = match &token.kind {
+            TokenKind::Identifier => match (analysis, &names) {
+                (Some(analysis), Some(names)) => {
+                    classify_identifier(analysis, names, token, previous, next)
+                }
+                _ => None,
+            },
+            TokenKind::Keyword(keyword) => Some((keyword_type(*keyword, previous), 0)),
+            TokenKind::NumberLiteral(_) => Some((NUMBER, 0)),
+            TokenKind::StringLiteral(_) | TokenKind::CharLiteral(_) => Some((STRING, 0)),
+            TokenKind::Operator(_) => Some((OPERATOR, 0)),
+            _ => None,
+        };
+        if let Some((token_type, modifiers)) = class {
+            classified.push((token.span.start, token.span.end, token_type, modifiers));
+        }
+    }
+    for token in &tokens {
+        if matches!(token.kind, TokenKind::Comment | TokenKind::DocComment) {
+            classified.push((token.span.start, token.span.end, COMMENT, 0));
+        }
+    }
+    classified.sort_unstable_by_key(|(start, ..)| *start);
+    Some(encode_relative(text, &classified))
+}
+
+fn keyword_type(keyword: Keyword, previous: Option<&Token>) -> u32 {
+    match keyword {
+        Keyword::Ref | Keyword::Out | Keyword::Mut => MODIFIER,
+        // `in` qualifies a parameter or argument; elsewhere (`foreach`) it is a keyword.
+        Keyword::In if is_punctuation(previous, '(') || is_punctuation(previous, ',') => MODIFIER,
+        _ => KEYWORD,
+    }
+}
+
+fn classify_identifier(
+    analysis: &DocumentAnalysis,
+    names: &Names<'_>,
+    token: &Token,
+    previous: Option<&Token>,
+    next: Option<&Token>,
+) -> Option<(u32, u32)> {
+    let name = token.lexeme.trim_matches('`');
+    let is_member = is_punctuation(previous, '.')
+        || previous.is_some_and(|previous| previous.kind == TokenKind::Operator("?."));
+    let is_call = is_punctuation(next, '(');
+    let declaration = if names.declaration_sites.contains(&token.span.start) {
+        DECLARATION
+    } else {
+        0
+    };
+
+    if is_member {
+        if is_call && names.extension_methods.contains(name) {
+            return Some((METHOD, EXTENSION));
+        }
+        if let Some(&member) = names.members.get(name) {
+            return Some((member, 0));
+        }
+        return names
+            .declared
+            .get(name)
+            .copied()
+            .or(is_call.then_some(METHOD))
+            .map(|token_type| (token_type, 0));
+    }
+    if let Some(local) = enclosing_local(&analysis.locals, name, token.span) {
+        let token_type = if local.parameter { PARAMETER } else { VARIABLE };
+        let modifiers = if local.mutable { MUTABLE } else { READONLY };
+        return Some((token_type, modifiers));
+    }
+    if let Some(&declared) = names.declared.get(name) {
+        return Some((declared, declaration));
+    }
+    if names.types.contains(name) {
+        return Some((TYPE, 0));
+    }
+    is_call.then_some((FUNCTION, 0))
+}
+
+/// Delta-encode `(start, end, type, modifiers)` byte ranges, splitting tokens that span
+/// several lines.
+fn encode_relative(text: &str, classified: &[(usize, usize, u32, u32)]) -> Vec<u32> {
+    let mut data = Vec::with_capacity(classified.len() * 5);
+    let mut last_line = 0u32;
+    let mut last_start = 0u32;
+    let mut line = 0u32;
+    let mut line_start = 0usize;
+    let mut scanned = 0usize;
+    for &(start, end, token_type, modifiers) in classified {
+        if start < scanned {
+            continue;
+        }
+        for (offset, ch) in text[scanned..start].char_indices() {
+            if ch == '\n' {
+                line += 1;
+                line_start = scanned + offset + 1;
+            }
+        }
+        let mut segment_start = start;
+        for segment in text[start..end].split_inclusive('\n') {
+            let content = segment.trim_end_matches(['\n', '\r']);
+            let column = utf16_len(&text[line_start..segment_start]);
+            let length = utf16_len(content);
+            if length > 0 {
+                let delta_line = line - last_line;
+                let delta_start = if delta_line == 0 {
+                    column - last_start
+                } else {
+                    column
+                };
+                data.extend([delta_line, delta_start, length, token_type, modifiers]);
+                last_line = line;
+                last_start = column;
+            }
+            segment_start += segment.len();
+            if segment.ends_with('\n') {
+                line += 1;
+                line_start = segment_start;
+            }
+        }
+        scanned = end;
+    }
+    data
+}
+
+fn utf16_len(text: &str) -> u32 {
+    u32::try_from(text.encode_utf16().count()).unwrap_or(u32::MAX)
+}
+
+/// The single edit turning `previous` into `current` (common prefix and suffix kept).
+pub(super) fn delta(previous: &[u32], current: &[u32]) -> Vec<SemanticTokensEdit> {
+    if previous == current {
+        return Vec::new();
+    }
+    let prefix = previous
+        .iter()
+        .zip(current)
+        .take_while(|(left, right)| left == right)
+        .count();
+    let suffix = previous[prefix..]
+        .iter()
+        .rev()
+        .zip(current[prefix..].iter().rev())
+        .take_while(|(left, right)| left == right)
+        .count();
+    vec![SemanticTokensEdit {
+        start: u32::try_from(prefix).unwrap_or(u32::MAX),
+        delete_count: u32::try_from(previous.len() - prefix - suffix).unwrap_or(u32::MAX),
+        data: current[prefix..current.len() - suffix].to_vec(),
+    }]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multi_line_tokens_are_split_per_line() {
+        let text = "/* a\nbc */ x";
+        let data = encode_relative(text, &[(0, 10, COMMENT, 0), (11, 12, VARIABLE, 0)]);
+        assert_eq!(
+            data,
+            vec![
+                0, 0, 4, COMMENT, 0, 1, 0, 5, COMMENT, 0, 0, 6, 1, VARIABLE, 0
+            ]
+        );
+    }
+
+    #[test]
+    fn delta_keeps_common_prefix_and_suffix() {
+        let edits = delta(&[1, 2, 3, 4, 5], &[1, 2, 9, 9, 4, 5]);
+        assert_eq!(
+            edits,
+            vec![SemanticTokensEdit {
+                start: 2,
+                delete_count: 1,
+                data: vec![9, 9],
+            }]
+        );
+        assert!(delta(&[1, 2], &[1, 2]).is_empty());
+    }
+}
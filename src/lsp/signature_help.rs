@@ -0,0 +1,298 @@
+//! `textDocument/signatureHelp` support, plus the call-site and overload lookups shared
+//! with parameter-name inlay hints.
+//!
+//! The call around the cursor is found by walking the lexed buffer back to the unmatched
+//! `(`. Its overload set comes from the declarations recorded in the symbol index, so
+//! parameter names, borrow qualifiers and default arguments are shown as written.
+
+use crate::frontend::ast::{BindingModifier, Parameter, TypeExpr};
+use crate::frontend::lexer::{Keyword, Token, TokenKind, lex};
+use crate::frontend::type_utils::type_expr_surface;
+
+use super::symbols::type_leaf;
+use super::types::{ParameterInformation, Position, SignatureHelp, SignatureInformation, Uri};
+use super::{DocumentAnalysis, DocumentStore, offset_at};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(super) enum CallKind {
+    /// `Name(...)`
+    Function,
+    /// `receiver.Name(...)`; extension receivers are supplied implicitly.
+    Method,
+    /// `new Name(...)`
+    Constructor,
+}
+
+/// The callee of the call enclosing a position.
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct CallSite {
+    pub callee: String,
+    pub kind: CallKind,
+    /// Index of the argument the position is in.
+    pub argument: usize,
+}
+
+/// One overload of a callee, rendered for display.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct Overload {
+    pub label: String,
+    /// Name and UTF-16 label range of every explicit parameter.
+    pub params: Vec<(String, [u32; 2])>,
+    /// Parameters that may be omitted at the end of the list.
+    pub optional: usize,
+    pub variadic: bool,
+    pub documentation: Option<String>,
+}
+
+impl Overload {
+    /// Whether a call with `count` arguments can bind to this overload.
+    pub fn accepts(&self, count: usize) -> bool {
+        self.variadic || (self.params.len() - self.optional..=self.params.len()).contains(&count)
+    }
+}
+
+pub(super) fn signature_help(
+    store: &DocumentStore,
+    uri: &Uri,
+    position: Position,
+) -> Option<SignatureHelp> {
+    let text = &store.document(uri)?.text;
+    let analysis = store.analysis(uri)?;
+    let offset = offset_at(text, position).min(text.len());
+    let call = enclosing_call(&text[..offset])?;
+    let overloads = overloads(analysis, &call.callee, call.kind);
+    let active = overloads
+        .iter()
+        .position(|overload| overload.variadic || overload.params.len() > call.argument)
+        .unwrap_or(0);
+    let signatures = overloads
+        .into_iter()
+        .map(|overload| SignatureInformation {
+            label: overload.label,
+            parameters: overload
+                .params
+                .into_iter()
+                .map(|(_, label)| ParameterInformation { label })
+                .collect(),
+            documentation: overload.documentation,
+        })
+        .collect::<Vec<_>>();
+    if signatures.is_empty() {
+        return None;
+    }
+    Some(SignatureHelp {
+        signatures,
+        active_signature: u32::try_from(active).unwrap_or(0),
+        active_parameter: u32::try_from(call.argument).unwrap_or(u32::MAX),
+    })
+}
+
+/// Find the call whose argument list `prefix` (the buffer up to the cursor) ends inside.
+pub(super) fn enclosing_call(prefix: &str) -> Option<CallSite> {
+    let tokens: Vec<Token> = lex(prefix)
+        .tokens
+        .into_iter()
+        .filter(|token| {
+            !matches!(
+                token.kind,
+                TokenKind::Whitespace | TokenKind::Comment | TokenKind::DocComment
+            )
+        })
+        .collect();
+    let mut depth = 0usize;
+    let mut argument = 0usize;
+    for (index, token) in tokens.iter().enumerate().rev() {
+        let TokenKind::Punctuation(ch) = token.kind else {
+            continue;
+        };
+        match ch {
+            ')' | ']' | '}' => depth += 1,
+            '(' if depth == 0 => {
+                return call_site(&tokens[..index], argument);
+            }
+            '(' | '[' if depth > 0 => depth -= 1,
+            '{' if depth > 0 => depth -= 1,
+            '[' | '{' => return None,
+            ',' if depth == 0 => argument += 1,
+            ';' if depth == 0 => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Describe the callee ending `before` (the tokens preceding an opening parenthesis).
+fn call_site(before: &[Token], argument: usize) -> Option<CallSite> {
+    let (callee, rest) = before.split_last()?;
+    if callee.kind != TokenKind::Identifier {
+        return None;
+    }
+    let kind = match rest.last().map(|token| &token.kind) {
+        Some(TokenKind::Punctuation('.')) | Some(TokenKind::Operator("?.")) => CallKind::Method,
+        Some(TokenKind::Keyword(Keyword::New)) => CallKind::Constructor,
+        _ => CallKind::Function,
+    };
+    Some(CallSite {
+        callee: callee.lexeme.trim_matches('`').to_string(),
+        kind,
+        argument,
+    })
+}
+
+/// Overloads named `callee` across the analysed modules, fewest parameters first.
+pub(super) fn overloads(
+    analysis: &DocumentAnalysis,
+    callee: &str,
+    kind: CallKind,
+) -> Vec<Overload> {
+    let mut overloads = Vec::new();
+    for index in &analysis.indices {
+        if kind == CallKind::Constructor {
+            for (owner, constructors) in &index.constructor_decls {
+                if type_leaf(owner) != callee {
+                    continue;
+                }
+                for ctor in constructors {
+                    overloads.push(render(
+                        &format!("new {callee}"),
+                        None,
+                        &ctor.constructor.parameters,
+                        false,
+                        false,
+                        ctor.constructor
+                            .doc
+                            .as_ref()
+                            .map(|doc| doc.lines.join("\n")),
+                    ));
+                }
+            }
+            continue;
+        }
+        for (qualified, decls) in &index.function_decls {
+            if type_leaf(qualified) != callee {
+                continue;
+            }
+            for decl in decls {
+                let function = &decl.function;
+                overloads.push(render(
+                    callee,
+                    Some(&function.signature.return_type),
+                    &function.signature.parameters,
+                    kind == CallKind::Method,
+                    function.signature.variadic,
+                    function.doc.as_ref().map(|doc| doc.lines.join("\n")),
+                ));
+            }
+        }
+    }
+    overloads.sort_by(|left, right| {
+        (left.params.len(), &left.label).cmp(&(right.params.len(), &right.label))
+    });
+    overloads.dedup();
+    overloads
+}
+
+fn render(
+    name: &str,
+    return_type: Option<&TypeExpr>,
+    parameters: &[Parameter],
+    skip_receiver: bool,
+    variadic: bool,
+    documentation: Option<String>,
+) -> Overload {
+    let mut label = match return_type {
+        Some(ty) => format!("{} {name}(", type_expr_surface(ty)),
+        None => format!("{name}("),
+    };
+    let mut params = Vec::new();
+    let mut optional = 0;
+    let explicit = parameters
+        .iter()
+        .filter(|param| !(skip_receiver && param.is_extension_this));
+    for (index, param) in explicit.enumerate() {
+        if index > 0 {
+            label.push_str(", ");
+        }
+        let start = utf16_len(&label);
+        if param.is_extension_this {
+            label.push_str("this ");
+        }
+        match param.binding {
+            BindingModifier::In => label.push_str("in "),
+            BindingModifier::Ref => label.push_str("ref "),
+            BindingModifier::Out => label.push_str("out "),
+            BindingModifier::Value => {}
+        }
+        label.push_str(&type_expr_surface(&param.ty));
+        label.push(' ');
+        label.push_str(&param.name);
+        match &param.default {
+            Some(default) => {
+                label.push_str(" = ");
+                label.push_str(default.text.trim());
+                optional += 1;
+            }
+            None => optional = 0,
+        }
+        params.push((param.name.clone(), [start, utf16_len(&label)]));
+    }
+    if variadic {
+        if !params.is_empty() {
+            label.push_str(", ");
+        }
+        label.push_str("...");
+    }
+    label.push(')');
+    Overload {
+        label,
+        params,
+        optional,
+        variadic,
+        documentation,
+    }
+}
+
+fn utf16_len(text: &str) -> u32 {
+    u32::try_from(text.encode_utf16().count()).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enclosing_call_counts_top_level_arguments() {
+        assert_eq!(
+            enclosing_call("let x = Add(1, Scale(2, 3), "),
+            Some(CallSite {
+                callee: String::from("Add"),
+                kind: CallKind::Function,
+                argument: 2,
+            })
+        );
+        assert_eq!(
+            enclosing_call("counter.Step(").map(|call| call.kind),
+            Some(CallKind::Method)
+        );
+        assert_eq!(
+            enclosing_call("var p = new Point(1").map(|call| call.kind),
+            Some(CallKind::Constructor)
+        );
+        assert_eq!(enclosing_call("Add(1, 2);\nlet y = "), None);
+    }
+
+    #[test]
+    fn overload_accepts_omitted_trailing_defaults() {
+        let overload = Overload {
+            label: String::new(),
+            params: vec![(String::from("a"), [0, 1]), (String::from("b"), [2, 3])],
+            optional: 1,
+            variadic: false,
+            documentation: None,
+        };
+        assert!(overload.accepts(1));
+        assert!(overload.accepts(2));
+        assert!(!overload.accepts(0));
+        assert!(!overload.accepts(3));
+    }
+}
@@ -0,0 +1,820 @@
+//! Declaration collection for outlines, workspace symbols, references and completion.
+//!
+//! Most AST items do not record the span of their name, so declarations are located by
+//! walking the parsed module alongside the significant lexer tokens of the same file.
+//! Each container (file, namespace block, type body) is searched at its own brace depth,
+//! which keeps parameters, locals and nested bodies from shadowing member names.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::diagnostics::{FileCache, FileId, Span};
+use crate::frontend::ast::{
+    ClassMember, ConstDeclaration, ExtensionMember, FunctionDecl, ImplMember, InterfaceMember,
+    Item, Module, Parameter, TraitMember, UnionMember,
+};
+use crate::frontend::lexer::{Keyword, Token, TokenKind, lex_with_file};
+use crate::frontend::type_utils::type_expr_surface;
+use crate::mir::{LocalKind, MirModule};
+
+use super::types::symbol_kind;
+
+/// A named declaration discovered in a single source file.
+#[derive(Clone, Debug)]
+pub(super) struct Declaration {
+    pub name: String,
+    pub qualified: String,
+    pub kind: u32,
+    pub detail: Option<String>,
+    /// Span of the declared name.
+    pub selection: Span,
+    /// Span covering the name through the end of the declaration body.
+    pub extent: Span,
+    pub parent: Option<usize>,
+}
+
+impl Declaration {
+    pub(super) fn is_type(&self) -> bool {
+        matches!(
+            self.kind,
+            symbol_kind::CLASS | symbol_kind::STRUCT | symbol_kind::ENUM | symbol_kind::INTERFACE
+        )
+    }
+}
+
+/// A named MIR local (parameter or binding) with its lowered type.
+#[derive(Clone, Debug)]
+pub(super) struct LocalBinding {
+    pub name: String,
+    pub ty: String,
+    pub span: Option<Span>,
+    /// Span of the block that declares the local (the owning function for parameters);
+    /// locals are only visible inside it.
+    pub scope: Span,
+    /// `var` bindings (and mutable parameters); `let` bindings are read-only.
+    pub mutable: bool,
+    pub parameter: bool,
+}
+
+/// Collect user-visible locals from every lowered function body.
+pub(super) fn collect_local_bindings(module: &MirModule, files: &FileCache) -> Vec<LocalBinding> {
+    let mut blocks: HashMap<FileId, Vec<Span>> = HashMap::new();
+    let mut bindings = Vec::new();
+    for function in &module.functions {
+        let Some(function_scope) = function_scope(function.span, function.body.span) else {
+            continue;
+        };
+        let blocks = blocks.entry(function_scope.file_id).or_insert_with(|| {
+            files
+                .get(function_scope.file_id)
+                .map(|file| brace_blocks(&file.source, function_scope.file_id))
+                .unwrap_or_default()
+        });
+        for local in &function.body.locals {
+            let Some(name) = local.name.as_deref() else {
+                continue;
+            };
+            if !matches!(local.kind, LocalKind::Arg(_) | LocalKind::Local) {
+                continue;
+            }
+            if name.is_empty() || name.starts_with('$') || name.contains('$') {
+                continue;
+            }
+            let parameter = matches!(local.kind, LocalKind::Arg(_));
+            let scope = match local.span {
+                Some(span) if !parameter => enclosing_block(blocks, function_scope, span),
+                _ => function_scope,
+            };
+            bindings.push(LocalBinding {
+                name: name.to_string(),
+                ty: local.ty.canonical_name(),
+                span: local.span,
+                scope,
+                mutable: local.mutable,
+                parameter,
+            });
+        }
+    }
+    bindings
+}
+
+fn function_scope(function: Option<Span>, body: Option<Span>) -> Option<Span> {
+    match (function, body) {
+        (Some(function), Some(body)) if function.file_id == body.file_id => Some(Span::in_file(
+            function.file_id,
+            function.start.min(body.start),
+            function.end.max(body.end),
+        )),
+        (Some(span), _) | (None, Some(span)) => Some(span),
+        (None, None) => None,
+    }
+}
+
+/// Spans of every `{ ... }` pair in `source`, from the opening to the closing brace.
+fn brace_blocks(source: &str, file_id: FileId) -> Vec<Span> {
+    let mut open = Vec::new();
+    let mut blocks = Vec::new();
+    for token in lex_with_file(source, file_id).tokens {
+        match token.kind {
+            TokenKind::Punctuation('{') => open.push(token.span.start),
+            TokenKind::Punctuation('}') => {
+                if let Some(start) = open.pop() {
+                    blocks.push(Span::in_file(file_id, start, token.span.end));
+                }
+            }
+            _ => {}
+        }
+    }
+    blocks
+}
+
+/// The innermost block inside `function` that contains the declaration at `at`, or the
+/// whole function when the local is not declared inside a block (expression bodies).
+fn enclosing_block(blocks: &[Span], function: Span, at: Span) -> Span {
+    blocks
+        .iter()
+        .filter(|block| {
+            function.start <= block.start
+                && block.end <= function.end
+                && block.start < at.start
+                && at.end <= block.end
+        })
+        .min_by_key(|block| block.end - block.start)
+        .copied()
+        .unwrap_or(function)
+}
+
+/// Strip generic arguments, nullability and namespace qualification from a type name.
+pub(super) fn type_leaf(name: &str) -> &str {
+    let trimmed = name.trim().trim_end_matches('?');
+    let base = trimmed.split('<').next().unwrap_or(trimmed);
+    base.rsplit(|ch| ch == ':' || ch == '.')
+        .find(|part| !part.is_empty())
+        .unwrap_or(base)
+}
+
+/// Collect every declaration in `module`, whose source text is `source` in `file_id`.
+pub(super) fn collect_declarations(
+    module: &Module,
+    source: &str,
+    file_id: FileId,
+) -> Vec<Declaration> {
+    let tokens = lex_with_file(source, file_id)
+        .tokens
+        .into_iter()
+        .filter(|token| {
+            !matches!(
+                token.kind,
+                TokenKind::Whitespace | TokenKind::Comment | TokenKind::DocComment
+            )
+        })
+        .collect::<Vec<_>>();
+    let mut collector = Collector {
+        file_id,
+        end: tokens.len(),
+        tokens,
+        claimed: HashSet::new(),
+        decls: Vec::new(),
+    };
+    let scope = Scope {
+        lo: 0,
+        hi: collector.end,
+        parent: None,
+        prefix: String::new(),
+    };
+    match module.namespace.as_deref() {
+        Some(namespace) => {
+            let ns_scope = collector.namespace(namespace, module.namespace_span, &scope);
+            collector.items(&module.items, &ns_scope);
+        }
+        None => collector.items(&module.items, &scope),
+    }
+    collector.decls
+}
+
+#[derive(Clone)]
+struct Scope {
+    lo: usize,
+    hi: usize,
+    parent: Option<usize>,
+    prefix: String,
+}
+
+impl Scope {
+    fn qualify(&self, name: &str) -> String {
+        if self.prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}::{name}", self.prefix)
+        }
+    }
+}
+
+struct Collector {
+    file_id: FileId,
+    tokens: Vec<Token>,
+    end: usize,
+    claimed: HashSet<usize>,
+    decls: Vec<Declaration>,
+}
+
+impl Collector {
+    fn items(&mut self, items: &[Item], scope: &Scope) {
+        for item in items {
+            self.item(item, scope);
+        }
+    }
+
+    fn item(&mut self, item: &Item, scope: &Scope) {
+        match item {
+            Item::Function(func) => self.function(func, symbol_kind::FUNCTION, scope),
+            Item::Struct(decl) => {
+                let Some(body) = self.type_decl(&decl.name, symbol_kind::STRUCT, None, scope)
+                else {
+                    return;
+                };
+                for field in &decl.fields {
+                    self.member(
+                        &field.name,
+                        symbol_kind::FIELD,
+                        Some(type_expr_surface(&field.ty)),
+                        &body,
+                    );
+                }
+                for property in &decl.properties {
+                    self.member(
+                        &property.name,
+                        symbol_kind::PROPERTY,
+                        Some(type_expr_surface(&property.ty)),
+                        &body,
+                    );
+                }
+                for constructor in &decl.constructors {
+                    self.constructor(&decl.name, &constructor.parameters, &body);
+                }
+                for constant in &decl.consts {
+                    self.constants(&constant.declaration, &body);
+                }
+                for method in &decl.methods {
+                    self.function(method, symbol_kind::METHOD, &body);
+                }
+                self.items(&decl.nested_types, &body);
+            }
+            Item::Union(decl) => {
+                let Some(body) = self.type_decl(&decl.name, symbol_kind::STRUCT, None, scope)
+                else {
+                    return;
+                };
+                for member in &decl.members {
+                    match member {
+                        UnionMember::Field(field) => {
+                            self.member(
+                                &field.name,
+                                symbol_kind::FIELD,
+                                Some(type_expr_surface(&field.ty)),
+                                &body,
+                            );
+                        }
+                        UnionMember::View(view) => {
+                            self.member(&view.name, symbol_kind::STRUCT, None, &body);
+                        }
+                    }
+                }
+            }
+            Item::Enum(decl) => {
+                let Some(body) = self.type_decl(&decl.name, symbol_kind::ENUM, None, scope) else {
+                    return;
+                };
+                for variant in &decl.variants {
+                    if let Some(index) = self.find(&variant.name, &body, None) {
+                        self.push(
+                            &variant.name,
+                            symbol_kind::ENUM_MEMBER,
+                            None,
+                            index,
+                            Some(index),
+                            &body,
+                        );
+                    }
+                }
+            }
+            Item::Class(decl) => {
+                let Some(body) = self.type_decl(&decl.name, symbol_kind::CLASS, None, scope) else {
+                    return;
+                };
+                for member in &decl.members {
+                    match member {
+                        ClassMember::Field(field) => {
+                            self.member(
+                                &field.name,
+                                symbol_kind::FIELD,
+                                Some(type_expr_surface(&field.ty)),
+                                &body,
+                            );
+                        }
+                        ClassMember::Method(method) => {
+                            self.function(method, symbol_kind::METHOD, &body);
+                        }
+                        ClassMember::Property(property) => {
+                            self.member(
+                                &property.name,
+                                symbol_kind::PROPERTY,
+                                Some(type_expr_surface(&property.ty)),
+                                &body,
+                            );
+                        }
+                        ClassMember::Constructor(constructor) => {
+                            self.constructor(&decl.name, &constructor.parameters, &body);
+                        }
+                        ClassMember::Const(constant) => {
+                            self.constants(&constant.declaration, &body)
+                        }
+                    }
+                }
+                self.items(&decl.nested_types, &body);
+            }
+            Item::Interface(decl) => {
+                let Some(body) = self.type_decl(&decl.name, symbol_kind::INTERFACE, None, scope)
+                else {
+                    return;
+                };
+                for member in &decl.members {
+                    match member {
+                        InterfaceMember::Method(method) => {
+                            self.function(method, symbol_kind::METHOD, &body);
+                        }
+                        InterfaceMember::Property(property) => {
+                            self.member(
+                                &property.name,
+                                symbol_kind::PROPERTY,
+                                Some(type_expr_surface(&property.ty)),
+                                &body,
+                            );
+                        }
+                        InterfaceMember::AssociatedType(assoc) => {
+                            self.member(&assoc.name, symbol_kind::TYPE_PARAMETER, None, &body);
+                        }
+                        InterfaceMember::Const(constant) => {
+                            self.constants(&constant.declaration, &body);
+                        }
+                    }
+                }
+            }
+            Item::Trait(decl) => {
+                let Some(body) = self.type_decl(&decl.name, symbol_kind::INTERFACE, None, scope)
+                else {
+                    return;
+                };
+                for member in &decl.members {
+                    match member {
+                        TraitMember::Method(method) => {
+                            self.function(method, symbol_kind::METHOD, &body);
+                        }
+                        TraitMember::AssociatedType(assoc) => {
+                            self.member(&assoc.name, symbol_kind::TYPE_PARAMETER, None, &body);
+                        }
+                        TraitMember::Const(constant) => {
+                            self.constants(&constant.declaration, &body)
+                        }
+                    }
+                }
+            }
+            Item::Impl(decl) => {
+                let body = self.anonymous_body(decl.span, scope);
+                for member in &decl.members {
+                    match member {
+                        ImplMember::Method(method) => {
+                            self.function(method, symbol_kind::METHOD, &body);
+                        }
+                        ImplMember::AssociatedType(assoc) => {
+                            self.member(&assoc.name, symbol_kind::TYPE_PARAMETER, None, &body);
+                        }
+                        ImplMember::Const(constant) => self.constants(&constant.declaration, &body),
+                    }
+                }
+            }
+            Item::Extension(decl) => {
+                let body = self.anonymous_body(None, scope);
+                for member in &decl.members {
+                    let ExtensionMember::Method(method) = member;
+                    self.function(&method.function, symbol_kind::METHOD, &body);
+                }
+            }
+            Item::Delegate(decl) => {
+                let detail = Some(type_expr_surface(&decl.signature.return_type));
+                self.type_decl(&decl.name, symbol_kind::FUNCTION, detail, scope);
+            }
+            Item::TypeAlias(decl) => {
+                let detail = Some(type_expr_surface(&decl.target));
+                if let Some(index) = self.find(&decl.name, scope, decl.span) {
+                    self.push(&decl.name, symbol_kind::CLASS, detail, index, None, scope);
+                }
+            }
+            Item::TestCase(test) => {
+                if let Some(index) = self.find(&test.name, scope, None) {
+                    self.push(
+                        &test.name,
+                        symbol_kind::METHOD,
+                        Some(test.kind.keyword().into()),
+                        index,
+                        None,
+                        scope,
+                    );
+                }
+            }
+            Item::Namespace(ns) => {
+                let body = self.namespace(&ns.name, ns.span, scope);
+                self.items(&ns.items, &body);
+            }
+            Item::Const(constant) => self.constants(&constant.declaration, scope),
+            Item::Static(item) => {
+                let detail = Some(type_expr_surface(&item.declaration.ty));
+                for declarator in &item.declaration.declarators {
+                    if let Some(index) = self.find(&declarator.name, scope, declarator.span) {
+                        self.push(
+                            &declarator.name,
+                            symbol_kind::VARIABLE,
+                            detail.clone(),
+                            index,
+                            None,
+                            scope,
+                        );
+                    }
+                }
+            }
+            Item::Import(_) => {}
+        }
+    }
+
+    fn namespace(&mut self, name: &str, span: Option<Span>, scope: &Scope) -> Scope {
+        // Namespaces are dotted paths; the selection covers the first segment we can see.
+        let first = name.split('.').next().unwrap_or(name);
+        let Some(index) = self.find(first, scope, span) else {
+            return Scope {
+                prefix: scope.qualify(&name.replace('.', "::")),
+                ..scope.clone()
+            };
+        };
+        let (last, body) = self.extent(index, scope.hi);
+        let qualified = scope.qualify(&name.replace('.', "::"));
+        let decl = self.push_raw(
+            name,
+            qualified.clone(),
+            symbol_kind::NAMESPACE,
+            None,
+            index,
+            last,
+            scope.parent,
+        );
+        match body {
+            Some((lo, hi)) => Scope {
+                lo,
+                hi,
+                parent: Some(decl),
+                prefix: qualified,
+            },
+            // File-scoped namespace: everything after the declaration belongs to it.
+            None => {
+                let file_end = self.end.saturating_sub(1).max(index);
+                if let Some(entry) = self.decls.get_mut(decl) {
+                    entry.extent = Span::in_file(
+                        self.file_id,
+                        entry.selection.start,
+                        self.tokens
+                            .get(file_end)
+                            .map_or(entry.selection.end, |token| token.span.end),
+                    );
+                }
+                Scope {
+                    lo: last + 1,
+                    hi: scope.hi,
+                    parent: Some(decl),
+                    prefix: qualified,
+                }
+            }
+        }
+    }
+
+    fn type_decl(
+        &mut self,
+        name: &str,
+        kind: u32,
+        detail: Option<String>,
+        scope: &Scope,
+    ) -> Option<Scope> {
+        let index = self.find_after_keyword(name, scope)?;
+        let (last, body) = self.extent(index, scope.hi);
+        let qualified = scope.qualify(name);
+        let decl = self.push_raw(
+            name,
+            qualified.clone(),
+            kind,
+            detail,
+            index,
+            last,
+            scope.parent,
+        );
+        let (lo, hi) = body.unwrap_or((last, last));
+        Some(Scope {
+            lo,
+            hi,
+            parent: Some(decl),
+            prefix: qualified,
+        })
+    }
+
+    fn anonymous_body(&mut self, span: Option<Span>, scope: &Scope) -> Scope {
+        let start = span
+            .and_then(|span| self.index_at(span.start))
+            .unwrap_or(scope.lo);
+        let (_, body) = self.extent(start, scope.hi);
+        let (lo, hi) = body.unwrap_or((scope.lo, scope.hi));
+        Scope {
+            lo,
+            hi,
+            parent: scope.parent,
+            prefix: scope.prefix.clone(),
+        }
+    }
+
+    fn function(&mut self, func: &FunctionDecl, kind: u32, scope: &Scope) {
+        let name = func.name.rsplit('.').next().unwrap_or(&func.name);
+        let kind = if func.operator.is_some() {
+            symbol_kind::OPERATOR
+        } else {
+            kind
+        };
+        let detail = Some(format!(
+            "{}({})",
+            type_expr_surface(&func.signature.return_type),
+            format_parameters(&func.signature.parameters)
+        ));
+        if let Some(index) = self.find(name, scope, func.name_span) {
+            self.push(name, kind, detail, index, None, scope);
+        }
+    }
+
+    fn constructor(&mut self, owner: &str, parameters: &[Parameter], scope: &Scope) {
+        let detail = Some(format!("init({})", format_parameters(parameters)));
+        let Some(index) = self
+            .find_keyword(Keyword::Init, scope)
+            .or_else(|| self.find(owner, scope, None))
+        else {
+            return;
+        };
+        self.push(owner, symbol_kind::CONSTRUCTOR, detail, index, None, scope);
+    }
+
+    fn constants(&mut self, declaration: &ConstDeclaration, scope: &Scope) {
+        let detail = Some(type_expr_surface(&declaration.ty));
+        for declarator in &declaration.declarators {
+            if let Some(index) = self.find(&declarator.name, scope, declarator.span) {
+                self.push(
+                    &declarator.name,
+                    symbol_kind::CONSTANT,
+                    detail.clone(),
+                    index,
+                    None,
+                    scope,
+                );
+            }
+        }
+    }
+
+    fn member(&mut self, name: &str, kind: u32, detail: Option<String>, scope: &Scope) {
+        if let Some(index) = self.find(name, scope, None) {
+            self.push(name, kind, detail, index, None, scope);
+        }
+    }
+
+    fn push(
+        &mut self,
+        name: &str,
+        kind: u32,
+        detail: Option<String>,
+        index: usize,
+        last: Option<usize>,
+        scope: &Scope,
+    ) -> usize {
+        let last = last.unwrap_or_else(|| self.extent(index, scope.hi).0);
+        self.push_raw(
+            name,
+            scope.qualify(name),
+            kind,
+            detail,
+            index,
+            last,
+            scope.parent,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn push_raw(
+        &mut self,
+        name: &str,
+        qualified: String,
+        kind: u32,
+        detail: Option<String>,
+        index: usize,
+        last: usize,
+        parent: Option<usize>,
+    ) -> usize {
+        self.claimed.insert(index);
+        let selection = self.tokens[index].span;
+        let end = self
+            .tokens
+            .get(last)
+            .map_or(selection.end, |token| token.span.end.max(selection.end));
+        self.decls.push(Declaration {
+            name: name.to_string(),
+            qualified,
+            kind,
+            detail,
+            selection,
+            extent: Span::in_file(self.file_id, selection.start, end),
+            parent,
+        });
+        self.decls.len() - 1
+    }
+
+    fn index_at(&self, offset: usize) -> Option<usize> {
+        self.tokens.iter().position(|token| {
+            token.span.start <= offset && offset < token.span.end.max(token.span.start + 1)
+        })
+    }
+
+    /// Find the first unclaimed identifier `name` directly inside `scope`.
+    fn find(&self, name: &str, scope: &Scope, hint: Option<Span>) -> Option<usize> {
+        if let Some(hint) = hint {
+            let hinted = (scope.lo..scope.hi.min(self.end)).find(|&idx| {
+                let token = &self.tokens[idx];
+                token.span.start >= hint.start
+                    && token.span.start < hint.end.max(hint.start + 1)
+                    && is_name(token, name)
+            });
+            if hinted.is_some() {
+                return hinted;
+            }
+        }
+        self.scan(scope, |collector, idx| {
+            is_name(&collector.tokens[idx], name)
+        })
+    }
+
+    /// Type names are preferred where they follow a declaring keyword (`class Foo`).
+    fn find_after_keyword(&self, name: &str, scope: &Scope) -> Option<usize> {
+        self.scan(scope, |collector, idx| {
+            if idx == 0 || !is_name(&collector.tokens[idx], name) {
+                return false;
+            }
+            let previous = &collector.tokens[idx - 1];
+            matches!(
+                previous.kind,
+                TokenKind::Keyword(
+                    Keyword::Class
+                        | Keyword::Struct
+                        | Keyword::Enum
+                        | Keyword::Union
+                        | Keyword::Interface
+                        | Keyword::Delegate
+                )
+            ) || previous.lexeme == "trait"
+        })
+        .or_else(|| self.find(name, scope, None))
+    }
+
+    fn find_keyword(&self, keyword: Keyword, scope: &Scope) -> Option<usize> {
+        self.scan(scope, |collector, idx| {
+            collector.tokens[idx].kind == TokenKind::Keyword(keyword)
+        })
+    }
+
+    fn scan<F>(&self, scope: &Scope, predicate: F) -> Option<usize>
+    where
+        F: Fn(&Self, usize) -> bool,
+    {
+        let mut braces = 0usize;
+        let mut parens = 0usize;
+        for idx in scope.lo..scope.hi.min(self.end) {
+            match self.tokens[idx].kind {
+                TokenKind::Punctuation('{') => braces += 1,
+                TokenKind::Punctuation('}') => braces = braces.saturating_sub(1),
+                TokenKind::Punctuation('(' | '[') => parens += 1,
+                TokenKind::Punctuation(')' | ']') => parens = parens.saturating_sub(1),
+                _ => {
+                    if braces == 0
+                        && parens == 0
+                        && !self.claimed.contains(&idx)
+                        && predicate(self, idx)
+                    {
+                        return Some(idx);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the index of the last token of the declaration starting at `index`, plus the
+    /// token range of its brace-delimited body when it has one.
+    fn extent(&self, index: usize, hi: usize) -> (usize, Option<(usize, usize)>) {
+        let hi = hi.min(self.end);
+        let mut parens = 0usize;
+        let mut idx = index + 1;
+        while idx < hi {
+            match self.tokens[idx].kind {
+                TokenKind::Punctuation('(' | '[') => parens += 1,
+                TokenKind::Punctuation(')' | ']') => parens = parens.saturating_sub(1),
+                TokenKind::Punctuation(';' | ',') if parens == 0 => return (idx, None),
+                TokenKind::Punctuation('}') if parens == 0 => {
+                    return (idx.saturating_sub(1).max(index), None);
+                }
+                TokenKind::Punctuation('{') if parens == 0 => {
+                    let close = self.matching_brace(idx, hi);
+                    return (close, Some((idx + 1, close)));
+                }
+                _ => {}
+            }
+            idx += 1;
+        }
+        (index, None)
+    }
+
+    fn matching_brace(&self, open: usize, hi: usize) -> usize {
+        let mut depth = 0usize;
+        for idx in open..hi {
+            match self.tokens[idx].kind {
+                TokenKind::Punctuation('{') => depth += 1,
+                TokenKind::Punctuation('}') => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        return idx;
+                    }
+                }
+                _ => {}
+            }
+        }
+        hi.saturating_sub(1)
+    }
+}
+
+fn is_name(token: &Token, name: &str) -> bool {
+    token.kind == TokenKind::Identifier && token.lexeme.trim_matches('`') == name
+}
+
+pub(super) fn format_parameters(parameters: &[Parameter]) -> String {
+    parameters
+        .iter()
+        .map(|param| format!("{} {}", type_expr_surface(&param.ty), param.name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::parse_module;
+
+    fn declarations(source: &str) -> Vec<Declaration> {
+        let parsed = parse_module(source).expect("parse module");
+        collect_declarations(&parsed.module, source, FileId(0))
+    }
+
+    #[test]
+    fn collects_nested_members_with_parents() {
+        let source = "namespace Demo;\n\npublic class Counter\n{\n    public int Count;\n    public int Next(int step) { return Count + step; }\n}\n";
+        let decls = declarations(source);
+        let names = decls
+            .iter()
+            .map(|decl| decl.qualified.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                "Demo",
+                "Demo::Counter",
+                "Demo::Counter::Count",
+                "Demo::Counter::Next"
+            ]
+        );
+        let counter = &decls[1];
+        assert_eq!(counter.kind, symbol_kind::CLASS);
+        assert_eq!(
+            &source[counter.selection.start..counter.selection.end],
+            "Counter"
+        );
+        assert_eq!(decls[3].parent, Some(1));
+        assert!(counter.extent.end >= decls[3].extent.end);
+    }
+
+    #[test]
+    fn member_lookup_skips_parameters_and_bodies() {
+        let source = "namespace Demo;\n\npublic struct Pair\n{\n    public int Sum(int Left) { let Right = Left; return Right; }\n    public int Left;\n    public int Right;\n}\n";
+        let decls = declarations(source);
+        let left = decls
+            .iter()
+            .find(|decl| decl.name == "Left")
+            .expect("field declaration");
+        let line = source[..left.selection.start].matches('\n').count();
+        assert_eq!(line, 5, "field should resolve to its own declaration line");
+    }
+}
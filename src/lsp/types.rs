@@ -17,9 +17,24 @@ pub mod methods {
     pub const DID_OPEN: &str = "textDocument/didOpen";
     pub const DID_CHANGE: &str = "textDocument/didChange";
     pub const DID_CLOSE: &str = "textDocument/didClose";
+    pub const DID_CHANGE_WATCHED_FILES: &str = "workspace/didChangeWatchedFiles";
 
     pub const HOVER: &str = "textDocument/hover";
     pub const DEFINITION: &str = "textDocument/definition";
+    pub const COMPLETION: &str = "textDocument/completion";
+    pub const REFERENCES: &str = "textDocument/references";
+    pub const RENAME: &str = "textDocument/rename";
+    pub const PREPARE_RENAME: &str = "textDocument/prepareRename";
+    pub const DOCUMENT_SYMBOL: &str = "textDocument/documentSymbol";
+    pub const WORKSPACE_SYMBOL: &str = "workspace/symbol";
+    pub const CODE_ACTION: &str = "textDocument/codeAction";
+    pub const FORMATTING: &str = "textDocument/formatting";
+    pub const RANGE_FORMATTING: &str = "textDocument/rangeFormatting";
+    pub const ON_TYPE_FORMATTING: &str = "textDocument/onTypeFormatting";
+    pub const SEMANTIC_TOKENS_FULL: &str = "textDocument/semanticTokens/full";
+    pub const SEMANTIC_TOKENS_DELTA: &str = "textDocument/semanticTokens/full/delta";
+    pub const INLAY_HINT: &str = "textDocument/inlayHint";
+    pub const SIGNATURE_HELP: &str = "textDocument/signatureHelp";
 
     pub const PUBLISH_DIAGNOSTICS: &str = "textDocument/publishDiagnostics";
 }
@@ -49,6 +64,86 @@ pub struct ServerCapabilities {
     pub hover_provider: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub definition_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completion_provider: Option<CompletionOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub references_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rename_provider: Option<RenameOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_symbol_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workspace_symbol_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_action_provider: Option<CodeActionOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_formatting_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_range_formatting_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_on_type_formatting_provider: Option<DocumentOnTypeFormattingOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub semantic_tokens_provider: Option<SemanticTokensOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inlay_hint_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature_help_provider: Option<SignatureHelpOptions>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionOptions {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub trigger_characters: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenameOptions {
+    pub prepare_provider: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeActionOptions {
+    pub code_action_kinds: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentOnTypeFormattingOptions {
+    pub first_trigger_character: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub more_trigger_character: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticTokensLegend {
+    pub token_types: Vec<String>,
+    pub token_modifiers: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticTokensFullOptions {
+    pub delta: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticTokensOptions {
+    pub legend: SemanticTokensLegend,
+    pub full: SemanticTokensFullOptions,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureHelpOptions {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub trigger_characters: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub retrigger_characters: Vec<String>,
 }
 
 #[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
@@ -150,6 +245,20 @@ pub struct DidCloseTextDocumentParams {
     pub text_document: TextDocumentIdentifier,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileEvent {
+    pub uri: Uri,
+    #[serde(rename = "type")]
+    pub kind: u32,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidChangeWatchedFilesParams {
+    pub changes: Vec<FileEvent>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TextDocumentContentChangeEvent {
@@ -209,6 +318,317 @@ pub struct Diagnostic {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<String>,
     pub message: String,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub related_information: Vec<DiagnosticRelatedInformation>,
 }
+
+/// `SymbolKind` values from the LSP specification (only the kinds Chic emits).
+pub mod symbol_kind {
+    pub const NAMESPACE: u32 = 3;
+    pub const CLASS: u32 = 5;
+    pub const METHOD: u32 = 6;
+    pub const PROPERTY: u32 = 7;
+    pub const FIELD: u32 = 8;
+    pub const CONSTRUCTOR: u32 = 9;
+    pub const ENUM: u32 = 10;
+    pub const INTERFACE: u32 = 11;
+    pub const FUNCTION: u32 = 12;
+    pub const VARIABLE: u32 = 13;
+    pub const CONSTANT: u32 = 14;
+    pub const ENUM_MEMBER: u32 = 22;
+    pub const STRUCT: u32 = 23;
+    pub const OPERATOR: u32 = 25;
+    pub const TYPE_PARAMETER: u32 = 26;
+}
+
+/// `CompletionItemKind` values from the LSP specification.
+pub mod completion_kind {
+    pub const METHOD: u32 = 2;
+    pub const FUNCTION: u32 = 3;
+    pub const CONSTRUCTOR: u32 = 4;
+    pub const FIELD: u32 = 5;
+    pub const VARIABLE: u32 = 6;
+    pub const CLASS: u32 = 7;
+    pub const INTERFACE: u32 = 8;
+    pub const MODULE: u32 = 9;
+    pub const PROPERTY: u32 = 10;
+    pub const ENUM: u32 = 13;
+    pub const KEYWORD: u32 = 14;
+    pub const ENUM_MEMBER: u32 = 20;
+    pub const CONSTANT: u32 = 21;
+    pub const STRUCT: u32 = 22;
+    pub const OPERATOR: u32 = 24;
+    pub const TYPE_PARAMETER: u32 = 25;
+}
+
+pub type CompletionParams = TextDocumentPositionParams;
+pub type PrepareRenameParams = TextDocumentPositionParams;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionItem {
+    pub label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_text: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionList {
+    pub is_incomplete: bool,
+    pub items: Vec<CompletionItem>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceContext {
+    pub include_declaration: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceParams {
+    pub text_document: TextDocumentIdentifier,
+    pub position: Position,
+    #[serde(default)]
+    pub context: ReferenceContext,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenameParams {
+    pub text_document: TextDocumentIdentifier,
+    pub position: Position,
+    pub new_name: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrepareRenameResult {
+    pub range: Range,
+    pub placeholder: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TextEdit {
+    pub range: Range,
+    pub new_text: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceEdit {
+    pub changes: std::collections::BTreeMap<Uri, Vec<TextEdit>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentSymbolParams {
+    pub text_document: TextDocumentIdentifier,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentSymbol {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    pub kind: u32,
+    pub range: Range,
+    pub selection_range: Range,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<DocumentSymbol>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceSymbolParams {
+    pub query: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolInformation {
+    pub name: String,
+    pub kind: u32,
+    pub location: Location,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_name: Option<String>,
+}
+
+/// `CodeActionKind` values used by Chic code actions.
+pub mod code_action_kind {
+    pub const QUICK_FIX: &str = "quickfix";
+    pub const SOURCE_FIX_ALL: &str = "source.fixAll.chic";
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeActionContext {
+    #[serde(default)]
+    pub diagnostics: Vec<Diagnostic>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeActionParams {
+    pub text_document: TextDocumentIdentifier,
+    pub range: Range,
+    #[serde(default)]
+    pub context: CodeActionContext,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeAction {
+    pub title: String,
+    pub kind: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub diagnostics: Vec<Diagnostic>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub is_preferred: bool,
+    pub edit: WorkspaceEdit,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FormattingOptions {
+    pub tab_size: u32,
+    pub insert_spaces: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentFormattingParams {
+    pub text_document: TextDocumentIdentifier,
+    #[serde(default)]
+    pub options: FormattingOptions,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentRangeFormattingParams {
+    pub text_document: TextDocumentIdentifier,
+    pub range: Range,
+    #[serde(default)]
+    pub options: FormattingOptions,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentOnTypeFormattingParams {
+    pub text_document: TextDocumentIdentifier,
+    pub position: Position,
+    pub ch: String,
+    #[serde(default)]
+    pub options: FormattingOptions,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticTokensParams {
+    pub text_document: TextDocumentIdentifier,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticTokensDeltaParams {
+    pub text_document: TextDocumentIdentifier,
+    pub previous_result_id: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticTokens {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_id: Option<String>,
+    pub data: Vec<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticTokensEdit {
+    pub start: u32,
+    pub delete_count: u32,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub data: Vec<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticTokensDelta {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_id: Option<String>,
+    pub edits: Vec<SemanticTokensEdit>,
+}
+
+/// Reply to a delta request: a delta against the client's result, or full tokens when the
+/// previous result is unknown.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum SemanticTokensDeltaResult {
+    Delta(SemanticTokensDelta),
+    Full(SemanticTokens),
+}
+
+pub mod inlay_hint_kind {
+    pub const TYPE: u32 = 1;
+    pub const PARAMETER: u32 = 2;
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InlayHintParams {
+    pub text_document: TextDocumentIdentifier,
+    pub range: Range,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InlayHint {
+    pub position: Position,
+    pub label: String,
+    pub kind: u32,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub padding_left: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub padding_right: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureHelpParams {
+    pub text_document: TextDocumentIdentifier,
+    pub position: Position,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParameterInformation {
+    /// Start and end (UTF-16 offsets) of the parameter within the signature label.
+    pub label: [u32; 2],
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureInformation {
+    pub label: String,
+    pub parameters: Vec<ParameterInformation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureHelp {
+    pub signatures: Vec<SignatureInformation>,
+    pub active_signature: u32,
+    pub active_parameter: u32,
+}
@@ -0,0 +1,216 @@
+//! Manifest discovery and package graph loading for workspace-aware analysis.
+//!
+//! Every open document belongs to an analysis unit: the `manifest.yaml` project that
+//! contains it, or the document alone when no manifest is found. Projects resolve their
+//! dependency graph through the package resolver so edits in a dependency's sources can be
+//! traced back to the projects that consume it. Resolution is read-only: the editor never
+//! rewrites `manifest.lock` or fetches packages, and a project is reloaded once its
+//! manifest or lock changes on disk.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::chic_kind::ChicKind;
+use crate::manifest::{Manifest, WorkspaceConfig};
+use crate::package::{LockMode, ResolveOptions, resolve_dependencies};
+
+const LOCKFILE: &str = "manifest.lock";
+
+/// A `manifest.yaml` project and the roots of every package in its resolved graph.
+pub(super) struct Project {
+    pub manifest: Manifest,
+    pub workspace: Option<WorkspaceConfig>,
+    /// Directory containing the manifest.
+    pub root: PathBuf,
+    /// Roots of resolved dependencies (canonical where possible).
+    pub dependency_roots: Vec<PathBuf>,
+    /// Modification times of the manifest and lock the project was loaded from.
+    stamps: [Option<SystemTime>; 2],
+}
+
+impl Project {
+    /// Resolve the dependency graph of a discovered manifest from its lock and the offline
+    /// package cache.
+    pub fn load(manifest: Manifest) -> Option<Self> {
+        let manifest_path = manifest.path()?.to_path_buf();
+        let root = manifest_path.parent()?.to_path_buf();
+        let stamps = file_stamps(&manifest_path, &root);
+        let lockfile = root.join(LOCKFILE);
+        let outcome = resolve_dependencies(
+            &manifest,
+            &manifest_path,
+            &ResolveOptions::from_env(Some(lockfile)).with_lock_mode(LockMode::ReadOnly),
+        );
+        let dependency_roots = outcome
+            .packages
+            .iter()
+            .map(|package| canonical(&package.root))
+            .filter(|package_root| *package_root != root)
+            .collect();
+        let workspace = WorkspaceConfig::discover(&root).ok().flatten();
+        Some(Self {
+            manifest,
+            workspace,
+            root,
+            dependency_roots,
+            stamps,
+        })
+    }
+
+    /// Whether the manifest or lock changed on disk since the project was loaded.
+    pub fn is_stale(&self) -> bool {
+        self.manifest
+            .path()
+            .is_none_or(|path| file_stamps(path, &self.root) != self.stamps)
+    }
+
+    /// Whether `path` is the manifest or lock this project was loaded from.
+    pub fn is_project_file(&self, path: &Path) -> bool {
+        let path = canonical(path);
+        self.manifest
+            .path()
+            .is_some_and(|manifest| canonical(manifest) == path)
+            || canonical(&self.root.join(LOCKFILE)) == path
+    }
+
+    /// Re-read the manifest and resolve the project again; `None` once it is gone.
+    pub fn reload(&self) -> Option<Self> {
+        let manifest = Manifest::discover(&self.root).ok().flatten()?;
+        Self::load(manifest)
+    }
+
+    pub fn kind(&self) -> ChicKind {
+        self.manifest.build().kind.unwrap_or(ChicKind::Executable)
+    }
+
+    /// Whether `path` lies in a resolved dependency of this project.
+    pub fn depends_on(&self, path: &Path) -> bool {
+        let path = canonical(path);
+        self.dependency_roots
+            .iter()
+            .any(|package_root| path.starts_with(package_root))
+    }
+
+    /// Source files of the root package, read from the manifest's source roots (and the
+    /// `tests` directory when tests are enabled).
+    pub fn source_files(&self) -> Vec<PathBuf> {
+        let mut roots: Vec<PathBuf> = self
+            .manifest
+            .derived_source_roots()
+            .into_iter()
+            .map(|source_root| self.root.join(source_root.path))
+            .collect();
+        let tests = self.manifest.tests();
+        if tests.enabled && tests.include_tests_dir {
+            roots.push(self.root.join("tests"));
+        }
+        let mut files = Vec::new();
+        for root in roots {
+            if root.is_file() {
+                files.push(canonical(&root));
+            } else {
+                collect_ch_files(&root, &mut files);
+            }
+        }
+        files.sort();
+        files.dedup();
+        files
+    }
+}
+
+fn file_stamps(manifest: &Path, root: &Path) -> [Option<SystemTime>; 2] {
+    let modified = |path: &Path| fs::metadata(path).and_then(|meta| meta.modified()).ok();
+    [modified(manifest), modified(&root.join(LOCKFILE))]
+}
+
+fn collect_ch_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_ch_files(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "ch") {
+            files.push(canonical(&path));
+        }
+    }
+}
+
+/// Canonicalise `path`, falling back to the path itself for buffers not yet on disk.
+pub(super) fn canonical(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn discovers_project_sources_and_path_dependencies() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let app = dir.path().join("app");
+        let lib = dir.path().join("lib");
+        fs::create_dir_all(app.join("src")).expect("app src");
+        fs::create_dir_all(lib.join("src")).expect("lib src");
+        fs::write(
+            app.join("manifest.yaml"),
+            "package:\n  name: app\n  namespace: App\n  version: 0.1.0\ndependencies:\n  lib:\n    path: ../lib\n",
+        )
+        .expect("app manifest");
+        fs::write(
+            lib.join("manifest.yaml"),
+            "package:\n  name: lib\n  namespace: Lib\n  version: 0.1.0\n",
+        )
+        .expect("lib manifest");
+        fs::write(app.join("src/main.ch"), "namespace App;\n").expect("app source");
+        fs::write(lib.join("src/util.ch"), "namespace Lib;\n").expect("lib source");
+
+        let manifest = Manifest::discover(&app.join("src/main.ch"))
+            .expect("discover manifest")
+            .expect("app manifest");
+        let project = Project::load(manifest).expect("project");
+        assert_eq!(project.root, canonical(&app));
+        assert_eq!(
+            project.source_files(),
+            vec![canonical(&app.join("src/main.ch"))]
+        );
+        assert!(project.depends_on(&lib.join("src/util.ch")));
+        assert!(!project.depends_on(&app.join("src/main.ch")));
+        assert!(
+            !app.join(LOCKFILE).exists(),
+            "editor resolution must not write manifest.lock"
+        );
+        assert!(!project.is_stale());
+        assert!(project.is_project_file(&app.join("manifest.yaml")));
+    }
+
+    #[test]
+    fn manifest_edits_mark_the_project_stale() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let app = dir.path().join("app");
+        fs::create_dir_all(app.join("src")).expect("app src");
+        let manifest_path = app.join("manifest.yaml");
+        fs::write(
+            &manifest_path,
+            "package:\n  name: app\n  namespace: App\n  version: 0.1.0\n",
+        )
+        .expect("app manifest");
+        fs::write(app.join("src/main.ch"), "namespace App;\n").expect("app source");
+        let manifest = Manifest::discover(&app)
+            .expect("discover")
+            .expect("manifest");
+        let project = Project::load(manifest).expect("project");
+
+        let file = fs::OpenOptions::new()
+            .append(true)
+            .open(&manifest_path)
+            .expect("open manifest");
+        file.set_modified(SystemTime::now() + std::time::Duration::from_secs(5))
+            .expect("touch manifest");
+        assert!(project.is_stale());
+        let reloaded = project.reload().expect("reloaded project");
+        assert!(!reloaded.is_stale());
+    }
+}
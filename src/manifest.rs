@@ -5,7 +5,7 @@ use crate::format::{FormatConfig, RawFormatSection};
 use crate::package::version::{Version, VersionReq};
 use crate::target::Target;
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -29,6 +29,7 @@ pub struct Manifest {
     code_style: CodeStyleConfig,
     sources: Vec<SourceRoot>,
     dependencies: Vec<Dependency>,
+    features: FeatureSettings,
     issues: Vec<ManifestIssue>,
 }
 
@@ -205,6 +206,47 @@ pub struct Dependency {
     pub name: String,
     pub requirement: Option<VersionReq>,
     pub source: DependencySource,
+    /// Only part of the graph when one of this package's active features enables it.
+    pub optional: bool,
+    /// Features to enable on the dependency.
+    pub features: Vec<String>,
+    /// Whether the dependency's `default` features are enabled as well.
+    pub default_features: bool,
+}
+
+/// The `features:` section: named features, what each one enables, and the defaults.
+#[derive(Debug, Clone, Default)]
+pub struct FeatureSettings {
+    /// Features enabled unless every dependent sets `default_features: false`.
+    pub default: Vec<String>,
+    pub features: BTreeMap<String, Vec<FeatureRef>>,
+}
+
+/// One entry in a feature's list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeatureRef {
+    /// Another feature of this package (or the optional dependency of that name).
+    Feature(String),
+    /// `dep:name`: an optional dependency.
+    Dependency(String),
+    /// `name/feature`: a feature of a dependency, which is enabled too when optional.
+    DependencyFeature { dependency: String, feature: String },
+}
+
+impl FeatureRef {
+    fn parse(text: &str) -> Self {
+        let text = text.trim();
+        if let Some(dependency) = text.strip_prefix("dep:") {
+            return Self::Dependency(dependency.trim().to_string());
+        }
+        match text.split_once('/') {
+            Some((dependency, feature)) => Self::DependencyFeature {
+                dependency: dependency.trim().to_string(),
+                feature: feature.trim().to_string(),
+            },
+            None => Self::Feature(text.to_string()),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -441,6 +483,11 @@ impl Manifest {
         &self.dependencies
     }
 
+    #[must_use]
+    pub fn features(&self) -> &FeatureSettings {
+        &self.features
+    }
+
     #[must_use]
     pub fn issues(&self) -> &[ManifestIssue] {
         &self.issues
@@ -561,6 +608,8 @@ impl Manifest {
                 Err(issue) => issues.push(issue),
             }
         }
+        let (features, mut feature_issues) = FeatureSettings::from_raw(raw.features, &dependencies);
+        issues.append(&mut feature_issues);
         Self {
             path,
             wasm: raw.runtime.wasm.map(WasmRuntimeSection::from_raw),
@@ -576,6 +625,7 @@ impl Manifest {
             code_style,
             sources,
             dependencies,
+            features,
             issues,
         }
     }
@@ -684,6 +734,7 @@ impl WasmRuntimeSettings {
             feature_flags: self.feature_flags.clone(),
             error_hook: None,
             coverage_hook: None,
+            snapshot_hook: None,
             io_hooks: None,
             async_layout: None,
             async_result_len: None,
@@ -698,6 +749,7 @@ impl WasmRuntimeSettings {
             rounding_mode: None,
             watchdog_step_limit: None,
             watchdog_timeout: None,
+            source_map: None,
         }
     }
 }
@@ -728,6 +780,8 @@ struct RawManifest {
     tests: RawTestsSection,
     #[serde(default)]
     coverage: Option<RawCoverageSection>,
+    #[serde(default)]
+    features: BTreeMap<String, Vec<String>>,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -817,6 +871,12 @@ struct RawDependencyDetail {
     tag: Option<String>,
     #[serde(default)]
     subdir: Option<String>,
+    #[serde(default)]
+    optional: bool,
+    #[serde(default)]
+    features: Vec<String>,
+    #[serde(default, alias = "default-features")]
+    default_features: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -1293,6 +1353,9 @@ impl Dependency {
                     name,
                     requirement: Some(requirement),
                     source: DependencySource::Registry { registry: None },
+                    optional: false,
+                    features: Vec::new(),
+                    default_features: true,
                 })
             }
             RawDependency::Detailed(detail) => {
@@ -1305,6 +1368,14 @@ impl Dependency {
                     })?),
                     None => None,
                 };
+                let optional = detail.optional;
+                let features = detail
+                    .features
+                    .iter()
+                    .map(|feature| feature.trim().to_string())
+                    .filter(|feature| !feature.is_empty())
+                    .collect::<Vec<_>>();
+                let default_features = detail.default_features.unwrap_or(true);
 
                 if let Some(path) = detail.path {
                     let resolved = if Path::new(&path).is_absolute() {
@@ -1318,6 +1389,9 @@ impl Dependency {
                         name,
                         requirement,
                         source: DependencySource::Path(resolved),
+                        optional,
+                        features,
+                        default_features,
                     });
                 }
 
@@ -1332,6 +1406,9 @@ impl Dependency {
                             tag: detail.tag,
                             subdir: detail.subdir.map(PathBuf::from),
                         },
+                        optional,
+                        features,
+                        default_features,
                     });
                 }
 
@@ -1348,12 +1425,91 @@ impl Dependency {
                     name,
                     requirement,
                     source: DependencySource::Registry { registry },
+                    optional,
+                    features,
+                    default_features,
                 })
             }
         }
     }
 }
 
+impl FeatureSettings {
+    fn from_raw(
+        raw: BTreeMap<String, Vec<String>>,
+        dependencies: &[Dependency],
+    ) -> (Self, Vec<ManifestIssue>) {
+        let mut settings = Self::default();
+        let mut issues = Vec::new();
+        for (name, entries) in raw {
+            let name = name.trim().to_string();
+            if name == "default" {
+                settings.default = entries
+                    .iter()
+                    .map(|entry| entry.trim().to_string())
+                    .collect();
+                continue;
+            }
+            if name.is_empty() || name.contains('/') || name.starts_with("dep:") {
+                issues.push(ManifestIssue::new(
+                    "PKG0206",
+                    format!("invalid feature name `{name}`"),
+                ));
+                continue;
+            }
+            let refs = entries
+                .iter()
+                .map(|entry| FeatureRef::parse(entry))
+                .collect();
+            settings.features.insert(name, refs);
+        }
+
+        let dependency = |name: &str| dependencies.iter().find(|dep| dep.name == name);
+        let check_feature = |owner: &str, feature: &str, issues: &mut Vec<ManifestIssue>| {
+            let implicit = dependency(feature).is_some_and(|dep| dep.optional);
+            if !settings.features.contains_key(feature) && !implicit {
+                issues.push(ManifestIssue::new(
+                    "PKG0206",
+                    format!("feature `{owner}` enables undeclared feature `{feature}`"),
+                ));
+            }
+        };
+        for feature in &settings.default {
+            check_feature("default", feature, &mut issues);
+        }
+        for (name, refs) in &settings.features {
+            for entry in refs {
+                match entry {
+                    FeatureRef::Feature(feature) => check_feature(name, feature, &mut issues),
+                    FeatureRef::Dependency(dep) => {
+                        if !dependency(dep).is_some_and(|dep| dep.optional) {
+                            issues.push(ManifestIssue::new(
+                                "PKG0206",
+                                format!(
+                                    "feature `{name}` enables `dep:{dep}`, which is not an optional dependency"
+                                ),
+                            ));
+                        }
+                    }
+                    FeatureRef::DependencyFeature {
+                        dependency: dep, ..
+                    } => {
+                        if dependency(dep).is_none() {
+                            issues.push(ManifestIssue::new(
+                                "PKG0206",
+                                format!(
+                                    "feature `{name}` enables a feature of `{dep}`, which is not a dependency"
+                                ),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        (settings, issues)
+    }
+}
+
 impl SourceRoot {
     fn from_raw(raw: RawSourceRoot) -> Option<Self> {
         let path = raw.path?;
@@ -1494,6 +1650,7 @@ impl WorkspaceConfig {
             code_style: RawCodeStyleSection::default(),
             tests: RawTestsSection::default(),
             coverage: None,
+            features: BTreeMap::new(),
         };
         let (coverage, _issues) = CoverageSettings::from_raw(raw.coverage.as_ref());
         Self {
@@ -1763,6 +1920,55 @@ coverage:
         assert_eq!(coverage.scope, CoverageScope::Package);
     }
 
+    #[test]
+    fn parses_features_and_reports_invalid_references() {
+        let dir = tempdir().expect("tempdir");
+        write_manifest(
+            dir.path(),
+            r#"
+dependencies:
+  json:
+    path: ../json
+    optional: true
+  math:
+    path: ../math
+    default_features: false
+    features: [simd]
+features:
+  default: [std, missing]
+  std: []
+  serde: ["dep:json", "json/derive"]
+  fast: ["dep:math", "other/fast"]
+"#,
+        );
+        let manifest = Manifest::discover(dir.path())
+            .expect("discover")
+            .expect("manifest");
+        let math = manifest
+            .dependencies()
+            .iter()
+            .find(|dep| dep.name == "math")
+            .expect("math dependency");
+        assert!(!math.default_features);
+        assert_eq!(math.features, vec!["simd".to_string()]);
+        assert_eq!(
+            manifest.features().features["serde"],
+            vec![
+                FeatureRef::Dependency("json".into()),
+                FeatureRef::DependencyFeature {
+                    dependency: "json".into(),
+                    feature: "derive".into(),
+                },
+            ]
+        );
+        let issues: Vec<_> = manifest
+            .issues()
+            .iter()
+            .filter(|issue| issue.code == "PKG0206")
+            .collect();
+        assert_eq!(issues.len(), 3, "{issues:?}");
+    }
+
     #[test]
     fn discover_loads_manifest_and_merges_defaults() {
         let dir = tempdir().expect("tempdir");
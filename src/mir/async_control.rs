@@ -4,9 +4,10 @@ pub const ASYNC_DIAG_NO_CAPTURE: &str = "AS0003";
 pub const ASYNC_DIAG_ATTRIBUTE: &str = "AS0004";
 
 use crate::frontend::diagnostics::Span;
+use serde::Serialize;
 
 /// Source-level policy hints applied to an async frame.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct AsyncFramePolicy {
     pub stack_only: Option<AttrSource>,
     pub frame_limit: Option<FrameLimitAttr>,
@@ -16,26 +17,26 @@ pub struct AsyncFramePolicy {
 }
 
 /// Marker describing where a policy was declared.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct AttrSource {
     pub span: Option<Span>,
 }
 
 /// `@frame_limit(bytes)` payload.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FrameLimitAttr {
     pub bytes: u64,
     pub span: Option<Span>,
 }
 
 /// `@no_capture` payload.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct NoCaptureAttr {
     pub mode: NoCaptureMode,
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum NoCaptureMode {
     Any,
     MoveOnly,
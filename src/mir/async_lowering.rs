@@ -21,6 +21,7 @@ use crate::mir::{
     LocalKind, MirBody, MirFunction, MirModule, Operand, ParamMode, Place, PointerTy, Rvalue,
     Terminator, Ty,
 };
+use serde::Serialize;
 
 const AWAIT_READY: u32 = 1;
 
@@ -641,7 +642,7 @@ fn synthesise_drop_function(plan: &AsyncPlanData, span: Option<Span>) -> MirFunc
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AsyncLoweringArtifact {
     pub function_index: usize,
     pub function_name: String,
@@ -660,14 +661,14 @@ pub struct AsyncLoweringArtifact {
     pub captured_locals: Vec<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AsyncFrameFieldPlan {
     pub local: LocalId,
     pub name: Option<String>,
     pub ty: Ty,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AsyncSuspendPlan {
     pub id: usize,
     pub await_block: BlockId,
@@ -677,14 +678,14 @@ pub struct AsyncSuspendPlan {
     pub destination_local: Option<LocalId>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AsyncResumeStatePlan {
     pub state_id: u32,
     pub resume_block: BlockId,
     pub drop_block: BlockId,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct AsyncFrameMetrics {
     pub frame_size: Option<usize>,
     pub frame_align: Option<usize>,
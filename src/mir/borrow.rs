@@ -1,6 +1,7 @@
 //! Borrow checking over Chic MIR.
 
 use crate::frontend::diagnostics::{Diagnostic, Severity};
+use crate::threading;
 
 use super::data::{Abi, MirFunction, MirModule};
 use super::layout::TypeLayoutTable;
@@ -27,15 +28,15 @@ impl BorrowCheckResult {
     }
 }
 
-/// Borrow check an entire MIR module.
+/// Borrow check an entire MIR module, one function per job.
 #[must_use]
 pub fn borrow_check_module(module: &MirModule) -> BorrowCheckResult {
     let mut result = BorrowCheckResult::default();
-    for function in &module.functions {
-        result.merge(borrow_check_function_with_layouts(
-            function,
-            &module.type_layouts,
-        ));
+    let results = threading::parallel_map(&module.functions, |function| {
+        borrow_check_function_with_layouts(function, &module.type_layouts)
+    });
+    for function_result in results {
+        result.merge(function_result);
     }
     result
 }
@@ -307,6 +307,9 @@ pub(super) struct BodyBuilder<'a> {
     labels: HashMap<String, LabelState>,
     pending_gotos: HashMap<String, Vec<PendingGoto>>,
     match_binding_counter: usize,
+    /// Blocks throwing `InvalidCastException` for a failed checked cast; these throws are
+    /// unchecked and stay out of the function's effects.
+    runtime_cast_failures: HashSet<BlockId>,
     async_cross_locals: HashSet<LocalId>,
     function_kind: FunctionKind,
     nested_functions: Vec<MirFunction>,
@@ -516,6 +519,12 @@ impl<'a> BodyBuilder<'a> {
                     Some(segments.join("::"))
                 }
             }
+            // Testcases declared inside a class share its access to non-public members.
+            FunctionKind::Testcase => function_name
+                .rsplit_once("::")
+                .map(|(owner, _)| owner)
+                .filter(|owner| type_visibilities.contains_key(*owner))
+                .map(str::to_string),
             _ => None,
         };
 
@@ -590,6 +599,7 @@ impl<'a> BodyBuilder<'a> {
             labels: HashMap::new(),
             pending_gotos: HashMap::new(),
             match_binding_counter: 0,
+            runtime_cast_failures: HashSet::new(),
             async_cross_locals: HashSet::new(),
             function_kind,
             nested_functions: Vec::new(),
@@ -3,7 +3,8 @@ use crate::frontend::parser::parse_type_expression_text;
 use crate::mir::CastKind;
 use crate::mir::Ty;
 use crate::mir::casts::{
-    float_info, int_cast_may_truncate, int_info, is_pointer_type, short_type_name,
+    INVALID_CAST_EXCEPTION, float_info, int_cast_may_truncate, int_info, is_pointer_type,
+    short_type_name,
 };
 use crate::mir::layout::table::TypeLayout;
 use crate::mir::operators::ConversionResolution;
@@ -147,19 +148,12 @@ body_builder_impl! {
             ));
         }
 
-        if self.is_class_downcast(&source_ty, &target_ty) {
-            self.diagnostics.push(LoweringDiagnostic {
-                message: format!(
-                    "downcasting from `{source_name}` to `{target_name}` is not supported; \
-consider pattern matching or a dedicated conversion helper"
-                ),
-                span,
-            });
-            return Some(self.emit_cast_rvalue(
-                CastKind::Unknown,
+        if self.is_runtime_checked_cast(&source_ty, &target_ty) {
+            return Some(self.emit_checked_reference_cast(
                 operand,
                 source_ty,
-                target_ty,
+                &target_ty,
+                syntax,
                 span,
             ));
         }
@@ -522,6 +516,193 @@ match on the variants instead"
         Operand::Copy(Place::new(temp))
     }
 
+    /// Lowers a reference cast that needs a runtime type test: the object's vtable is matched
+    /// against `target` and its subtypes. `as` yields `null` on a mismatch while the C-style
+    /// form throws `InvalidCastException`. A `null` from a nullable source casts to `null`.
+    #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+    fn emit_checked_reference_cast(
+        &mut self,
+        operand: Operand,
+        source_ty: Ty,
+        target_ty: &Ty,
+        syntax: CastSyntax,
+        span: Option<Span>,
+    ) -> Operand {
+        let source_local = self.ensure_operand_local(operand, span);
+        let target_name = self.non_nullable_name(target_ty);
+        let reference_ty = match target_ty {
+            Ty::Nullable(inner) => inner.as_ref().clone(),
+            other => other.clone(),
+        };
+        let source_nullable = matches!(source_ty, Ty::Nullable(_));
+        let result_ty = if source_nullable
+            || matches!(syntax, CastSyntax::As)
+            || matches!(target_ty, Ty::Nullable(_))
+        {
+            Ty::Nullable(Box::new(reference_ty.clone()))
+        } else {
+            reference_ty.clone()
+        };
+        let result = self.create_temp(span);
+        if let Some(local) = self.locals.get_mut(result.0) {
+            local.ty = result_ty.clone();
+            local.is_nullable = matches!(result_ty, Ty::Nullable(_));
+        }
+        let join = self.new_block(span);
+
+        // Nullable references are `{ HasValue, Value }` pairs; only a present value is tested.
+        let (object_local, object_ty) = match &source_ty {
+            Ty::Nullable(inner) => {
+                let mut has_value = Place::new(source_local);
+                has_value
+                    .projection
+                    .push(ProjectionElem::FieldNamed("HasValue".into()));
+                let mut value = Place::new(source_local);
+                value.projection.push(ProjectionElem::FieldNamed("Value".into()));
+                let null_block = self.new_block(span);
+                let present = self.new_block(span);
+                self.set_terminator(
+                    span,
+                    Terminator::SwitchInt {
+                        discr: Operand::Copy(has_value),
+                        targets: vec![(0, null_block)],
+                        otherwise: present,
+                    },
+                );
+                self.switch_to_block(null_block);
+                self.assign_null(result, span);
+                self.ensure_goto(join, span);
+
+                self.switch_to_block(present);
+                let object = self.create_temp(span);
+                if let Some(local) = self.locals.get_mut(object.0) {
+                    local.ty = inner.as_ref().clone();
+                    local.is_nullable = false;
+                }
+                self.push_statement(MirStatement {
+                    span,
+                    kind: MirStatementKind::Assign {
+                        place: Place::new(object),
+                        value: Rvalue::Use(Operand::Copy(value)),
+                    },
+                });
+                (object, inner.as_ref().clone())
+            }
+            _ => (source_local, source_ty),
+        };
+
+        let matched = self.new_block(span);
+        let mismatch = self.new_block(span);
+        self.set_terminator(
+            span,
+            Terminator::Match {
+                value: Place::new(object_local),
+                arms: vec![MatchArm {
+                    pattern: Pattern::Type(Ty::named(target_name.clone())),
+                    guard: None,
+                    bindings: Vec::new(),
+                    target: matched,
+                }],
+                otherwise: mismatch,
+            },
+        );
+
+        self.switch_to_block(matched);
+        let converted = self.emit_cast_rvalue(
+            CastKind::Unknown,
+            Operand::Copy(Place::new(object_local)),
+            object_ty.clone(),
+            reference_ty,
+            span,
+        );
+        let converted = self.coerce_operand_to_ty(converted, &result_ty, false, span);
+        self.push_statement(MirStatement {
+            span,
+            kind: MirStatementKind::Assign {
+                place: Place::new(result),
+                value: Rvalue::Use(converted),
+            },
+        });
+        self.ensure_goto(join, span);
+
+        self.switch_to_block(mismatch);
+        match syntax {
+            CastSyntax::As => {
+                self.assign_null(result, span);
+                self.ensure_goto(join, span);
+            }
+            CastSyntax::Paren => {
+                let source_name = self.non_nullable_name(&object_ty);
+                self.emit_invalid_cast(&source_name, &target_name, span);
+            }
+        }
+
+        self.switch_to_block(join);
+        Operand::Copy(Place::new(result))
+    }
+
+    fn assign_null(&mut self, local: LocalId, span: Option<Span>) {
+        self.push_statement(MirStatement {
+            span,
+            kind: MirStatementKind::Assign {
+                place: Place::new(local),
+                value: Rvalue::Use(Operand::Const(ConstOperand::new(ConstValue::Null))),
+            },
+        });
+    }
+
+    /// Throws `InvalidCastException` from the current block. Without the standard library the
+    /// exception type is unavailable and the failed cast panics instead.
+    fn emit_invalid_cast(&mut self, source_name: &str, target_name: &str, span: Option<Span>) {
+        let constructor = self
+            .symbol_index
+            .constructor_overloads(INVALID_CAST_EXCEPTION)
+            .into_iter()
+            .find(|symbol| symbol.signature.params == [Ty::Str])
+            .map(|symbol| (symbol.internal_name.clone(), symbol.signature.param_modes.clone()));
+        let exception_ty = Ty::named(INVALID_CAST_EXCEPTION);
+        let exception = constructor.and_then(|constructor| {
+            self.ensure_ty_layout_for_ty(&exception_ty);
+            let exception = self.allocate_reference_object(&exception_ty, span)?;
+            Some((constructor, exception))
+        });
+        let Some(((constructor, param_modes), exception)) = exception else {
+            self.set_terminator(span, Terminator::Panic);
+            return;
+        };
+        self.initialise_vtable_header(&exception, &exception_ty, span);
+        let message = self.normalise_const(
+            ConstValue::RawStr(format!(
+                "unable to cast `{source_name}` to `{target_name}`"
+            )),
+            span,
+        );
+        let constructed = self.new_block(span);
+        let unwind = self.current_unwind_target();
+        self.set_terminator(
+            span,
+            Terminator::Call {
+                func: Operand::Const(ConstOperand::new(ConstValue::Symbol(constructor))),
+                args: vec![
+                    Operand::Copy(exception.clone()),
+                    Operand::Const(ConstOperand::new(message)),
+                ],
+                arg_modes: std::iter::once(ParamMode::Value)
+                    .chain(param_modes)
+                    .collect(),
+                destination: None,
+                target: constructed,
+                unwind,
+                dispatch: None,
+            },
+        );
+        self.switch_to_block(constructed);
+        let block = self.current_block;
+        if self.emit_throw(span, Some(Operand::Copy(exception))) {
+            self.runtime_cast_failures.insert(block);
+        }
+    }
+
     fn maybe_emit_numeric_cast_warning(
         &mut self,
         source: crate::mir::casts::IntInfo,
@@ -720,7 +901,28 @@ match on the variants instead"
             .any(|candidate| self.type_names_equivalent_str(candidate, &source_name))
     }
 
-    fn is_class_type(&self, name: &str) -> bool {
+    /// Reference casts that cannot be proven statically: downcasts along the class chain and
+    /// casts from or to an interface.
+    pub(super) fn is_runtime_checked_cast(&self, source: &Ty, target: &Ty) -> bool {
+        let source_name = self.non_nullable_name(source);
+        let target_name = self.non_nullable_name(target);
+        if source_name == target_name
+            || !self.is_class_type(&source_name)
+            || !self.is_class_type(&target_name)
+        {
+            return false;
+        }
+        self.is_class_downcast(source, target)
+            || self.is_interface_type(&source_name)
+            || self.is_interface_type(&target_name)
+    }
+
+    pub(super) fn is_interface_type(&self, name: &str) -> bool {
+        let key = self.type_layouts.resolve_type_key(name).unwrap_or(name);
+        self.symbol_index.is_interface(key)
+    }
+
+    pub(super) fn is_class_type(&self, name: &str) -> bool {
         let Some(layout) = self.type_layouts.layout_for_name(name) else {
             return false;
         };
@@ -785,6 +785,7 @@ body_builder_impl! {
             } => {
                 let mut place = self.lower_place_expr(*base, span)?;
                 let index_count = indices.len();
+                let mut rectangular = false;
 
                 if let Some(base_ty) = self.place_ty(&place) {
                     let canonical_ty = Self::strip_nullable(&base_ty);
@@ -800,6 +801,7 @@ body_builder_impl! {
                                                                     });
                                 return None;
                             }
+                            rectangular = rank > 1;
                         }
                         Some(IndexableKind::Vec) => {
                             if index_count != 1 {
@@ -840,6 +842,13 @@ body_builder_impl! {
                     }
                 }
 
+                if rectangular {
+                    let flat = self.lower_rectangular_index(&place, indices, span)?;
+                    place.projection.push(ProjectionElem::Index(flat));
+                    self.normalise_place(&mut place);
+                    return Some(place);
+                }
+
                 for index_expr in indices {
                     let index_local = match index_expr {
                         ExprNode::IndexFromEnd(from_end) => {
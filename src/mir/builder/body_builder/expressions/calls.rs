@@ -332,6 +332,20 @@ body_builder_impl! {
                                 info: None,
                             }),
                             operand => {
+                                if member_name == "GetLength"
+                                    && let [argument] = args.as_slice()
+                                    && let Some(Ty::Array(array_ty)) = self
+                                        .operand_ty(&operand)
+                                        .map(|ty| Self::strip_nullable(&ty).clone())
+                                {
+                                    return self.lower_array_get_length(
+                                        operand,
+                                        &array_ty,
+                                        argument.clone(),
+                                        destination_override,
+                                        span,
+                                    );
+                                }
                                 if let Some(owner) = self.receiver_owner_from_operand(&operand) {
                                     call_info.receiver_owner = Some(owner);
                                 }
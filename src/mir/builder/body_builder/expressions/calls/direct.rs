@@ -893,18 +893,12 @@ body_builder_impl! {
         specialized: &str,
         type_args: Vec<Ty>,
     ) {
-        let mut store = self.generic_specializations.borrow_mut();
-        if store
-            .iter()
-            .any(|entry| entry.specialized == specialized || entry.base == base && entry.type_args == type_args)
-        {
-            return;
-        }
-        store.push(FunctionSpecialization {
+        FunctionSpecialization {
             base: base.to_string(),
             specialized: specialized.to_string(),
             type_args,
-        });
+        }
+        .record_into(&mut self.generic_specializations.borrow_mut());
     }
 
 
@@ -78,7 +78,14 @@ body_builder_impl! {
 
         if let Some(bin_op) = Self::bin_op_for_assign(op) {
             let lhs_view = Operand::Copy(place.clone());
-            match self.resolve_overloaded_binary(bin_op, &lhs_view, &rhs_operand, span) {
+            let resolution =
+                match self.resolve_compound_assignment_overload(bin_op, &lhs_view, &rhs_operand, span) {
+                    OperatorResolution::Skip => {
+                        self.resolve_overloaded_binary(bin_op, &lhs_view, &rhs_operand, span)
+                    }
+                    resolution => resolution,
+                };
+            match resolution {
                 OperatorResolution::Handled(overload) => {
                     let args = vec![Operand::Copy(place.clone()), rhs_operand];
                     let Some(result_operand) = self.emit_operator_call(overload, args, span) else {
@@ -195,6 +202,52 @@ body_builder_impl! {
         true
     }
 
+    /// Computes `current op value` for a compound assignment whose target is read and written
+    /// through accessors (properties, statics). A dedicated `operator op=` overload wins over the
+    /// binary operator overload, which in turn wins over the built-in operator.
+    pub(crate) fn lower_compound_assignment_value(
+        &mut self,
+        op: AssignOp,
+        current: Operand,
+        value_expr: ExprNode,
+        span: Option<Span>,
+    ) -> Option<Operand> {
+        let bin_op = Self::bin_op_for_assign(op)?;
+        let rhs_operand = self.lower_expr_node(value_expr, span)?;
+        let resolution =
+            match self.resolve_compound_assignment_overload(bin_op, &current, &rhs_operand, span) {
+                OperatorResolution::Skip => {
+                    self.resolve_overloaded_binary(bin_op, &current, &rhs_operand, span)
+                }
+                resolution => resolution,
+            };
+        match resolution {
+            OperatorResolution::Handled(overload) => {
+                return self.emit_operator_call(overload, vec![current, rhs_operand], span);
+            }
+            OperatorResolution::Error => return None,
+            OperatorResolution::Skip => {}
+        }
+        let current_ty = self.operand_ty(&current);
+        let result = self.create_temp(span);
+        if let Some(ty) = current_ty {
+            self.hint_local_ty(result, ty);
+        }
+        self.push_statement(MirStatement {
+            span,
+            kind: MirStatementKind::Assign {
+                place: Place::new(result),
+                value: Rvalue::Binary {
+                    op: bin_op,
+                    lhs: current,
+                    rhs: rhs_operand,
+                    rounding: None,
+                },
+            },
+        });
+        Some(Operand::Copy(Place::new(result)))
+    }
+
     pub(crate) fn try_property_assignment(
         &mut self,
         target: &ExprNode,
@@ -260,16 +313,6 @@ body_builder_impl! {
         };
         let symbol = symbol_ref.clone();
 
-        if op != AssignOp::Assign {
-            self.diagnostics.push(LoweringDiagnostic {
-                message: format!(
-                    "compound assignment on property `{type_name}.{member}` is not supported"
-                ),
-                span,
-            });
-            return Some(false);
-        }
-
         let Some((accessor_metadata, accessor_kind)) =
             self.property_setter_metadata(&symbol, &type_name, &member, span)
         else {
@@ -297,9 +340,23 @@ body_builder_impl! {
             return Some(false);
         }
 
-        let rhs_operand = match self.lower_expr_node(value_expr, span) {
-            Some(operand) => operand,
-            None => return Some(false),
+        let rhs_operand = if op == AssignOp::Assign {
+            self.lower_expr_node(value_expr, span)
+        } else if !symbol.accessors.contains_key(&PropertyAccessorKind::Get) {
+            self.diagnostics.push(LoweringDiagnostic {
+                message: format!("property `{type_name}.{member}` does not provide a getter"),
+                span: symbol.span.or(span),
+            });
+            None
+        } else {
+            // The receiver was lowered once above; the getter and setter both reuse it.
+            self.lower_property_member(&base_operand, &member, span)
+                .and_then(|current| {
+                    self.lower_compound_assignment_value(op, current, value_expr, span)
+                })
+        };
+        let Some(rhs_operand) = rhs_operand else {
+            return Some(false);
         };
 
         let mut args = Vec::new();
@@ -0,0 +1,135 @@
+use super::*;
+use crate::mir::layout::EnumVariantLayout;
+
+body_builder_impl! {
+    /// Resolves the `Enum.Variant` operand of a `new` expression to the enum layout name and
+    /// variant, returning `None` for ordinary struct/class targets.
+    pub(super) fn resolve_new_enum_variant(
+        &self,
+        type_name: &str,
+    ) -> Option<(String, EnumVariantLayout)> {
+        let trimmed = type_name.trim();
+        if trimmed.contains(['<', '[', '?', '*']) {
+            return None;
+        }
+        let normalized = trimmed.replace("::", ".");
+        let (owner, variant) = normalized.rsplit_once('.')?;
+        let layout = self.lookup_enum_layout(&owner.replace('.', "::"))?;
+        let variant_layout = layout.variants.iter().find(|item| item.name == variant)?;
+        Some((layout.name.clone(), variant_layout.clone()))
+    }
+
+    /// Lowers `new Enum.Variant { Field = value, ... }` into a variant aggregate. Every payload
+    /// field must be initialised exactly once; constructor arguments are not accepted.
+    #[allow(clippy::too_many_lines)]
+    pub(super) fn lower_new_enum_variant(
+        &mut self,
+        enum_name: String,
+        variant: EnumVariantLayout,
+        new_expr: NewExpr,
+        span: Option<Span>,
+    ) -> Option<Operand> {
+        let display = format!("{}.{}", enum_name.replace("::", "."), variant.name);
+        if !new_expr.args.is_empty() {
+            self.diagnostics.push(LoweringDiagnostic {
+                message: format!(
+                    "enum variant `{display}` does not accept constructor arguments; initialise its fields with `{{ Field = value }}`"
+                ),
+                span: new_expr.arguments_span.or(span),
+            });
+            return None;
+        }
+        let entries = match new_expr.initializer {
+            None => Vec::new(),
+            Some(NewInitializer::Object { fields, .. }) => fields,
+            Some(NewInitializer::Collection { span: init_span, .. }) => {
+                self.diagnostics.push(LoweringDiagnostic {
+                    message: format!(
+                        "collection initializers are not supported for enum variant `{display}`"
+                    ),
+                    span: init_span.or(span),
+                });
+                return None;
+            }
+        };
+
+        let mut values: Vec<Option<Operand>> = vec![None; variant.fields.len()];
+        let mut success = true;
+        for entry in entries {
+            let entry_span = entry.span.or(entry.name_span).or(span);
+            let Some(position) = variant
+                .fields
+                .iter()
+                .position(|field| field.matches_name(&entry.name))
+            else {
+                self.diagnostics.push(LoweringDiagnostic {
+                    message: format!("enum variant `{display}` has no field `{}`", entry.name),
+                    span: entry_span,
+                });
+                success = false;
+                continue;
+            };
+            if values[position].is_some() {
+                self.diagnostics.push(LoweringDiagnostic {
+                    message: format!(
+                        "field `{}` of enum variant `{display}` is initialised more than once",
+                        entry.name
+                    ),
+                    span: entry_span,
+                });
+                success = false;
+                continue;
+            }
+            let Some(operand) = self.lower_expr_node(entry.value, entry.value_span.or(entry_span))
+            else {
+                success = false;
+                continue;
+            };
+            let field_ty = variant.fields[position].ty.clone();
+            values[position] =
+                Some(self.coerce_operand_to_ty(operand, &field_ty, false, entry.value_span));
+        }
+
+        let mut fields = Vec::with_capacity(values.len());
+        for (field, value) in variant.fields.iter().zip(values) {
+            if let Some(operand) = value {
+                fields.push(operand);
+                continue;
+            }
+            if success {
+                self.diagnostics.push(LoweringDiagnostic {
+                    message: format!(
+                        "enum variant `{display}` requires a value for field `{}`",
+                        field.name
+                    ),
+                    span,
+                });
+            }
+            success = false;
+        }
+        if !success {
+            return None;
+        }
+
+        let local = self.create_temp(span);
+        if let Some(decl) = self.locals.get_mut(local.0) {
+            decl.ty = Ty::named(enum_name.clone());
+            decl.is_nullable = false;
+        }
+        let place = Place::new(local);
+        self.push_statement(MirStatement {
+            span,
+            kind: MirStatementKind::Assign {
+                place: place.clone(),
+                value: Rvalue::Aggregate {
+                    kind: AggregateKind::Adt {
+                        name: enum_name,
+                        variant: Some(variant.name),
+                    },
+                    fields,
+                },
+            },
+        });
+        Some(Operand::Copy(place))
+    }
+}
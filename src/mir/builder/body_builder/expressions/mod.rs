@@ -33,6 +33,7 @@ mod clone_glue;
 mod control;
 mod display;
 mod drop_glue;
+mod enum_variants;
 mod eq_glue;
 mod hash_glue;
 mod identifiers;
@@ -40,6 +41,7 @@ mod inline_asm;
 mod intrinsics;
 mod operators;
 mod patterns;
+mod rectangular;
 mod shared;
 mod type_id;
 pub(crate) use call_support::CallBindingInfo;
@@ -358,6 +360,9 @@ body_builder_impl! {
     ) -> Option<Operand> {
         let expr_span = new_expr.span;
         let new_span = span.or(expr_span);
+        if let Some((enum_name, variant)) = self.resolve_new_enum_variant(&new_expr.type_name) {
+            return self.lower_new_enum_variant(enum_name, variant, new_expr, new_span);
+        }
         let (object_ty, canonical_name, kind) = self.resolve_new_type(&new_expr, new_span)?;
         let NewExpr {
             type_name,
@@ -367,13 +372,6 @@ body_builder_impl! {
             ..
         } = new_expr;
         if let Ty::Array(array_ty) = &object_ty {
-            if array_ty.rank != 1 {
-                self.diagnostics.push(LoweringDiagnostic {
-                    message: "only single-dimensional arrays are supported".into(),
-                    span: new_span,
-                });
-                return None;
-            }
             if !args.is_empty() {
                 self.diagnostics.push(LoweringDiagnostic {
                     message: "array creation does not accept constructor arguments; provide a length or initializer list instead".into(),
@@ -381,18 +379,17 @@ body_builder_impl! {
                 });
                 return None;
             }
+            if array_ty.rank > 1 {
+                return self.lower_new_rectangular_array(
+                    array_ty,
+                    array_lengths,
+                    initializer,
+                    new_span,
+                );
+            }
             let explicit_len_expr = array_lengths
                 .as_ref()
                 .and_then(|lengths| lengths.get(0).cloned());
-            if let Some(lengths) = array_lengths.as_ref() {
-                if lengths.len() > 1 {
-                    self.diagnostics.push(LoweringDiagnostic {
-                        message: "multi-dimensional array lengths are not supported; use jagged arrays (`T[][]`) instead".into(),
-                        span: new_span,
-                    });
-                    return None;
-                }
-            }
 
             let mut initializer_value = None;
             if let Some(init) = &initializer {
@@ -723,6 +720,15 @@ fn split_top_level_type_args(args: String) -> Vec<String> {
         }
 
         let literal_span = literal.span.or(span);
+        if let Some(Ty::Array(array)) = literal
+            .explicit_type
+            .as_deref()
+            .and_then(parse_type_expression_text)
+            .map(|type_expr| Ty::from_type_expr(&type_expr))
+            && array.rank > 1
+        {
+            return self.lower_rectangular_array_literal(&array, literal, literal_span);
+        }
         let mut operands = Vec::with_capacity(literal.elements.len());
         for (element, elem_span) in literal
             .elements
@@ -743,13 +749,6 @@ fn split_top_level_type_args(args: String) -> Vec<String> {
                 let ty = Ty::from_type_expr(&type_expr);
                 match ty {
                     Ty::Array(array) => {
-                        if array.rank != 1 {
-                            self.diagnostics.push(LoweringDiagnostic {
-                                message: "multi-dimensional array literals are not supported".into(),
-                                span: literal_span.or(literal.explicit_type_span),
-                            });
-                            return None;
-                        }
                         element_ty = Some(array.element.as_ref().clone());
                         container = Some(ContainerKind::Array(array));
                     }
@@ -1419,7 +1418,11 @@ impl<'a> BodyBuilder<'a> {
         Some((ty, resolved, kind))
     }
 
-    fn allocate_reference_object(&mut self, ty: &Ty, span: Option<Span>) -> Option<Place> {
+    pub(super) fn allocate_reference_object(
+        &mut self,
+        ty: &Ty,
+        span: Option<Span>,
+    ) -> Option<Place> {
         let type_id = self.type_id_operand_for_ty(ty, span)?;
         let operand = self.call_runtime_function(
             Self::OBJECT_ALLOC_RUNTIME_FN,
@@ -1444,7 +1447,12 @@ impl<'a> BodyBuilder<'a> {
         place
     }
 
-    fn initialise_vtable_header(&mut self, place: &Place, object_ty: &Ty, span: Option<Span>) {
+    pub(super) fn initialise_vtable_header(
+        &mut self,
+        place: &Place,
+        object_ty: &Ty,
+        span: Option<Span>,
+    ) {
         let Some(owner) = self.resolve_ty_name(object_ty) else {
             return;
         };
@@ -1,4 +1,5 @@
 use super::*;
+use crate::mir::CastKind;
 use crate::syntax::expr::builders::PatternGuardExpr;
 use crate::syntax::expr::parse_expression;
 
@@ -27,6 +28,10 @@ body_builder_impl! {
             });
             return None;
         }
+        // `is T name` declares `name` in the enclosing scope so the branch guarded by the test
+        // (and any trailing `&&` operands) can use the narrowed reference.
+        let type_binding = self.declare_is_type_binding(discr_local, &pattern.node, pattern.span.or(span));
+
         // `is` needs temporary blocks to compute the boolean, but the resulting boolean value
         // must outlive the internal lowering scope so callers (e.g. guard chains) can branch on it.
         let result_temp = self.create_temp(span);
@@ -74,6 +79,8 @@ body_builder_impl! {
                 }
             }
             mir_pattern = Some(Pattern::Wildcard);
+        } else if let Some((_, ty)) = &type_binding {
+            mir_pattern = Some(Pattern::Type(ty.clone()));
         } else if allow_generated_guards {
             if let Some(predicate) = self.pattern_predicate_for_is(
                 &pattern.node,
@@ -113,6 +120,7 @@ body_builder_impl! {
             result_temp,
             discr_local,
             mir_pattern,
+            type_binding.as_ref(),
             &guard_meta,
             span,
         );
@@ -121,6 +129,61 @@ body_builder_impl! {
         Some(result)
     }
 
+    /// Declares the local bound by an `is T name` pattern when `T` is a class or interface,
+    /// returning it with the resolved type the runtime type test checks against.
+    fn declare_is_type_binding(
+        &mut self,
+        discr_local: LocalId,
+        node: &PatternNode,
+        span: Option<Span>,
+    ) -> Option<(LocalId, Ty)> {
+        let PatternNode::Type {
+            path,
+            subpattern: Some(inner),
+        } = node
+        else {
+            return None;
+        };
+        let PatternNode::Binding(binding) = inner.as_ref() else {
+            return None;
+        };
+        let discr_name = match self.locals.get(discr_local.0).map(|local| &local.ty)? {
+            Ty::Nullable(_) => return None,
+            ty => ty.canonical_name(),
+        };
+        let bare = PatternNode::Type {
+            path: path.clone(),
+            subpattern: None,
+        };
+        let diagnostics_len = self.diagnostics.len();
+        let Some(Pattern::Type(ty)) = self.lower_is_pattern_node(&bare, span) else {
+            self.diagnostics.truncate(diagnostics_len);
+            return None;
+        };
+        let target_name = ty.canonical_name();
+        if !self.is_class_type(&target_name)
+            || !self.is_class_type(&discr_name)
+        {
+            return None;
+        }
+        let mutable = matches!(binding.mutability, PatternBindingMutability::Mutable);
+        let decl = LocalDecl::new(
+            Some(binding.name.clone()),
+            ty.clone(),
+            mutable,
+            binding.span.or(span),
+            LocalKind::Local,
+        );
+        let local = self.push_local(decl);
+        self.bind_name(&binding.name, local);
+        self.push_statement(MirStatement {
+            span: binding.span.or(span),
+            kind: MirStatementKind::StorageLive(local),
+        });
+        self.record_local(local, binding.span.or(span));
+        Some((local, ty))
+    }
+
     pub(crate) fn prepare_is_pattern(&mut self, pattern: &PatternAst, span: Option<Span>) -> Option<Pattern> {
         let pattern_span = pattern.span.or(span);
 
@@ -335,6 +398,7 @@ body_builder_impl! {
         temp: LocalId,
         discr_local: LocalId,
         pattern: Pattern,
+        binding: Option<&(LocalId, Ty)>,
         guards: &[GuardMetadata],
         span: Option<Span>,
     ) -> Operand {
@@ -342,7 +406,16 @@ body_builder_impl! {
         let false_block = self.new_block(span);
         let join_block = self.new_block(span);
 
-        let guard_entry = self.lower_guard_chain(guards, true_block, false_block, span);
+        let mut guard_entry = self.lower_guard_chain(guards, true_block, false_block, span);
+        if let Some((local, ty)) = binding {
+            let bind_block = self.new_block(span);
+            let prev = self.current_block;
+            self.current_block = bind_block;
+            self.assign_is_type_binding(*local, ty, discr_local, span);
+            self.ensure_goto(guard_entry, span);
+            self.current_block = prev;
+            guard_entry = bind_block;
+        }
 
         self.set_terminator(
             span,
@@ -363,6 +436,11 @@ body_builder_impl! {
         self.ensure_goto(join_block, span);
 
         self.switch_to_block(false_block);
+        // The binding is only read where the test succeeded, but assignment tracking does not
+        // follow the boolean, so the failing path stores the (unused) reference as well.
+        if let Some((local, ty)) = binding {
+            self.assign_is_type_binding(*local, ty, discr_local, span);
+        }
         self.assign_boolean_temp(temp, false, span);
         self.ensure_goto(join_block, span);
 
@@ -370,6 +448,29 @@ body_builder_impl! {
         Operand::Copy(Place::new(temp))
     }
 
+    fn assign_is_type_binding(
+        &mut self,
+        local: LocalId,
+        ty: &Ty,
+        discr_local: LocalId,
+        span: Option<Span>,
+    ) {
+        let source = self.locals[discr_local.0].ty.clone();
+        self.push_statement(MirStatement {
+            span,
+            kind: MirStatementKind::Assign {
+                place: Place::new(local),
+                value: Rvalue::Cast {
+                    kind: CastKind::Unknown,
+                    operand: Operand::Copy(Place::new(discr_local)),
+                    source,
+                    target: ty.clone(),
+                    rounding: None,
+                },
+            },
+        });
+    }
+
     pub(crate) fn assign_boolean_temp(&mut self, temp: LocalId, value: bool, span: Option<Span>) {
         self.push_statement(MirStatement {
             span,
@@ -0,0 +1,417 @@
+use super::*;
+
+/// Name of the layout field holding the extent of `dimension` in a rectangular array.
+pub(crate) fn array_dimension_field(dimension: usize) -> String {
+    format!("dim{dimension}")
+}
+
+body_builder_impl! {
+    /// Lowers `new T[a, b, ...]` (optionally with a nested initializer) into a single contiguous
+    /// allocation of `a * b * ...` elements with each extent recorded in the array header.
+    pub(super) fn lower_new_rectangular_array(
+        &mut self,
+        array_ty: &ArrayTy,
+        array_lengths: Option<Vec<ExprNode>>,
+        initializer: Option<NewInitializer>,
+        span: Option<Span>,
+    ) -> Option<Operand> {
+        let rank = array_ty.rank;
+        let elements = match initializer {
+            Some(NewInitializer::Object { .. }) => {
+                self.diagnostics.push(LoweringDiagnostic {
+                    message: "object initializers are not supported for arrays".into(),
+                    span,
+                });
+                return None;
+            }
+            Some(NewInitializer::Collection { elements, .. }) => Some(elements),
+            None => None,
+        };
+        let shape = match &elements {
+            Some(elements) => {
+                let Some(shape) = ArrayLiteralExpr::rectangular_shape(elements, rank) else {
+                    self.diagnostics.push(LoweringDiagnostic {
+                        message: format!(
+                            "initializer for an array of rank {rank} must nest rows of equal length"
+                        ),
+                        span,
+                    });
+                    return None;
+                };
+                Some(shape)
+            }
+            None => None,
+        };
+
+        let lengths = match (array_lengths.filter(|lengths| !lengths.is_empty()), shape) {
+            (Some(lengths), _) => {
+                if lengths.len() != rank {
+                    self.diagnostics.push(LoweringDiagnostic {
+                        message: format!(
+                            "array of rank {rank} requires {rank} length expression(s) but {} were supplied",
+                            lengths.len()
+                        ),
+                        span,
+                    });
+                    return None;
+                }
+                let mut operands = Vec::with_capacity(rank);
+                for length in lengths {
+                    operands.push(self.lower_expr_node(length, span)?);
+                }
+                operands
+            }
+            (None, Some(shape)) => shape
+                .into_iter()
+                .map(|extent| Operand::Const(ConstOperand::new(ConstValue::UInt(extent as u128))))
+                .collect(),
+            (None, None) => {
+                self.diagnostics.push(LoweringDiagnostic {
+                    message: "array length must be specified explicitly or via a collection initializer".into(),
+                    span,
+                });
+                return None;
+            }
+        };
+
+        let elements = elements.map(|elements| ArrayLiteralExpr::flatten_rectangular(elements, rank));
+        self.lower_rectangular_array_storage(array_ty, lengths, elements, span)
+    }
+
+    /// Lowers a typed rectangular literal such as `int[,] [[1, 2], [3, 4]]`.
+    pub(super) fn lower_rectangular_array_literal(
+        &mut self,
+        array_ty: &ArrayTy,
+        literal: ArrayLiteralExpr,
+        span: Option<Span>,
+    ) -> Option<Operand> {
+        let rank = array_ty.rank;
+        let Some(shape) = ArrayLiteralExpr::rectangular_shape(&literal.elements, rank) else {
+            self.diagnostics.push(LoweringDiagnostic {
+                message: format!(
+                    "array literal for `{}` must nest rows of equal length",
+                    Ty::Array(array_ty.clone()).canonical_name()
+                ),
+                span,
+            });
+            return None;
+        };
+        let lengths = shape
+            .into_iter()
+            .map(|extent| Operand::Const(ConstOperand::new(ConstValue::UInt(extent as u128))))
+            .collect();
+        let elements = ArrayLiteralExpr::flatten_rectangular(literal.elements, rank);
+        self.lower_rectangular_array_storage(array_ty, lengths, Some(elements), span)
+    }
+
+    fn lower_rectangular_array_storage(
+        &mut self,
+        array_ty: &ArrayTy,
+        lengths: Vec<Operand>,
+        elements: Option<Vec<ExprNode>>,
+        span: Option<Span>,
+    ) -> Option<Operand> {
+        let usize_ty = Ty::named("usize");
+        let mut extents = Vec::with_capacity(lengths.len());
+        for length in lengths {
+            let coerced = self.coerce_operand_to_ty(length, &usize_ty, false, span);
+            let local = self.ensure_operand_local(coerced, span);
+            self.hint_local_ty(local, usize_ty.clone());
+            extents.push(local);
+        }
+
+        let mut total = Operand::Const(ConstOperand::new(ConstValue::UInt(1)));
+        for extent in &extents {
+            let product = self.create_temp(span);
+            self.hint_local_ty(product, usize_ty.clone());
+            self.push_statement(MirStatement {
+                span,
+                kind: MirStatementKind::Assign {
+                    place: Place::new(product),
+                    value: Rvalue::Binary {
+                        op: BinOp::Mul,
+                        lhs: total,
+                        rhs: Operand::Copy(Place::new(*extent)),
+                        rounding: None,
+                    },
+                },
+            });
+            total = Operand::Copy(Place::new(product));
+        }
+
+        let (place, coerced_length, elem_size) = self.lower_new_array(array_ty, total, span)?;
+        self.zero_init_array_data(&place, elem_size, coerced_length.clone(), span);
+        // Flattened element stores are bounds-checked against `len`, so it must be set first.
+        self.set_array_len(&place, coerced_length, span);
+
+        for (dimension, extent) in extents.into_iter().enumerate() {
+            let mut dim_place = place.clone();
+            dim_place
+                .projection
+                .push(ProjectionElem::FieldNamed(array_dimension_field(dimension)));
+            self.normalise_place(&mut dim_place);
+            self.push_statement(MirStatement {
+                span,
+                kind: MirStatementKind::Assign {
+                    place: dim_place,
+                    value: Rvalue::Use(Operand::Copy(Place::new(extent))),
+                },
+            });
+        }
+
+        if let Some(elements) = elements {
+            let initializer = NewInitializer::Collection { elements, span };
+            if !self.lower_array_initializer(&place, array_ty, initializer, span) {
+                return None;
+            }
+        }
+        Some(Operand::Copy(place))
+    }
+
+    /// Reads the extent of `dimension` from a rectangular array header into a fresh temp.
+    fn load_array_dimension(
+        &mut self,
+        place: &Place,
+        dimension: usize,
+        span: Option<Span>,
+    ) -> LocalId {
+        let mut dim_place = place.clone();
+        dim_place
+            .projection
+            .push(ProjectionElem::FieldNamed(array_dimension_field(dimension)));
+        self.normalise_place(&mut dim_place);
+        let local = self.create_temp(span);
+        self.hint_local_ty(local, Ty::named("usize"));
+        self.push_statement(MirStatement {
+            span,
+            kind: MirStatementKind::Assign {
+                place: Place::new(local),
+                value: Rvalue::Use(Operand::Copy(dim_place)),
+            },
+        });
+        local
+    }
+
+    /// Computes the row-major element offset for `place[i0, i1, ...]`, panicking when any index
+    /// falls outside its dimension. The flattened offset is bounds-checked again by the backend
+    /// against the total element count.
+    pub(super) fn lower_rectangular_index(
+        &mut self,
+        place: &Place,
+        indices: Vec<ExprNode>,
+        span: Option<Span>,
+    ) -> Option<LocalId> {
+        let usize_ty = Ty::named("usize");
+        let mut flat: Option<LocalId> = None;
+        for (dimension, index_expr) in indices.into_iter().enumerate() {
+            let extent = self.load_array_dimension(place, dimension, span);
+            let index = match index_expr {
+                ExprNode::IndexFromEnd(from_end) => {
+                    let value = self.lower_expr_node(*from_end.expr, span)?;
+                    let value = self.coerce_operand_to_ty(value, &usize_ty, true, span);
+                    let local = self.create_temp(span);
+                    self.hint_local_ty(local, usize_ty.clone());
+                    self.push_statement(MirStatement {
+                        span,
+                        kind: MirStatementKind::Assign {
+                            place: Place::new(local),
+                            value: Rvalue::Binary {
+                                op: BinOp::Sub,
+                                lhs: Operand::Copy(Place::new(extent)),
+                                rhs: value,
+                                rounding: None,
+                            },
+                        },
+                    });
+                    local
+                }
+                ExprNode::Range(range) => {
+                    self.diagnostics.push(LoweringDiagnostic {
+                        message: "range indices are not supported on rectangular arrays".into(),
+                        span: range.span.or(span),
+                    });
+                    return None;
+                }
+                other => {
+                    let value = self.lower_expr_node(other, span)?;
+                    let value = self.coerce_operand_to_ty(value, &usize_ty, true, span);
+                    let local = self.ensure_operand_local(value, span);
+                    self.hint_local_ty(local, usize_ty.clone());
+                    local
+                }
+            };
+            self.emit_dimension_bounds_check(index, extent, span);
+
+            flat = Some(match flat {
+                None => index,
+                Some(previous) => {
+                    let scaled = self.create_temp(span);
+                    self.hint_local_ty(scaled, usize_ty.clone());
+                    self.push_statement(MirStatement {
+                        span,
+                        kind: MirStatementKind::Assign {
+                            place: Place::new(scaled),
+                            value: Rvalue::Binary {
+                                op: BinOp::Mul,
+                                lhs: Operand::Copy(Place::new(previous)),
+                                rhs: Operand::Copy(Place::new(extent)),
+                                rounding: None,
+                            },
+                        },
+                    });
+                    let offset = self.create_temp(span);
+                    self.hint_local_ty(offset, usize_ty.clone());
+                    self.push_statement(MirStatement {
+                        span,
+                        kind: MirStatementKind::Assign {
+                            place: Place::new(offset),
+                            value: Rvalue::Binary {
+                                op: BinOp::Add,
+                                lhs: Operand::Copy(Place::new(scaled)),
+                                rhs: Operand::Copy(Place::new(index)),
+                                rounding: None,
+                            },
+                        },
+                    });
+                    offset
+                }
+            });
+        }
+        flat
+    }
+
+    fn emit_dimension_bounds_check(&mut self, index: LocalId, extent: LocalId, span: Option<Span>) {
+        let in_range = self.create_temp(span);
+        self.hint_local_ty(in_range, Ty::named("bool"));
+        self.push_statement(MirStatement {
+            span,
+            kind: MirStatementKind::Assign {
+                place: Place::new(in_range),
+                value: Rvalue::Binary {
+                    op: BinOp::Lt,
+                    lhs: Operand::Copy(Place::new(index)),
+                    rhs: Operand::Copy(Place::new(extent)),
+                    rounding: None,
+                },
+            },
+        });
+        let out_of_range = self.new_block(span);
+        let continue_block = self.new_block(span);
+        self.set_terminator(
+            span,
+            Terminator::SwitchInt {
+                discr: Operand::Copy(Place::new(in_range)),
+                targets: vec![(0, out_of_range)],
+                otherwise: continue_block,
+            },
+        );
+        self.switch_to_block(out_of_range);
+        self.set_terminator(span, Terminator::Panic);
+        self.switch_to_block(continue_block);
+    }
+
+    /// Lowers `array.GetLength(dimension)`. Constant dimensions read the header field directly;
+    /// runtime dimensions dispatch over the rank and panic when out of range.
+    pub(super) fn lower_array_get_length(
+        &mut self,
+        receiver: Operand,
+        array_ty: &ArrayTy,
+        argument: CallArgument,
+        destination: Option<Place>,
+        span: Option<Span>,
+    ) -> Option<Operand> {
+        let usize_ty = Ty::named("usize");
+        let place = match receiver {
+            Operand::Copy(mut place) | Operand::Move(mut place) => {
+                self.normalise_place(&mut place);
+                place
+            }
+            other => {
+                let local = self.ensure_operand_local(other, span);
+                self.hint_local_ty(local, Ty::Array(array_ty.clone()));
+                Place::new(local)
+            }
+        };
+        let result = destination.unwrap_or_else(|| {
+            let temp = self.create_temp(span);
+            self.hint_local_ty(temp, usize_ty.clone());
+            Place::new(temp)
+        });
+        let arg_span = argument.span.or(argument.value_span).or(span);
+        let constant_dimension = match &argument.value {
+            ExprNode::Literal(literal) => match literal.value {
+                ConstValue::Int(value) | ConstValue::Int32(value) => Some(value),
+                ConstValue::UInt(value) => Some(i128::try_from(value).unwrap_or(i128::MAX)),
+                _ => None,
+            },
+            _ => None,
+        };
+        let rank = array_ty.rank;
+        if let Some(dimension) = constant_dimension {
+            let Some(dimension) = usize::try_from(dimension).ok().filter(|dim| *dim < rank) else {
+                self.diagnostics.push(LoweringDiagnostic {
+                    message: format!(
+                        "dimension {dimension} is out of range for `{}` (rank {rank})",
+                        Ty::Array(array_ty.clone()).canonical_name()
+                    ),
+                    span: arg_span,
+                });
+                return None;
+            };
+            let value = self.array_dimension_rvalue(&place, rank, dimension);
+            self.push_statement(MirStatement {
+                span,
+                kind: MirStatementKind::Assign {
+                    place: result.clone(),
+                    value,
+                },
+            });
+            return Some(Operand::Copy(result));
+        }
+
+        let dimension = self.lower_expr_node(argument.value, arg_span)?;
+        let dimension = self.coerce_operand_to_ty(dimension, &usize_ty, true, arg_span);
+        let out_of_range = self.new_block(span);
+        let join = self.new_block(span);
+        let mut targets = Vec::with_capacity(rank);
+        for index in 0..rank {
+            targets.push((index as i128, self.new_block(span)));
+        }
+        self.set_terminator(
+            span,
+            Terminator::SwitchInt {
+                discr: dimension,
+                targets: targets.clone(),
+                otherwise: out_of_range,
+            },
+        );
+        for (index, block) in targets {
+            self.switch_to_block(block);
+            let value = self.array_dimension_rvalue(&place, rank, index as usize);
+            self.push_statement(MirStatement {
+                span,
+                kind: MirStatementKind::Assign {
+                    place: result.clone(),
+                    value,
+                },
+            });
+            self.ensure_goto(join, span);
+        }
+        self.switch_to_block(out_of_range);
+        self.set_terminator(span, Terminator::Panic);
+        self.switch_to_block(join);
+        Some(Operand::Copy(result))
+    }
+
+    fn array_dimension_rvalue(&mut self, place: &Place, rank: usize, dimension: usize) -> Rvalue {
+        if rank == 1 {
+            return Rvalue::Len(place.clone());
+        }
+        let mut dim_place = place.clone();
+        dim_place
+            .projection
+            .push(ProjectionElem::FieldNamed(array_dimension_field(dimension)));
+        self.normalise_place(&mut dim_place);
+        Rvalue::Use(Operand::Copy(dim_place))
+    }
+}
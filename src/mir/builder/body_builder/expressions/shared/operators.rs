@@ -79,12 +79,10 @@ body_builder_impl! {
             return OperatorResolution::Skip;
         };
 
-        if matches!(op, BinOp::Eq | BinOp::Ne)
+        // Enums compare structurally unless they declare (or derive) an equality operator.
+        let builtin_enum_equality = matches!(op, BinOp::Eq | BinOp::Ne)
             && lhs_ty == rhs_ty
-            && self.lookup_enum_layout(&lhs_ty).is_some()
-        {
-            return OperatorResolution::Skip;
-        }
+            && self.lookup_enum_layout(&lhs_ty).is_some();
         if matches!(op, BinOp::Eq | BinOp::Ne)
             && lhs_ty.starts_with("fn ")
             && lhs_ty == rhs_ty
@@ -134,6 +132,9 @@ body_builder_impl! {
                 OperatorResolution::Error
             }
             OperatorMatch::None => {
+                if builtin_enum_equality {
+                    return OperatorResolution::Skip;
+                }
                 if is_builtin_primitive(self.primitive_registry, &lhs_ty)
                     && is_builtin_primitive(self.primitive_registry, &rhs_ty)
                 {
@@ -155,6 +156,42 @@ body_builder_impl! {
             }
         }
     }
+    pub(crate) fn resolve_compound_assignment_overload(
+        &mut self,
+        op: BinOp,
+        lhs: &Operand,
+        rhs: &Operand,
+        span: Option<Span>,
+    ) -> OperatorResolution {
+        let (Some(lhs_ty), Some(rhs_ty)) =
+            (self.operand_type_name(lhs), self.operand_type_name(rhs))
+        else {
+            return OperatorResolution::Skip;
+        };
+        match self
+            .operator_registry
+            .resolve_compound_assignment(&lhs_ty, &rhs_ty, op)
+        {
+            OperatorMatch::Found(overload) => OperatorResolution::Handled(overload.clone()),
+            OperatorMatch::Ambiguous(candidates) => {
+                let names = candidates
+                    .iter()
+                    .map(|candidate| candidate.function.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                self.diagnostics.push(LoweringDiagnostic {
+                    message: format!(
+                        "ambiguous operator `{}=` for operand types `{lhs_ty}` and `{rhs_ty}`; candidates: {names}",
+                        binary_operator_symbol(op),
+                    ),
+                    span,
+                });
+                OperatorResolution::Error
+            }
+            // No dedicated overload: callers fall back to the binary operator.
+            OperatorMatch::None => OperatorResolution::Skip,
+        }
+    }
     pub(crate) fn emit_operator_call(
         &mut self,
         overload: OperatorOverload,
@@ -602,13 +602,8 @@ body_builder_impl! {
     }
     pub(crate) fn sequence_element_ty(&self, ty: &Ty) -> Option<Ty> {
         match ty {
-            Ty::Array(array) => {
-                if array.rank > 1 {
-                    Some(Ty::Array(ArrayTy::new(array.element.clone(), array.rank - 1)))
-                } else {
-                    Some((*array.element).clone())
-                }
-            }
+            // Rectangular arrays are indexed through a single flattened offset.
+            Ty::Array(array) => Some((*array.element).clone()),
             Ty::Vec(vec) => Some((*vec.element).clone()),
             Ty::Span(span) => Some((*span.element).clone()),
             Ty::ReadOnlySpan(span) => Some((*span.element).clone()),
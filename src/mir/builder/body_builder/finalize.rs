@@ -435,7 +435,10 @@ body_builder_impl! {
     fn collect_effects(&self) -> Vec<Ty> {
         let mut seen = HashSet::new();
         let mut effects = Vec::new();
-        for block in &self.body.blocks {
+        for (index, block) in self.body.blocks.iter().enumerate() {
+            if self.runtime_cast_failures.contains(&BlockId(index)) {
+                continue;
+            }
             if let Some(Terminator::Throw { ty, .. }) = &block.terminator {
                 let effect_ty = ty.clone().unwrap_or_else(|| Ty::named("Exception"));
                 let key = effect_ty.canonical_name();
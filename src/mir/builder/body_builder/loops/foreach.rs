@@ -358,13 +358,9 @@ body_builder_impl! {
             Ty::Nullable(inner) => self.intrinsic_foreach_kind(inner),
             Ty::Ref(reference) => self.intrinsic_foreach_kind(&reference.element),
             Ty::Vec(_) => Some(IntrinsicForeachKind::Vec),
-            Ty::Array(array_ty) => {
-                if array_ty.rank == 1 {
-                    Some(IntrinsicForeachKind::Array)
-                } else {
-                    None
-                }
-            }
+            // Rectangular arrays store their elements contiguously, so `foreach` walks them in
+            // row-major order exactly like a single-dimensional array.
+            Ty::Array(_) => Some(IntrinsicForeachKind::Array),
             Ty::Span(_) => Some(IntrinsicForeachKind::Span),
             Ty::ReadOnlySpan(_) => Some(IntrinsicForeachKind::ReadOnlySpan),
             Ty::String => Some(IntrinsicForeachKind::String),
@@ -287,18 +287,10 @@ body_builder_impl! {
             return self.lower_static_property_assignment(&owner, member, symbol, op, value_expr, span);
         }
         if let Some(field) = self.symbol_index.field_symbol(&owner, member) {
-            if op != AssignOp::Assign {
-                self.diagnostics.push(LoweringDiagnostic {
-                    message: format!(
-                        "compound assignment on field `{owner}.{member}` is not supported"
-                    ),
-                    span,
-                });
+            let Some(value_operand) =
+                self.lower_static_field_assigned_value(&owner, member, field, op, value_expr, span)
+            else {
                 return Some(false);
-            }
-            let value_operand = match self.lower_expr_node(value_expr, span) {
-                Some(operand) => operand,
-                None => return Some(false),
             };
             return Some(self.emit_static_store(&owner, member, field, value_operand, span));
         }
@@ -323,24 +315,37 @@ body_builder_impl! {
             if !field.is_static {
                 return None;
             }
-            if op != AssignOp::Assign {
-                self.diagnostics.push(LoweringDiagnostic {
-                    message: format!(
-                        "compound assignment on field `{owner}.{name}` is not supported"
-                    ),
-                    span,
-                });
+            let Some(value_operand) =
+                self.lower_static_field_assigned_value(&owner, name, field, op, value_expr, span)
+            else {
                 return Some(false);
-            }
-            let value_operand = match self.lower_expr_node(value_expr, span) {
-                Some(operand) => operand,
-                None => return Some(false),
             };
             return Some(self.emit_static_store(&owner, name, field, value_operand, span));
         }
         None
     }
 
+    /// Produces the value stored by `Owner.Field op= value`: the right-hand side for plain
+    /// assignment, or the current static value combined with it for compound assignment.
+    #[allow(clippy::too_many_arguments)]
+    fn lower_static_field_assigned_value(
+        &mut self,
+        owner: &str,
+        member: &str,
+        field: &FieldSymbol,
+        op: AssignOp,
+        value_expr: ExprNode,
+        span: Option<Span>,
+    ) -> Option<Operand> {
+        // Instance fields are rejected by `emit_static_store`; skip the read so the error is
+        // reported once.
+        if op == AssignOp::Assign || !field.is_static {
+            return self.lower_expr_node(value_expr, span);
+        }
+        let current = self.lower_static_field_value(owner, member, field, span)?;
+        self.lower_compound_assignment_value(op, current, value_expr, span)
+    }
+
     pub(crate) fn lower_namespace_static_identifier(
         &mut self,
         name: &str,
@@ -506,16 +511,6 @@ body_builder_impl! {
             return None;
         };
 
-        if op != AssignOp::Assign {
-            self.diagnostics.push(LoweringDiagnostic {
-                message: format!(
-                    "compound assignment on static `{}` is not supported",
-                    var.qualified
-                ),
-                span,
-            });
-            return Some(false);
-        }
         if var.is_readonly {
             self.diagnostics.push(LoweringDiagnostic {
                 message: format!("static `{}` is immutable", var.qualified),
@@ -533,9 +528,14 @@ body_builder_impl! {
             });
         }
 
-        let value_operand = match self.lower_expr_node(value_expr, span) {
-            Some(operand) => operand,
-            None => return Some(false),
+        let value_operand = if op == AssignOp::Assign {
+            self.lower_expr_node(value_expr, span)
+        } else {
+            let current = self.emit_static_load(id, var, span);
+            self.lower_compound_assignment_value(op, current, value_expr, span)
+        };
+        let Some(value_operand) = value_operand else {
+            return Some(false);
         };
         let coerced = self.coerce_operand_to_ty(value_operand, &var.ty, false, span);
         let value_local = self.ensure_operand_local(coerced, span);
@@ -773,15 +773,6 @@ body_builder_impl! {
             });
             return Some(false);
         }
-        if op != AssignOp::Assign {
-            self.diagnostics.push(LoweringDiagnostic {
-                message: format!(
-                    "compound assignment on property `{owner}.{member}` is not supported"
-                ),
-                span,
-            });
-            return Some(false);
-        }
         let descriptor = format!("property `{owner}.{member}`");
         if !self.check_static_visibility(
             owner,
@@ -806,9 +797,16 @@ body_builder_impl! {
         ) {
             return Some(false);
         }
-        let value_operand = match self.lower_expr_node(value_expr, span) {
-            Some(operand) => operand,
-            None => return Some(false),
+        let value_operand = if op == AssignOp::Assign {
+            self.lower_expr_node(value_expr, span)
+        } else {
+            self.lower_static_property_value(owner, None, member, symbol, span)
+                .and_then(|current| {
+                    self.lower_compound_assignment_value(op, current, value_expr, span)
+                })
+        };
+        let Some(value_operand) = value_operand else {
+            return Some(false);
         };
         let value_ty = Ty::named(symbol.ty.clone());
         let coerced = self.coerce_operand_to_ty(value_operand, &value_ty, false, span);
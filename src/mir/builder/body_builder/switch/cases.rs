@@ -10,7 +10,7 @@ body_builder_impl! {
         next_block: BlockId,
     ) {
         match &case.pattern {
-            CasePatternKind::Wildcard => self.lower_wildcard_case(case, next_block),
+            CasePatternKind::Wildcard => self.lower_wildcard_case(case, discr_local, next_block),
             CasePatternKind::Literal(value) => {
                 self.lower_literal_case(case, discr_local, value, next_block);
             }
@@ -18,11 +18,12 @@ body_builder_impl! {
         }
     }
 
-    fn lower_wildcard_case(&mut self, case: &SwitchCase, next_block: BlockId) {
+    fn lower_wildcard_case(&mut self, case: &SwitchCase, discr_local: LocalId, next_block: BlockId) {
         let post_guard_entry =
             self.lower_guard_chain(&case.guards, case.body_block, next_block, case.span);
+        let binding_entry = self.lower_case_bindings(case, discr_local, post_guard_entry);
         let entry =
-            self.lower_guard_chain(&case.pre_guards, post_guard_entry, next_block, case.span);
+            self.lower_guard_chain(&case.pre_guards, binding_entry, next_block, case.span);
         self.set_terminator(
             case.span,
             Terminator::Goto {
@@ -61,8 +62,9 @@ body_builder_impl! {
 
         let guard_entry =
             self.lower_guard_chain(&case.guards, case.body_block, next_block, case.span);
+        let binding_entry = self.lower_case_bindings(case, discr_local, guard_entry);
         let pre_entry =
-            self.lower_guard_chain(&case.pre_guards, guard_entry, next_block, case.span);
+            self.lower_guard_chain(&case.pre_guards, binding_entry, next_block, case.span);
         self.set_terminator(
             case.pattern_span.or(case.span),
             Terminator::SwitchInt {
@@ -73,6 +75,22 @@ body_builder_impl! {
         );
     }
 
+    /// Structural patterns dispatch through an `is` pre-guard; once it succeeds the bindings
+    /// are assigned ahead of the `when` guards, mirroring the match lowering.
+    fn lower_case_bindings(
+        &mut self,
+        case: &SwitchCase,
+        discr_local: LocalId,
+        next_block: BlockId,
+    ) -> BlockId {
+        if case.bindings.is_empty() && case.list_plan.is_none() {
+            return next_block;
+        }
+        let block = self.new_block(case.span);
+        self.emit_binding_assignments(block, case, discr_local, next_block);
+        block
+    }
+
     fn lower_complex_case(&mut self, case: &SwitchCase, next_block: BlockId) {
         self.set_terminator(case.span, Terminator::Goto { target: next_block });
     }
@@ -78,7 +78,8 @@ body_builder_impl! {
         self.switch_to_block(fallback_block);
     }
 
-    fn emit_binding_assignments(
+    #[allow(clippy::too_many_lines)]
+    pub(super) fn emit_binding_assignments(
         &mut self,
         block: BlockId,
         case: &SwitchCase,
@@ -148,7 +149,7 @@ body_builder_impl! {
                 continue;
             }
 
-            let source = self.build_binding_place(discr_local, binding);
+            let (source, source_ty) = self.build_binding_place(discr_local, binding);
             let span = binding.span.or(case.span);
             let operand = match binding.mode {
                 PatternBindingMode::Value => Operand::Copy(source.clone()),
@@ -160,6 +161,22 @@ body_builder_impl! {
                     self.borrow_argument_place(source.clone(), BorrowKind::Unique, span)
                 }
             };
+            // Give by-value bindings the payload field type so member calls on them resolve;
+            // generic owners keep the declared (unsubstituted) field types, so skip those.
+            let generic_owner = self
+                .locals
+                .get(discr_local.0)
+                .is_some_and(|decl| matches!(&decl.ty, Ty::Named(name) if !name.args.is_empty()));
+            if matches!(
+                binding.mode,
+                PatternBindingMode::Value | PatternBindingMode::Move
+            ) && !generic_owner
+                && let Some(ty) = source_ty
+                && let Some(local) = self.locals.get_mut(binding.local.0)
+                && matches!(local.ty, Ty::Unknown)
+            {
+                local.ty = ty;
+            }
             let assign = MirStatement {
                 span,
                                 kind: MirStatementKind::Assign {
@@ -203,7 +220,7 @@ body_builder_impl! {
 
         let mut prefix_binding = binding.clone();
         prefix_binding.projection.truncate(position);
-        let base_place = self.build_binding_place(discr_local, &prefix_binding);
+        let (base_place, _) = self.build_binding_place(discr_local, &prefix_binding);
         let base_ty = match self.place_ty(&base_place) {
             Some(ty) => ty,
             None => {
@@ -342,8 +359,13 @@ body_builder_impl! {
         clippy::too_many_lines,
         reason = "Binding projection must trace through struct/union/enum layouts precisely."
     )]
-    fn build_binding_place(&self, discr_local: LocalId, binding: &PatternBinding) -> Place {
+    fn build_binding_place(
+        &self,
+        discr_local: LocalId,
+        binding: &PatternBinding,
+    ) -> (Place, Option<Ty>) {
         let mut place = Place::new(discr_local);
+        let mut binding_ty = self.locals.get(discr_local.0).map(|decl| decl.ty.clone());
 
         let mut current_type = self
             .locals
@@ -382,6 +404,7 @@ body_builder_impl! {
                         current_struct = None;
                         current_union = None;
                         current_type = None;
+                        binding_ty = None;
                         continue;
                     }
 
@@ -392,6 +415,7 @@ body_builder_impl! {
                     current_struct = None;
                     current_union = None;
                     current_type = None;
+                    binding_ty = None;
                 }
                 PatternProjectionElem::FieldNamed(name) => {
                     if let Some(variant_layout) = current_variant
@@ -399,6 +423,7 @@ body_builder_impl! {
                     {
                         place.projection.push(ProjectionElem::Field(field.index));
                         current_type = self.resolve_ty_name(&field.ty);
+                        binding_ty = Some(field.ty.clone());
                         current_struct = current_type
                             .as_deref()
                             .and_then(|ty| self.lookup_struct_layout_by_name(ty));
@@ -414,6 +439,7 @@ body_builder_impl! {
                     {
                         place.projection.push(ProjectionElem::Field(field.index));
                         current_type = self.resolve_ty_name(&field.ty);
+                        binding_ty = Some(field.ty.clone());
                         current_struct = current_type
                             .as_deref()
                             .and_then(|ty| self.lookup_struct_layout_by_name(ty));
@@ -432,6 +458,7 @@ body_builder_impl! {
                             name: field.name.clone(),
                         });
                         current_type = self.resolve_ty_name(&field.ty);
+                        binding_ty = Some(field.ty.clone());
                         current_struct = current_type
                             .as_deref()
                             .and_then(|ty| self.lookup_struct_layout_by_name(ty));
@@ -449,6 +476,7 @@ body_builder_impl! {
                     current_struct = None;
                     current_union = None;
                     current_type = None;
+                    binding_ty = None;
                 }
                 PatternProjectionElem::FieldIndex(index) => {
                     if let Some(variant_layout) = current_variant
@@ -457,6 +485,7 @@ body_builder_impl! {
                     {
                         place.projection.push(ProjectionElem::Field(*index));
                         current_type = self.resolve_ty_name(&field.ty);
+                        binding_ty = Some(field.ty.clone());
                         current_struct = current_type
                             .as_deref()
                             .and_then(|ty| self.lookup_struct_layout_by_name(ty));
@@ -472,6 +501,7 @@ body_builder_impl! {
                     {
                         place.projection.push(ProjectionElem::Field(*index));
                         current_type = self.resolve_ty_name(&field.ty);
+                        binding_ty = Some(field.ty.clone());
                         current_struct = current_type
                             .as_deref()
                             .and_then(|ty| self.lookup_struct_layout_by_name(ty));
@@ -487,6 +517,7 @@ body_builder_impl! {
                     current_struct = None;
                     current_union = None;
                     current_type = None;
+                    binding_ty = None;
                 }
                 PatternProjectionElem::Index(local) => {
                     place.projection.push(ProjectionElem::Index(*local));
@@ -494,6 +525,7 @@ body_builder_impl! {
                     current_struct = None;
                     current_union = None;
                     current_type = None;
+                    binding_ty = None;
                 }
                 PatternProjectionElem::Subslice { from, to } => {
                     place.projection.push(ProjectionElem::Subslice {
@@ -504,10 +536,11 @@ body_builder_impl! {
                     current_struct = None;
                     current_union = None;
                     current_type = None;
+                    binding_ty = None;
                 }
             }
         }
-        place
+        (place, binding_ty)
     }
 }
 
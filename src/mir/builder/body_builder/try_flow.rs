@@ -189,6 +189,8 @@ body_builder_impl! {
 
             if let Some(dispatch) = dispatch_block {
                 self.set_terminator(try_span, Terminator::Goto { target: dispatch });
+            } else if let Some(finally) = finally_entry {
+                self.set_terminator(try_span, Terminator::Goto { target: finally });
             } else if let Some(unhandled) = unhandled_block {
                 self.set_terminator(try_span, Terminator::Goto { target: unhandled });
             } else {
@@ -15,7 +15,7 @@ pub(crate) enum DefaultArgumentValue {
     },
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub(crate) struct DefaultArgumentMap {
     entries: HashMap<String, Vec<Option<DefaultArgumentValue>>>,
 }
@@ -31,6 +31,19 @@ impl DefaultArgumentMap {
         self.entries.insert(internal.into(), values);
     }
 
+    /// Adopt the entries of `fork` that this map does not record yet.
+    pub(crate) fn absorb(&mut self, fork: DefaultArgumentMap) {
+        for (internal, values) in fork.entries {
+            self.entries.entry(internal).or_insert(values);
+        }
+    }
+
+    pub(crate) fn values_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut Vec<Option<DefaultArgumentValue>>> {
+        self.entries.values_mut()
+    }
+
     pub(crate) fn value(&self, internal: &str, index: usize) -> Option<&DefaultArgumentValue> {
         self.entries
             .get(internal)
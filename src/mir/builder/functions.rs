@@ -6,7 +6,8 @@ use super::symbol_index::SymbolIndex;
 use super::*;
 use crate::frontend::ast::{
     Attribute, BindingModifier, ConstructorDecl, FunctionDecl, GenericParamKind, GenericParams,
-    MemberDispatch, Parameter, PropertyAccessorKind, TestCaseDecl, TypeExpr,
+    MemberDispatch, Parameter, PropertyAccessorKind, TestArgumentSource, TestCaseDecl,
+    TestCaseKind, TestCaseRowDecl, TypeExpr,
 };
 use crate::frontend::attributes::{
     AttributeError, OptimizationHints, collect_optimization_hints, extract_conditional_attribute,
@@ -20,7 +21,7 @@ use crate::mir::data::{Rvalue, StatementKind};
 use crate::mir::operators::OperatorRegistry;
 use crate::mir::{
     ASYNC_DIAG_ATTRIBUTE, AsyncFramePolicy, AttrSource, FnSig, FrameLimitAttr, MirExternSpec,
-    NoCaptureAttr, NoCaptureMode, TestCaseParameterMetadata,
+    NoCaptureAttr, NoCaptureMode, TestCaseParameterMetadata, TestValueDomain,
 };
 use crate::primitives::PrimitiveRegistry;
 use std::cell::RefCell;
@@ -51,6 +52,10 @@ pub(super) struct TestCaseLoweringMetadata {
     pub(super) explicit_id: Option<String>,
     pub(super) span: Option<Span>,
     pub(super) parameters: Vec<TestCaseParameterMetadata>,
+    pub(super) kind: TestCaseKind,
+    pub(super) runs: Option<u32>,
+    /// Declared testcase name and `@cases` row for data-row testcases.
+    pub(super) row: Option<(String, TestCaseRowDecl)>,
 }
 
 fn collect_type_param_names(params: Option<&GenericParams>) -> Vec<String> {
@@ -428,10 +433,15 @@ pub(super) fn lower_testcase(
     let (async_policy, mut async_attr_diagnostics) =
         collect_async_frame_policy(&test.attributes, test.is_async);
     hint_diagnostics.append(&mut async_attr_diagnostics);
-    let (categories, explicit_id, mut testcase_attr_diagnostics) =
-        collect_testcase_attributes(&test.attributes);
+    let TestcaseAttributes {
+        categories,
+        explicit_id,
+        runs,
+        diagnostics: mut testcase_attr_diagnostics,
+    } = collect_testcase_attributes(&test.attributes, test.kind);
     hint_diagnostics.append(&mut testcase_attr_diagnostics);
-    let parameters = testcase_parameters(test.signature.as_ref());
+    let parameters = testcase_parameters(test, primitive_registry);
+    hint_diagnostics.extend(generated_parameter_diagnostics(test, &parameters));
     let opaque_return = opaque_return_info_from_ty(&sig.ret, span);
     let mut builder = BodyBuilder::new(
         &sig,
@@ -484,6 +494,14 @@ pub(super) fn lower_testcase(
             explicit_id,
             span,
             parameters,
+            kind: test.kind,
+            runs,
+            row: test.expansion.as_ref().and_then(|expansion| {
+                expansion
+                    .row
+                    .clone()
+                    .map(|row| (expansion.declared_name.clone(), row))
+            }),
         }),
         false,
         false,
@@ -740,11 +758,17 @@ fn convert_attribute_errors(errors: Vec<AttributeError>) -> Vec<LoweringDiagnost
         .collect()
 }
 
-fn collect_testcase_attributes(
-    attrs: &[Attribute],
-) -> (Vec<String>, Option<String>, Vec<LoweringDiagnostic>) {
+struct TestcaseAttributes {
+    categories: Vec<String>,
+    explicit_id: Option<String>,
+    runs: Option<u32>,
+    diagnostics: Vec<LoweringDiagnostic>,
+}
+
+fn collect_testcase_attributes(attrs: &[Attribute], kind: TestCaseKind) -> TestcaseAttributes {
     let mut categories = Vec::new();
     let mut explicit_id = None;
+    let mut runs = None;
     let mut diagnostics = Vec::new();
 
     for attr in attrs {
@@ -816,10 +840,29 @@ fn collect_testcase_attributes(
                     });
                 }
             }
+            "runs" if kind.is_generative() => {
+                let value = attr
+                    .arguments
+                    .first()
+                    .map(|arg| normalize_testcase_attribute_value(&arg.value));
+                match value.as_deref().map(str::trim).map(str::parse::<u32>) {
+                    Some(Ok(count)) if count > 0 && attr.arguments.len() == 1 => {
+                        runs = Some(count);
+                    }
+                    _ => diagnostics.push(LoweringDiagnostic {
+                        message: format!(
+                            "`@runs` on a `{}` requires a single positive integer",
+                            kind.keyword()
+                        ),
+                        span: attr.span,
+                    }),
+                }
+            }
             _ => diagnostics.push(LoweringDiagnostic {
                 message: format!(
-                    "attribute `@{}` is not supported on testcase declarations",
-                    attr.name
+                    "attribute `@{}` is not supported on {} declarations",
+                    attr.name,
+                    kind.keyword()
                 ),
                 span: attr.span,
             }),
@@ -828,7 +871,12 @@ fn collect_testcase_attributes(
 
     categories.sort();
     categories.dedup();
-    (categories, explicit_id, diagnostics)
+    TestcaseAttributes {
+        categories,
+        explicit_id,
+        runs,
+        diagnostics,
+    }
 }
 
 fn normalize_testcase_attribute_value(value: &str) -> String {
@@ -843,17 +891,84 @@ fn normalize_testcase_attribute_value(value: &str) -> String {
     trimmed.to_string()
 }
 
-fn testcase_parameters(signature: Option<&Signature>) -> Vec<TestCaseParameterMetadata> {
-    let Some(signature) = signature else {
+/// Parameters as declared, which for an expanded testcase are those of the original declaration.
+fn declared_testcase_parameters(
+    test: &TestCaseDecl,
+) -> Vec<(&Parameter, Option<TestArgumentSource>)> {
+    if let Some(expansion) = &test.expansion {
+        return expansion
+            .parameters
+            .iter()
+            .map(|(param, source)| (param, source.clone()))
+            .collect();
+    }
+    test.signature
+        .as_ref()
+        .map(|signature| {
+            signature
+                .parameters
+                .iter()
+                .map(|param| (param, None))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn testcase_parameters(
+    test: &TestCaseDecl,
+    primitive_registry: &PrimitiveRegistry,
+) -> Vec<TestCaseParameterMetadata> {
+    declared_testcase_parameters(test)
+        .into_iter()
+        .map(|(param, source)| {
+            let ty = type_expr_surface(&param.ty);
+            let domain = primitive_registry
+                .kind_for_name(&ty)
+                .and_then(TestValueDomain::from_primitive);
+            TestCaseParameterMetadata {
+                name: param.name.clone(),
+                ty: Some(ty),
+                has_default: param.default.is_some(),
+                domain,
+                source,
+            }
+        })
+        .collect()
+}
+
+/// Property and fuzz testcases need at least one parameter, and every parameter must be a
+/// primitive the runner knows how to generate.
+fn generated_parameter_diagnostics(
+    test: &TestCaseDecl,
+    parameters: &[TestCaseParameterMetadata],
+) -> Vec<LoweringDiagnostic> {
+    if !test.kind.is_generative() {
         return Vec::new();
-    };
-    signature
-        .parameters
+    }
+    if parameters.iter().all(|meta| meta.source.is_some()) {
+        return vec![LoweringDiagnostic {
+            message: format!(
+                "`{}` `{}` must declare at least one parameter to generate",
+                test.kind.keyword(),
+                test.name
+            ),
+            span: test.body.span,
+        }];
+    }
+    let declared = declared_testcase_parameters(test);
+    parameters
         .iter()
-        .map(|param| TestCaseParameterMetadata {
-            name: param.name.clone(),
-            ty: Some(type_expr_surface(&param.ty)),
-            has_default: param.default.is_some(),
+        .zip(declared)
+        .filter(|(meta, _)| meta.source.is_none() && meta.domain.is_none())
+        .map(|(meta, param)| LoweringDiagnostic {
+            message: format!(
+                "cannot generate values for parameter `{}` of type `{}` in `{}` `{}`; use bool, char, 8- to 64-bit integer or float parameters",
+                meta.name,
+                meta.ty.as_deref().unwrap_or("?"),
+                test.kind.keyword(),
+                test.name
+            ),
+            span: param.0.name_span.or(test.body.span),
         })
         .collect()
 }
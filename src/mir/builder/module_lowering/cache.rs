@@ -64,6 +64,11 @@ impl LoweringCache {
         entry
     }
 
+    /// Like [`Self::lookup`] without cloning the entry or counting towards the metrics.
+    pub(super) fn contains(&self, key: &str) -> bool {
+        self.entries.contains_key(key)
+    }
+
     pub(super) fn insert(&mut self, key: String, entry: CachedLowering) {
         self.entries.insert(key, entry);
     }
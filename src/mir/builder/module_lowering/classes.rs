@@ -348,6 +348,12 @@ impl ModuleLowering {
                 };
                 RegistryOperatorKind::Binary(mapped)
             }
+            AstOperatorKind::CompoundAssignment(op) => {
+                let Some(mapped) = Self::map_binary_operator(op) else {
+                    return;
+                };
+                RegistryOperatorKind::CompoundAssignment(mapped)
+            }
             AstOperatorKind::Conversion(conv) => {
                 let mapped = match conv {
                     ConversionKind::Implicit => RegistryConversionKind::Implicit,
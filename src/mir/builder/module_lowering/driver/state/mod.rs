@@ -5,7 +5,7 @@ mod vtable;
 use super::{executor, planner};
 use crate::frontend::ast::{
     Attribute, BindingModifier, ClassDecl, ClassMember, Expression, FieldDecl, FnTypeExpr,
-    GenericArgument, Parameter, PropertyAccessorKind, PropertyDecl, StaticItemDecl,
+    GenericArgument, ImplDecl, Parameter, PropertyAccessorKind, PropertyDecl, StaticItemDecl,
     StaticMutability, TraitObjectTypeExpr, TypeAliasDecl, TypeExpr, TypeSuffix,
 };
 use crate::frontend::diagnostics::Span;
@@ -201,6 +201,9 @@ pub(crate) struct ModuleLowering {
     pub(crate) cache: LoweringCache,
     pub(crate) trait_decls: HashMap<String, TraitLoweringInfo>,
     pub(crate) trait_vtables: Vec<TraitVTable>,
+    /// Blanket impls (`impl<T> Trait for T`) and their namespaces, instantiated per type once
+    /// every concrete impl has been lowered.
+    pub(crate) blanket_impls: Vec<(ImplDecl, Option<String>)>,
     pub(crate) class_decls: HashMap<String, ClassDecl>,
     class_vtable_plans: HashMap<String, ClassVTablePlan>,
     class_vtable_in_progress: HashSet<String>,
@@ -246,6 +249,7 @@ impl Default for ModuleLowering {
             cache: LoweringCache::new(),
             trait_decls: HashMap::new(),
             trait_vtables: Vec::new(),
+            blanket_impls: Vec::new(),
             class_decls: HashMap::new(),
             class_vtable_plans: HashMap::new(),
             class_vtable_in_progress: HashSet::new(),
@@ -110,6 +110,7 @@ fn make_constructor_symbol(name: &str, parameters: Vec<Parameter>) -> Constructo
                 end: 0,
             }),
             attributes: Vec::new(),
+            name_span: None,
             di_inject: None,
         },
         owner: name.to_string(),
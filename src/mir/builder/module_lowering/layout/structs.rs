@@ -484,96 +484,7 @@ impl ModuleLowering {
     }
 
     fn ensure_array_layout(&mut self, array: &ArrayTy) {
-        let name = Ty::Array(array.clone()).canonical_name();
-        if self.type_layouts.types.contains_key(&name) {
-            return;
-        }
-
-        let word_size = pointer_size();
-        let word_align = pointer_align();
-
-        let mut fields = Vec::new();
-        let mut offset = 0usize;
-
-        fields.push(make_field("ptr", Ty::named("byte*"), 0, offset));
-        offset += word_size;
-
-        offset = align_to(offset, word_align);
-        fields.push(make_field("len", Ty::named("usize"), 1, offset));
-        offset += word_size;
-
-        offset = align_to(offset, word_align);
-        fields.push(make_field("cap", Ty::named("usize"), 2, offset));
-        offset += word_size;
-
-        offset = align_to(offset, word_align);
-        fields.push(make_field("elem_size", Ty::named("usize"), 3, offset));
-        offset += word_size;
-
-        offset = align_to(offset, word_align);
-        fields.push(make_field("elem_align", Ty::named("usize"), 4, offset));
-        offset += word_size;
-
-        offset = align_to(offset, word_align);
-        fields.push(make_field("drop_fn", Ty::named("isize"), 5, offset));
-        offset += word_size;
-
-        // Arrays are backed by the Chic runtime Vec representation today.
-        offset = align_to(offset, word_align);
-        fields.push(make_field("region_ptr", Ty::named("byte*"), 6, offset));
-        offset += word_size;
-
-        offset = align_to(offset, 1);
-        fields.push(make_field("uses_inline", Ty::named("byte"), 7, offset));
-        offset += 1;
-
-        offset = align_to(offset, 1);
-        fields.push(make_field(
-            "inline_pad",
-            Ty::named("Std::Runtime::Collections::InlinePadding7"),
-            8,
-            offset,
-        ));
-        offset += 7;
-
-        offset = align_to(offset, 1);
-        fields.push(make_field(
-            "inline_storage",
-            Ty::named("Std::Runtime::Collections::InlineBytes64"),
-            9,
-            offset,
-        ));
-        offset += 64;
-
-        let align = word_align.max(1);
-        let size = align_to(offset, align);
-
-        let layout = StructLayout {
-            name: name.clone(),
-            repr: TypeRepr::Default,
-            packing: None,
-            fields,
-            positional: Vec::new(),
-            list: Some(ListLayout {
-                element_index: Some(0),
-                length_index: Some(1),
-                span: None,
-            }),
-            size: Some(size),
-            align: Some(align),
-            is_readonly: false,
-            is_intrinsic: true,
-            allow_cross_inline: true,
-            auto_traits: auto_traits::unknown_set(),
-            overrides: auto_traits::default_override(),
-            mmio: None,
-            dispose: None,
-            class: None,
-        };
-
-        self.type_layouts
-            .types
-            .insert(name, TypeLayout::Struct(layout));
+        self.type_layouts.ensure_array_layout(array);
     }
 
     fn ensure_vec_layout(&mut self, vec: &VecTy) {
@@ -5,6 +5,7 @@ pub(crate) mod driver;
 pub(crate) mod interfaces;
 pub(crate) mod layout;
 pub(crate) mod operators;
+pub(crate) mod parallel;
 pub(crate) mod pipeline;
 pub(crate) mod primitives;
 pub(crate) mod queue;
@@ -36,6 +36,11 @@ impl ModuleLowering {
                 Item::Extension(ext) => {
                     self.collect_extension_operator_overloads(ext, namespace);
                 }
+                Item::Impl(impl_decl) => {
+                    if let Some(ext) = impl_decl.inherent_extension() {
+                        self.collect_extension_operator_overloads(&ext, namespace);
+                    }
+                }
                 _ => {}
             }
         }
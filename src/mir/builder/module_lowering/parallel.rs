@@ -0,0 +1,304 @@
+//! Lowering of consecutive free-function bodies on the compiler's worker pool.
+//!
+//! Each worker lowers a contiguous chunk of bodies against private copies of the state
+//! body lowering mutates (layouts, interned strings, lambda default arguments and
+//! generic specialisation requests). The chunks are then folded back in item order:
+//! strings are re-interned in the order a serial run would have interned them and the
+//! body's `StrId`s rewritten, so the lowered module does not depend on the job count.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::rc::Rc;
+
+use super::super::default_arguments::{DefaultArgumentMap, DefaultArgumentValue};
+use super::super::functions::{LoweredFunction, lower_function};
+use super::super::specialization::FunctionSpecialization;
+use super::super::string_interner::StringInterner;
+use super::super::{FunctionKind, Item, qualify};
+use super::driver::ModuleLowering;
+use super::queue::cache_key;
+use crate::mir::passes::raw_strings::{StringSites, walk_body_strings, walk_const_strings};
+use crate::mir::{ConstValue, StrId, TypeLayoutTable};
+use crate::threading;
+
+/// Forking the layout table and interner costs about as much as lowering a few small
+/// bodies, so workers only take chunks at least this long.
+const MIN_BODIES_PER_JOB: usize = 4;
+
+/// A body lowered on a worker, waiting to be recorded by [`ModuleLowering::lower_item`].
+pub(super) struct PreloweredFunction<'a> {
+    lowered: LoweredFunction,
+    /// Strings the body interned first, as indices into the fork's interner.
+    interned: Range<usize>,
+    fork: &'a mut ForkMerge,
+}
+
+/// What a worker produced for one chunk.
+struct ForkedChunk {
+    bodies: VecDeque<(usize, LoweredFunction, Range<usize>)>,
+    type_layouts: TypeLayoutTable,
+    default_arguments: DefaultArgumentMap,
+    specializations: Vec<FunctionSpecialization>,
+}
+
+/// Maps a worker's interner onto the module interner as its bodies are recorded.
+pub(super) struct ForkMerge {
+    interner: StringInterner,
+    base: usize,
+    ids: Vec<Option<StrId>>,
+}
+
+impl ForkMerge {
+    fn new(interner: StringInterner, base: usize) -> Self {
+        let ids = vec![None; interner.len() - base];
+        Self {
+            interner,
+            base,
+            ids,
+        }
+    }
+
+    fn map(&mut self, id: StrId, module: &mut StringInterner) -> StrId {
+        self.map_index(id.index(), module).unwrap_or(id)
+    }
+
+    /// Module id for the fork's string at `index`; `None` for strings the fork inherited.
+    fn map_index(&mut self, index: usize, module: &mut StringInterner) -> Option<StrId> {
+        let offset = index.checked_sub(self.base)?;
+        if let Some(mapped) = self.ids[offset] {
+            return Some(mapped);
+        }
+        let entry = self.interner.segments().get(index)?;
+        let mapped = module.intern(&entry.value, entry.lifetime, entry.span);
+        self.ids[offset] = Some(mapped);
+        Some(mapped)
+    }
+}
+
+struct StrIdRemap<'a> {
+    fork: &'a mut ForkMerge,
+    module: &'a mut StringInterner,
+}
+
+impl StringSites for StrIdRemap<'_> {
+    fn visit_const(&mut self, value: &mut ConstValue) {
+        if let ConstValue::Str { id, .. } = value {
+            *id = self.fork.map(*id, self.module);
+        }
+    }
+
+    fn visit_str_id(&mut self, id: &mut StrId) {
+        *id = self.fork.map(*id, self.module);
+    }
+}
+
+impl ModuleLowering {
+    /// Lower `items` (each tagged with its translation unit) in order, handing runs of
+    /// free functions from one unit to the worker pool.
+    pub(super) fn lower_item_sequence(
+        &mut self,
+        items: &[(&Item, usize)],
+        namespace: Option<&str>,
+    ) {
+        let mut index = 0;
+        while index < items.len() {
+            let (item, unit) = items[index];
+            let run = items[index..]
+                .iter()
+                .take_while(|(candidate, candidate_unit)| {
+                    matches!(candidate, Item::Function(_)) && *candidate_unit == unit
+                })
+                .count();
+            if run < 2 {
+                self.lower_item(item, namespace, unit, None);
+                index += 1;
+                continue;
+            }
+            let run_items = items[index..index + run]
+                .iter()
+                .map(|(item, _)| *item)
+                .collect::<Vec<_>>();
+            self.lower_function_run(&run_items, namespace, unit);
+            index += run;
+        }
+    }
+
+    fn lower_function_run(&mut self, items: &[&Item], namespace: Option<&str>, unit: usize) {
+        let misses = items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| !self.cache.contains(&cache_key(item, namespace, unit)))
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        let jobs = threading::jobs().min(misses.len() / MIN_BODIES_PER_JOB);
+        if jobs < 2 {
+            for item in items {
+                self.lower_item(item, namespace, unit, None);
+            }
+            return;
+        }
+
+        let base = self.string_interner.len();
+        let chunks = misses
+            .chunks(misses.len().div_ceil(jobs))
+            .map(<[usize]>::to_vec)
+            .collect::<Vec<_>>();
+        let forked = self.fork_function_bodies(items, &chunks, namespace, unit);
+
+        let specialization_base = self.generic_specializations.borrow().len();
+        let mut forked = forked.into_iter();
+        let mut active: Option<(ForkedChunk, ForkMerge)> = None;
+        for (index, item) in items.iter().enumerate() {
+            if active.is_none() {
+                active = forked
+                    .next()
+                    .map(|(chunk, interner)| (chunk, ForkMerge::new(interner, base)));
+            }
+            let prelowered = active.as_mut().and_then(|(chunk, fork)| {
+                let (next, ..) = chunk.bodies.front()?;
+                if *next != index {
+                    return None;
+                }
+                let (_, lowered, interned) = chunk.bodies.pop_front()?;
+                Some(PreloweredFunction {
+                    lowered,
+                    interned,
+                    fork,
+                })
+            });
+            self.lower_item(item, namespace, unit, prelowered);
+            if let Some((chunk, fork)) = active.take_if(|(chunk, _)| chunk.bodies.is_empty()) {
+                self.absorb_forked_chunk(chunk, fork, specialization_base);
+            }
+        }
+    }
+
+    /// Lower the bodies of `items` named by each chunk on its own worker.
+    fn fork_function_bodies(
+        &self,
+        items: &[&Item],
+        chunks: &[Vec<usize>],
+        namespace: Option<&str>,
+        unit: usize,
+    ) -> Vec<(ForkedChunk, StringInterner)> {
+        let default_arguments = self.default_arguments.borrow().clone();
+        let specializations = self.generic_specializations.borrow().clone();
+        let package = self.unit_packages.get(unit).and_then(Clone::clone);
+        let import_resolver = self.import_resolver_for_unit(unit);
+        let (type_layouts, string_interner) = (&self.type_layouts, &self.string_interner);
+        let (type_visibilities, primitive_registry) =
+            (&self.type_visibilities, &self.primitive_registry);
+        let (function_packages, operator_registry) =
+            (&self.function_packages, &self.operator_registry);
+        let (symbol_index, static_registry) = (&self.symbol_index, &self.static_registry);
+        let (class_bases, class_virtual_slots, trait_decls) = (
+            &self.class_bases,
+            &self.class_virtual_slots,
+            &self.trait_decls,
+        );
+        threading::parallel_map(chunks, |chunk| {
+            let mut type_layouts = type_layouts.clone();
+            let mut interner = string_interner.clone();
+            let defaults = Rc::new(RefCell::new(default_arguments.clone()));
+            let specs = Rc::new(RefCell::new(specializations.clone()));
+            let bodies = chunk
+                .iter()
+                .map(|&index| {
+                    let Item::Function(func) = items[index] else {
+                        unreachable!("function runs only hold functions");
+                    };
+                    let start = interner.len();
+                    let lowered = lower_function(
+                        func,
+                        &qualify(namespace, &func.name),
+                        FunctionKind::Function,
+                        namespace,
+                        package.as_deref(),
+                        None,
+                        &mut type_layouts,
+                        type_visibilities,
+                        primitive_registry,
+                        defaults.clone(),
+                        function_packages,
+                        operator_registry,
+                        &mut interner,
+                        symbol_index,
+                        import_resolver,
+                        static_registry,
+                        class_bases,
+                        class_virtual_slots,
+                        trait_decls,
+                        specs.clone(),
+                    );
+                    (index, lowered, start..interner.len())
+                })
+                .collect();
+            let chunk = ForkedChunk {
+                bodies,
+                type_layouts,
+                default_arguments: defaults.take(),
+                specializations: specs.take(),
+            };
+            (chunk, interner)
+        })
+    }
+
+    /// Record the strings `prelowered` interned first, in the order it interned them, and
+    /// rewrite its bodies to the module's ids.
+    pub(super) fn adopt_prelowered(
+        &mut self,
+        prelowered: PreloweredFunction<'_>,
+    ) -> LoweredFunction {
+        let PreloweredFunction {
+            mut lowered,
+            interned,
+            fork,
+        } = prelowered;
+        for index in interned {
+            fork.map_index(index, &mut self.string_interner);
+        }
+        let mut remap = StrIdRemap {
+            fork,
+            module: &mut self.string_interner,
+        };
+        walk_body_strings(&mut lowered.function.body, &mut remap);
+        for nested in &mut lowered.nested_functions {
+            walk_body_strings(&mut nested.body, &mut remap);
+        }
+        lowered
+    }
+
+    fn absorb_forked_chunk(
+        &mut self,
+        chunk: ForkedChunk,
+        mut fork: ForkMerge,
+        specialization_base: usize,
+    ) {
+        let ForkedChunk {
+            type_layouts,
+            mut default_arguments,
+            specializations,
+            ..
+        } = chunk;
+        self.type_layouts.absorb(type_layouts);
+        let mut remap = StrIdRemap {
+            fork: &mut fork,
+            module: &mut self.string_interner,
+        };
+        for values in default_arguments.values_mut() {
+            for value in values.iter_mut().flatten() {
+                if let DefaultArgumentValue::Const(value) = value {
+                    walk_const_strings(value, &mut remap);
+                }
+            }
+        }
+        self.default_arguments
+            .borrow_mut()
+            .absorb(default_arguments);
+        let mut store = self.generic_specializations.borrow_mut();
+        for specialization in specializations.into_iter().skip(specialization_base) {
+            specialization.record_into(&mut store);
+        }
+    }
+}
@@ -328,6 +328,7 @@ impl ModuleLowering {
             );
         }
         self.synthesise_interface_trait_vtables();
+        self.instantiate_blanket_impls();
         self.type_layouts.backfill_missing_offsets();
         self.type_layouts.finalize_type_flags();
         self.type_layouts.primitive_registry = self.primitive_registry.clone();
@@ -2,6 +2,8 @@ use super::super::functions::{LoweredFunction, lower_function, lower_testcase};
 use super::super::{FunctionKind, Item, Visibility, qualify};
 use super::cache::CachedLowering;
 use super::driver::{ModuleLowering, ModuleUnitSlice, TypeDeclInfo};
+use super::parallel::PreloweredFunction;
+use crate::frontend::ast::ExtensionDecl;
 use crate::frontend::ast::items::UsingKind;
 use crate::frontend::attributes::{
     AttributeError, collect_cost_attribute, collect_trace_attribute,
@@ -131,26 +133,7 @@ impl ModuleLowering {
                 let type_name = qualify(namespace, &delegate.name);
                 self.upsert_type_package(&type_name, delegate.visibility, namespace, None, package);
             }
-            Item::Extension(ext) => {
-                if ext.target.base.is_empty() {
-                    return;
-                }
-                let base = ext.target.base.join("::");
-                let mut candidates = Vec::new();
-                candidates.push(base.clone());
-                if let Some(ns) = namespace {
-                    candidates.push(format!("{ns}::{base}"));
-                }
-
-                for member in &ext.members {
-                    let crate::frontend::ast::ExtensionMember::Method(method) = member;
-                    for candidate in &candidates {
-                        let owner_key = canonical_method_owner(candidate);
-                        let qualified = format!("{owner_key}::{}", method.function.name);
-                        self.record_function_package(&qualified, package);
-                    }
-                }
-            }
+            Item::Extension(ext) => self.record_extension_packages(ext, namespace, package),
             Item::Trait(trait_decl) => {
                 let type_name = qualify(namespace, &trait_decl.name);
                 self.upsert_type_package(
@@ -161,11 +144,41 @@ impl ModuleLowering {
                     package,
                 );
             }
-            Item::Impl(_) => {}
+            Item::Impl(impl_decl) => {
+                if let Some(ext) = impl_decl.inherent_extension() {
+                    self.record_extension_packages(&ext, namespace, package);
+                }
+            }
             Item::Const(_) | Item::Static(_) | Item::Import(_) | Item::TypeAlias(_) => {}
         }
     }
 
+    fn record_extension_packages(
+        &mut self,
+        ext: &ExtensionDecl,
+        namespace: Option<&str>,
+        package: Option<&str>,
+    ) {
+        if ext.target.base.is_empty() {
+            return;
+        }
+        let base = ext.target.base.join("::");
+        let mut candidates = Vec::new();
+        candidates.push(base.clone());
+        if let Some(ns) = namespace {
+            candidates.push(format!("{ns}::{base}"));
+        }
+
+        for member in &ext.members {
+            let crate::frontend::ast::ExtensionMember::Method(method) = member;
+            for candidate in &candidates {
+                let owner_key = canonical_method_owner(candidate);
+                let qualified = format!("{owner_key}::{}", method.function.name);
+                self.record_function_package(&qualified, package);
+            }
+        }
+    }
+
     fn record_function_package(&mut self, qualified: &str, package: Option<&str>) {
         if let Some(pkg) = package {
             self.function_packages
@@ -206,7 +219,7 @@ impl ModuleLowering {
     // queue_mgmt NOTE: Root traversal and translation-unit tagging reside here to
     // make queue-focused testing possible.
     pub(super) fn lower_root_items(&mut self, items: &[Item], namespace: Option<&str>) {
-        for item in items {
+        let items = items.iter().map(|item| {
             let unit = if let Some(units) = &self.item_units {
                 let unit = units.get(self.root_item_index).copied().unwrap_or(0);
                 self.root_item_index += 1;
@@ -215,8 +228,10 @@ impl ModuleLowering {
                 self.root_item_index += 1;
                 0
             };
-            self.lower_item(item, namespace, unit);
-        }
+            (item, unit)
+        });
+        let items = items.collect::<Vec<_>>();
+        self.lower_item_sequence(&items, namespace);
     }
 
     pub(super) fn lower_items<'a>(
@@ -225,9 +240,8 @@ impl ModuleLowering {
         namespace: Option<&str>,
         unit: usize,
     ) {
-        for item in items {
-            self.lower_item(item, namespace, unit);
-        }
+        let items = items.map(|item| (item, unit)).collect::<Vec<_>>();
+        self.lower_item_sequence(&items, namespace);
     }
 
     pub(super) fn record_lowered_function(&mut self, lowered: LoweredFunction) -> String {
@@ -271,7 +285,40 @@ impl ModuleLowering {
             let explicit_id = test_metadata
                 .as_ref()
                 .and_then(|meta| meta.explicit_id.clone());
-            let id = crate::mir::TestCaseMetadata::stable_id(&final_name, explicit_id.as_deref());
+            let kind = test_metadata
+                .as_ref()
+                .map(|meta| meta.kind)
+                .unwrap_or_default();
+            let runs = test_metadata.as_ref().and_then(|meta| meta.runs);
+            let row = test_metadata
+                .as_ref()
+                .and_then(|meta| meta.row.clone())
+                .map(|(declared, row)| crate::mir::TestCaseRow {
+                    testcase: qualify(resolved_namespace.as_deref(), &declared),
+                    index: row.index,
+                    arguments: row.arguments,
+                });
+            let (id, short_name) = match &row {
+                // Rows are identified by their arguments so inserting a row keeps existing ids.
+                Some(row) => (
+                    crate::mir::TestCaseMetadata::stable_id(
+                        &format!("{}({})", row.testcase, row.arguments),
+                        explicit_id
+                            .as_deref()
+                            .map(|id| format!("{id}[{}]", row.index))
+                            .as_deref(),
+                    ),
+                    format!(
+                        "{}({})",
+                        crate::mir::TestCaseMetadata::split_namespace(&row.testcase).1,
+                        row.arguments
+                    ),
+                ),
+                None => (
+                    crate::mir::TestCaseMetadata::stable_id(&final_name, explicit_id.as_deref()),
+                    short_name,
+                ),
+            };
             self.test_cases.push(crate::mir::TestCaseMetadata {
                 function_index,
                 id,
@@ -280,6 +327,9 @@ impl ModuleLowering {
                 namespace: resolved_namespace,
                 categories,
                 parameters,
+                kind,
+                runs,
+                row,
                 is_async: self
                     .functions
                     .get(function_index)
@@ -359,7 +409,15 @@ impl ModuleLowering {
 
     // queue_mgmt NOTE: Item dispatch now lives alongside the queue utilities so
     // the future pipeline can call into a single abstraction.
-    pub(super) fn lower_item(&mut self, item: &Item, namespace: Option<&str>, unit: usize) {
+    /// `prelowered` carries the body of an `Item::Function` already lowered on a worker
+    /// (see [`super::parallel`]); everything else about the item is recorded here.
+    pub(super) fn lower_item(
+        &mut self,
+        item: &Item,
+        namespace: Option<&str>,
+        unit: usize,
+        prelowered: Option<PreloweredFunction<'_>>,
+    ) {
         let previous_package = self.current_package.clone();
         self.current_package = self.unit_packages.get(unit).and_then(|pkg| pkg.clone());
 
@@ -412,32 +470,36 @@ impl ModuleLowering {
                 let perf_diags = self.record_perf_attributes(&name, &func.attributes, None);
                 self.diagnostics.extend(perf_diags);
                 self.check_signature(&func.signature, namespace, None, &name);
-                let import_resolver = self.import_resolver_for_unit(unit) as *const _;
-                let lowered = lower_function(
-                    func,
-                    &name,
-                    FunctionKind::Function,
-                    namespace,
-                    self.current_package.as_deref(),
-                    None,
-                    &mut self.type_layouts,
-                    &self.type_visibilities,
-                    &self.primitive_registry,
-                    self.default_arguments.clone(),
-                    &self.function_packages,
-                    &self.operator_registry,
-                    &mut self.string_interner,
-                    &self.symbol_index,
-                    // SAFETY: The resolver is stored on `self` and we don't mutate the resolver
-                    // storage during lowering, so the pointer remains valid for the duration of
-                    // this call.
-                    unsafe { &*import_resolver },
-                    &self.static_registry,
-                    &self.class_bases,
-                    &self.class_virtual_slots,
-                    &self.trait_decls,
-                    self.generic_specializations.clone(),
-                );
+                let lowered = if let Some(prelowered) = prelowered {
+                    self.adopt_prelowered(prelowered)
+                } else {
+                    let import_resolver = self.import_resolver_for_unit(unit) as *const _;
+                    lower_function(
+                        func,
+                        &name,
+                        FunctionKind::Function,
+                        namespace,
+                        self.current_package.as_deref(),
+                        None,
+                        &mut self.type_layouts,
+                        &self.type_visibilities,
+                        &self.primitive_registry,
+                        self.default_arguments.clone(),
+                        &self.function_packages,
+                        &self.operator_registry,
+                        &mut self.string_interner,
+                        &self.symbol_index,
+                        // SAFETY: The resolver is stored on `self` and we don't mutate the
+                        // resolver storage during lowering, so the pointer remains valid for
+                        // the duration of this call.
+                        unsafe { &*import_resolver },
+                        &self.static_registry,
+                        &self.class_bases,
+                        &self.class_virtual_slots,
+                        &self.trait_decls,
+                        self.generic_specializations.clone(),
+                    )
+                };
                 let _ = self.record_lowered_function(lowered);
             }
             Item::TestCase(test) => {
@@ -518,7 +580,10 @@ impl ModuleLowering {
             }
             Item::Extension(ext) => self.lower_extension(ext, namespace),
             Item::Trait(_) => {}
-            Item::Impl(impl_decl) => self.lower_impl(impl_decl, namespace),
+            Item::Impl(impl_decl) => match impl_decl.inherent_extension() {
+                Some(ext) => self.lower_extension(&ext, namespace),
+                None => self.lower_impl(impl_decl, namespace),
+            },
             Item::Const(_) | Item::Static(_) => {}
             Item::Import(_) => {}
             Item::TypeAlias(_) => {}
@@ -597,7 +662,7 @@ fn force_profile_tracepoints() -> bool {
     })
 }
 
-fn cache_key(item: &Item, namespace: Option<&str>, unit: usize) -> String {
+pub(super) fn cache_key(item: &Item, namespace: Option<&str>, unit: usize) -> String {
     let digest = hash(format!("{:?}", item).as_bytes());
     format!(
         "ns:{}|unit:{}|{}",
@@ -3,13 +3,20 @@ use super::super::{FunctionKind, Item, qualify};
 use super::driver::{LoweringDiagnostic, ModuleLowering};
 use crate::frontend::ast::Signature;
 use crate::frontend::ast::{
-    FunctionDecl, ImplDecl, ImplMember, InterfaceDecl, InterfaceMember, TraitDecl, TraitMember,
+    FunctionDecl, GenericConstraintKind, ImplDecl, ImplMember, InterfaceDecl, InterfaceMember,
+    TraitDecl, TraitMember, TypeExpr, Visibility,
 };
 use crate::frontend::import_resolver::Resolution as ImportResolution;
-use crate::frontend::type_utils::instantiate_extension_method;
+use crate::frontend::type_utils::{instantiate_blanket_method, instantiate_extension_method};
 use crate::mir::data::{TraitVTable, VTableSlot};
-use crate::mir::trait_vtable_symbol_name;
-use std::collections::HashMap;
+use crate::mir::{TypeLayout, trait_vtable_symbol_name};
+use crate::primitives::PrimitiveKind;
+use crate::typeck::{
+    ImplFact, ImplFacts, layout_has_auto_trait, names_match, qualify_blanket_bounds,
+    satisfies_blanket_bounds,
+};
+use std::collections::{HashMap, HashSet};
+use std::mem::take;
 
 impl ModuleLowering {
     pub(super) fn collect_traits(&mut self, items: &[Item], namespace: Option<&str>) {
@@ -71,22 +78,151 @@ impl ModuleLowering {
     }
 
     pub(super) fn lower_impl(&mut self, impl_decl: &ImplDecl, namespace: Option<&str>) {
-        if impl_decl.trait_ref.is_none() {
+        if impl_decl.blanket_param().is_some() {
+            self.blanket_impls
+                .push((impl_decl.clone(), namespace.map(str::to_string)));
+            return;
+        }
+        if impl_decl
+            .generics
+            .as_ref()
+            .is_some_and(|generics| !generics.params.is_empty())
+        {
             self.diagnostics.push(LoweringDiagnostic {
-                message: "inherent impl blocks are not supported during lowering".into(),
+                message: format!(
+                    "generic impl for `{}` cannot be lowered; only blanket impls over a bare type parameter are instantiated",
+                    impl_decl.target.name
+                ),
                 span: impl_decl.span,
             });
             return;
         }
-        self.lower_trait_impl(impl_decl, namespace);
+        let Some(trait_name) = self.resolve_impl_trait(impl_decl, namespace) else {
+            return;
+        };
+        let impl_name = qualify(namespace, &impl_decl.target.name);
+        self.lower_trait_impl(impl_decl, &trait_name, impl_name);
+    }
+
+    /// Instantiates each deferred blanket impl for every struct, class, enum or primitive that
+    /// satisfies its bounds and does not already implement the trait directly. Candidates are
+    /// chosen with the type checker's implementation predicate, and rounds repeat until no new
+    /// instance appears so instances can satisfy the bounds of other blanket impls.
+    pub(super) fn instantiate_blanket_impls(&mut self) {
+        let mut blankets = Vec::new();
+        for (mut impl_decl, namespace) in take(&mut self.blanket_impls) {
+            if let Some(trait_name) = self.resolve_impl_trait(&impl_decl, namespace.as_deref()) {
+                if let Some(generics) = impl_decl.generics.as_mut() {
+                    qualify_blanket_bounds(generics, &impl_decl.target, |bound| {
+                        match self.resolve_type_for_expr(bound, namespace.as_deref(), None) {
+                            ImportResolution::Found(name) => Some(name),
+                            _ => None,
+                        }
+                    });
+                }
+                blankets.push(BlanketImpl {
+                    decl: impl_decl,
+                    trait_name,
+                });
+            }
+        }
+        let candidates = self.blanket_candidates();
+        let mut instantiated = HashSet::new();
+        loop {
+            let facts = LoweredImpls {
+                lowering: self,
+                blankets: &blankets,
+            };
+            let mut pending = Vec::new();
+            for (index, blanket) in blankets.iter().enumerate() {
+                let Some(param) = blanket.decl.blanket_param() else {
+                    continue;
+                };
+                for candidate in &candidates {
+                    if instantiated.contains(&(index, candidate.clone()))
+                        || facts.has_direct_impl(candidate, &blanket.trait_name)
+                        || !satisfies_blanket_bounds(&facts, candidate, param, &mut HashSet::new())
+                    {
+                        continue;
+                    }
+                    pending.push((index, candidate.clone()));
+                }
+            }
+            if pending.is_empty() {
+                break;
+            }
+            for (index, candidate) in pending {
+                self.lower_blanket_instance(&blankets[index], candidate.clone());
+                instantiated.insert((index, candidate));
+            }
+        }
+    }
+
+    /// User-declared structs, classes and enums plus every primitive, sorted by name.
+    fn blanket_candidates(&self) -> Vec<String> {
+        let mut candidates = self
+            .type_visibilities
+            .keys()
+            .filter(|name| {
+                matches!(
+                    self.type_layouts.types.get(*name),
+                    Some(TypeLayout::Struct(layout) | TypeLayout::Class(layout))
+                        if !layout.is_intrinsic
+                ) || matches!(
+                    self.type_layouts.types.get(*name),
+                    Some(TypeLayout::Enum(_))
+                )
+            })
+            .filter(|name| {
+                !self.symbol_index.is_interface(name) && !self.trait_decls.contains_key(*name)
+            })
+            .cloned()
+            .chain(
+                self.primitive_registry
+                    .descriptors()
+                    .iter()
+                    .filter(|descriptor| !matches!(descriptor.kind, PrimitiveKind::Void))
+                    .map(|descriptor| descriptor.primitive_name.clone()),
+            )
+            .collect::<Vec<_>>();
+        candidates.sort();
+        candidates.dedup();
+        candidates
     }
 
-    fn lower_trait_impl(&mut self, impl_decl: &ImplDecl, namespace: Option<&str>) {
-        let Some(trait_ref) = &impl_decl.trait_ref else {
+    fn lower_blanket_instance(&mut self, blanket: &BlanketImpl, candidate: String) {
+        let Some(param) = blanket.decl.blanket_param() else {
             return;
         };
-        let trait_name = match self.resolve_type_for_expr(trait_ref, namespace, None) {
-            ImportResolution::Found(name) => name,
+        let target = TypeExpr::simple(candidate.replace("::", "."));
+        let mut concrete = blanket.decl.clone();
+        concrete.members = blanket
+            .decl
+            .members
+            .iter()
+            .map(|member| match member {
+                ImplMember::Method(method) => {
+                    ImplMember::Method(instantiate_blanket_method(method, &param.name, &target))
+                }
+                other => other.clone(),
+            })
+            .collect();
+        concrete.generics = blanket.decl.generics.clone().and_then(|mut generics| {
+            generics.params.retain(|other| other.name != param.name);
+            (!generics.params.is_empty()).then_some(generics)
+        });
+        concrete.target = target;
+        self.lower_trait_impl(&concrete, &blanket.trait_name, candidate);
+    }
+
+    fn resolve_impl_trait(
+        &mut self,
+        impl_decl: &ImplDecl,
+        namespace: Option<&str>,
+    ) -> Option<String> {
+        let trait_ref = impl_decl.trait_ref.as_ref()?;
+        match self.resolve_type_for_expr(trait_ref, namespace, None) {
+            ImportResolution::Found(name) => Some(name),
             ImportResolution::Ambiguous(candidates) => {
                 self.diagnostics.push(LoweringDiagnostic {
                     message: format!(
@@ -96,18 +232,21 @@ impl ModuleLowering {
                     ),
                     span: impl_decl.span,
                 });
-                return;
+                None
             }
             ImportResolution::NotFound => {
                 self.diagnostics.push(LoweringDiagnostic {
                     message: format!("trait `{}` not found", trait_ref.name),
                     span: impl_decl.span,
                 });
-                return;
+                None
             }
-        };
+        }
+    }
 
-        let Some(trait_info) = self.trait_decls.get(&trait_name).cloned() else {
+    #[allow(clippy::too_many_lines)]
+    fn lower_trait_impl(&mut self, impl_decl: &ImplDecl, trait_name: &str, impl_name: String) {
+        let Some(trait_info) = self.trait_decls.get(trait_name).cloned() else {
             self.diagnostics.push(LoweringDiagnostic {
                 message: format!(
                     "trait `{}` has not been declared in this module",
@@ -118,7 +257,6 @@ impl ModuleLowering {
             return;
         };
 
-        let impl_name = qualify(namespace, &impl_decl.target.name);
         let mut provided = ImplMethodMap::new();
         for member in &impl_decl.members {
             if let ImplMember::Method(method) = member {
@@ -158,7 +296,7 @@ impl ModuleLowering {
                 });
             }
 
-            let trait_label = trait_name.rsplit("::").next().unwrap_or(&trait_name);
+            let trait_label = trait_name.rsplit("::").next().unwrap_or(trait_name);
             let lowered_name = format!("{impl_name}::{trait_label}::{}", method.name);
             self.check_signature(
                 &method_decl.signature,
@@ -199,10 +337,10 @@ impl ModuleLowering {
         }
 
         if !slots.is_empty() {
-            let symbol = trait_vtable_symbol_name(&trait_name, &impl_name);
+            let symbol = trait_vtable_symbol_name(trait_name, &impl_name);
             self.trait_vtables.push(TraitVTable {
                 symbol,
-                trait_name,
+                trait_name: trait_name.to_string(),
                 impl_type: impl_name,
                 slots,
             });
@@ -224,3 +362,88 @@ pub(crate) struct TraitMethodLoweringInfo {
     pub default_impl: Option<FunctionDecl>,
     pub is_async: bool,
 }
+
+/// A deferred blanket impl with its resolved trait name.
+struct BlanketImpl {
+    decl: ImplDecl,
+    trait_name: String,
+}
+
+/// Impls known to lowering, viewed through the type checker's implementation predicate:
+/// lowered vtables are concrete impls and deferred blanket impls keep their bounds.
+struct LoweredImpls<'a> {
+    lowering: &'a ModuleLowering,
+    blankets: &'a [BlanketImpl],
+}
+
+impl LoweredImpls<'_> {
+    fn has_direct_impl(&self, type_name: &str, trait_name: &str) -> bool {
+        self.lowering.trait_vtables.iter().any(|vtable| {
+            vtable.trait_name == trait_name && names_match(&vtable.impl_type, type_name)
+        }) || self.implements_interface(type_name, trait_name)
+    }
+}
+
+impl ImplFacts for LoweredImpls<'_> {
+    fn impl_facts(&self) -> impl Iterator<Item = ImplFact<'_>> {
+        let concrete = self.lowering.trait_vtables.iter().map(|vtable| ImplFact {
+            trait_name: vtable.trait_name.clone(),
+            target: vtable.impl_type.clone(),
+            blanket_param: None,
+        });
+        let blanket = self.blankets.iter().map(|blanket| ImplFact {
+            trait_name: blanket.trait_name.clone(),
+            target: String::new(),
+            blanket_param: blanket.decl.blanket_param(),
+        });
+        concrete.chain(blanket)
+    }
+
+    fn implements_interface(&self, ty: &str, interface: &str) -> bool {
+        let mut visited = HashSet::new();
+        let mut pending = vec![ty.to_string()];
+        while let Some(current) = pending.pop() {
+            if names_match(&current, interface) {
+                return true;
+            }
+            if visited.insert(current.clone())
+                && let Some(bases) = self.lowering.class_bases.get(&current)
+            {
+                pending.extend(bases.iter().cloned());
+            }
+        }
+        false
+    }
+
+    fn satisfies_constraint(&self, ty: &str, constraint: &GenericConstraintKind) -> bool {
+        let layouts = &self.lowering.type_layouts;
+        let value_type = match layouts.types.get(ty) {
+            Some(TypeLayout::Class(_)) => Some(false),
+            Some(_) => Some(true),
+            None => self
+                .lowering
+                .primitive_registry
+                .kind_for_name(ty)
+                .map(|kind| !matches!(kind, PrimitiveKind::String | PrimitiveKind::Str)),
+        };
+        match constraint {
+            GenericConstraintKind::Struct => value_type == Some(true),
+            GenericConstraintKind::Class => value_type == Some(false),
+            GenericConstraintKind::DefaultConstructor => match value_type {
+                Some(true) => true,
+                Some(false) => {
+                    let constructors = self.lowering.symbol_index.constructor_overloads(ty);
+                    constructors.is_empty()
+                        || constructors.iter().any(|ctor| {
+                            ctor.params.is_empty() && matches!(ctor.visibility, Visibility::Public)
+                        })
+                }
+                None => false,
+            },
+            GenericConstraintKind::AutoTrait(required) => {
+                layout_has_auto_trait(layouts, ty, *required)
+            }
+            GenericConstraintKind::NotNull | GenericConstraintKind::Type(_) => true,
+        }
+    }
+}
@@ -9,6 +9,20 @@ pub(crate) struct FunctionSpecialization {
     pub type_args: Vec<Ty>,
 }
 
+impl FunctionSpecialization {
+    /// Append `self` unless `store` already specialises the same symbol or the same
+    /// base/type-argument pair; the first request wins.
+    pub(crate) fn record_into(self, store: &mut Vec<FunctionSpecialization>) {
+        if store.iter().any(|entry| {
+            entry.specialized == self.specialized
+                || entry.base == self.base && entry.type_args == self.type_args
+        }) {
+            return;
+        }
+        store.push(self);
+    }
+}
+
 /// Generate a stable, human-readable mangled name for a specialised function.
 pub(crate) fn specialised_function_name(base: &str, type_args: &[Ty]) -> String {
     if type_args.is_empty() {
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use crate::frontend::diagnostics::Span;
 use crate::mir::data::{InternedStr, StrId, StrLifetime};
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub(crate) struct StringInterner {
     map: HashMap<String, StrId>,
     segments: Vec<InternedStr>,
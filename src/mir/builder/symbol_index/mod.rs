@@ -2,7 +2,7 @@ use std::collections::{HashMap, HashSet};
 use std::ops::{Deref, DerefMut};
 
 use crate::frontend::ast::{Module, PropertyAccessorKind};
-use crate::frontend::metadata::{TypeDescriptor, collect_reflection_tables};
+use crate::frontend::metadata::{TypeDescriptor, TypeKind, collect_reflection_tables};
 use crate::mir::data::{ConstValue, FnTy};
 use crate::type_metadata::TypeVariance;
 
@@ -102,6 +102,14 @@ impl SymbolIndex {
         self.constructor_decls.values()
     }
 
+    pub(crate) fn is_interface(&self, name: &str) -> bool {
+        if self.interfaces.contains(name) {
+            return true;
+        }
+        self.reflection_descriptor(name)
+            .is_some_and(|descriptor| descriptor.kind == TypeKind::Interface)
+    }
+
     pub(crate) fn is_readonly_struct(&self, name: &str) -> bool {
         if self.readonly_structs.contains(name) {
             return true;
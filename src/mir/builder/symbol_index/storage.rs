@@ -144,6 +144,7 @@ pub struct TypeGenericParamEntry {
 pub struct SymbolStorage {
     pub types: HashSet<String>,
     pub readonly_structs: HashSet<String>,
+    pub interfaces: HashSet<String>,
     pub functions: HashMap<String, Vec<FunctionSymbol>>,
     pub function_decls: HashMap<String, Vec<FunctionDeclSymbol>>,
     pub constructor_decls: HashMap<String, Vec<ConstructorDeclSymbol>>,
@@ -169,6 +170,7 @@ impl SymbolStorage {
     pub fn clear(&mut self) {
         self.types.clear();
         self.readonly_structs.clear();
+        self.interfaces.clear();
         self.functions.clear();
         self.function_decls.clear();
         self.constructor_decls.clear();
@@ -42,7 +42,11 @@ impl SymbolIndex {
                     let nested = qualify(namespace, &ns.name);
                     self.collect_items(ns.items.iter(), Some(nested.as_str()));
                 }
-                Item::Impl(_) => {}
+                Item::Impl(impl_decl) => {
+                    if let Some(ext) = impl_decl.inherent_extension() {
+                        self.register_extension(namespace, &ext);
+                    }
+                }
                 Item::TypeAlias(_) => {}
                 Item::TestCase(test) => self.register_testcase(namespace, test),
                 Item::Import(_) => {}
@@ -422,6 +426,7 @@ impl SymbolIndex {
     fn register_interface(&mut self, namespace: Option<&str>, iface: &InterfaceDecl) {
         let type_name = qualify(namespace, &iface.name);
         self.types.insert(type_name.clone());
+        self.interfaces.insert(type_name.clone());
         self.record_type_generics(&type_name, iface.generics.as_ref());
         for member in &iface.members {
             match member {
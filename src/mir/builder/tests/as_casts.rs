@@ -1,5 +1,5 @@
 use super::*;
-use crate::mir::AggregateKind;
+use crate::mir::{AggregateKind, Pattern};
 
 fn lower_source(source: &str) -> LoweringResult {
     let parsed = parse_module(source).expect("module should parse");
@@ -421,8 +421,90 @@ namespace Objects {
     assert_eq!(*kind, CastKind::Unknown);
 }
 
+const CAST_EXCEPTION_PRELUDE: &str = r#"
+namespace Std {
+    public class Exception {
+        public string Message;
+        public init(string message) { Message = message; }
+    }
+    public class InvalidCastException : Exception {
+        public init(str message) : base(message) { }
+    }
+}
+"#;
+
+fn type_test_targets(func: &MirFunction) -> Vec<String> {
+    func.body
+        .blocks
+        .iter()
+        .filter_map(|block| match &block.terminator {
+            Some(Terminator::Match { arms, .. }) => Some(arms),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|arm| match &arm.pattern {
+            Pattern::Type(ty) => Some(ty.canonical_name()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn class_downcasts_lower_to_runtime_type_tests() {
+    let source = format!(
+        "{CAST_EXCEPTION_PRELUDE}{}",
+        r#"
+namespace Objects {
+    public class Base { }
+    public class Derived : Base { }
+
+    public Derived Downcast(Base value) { return (Derived)value; }
+}
+"#
+    );
+    let lowering = lower_source(&source);
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+    let func = find_function(&lowering, "Objects::Downcast");
+    assert_eq!(type_test_targets(func), vec!["Derived".to_string()]);
+    let thrown = func
+        .body
+        .blocks
+        .iter()
+        .find_map(|block| match &block.terminator {
+            Some(Terminator::Throw { ty, .. }) => ty.clone(),
+            _ => None,
+        })
+        .expect("expected failed downcast to throw");
+    assert_eq!(thrown.canonical_name(), "Std::InvalidCastException");
+    assert!(
+        func.body.blocks.iter().any(|block| matches!(
+            &block.terminator,
+            Some(Terminator::Call {
+                func: Operand::Const(constant),
+                args,
+                ..
+            }) if matches!(constant.value(), ConstValue::Symbol(name) if name.starts_with("Std::InvalidCastException::init"))
+                && args.iter().any(|arg| matches!(
+                    arg,
+                    Operand::Const(message)
+                        if matches!(message.value(), ConstValue::Str { value, .. } if value == "unable to cast `Base` to `Derived`")
+                ))
+        )),
+        "expected the exception to be constructed with the cast message"
+    );
+    assert!(
+        func.body.effects.is_empty(),
+        "cast failures should not surface as declared effects: {:?}",
+        func.body.effects
+    );
+}
+
 #[test]
-fn class_downcasts_surface_diagnostics() {
+fn class_downcasts_panic_without_invalid_cast_exception() {
     let source = r#"
 namespace Objects {
     public class Base { }
@@ -433,11 +515,115 @@ namespace Objects {
 "#;
     let lowering = lower_source(source);
     assert!(
-        lowering
-            .diagnostics
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+    let func = find_function(&lowering, "Objects::Downcast");
+    assert!(
+        func.body
+            .blocks
+            .iter()
+            .any(|block| matches!(block.terminator, Some(Terminator::Panic { .. }))),
+        "expected failed downcast to panic"
+    );
+}
+
+#[test]
+fn as_downcasts_yield_null_on_mismatch() {
+    let source = r#"
+namespace Objects {
+    public class Base { }
+    public class Derived : Base { }
+
+    public Derived? TryDowncast(Base value) { return value as Derived; }
+}
+"#;
+    let lowering = lower_source(source);
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+    let func = find_function(&lowering, "Objects::TryDowncast");
+    assert_eq!(type_test_targets(func), vec!["Derived".to_string()]);
+    assert!(
+        func.body.blocks.iter().all(|block| !matches!(
+            block.terminator,
+            Some(Terminator::Throw { .. } | Terminator::Panic { .. })
+        )),
+        "`as` casts must not throw"
+    );
+    assert!(
+        func.body
+            .blocks
             .iter()
-            .any(|diag| diag.message.contains("downcasting")),
-        "expected downcast diagnostic, found {:?}",
+            .any(|block| block.statements.iter().any(|stmt| matches!(
+                &stmt.kind,
+                StatementKind::Assign {
+                    value: Rvalue::Use(Operand::Const(constant)),
+                    ..
+                } if matches!(constant.value(), ConstValue::Null)
+            ))),
+        "expected mismatch path to assign null"
+    );
+}
+
+#[test]
+fn interface_casts_lower_to_runtime_type_tests() {
+    let source = r#"
+namespace Objects {
+    public interface IShape { int Sides(); }
+    public class Base { }
+    public class Square : Base, IShape { public int Sides() { return 4; } }
+
+    public IShape ToShape(Base value) { return (IShape)value; }
+    public Square FromShape(IShape shape) { return (Square)shape; }
+}
+"#;
+    let lowering = lower_source(source);
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
         lowering.diagnostics
     );
+    let to_shape = find_function(&lowering, "Objects::ToShape");
+    assert_eq!(type_test_targets(to_shape), vec!["IShape".to_string()]);
+    let from_shape = find_function(&lowering, "Objects::FromShape");
+    assert_eq!(type_test_targets(from_shape), vec!["Square".to_string()]);
+}
+
+#[test]
+fn is_type_patterns_bind_the_converted_reference() {
+    let source = r#"
+namespace Objects {
+    public class Base { }
+    public class Derived : Base { public int Value; }
+
+    public int Read(Base value) {
+        if (value is Derived derived) {
+            return derived.Value;
+        }
+        return 0;
+    }
+}
+"#;
+    let lowering = lower_source(source);
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+    let func = find_function(&lowering, "Objects::Read");
+    assert_eq!(
+        type_test_targets(func),
+        vec!["Objects::Derived".to_string()]
+    );
+    let binding = func
+        .body
+        .locals
+        .iter()
+        .find(|local| local.name.as_deref() == Some("derived"))
+        .expect("expected `derived` binding local");
+    assert_eq!(binding.ty.canonical_name(), "Objects::Derived");
 }
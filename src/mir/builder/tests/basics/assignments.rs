@@ -177,21 +177,21 @@ matrix[row, column] = row + column;
     let lowering = lower_no_diagnostics(source);
     let func = find_function(&lowering, "Set");
 
-    let place = func.body.blocks[0]
-        .statements
+    let place = func
+        .body
+        .blocks
         .iter()
-        .find_map(|stmt| {
-            if let MirStatementKind::Assign { place, .. } = &stmt.kind {
-                let index_count = place
+        .flat_map(|block| &block.statements)
+        .find_map(|stmt| match &stmt.kind {
+            MirStatementKind::Assign { place, .. }
+                if place
                     .projection
                     .iter()
-                    .filter(|proj| matches!(proj, ProjectionElem::Index(_)))
-                    .count();
-                if index_count == 2 {
-                    return Some(place.clone());
-                }
+                    .any(|proj| matches!(proj, ProjectionElem::Index(_))) =>
+            {
+                Some(place.clone())
             }
-            None
+            _ => None,
         })
         .require("missing matrix index assignment");
 
@@ -200,7 +200,10 @@ matrix[row, column] = row + column;
         .iter()
         .filter(|proj| matches!(proj, ProjectionElem::Index(_)))
         .count();
-    assert_eq!(index_count, 2, "expected exactly two index projections");
+    assert_eq!(
+        index_count, 1,
+        "rectangular indices should flatten into a single row-major offset"
+    );
 }
 
 #[test]
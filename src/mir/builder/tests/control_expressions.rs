@@ -62,8 +62,21 @@ public void Bump(Device dev)
     verify_body(body).require("verify mmio compound assignment body");
 }
 
+fn call_targets(body: &MirBody) -> Vec<String> {
+    body.blocks
+        .iter()
+        .filter_map(|block| match &block.terminator {
+            Some(Terminator::Call {
+                func: Operand::Pending(pending),
+                ..
+            }) => Some(pending.repr.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
 #[test]
-fn compound_property_assignment_reports_diagnostic() {
+fn compound_property_assignment_lowers_through_getter_and_setter() {
     let source = r#"
 namespace Demo;
 
@@ -81,13 +94,130 @@ public void Increment(Counter counter)
     let parsed = parse_module(source).require("parse property module");
     let lowering = lower_module(&parsed.module);
     assert!(
-        lowering
-            .diagnostics
-            .iter()
-            .any(|diag| diag.message.contains("compound assignment on property")),
-        "expected compound property diagnostic, got {:?}",
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
         lowering.diagnostics
     );
+    let func = lowering
+        .module
+        .functions
+        .iter()
+        .find(|func| func.name == "Demo::Increment")
+        .require("Increment function");
+    let calls = call_targets(&func.body);
+    assert_eq!(calls.len(), 2, "expected getter then setter, got {calls:?}");
+    assert!(calls[0].ends_with("Counter::get_Value"), "{calls:?}");
+    assert!(calls[1].ends_with("Counter::set_Value"), "{calls:?}");
+
+    let receivers: Vec<&Operand> = func
+        .body
+        .blocks
+        .iter()
+        .filter_map(|block| match &block.terminator {
+            Some(Terminator::Call { args, .. }) => args.first(),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        format!("{:?}", receivers[0]),
+        format!("{:?}", receivers[1]),
+        "getter and setter should share the receiver evaluated once"
+    );
+    let adds = func
+        .body
+        .blocks
+        .iter()
+        .flat_map(|block| &block.statements)
+        .any(|stmt| {
+            matches!(
+                &stmt.kind,
+                MirStatementKind::Assign {
+                    value: Rvalue::Binary { op: BinOp::Add, .. },
+                    ..
+                }
+            )
+        });
+    assert!(
+        adds,
+        "compound property assignment should add to the current value"
+    );
+}
+
+#[test]
+fn compound_assignment_on_statics_reads_and_writes_back() {
+    let source = r#"
+namespace Demo;
+
+public static class Stats
+{
+    public static int Hits { get; set; }
+    public static int Total;
+
+    public static void Double()
+    {
+        Total *= 2;
+    }
+}
+
+public void Record()
+{
+    Stats.Hits += 2;
+    Stats.Total -= 1;
+}
+"#;
+
+    let parsed = parse_module(source).require("parse static module");
+    let lowering = lower_module(&parsed.module);
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+
+    let record = lowering
+        .module
+        .functions
+        .iter()
+        .find(|func| func.name == "Demo::Record")
+        .require("Record function");
+    let calls = call_targets(&record.body);
+    assert!(
+        calls.len() == 2
+            && calls[0].ends_with("Stats::get_Hits")
+            && calls[1].ends_with("Stats::set_Hits"),
+        "static property compound assignment should call getter then setter, got {calls:?}"
+    );
+
+    for name in ["Demo::Record", "Demo::Stats::Double"] {
+        let func = lowering
+            .module
+            .functions
+            .iter()
+            .find(|func| func.name == name)
+            .require("static field function");
+        let statements: Vec<_> = func
+            .body
+            .blocks
+            .iter()
+            .flat_map(|block| &block.statements)
+            .collect();
+        let load = statements.iter().position(|stmt| {
+            matches!(
+                stmt.kind,
+                MirStatementKind::Assign {
+                    value: Rvalue::StaticLoad { .. },
+                    ..
+                }
+            )
+        });
+        let store = statements
+            .iter()
+            .position(|stmt| matches!(stmt.kind, MirStatementKind::StaticStore { .. }));
+        assert!(
+            matches!((load, store), (Some(load), Some(store)) if load < store),
+            "{name} should load the static field before storing the combined value"
+        );
+    }
 }
 
 #[test]
@@ -0,0 +1,88 @@
+use super::common::RequireExt;
+use super::*;
+use crate::frontend::macro_expander::{MacroRegistry, expand_module};
+
+fn expand_and_lower(source: &str) -> LoweringResult {
+    let mut parsed = parse_module(source).require("parse");
+    let expansion = expand_module(&mut parsed.module, &MacroRegistry::with_builtins());
+    assert!(
+        expansion.diagnostics.is_empty(),
+        "unexpected expansion diagnostics: {:?}",
+        expansion.diagnostics
+    );
+    lower_module(&parsed.module)
+}
+
+#[test]
+fn derived_payload_enum_members_lower_and_dispatch() {
+    let source = r#"
+namespace Geo;
+
+@derive(Equatable, Hashable, Debug, Default)
+public enum Shape
+{
+    Empty,
+    Circle { public int Radius; },
+    Rect { public int Width; public int Height; },
+}
+
+public bool Same(Shape left, Shape right)
+{
+    return left == right;
+}
+
+public Shape Origin()
+{
+    return Shape.Default();
+}
+"#;
+
+    let lowering = expand_and_lower(source);
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+    for name in [
+        "Geo::Shape::op_Equality",
+        "Geo::Shape::op_Inequality",
+        "Geo::Shape::GetHashCode",
+        "Geo::Shape::ToString",
+        "Geo::Shape::Default",
+    ] {
+        assert!(
+            lowering.module.functions.iter().any(|f| f.name == name),
+            "missing derived member `{name}`"
+        );
+    }
+
+    let equality = lowering
+        .module
+        .functions
+        .iter()
+        .find(|f| f.name == "Geo::Shape::op_Equality")
+        .require("missing op_Equality");
+    assert_eq!(
+        equality.signature.params.len(),
+        2,
+        "derived operators are static and take no receiver"
+    );
+
+    let same = lowering
+        .module
+        .functions
+        .iter()
+        .find(|f| f.name == "Geo::Same")
+        .require("missing Same function");
+    let calls_overload = same.body.blocks.iter().any(|block| {
+        matches!(
+            &block.terminator,
+            Some(Terminator::Call { func: Operand::Pending(pending), .. })
+                if pending.repr == "Geo::Shape::op_Equality"
+        )
+    });
+    assert!(
+        calls_overload,
+        "`==` on an enum deriving Equatable should call the generated operator"
+    );
+}
@@ -249,6 +249,26 @@ finally
         }
         other => panic!("expected switch terminator for finally exit, found {other:?}"),
     }
+    assert!(
+        body.blocks.iter().any(|block| {
+            block.statements.iter().any(|statement| {
+                matches!(
+                    &statement.kind,
+                    MirStatementKind::Assign {
+                        value: Rvalue::Cast {
+                            kind: CastKind::IntToPointer,
+                            ..
+                        },
+                        ..
+                    }
+                )
+            }) && matches!(
+                block.terminator,
+                Some(Terminator::Goto { target }) if target == finally.entry
+            )
+        }),
+        "exceptions captured from calls should run the finally block"
+    );
 }
 
 #[test]
@@ -33,6 +33,7 @@ mod conversions;
 mod decimal_intrinsics;
 mod default_arguments;
 mod delegates;
+mod derives;
 mod di;
 mod exceptions;
 mod externs;
@@ -48,9 +49,11 @@ mod new_expr;
 mod null_coalesce;
 mod operators;
 mod overloads;
+mod parallel_lowering;
 mod patterns;
 mod properties;
 mod quote;
+mod rectangular_arrays;
 mod reflect;
 mod required;
 mod resources;
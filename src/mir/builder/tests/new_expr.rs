@@ -1,5 +1,6 @@
 use super::common::RequireExt;
 use super::*;
+use crate::mir::data::AggregateKind;
 
 #[test]
 fn class_new_invokes_runtime_allocator_and_passes_self() {
@@ -466,3 +467,107 @@ public class Factory
         "collection initializer should call Add for each element"
     );
 }
+
+#[test]
+fn enum_variant_new_builds_variant_aggregate() {
+    let source = r#"
+namespace Demo;
+
+public enum Shape
+{
+    Empty,
+    Rect { public int Width; public int Height; },
+}
+
+public Shape Build()
+{
+    return new Shape.Rect { Height = 2, Width = 1 };
+}
+"#;
+
+    let parsed = parse_module(source).require("parse");
+    let lowering = lower_module(&parsed.module);
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+
+    let build_func = lowering
+        .module
+        .functions
+        .iter()
+        .find(|f| f.name.ends_with("::Build"))
+        .require("missing Build function");
+    let fields = build_func
+        .body
+        .blocks
+        .iter()
+        .flat_map(|block| block.statements.iter())
+        .find_map(|stmt| match &stmt.kind {
+            StatementKind::Assign {
+                value:
+                    Rvalue::Aggregate {
+                        kind: AggregateKind::Adt { name, variant },
+                        fields,
+                    },
+                ..
+            } if name == "Demo::Shape" && variant.as_deref() == Some("Rect") => Some(fields),
+            _ => None,
+        })
+        .require("expected a `Rect` variant aggregate");
+    let values: Vec<_> = fields
+        .iter()
+        .map(|operand| match operand {
+            Operand::Const(constant) => constant.value.clone(),
+            other => panic!("expected constant payload, found {other:?}"),
+        })
+        .collect();
+    assert!(
+        matches!(values.as_slice(), [ConstValue::Int(1), ConstValue::Int(2)]),
+        "payload must follow declaration order: {values:?}"
+    );
+}
+
+#[test]
+fn enum_variant_new_reports_missing_and_unknown_fields() {
+    let source = r#"
+namespace Demo;
+
+public enum Shape
+{
+    Empty,
+    Rect { public int Width; public int Height; },
+}
+
+public Shape Missing()
+{
+    return new Shape.Rect { Width = 1 };
+}
+
+public Shape Unknown()
+{
+    return new Shape.Rect { Width = 1, Height = 2, Depth = 3 };
+}
+"#;
+
+    let parsed = parse_module(source).require("parse");
+    let lowering = lower_module(&parsed.module);
+    let messages: Vec<_> = lowering
+        .diagnostics
+        .iter()
+        .map(|diag| diag.message.as_str())
+        .collect();
+    assert!(
+        messages
+            .iter()
+            .any(|message| message.contains("requires a value for field `Height`")),
+        "expected missing field diagnostic: {messages:?}"
+    );
+    assert!(
+        messages
+            .iter()
+            .any(|message| message.contains("has no field `Depth`")),
+        "expected unknown field diagnostic: {messages:?}"
+    );
+}
@@ -871,3 +871,82 @@ fn lowers_postfix_increment_operator_to_call() {
         "postfix increment should store result"
     );
 }
+
+#[test]
+fn compound_assignment_operator_overload_takes_precedence() {
+    let source = r#"
+namespace Geometry;
+
+public class Vec2
+{
+    public float X;
+
+    public static Vec2 operator +(Vec2 lhs, Vec2 rhs) { return lhs; }
+    public static Vec2 operator +=(Vec2 target, Vec2 delta) { return target; }
+}
+
+public class Body
+{
+    public Vec2 Position { get; set; }
+}
+
+public void Shift(Vec2 point, Vec2 delta)
+{
+    point += delta;
+}
+
+public void Move(Body body, Vec2 delta)
+{
+    body.Position += delta;
+}
+
+public Vec2 Sum(Vec2 a, Vec2 b)
+{
+    return a + b;
+}
+"#;
+    let parsed = parse_module(source).expect("parse compound operator module");
+    let lowering = lower_module(&parsed.module);
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+
+    let calls_in = |name: &str| -> Vec<String> {
+        let func = lowering
+            .module
+            .functions
+            .iter()
+            .find(|func| func.name == name)
+            .unwrap_or_else(|| panic!("missing {name}"));
+        func.body
+            .blocks
+            .iter()
+            .filter_map(|block| match &block.terminator {
+                Some(Terminator::Call {
+                    func: Operand::Pending(pending),
+                    ..
+                }) => Some(pending.repr.clone()),
+                _ => None,
+            })
+            .collect()
+    };
+
+    assert_eq!(
+        calls_in("Geometry::Shift"),
+        vec!["Geometry::Vec2::op_AdditionAssignment".to_string()]
+    );
+    let moved = calls_in("Geometry::Move");
+    assert_eq!(
+        moved.len(),
+        3,
+        "expected getter, operator and setter, got {moved:?}"
+    );
+    assert_eq!(moved[1], "Geometry::Vec2::op_AdditionAssignment");
+    assert_eq!(
+        calls_in("Geometry::Sum"),
+        vec!["Geometry::Vec2::op_Addition".to_string()],
+        "plain binary expressions keep using the binary overload"
+    );
+}
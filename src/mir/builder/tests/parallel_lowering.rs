@@ -0,0 +1,49 @@
+use super::common::RequireExt;
+use super::*;
+use crate::threading::configure_jobs;
+
+fn lowered_with_jobs(source: &str, jobs: usize) -> String {
+    let parsed = parse_module(source).require("parse module");
+    configure_jobs(Some(jobs));
+    let lowering = lower_module(&parsed.module);
+    configure_jobs(None);
+    let mut layouts = lowering
+        .module
+        .type_layouts
+        .types
+        .keys()
+        .cloned()
+        .collect::<Vec<_>>();
+    layouts.sort();
+    format!(
+        "{:#?}",
+        (
+            &lowering.module.functions,
+            &lowering.module.interned_strs,
+            &lowering.module.default_arguments,
+            &lowering.diagnostics,
+            layouts,
+        )
+    )
+}
+
+#[test]
+fn function_bodies_lower_identically_at_any_job_count() {
+    let mut source = String::from("namespace Jobs;\n\npublic int Id<T>(T value) { return 0; }\n");
+    for index in 0..12 {
+        source.push_str(&format!(
+            r#"
+public string Describe{index}(int value) {{
+    let pair = (value, {index});
+    let shared = "shared";
+    let scale = (int x, int factor = {index}) => x * factor;
+    Id<(int, bool)>((value, true));
+    return $"{{shared}}-{index}: {{pair.Item1,4:X}} {{scale(value)}}" + "own{index}";
+}}
+"#
+        ));
+    }
+    let serial = lowered_with_jobs(&source, 1);
+    assert!(serial.contains("own11"), "bodies should intern their literals");
+    assert_eq!(serial, lowered_with_jobs(&source, 3));
+}
@@ -0,0 +1,418 @@
+use super::common::{RequireExt, assert_no_pending};
+use super::*;
+
+fn lower_harness(source: &str) -> LoweringResult {
+    let parsed = parse_module(source).require("parse");
+    lower_module(&parsed.module)
+}
+
+fn function_body<'a>(lowering: &'a LoweringResult, suffix: &str) -> &'a MirBody {
+    &lowering
+        .module
+        .functions
+        .iter()
+        .find(|f| f.name.ends_with(suffix))
+        .require("missing function lowering")
+        .body
+}
+
+fn writes_field(place: &crate::mir::data::Place, field: &str) -> bool {
+    place
+        .projection
+        .iter()
+        .any(|proj| matches!(proj, ProjectionElem::FieldNamed(name) if name == field))
+}
+
+#[test]
+fn rectangular_new_records_each_extent_and_allocates_product() {
+    let lowering = lower_harness(
+        r#"
+namespace Demo;
+
+public static class Harness
+{
+    public static int[,] Make(int rows, int cols)
+    {
+        return new int[rows, cols];
+    }
+}
+"#,
+    );
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+    let body = function_body(&lowering, "::Harness::Make");
+    assert_no_pending(body);
+
+    let mut saw_alloc = false;
+    let mut saw_product = false;
+    let mut dims = Vec::new();
+    for block in &body.blocks {
+        if let Some(Terminator::Call {
+            func:
+                Operand::Const(ConstOperand {
+                    value: ConstValue::Symbol(sym),
+                    ..
+                }),
+            ..
+        }) = &block.terminator
+        {
+            saw_alloc |= sym == "chic_rt_vec_with_capacity";
+        }
+        for stmt in &block.statements {
+            if let StatementKind::Assign { place, value } = &stmt.kind {
+                saw_product |= matches!(value, Rvalue::Binary { op: BinOp::Mul, .. });
+                for field in ["dim0", "dim1", "dim2"] {
+                    if writes_field(place, field) {
+                        dims.push(field);
+                    }
+                }
+            }
+        }
+    }
+    assert!(
+        saw_alloc,
+        "rectangular array should allocate a single buffer"
+    );
+    assert!(saw_product, "total length should multiply the extents");
+    assert_eq!(dims, ["dim0", "dim1"]);
+
+    let layout = lowering
+        .module
+        .type_layouts
+        .layout_for_name("Array<int>[,]")
+        .require("rectangular array layout");
+    let TypeLayout::Struct(layout) = layout else {
+        panic!("expected struct layout for rectangular array");
+    };
+    let names = layout
+        .fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(&names[..2], ["ptr", "len"]);
+    assert_eq!(&names[names.len() - 2..], ["dim0", "dim1"]);
+}
+
+#[test]
+fn rectangular_initializer_stores_rows_in_row_major_order() {
+    let lowering = lower_harness(
+        r#"
+namespace Demo;
+
+public static class Harness
+{
+    public static int[,] Make()
+    {
+        return new int[,] { { 1, 2, 3 }, { 4, 5, 6 } };
+    }
+}
+"#,
+    );
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+    let body = function_body(&lowering, "::Harness::Make");
+
+    let mut stored = Vec::new();
+    for block in &body.blocks {
+        for stmt in &block.statements {
+            if let StatementKind::Assign { place, value } = &stmt.kind
+                && place
+                    .projection
+                    .iter()
+                    .any(|proj| matches!(proj, ProjectionElem::Index(_)))
+                && let Rvalue::Use(Operand::Const(constant)) = value
+            {
+                stored.push(constant.value.clone());
+            }
+        }
+    }
+    let stored = stored
+        .into_iter()
+        .map(|value| match value {
+            ConstValue::Int(v) | ConstValue::Int32(v) => v,
+            other => panic!("unexpected element constant {other:?}"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(stored, [1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn typed_rectangular_literal_lowers_like_initializer() {
+    let lowering = lower_harness(
+        r#"
+namespace Demo;
+
+public static class Harness
+{
+    public static int[,] Make()
+    {
+        return int[,] [[1, 2], [3, 4]];
+    }
+}
+"#,
+    );
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+    let body = function_body(&lowering, "::Harness::Make");
+    let element_stores = body
+        .blocks
+        .iter()
+        .flat_map(|block| &block.statements)
+        .filter(|stmt| {
+            matches!(&stmt.kind, StatementKind::Assign { place, .. }
+                if place.projection.iter().any(|proj| matches!(proj, ProjectionElem::Index(_))))
+        })
+        .count();
+    assert_eq!(element_stores, 4);
+}
+
+#[test]
+fn ragged_rectangular_initializer_is_rejected() {
+    let lowering = lower_harness(
+        r#"
+namespace Demo;
+
+public static class Harness
+{
+    public static int[,] Make()
+    {
+        return new int[,] { { 1, 2 }, { 3 } };
+    }
+}
+"#,
+    );
+    assert!(
+        lowering
+            .diagnostics
+            .iter()
+            .any(|diag| diag.message.contains("must nest rows of equal length")),
+        "expected ragged initializer diagnostic, found {:?}",
+        lowering.diagnostics
+    );
+}
+
+#[test]
+fn rectangular_index_flattens_and_checks_each_dimension() {
+    let lowering = lower_harness(
+        r#"
+namespace Demo;
+
+public static class Harness
+{
+    public static int Read(int[,] grid, int row, int col)
+    {
+        return grid[row, col];
+    }
+}
+"#,
+    );
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+    let body = function_body(&lowering, "::Harness::Read");
+    assert_no_pending(body);
+
+    let panics = body
+        .blocks
+        .iter()
+        .filter(|block| matches!(block.terminator, Some(Terminator::Panic)))
+        .count();
+    assert_eq!(panics, 2, "each dimension should be bounds-checked");
+
+    let mut dims_read = Vec::new();
+    let mut index_projections = Vec::new();
+    for block in &body.blocks {
+        for stmt in &block.statements {
+            if let StatementKind::Assign {
+                value: Rvalue::Use(Operand::Copy(place)),
+                ..
+            } = &stmt.kind
+            {
+                for field in ["dim0", "dim1"] {
+                    if writes_field(place, field) {
+                        dims_read.push(field);
+                    }
+                }
+                let indices = place
+                    .projection
+                    .iter()
+                    .filter(|proj| matches!(proj, ProjectionElem::Index(_)))
+                    .count();
+                if indices > 0 {
+                    index_projections.push(indices);
+                }
+            }
+        }
+    }
+    assert_eq!(dims_read, ["dim0", "dim1"]);
+    assert_eq!(
+        index_projections,
+        [1],
+        "element access should use a single flattened index"
+    );
+}
+
+#[test]
+fn rectangular_initializer_sets_length_before_element_stores() {
+    let lowering = lower_harness(
+        r#"
+namespace Demo;
+
+public static class Harness
+{
+    public static int[,] Make()
+    {
+        return new int[,] { { 1, 2 }, { 3, 4 } };
+    }
+}
+"#,
+    );
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+    let body = function_body(&lowering, "::Harness::Make");
+    let assigned: Vec<_> = body
+        .blocks
+        .iter()
+        .flat_map(|block| &block.statements)
+        .filter_map(|stmt| match &stmt.kind {
+            StatementKind::Assign { place, .. } => Some(place),
+            _ => None,
+        })
+        .collect();
+    let len_store = assigned
+        .iter()
+        .position(|place| writes_field(place, "len"))
+        .require("array length assignment missing");
+    let first_element = assigned
+        .iter()
+        .position(|place| {
+            place
+                .projection
+                .iter()
+                .any(|proj| matches!(proj, ProjectionElem::Index(_)))
+        })
+        .require("element stores missing");
+    assert!(
+        len_store < first_element,
+        "flattened element stores are bounds-checked, so `len` must be set first"
+    );
+}
+
+#[test]
+fn get_length_reads_requested_dimension() {
+    let lowering = lower_harness(
+        r#"
+namespace Demo;
+
+public static class Harness
+{
+    public static usize Columns(int[,] grid)
+    {
+        return grid.GetLength(1);
+    }
+}
+"#,
+    );
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+    let body = function_body(&lowering, "::Harness::Columns");
+    assert_no_pending(body);
+    let reads_dim1 = body.blocks.iter().flat_map(|block| &block.statements).any(|stmt| {
+        matches!(&stmt.kind, StatementKind::Assign { value: Rvalue::Use(Operand::Copy(place)), .. }
+            if writes_field(place, "dim1"))
+    });
+    assert!(reads_dim1, "GetLength(1) should read the second extent");
+    assert!(
+        body.blocks
+            .iter()
+            .all(|block| !matches!(block.terminator, Some(Terminator::Call { .. }))),
+        "GetLength should not lower to a call"
+    );
+}
+
+#[test]
+fn get_length_rejects_out_of_range_dimension() {
+    let lowering = lower_harness(
+        r#"
+namespace Demo;
+
+public static class Harness
+{
+    public static usize Depth(int[,] grid)
+    {
+        return grid.GetLength(2);
+    }
+}
+"#,
+    );
+    assert!(
+        lowering
+            .diagnostics
+            .iter()
+            .any(|diag| diag.message.contains("dimension 2 is out of range")),
+        "expected out-of-range dimension diagnostic, found {:?}",
+        lowering.diagnostics
+    );
+}
+
+#[test]
+fn foreach_over_rectangular_array_walks_flat_storage() {
+    let lowering = lower_harness(
+        r#"
+namespace Demo;
+
+public static class Harness
+{
+    public static int Sum(int[,] grid)
+    {
+        var total = 0;
+        foreach (var value in grid)
+        {
+            total += value;
+        }
+        return total;
+    }
+}
+"#,
+    );
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+    let body = function_body(&lowering, "::Harness::Sum");
+    let uses_len = body
+        .blocks
+        .iter()
+        .flat_map(|block| &block.statements)
+        .any(|stmt| {
+            matches!(
+                &stmt.kind,
+                StatementKind::Assign {
+                    value: Rvalue::Len(_),
+                    ..
+                }
+            )
+        });
+    assert!(
+        uses_len,
+        "foreach should iterate up to the total element count"
+    );
+}
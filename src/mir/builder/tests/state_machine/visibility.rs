@@ -283,7 +283,7 @@ fn static_lowering_error_paths_emit_diagnostics() {
             );
             assert!(
                 matches!(compound_result, Some(false)),
-                "compound assignments on static properties should fail"
+                "compound assignments on static properties without accessors should fail"
             );
 
             assert!(
@@ -320,8 +320,9 @@ fn static_lowering_error_paths_emit_diagnostics() {
             assert!(
                 messages
                     .iter()
-                    .any(|msg| msg.contains("compound assignment on property")),
-                "expected compound assignment diagnostic, got {messages:?}"
+                    .any(|msg| msg
+                        .contains("property `Demo::Config.Name` does not provide a setter")),
+                "expected missing setter diagnostic, got {messages:?}"
             );
             assert!(
                 messages
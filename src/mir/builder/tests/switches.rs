@@ -700,3 +700,65 @@ public int Consume((int X, int Y) pair)
         "expected move assignment for `move` binding"
     );
 }
+
+#[test]
+fn payload_bindings_are_assigned_and_typed_from_the_variant_field() {
+    let source = r"
+namespace N;
+
+public enum Shape
+{
+    Empty,
+    Circle { public int Radius; },
+}
+
+public int Describe(Shape shape)
+{
+    switch (shape)
+    {
+        case Shape.Empty:
+            return 1;
+        case Shape.Circle { Radius: var radius }:
+            return radius;
+        default:
+            return 0;
+    }
+}
+";
+
+    let parsed = parse_module(source).require("parse");
+    let lowering = lower_module(&parsed.module);
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+    let func = lowering
+        .module
+        .functions
+        .iter()
+        .find(|f| f.name == "N::Describe")
+        .require("missing Describe function");
+    let (index, radius) = func
+        .body
+        .locals
+        .iter()
+        .enumerate()
+        .find(|(_, decl)| decl.name.as_deref() == Some("radius"))
+        .require("missing `radius` binding");
+    assert_eq!(radius.ty.canonical_name(), "int");
+
+    let assigned = func
+        .body
+        .blocks
+        .iter()
+        .flat_map(|block| &block.statements)
+        .any(|stmt| {
+            matches!(
+                &stmt.kind,
+                StatementKind::Assign { place, .. }
+                    if place.local.0 == index && place.projection.is_empty()
+            )
+        });
+    assert!(assigned, "`radius` must be assigned from the payload");
+}
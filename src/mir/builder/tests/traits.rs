@@ -1,5 +1,9 @@
 use super::common::RequireExt;
 use super::*;
+use crate::frontend::ast::{
+    ExtensionMember, GenericConstraint, GenericConstraintKind, GenericParam, GenericParamKind,
+    GenericParams, ImplDecl, ImplMember, Module, TypeExpr,
+};
 
 #[test]
 fn lowers_trait_impl_and_builds_vtable() {
@@ -137,3 +141,444 @@ public class RunnerImpl : Worker
         "expected async mismatch diagnostic, got {messages:?}"
     );
 }
+
+/// Rewrites the module's single `extension` into an `impl` block, since `impl` is not surface
+/// syntax: `trait_ref`/`generics` select between inherent and blanket impls.
+fn extension_as_impl(
+    module: &mut Module,
+    trait_ref: Option<TypeExpr>,
+    target: TypeExpr,
+    generics: Option<GenericParams>,
+) {
+    extensions_as_impls(module, vec![(trait_ref, target, generics)]);
+}
+
+/// Rewrites the module's `extension` blocks, in declaration order, into the given impls.
+fn extensions_as_impls(
+    module: &mut Module,
+    impls: Vec<(Option<TypeExpr>, TypeExpr, Option<GenericParams>)>,
+) {
+    for (trait_ref, target, generics) in impls {
+        let index = module
+            .items
+            .iter()
+            .position(|item| matches!(item, Item::Extension(_)))
+            .expect("module should declare an extension per impl");
+        let Item::Extension(ext) = module.items.remove(index) else {
+            unreachable!();
+        };
+        let members = ext
+            .members
+            .into_iter()
+            .map(|ExtensionMember::Method(method)| ImplMember::Method(method.function))
+            .collect();
+        module.items.push(Item::Impl(ImplDecl {
+            visibility: ext.visibility,
+            trait_ref,
+            target,
+            generics,
+            members,
+            doc: None,
+            attributes: Vec::new(),
+            span: None,
+        }));
+    }
+}
+
+/// `<T>` bounded by `bound`, as targeted by a blanket impl.
+fn bounded_param(bound: &str) -> Option<GenericParams> {
+    constrained_param(GenericConstraintKind::Type(TypeExpr::simple(bound)))
+}
+
+/// `<T>` carrying the single `constraint`, as targeted by a blanket impl.
+fn constrained_param(constraint: GenericConstraintKind) -> Option<GenericParams> {
+    let mut param = GenericParam::type_param("T", None);
+    if let GenericParamKind::Type(data) = &mut param.kind {
+        data.constraints
+            .push(GenericConstraint::new(constraint, None));
+    }
+    Some(GenericParams::new(None, vec![param]))
+}
+
+#[test]
+fn lowers_inherent_impl_like_extension_members() {
+    let source = r#"
+namespace Demo;
+
+public struct Widget { public int Size; }
+
+public extension Widget
+{
+    public int Area(in this) { return this.Size * this.Size; }
+}
+"#;
+
+    let mut parsed = parse_module(source).require("parse inherent impl module");
+    extension_as_impl(&mut parsed.module, None, TypeExpr::simple("Widget"), None);
+    let lowering = lower_module(&parsed.module);
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+    assert!(
+        lowering
+            .module
+            .functions
+            .iter()
+            .any(|func| func.name == "Demo::Widget::Area"),
+        "inherent impl method should lower as `Demo::Widget::Area`"
+    );
+    assert!(lowering.module.trait_vtables.is_empty());
+}
+
+#[test]
+fn instantiates_blanket_impl_for_types_meeting_bounds() {
+    let source = r#"
+namespace Demo;
+
+public interface Describe
+{
+    string Describe();
+}
+
+public interface Display
+{
+    string Show();
+}
+
+public class Widget : Describe
+{
+    public string Describe() { return "widget"; }
+}
+
+public class Gadget { }
+
+public extension Widget
+{
+    public string Show(in this) { return "shown"; }
+}
+"#;
+
+    let mut parsed = parse_module(source).require("parse blanket impl module");
+    extension_as_impl(
+        &mut parsed.module,
+        Some(TypeExpr::simple("Display")),
+        TypeExpr::simple("T"),
+        bounded_param("Describe"),
+    );
+    let lowering = lower_module(&parsed.module);
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+
+    let display_impls: Vec<_> = lowering
+        .module
+        .trait_vtables
+        .iter()
+        .filter(|vtable| vtable.trait_name == "Demo::Display")
+        .collect();
+    assert_eq!(
+        display_impls.len(),
+        1,
+        "only `Widget` satisfies `T: Describe`"
+    );
+    let vtable = display_impls[0];
+    assert_eq!(vtable.impl_type, "Demo::Widget");
+    assert_eq!(vtable.slots.len(), 1);
+    assert_eq!(vtable.slots[0].symbol, "Demo::Widget::Display::Show");
+    assert!(
+        lowering
+            .module
+            .functions
+            .iter()
+            .any(|func| func.name == "Demo::Widget::Display::Show")
+    );
+}
+
+#[test]
+fn instantiates_chained_blanket_impls_for_enums_and_primitives() {
+    let source = r#"
+namespace Demo;
+
+public interface Describe
+{
+    string Describe();
+}
+
+public interface Display
+{
+    string Show();
+}
+
+public interface Render
+{
+    string Render();
+}
+
+public enum Color { Red, Green }
+
+public extension Color
+{
+    public string Describe(in this) { return "color"; }
+}
+
+public extension int
+{
+    public string Describe(in this) { return "int"; }
+}
+
+public extension Color
+{
+    public string Show(in this) { return "shown"; }
+}
+
+public extension Color
+{
+    public string Render(in this) { return "rendered"; }
+}
+"#;
+
+    let mut parsed = parse_module(source).require("parse chained blanket impl module");
+    extensions_as_impls(
+        &mut parsed.module,
+        vec![
+            (
+                Some(TypeExpr::simple("Describe")),
+                TypeExpr::simple("Color"),
+                None,
+            ),
+            (
+                Some(TypeExpr::simple("Describe")),
+                TypeExpr::simple("int"),
+                None,
+            ),
+            (
+                Some(TypeExpr::simple("Display")),
+                TypeExpr::simple("T"),
+                bounded_param("Describe"),
+            ),
+            (
+                Some(TypeExpr::simple("Render")),
+                TypeExpr::simple("T"),
+                bounded_param("Display"),
+            ),
+        ],
+    );
+    let lowering = lower_module(&parsed.module);
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+
+    let impls_of = |trait_name: &str| {
+        let mut types = lowering
+            .module
+            .trait_vtables
+            .iter()
+            .filter(|vtable| vtable.trait_name == trait_name)
+            .map(|vtable| vtable.impl_type.clone())
+            .collect::<Vec<_>>();
+        types.sort();
+        types
+    };
+    assert_eq!(
+        impls_of("Demo::Display"),
+        vec!["Demo::Color".to_string(), "int".to_string()],
+        "enums and primitives meeting `T: Describe` get the blanket impl"
+    );
+    assert_eq!(
+        impls_of("Demo::Render"),
+        vec!["Demo::Color".to_string(), "int".to_string()],
+        "types covered by the `Display` blanket impl satisfy `T: Display`"
+    );
+}
+
+#[test]
+fn rejects_generic_impls_over_constructed_types() {
+    let source = r#"
+namespace Demo;
+
+public interface Display
+{
+    string Show();
+}
+
+public struct Wrapper<T> { public T Value; }
+
+public extension Wrapper<T>
+{
+    public string Show(in this) { return "wrapped"; }
+}
+"#;
+
+    let mut parsed = parse_module(source).require("parse generic impl module");
+    extension_as_impl(
+        &mut parsed.module,
+        Some(TypeExpr::simple("Display")),
+        crate::frontend::parser::parse_type_expression_text("Wrapper<T>").expect("target type"),
+        Some(GenericParams::new(
+            None,
+            vec![GenericParam::type_param("T", None)],
+        )),
+    );
+    let lowering = lower_module(&parsed.module);
+    assert!(
+        lowering.diagnostics.iter().any(|diag| diag
+            .message
+            .contains("generic impl for `Wrapper<T>` cannot be lowered")),
+        "expected generic impl diagnostic, got {:?}",
+        lowering.diagnostics
+    );
+    assert!(
+        lowering
+            .module
+            .trait_vtables
+            .iter()
+            .all(|vtable| vtable.trait_name != "Demo::Display"),
+        "generic impls must not produce vtables"
+    );
+}
+
+#[test]
+fn blanket_impls_check_kind_constraints_against_candidates() {
+    let source = r#"
+namespace Demo;
+
+public interface Display
+{
+    string Show();
+}
+
+public interface Render
+{
+    string Render();
+}
+
+public class Widget { }
+
+public struct Point { public int X; }
+
+public extension Point
+{
+    public string Show(in this) { return "shown"; }
+}
+
+public extension Widget
+{
+    public string Render(in this) { return "rendered"; }
+}
+"#;
+
+    let mut parsed = parse_module(source).require("parse constrained blanket impl module");
+    extensions_as_impls(
+        &mut parsed.module,
+        vec![
+            (
+                Some(TypeExpr::simple("Display")),
+                TypeExpr::simple("T"),
+                constrained_param(GenericConstraintKind::Struct),
+            ),
+            (
+                Some(TypeExpr::simple("Render")),
+                TypeExpr::simple("T"),
+                constrained_param(GenericConstraintKind::Class),
+            ),
+        ],
+    );
+    let lowering = lower_module(&parsed.module);
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+
+    let implements = |trait_name: &str, type_name: &str| {
+        lowering
+            .module
+            .trait_vtables
+            .iter()
+            .any(|vtable| vtable.trait_name == trait_name && vtable.impl_type == type_name)
+    };
+    assert!(implements("Demo::Display", "Demo::Point"));
+    assert!(implements("Demo::Display", "int"));
+    assert!(
+        !implements("Demo::Display", "Demo::Widget"),
+        "classes do not satisfy `T: struct`"
+    );
+    assert!(implements("Demo::Render", "Demo::Widget"));
+    assert!(
+        !implements("Demo::Render", "Demo::Point") && !implements("Demo::Render", "int"),
+        "value types do not satisfy `T: class`"
+    );
+}
+
+#[test]
+fn blanket_bounds_match_interfaces_by_qualified_name() {
+    let source = r#"
+namespace Demo;
+
+public interface Describe
+{
+    string Describe();
+}
+
+public interface Display
+{
+    string Show();
+}
+
+namespace Other
+{
+    public interface Describe
+    {
+        string Describe();
+    }
+}
+
+public class Widget : Describe
+{
+    public string Describe() { return "widget"; }
+}
+
+public class Gadget : Other.Describe
+{
+    public string Describe() { return "gadget"; }
+}
+
+public extension Widget
+{
+    public string Show(in this) { return "shown"; }
+}
+"#;
+
+    let mut parsed = parse_module(source).require("parse qualified bound module");
+    extension_as_impl(
+        &mut parsed.module,
+        Some(TypeExpr::simple("Display")),
+        TypeExpr::simple("T"),
+        bounded_param("Describe"),
+    );
+    let lowering = lower_module(&parsed.module);
+    assert!(
+        lowering.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        lowering.diagnostics
+    );
+
+    let display_impls: Vec<_> = lowering
+        .module
+        .trait_vtables
+        .iter()
+        .filter(|vtable| vtable.trait_name == "Demo::Display")
+        .map(|vtable| vtable.impl_type.as_str())
+        .collect();
+    assert_eq!(
+        display_impls,
+        vec!["Demo::Widget"],
+        "`Other::Describe` is a different interface from the `Demo::Describe` bound"
+    );
+}
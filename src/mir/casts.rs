@@ -1,9 +1,10 @@
+use serde::Serialize;
 use std::cmp::Ordering;
 
 use crate::primitives::{PrimitiveKind, PrimitiveRegistry};
 
 /// Metadata describing a builtin integer type.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
 pub struct IntInfo {
     pub bits: u16,
     pub signed: bool,
@@ -22,6 +23,9 @@ pub enum ScalarKind {
     Float(FloatInfo),
 }
 
+/// Exception thrown when a checked reference cast fails its runtime type test.
+pub const INVALID_CAST_EXCEPTION: &str = "Std::InvalidCastException";
+
 /// Returns the short type name without namespace qualifiers or nullable suffix.
 #[must_use]
 pub fn short_type_name(name: &str) -> &str {
@@ -1,3 +1,4 @@
+use serde::Serialize;
 use std::fmt;
 
 use crate::decimal::Decimal128;
@@ -15,7 +16,7 @@ use super::utils::{new_mir_body, new_place};
 /// Mid-level representation of a function body.
 /// Body/basic block structures live alongside their string forms here to keep the MIR
 /// text definitions consistent while the split modules are stabilised.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MirBody {
     pub arg_count: usize,
     pub locals: Vec<LocalDecl>,
@@ -53,7 +54,7 @@ impl MirBody {
 }
 
 /// Metadata recorded for each accelerator stream present in a MIR body.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StreamMetadata {
     pub local: LocalId,
     pub mem_space: Option<Ty>,
@@ -61,14 +62,14 @@ pub struct StreamMetadata {
 }
 
 /// Debug-only notes attached to a MIR body.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct DebugNote {
     pub message: String,
     pub span: Option<Span>,
 }
 
 /// Declaration for a local slot in MIR.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct LocalDecl {
     pub name: Option<String>,
     pub ty: Ty,
@@ -118,7 +119,7 @@ impl LocalDecl {
 }
 
 /// The role played by a local slot.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum LocalKind {
     Return,
     Arg(usize),
@@ -127,7 +128,7 @@ pub enum LocalKind {
 }
 
 /// Parameter binding semantics for a function argument.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum ParamMode {
     Value,
     In,
@@ -135,7 +136,7 @@ pub enum ParamMode {
     Out,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash, Serialize)]
 pub struct AliasContract {
     pub noalias: bool,
     pub nocapture: bool,
@@ -147,7 +148,7 @@ pub struct AliasContract {
 }
 
 /// Identifier for a MIR local.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub struct LocalId(pub usize);
 
 impl fmt::Display for LocalId {
@@ -157,7 +158,7 @@ impl fmt::Display for LocalId {
 }
 
 /// Identifier for a MIR basic block.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub struct BlockId(pub usize);
 
 impl fmt::Display for BlockId {
@@ -167,15 +168,15 @@ impl fmt::Display for BlockId {
 }
 
 /// Identifier for a borrow.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub struct BorrowId(pub usize);
 
 /// Identifier for a region variable.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub struct RegionVar(pub usize);
 
 /// A MIR basic block: linear list of statements ending in a terminator.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BasicBlock {
     pub id: BlockId,
     pub statements: Vec<Statement>,
@@ -198,14 +199,14 @@ impl BasicBlock {
 /// Statement inside a MIR basic block.
 /// Statements and terminators are defined here with the block model to keep the stringified
 /// MIR definitions coherent during the ongoing module split.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Statement {
     pub span: Option<Span>,
     pub kind: StatementKind,
 }
 
 /// Ownership-aware categories for MIR operands.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum ValueCategory {
     Move,
     Copy,
@@ -214,7 +215,7 @@ pub enum ValueCategory {
 }
 
 /// Statement variants recognised by MIR.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum StatementKind {
     Assign {
         place: Place,
@@ -308,7 +309,7 @@ pub enum StatementKind {
 }
 
 /// Inline assembly representation in MIR.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct InlineAsm {
     pub template: Vec<InlineAsmTemplatePiece>,
     pub operands: Vec<InlineAsmOperand>,
@@ -318,7 +319,7 @@ pub struct InlineAsm {
 }
 
 /// Template fragment emitted for inline assembly.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum InlineAsmTemplatePiece {
     Literal(String),
     Placeholder {
@@ -329,14 +330,14 @@ pub enum InlineAsmTemplatePiece {
 }
 
 /// Register selector supported by inline assembly.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 pub enum InlineAsmRegister {
     Class(InlineAsmRegisterClass),
     Explicit(String),
 }
 
 /// Register classes recognised by inline assembly.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum InlineAsmRegisterClass {
     Reg,
     Reg8,
@@ -351,7 +352,7 @@ pub enum InlineAsmRegisterClass {
 }
 
 /// Operand supplied to an inline assembly expression.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct InlineAsmOperand {
     pub name: Option<String>,
     pub reg: InlineAsmRegister,
@@ -360,7 +361,7 @@ pub struct InlineAsmOperand {
 }
 
 /// Operand categories for inline assembly.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum InlineAsmOperandKind {
     In {
         value: Operand,
@@ -383,7 +384,7 @@ pub enum InlineAsmOperandKind {
 }
 
 /// Copy directions recognised by accelerator copy statements.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum AcceleratorCopyKind {
     HostToDevice,
     DeviceToHost,
@@ -392,7 +393,7 @@ pub enum AcceleratorCopyKind {
 }
 
 /// Inline assembly options (mirrors Rust `asm!` surface).
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct InlineAsmOptions {
     pub volatile: bool,
     pub alignstack: bool,
@@ -407,7 +408,7 @@ pub struct InlineAsmOptions {
 
 /// Terminators transfer control at the end of a block.
 /// Kept adjacent to `BasicBlock`/`Statement` for the string-backed MIR definitions.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum Terminator {
     Goto {
         target: BlockId,
@@ -453,7 +454,7 @@ pub enum Terminator {
 }
 
 /// Metadata required to lower a `dyn Trait` call through a vtable slot.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TraitObjectDispatch {
     pub trait_name: String,
     pub method: String,
@@ -464,14 +465,14 @@ pub struct TraitObjectDispatch {
 }
 
 /// Dispatch metadata attached to indirect calls.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum CallDispatch {
     Trait(TraitObjectDispatch),
     Virtual(VirtualDispatch),
 }
 
 /// Metadata for class virtual calls.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct VirtualDispatch {
     pub slot_index: u32,
     pub receiver_index: usize,
@@ -479,7 +480,7 @@ pub struct VirtualDispatch {
 }
 
 /// Borrow classification.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum BorrowKind {
     Shared,
     Unique,
@@ -487,7 +488,7 @@ pub enum BorrowKind {
 }
 
 /// An lvalue/Place in MIR.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Place {
     pub local: LocalId,
     pub projection: Vec<ProjectionElem>,
@@ -501,7 +502,7 @@ impl Place {
 }
 
 /// Projection applied when traversing an aggregate.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum ProjectionElem {
     Field(u32),
     FieldNamed(String),
@@ -526,7 +527,7 @@ pub enum ProjectionElem {
 }
 
 /// Operand consumed by statements/terminators.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum Operand {
     Copy(Place),
     Move(Place),
@@ -536,7 +537,7 @@ pub enum Operand {
     Pending(PendingOperand),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ConstOperand {
     pub value: ConstValue,
     pub literal: Option<crate::syntax::numeric::NumericLiteralMetadata>,
@@ -580,7 +581,7 @@ impl ConstOperand {
 }
 
 /// Borrow operand.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BorrowOperand {
     pub kind: BorrowKind,
     pub place: Place,
@@ -588,7 +589,7 @@ pub struct BorrowOperand {
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MmioOperand {
     pub base_address: u64,
     pub offset: u32,
@@ -602,7 +603,7 @@ pub struct MmioOperand {
 }
 
 /// Supported floating widths.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum FloatWidth {
     F16,
     F32,
@@ -611,7 +612,7 @@ pub enum FloatWidth {
 }
 
 /// Supported integer widths for numeric intrinsics.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum NumericWidth {
     W8,
     W16,
@@ -622,7 +623,7 @@ pub enum NumericWidth {
 }
 
 /// Intrinsic operations on scalar integers.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum NumericIntrinsicKind {
     TryAdd,
     TrySub,
@@ -638,7 +639,7 @@ pub enum NumericIntrinsicKind {
 }
 
 /// Rvalue representation for numeric intrinsics (checked arithmetic and bit ops).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct NumericIntrinsic {
     pub kind: NumericIntrinsicKind,
     pub width: NumericWidth,
@@ -661,7 +662,7 @@ impl FloatWidth {
 }
 
 /// IEEE 754 rounding modes.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum RoundingMode {
     NearestTiesToEven,
     NearestTiesToAway,
@@ -671,7 +672,7 @@ pub enum RoundingMode {
 }
 
 /// IEEE 754 status flags.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
 pub struct FloatStatusFlags {
     pub invalid: bool,
     pub div_by_zero: bool,
@@ -700,7 +701,7 @@ impl FloatStatusFlags {
 }
 
 /// Floating-point literal/constant with exact bit representation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub struct FloatValue {
     pub bits: u128,
     pub width: FloatWidth,
@@ -939,7 +940,7 @@ fn binary128_bits_to_f64(bits: u128) -> f64 {
 }
 
 /// Constant values recognised by MIR.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum ConstValue {
     Int(i128),
     Int32(i128),
@@ -969,7 +970,7 @@ pub enum ConstValue {
 }
 
 /// Rvalue representing the right-hand side of an assignment.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum Rvalue {
     Use(Operand),
     Unary {
@@ -1037,7 +1038,7 @@ pub enum Rvalue {
 }
 
 /// Unary operators supported by MIR.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum UnOp {
     Neg,
     Not,
@@ -1050,7 +1051,7 @@ pub enum UnOp {
     AddrOfMut,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct DecimalIntrinsic {
     pub kind: DecimalIntrinsicKind,
     pub lhs: Operand,
@@ -1060,7 +1061,7 @@ pub struct DecimalIntrinsic {
     pub vectorize: Operand,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum DecimalIntrinsicKind {
     Add,
     Sub,
@@ -1081,7 +1082,7 @@ impl DecimalIntrinsicKind {
 }
 
 /// Binary operators supported by MIR.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum BinOp {
     Add,
     Sub,
@@ -1105,7 +1106,7 @@ pub enum BinOp {
 }
 
 /// Aggregate construction.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum AggregateKind {
     Tuple,
     Array,
@@ -1116,7 +1117,7 @@ pub enum AggregateKind {
 }
 
 /// Casting semantics.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum CastKind {
     IntToInt,
     IntToFloat,
@@ -1129,14 +1130,14 @@ pub enum CastKind {
 }
 
 /// Mutability marker used by `AddressOf` operations.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum Mutability {
     Immutable,
     Mutable,
 }
 
 /// Match arm used by structured pattern matching.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MatchArm {
     pub pattern: Pattern,
     pub guard: Option<MatchGuard>,
@@ -1145,7 +1146,7 @@ pub struct MatchArm {
 }
 
 /// Guard expression attached to a match arm.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MatchGuard {
     pub expr: String,
     pub span: Option<Span>,
@@ -1153,7 +1154,7 @@ pub struct MatchGuard {
 }
 
 /// Structured pattern used during `match` lowering.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum Pattern {
     Wildcard,
     Literal(ConstValue),
@@ -1172,7 +1173,7 @@ pub enum Pattern {
 }
 
 /// Binding captured by a pattern (mode + mutability).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BindingPattern {
     pub name: String,
     pub mutability: PatternBindingMutability,
@@ -1180,7 +1181,7 @@ pub struct BindingPattern {
 }
 
 /// Binding mode requested by the pattern author.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum PatternBindingMode {
     Value,
     In,
@@ -1190,14 +1191,14 @@ pub enum PatternBindingMode {
 }
 
 /// Whether the binding is mutable (`var`) or immutable (`let`).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum PatternBindingMutability {
     Immutable,
     Mutable,
 }
 
 /// Binding information extracted from a pattern.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PatternBinding {
     pub name: String,
     pub local: LocalId,
@@ -1208,7 +1209,7 @@ pub struct PatternBinding {
 }
 
 /// One segment of a pattern binding projection.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum PatternProjectionElem {
     Variant { path: Vec<String>, variant: String },
     FieldNamed(String),
@@ -1218,14 +1219,14 @@ pub enum PatternProjectionElem {
 }
 
 /// Field entry for struct/enum patterns.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PatternField {
     pub name: String,
     pub pattern: Pattern,
 }
 
 /// Enum variant payload description.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum VariantPatternFields {
     Unit,
     Tuple(Vec<Pattern>),
@@ -1233,14 +1234,14 @@ pub enum VariantPatternFields {
 }
 
 /// Structured record of statements not yet fully lowered.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PendingStatement {
     pub kind: PendingStatementKind,
     pub detail: Option<String>,
 }
 
 /// Classifies why a statement is pending.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum PendingStatementKind {
     Expression,
     Const,
@@ -1269,14 +1270,14 @@ pub enum PendingStatementKind {
 }
 
 /// Terminators awaiting a richer lowering.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PendingTerminator {
     pub kind: PendingTerminatorKind,
     pub detail: Option<String>,
 }
 
 /// Classifies pending terminators.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum PendingTerminatorKind {
     Branch,
     Loop,
@@ -1287,7 +1288,7 @@ pub enum PendingTerminatorKind {
 }
 
 /// Placeholder operand recorded when we cannot yet produce MIR-level semantics.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PendingOperand {
     pub category: ValueCategory,
     pub repr: String,
@@ -1295,7 +1296,7 @@ pub struct PendingOperand {
     pub info: Option<Box<PendingOperandInfo>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum PendingOperandInfo {
     FunctionGroup {
         path: String,
@@ -1304,7 +1305,7 @@ pub enum PendingOperandInfo {
     },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PendingFunctionCandidate {
     pub qualified: String,
     pub signature: FnTy,
@@ -1312,13 +1313,13 @@ pub struct PendingFunctionCandidate {
 }
 
 /// Placeholder Rvalue recorded when expression lowering is deferred.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PendingRvalue {
     pub repr: String,
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum AtomicOrdering {
     Relaxed,
     Acquire,
@@ -1352,14 +1353,14 @@ impl AtomicOrdering {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum AtomicFenceScope {
     Full,
     BlockEnter,
     BlockExit,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum AtomicRmwOp {
     Exchange,
     Add,
@@ -1372,7 +1373,7 @@ pub enum AtomicRmwOp {
 }
 
 /// Segment composing a runtime string interpolation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum InterpolatedStringSegment {
     Text {
         id: StrId,
@@ -3,9 +3,10 @@ use super::types::{Abi, Ty};
 use crate::frontend::ast::{ExternBinding, ExternOptions};
 use crate::frontend::attributes::OptimizationHints;
 use crate::frontend::diagnostics::Span;
+use serde::Serialize;
 
 /// MIR representation for an individual function or testcase.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MirFunction {
     pub name: String,
     pub kind: FunctionKind,
@@ -30,7 +31,7 @@ impl MirFunction {
 }
 
 /// Identifies the flavour of function represented by the MIR body.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum FunctionKind {
     Function,
     Testcase,
@@ -39,7 +40,7 @@ pub enum FunctionKind {
 }
 
 /// Function signature lowered alongside MIR.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FnSig {
     pub params: Vec<Ty>,
     pub ret: Ty,
@@ -51,7 +52,7 @@ pub struct FnSig {
 }
 
 /// Metadata describing how an extern function should be resolved/emitted.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MirExternSpec {
     pub convention: String,
     pub library: Option<String>,
@@ -1,7 +1,8 @@
 use crate::frontend::diagnostics::Span;
+use serde::Serialize;
 
 /// Identifier for an interned string used throughout MIR.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub struct StrId(u32);
 
 impl StrId {
@@ -17,13 +18,13 @@ impl StrId {
 }
 
 /// Lifetimes associated with interned strings.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum StrLifetime {
     Static,
 }
 
 /// Interned string record stored alongside MIR modules.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct InternedStr {
     pub id: StrId,
     pub value: String,
@@ -3,6 +3,7 @@ use crate::frontend::diagnostics::Span;
 use crate::mir::layout::TypeLayoutTable;
 use crate::primitives::PrimitiveRegistry;
 use crate::type_metadata::TypeVariance;
+use serde::Serialize;
 use std::collections::HashMap;
 
 use super::basic_blocks::ConstValue;
@@ -54,11 +55,11 @@ impl Default for MirModule {
 }
 
 /// Identifier for a module-level static variable.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub struct StaticId(pub usize);
 
 /// Chic static storage definition emitted alongside MIR.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StaticVar {
     pub id: StaticId,
     pub qualified: String,
@@ -78,7 +79,7 @@ pub struct StaticVar {
     pub initializer: Option<ConstValue>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct InterfaceDefaultImpl {
     pub implementer: String,
     pub interface: String,
@@ -86,7 +87,7 @@ pub struct InterfaceDefaultImpl {
     pub symbol: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct DefaultArgumentRecord {
     pub function: String,
     pub internal: String,
@@ -96,7 +97,7 @@ pub struct DefaultArgumentRecord {
     pub value: DefaultArgumentKind,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum DefaultArgumentKind {
     Const(ConstValue),
     Thunk {
@@ -1,8 +1,9 @@
 use crate::di::DiManifest;
 use crate::frontend::diagnostics::Span;
+use serde::Serialize;
 
 /// Explicit export declared via `@export`.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Export {
     pub function: String,
     pub symbol: String,
@@ -10,7 +11,7 @@ pub struct Export {
 }
 
 /// Selected global allocator for the module.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct GlobalAllocator {
     pub type_name: String,
     pub target: Option<String>,
@@ -18,13 +19,13 @@ pub struct GlobalAllocator {
 }
 
 /// Native link dependency declared via `@link("<name>")`.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct LinkLibrary {
     pub name: String,
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum StdProfile {
     Std,
     NoStd,
@@ -37,7 +38,7 @@ impl StdProfile {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum StdProfileSource {
     Default,
     CrateAttribute,
@@ -45,7 +46,7 @@ pub enum StdProfileSource {
 }
 
 /// Module-level behavioural attributes.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ModuleAttributes {
     pub std_profile: StdProfile,
     pub std_profile_span: Option<Span>,
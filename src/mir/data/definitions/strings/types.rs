@@ -1,3 +1,4 @@
+use serde::Serialize;
 use std::borrow::Borrow;
 use std::fmt;
 
@@ -7,14 +8,14 @@ use crate::frontend::ast::TypeExpr;
 use super::utils::{canonical_fn_name, canonical_tuple_name, canonical_ty_name, ty_from_type_expr};
 
 /// Calling convention/ABI for a function.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum Abi {
     Chic,
     Extern(String),
 }
 
 /// Simplified type information used during MIR construction.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum Ty {
     Named(NamedTy),
     Array(ArrayTy),
@@ -36,69 +37,69 @@ pub enum Ty {
     TraitObject(TraitObjectTy),
 }
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, Serialize)]
 pub struct NamedTy {
     pub name: String,
     pub args: Vec<GenericArg>,
 }
 
 /// Generic argument supplied to a named type.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum GenericArg {
     Type(Ty),
     Const(ConstGenericArg),
 }
 
 /// Normalised representation of a const generic argument.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ConstGenericArg {
     value: String,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ArrayTy {
     pub element: Box<Ty>,
     pub rank: usize,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct VecTy {
     pub element: Box<Ty>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct SpanTy {
     pub element: Box<Ty>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ReadOnlySpanTy {
     pub element: Box<Ty>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct RcTy {
     pub element: Box<Ty>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ArcTy {
     pub element: Box<Ty>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct VectorTy {
     pub element: Box<Ty>,
     pub lanes: u32,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct TupleTy {
     pub elements: Vec<Ty>,
     pub element_names: Vec<Option<String>>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct FnTy {
     pub params: Vec<Ty>,
     pub param_modes: Vec<ParamMode>,
@@ -107,13 +108,13 @@ pub struct FnTy {
     pub variadic: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct TraitObjectTy {
     pub traits: Vec<String>,
     pub opaque_impl: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct RefTy {
     pub element: Ty,
     pub readonly: bool,
@@ -501,14 +502,14 @@ impl TraitObjectTy {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct PointerTy {
     pub element: Ty,
     pub mutable: bool,
     pub qualifiers: PointerQualifiers,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
 pub struct PointerQualifiers {
     pub restrict: bool,
     pub noalias: bool,
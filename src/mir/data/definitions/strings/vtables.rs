@@ -1,9 +1,10 @@
 //! Trait and class vtable metadata emitted alongside MIR for dynamic dispatch.
 
 use crate::frontend::ast::PropertyAccessorKind;
+use serde::Serialize;
 
 /// Describes a single vtable generated for a `(Trait, ImplType)` pairing.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TraitVTable {
     pub symbol: String,
     pub trait_name: String,
@@ -12,14 +13,14 @@ pub struct TraitVTable {
 }
 
 /// Entry for an object-safe method exposed through a trait vtable.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct VTableSlot {
     pub method: String,
     pub symbol: String,
 }
 
 /// Describes the virtual dispatch table for a concrete class type.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ClassVTable {
     pub type_name: String,
     pub symbol: String,
@@ -28,7 +29,7 @@ pub struct ClassVTable {
 }
 
 /// Entry for a class vtable slot (method or accessor).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ClassVTableSlot {
     pub slot_index: u32,
     pub member: String,
@@ -1,3 +1,4 @@
+use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 
 use crate::frontend::ast::TypeExpr;
@@ -16,7 +17,7 @@ struct TraitStatusContext<'a> {
 }
 
 /// Auto trait fulfilment recorded for a Chic type.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum AutoTraitStatus {
     Yes,
     No,
@@ -46,7 +47,7 @@ impl AutoTraitStatus {
 }
 
 /// Triple of auto trait statuses tracked per type.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub struct AutoTraitSet {
     pub thread_safe: AutoTraitStatus,
     pub shareable: AutoTraitStatus,
@@ -96,7 +97,7 @@ impl AutoTraitSet {
 }
 
 /// Optional overrides supplied via attributes.
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, Serialize)]
 pub struct AutoTraitOverride {
     pub thread_safe: Option<bool>,
     pub shareable: Option<bool>,
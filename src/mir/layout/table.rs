@@ -1,5 +1,6 @@
 //! Type layout metadata and auto-trait computation for MIR.
 
+use serde::Serialize;
 use std::cell::{Cell, RefCell};
 use std::collections::{HashMap, HashSet};
 use std::sync::{OnceLock, RwLock};
@@ -15,7 +16,7 @@ use crate::primitives::PrimitiveRegistry;
 
 pub(crate) const MIN_ALIGN: usize = 1;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Serialize)]
 struct PointerInfo {
     size: usize,
     align: usize,
@@ -73,7 +74,7 @@ pub(crate) fn align_to(value: usize, align: usize) -> usize {
 }
 
 /// Registry with layout/type metadata referenced by MIR backends.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TypeLayoutTable {
     pub types: HashMap<String, TypeLayout>,
     type_flags: HashMap<String, TypeFlags>,
@@ -157,6 +158,22 @@ impl TypeLayoutTable {
         TypeFlags::empty()
     }
 
+    /// Adopt layouts that `fork` (a clone of this table used to lower bodies on another
+    /// thread) synthesised and this table lacks. Layouts are keyed by canonical name, so
+    /// entries present in both describe the same type.
+    pub(crate) fn absorb(&mut self, fork: TypeLayoutTable) {
+        fn merge<V>(into: &mut HashMap<String, V>, from: HashMap<String, V>) {
+            for (name, value) in from {
+                into.entry(name).or_insert(value);
+            }
+        }
+        merge(&mut self.types, fork.types);
+        merge(&mut self.type_flags, fork.type_flags);
+        merge(&mut self.delegate_signatures, fork.delegate_signatures);
+        merge(&mut self.delegate_auto_traits, fork.delegate_auto_traits);
+        merge(&mut self.type_generic_params, fork.type_generic_params);
+    }
+
     pub fn record_delegate_signature(&mut self, name: impl Into<String>, signature: FnTy) {
         let key = name.into();
         self.ensure_fn_layout(&signature);
@@ -350,6 +367,21 @@ impl TypeLayoutTable {
         ));
         offset += 64;
 
+        // Rectangular arrays (`T[,]`) keep the `VecPtr` prefix above so runtime helpers see a
+        // flat buffer of `len` elements, then append one extent per dimension.
+        if array.rank > 1 {
+            for (dimension, index) in (0..array.rank).zip(10u32..) {
+                offset = align_to(offset, word_align);
+                fields.push(make_field(
+                    &format!("dim{dimension}"),
+                    Ty::named("usize"),
+                    index,
+                    offset,
+                ));
+                offset += word_size;
+            }
+        }
+
         let align = word_align.max(1);
         let size = align_to(offset, align);
 
@@ -1357,7 +1389,7 @@ fn generate_task_layout(layouts: &TypeLayoutTable, inner_ty: &Ty) -> Option<Stru
 }
 
 /// Captured layout for a nominal Chic type.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum TypeLayout {
     Struct(StructLayout),
     Class(StructLayout),
@@ -1402,7 +1434,7 @@ impl TypeLayout {
 }
 
 /// Field-oriented layout for structs and classes.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StructLayout {
     pub name: String,
     pub repr: TypeRepr,
@@ -1423,7 +1455,7 @@ pub struct StructLayout {
 }
 
 /// Variant-oriented layout for enums.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct EnumLayout {
     pub name: String,
     pub repr: TypeRepr,
@@ -1439,13 +1471,13 @@ pub struct EnumLayout {
     pub is_flags: bool,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum ClassLayoutKind {
     Class,
     Error,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ClassLayoutInfo {
     pub kind: ClassLayoutKind,
     pub bases: Vec<String>,
@@ -1453,7 +1485,7 @@ pub struct ClassLayoutInfo {
 }
 
 /// View-oriented layout for unions.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct UnionLayout {
     pub name: String,
     pub repr: TypeRepr,
@@ -1466,7 +1498,7 @@ pub struct UnionLayout {
 }
 
 /// Field metadata captured during lowering.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FieldLayout {
     pub name: String,
     pub ty: Ty,
@@ -1496,7 +1528,7 @@ impl FieldLayout {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MmioStructLayout {
     pub base_address: u64,
     pub size: Option<u64>,
@@ -1505,28 +1537,28 @@ pub struct MmioStructLayout {
     pub requires_unsafe: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MmioFieldLayout {
     pub offset: u32,
     pub width_bits: u16,
     pub access: MmioAccess,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum MmioAccess {
     ReadOnly,
     WriteOnly,
     ReadWrite,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum MmioEndianness {
     Little,
     Big,
 }
 
 /// Enum variant metadata.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct EnumVariantLayout {
     pub name: String,
     pub index: u32,
@@ -1536,7 +1568,7 @@ pub struct EnumVariantLayout {
 }
 
 /// Metadata describing positional accessors for tuple-like patterns.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PositionalElement {
     pub field_index: u32,
     pub name: Option<String>,
@@ -1544,7 +1576,7 @@ pub struct PositionalElement {
 }
 
 /// Metadata describing list-like layout for pattern matching.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ListLayout {
     pub element_index: Option<u32>,
     pub length_index: Option<u32>,
@@ -1641,7 +1673,7 @@ mod tests {
 }
 
 /// Union view metadata.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct UnionFieldLayout {
     pub name: String,
     pub ty: Ty,
@@ -1652,7 +1684,7 @@ pub struct UnionFieldLayout {
 }
 
 /// Access semantics for a union view.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum UnionFieldMode {
     Value,
     Readonly,
@@ -1670,7 +1702,7 @@ impl UnionFieldMode {
 }
 
 /// Representation hints propagated from attributes (bootstrap currently defaults everything).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum TypeRepr {
     Default,
     C,
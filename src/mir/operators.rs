@@ -12,6 +12,8 @@ pub enum ConversionKind {
 pub enum OperatorKind {
     Unary(UnOp),
     Binary(BinOp),
+    /// User-declared compound assignment (`operator +=`), keyed by the underlying binary op.
+    CompoundAssignment(BinOp),
     Conversion(ConversionKind),
 }
 
@@ -81,6 +83,25 @@ impl OperatorRegistry {
         lhs_ty: &str,
         rhs_ty: &str,
         op: BinOp,
+    ) -> OperatorMatch<'a> {
+        self.resolve_two_operand(lhs_ty, rhs_ty, &OperatorKind::Binary(op))
+    }
+
+    /// Resolves a dedicated compound-assignment overload (`operator +=`) for `lhs op= rhs`.
+    pub fn resolve_compound_assignment<'a>(
+        &'a self,
+        lhs_ty: &str,
+        rhs_ty: &str,
+        op: BinOp,
+    ) -> OperatorMatch<'a> {
+        self.resolve_two_operand(lhs_ty, rhs_ty, &OperatorKind::CompoundAssignment(op))
+    }
+
+    fn resolve_two_operand<'a>(
+        &'a self,
+        lhs_ty: &str,
+        rhs_ty: &str,
+        kind: &OperatorKind,
     ) -> OperatorMatch<'a> {
         let mut owners = self.owner_candidates(lhs_ty);
         owners.extend(self.owner_candidates(rhs_ty));
@@ -91,14 +112,12 @@ impl OperatorRegistry {
         for owner in owners {
             if let Some(overloads) = self.overloads.get(owner) {
                 for overload in overloads {
-                    if let OperatorKind::Binary(kind) = overload.kind {
-                        if kind == op
-                            && overload.params.len() == 2
-                            && type_matches(&overload.params[0], lhs_ty)
-                            && type_matches(&overload.params[1], rhs_ty)
-                        {
-                            matches.push(overload);
-                        }
+                    if overload.kind == *kind
+                        && overload.params.len() == 2
+                        && type_matches(&overload.params[0], lhs_ty)
+                        && type_matches(&overload.params[1], rhs_ty)
+                    {
+                        matches.push(overload);
                     }
                 }
             }
@@ -151,3 +151,43 @@ fn reports_ambiguous_implicit_conversions() {
         other => panic!("expected ambiguous implicit conversions, found {other:?}"),
     }
 }
+
+#[test]
+fn compound_assignment_overloads_resolve_separately_from_binary() {
+    let mut registry = OperatorRegistry::default();
+    registry.register(
+        "Geometry::Vec2",
+        OperatorOverload {
+            kind: OperatorKind::Binary(BinOp::Add),
+            params: vec!["Geometry::Vec2".into(), "Geometry::Vec2".into()],
+            result: "Geometry::Vec2".into(),
+            function: "Geometry::Vec2::op_Addition".into(),
+        },
+    );
+    registry.register(
+        "Geometry::Vec2",
+        OperatorOverload {
+            kind: OperatorKind::CompoundAssignment(BinOp::Add),
+            params: vec!["Geometry::Vec2".into(), "Geometry::Vec2".into()],
+            result: "Geometry::Vec2".into(),
+            function: "Geometry::Vec2::op_AdditionAssignment".into(),
+        },
+    );
+
+    match registry.resolve_compound_assignment("Geometry::Vec2", "Geometry::Vec2", BinOp::Add) {
+        OperatorMatch::Found(overload) => {
+            assert_eq!(overload.function, "Geometry::Vec2::op_AdditionAssignment");
+        }
+        other => panic!("expected compound assignment overload, found {other:?}"),
+    }
+    match registry.resolve_binary("Geometry::Vec2", "Geometry::Vec2", BinOp::Add) {
+        OperatorMatch::Found(overload) => {
+            assert_eq!(overload.function, "Geometry::Vec2::op_Addition");
+        }
+        other => panic!("expected binary overload, found {other:?}"),
+    }
+    assert!(matches!(
+        registry.resolve_compound_assignment("Geometry::Vec2", "Geometry::Vec2", BinOp::Sub),
+        OperatorMatch::None
+    ));
+}
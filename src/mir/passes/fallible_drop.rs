@@ -6,6 +6,7 @@ use crate::mir::data::{
     Statement, StatementKind, Terminator,
 };
 use crate::mir::{Abi, FunctionKind, MirFunction, MirModule};
+use crate::threading;
 
 #[derive(Clone, PartialEq)]
 struct FallibleOrigin {
@@ -13,18 +14,17 @@ struct FallibleOrigin {
 }
 
 pub fn check_fallible_values(module: &MirModule) -> Vec<Diagnostic> {
-    let mut diagnostics = Vec::new();
-    for function in &module.functions {
-        if matches!(function.signature.abi, Abi::Extern(_)) {
-            continue;
+    threading::parallel_map(&module.functions, |function| {
+        let mut diagnostics = Vec::new();
+        if matches!(function.signature.abi, Abi::Extern(_)) || function.body.blocks.is_empty() {
+            return diagnostics;
         }
-        if function.body.blocks.is_empty() {
-            continue;
-        }
-        let mut analyzer = FunctionAnalyzer::new(module, function);
-        analyzer.run(&mut diagnostics);
-    }
-    diagnostics
+        FunctionAnalyzer::new(module, function).run(&mut diagnostics);
+        diagnostics
+    })
+    .into_iter()
+    .flatten()
+    .collect()
 }
 
 struct FunctionAnalyzer<'a> {
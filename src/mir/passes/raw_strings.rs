@@ -10,20 +10,28 @@ use crate::mir::{
 pub fn intern_raw_strings(module: &mut MirModule) {
     let mut interner = RawStringInterner::new(&mut module.interned_strs);
     for function in &mut module.functions {
-        intern_raw_strings_in_body(&mut function.body, &mut interner);
+        walk_body_strings(&mut function.body, &mut interner);
     }
     for static_var in &mut module.statics {
         if let Some(initializer) = static_var.initializer.as_mut() {
-            intern_raw_strings_in_const(initializer, &mut interner);
+            walk_const_strings(initializer, &mut interner);
         }
     }
     for default in &mut module.default_arguments {
         if let DefaultArgumentKind::Const(value) = &mut default.value {
-            intern_raw_strings_in_const(value, &mut interner);
+            walk_const_strings(value, &mut interner);
         }
     }
 }
 
+/// Callbacks for every place MIR stores string data: constants (including nested struct
+/// fields) and the interned segments of string interpolations.
+pub(crate) trait StringSites {
+    fn visit_const(&mut self, value: &mut ConstValue);
+
+    fn visit_str_id(&mut self, _id: &mut StrId) {}
+}
+
 struct RawStringInterner<'a> {
     interned: &'a mut Vec<InternedStr>,
     map: HashMap<String, StrId>,
@@ -54,44 +62,54 @@ impl<'a> RawStringInterner<'a> {
     }
 }
 
-fn intern_raw_strings_in_body(body: &mut MirBody, interner: &mut RawStringInterner<'_>) {
+impl StringSites for RawStringInterner<'_> {
+    fn visit_const(&mut self, value: &mut ConstValue) {
+        if let ConstValue::RawStr(text) = value {
+            let raw = std::mem::take(text);
+            let id = self.intern(&raw);
+            *value = ConstValue::Str { id, value: raw };
+        }
+    }
+}
+
+pub(crate) fn walk_body_strings(body: &mut MirBody, sites: &mut impl StringSites) {
     for block in &mut body.blocks {
         for statement in &mut block.statements {
-            intern_raw_strings_in_statement(&mut statement.kind, interner);
+            walk_statement_strings(&mut statement.kind, sites);
         }
         if let Some(terminator) = block.terminator.as_mut() {
-            intern_raw_strings_in_terminator(terminator, interner);
+            walk_terminator_strings(terminator, sites);
         }
     }
 }
 
-fn intern_raw_strings_in_statement(kind: &mut StatementKind, interner: &mut RawStringInterner<'_>) {
+fn walk_statement_strings(kind: &mut StatementKind, sites: &mut impl StringSites) {
     match kind {
-        StatementKind::Assign { value, .. } => intern_raw_strings_in_rvalue(value, interner),
+        StatementKind::Assign { value, .. } => walk_rvalue_strings(value, sites),
         StatementKind::ZeroInitRaw { pointer, length } => {
-            intern_raw_strings_in_operand(pointer, interner);
-            intern_raw_strings_in_operand(length, interner);
+            walk_operand_strings(pointer, sites);
+            walk_operand_strings(length, sites);
         }
         StatementKind::AtomicStore { value, .. } => {
-            intern_raw_strings_in_operand(value, interner);
+            walk_operand_strings(value, sites);
         }
         StatementKind::MmioStore { value, .. } => {
-            intern_raw_strings_in_operand(value, interner);
+            walk_operand_strings(value, sites);
         }
-        StatementKind::Assert { cond, .. } => intern_raw_strings_in_operand(cond, interner),
+        StatementKind::Assert { cond, .. } => walk_operand_strings(cond, sites),
         StatementKind::EnqueueKernel { kernel, args, .. } => {
-            intern_raw_strings_in_operand(kernel, interner);
+            walk_operand_strings(kernel, sites);
             for arg in args {
-                intern_raw_strings_in_operand(arg, interner);
+                walk_operand_strings(arg, sites);
             }
         }
         StatementKind::EnqueueCopy { bytes, .. } => {
-            intern_raw_strings_in_operand(bytes, interner);
+            walk_operand_strings(bytes, sites);
         }
         StatementKind::StaticStore { value, .. } => {
-            intern_raw_strings_in_operand(value, interner);
+            walk_operand_strings(value, sites);
         }
-        StatementKind::InlineAsm(asm) => intern_raw_strings_in_inline_asm(asm, interner),
+        StatementKind::InlineAsm(asm) => walk_inline_asm_strings(asm, sites),
         StatementKind::AtomicFence { .. }
         | StatementKind::StorageLive(_)
         | StatementKind::StorageDead(_)
@@ -113,70 +131,78 @@ fn intern_raw_strings_in_statement(kind: &mut StatementKind, interner: &mut RawS
     }
 }
 
-fn intern_raw_strings_in_inline_asm(asm: &mut InlineAsm, interner: &mut RawStringInterner<'_>) {
+fn walk_inline_asm_strings(asm: &mut InlineAsm, sites: &mut impl StringSites) {
     for operand in &mut asm.operands {
         match &mut operand.kind {
             InlineAsmOperandKind::In { value } => {
-                intern_raw_strings_in_operand(value, interner);
+                walk_operand_strings(value, sites);
             }
             InlineAsmOperandKind::InOut { input, .. } => {
-                intern_raw_strings_in_operand(input, interner);
+                walk_operand_strings(input, sites);
             }
             InlineAsmOperandKind::Const { value } => {
-                intern_raw_strings_in_operand(value, interner);
+                walk_operand_strings(value, sites);
             }
             InlineAsmOperandKind::Out { .. } | InlineAsmOperandKind::Sym { .. } => {}
         }
     }
 }
 
-fn intern_raw_strings_in_rvalue(rvalue: &mut Rvalue, interner: &mut RawStringInterner<'_>) {
+fn walk_rvalue_strings(rvalue: &mut Rvalue, sites: &mut impl StringSites) {
     match rvalue {
-        Rvalue::Use(operand) => intern_raw_strings_in_operand(operand, interner),
-        Rvalue::Unary { operand, .. } => intern_raw_strings_in_operand(operand, interner),
+        Rvalue::Use(operand) => walk_operand_strings(operand, sites),
+        Rvalue::Unary { operand, .. } => walk_operand_strings(operand, sites),
         Rvalue::Binary { lhs, rhs, .. } => {
-            intern_raw_strings_in_operand(lhs, interner);
-            intern_raw_strings_in_operand(rhs, interner);
+            walk_operand_strings(lhs, sites);
+            walk_operand_strings(rhs, sites);
         }
         Rvalue::Aggregate { fields, .. } => {
             for field in fields {
-                intern_raw_strings_in_operand(field, interner);
+                walk_operand_strings(field, sites);
             }
         }
         Rvalue::SpanStackAlloc { length, source, .. } => {
-            intern_raw_strings_in_operand(length, interner);
+            walk_operand_strings(length, sites);
             if let Some(source) = source.as_mut() {
-                intern_raw_strings_in_operand(source, interner);
+                walk_operand_strings(source, sites);
             }
         }
-        Rvalue::Cast { operand, .. } => intern_raw_strings_in_operand(operand, interner),
+        Rvalue::Cast { operand, .. } => walk_operand_strings(operand, sites),
         Rvalue::StringInterpolate { segments } => {
             for segment in segments {
-                if let InterpolatedStringSegment::Expr { operand, .. } = segment {
-                    intern_raw_strings_in_operand(operand, interner);
+                match segment {
+                    InterpolatedStringSegment::Text { id } => sites.visit_str_id(id),
+                    InterpolatedStringSegment::Expr {
+                        operand, format, ..
+                    } => {
+                        walk_operand_strings(operand, sites);
+                        if let Some(format) = format.as_mut() {
+                            sites.visit_str_id(format);
+                        }
+                    }
                 }
             }
         }
         Rvalue::NumericIntrinsic(intrinsic) => {
             for operand in &mut intrinsic.operands {
-                intern_raw_strings_in_operand(operand, interner);
+                walk_operand_strings(operand, sites);
             }
         }
         Rvalue::DecimalIntrinsic(decimal) => {
-            intern_raw_strings_in_operand(&mut decimal.lhs, interner);
-            intern_raw_strings_in_operand(&mut decimal.rhs, interner);
+            walk_operand_strings(&mut decimal.lhs, sites);
+            walk_operand_strings(&mut decimal.rhs, sites);
             if let Some(addend) = decimal.addend.as_mut() {
-                intern_raw_strings_in_operand(addend, interner);
+                walk_operand_strings(addend, sites);
             }
-            intern_raw_strings_in_operand(&mut decimal.rounding, interner);
-            intern_raw_strings_in_operand(&mut decimal.vectorize, interner);
+            walk_operand_strings(&mut decimal.rounding, sites);
+            walk_operand_strings(&mut decimal.vectorize, sites);
         }
-        Rvalue::AtomicRmw { value, .. } => intern_raw_strings_in_operand(value, interner),
+        Rvalue::AtomicRmw { value, .. } => walk_operand_strings(value, sites),
         Rvalue::AtomicCompareExchange {
             expected, desired, ..
         } => {
-            intern_raw_strings_in_operand(expected, interner);
-            intern_raw_strings_in_operand(desired, interner);
+            walk_operand_strings(expected, sites);
+            walk_operand_strings(desired, sites);
         }
         Rvalue::AtomicLoad { .. }
         | Rvalue::AddressOf { .. }
@@ -187,17 +213,17 @@ fn intern_raw_strings_in_rvalue(rvalue: &mut Rvalue, interner: &mut RawStringInt
     }
 }
 
-fn intern_raw_strings_in_operand(operand: &mut Operand, interner: &mut RawStringInterner<'_>) {
+fn walk_operand_strings(operand: &mut Operand, sites: &mut impl StringSites) {
     match operand {
         Operand::Const(constant) => {
-            intern_raw_strings_in_const(&mut constant.value, interner);
+            walk_const_strings(&mut constant.value, sites);
         }
         Operand::Pending(pending) => {
             if let Some(info) = pending.info.as_mut() {
                 match info.as_mut() {
                     PendingOperandInfo::FunctionGroup { receiver, .. } => {
                         if let Some(receiver) = receiver.as_mut() {
-                            intern_raw_strings_in_operand(receiver, interner);
+                            walk_operand_strings(receiver, sites);
                         }
                     }
                 }
@@ -207,27 +233,24 @@ fn intern_raw_strings_in_operand(operand: &mut Operand, interner: &mut RawString
     }
 }
 
-fn intern_raw_strings_in_terminator(
-    terminator: &mut Terminator,
-    interner: &mut RawStringInterner<'_>,
-) {
+fn walk_terminator_strings(terminator: &mut Terminator, sites: &mut impl StringSites) {
     match terminator {
-        Terminator::SwitchInt { discr, .. } => intern_raw_strings_in_operand(discr, interner),
+        Terminator::SwitchInt { discr, .. } => walk_operand_strings(discr, sites),
         Terminator::Match { arms, .. } => {
             for arm in arms {
-                intern_raw_strings_in_pattern(&mut arm.pattern, interner);
+                walk_pattern_strings(&mut arm.pattern, sites);
             }
         }
         Terminator::Call { func, args, .. } => {
-            intern_raw_strings_in_operand(func, interner);
+            walk_operand_strings(func, sites);
             for arg in args {
-                intern_raw_strings_in_operand(arg, interner);
+                walk_operand_strings(arg, sites);
             }
         }
-        Terminator::Yield { value, .. } => intern_raw_strings_in_operand(value, interner),
+        Terminator::Yield { value, .. } => walk_operand_strings(value, sites),
         Terminator::Throw { exception, .. } => {
             if let Some(exception) = exception.as_mut() {
-                intern_raw_strings_in_operand(exception, interner);
+                walk_operand_strings(exception, sites);
             }
         }
         Terminator::Goto { .. }
@@ -239,29 +262,29 @@ fn intern_raw_strings_in_terminator(
     }
 }
 
-fn intern_raw_strings_in_pattern(pattern: &mut Pattern, interner: &mut RawStringInterner<'_>) {
+fn walk_pattern_strings(pattern: &mut Pattern, sites: &mut impl StringSites) {
     match pattern {
-        Pattern::Literal(value) => intern_raw_strings_in_const(value, interner),
+        Pattern::Literal(value) => walk_const_strings(value, sites),
         Pattern::Tuple(items) => {
             for item in items {
-                intern_raw_strings_in_pattern(item, interner);
+                walk_pattern_strings(item, sites);
             }
         }
         Pattern::Struct { fields, .. } => {
             for field in fields {
-                intern_raw_strings_in_pattern_field(field, interner);
+                walk_pattern_field_strings(field, sites);
             }
         }
         Pattern::Enum { fields, .. } => match fields {
             VariantPatternFields::Unit => {}
             VariantPatternFields::Tuple(items) => {
                 for item in items {
-                    intern_raw_strings_in_pattern(item, interner);
+                    walk_pattern_strings(item, sites);
                 }
             }
             VariantPatternFields::Struct(fields) => {
                 for field in fields {
-                    intern_raw_strings_in_pattern_field(field, interner);
+                    walk_pattern_field_strings(field, sites);
                 }
             }
         },
@@ -269,25 +292,15 @@ fn intern_raw_strings_in_pattern(pattern: &mut Pattern, interner: &mut RawString
     }
 }
 
-fn intern_raw_strings_in_pattern_field(
-    field: &mut PatternField,
-    interner: &mut RawStringInterner<'_>,
-) {
-    intern_raw_strings_in_pattern(&mut field.pattern, interner);
+fn walk_pattern_field_strings(field: &mut PatternField, sites: &mut impl StringSites) {
+    walk_pattern_strings(&mut field.pattern, sites);
 }
 
-fn intern_raw_strings_in_const(value: &mut ConstValue, interner: &mut RawStringInterner<'_>) {
-    match value {
-        ConstValue::RawStr(text) => {
-            let raw = std::mem::take(text);
-            let id = interner.intern(&raw);
-            *value = ConstValue::Str { id, value: raw };
-        }
-        ConstValue::Struct { fields, .. } => {
-            for (_, field_value) in fields {
-                intern_raw_strings_in_const(field_value, interner);
-            }
+pub(crate) fn walk_const_strings(value: &mut ConstValue, sites: &mut impl StringSites) {
+    sites.visit_const(value);
+    if let ConstValue::Struct { fields, .. } = value {
+        for (_, field_value) in fields {
+            walk_const_strings(field_value, sites);
         }
-        _ => {}
     }
 }
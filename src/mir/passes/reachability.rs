@@ -5,21 +5,23 @@ use crate::mir::data::{
     BinOp, BlockId, BorrowKind, ConstValue, LocalId, MirFunction, MirModule, Operand, ParamMode,
     Rvalue, StatementKind, Terminator, UnOp,
 };
+use crate::threading;
 
 const UNREACHABLE_CODE: &str = "E0400";
 const CATEGORY: &str = "reachability";
 
 /// Perform reachability analysis over MIR bodies and emit unreachable-code diagnostics.
 pub fn check_unreachable_code(module: &MirModule) -> Vec<Diagnostic> {
-    let mut diagnostics = Vec::new();
-    for function in &module.functions {
-        if function.body.blocks.is_empty() {
-            continue;
+    threading::parallel_map(&module.functions, |function| {
+        let mut diagnostics = Vec::new();
+        if !function.body.blocks.is_empty() {
+            ReachabilityAnalyzer::new(function).run(&mut diagnostics);
         }
-        let mut analyzer = ReachabilityAnalyzer::new(function);
-        analyzer.run(&mut diagnostics);
-    }
-    diagnostics
+        diagnostics
+    })
+    .into_iter()
+    .flatten()
+    .collect()
 }
 
 struct ReachabilityAnalyzer<'a> {
@@ -3,9 +3,10 @@
 use crate::frontend::diagnostics::Span;
 use crate::mir::AsyncFramePolicy;
 use crate::mir::data::{BlockId, LocalId, Ty};
+use serde::Serialize;
 
 /// Captures async state-machine metadata for a MIR body.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct AsyncStateMachine {
     pub suspend_points: Vec<AsyncSuspendPoint>,
     pub pinned_locals: Vec<LocalId>,
@@ -18,7 +19,7 @@ pub struct AsyncStateMachine {
 }
 
 /// Records one `await` suspension point in an async body.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AsyncSuspendPoint {
     pub id: usize,
     pub await_block: BlockId,
@@ -29,7 +30,7 @@ pub struct AsyncSuspendPoint {
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AsyncFrameField {
     pub local: LocalId,
     pub name: Option<String>,
@@ -37,13 +38,13 @@ pub struct AsyncFrameField {
 }
 
 /// Captures iterator state-machine metadata for generator bodies.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct GeneratorStateMachine {
     pub yields: Vec<GeneratorYieldPoint>,
 }
 
 /// Records a `yield` suspension point inside an iterator body.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct GeneratorYieldPoint {
     pub id: usize,
     pub yield_block: BlockId,
@@ -54,7 +55,7 @@ pub struct GeneratorYieldPoint {
 }
 
 /// Records the structure of a `try` statement and its handlers.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ExceptionRegion {
     pub id: usize,
     pub span: Option<Span>,
@@ -67,7 +68,7 @@ pub struct ExceptionRegion {
 }
 
 /// Metadata for a single `catch` clause.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CatchRegion {
     pub span: Option<Span>,
     pub entry: BlockId,
@@ -79,7 +80,7 @@ pub struct CatchRegion {
 }
 
 /// Metadata describing a `when` filter attached to a catch clause.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CatchFilter {
     pub expr: String,
     pub span: Option<Span>,
@@ -88,7 +89,7 @@ pub struct CatchFilter {
 }
 
 /// Metadata describing the `finally` clause of a `try` statement.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FinallyRegion {
     pub span: Option<Span>,
     pub entry: BlockId,
@@ -1,10 +1,12 @@
 use blake3::hash;
+use serde::Serialize;
 
+pub use crate::frontend::ast::{TestArgumentSource, TestCaseKind};
 use crate::frontend::diagnostics::Span;
 use crate::mir::{FunctionKind, MirModule};
 
 /// Metadata describing a testcase discovered during MIR lowering.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct TestCaseMetadata {
     /// Index of the testcase function inside `MirModule::functions`.
     pub function_index: usize,
@@ -20,6 +22,12 @@ pub struct TestCaseMetadata {
     pub categories: Vec<String>,
     /// Parameter metadata for data-driven scenarios.
     pub parameters: Vec<TestCaseParameterMetadata>,
+    /// Declaration form (`testcase`, `proptestcase` or `fuzzcase`).
+    pub kind: TestCaseKind,
+    /// Generated-case count requested via `@runs(N)` on property and fuzz testcases.
+    pub runs: Option<u32>,
+    /// `@cases` data row this result runs, when the testcase declares rows.
+    pub row: Option<TestCaseRow>,
     /// Whether the testcase is async.
     pub is_async: bool,
     /// Source span for the declaration.
@@ -27,7 +35,7 @@ pub struct TestCaseMetadata {
 }
 
 /// Parameter metadata captured for testcase declarations.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct TestCaseParameterMetadata {
     /// Parameter name as declared.
     pub name: String,
@@ -35,6 +43,53 @@ pub struct TestCaseParameterMetadata {
     pub ty: Option<String>,
     /// Whether the parameter has a default value.
     pub has_default: bool,
+    /// Domain generated values are drawn from, when the type is a supported primitive.
+    pub domain: Option<TestValueDomain>,
+    /// Fixture, `@cases` value or default bound to the parameter; `None` when the runner has to
+    /// supply the argument.
+    pub source: Option<TestArgumentSource>,
+}
+
+/// `@cases` data row a testcase result was expanded from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TestCaseRow {
+    /// Fully-qualified name of the declared testcase.
+    pub testcase: String,
+    /// Zero-based position among the testcase's `@cases` attributes.
+    pub index: usize,
+    /// Row arguments as written, e.g. `1, 2`.
+    pub arguments: String,
+}
+
+/// Primitive value domains the test runner can generate arguments for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum TestValueDomain {
+    Bool,
+    Int { bits: u16, signed: bool },
+    Float { bits: u16 },
+    Char { bits: u16 },
+}
+
+impl TestValueDomain {
+    /// Map a primitive kind onto a generatable domain; pointer-sized, 128-bit and
+    /// non-scalar primitives are not supported.
+    #[must_use]
+    pub fn from_primitive(kind: &crate::primitives::PrimitiveKind) -> Option<Self> {
+        use crate::primitives::PrimitiveKind;
+        match *kind {
+            PrimitiveKind::Bool => Some(Self::Bool),
+            PrimitiveKind::Int {
+                bits,
+                signed,
+                pointer_sized: false,
+            } if matches!(bits, 8 | 16 | 32 | 64) => Some(Self::Int { bits, signed }),
+            PrimitiveKind::Float { bits } if matches!(bits, 32 | 64) => Some(Self::Float { bits }),
+            PrimitiveKind::Char { bits } if matches!(bits, 8 | 16 | 32) => {
+                Some(Self::Char { bits })
+            }
+            _ => None,
+        }
+    }
 }
 
 impl TestCaseMetadata {
@@ -47,6 +102,13 @@ impl TestCaseMetadata {
         format!("t-{}", &digest.to_hex()[..12])
     }
 
+    /// Parameters the runner has to supply because no fixture, `@cases` row or default binds them.
+    pub fn runner_parameters(&self) -> impl Iterator<Item = &TestCaseParameterMetadata> {
+        self.parameters
+            .iter()
+            .filter(|parameter| parameter.source.is_none())
+    }
+
     #[must_use]
     pub fn split_namespace(name: &str) -> (Option<String>, String) {
         let mut parts = name.rsplitn(2, "::");
@@ -109,6 +171,9 @@ pub fn collect_test_metadata(module: &MirModule) -> Vec<TestCaseMetadata> {
                 namespace,
                 categories: Vec::new(),
                 parameters: Vec::new(),
+                kind: TestCaseKind::Unit,
+                runs: None,
+                row: None,
                 is_async: function.is_async,
                 span: function.span,
             })
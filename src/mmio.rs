@@ -1,5 +1,6 @@
 use crate::mir::MmioEndianness;
 use blake3;
+use serde::Serialize;
 use std::fmt;
 
 /// Compact identifier for an MMIO address space.
@@ -7,7 +8,7 @@ use std::fmt;
 /// Address spaces let drivers distinguish between different peripheral buses
 /// (e.g. AHB vs APB) without duplicating address ranges. They are encoded into
 /// runtime flags so simulated environments can keep their state separated.
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub struct AddressSpaceId(u16);
 
 impl AddressSpaceId {
@@ -0,0 +1,311 @@
+//! Package features: what a request for some features of a package switches on.
+//!
+//! A package's active set is the closure of what its dependents (or, for the root, the
+//! command line) request, plus its `default` list unless every request opts out. Features
+//! may enable other features, optional dependencies (`dep:name`) and features of
+//! dependencies (`name/feature`); an optional dependency also acts as a feature of its own
+//! name.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::frontend::conditional::{ConditionalDefines, DefineValue, feature_define};
+use crate::manifest::{FeatureRef, Manifest};
+
+/// The features asked of one package.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeatureRequest {
+    pub default_features: bool,
+    pub features: BTreeSet<String>,
+}
+
+impl FeatureRequest {
+    /// A package's default features and nothing else.
+    #[must_use]
+    pub fn defaults() -> Self {
+        Self {
+            default_features: true,
+            features: BTreeSet::new(),
+        }
+    }
+
+    /// The root package's request: its defaults plus every feature switched on with
+    /// `-D feature=...`.
+    ///
+    /// # Errors
+    /// Returns [`UnknownFeatures`] when a switched-on feature is not declared by the root
+    /// package.
+    pub fn for_root(
+        manifest: &Manifest,
+        defines: &ConditionalDefines,
+    ) -> Result<Self, UnknownFeatures> {
+        let declared: BTreeSet<&String> = manifest
+            .features()
+            .features
+            .keys()
+            .chain(
+                manifest
+                    .dependencies()
+                    .iter()
+                    .filter(|dep| dep.optional)
+                    .map(|dep| &dep.name),
+            )
+            .collect();
+        let unknown = undeclared_features(defines, &declared);
+        if !unknown.is_empty() {
+            return Err(UnknownFeatures {
+                package: manifest
+                    .package()
+                    .and_then(|package| package.name.clone())
+                    .unwrap_or_default(),
+                unknown,
+                declared: declared.into_iter().cloned().collect(),
+            });
+        }
+        Ok(Self {
+            default_features: true,
+            features: declared
+                .into_iter()
+                .filter(|name| defines.has_feature(name))
+                .cloned()
+                .collect(),
+        })
+    }
+}
+
+/// The switched-on features none of `declared` maps to, spelled as on the command line
+/// when the `feature` define still names them.
+fn undeclared_features(defines: &ConditionalDefines, declared: &BTreeSet<&String>) -> Vec<String> {
+    let spelled: Vec<&str> = match defines.get("feature") {
+        Some(DefineValue::String(list)) => list.split(',').map(str::trim).collect(),
+        _ => Vec::new(),
+    };
+    let mut unknown = Vec::new();
+    for (key, value) in defines.iter() {
+        let Some(suffix) = key.strip_prefix("feature_") else {
+            continue;
+        };
+        if !matches!(value, DefineValue::Bool(true))
+            || declared.iter().any(|name| feature_define(name) == *key)
+        {
+            continue;
+        }
+        let name = spelled
+            .iter()
+            .find(|name| feature_define(name) == *key)
+            .map_or(suffix, |name| *name);
+        unknown.push(name.to_string());
+    }
+    unknown.sort();
+    unknown
+}
+
+/// Features requested of the root package that its manifest does not declare.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownFeatures {
+    pub package: String,
+    pub unknown: Vec<String>,
+    /// Every feature the package declares, optional dependencies included.
+    pub declared: Vec<String>,
+}
+
+impl fmt::Display for UnknownFeatures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let quote = |names: &[String]| {
+            names
+                .iter()
+                .map(|name| format!("`{name}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let noun = if self.unknown.len() == 1 {
+            "feature"
+        } else {
+            "features"
+        };
+        write!(
+            f,
+            "unknown {noun} {} requested for `{}`; ",
+            quote(&self.unknown),
+            self.package
+        )?;
+        if self.declared.is_empty() {
+            write!(f, "it declares no features")
+        } else {
+            write!(f, "declared features: {}", quote(&self.declared))
+        }
+    }
+}
+
+/// What a package's requested features switch on.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeatureActivation {
+    pub features: BTreeSet<String>,
+    /// Optional dependencies that become part of the graph.
+    pub optional_dependencies: BTreeSet<String>,
+    /// Features this package asks of each dependency.
+    pub dependency_features: BTreeMap<String, BTreeSet<String>>,
+    /// Requested features the package does not declare.
+    pub unknown: BTreeSet<String>,
+}
+
+/// Expand `request` against the features `manifest` declares.
+#[must_use]
+pub fn activate(manifest: &Manifest, request: &FeatureRequest) -> FeatureActivation {
+    let declared = manifest.features();
+    let is_optional = |name: &str| {
+        manifest
+            .dependencies()
+            .iter()
+            .any(|dep| dep.optional && dep.name == name)
+    };
+    let mut activation = FeatureActivation::default();
+    let mut pending: Vec<String> = request.features.iter().cloned().collect();
+    if request.default_features {
+        pending.extend(declared.default.iter().cloned());
+    }
+    while let Some(name) = pending.pop() {
+        if activation.features.contains(&name) {
+            continue;
+        }
+        if let Some(refs) = declared.features.get(&name) {
+            for entry in refs {
+                match entry {
+                    FeatureRef::Feature(feature) => pending.push(feature.clone()),
+                    FeatureRef::Dependency(dependency) => {
+                        activation.optional_dependencies.insert(dependency.clone());
+                    }
+                    FeatureRef::DependencyFeature {
+                        dependency,
+                        feature,
+                    } => {
+                        if is_optional(dependency) {
+                            activation.optional_dependencies.insert(dependency.clone());
+                        }
+                        activation
+                            .dependency_features
+                            .entry(dependency.clone())
+                            .or_default()
+                            .insert(feature.clone());
+                    }
+                }
+            }
+        } else if is_optional(&name) {
+            activation.optional_dependencies.insert(name.clone());
+        } else {
+            activation.unknown.insert(name);
+            continue;
+        }
+        activation.features.insert(name);
+    }
+    activation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn manifest(contents: &str) -> Manifest {
+        let dir = tempdir().expect("temp dir");
+        let path = dir.path().join("manifest.yaml");
+        fs::write(&path, contents).expect("write manifest");
+        Manifest::discover(&path)
+            .expect("parse manifest")
+            .expect("manifest present")
+    }
+
+    const MANIFEST: &str = r#"
+package:
+  name: Demo
+  version: 1.0.0
+dependencies:
+  json:
+    path: ../json
+    optional: true
+  math:
+    path: ../math
+features:
+  default: [std]
+  std: []
+  simd: [std, "math/simd"]
+  serde: ["dep:json", "json/derive"]
+"#;
+
+    fn request(default_features: bool, features: &[&str]) -> FeatureRequest {
+        FeatureRequest {
+            default_features,
+            features: features.iter().map(|name| name.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn defaults_and_implications_are_expanded() {
+        let manifest = manifest(MANIFEST);
+        assert!(manifest.issues().is_empty(), "{:?}", manifest.issues());
+        let activation = activate(&manifest, &request(true, &["simd"]));
+        assert_eq!(
+            activation.features,
+            BTreeSet::from(["simd".to_string(), "std".to_string()])
+        );
+        assert_eq!(
+            activation.dependency_features.get("math"),
+            Some(&BTreeSet::from(["simd".to_string()]))
+        );
+        assert!(activation.optional_dependencies.is_empty());
+
+        let bare = activate(&manifest, &request(false, &[]));
+        assert!(
+            bare.features.is_empty(),
+            "default_features: false skips defaults"
+        );
+    }
+
+    #[test]
+    fn features_enable_optional_dependencies() {
+        let manifest = manifest(MANIFEST);
+        let activation = activate(&manifest, &request(false, &["serde"]));
+        assert_eq!(
+            activation.optional_dependencies,
+            BTreeSet::from(["json".to_string()])
+        );
+        let implicit = activate(&manifest, &request(false, &["json"]));
+        assert!(implicit.features.contains("json"));
+        assert!(implicit.optional_dependencies.contains("json"));
+    }
+
+    #[test]
+    fn reports_unknown_features() {
+        let manifest = manifest(MANIFEST);
+        let activation = activate(&manifest, &request(true, &["gpu"]));
+        assert_eq!(activation.unknown, BTreeSet::from(["gpu".to_string()]));
+        assert!(!activation.features.contains("gpu"));
+    }
+
+    #[test]
+    fn root_request_reads_feature_defines() {
+        let manifest = manifest(MANIFEST);
+        let mut defines = ConditionalDefines::default();
+        defines.set_feature("simd", true);
+        assert_eq!(
+            FeatureRequest::for_root(&manifest, &defines),
+            Ok(request(true, &["simd"]))
+        );
+    }
+
+    #[test]
+    fn unknown_root_features_list_the_declared_ones() {
+        let manifest = manifest(MANIFEST);
+        let mut defines = ConditionalDefines::default();
+        defines.set_feature("simd", true);
+        defines.set_feature("fast-math", true);
+        defines.set_string("feature", "simd,fast-math");
+        let err = FeatureRequest::for_root(&manifest, &defines).expect_err("unknown feature");
+        assert_eq!(err.unknown, vec!["fast-math".to_string()]);
+        assert_eq!(
+            err.to_string(),
+            "unknown feature `fast-math` requested for `Demo`; declared features: `json`, `serde`, `simd`, `std`"
+        );
+    }
+}
@@ -0,0 +1,256 @@
+//! Minimal HTTP/1.1 over `std::net` for talking to package registries.
+//!
+//! Only plain `http://` is supported: registries are expected on localhost or behind a
+//! proxy that terminates TLS. Each request uses its own connection (`Connection: close`).
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+use url::Url;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+/// Largest request body the registry server accepts; larger uploads get `413`.
+#[cfg(test)]
+pub(crate) const MAX_REQUEST_BODY: usize = 64 * 1024 * 1024;
+
+#[derive(Debug)]
+pub(crate) struct Response {
+    pub status: u16,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    pub(crate) fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// The body as text, for error messages.
+    pub(crate) fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).trim().to_string()
+    }
+}
+
+#[cfg(test)]
+#[derive(Debug)]
+pub(crate) struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: Vec<u8>,
+}
+
+/// Send `method url` with an optional body and read the whole response. Response bodies
+/// larger than `limit` bytes are an error; they are read incrementally and never sized up
+/// front from the server's headers.
+pub(crate) fn send(
+    method: &str,
+    url: &str,
+    body: Option<&[u8]>,
+    limit: usize,
+) -> Result<Response, String> {
+    let parsed = Url::parse(url).map_err(|err| format!("invalid URL `{url}`: {err}"))?;
+    if parsed.scheme() != "http" {
+        return Err(format!(
+            "unsupported URL scheme `{}` in `{url}`; registries are reached over http:// or as directories",
+            parsed.scheme()
+        ));
+    }
+    let host = parsed
+        .host_str()
+        .ok_or_else(|| format!("URL `{url}` has no host"))?;
+    let port = parsed.port_or_known_default().unwrap_or(80);
+    let target = match parsed.query() {
+        Some(query) => format!("{}?{query}", parsed.path()),
+        None => parsed.path().to_string(),
+    };
+
+    let io_error = |err: io::Error| format!("{method} {url} failed: {err}");
+    let mut stream = TcpStream::connect((host, port)).map_err(io_error)?;
+    stream.set_read_timeout(Some(TIMEOUT)).map_err(io_error)?;
+    stream.set_write_timeout(Some(TIMEOUT)).map_err(io_error)?;
+    let body = body.unwrap_or_default();
+    write!(
+        stream,
+        "{method} {target} HTTP/1.1\r\nHost: {host}:{port}\r\nUser-Agent: chic\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len()
+    )
+    .map_err(io_error)?;
+    stream.write_all(body).map_err(io_error)?;
+    stream.flush().map_err(io_error)?;
+
+    let mut reader = BufReader::new(stream);
+    let mut status_line = String::new();
+    reader.read_line(&mut status_line).map_err(io_error)?;
+    let status = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| {
+            format!(
+                "{method} {url}: malformed status line `{}`",
+                status_line.trim()
+            )
+        })?;
+    let headers = read_headers(&mut reader).map_err(io_error)?;
+    let body = read_body(&mut reader, &headers, limit).map_err(|err| match err.kind() {
+        io::ErrorKind::FileTooLarge => {
+            format!("{method} {url}: response body exceeds {limit} bytes")
+        }
+        io::ErrorKind::InvalidData => format!("{method} {url}: {err}"),
+        _ => io_error(err),
+    })?;
+    Ok(Response { status, body })
+}
+
+/// Read one request from a client connection. A body declared larger than
+/// [`MAX_REQUEST_BODY`] is answered with `413` and never read.
+#[cfg(test)]
+pub(crate) fn read_request(stream: &mut TcpStream) -> io::Result<Request> {
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "malformed request line",
+        ));
+    };
+    let (method, path) = (method.to_string(), path.to_string());
+    let headers = read_headers(&mut reader)?;
+    let length = header(&headers, "content-length")
+        .and_then(|value| value.parse::<usize>().ok())
+        .unwrap_or(0);
+    if length > MAX_REQUEST_BODY {
+        let message = format!("request body exceeds {MAX_REQUEST_BODY} bytes");
+        write_response(reader.get_mut(), 413, "text/plain", message.as_bytes())?;
+        return Err(io::Error::new(io::ErrorKind::InvalidData, message));
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+    Ok(Request { method, path, body })
+}
+
+#[cfg(test)]
+pub(crate) fn write_response(
+    stream: &mut TcpStream,
+    status: u16,
+    content_type: &str,
+    body: &[u8],
+) -> io::Result<()> {
+    let reason = match status {
+        200 => "OK",
+        201 => "Created",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        409 => "Conflict",
+        413 => "Payload Too Large",
+        _ => "Error",
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len()
+    )?;
+    stream.write_all(body)?;
+    stream.flush()
+}
+
+fn read_headers(reader: &mut impl BufRead) -> io::Result<Vec<(String, String)>> {
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_ascii_lowercase(), value.trim().to_string()));
+        }
+    }
+    Ok(headers)
+}
+
+fn header<'h>(headers: &'h [(String, String)], name: &str) -> Option<&'h str> {
+    headers
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+}
+
+/// Read a response body framed by `headers`, failing with [`io::ErrorKind::FileTooLarge`]
+/// once it exceeds `limit` bytes.
+fn read_body(
+    reader: &mut impl BufRead,
+    headers: &[(String, String)],
+    limit: usize,
+) -> io::Result<Vec<u8>> {
+    if header(headers, "transfer-encoding")
+        .is_some_and(|value| value.eq_ignore_ascii_case("chunked"))
+    {
+        return read_chunked(reader, limit);
+    }
+    let Some(length) = header(headers, "content-length") else {
+        let body = read_limited(reader, limit.saturating_add(1))?;
+        if body.len() > limit {
+            return Err(io::ErrorKind::FileTooLarge.into());
+        }
+        return Ok(body);
+    };
+    let length = length.parse::<usize>().map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid Content-Length `{length}`"),
+        )
+    })?;
+    if length > limit {
+        return Err(io::ErrorKind::FileTooLarge.into());
+    }
+    let body = read_limited(reader, length)?;
+    if body.len() < length {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    Ok(body)
+}
+
+/// Read at most `max` bytes, growing the buffer as data arrives.
+fn read_limited(reader: &mut impl Read, max: usize) -> io::Result<Vec<u8>> {
+    let mut body = Vec::new();
+    reader
+        .take(u64::try_from(max).unwrap_or(u64::MAX))
+        .read_to_end(&mut body)?;
+    Ok(body)
+}
+
+/// Read a chunked body, failing with [`io::ErrorKind::FileTooLarge`] once the chunks
+/// exceed `limit` bytes.
+fn read_chunked(reader: &mut impl BufRead, limit: usize) -> io::Result<Vec<u8>> {
+    let mut body = Vec::new();
+    loop {
+        let mut size_line = String::new();
+        reader.read_line(&mut size_line)?;
+        let size_text = size_line.trim().split(';').next().unwrap_or_default();
+        let size = usize::from_str_radix(size_text, 16)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "malformed chunk size"))?;
+        if size == 0 {
+            // Trailer headers end with an empty line.
+            read_headers(reader)?;
+            return Ok(body);
+        }
+        if size > limit - body.len() {
+            return Err(io::ErrorKind::FileTooLarge.into());
+        }
+        let chunk = read_limited(reader, size)?;
+        if chunk.len() < size {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        body.extend_from_slice(&chunk);
+        let mut crlf = [0; 2];
+        reader.read_exact(&mut crlf)?;
+    }
+}
@@ -1,7 +1,13 @@
+pub mod features;
+mod http;
+pub mod registry;
 pub mod resolver;
+mod solver;
 pub mod version;
 
+pub use features::{FeatureActivation, FeatureRequest};
+pub use registry::{PackedPackage, Registry, RegistryError, RegistryIndex};
 pub use resolver::{
-    ResolveOptions, ResolveOutcome, ResolvedPackage, ResolvedSource, resolve_dependencies,
+    LockMode, ResolveOptions, ResolveOutcome, ResolvedPackage, ResolvedSource, resolve_dependencies,
 };
 pub use version::{Version, VersionParseError, VersionReq, VersionReqError};
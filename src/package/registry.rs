@@ -0,0 +1,625 @@
+//! Package registries: a per-package index of published versions next to the packed
+//! archives, kept in a directory or served over HTTP.
+//!
+//! Both kinds share one layout under the registry root:
+//!
+//! ```text
+//! index/<name>.json              {"name": "Json", "versions": [{"version": "1.0.0",
+//!                                 "checksum": "<blake3 hex>", "yanked": false}]}
+//! packages/<name>/<version>.tar  the archive produced by `chic pack`
+//! ```
+//!
+//! Archives are checked against the blake3 checksum in the index before they are unpacked.
+//! HTTP registries publish with `PUT packages/<name>/<version>.tar` and answer with the new
+//! index entry. The tests serve a directory registry over localhost to stand in for a
+//! remote one.
+
+use std::fmt;
+use std::fs;
+use std::io;
+#[cfg(test)]
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::manifest::{Manifest, PROJECT_MANIFEST_BASENAME};
+use crate::package::http;
+use crate::package::version::Version;
+
+/// Environment variable naming the registry used by dependencies without a `source`.
+pub const REGISTRY_ENV: &str = "CHIC_REGISTRY";
+
+/// Largest index (or publish response) accepted from an HTTP registry.
+const MAX_INDEX_RESPONSE: usize = 16 * 1024 * 1024;
+/// Largest package archive downloaded from an HTTP registry.
+const MAX_ARCHIVE_RESPONSE: usize = 512 * 1024 * 1024;
+
+/// Files at the package root that are packed alongside the manifest and sources.
+const EXTRA_FILES: &[&str] = &["README.md", "LICENSE", "LICENSE.md", "CHANGELOG.md"];
+
+/// Every published version of one package.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegistryIndex {
+    pub name: String,
+    #[serde(default)]
+    pub versions: Vec<IndexEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexEntry {
+    pub version: String,
+    /// blake3 hash of the archive, hex encoded.
+    pub checksum: String,
+    /// Yanked versions stay downloadable for existing locks but are not selected anew.
+    #[serde(default)]
+    pub yanked: bool,
+}
+
+impl RegistryIndex {
+    #[must_use]
+    pub fn entry(&self, version: &Version) -> Option<&IndexEntry> {
+        self.versions
+            .iter()
+            .find(|entry| Version::parse(&entry.version).is_ok_and(|v| &v == version))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Registry {
+    Directory(PathBuf),
+    /// Base URL without a trailing slash.
+    Http(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistryError {
+    message: String,
+}
+
+/// A package packed for publishing.
+#[derive(Debug, Clone)]
+pub struct PackedPackage {
+    pub name: String,
+    pub version: Version,
+    pub archive: Vec<u8>,
+    pub checksum: String,
+}
+
+impl Registry {
+    /// `http://` URLs name HTTP registries; `file://` URLs and plain paths (relative to
+    /// `base_dir`) name directory registries.
+    ///
+    /// # Errors
+    /// Returns an error for other URL schemes and malformed `file://` URLs.
+    pub fn locate(location: &str, base_dir: &Path) -> Result<Self, RegistryError> {
+        let location = location.trim();
+        if location.starts_with("http://") {
+            return Ok(Self::Http(location.trim_end_matches('/').to_string()));
+        }
+        if location.starts_with("file://") {
+            let path = url::Url::parse(location)
+                .ok()
+                .and_then(|url| url.to_file_path().ok())
+                .ok_or_else(|| {
+                    RegistryError::new(format!("invalid registry location `{location}`"))
+                })?;
+            return Ok(Self::Directory(path));
+        }
+        if location.contains("://") {
+            return Err(RegistryError::new(format!(
+                "unsupported registry location `{location}`; use a directory or an http:// URL"
+            )));
+        }
+        Ok(Self::Directory(base_dir.join(location)))
+    }
+
+    /// The index of `name`, or `None` when the registry has never seen the package.
+    ///
+    /// # Errors
+    /// Returns an error when the registry cannot be read or the index is malformed.
+    pub fn index(&self, name: &str) -> Result<Option<RegistryIndex>, RegistryError> {
+        validate_name(name)?;
+        let bytes = match self {
+            Self::Directory(root) => match fs::read(index_path(root, name)) {
+                Ok(bytes) => bytes,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+                Err(err) => {
+                    return Err(RegistryError::new(format!(
+                        "failed to read the index of `{name}` from {}: {err}",
+                        root.display()
+                    )));
+                }
+            },
+            Self::Http(base) => {
+                let url = format!("{base}/index/{name}.json");
+                let response = http::send("GET", &url, None, MAX_INDEX_RESPONSE)
+                    .map_err(RegistryError::new)?;
+                if response.status == 404 {
+                    return Ok(None);
+                }
+                if !response.is_success() {
+                    return Err(RegistryError::new(format!(
+                        "registry {base} answered {} for the index of `{name}`: {}",
+                        response.status,
+                        response.text()
+                    )));
+                }
+                response.body
+            }
+        };
+        serde_json::from_slice(&bytes).map(Some).map_err(|err| {
+            RegistryError::new(format!("malformed registry index for `{name}`: {err}"))
+        })
+    }
+
+    /// Download the archive of `name` at `version`.
+    ///
+    /// # Errors
+    /// Returns an error when the archive cannot be read.
+    pub fn download(&self, name: &str, version: &Version) -> Result<Vec<u8>, RegistryError> {
+        validate_name(name)?;
+        match self {
+            Self::Directory(root) => {
+                let path = archive_path(root, name, version);
+                fs::read(&path).map_err(|err| {
+                    RegistryError::new(format!("failed to read {}: {err}", path.display()))
+                })
+            }
+            Self::Http(base) => {
+                let url = format!("{base}/packages/{name}/{version}.tar");
+                let response = http::send("GET", &url, None, MAX_ARCHIVE_RESPONSE)
+                    .map_err(RegistryError::new)?;
+                if !response.is_success() {
+                    return Err(RegistryError::new(format!(
+                        "registry answered {} for {url}: {}",
+                        response.status,
+                        response.text()
+                    )));
+                }
+                Ok(response.body)
+            }
+        }
+    }
+
+    /// Add `archive` as `name` `version` and return its index entry.
+    ///
+    /// # Errors
+    /// Returns an error when the version is already published or the registry rejects it.
+    pub fn publish(
+        &self,
+        name: &str,
+        version: &Version,
+        archive: &[u8],
+    ) -> Result<IndexEntry, RegistryError> {
+        validate_name(name)?;
+        match self {
+            Self::Directory(root) => publish_to_directory(root, name, version, archive),
+            Self::Http(base) => {
+                let url = format!("{base}/packages/{name}/{version}.tar");
+                let response = http::send("PUT", &url, Some(archive), MAX_INDEX_RESPONSE)
+                    .map_err(RegistryError::new)?;
+                if !response.is_success() {
+                    return Err(RegistryError::new(format!(
+                        "registry rejected {name} {version} ({}): {}",
+                        response.status,
+                        response.text()
+                    )));
+                }
+                serde_json::from_slice(&response.body).map_err(|err| {
+                    RegistryError::new(format!("malformed publish response from {base}: {err}"))
+                })
+            }
+        }
+    }
+}
+
+impl fmt::Display for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Directory(path) => write!(f, "{}", path.display()),
+            Self::Http(url) => write!(f, "{url}"),
+        }
+    }
+}
+
+impl RegistryError {
+    #[must_use]
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+/// blake3 checksum of an archive, hex encoded.
+#[must_use]
+pub fn checksum(bytes: &[u8]) -> String {
+    blake3::hash(bytes).to_hex().to_string()
+}
+
+/// Pack the package described by `manifest`: the manifest, every source root and the usual
+/// top-level documents. Entries are sorted and carry fixed metadata, so identical trees
+/// produce identical archives and checksums.
+///
+/// # Errors
+/// Returns an error when the package has no name or version or its files cannot be read.
+pub fn pack(manifest: &Manifest) -> Result<PackedPackage, RegistryError> {
+    let package = manifest.package();
+    let name = package
+        .and_then(|package| package.name.clone())
+        .ok_or_else(|| RegistryError::new("packing requires `package.name` in manifest.yaml"))?;
+    let version = package
+        .and_then(|package| package.version.clone())
+        .ok_or_else(|| RegistryError::new("packing requires `package.version` in manifest.yaml"))?;
+    let root = manifest
+        .path()
+        .and_then(Path::parent)
+        .ok_or_else(|| RegistryError::new("manifest.yaml has no path"))?;
+
+    let mut files = vec![PathBuf::from(PROJECT_MANIFEST_BASENAME)];
+    for extra in EXTRA_FILES {
+        if root.join(extra).is_file() {
+            files.push(PathBuf::from(extra));
+        }
+    }
+    for source in manifest.source_roots() {
+        let relative = source.path.strip_prefix(root).unwrap_or(&source.path);
+        if relative.is_absolute()
+            || relative
+                .components()
+                .any(|part| matches!(part, std::path::Component::ParentDir))
+        {
+            return Err(RegistryError::new(format!(
+                "source root {} lies outside the package and cannot be packed",
+                source.path.display()
+            )));
+        }
+        collect_files(root, relative, &mut files)?;
+    }
+    files.sort();
+    files.dedup();
+
+    let mut builder = tar::Builder::new(Vec::new());
+    for file in &files {
+        let data = fs::read(root.join(file)).map_err(|err| {
+            RegistryError::new(format!(
+                "failed to read {}: {err}",
+                root.join(file).display()
+            ))
+        })?;
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(0);
+        header.set_entry_type(tar::EntryType::Regular);
+        let entry_path = file.to_string_lossy().replace('\\', "/");
+        builder
+            .append_data(&mut header, &entry_path, data.as_slice())
+            .map_err(|err| RegistryError::new(format!("failed to pack {entry_path}: {err}")))?;
+    }
+    let archive = builder
+        .into_inner()
+        .map_err(|err| RegistryError::new(format!("failed to finish archive: {err}")))?;
+    let checksum = checksum(&archive);
+    Ok(PackedPackage {
+        name,
+        version,
+        archive,
+        checksum,
+    })
+}
+
+/// Unpack an archive produced by [`pack`] into `dest`.
+///
+/// # Errors
+/// Returns an error when the archive is malformed or `dest` cannot be written.
+pub fn unpack(archive: &[u8], dest: &Path) -> Result<(), RegistryError> {
+    fs::create_dir_all(dest)
+        .map_err(|err| RegistryError::new(format!("failed to create {}: {err}", dest.display())))?;
+    tar::Archive::new(archive)
+        .unpack(dest)
+        .map_err(|err| RegistryError::new(format!("failed to unpack archive: {err}")))
+}
+
+/// Serve the directory registry at `root` over HTTP until the listener fails: `GET` reads
+/// index and archive files, `PUT packages/<name>/<version>.tar` publishes.
+///
+/// # Errors
+/// Returns the listener's error.
+#[cfg(test)]
+pub(crate) fn serve(listener: TcpListener, root: PathBuf) -> io::Result<()> {
+    for stream in listener.incoming() {
+        let mut stream = stream?;
+        let Ok(request) = http::read_request(&mut stream) else {
+            continue;
+        };
+        let (status, content_type, body) = respond(&root, &request);
+        let _ = http::write_response(&mut stream, status, content_type, &body);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+fn respond(root: &Path, request: &http::Request) -> (u16, &'static str, Vec<u8>) {
+    const TEXT: &str = "text/plain";
+    let segments: Vec<&str> = request.path.trim_start_matches('/').split('/').collect();
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["index", file]) => {
+            let Some(name) = file.strip_suffix(".json") else {
+                return (404, TEXT, b"not found".to_vec());
+            };
+            if validate_name(name).is_err() {
+                return (400, TEXT, b"invalid package name".to_vec());
+            }
+            match fs::read(index_path(root, name)) {
+                Ok(bytes) => (200, "application/json", bytes),
+                Err(_) => (404, TEXT, b"not found".to_vec()),
+            }
+        }
+        (method @ ("GET" | "PUT"), ["packages", name, file]) => {
+            let Some(version) = file
+                .strip_suffix(".tar")
+                .and_then(|version| Version::parse(version).ok())
+            else {
+                return (400, TEXT, b"invalid package version".to_vec());
+            };
+            if validate_name(name).is_err() {
+                return (400, TEXT, b"invalid package name".to_vec());
+            }
+            if method == "GET" {
+                return match fs::read(archive_path(root, name, &version)) {
+                    Ok(bytes) => (200, "application/x-tar", bytes),
+                    Err(_) => (404, TEXT, b"not found".to_vec()),
+                };
+            }
+            match publish_to_directory(root, name, &version, &request.body) {
+                Ok(entry) => (
+                    201,
+                    "application/json",
+                    serde_json::to_vec(&entry).unwrap_or_default(),
+                ),
+                Err(err) => (409, TEXT, err.to_string().into_bytes()),
+            }
+        }
+        ("GET" | "PUT", _) => (404, TEXT, b"not found".to_vec()),
+        _ => (405, TEXT, b"method not allowed".to_vec()),
+    }
+}
+
+fn publish_to_directory(
+    root: &Path,
+    name: &str,
+    version: &Version,
+    archive: &[u8],
+) -> Result<IndexEntry, RegistryError> {
+    let io_error = |path: &Path, err: io::Error| {
+        RegistryError::new(format!("failed to write {}: {err}", path.display()))
+    };
+    let mut index = Registry::Directory(root.to_path_buf())
+        .index(name)?
+        .unwrap_or_else(|| RegistryIndex {
+            name: name.to_string(),
+            versions: Vec::new(),
+        });
+    if index.entry(version).is_some() {
+        return Err(RegistryError::new(format!(
+            "{name} {version} is already published"
+        )));
+    }
+
+    let archive_path = archive_path(root, name, version);
+    if let Some(parent) = archive_path.parent() {
+        fs::create_dir_all(parent).map_err(|err| io_error(parent, err))?;
+    }
+    fs::write(&archive_path, archive).map_err(|err| io_error(&archive_path, err))?;
+
+    let entry = IndexEntry {
+        version: version.to_string(),
+        checksum: checksum(archive),
+        yanked: false,
+    };
+    index.versions.push(entry.clone());
+    index
+        .versions
+        .sort_by_key(|entry| Version::parse(&entry.version).ok());
+    let index_path = index_path(root, name);
+    if let Some(parent) = index_path.parent() {
+        fs::create_dir_all(parent).map_err(|err| io_error(parent, err))?;
+    }
+    let serialized = serde_json::to_vec_pretty(&index)
+        .map_err(|err| RegistryError::new(format!("failed to encode index: {err}")))?;
+    // Write then rename so readers never observe a partial index.
+    let staging = index_path.with_extension("json.tmp");
+    fs::write(&staging, serialized).map_err(|err| io_error(&staging, err))?;
+    fs::rename(&staging, &index_path).map_err(|err| io_error(&index_path, err))?;
+    Ok(entry)
+}
+
+fn index_path(root: &Path, name: &str) -> PathBuf {
+    root.join("index").join(format!("{name}.json"))
+}
+
+fn archive_path(root: &Path, name: &str, version: &Version) -> PathBuf {
+    root.join("packages")
+        .join(name)
+        .join(format!("{version}.tar"))
+}
+
+/// Package names become file names and URL segments.
+fn validate_name(name: &str) -> Result<(), RegistryError> {
+    let valid = !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '.' | '-' | '_'));
+    if valid {
+        Ok(())
+    } else {
+        Err(RegistryError::new(format!(
+            "`{name}` is not a valid registry package name"
+        )))
+    }
+}
+
+fn collect_files(
+    root: &Path,
+    relative: &Path,
+    files: &mut Vec<PathBuf>,
+) -> Result<(), RegistryError> {
+    let path = root.join(relative);
+    if path.is_file() {
+        files.push(relative.to_path_buf());
+        return Ok(());
+    }
+    let entries = match fs::read_dir(&path) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => {
+            return Err(RegistryError::new(format!(
+                "failed to read {}: {err}",
+                path.display()
+            )));
+        }
+    };
+    for entry in entries.flatten() {
+        let file_name = entry.file_name();
+        if file_name.to_string_lossy().starts_with('.') {
+            continue;
+        }
+        collect_files(root, &relative.join(file_name), files)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn write_package(dir: &Path, version: &str) -> Manifest {
+        fs::create_dir_all(dir.join("src")).expect("create src");
+        fs::write(
+            dir.join("manifest.yaml"),
+            format!(
+                "package:\n  name: Json\n  namespace: Json\n  version: {version}\nsources:\n  - path: src\n"
+            ),
+        )
+        .expect("write manifest");
+        fs::write(dir.join("src").join("Json.ch"), "namespace Json;\n").expect("write source");
+        Manifest::discover(&dir.join("manifest.yaml"))
+            .expect("discover manifest")
+            .expect("manifest")
+    }
+
+    #[test]
+    fn packing_is_reproducible_and_round_trips() {
+        let dir = tempdir().expect("tempdir");
+        let manifest = write_package(&dir.path().join("json"), "1.0.0");
+        let first = pack(&manifest).expect("pack");
+        let second = pack(&manifest).expect("pack again");
+        assert_eq!(first.checksum, second.checksum);
+        assert_eq!(first.checksum, checksum(&first.archive));
+
+        let dest = dir.path().join("unpacked");
+        unpack(&first.archive, &dest).expect("unpack");
+        assert!(dest.join("manifest.yaml").is_file());
+        assert!(dest.join("src").join("Json.ch").is_file());
+    }
+
+    #[test]
+    fn http_and_directory_registries_share_one_layout() {
+        let dir = tempdir().expect("tempdir");
+        let root = dir.path().join("registry");
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+        let url = format!("http://{}", listener.local_addr().expect("addr"));
+        let served = root.clone();
+        std::thread::spawn(move || serve(listener, served));
+
+        let http = Registry::locate(&url, dir.path()).expect("http registry");
+        let local = Registry::locate("registry", dir.path()).expect("directory registry");
+        assert_eq!(local, Registry::Directory(root));
+        assert_eq!(http.index("Json").expect("index"), None);
+
+        let packed = pack(&write_package(&dir.path().join("json"), "1.0.0")).expect("pack");
+        let entry = http
+            .publish(&packed.name, &packed.version, &packed.archive)
+            .expect("publish over http");
+        assert_eq!(entry.checksum, packed.checksum);
+        let duplicate = http.publish(&packed.name, &packed.version, &packed.archive);
+        assert!(
+            duplicate
+                .as_ref()
+                .is_err_and(|err| err.to_string().contains("already published")),
+            "{duplicate:?}"
+        );
+
+        let index = local.index("Json").expect("read index").expect("index");
+        assert_eq!(index.versions, vec![entry]);
+        assert_eq!(
+            http.download("Json", &packed.version).expect("download"),
+            packed.archive
+        );
+        assert!(local.index("../etc").is_err());
+    }
+
+    #[test]
+    fn oversized_uploads_are_rejected_unread() {
+        use std::io::{Read, Write};
+
+        let dir = tempdir().expect("tempdir");
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        let served = dir.path().join("registry");
+        std::thread::spawn(move || serve(listener, served));
+
+        let mut stream = std::net::TcpStream::connect(addr).expect("connect");
+        write!(
+            stream,
+            "PUT /packages/Json/1.0.0.tar HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            http::MAX_REQUEST_BODY + 1
+        )
+        .expect("send headers");
+        let mut response = String::new();
+        stream.read_to_string(&mut response).expect("read response");
+        assert!(response.starts_with("HTTP/1.1 413 "), "{response}");
+        assert!(!dir.path().join("registry").join("packages").exists());
+    }
+
+    #[test]
+    fn oversized_responses_are_rejected_without_allocating() {
+        use std::io::Write;
+
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+        let url = format!("http://{}", listener.local_addr().expect("addr"));
+        std::thread::spawn(move || {
+            let heads = [
+                "HTTP/1.1 200 OK\r\nContent-Length: 18446744073709551615\r\n\r\n",
+                "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\nFFFFFFFFFFFF\r\n",
+            ];
+            for head in heads {
+                let (mut stream, _) = listener.accept().expect("accept");
+                let _ = http::read_request(&mut stream);
+                let _ = stream.write_all(head.as_bytes());
+            }
+        });
+
+        let registry = Registry::locate(&url, Path::new(".")).expect("http registry");
+        for _ in 0..2 {
+            let err = registry.index("Json").expect_err("oversized index");
+            assert!(
+                err.to_string()
+                    .contains(&format!("exceeds {MAX_INDEX_RESPONSE} bytes")),
+                "{err}"
+            );
+        }
+    }
+}
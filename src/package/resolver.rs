@@ -1,8 +1,11 @@
 use crate::frontend::diagnostics::{Diagnostic, DiagnosticCode};
 use crate::manifest::{Dependency, DependencySource, Manifest, PROJECT_MANIFEST_BASENAME};
+use crate::package::features::{self, FeatureActivation, FeatureRequest};
+use crate::package::registry::{self, REGISTRY_ENV, Registry};
+use crate::package::solver::{self, VersionSource};
 use crate::package::version::{Version, VersionReq};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::env;
 use std::fs;
 use std::hash::{Hash, Hasher};
@@ -14,6 +17,12 @@ const PKG_RESOLVE_CONFLICT: &str = "PKG2002";
 const PKG_RESOLVE_MISSING: &str = "PKG2003";
 const PKG_RESOLVE_OFFLINE: &str = "PKG2004";
 const PKG_RESOLVE_VERSION: &str = "PKG2005";
+const PKG_RESOLVE_LOCKED: &str = "PKG2006";
+const PKG_RESOLVE_CHECKSUM: &str = "PKG2007";
+const PKG_RESOLVE_FEATURE: &str = "PKG2008";
+
+/// Records the checksum of the archive a cached registry package was unpacked from.
+const CHECKSUM_FILE: &str = ".chic-checksum";
 
 #[derive(Debug, Clone)]
 pub struct ResolvedPackage {
@@ -22,6 +31,8 @@ pub struct ResolvedPackage {
     pub manifest: Manifest,
     pub root: PathBuf,
     pub source: ResolvedSource,
+    /// Features enabled for this package across every dependent that requests it.
+    pub features: BTreeSet<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -37,14 +48,38 @@ pub enum ResolvedSource {
     },
     Registry {
         registry: Option<String>,
+        /// blake3 checksum of the archive the package was unpacked from, when known.
+        checksum: Option<String>,
     },
 }
 
+/// How an existing `manifest.lock` constrains resolution.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum LockMode {
+    /// Reuse the locked git commits and registry versions that still match the manifest;
+    /// entries that no longer match are re-resolved and the lock is rewritten.
+    #[default]
+    Prefer,
+    /// Fail with `PKG2006` when the lock is missing or disagrees with the manifest. The
+    /// lock is never rewritten (`--locked`).
+    Locked,
+    /// Ignore the lock entries of the named packages, or of every package when the list is
+    /// empty (`chic update [pkg]`).
+    Update(Vec<String>),
+    /// Pin to the lock when there is one, but never rewrite it, fetch, clone or move a
+    /// cached checkout; only what the offline cache already holds is loaded (editor
+    /// analysis).
+    ReadOnly,
+}
+
 #[derive(Debug, Clone)]
 pub struct ResolveOptions {
     pub offline: bool,
     pub cache_dir: Option<PathBuf>,
     pub lockfile: Option<PathBuf>,
+    pub lock_mode: LockMode,
+    /// Features requested of the root package.
+    pub features: FeatureRequest,
 }
 
 impl ResolveOptions {
@@ -56,8 +91,22 @@ impl ResolveOptions {
                 .unwrap_or(false),
             cache_dir: env::var_os("CHIC_PACKAGE_CACHE").map(PathBuf::from),
             lockfile,
+            lock_mode: LockMode::Prefer,
+            features: FeatureRequest::defaults(),
         }
     }
+
+    #[must_use]
+    pub fn with_lock_mode(mut self, lock_mode: LockMode) -> Self {
+        self.lock_mode = lock_mode;
+        self
+    }
+
+    #[must_use]
+    pub fn with_features(mut self, features: FeatureRequest) -> Self {
+        self.features = features;
+        self
+    }
 }
 
 #[derive(Debug, Default)]
@@ -69,19 +118,95 @@ pub struct ResolveOutcome {
 struct ResolveCtx<'a> {
     manifest_dir: &'a Path,
     options: &'a ResolveOptions,
-    resolved: HashMap<String, ResolvedPackage>,
-    resolving: HashSet<String>,
     diagnostics: Vec<Diagnostic>,
     cache_dir: PathBuf,
+    /// Lock entries resolution may pin to, keyed by package name.
+    pins: HashMap<String, LockedPackage>,
 }
 
 impl<'a> ResolveCtx<'a> {
+    fn offline(&self) -> bool {
+        self.options.offline || self.read_only()
+    }
+
+    fn read_only(&self) -> bool {
+        self.options.lock_mode == LockMode::ReadOnly
+    }
+
     fn resolver_error(&self, code: &'static str, message: impl Into<String>) -> Diagnostic {
         Diagnostic::error(message, None).with_code(DiagnosticCode::new(
             code.to_string(),
             Some("package".into()),
         ))
     }
+
+    fn resolver_warning(&self, code: &'static str, message: impl Into<String>) -> Diagnostic {
+        Diagnostic::warning(message, None).with_code(DiagnosticCode::new(
+            code.to_string(),
+            Some("package".into()),
+        ))
+    }
+
+    /// The locked commit for a git dependency whose coordinates match the lock entry.
+    fn pinned_commit(&self, name: &str, source: &LockedSource) -> Option<String> {
+        let pin = self.pins.get(name)?;
+        match (&pin.source, source) {
+            (
+                LockedSource::Git {
+                    repo,
+                    rev,
+                    branch,
+                    tag,
+                    subdir,
+                    commit,
+                },
+                LockedSource::Git {
+                    repo: want_repo,
+                    rev: want_rev,
+                    branch: want_branch,
+                    tag: want_tag,
+                    subdir: want_subdir,
+                    ..
+                },
+            ) if (repo, rev, branch, tag, subdir)
+                == (want_repo, want_rev, want_branch, want_tag, want_subdir) =>
+            {
+                Some(commit.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// The locked version of a registry dependency that still satisfies `requirement`.
+    fn pinned_version(
+        &self,
+        name: &str,
+        registry: Option<&String>,
+        requirement: &VersionReq,
+    ) -> Option<Version> {
+        let pin = self.pins.get(name)?;
+        let LockedSource::Registry {
+            registry: locked_registry,
+            ..
+        } = &pin.source
+        else {
+            return None;
+        };
+        if locked_registry.as_ref() != registry {
+            return None;
+        }
+        Version::parse(&pin.version)
+            .ok()
+            .filter(|version| requirement.matches(version))
+    }
+
+    /// The archive checksum the lock records for a registry dependency.
+    fn pinned_checksum(&self, name: &str) -> Option<&str> {
+        match &self.pins.get(name)?.source {
+            LockedSource::Registry { checksum, .. } => checksum.as_deref(),
+            _ => None,
+        }
+    }
 }
 
 pub fn resolve_dependencies(
@@ -101,88 +226,482 @@ pub fn resolve_dependencies(
     };
 
     let cache_dir = options.cache_dir.clone().unwrap_or_else(default_cache_dir);
+    let mut diagnostics = Vec::new();
+    let previous = match options.lockfile.as_deref().map(read_lockfile) {
+        Some(Ok(previous)) => previous,
+        Some(Err(message)) => {
+            let diagnostic = if options.lock_mode == LockMode::Locked {
+                Diagnostic::error(message, None)
+            } else {
+                Diagnostic::warning(format!("{message}; resolving from scratch"), None)
+            };
+            diagnostics.push(diagnostic.with_code(DiagnosticCode::new(
+                PKG_RESOLVE_LOCKED.to_string(),
+                Some("package".into()),
+            )));
+            None
+        }
+        None => None,
+    };
+    let pins = previous
+        .iter()
+        .flat_map(|lock| lock.packages.iter())
+        .filter(|pkg| match &options.lock_mode {
+            LockMode::Prefer | LockMode::Locked | LockMode::ReadOnly => true,
+            LockMode::Update(names) => !names.is_empty() && !names.contains(&pkg.name),
+        })
+        .map(|pkg| (pkg.name.clone(), pkg.clone()))
+        .collect();
 
     let mut ctx = ResolveCtx {
         manifest_dir,
         options,
-        resolved: HashMap::new(),
-        resolving: HashSet::new(),
-        diagnostics: Vec::new(),
+        diagnostics,
         cache_dir,
+        pins,
     };
 
-    let mut root_name: Option<String> = None;
-    if let Some(package) = manifest.package() {
-        if let Some(name) = package.name.as_ref() {
-            let version = package
-                .version
-                .clone()
-                .unwrap_or_else(|| Version::new(0, 0, 0));
-            root_name = Some(name.clone());
-            ctx.resolved.insert(
-                name.clone(),
-                ResolvedPackage {
-                    name: name.clone(),
-                    version,
-                    manifest: manifest.clone(),
-                    root: manifest_dir.to_path_buf(),
-                    source: ResolvedSource::Path,
-                },
+    let root = manifest.package();
+    let root_name = root
+        .and_then(|package| package.name.clone())
+        .unwrap_or_else(|| String::from("<root>"));
+    let root_version = root
+        .and_then(|package| package.version.clone())
+        .unwrap_or_else(|| Version::new(0, 0, 0));
+    let mut source = SolverSource {
+        ctx: &mut ctx,
+        root: root_name.clone(),
+        root_manifest: manifest,
+        declared: HashMap::new(),
+        candidates: HashMap::new(),
+        manifests: HashMap::new(),
+        enabled_optional: HashMap::new(),
+    };
+    let mut packages = Vec::new();
+    // Features may pull optional dependencies into the graph, which can change the
+    // selection; re-solve until the enabled set stops growing.
+    let solved = loop {
+        let selection = match solver::solve(&mut source, &root_name, &root_version) {
+            Ok(selection) => selection,
+            Err(explanation) => break Err(explanation),
+        };
+        let activations = resolve_features(&mut source, &selection, &options.features);
+        let mut grew = false;
+        for (name, resolved) in &activations {
+            let enabled = source.enabled_optional.entry(name.clone()).or_default();
+            for dependency in &resolved.activation.optional_dependencies {
+                grew |= enabled.insert(dependency.clone());
+            }
+        }
+        if !grew {
+            break Ok((selection, activations));
+        }
+    };
+    match solved {
+        Ok((selection, activations)) => {
+            for (name, version) in selection {
+                let resolved = activations.get(&name);
+                if let Some(resolved) = resolved {
+                    for (feature, requester) in &resolved.unknown {
+                        let diagnostic = source.ctx.resolver_error(
+                            PKG_RESOLVE_FEATURE,
+                            format!(
+                                "`{requester}` enables feature `{feature}` of `{name}`, which does not declare it"
+                            ),
+                        );
+                        source.ctx.diagnostics.push(diagnostic);
+                    }
+                }
+                if name != root_name {
+                    packages.extend(source.package(&name, &version).map(|mut package| {
+                        if let Some(resolved) = resolved {
+                            package.features = resolved.activation.features.clone();
+                        }
+                        package
+                    }));
+                }
+            }
+        }
+        Err(explanation) => {
+            let mut diagnostic = source.ctx.resolver_error(
+                PKG_RESOLVE_CONFLICT,
+                "no set of dependency versions satisfies every requirement",
+            );
+            for line in explanation {
+                diagnostic.add_note(line);
+            }
+            source.ctx.diagnostics.push(diagnostic);
+            // Path and git checkouts are still loaded so `@package` imports report the
+            // mismatch against the version actually on disk.
+            packages.extend(
+                source
+                    .candidates
+                    .values()
+                    .filter_map(|candidates| match candidates {
+                        Candidates::Fixed(package) => Some(package.clone()),
+                        _ => None,
+                    }),
             );
         }
     }
-
-    for dep in manifest.dependencies() {
-        resolve_dependency(dep.clone(), None, &mut ctx);
+    packages.sort_by(|a, b| a.name.cmp(&b.name));
+    if let Some(name) = find_cycle(&packages) {
+        ctx.diagnostics.push(ctx.resolver_error(
+            PKG_RESOLVE_CYCLE,
+            format!("detected dependency cycle involving `{name}`"),
+        ));
     }
-
-    if let Some(name) = &root_name {
-        ctx.resolved.remove(name);
+    let mut diagnostics = ctx.diagnostics;
+
+    if let LockMode::Update(names) = &options.lock_mode {
+        for name in names {
+            if !packages.iter().any(|pkg| &pkg.name == name) {
+                diagnostics.push(
+                    Diagnostic::error(
+                        format!("cannot update `{name}`: it is not a dependency of this package"),
+                        None,
+                    )
+                    .with_code(DiagnosticCode::new(
+                        PKG_RESOLVE_MISSING.to_string(),
+                        Some("package".into()),
+                    )),
+                );
+            }
+        }
     }
 
-    let mut packages: Vec<_> = ctx.resolved.into_values().collect();
-    packages.sort_by(|a, b| a.name.cmp(&b.name));
-
     if let Some(lockfile) = options.lockfile.as_ref() {
-        write_lockfile(lockfile, &packages);
+        if options.lock_mode == LockMode::Locked {
+            // An unreadable lock has already been reported above.
+            if previous.is_some() || !lockfile.exists() {
+                diagnostics.extend(check_locked(lockfile, previous.as_ref(), &packages));
+            }
+        } else if options.lock_mode != LockMode::ReadOnly
+            && !diagnostics.iter().any(|diag| diag.severity.is_error())
+        {
+            write_lockfile(lockfile, &packages);
+        }
     }
 
     ResolveOutcome {
         packages,
-        diagnostics: ctx.diagnostics,
+        diagnostics,
     }
 }
 
-fn resolve_dependency(
-    dep: Dependency,
-    _parent: Option<&str>,
-    ctx: &mut ResolveCtx<'_>,
-) -> Option<ResolvedPackage> {
-    if let Some(existing) = ctx.resolved.get(&dep.name) {
-        if let Some(req) = &dep.requirement {
-            if !req.matches(&existing.version) {
-                ctx.diagnostics.push(ctx.resolver_error(
-                    PKG_RESOLVE_CONFLICT,
-                    format!(
-                        "dependency `{}` resolved to {}, which does not satisfy requirement `{}`",
-                        dep.name,
-                        existing.version,
-                        fmt_req(req)
-                    ),
-                ));
+/// Diagnose every difference between the existing lock and the lock `packages` would write.
+fn check_locked(
+    lockfile: &Path,
+    previous: Option<&Lockfile>,
+    packages: &[ResolvedPackage],
+) -> Vec<Diagnostic> {
+    let locked_error = |message: String| {
+        let mut diagnostic = Diagnostic::error(message, None).with_code(DiagnosticCode::new(
+            PKG_RESOLVE_LOCKED.to_string(),
+            Some("package".into()),
+        ));
+        diagnostic.add_note("run `chic update` to refresh manifest.lock, or drop `--locked`");
+        diagnostic
+    };
+    let Some(previous) = previous else {
+        return vec![locked_error(format!(
+            "`--locked` requires {}, which does not exist",
+            lockfile.display()
+        ))];
+    };
+    let lockfile_dir = lockfile.parent();
+    let current: Vec<LockedPackage> = packages
+        .iter()
+        .map(|pkg| LockedPackage::from_resolved(pkg, lockfile_dir))
+        .collect();
+    let mut diagnostics = Vec::new();
+    for pkg in &current {
+        match previous.packages.iter().find(|old| old.name == pkg.name) {
+            None => diagnostics.push(locked_error(format!(
+                "`{}` {} is missing from manifest.lock",
+                pkg.name,
+                pkg.describe()
+            ))),
+            Some(old) if old != pkg => diagnostics.push(locked_error(format!(
+                "manifest.lock pins `{}` {}, but the manifest resolves {}",
+                pkg.name,
+                old.describe(),
+                pkg.describe()
+            ))),
+            Some(_) => {}
+        }
+    }
+    for old in &previous.packages {
+        if !current.iter().any(|pkg| pkg.name == old.name) {
+            diagnostics.push(locked_error(format!(
+                "manifest.lock pins `{}`, which is no longer a dependency",
+                old.name
+            )));
+        }
+    }
+    diagnostics
+}
+
+/// Candidate versions of a dependency, loaded the first time the solver asks about it.
+enum Candidates {
+    /// Path and git sources offer exactly the version they check out.
+    Fixed(ResolvedPackage),
+    /// Every version the registry index (or, offline, the cache) offers; archives are
+    /// fetched and manifests read once a version is considered.
+    Registry {
+        registry: Option<String>,
+        base: PathBuf,
+        versions: Vec<Version>,
+        pinned: Option<Version>,
+        /// The registry to fetch missing versions from; `None` when working from the cache.
+        remote: Option<Registry>,
+        /// Index checksums keyed by version.
+        checksums: HashMap<String, String>,
+    },
+    /// Loading failed and has already been diagnosed.
+    Unavailable,
+}
+
+/// A package's activated features, with the first dependent to request each unknown one.
+struct PackageFeatures {
+    activation: FeatureActivation,
+    unknown: BTreeMap<String, String>,
+}
+
+/// Propagate feature requests from the root along the selected dependency edges. Each
+/// package gets the union of what its dependents ask for, and its defaults unless every
+/// dependent opts out with `default_features: false`.
+fn resolve_features(
+    source: &mut SolverSource<'_, '_>,
+    selection: &BTreeMap<String, Version>,
+    root_request: &FeatureRequest,
+) -> BTreeMap<String, PackageFeatures> {
+    let root = source.root.clone();
+    let mut requests = BTreeMap::from([(root.clone(), root_request.clone())]);
+    let mut requesters: BTreeMap<(String, String), String> = BTreeMap::new();
+    let mut pending = vec![root];
+    let mut activations = BTreeMap::new();
+    while let Some(name) = pending.pop() {
+        let Some(manifest) = selection
+            .get(&name)
+            .and_then(|version| source.manifest(&name, version))
+        else {
+            continue;
+        };
+        let activation = features::activate(&manifest, &requests[&name]);
+        for dep in manifest.dependencies() {
+            if !selection.contains_key(&dep.name)
+                || (dep.optional && !activation.optional_dependencies.contains(&dep.name))
+            {
+                continue;
+            }
+            let wanted = dep.features.iter().chain(
+                activation
+                    .dependency_features
+                    .get(&dep.name)
+                    .into_iter()
+                    .flatten(),
+            );
+            let request = requests.entry(dep.name.clone()).or_default();
+            let mut changed = dep.default_features && !request.default_features;
+            request.default_features |= dep.default_features;
+            for feature in wanted {
+                if request.features.insert(feature.clone()) {
+                    changed = true;
+                    requesters
+                        .entry((dep.name.clone(), feature.clone()))
+                        .or_insert_with(|| name.clone());
+                }
+            }
+            if changed || !activations.contains_key(&dep.name) {
+                pending.push(dep.name.clone());
             }
         }
-        return Some(existing.clone());
+        activations.insert(name, activation);
     }
+    activations
+        .into_iter()
+        .map(|(name, activation): (String, FeatureActivation)| {
+            let unknown = activation
+                .unknown
+                .iter()
+                .map(|feature| {
+                    let requester = requesters
+                        .get(&(name.clone(), feature.clone()))
+                        .cloned()
+                        .unwrap_or_else(|| name.clone());
+                    (feature.clone(), requester)
+                })
+                .collect();
+            (
+                name,
+                PackageFeatures {
+                    activation,
+                    unknown,
+                },
+            )
+        })
+        .collect()
+}
 
-    if !ctx.resolving.insert(dep.name.clone()) {
-        ctx.diagnostics.push(ctx.resolver_error(
-            PKG_RESOLVE_CYCLE,
-            format!("detected dependency cycle involving `{}`", dep.name),
-        ));
-        return None;
+/// Feeds the version solver from the dependency sources declared in manifests. Each package
+/// is fetched from the first source declared for it.
+struct SolverSource<'c, 'a> {
+    ctx: &'c mut ResolveCtx<'a>,
+    root: String,
+    root_manifest: &'c Manifest,
+    declared: HashMap<String, Dependency>,
+    candidates: HashMap<String, Candidates>,
+    manifests: HashMap<(String, String), Option<Manifest>>,
+    /// Optional dependencies switched on by each package's features.
+    enabled_optional: HashMap<String, BTreeSet<String>>,
+}
+
+impl SolverSource<'_, '_> {
+    fn candidates(&mut self, name: &str) -> &Candidates {
+        if !self.candidates.contains_key(name) {
+            let loaded = match self.declared.get(name).cloned() {
+                Some(dep) => load_candidates(dep, self.ctx),
+                None => Candidates::Unavailable,
+            };
+            self.candidates.insert(name.to_string(), loaded);
+        }
+        &self.candidates[name]
+    }
+
+    fn manifest(&mut self, name: &str, version: &Version) -> Option<Manifest> {
+        if name == self.root {
+            return Some(self.root_manifest.clone());
+        }
+        let key = (name.to_string(), version.to_string());
+        if let Some(manifest) = self.manifests.get(&key) {
+            return manifest.clone();
+        }
+        let manifest = match self.candidates(name) {
+            Candidates::Fixed(package) => Some(package.manifest.clone()),
+            Candidates::Registry {
+                base,
+                remote,
+                checksums,
+                ..
+            } => {
+                let root = base.join(version.to_string());
+                let (remote, expected) =
+                    (remote.clone(), checksums.get(&version.to_string()).cloned());
+                let (ctx, name) = (&mut *self.ctx, name.to_string());
+                restore_registry_version(
+                    &name,
+                    version,
+                    &root,
+                    remote.as_ref(),
+                    expected.as_deref(),
+                    ctx,
+                )
+                .then(|| load_manifest(&root, ctx))
+                .flatten()
+            }
+            Candidates::Unavailable => None,
+        };
+        self.manifests.insert(key, manifest.clone());
+        manifest
+    }
+
+    /// The resolved package for the version the solver selected.
+    fn package(&mut self, name: &str, version: &Version) -> Option<ResolvedPackage> {
+        let manifest = self.manifest(name, version)?;
+        let dep = self.declared.get(name)?.clone();
+        let package = match self.candidates(name) {
+            Candidates::Fixed(package) => Some(package.clone()),
+            Candidates::Registry {
+                registry,
+                base,
+                checksums,
+                ..
+            } => {
+                let root = base.join(version.to_string());
+                let checksum = checksums.get(&version.to_string()).cloned().or_else(|| {
+                    fs::read_to_string(root.join(CHECKSUM_FILE))
+                        .ok()
+                        .map(|text| text.trim().to_string())
+                });
+                build_resolved_package(
+                    dep,
+                    root,
+                    manifest,
+                    ResolvedSource::Registry {
+                        registry: registry.clone(),
+                        checksum,
+                    },
+                )
+            }
+            Candidates::Unavailable => None,
+        }?;
+        if std::env::var_os("CHIC_DEBUG_PACKAGE_TRIM").is_some() {
+            let manifest_path = package
+                .manifest
+                .path()
+                .map(|p| p.display().to_string())
+                .unwrap_or_else(|| "<none>".to_string());
+            eprintln!(
+                "[chic-debug] resolved dependency {} -> {} (root {})",
+                name,
+                manifest_path,
+                package.root.display()
+            );
+        }
+        Some(package)
+    }
+}
+
+impl VersionSource for SolverSource<'_, '_> {
+    fn versions(&mut self, package: &str) -> Vec<Version> {
+        match self.candidates(package) {
+            Candidates::Fixed(resolved) => vec![resolved.version.clone()],
+            Candidates::Registry { versions, .. } => versions.clone(),
+            Candidates::Unavailable => Vec::new(),
+        }
     }
 
+    fn dependencies(&mut self, package: &str, version: &Version) -> Vec<(String, VersionReq)> {
+        let Some(manifest) = self.manifest(package, version) else {
+            return Vec::new();
+        };
+        let mut dependencies = Vec::new();
+        for dep in manifest.dependencies() {
+            if dep.optional
+                && !self
+                    .enabled_optional
+                    .get(package)
+                    .is_some_and(|enabled| enabled.contains(&dep.name))
+            {
+                continue;
+            }
+            self.declared
+                .entry(dep.name.clone())
+                .or_insert_with(|| dep.clone());
+            // Sources that failed to load are already diagnosed; don't pile conflicts on top.
+            if dep.name != self.root
+                && matches!(self.candidates(&dep.name), Candidates::Unavailable)
+            {
+                continue;
+            }
+            dependencies.push((
+                dep.name.clone(),
+                dep.requirement.clone().unwrap_or_else(VersionReq::any),
+            ));
+        }
+        dependencies
+    }
+
+    fn preferred(&mut self, package: &str) -> Option<Version> {
+        match self.candidates(package) {
+            Candidates::Registry { pinned, .. } => pinned.clone(),
+            _ => None,
+        }
+    }
+}
+
+fn load_candidates(dep: Dependency, ctx: &mut ResolveCtx<'_>) -> Candidates {
     let resolved = match &dep.source {
         DependencySource::Path(path) => resolve_path_dependency(path, dep.clone(), ctx),
         DependencySource::Git {
@@ -201,49 +720,41 @@ fn resolve_dependency(
             ctx,
         ),
         DependencySource::Registry { registry } => {
-            resolve_registry_dependency(dep.clone(), registry.clone(), ctx)
+            return load_registry_candidates(dep.clone(), registry.clone(), ctx);
         }
     };
+    resolved.map_or(Candidates::Unavailable, Candidates::Fixed)
+}
 
-    let Some(package) = resolved else {
-        ctx.resolving.remove(&dep.name);
-        return None;
-    };
-
-    if let Some(req) = &dep.requirement {
-        if !req.matches(&package.version) {
-            ctx.diagnostics.push(ctx.resolver_error(
-                PKG_RESOLVE_VERSION,
-                format!(
-                    "dependency `{}` resolved to {}, which does not satisfy requirement `{}`",
-                    dep.name,
-                    package.version,
-                    fmt_req(req)
-                ),
-            ));
+/// Name of a package that (transitively) depends on itself, if any.
+fn find_cycle(packages: &[ResolvedPackage]) -> Option<String> {
+    fn visit<'p>(
+        name: &'p str,
+        packages: &'p [ResolvedPackage],
+        stack: &mut Vec<&'p str>,
+        done: &mut HashSet<&'p str>,
+    ) -> Option<String> {
+        if stack.contains(&name) {
+            return Some(name.to_string());
         }
+        if !done.insert(name) {
+            return None;
+        }
+        let package = packages.iter().find(|pkg| pkg.name == name)?;
+        stack.push(name);
+        for dep in package.manifest.dependencies() {
+            if let Some(cycle) = visit(&dep.name, packages, stack, done) {
+                return Some(cycle);
+            }
+        }
+        stack.pop();
+        None
     }
 
-    for child in package.manifest.dependencies().iter().cloned() {
-        resolve_dependency(child, Some(&package.name), ctx);
-    }
-
-    ctx.resolving.remove(&dep.name);
-    if std::env::var_os("CHIC_DEBUG_PACKAGE_TRIM").is_some() {
-        let manifest_path = package
-            .manifest
-            .path()
-            .map(|p| p.display().to_string())
-            .unwrap_or_else(|| "<none>".to_string());
-        eprintln!(
-            "[chic-debug] resolved dependency {} -> {} (root {})",
-            dep.name,
-            manifest_path,
-            package.root.display()
-        );
-    }
-    ctx.resolved.insert(dep.name.clone(), package.clone());
-    Some(package)
+    let mut done = HashSet::new();
+    packages
+        .iter()
+        .find_map(|pkg| visit(&pkg.name, packages, &mut Vec::new(), &mut done))
 }
 
 fn resolve_path_dependency(
@@ -280,7 +791,9 @@ fn resolve_git_dependency(
     ctx: &mut ResolveCtx<'_>,
 ) -> Option<ResolvedPackage> {
     let git_dir = ctx.cache_dir.join("git");
-    if let Err(err) = fs::create_dir_all(&git_dir) {
+    if !ctx.read_only()
+        && let Err(err) = fs::create_dir_all(&git_dir)
+    {
         ctx.diagnostics.push(ctx.resolver_error(
             PKG_RESOLVE_MISSING,
             format!(
@@ -297,8 +810,19 @@ fn resolve_git_dependency(
         rev.or(branch).or(tag).unwrap_or("head")
     );
     let checkout_dir = git_dir.join(sanitize_component(&key));
+    let pinned = ctx.pinned_commit(
+        &dep.name,
+        &LockedSource::Git {
+            repo: repo.to_string(),
+            rev: rev.map(str::to_string),
+            branch: branch.map(str::to_string),
+            tag: tag.map(str::to_string),
+            commit: String::new(),
+            subdir: subdir.and_then(|path| path.to_str().map(str::to_string)),
+        },
+    );
     if !checkout_dir.exists() {
-        if ctx.options.offline {
+        if ctx.offline() {
             ctx.diagnostics.push(ctx.resolver_error(
                 PKG_RESOLVE_OFFLINE,
                 format!(
@@ -328,7 +852,11 @@ fn resolve_git_dependency(
             ));
             return None;
         }
-    } else if !ctx.options.offline {
+    } else if !ctx.offline()
+        && !pinned
+            .as_deref()
+            .is_some_and(|commit| has_commit(&checkout_dir, commit))
+    {
         let _ = Command::new("git")
             .arg("-C")
             .arg(&checkout_dir)
@@ -336,18 +864,47 @@ fn resolve_git_dependency(
             .status();
     }
 
-    if let Some(rev) = rev {
+    if ctx.read_only() {
+        // Use the cached checkout as it stands.
+    } else if let Some(commit) = pinned.as_deref() {
+        let checked_out = Command::new("git")
+            .arg("-C")
+            .arg(&checkout_dir)
+            .args(["checkout", "--detach", commit])
+            .status()
+            .is_ok_and(|status| status.success());
+        if !checked_out {
+            ctx.diagnostics.push(ctx.resolver_error(
+                PKG_RESOLVE_MISSING,
+                format!(
+                    "commit {commit} of `{repo}` pinned in manifest.lock is not available; run `chic update {}` to re-resolve it",
+                    dep.name
+                ),
+            ));
+            return None;
+        }
+    } else if let Some(rev) = rev {
         let _ = Command::new("git")
             .arg("-C")
             .arg(&checkout_dir)
             .args(["checkout", rev])
             .status();
     } else if let Some(branch) = branch {
-        let _ = Command::new("git")
+        // Follow the fetched remote branch; the local branch only moves on clone.
+        let remote = Command::new("git")
             .arg("-C")
             .arg(&checkout_dir)
-            .args(["checkout", branch])
-            .status();
+            .args(["checkout", "--detach", &format!("origin/{branch}")])
+            .stderr(std::process::Stdio::null())
+            .status()
+            .is_ok_and(|status| status.success());
+        if !remote {
+            let _ = Command::new("git")
+                .arg("-C")
+                .arg(&checkout_dir)
+                .args(["checkout", branch])
+                .status();
+        }
     } else if let Some(tag) = tag {
         let _ = Command::new("git")
             .arg("-C")
@@ -384,13 +941,13 @@ fn resolve_git_dependency(
     build_resolved_package(dep, resolved_root, manifest, source)
 }
 
-fn resolve_registry_dependency(
+fn load_registry_candidates(
     dep: Dependency,
     registry: Option<String>,
     ctx: &mut ResolveCtx<'_>,
-) -> Option<ResolvedPackage> {
+) -> Candidates {
     let base = ctx.cache_dir.join("registry").join(&dep.name);
-    if dep.requirement.is_none() {
+    let Some(requirement) = dep.requirement.as_ref() else {
         ctx.diagnostics.push(ctx.resolver_error(
             PKG_RESOLVE_VERSION,
             format!(
@@ -398,33 +955,179 @@ fn resolve_registry_dependency(
                 dep.name
             ),
         ));
-        return None;
-    }
+        return Candidates::Unavailable;
+    };
 
-    let requirement = dep.requirement.clone().unwrap();
-    let versions = find_cached_versions(&base);
-    let candidate = versions
-        .iter()
-        .filter(|version| requirement.matches(version))
-        .max()
-        .cloned();
+    let pinned = ctx.pinned_version(&dep.name, registry.as_ref(), requirement);
+    let mut versions = find_cached_versions(&base);
+    let mut remote = None;
+    let mut checksums = HashMap::new();
+    let location = registry.clone().or_else(|| {
+        env::var(REGISTRY_ENV)
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+    });
+    if let Some(location) = location.filter(|_| !ctx.offline()) {
+        let index = Registry::locate(&location, ctx.manifest_dir)
+            .and_then(|source| Ok((source.index(&dep.name)?, source)));
+        match index {
+            Ok((Some(index), source)) => {
+                versions.clear();
+                for entry in index.versions {
+                    let Ok(version) = Version::parse(&entry.version) else {
+                        continue;
+                    };
+                    // Yanked versions are only kept for locks that already pin them.
+                    if !entry.yanked || pinned.as_ref() == Some(&version) {
+                        versions.push(version.clone());
+                    }
+                    checksums.insert(version.to_string(), entry.checksum);
+                }
+                remote = Some(source);
+            }
+            Ok((None, source)) => {
+                ctx.diagnostics.push(ctx.resolver_error(
+                    PKG_RESOLVE_MISSING,
+                    format!("`{}` is not published in registry {source}", dep.name),
+                ));
+                return Candidates::Unavailable;
+            }
+            Err(err) => {
+                ctx.diagnostics.push(ctx.resolver_warning(
+                    PKG_RESOLVE_OFFLINE,
+                    format!(
+                        "registry {location} is unavailable ({err}); using cached versions of `{}`",
+                        dep.name
+                    ),
+                ));
+            }
+        }
+    }
 
-    let Some(version) = candidate else {
+    if versions.is_empty() {
         ctx.diagnostics.push(ctx.resolver_error(
             PKG_RESOLVE_MISSING,
             format!(
-                "no cached registry package found for `{}` matching `{}`",
-                dep.name,
-                fmt_req(&requirement)
+                "no cached registry package found for `{}` matching `{requirement}`",
+                dep.name
             ),
         ));
-        return None;
+        return Candidates::Unavailable;
+    }
+    if let Some(pinned) = &pinned {
+        if !versions.contains(pinned) {
+            ctx.diagnostics.push(ctx.resolver_error(
+                PKG_RESOLVE_MISSING,
+                format!(
+                    "version {pinned} of `{}` pinned in manifest.lock is not in the registry cache; run `chic update {}` to re-resolve it",
+                    dep.name, dep.name
+                ),
+            ));
+            return Candidates::Unavailable;
+        }
+        let published = checksums.get(&pinned.to_string());
+        if let (Some(locked), Some(published)) = (ctx.pinned_checksum(&dep.name), published) {
+            if locked != published {
+                ctx.diagnostics.push(ctx.resolver_error(
+                    PKG_RESOLVE_CHECKSUM,
+                    format!(
+                        "checksum of `{}` {pinned} in the registry ({published}) does not match manifest.lock ({locked})",
+                        dep.name
+                    ),
+                ));
+                return Candidates::Unavailable;
+            }
+        }
+    }
+    Candidates::Registry {
+        registry,
+        base,
+        versions,
+        pinned,
+        remote,
+        checksums,
+    }
+}
+
+/// Make sure `name` `version` is unpacked at `root`, downloading and verifying it when the
+/// cache misses. Returns `false` once the failure has been diagnosed.
+fn restore_registry_version(
+    name: &str,
+    version: &Version,
+    root: &Path,
+    remote: Option<&Registry>,
+    expected: Option<&str>,
+    ctx: &mut ResolveCtx<'_>,
+) -> bool {
+    if root.join(PROJECT_MANIFEST_BASENAME).exists() {
+        let cached = fs::read_to_string(root.join(CHECKSUM_FILE)).ok();
+        if let (Some(cached), Some(expected)) = (cached.as_deref().map(str::trim), expected) {
+            if cached != expected {
+                ctx.diagnostics.push(ctx.resolver_error(
+                    PKG_RESOLVE_CHECKSUM,
+                    format!(
+                        "cached `{name}` {version} was unpacked from an archive with checksum {cached}, but the registry publishes {expected}; remove {} to fetch it again",
+                        root.display()
+                    ),
+                ));
+                return false;
+            }
+        }
+        return true;
+    }
+    let (Some(remote), Some(expected)) = (remote, expected) else {
+        // Nothing to fetch from; `load_manifest` reports the miss.
+        return true;
+    };
+    let archive = match remote.download(name, version) {
+        Ok(archive) => archive,
+        Err(err) => {
+            ctx.diagnostics.push(ctx.resolver_error(
+                PKG_RESOLVE_MISSING,
+                format!("failed to fetch `{name}` {version} from {remote}: {err}"),
+            ));
+            return false;
+        }
     };
+    let actual = registry::checksum(&archive);
+    if actual != expected {
+        ctx.diagnostics.push(ctx.resolver_error(
+            PKG_RESOLVE_CHECKSUM,
+            format!(
+                "checksum mismatch for `{name}` {version} from {remote}: the index records {expected}, the archive hashes to {actual}"
+            ),
+        ));
+        return false;
+    }
+    // Unpack next to the final location and rename, so a failed fetch leaves no
+    // half-populated version behind.
+    let staging = staging_dir(root);
+    let _ = fs::remove_dir_all(&staging);
+    let unpacked = registry::unpack(&archive, &staging).and_then(|()| {
+        fs::write(staging.join(CHECKSUM_FILE), &actual)
+            .and_then(|()| fs::rename(&staging, root))
+            .map_err(|err| registry::RegistryError::new(err.to_string()))
+    });
+    if let Err(err) = unpacked {
+        let _ = fs::remove_dir_all(&staging);
+        ctx.diagnostics.push(ctx.resolver_error(
+            PKG_RESOLVE_MISSING,
+            format!(
+                "failed to unpack `{name}` {version} into {}: {err}",
+                root.display()
+            ),
+        ));
+        return false;
+    }
+    true
+}
 
-    let root = base.join(version.to_string());
-    let manifest = load_manifest(&root, ctx)?;
-    let source = ResolvedSource::Registry { registry };
-    build_resolved_package(dep, root, manifest, source)
+/// `<version>.partial` next to `root`. The version's dots must not be read as an
+/// extension, or `1.2.0` and `1.2.1` would share `1.2.partial`.
+fn staging_dir(root: &Path) -> PathBuf {
+    let mut name = root.file_name().unwrap_or_default().to_os_string();
+    name.push(".partial");
+    root.with_file_name(name)
 }
 
 fn build_resolved_package(
@@ -447,6 +1150,7 @@ fn build_resolved_package(
         manifest,
         root,
         source,
+        features: BTreeSet::new(),
     })
 }
 
@@ -475,6 +1179,16 @@ fn load_manifest(root: &Path, ctx: &mut ResolveCtx<'_>) -> Option<Manifest> {
     }
 }
 
+fn has_commit(checkout_dir: &Path, commit: &str) -> bool {
+    Command::new("git")
+        .arg("-C")
+        .arg(checkout_dir)
+        .args(["cat-file", "-e", &format!("{commit}^{{commit}}")])
+        .stderr(std::process::Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
 fn find_cached_versions(base: &Path) -> Vec<Version> {
     let mut versions = Vec::new();
     if let Ok(entries) = fs::read_dir(base) {
@@ -523,23 +1237,19 @@ fn sanitize_component(input: &str) -> String {
         .collect()
 }
 
-fn fmt_req(req: &VersionReq) -> String {
-    req.to_string()
-}
-
 #[derive(Debug, Serialize, Deserialize)]
 struct Lockfile {
     packages: Vec<LockedPackage>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 struct LockedPackage {
     name: String,
     version: String,
     source: LockedSource,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 enum LockedSource {
     Path {
@@ -555,6 +1265,8 @@ enum LockedSource {
     },
     Registry {
         registry: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        checksum: Option<String>,
     },
 }
 
@@ -610,6 +1322,24 @@ fn path_relative_to(base_dir: &Path, path: &Path) -> Option<PathBuf> {
 }
 
 impl LockedPackage {
+    fn describe(&self) -> String {
+        match &self.source {
+            LockedSource::Path { path } => format!("{} (path {path})", self.version),
+            LockedSource::Git { repo, commit, .. } => {
+                format!("{} (git {repo} at {commit})", self.version)
+            }
+            LockedSource::Registry { registry, checksum } => format!(
+                "{} (registry {}{})",
+                self.version,
+                registry.as_deref().unwrap_or("default"),
+                checksum
+                    .as_deref()
+                    .map(|checksum| format!(", blake3 {checksum}"))
+                    .unwrap_or_default()
+            ),
+        }
+    }
+
     fn from_resolved(pkg: &ResolvedPackage, lockfile_dir: Option<&Path>) -> Self {
         let source = match &pkg.source {
             ResolvedSource::Path => {
@@ -656,13 +1386,26 @@ impl From<&ResolvedSource> for LockedSource {
                     .as_ref()
                     .and_then(|path| path.to_str().map(str::to_string)),
             },
-            ResolvedSource::Registry { registry } => LockedSource::Registry {
+            ResolvedSource::Registry { registry, checksum } => LockedSource::Registry {
                 registry: registry.clone(),
+                checksum: checksum.clone(),
             },
         }
     }
 }
 
+/// Read an existing lock; `Ok(None)` when there is none yet.
+fn read_lockfile(path: &Path) -> Result<Option<Lockfile>, String> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let text = fs::read_to_string(path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    serde_yaml::from_str(&text)
+        .map(Some)
+        .map_err(|err| format!("failed to parse {}: {err}", path.display()))
+}
+
 fn write_lockfile(path: &Path, packages: &[ResolvedPackage]) {
     let lockfile_dir = path.parent();
     let lockfile = Lockfile {
@@ -871,6 +1614,8 @@ dependencies:
             offline: false,
             cache_dir: Some(cache_dir.clone()),
             lockfile: Some(root_dir.join("manifest.lock")),
+            lock_mode: LockMode::Prefer,
+            features: FeatureRequest::defaults(),
         };
         let outcome = resolve_dependencies(&root_manifest, &root_manifest_path, &options);
         assert!(
@@ -887,6 +1632,8 @@ dependencies:
             offline: true,
             cache_dir: Some(cache_dir.clone()),
             lockfile: Some(root_dir.join("manifest.lock")),
+            lock_mode: LockMode::Prefer,
+            features: FeatureRequest::defaults(),
         };
         let offline_outcome =
             resolve_dependencies(&root_manifest, &root_manifest_path, &offline_opts);
@@ -950,6 +1697,8 @@ dependencies:
             offline: false,
             cache_dir: Some(dir.path().join("cache")),
             lockfile: Some(lockfile_path.clone()),
+            lock_mode: LockMode::Prefer,
+            features: FeatureRequest::defaults(),
         };
         let outcome = resolve_dependencies(&root_manifest, &root_manifest_path, &options);
         assert!(
@@ -974,4 +1723,351 @@ dependencies:
             other => panic!("expected path source, got {other:?}"),
         }
     }
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["-c", "user.email=test@example.com", "-c", "user.name=test"])
+            .args(args)
+            .status()
+            .expect("run git");
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    fn write_package(dir: &Path, name: &str, version: &str, dependencies: &str) {
+        fs::create_dir_all(dir).expect("create package dir");
+        fs::write(
+            dir.join("manifest.yaml"),
+            format!(
+                "package:\n  name: {name}\n  namespace: {name}\n  version: {version}\nsources:\n  - path: src\n{dependencies}"
+            ),
+        )
+        .expect("write package manifest");
+    }
+
+    fn resolve_root(root_dir: &Path, cache_dir: &Path, lock_mode: LockMode) -> ResolveOutcome {
+        let manifest_path = root_dir.join("manifest.yaml");
+        let manifest = Manifest::discover(&manifest_path)
+            .expect("discover root manifest")
+            .expect("root manifest missing");
+        let options = ResolveOptions {
+            offline: false,
+            cache_dir: Some(cache_dir.to_path_buf()),
+            lockfile: Some(root_dir.join("manifest.lock")),
+            lock_mode,
+            features: FeatureRequest::defaults(),
+        };
+        resolve_dependencies(&manifest, &manifest_path, &options)
+    }
+
+    fn codes(outcome: &ResolveOutcome) -> Vec<String> {
+        outcome
+            .diagnostics
+            .iter()
+            .filter_map(|diag| diag.code.as_ref().map(|code| code.code.clone()))
+            .collect()
+    }
+
+    fn resolved_version(outcome: &ResolveOutcome, name: &str) -> String {
+        outcome
+            .packages
+            .iter()
+            .find(|pkg| pkg.name == name)
+            .map(|pkg| pkg.version.to_string())
+            .unwrap_or_else(|| panic!("{name} did not resolve: {:?}", outcome.diagnostics))
+    }
+
+    #[test]
+    fn unifies_features_and_enables_optional_dependencies() {
+        let temp = tempdir().expect("temp dir");
+        let root = temp.path().join("app");
+        write_package(&temp.path().join("json"), "Json", "1.0.0", "");
+        write_package(
+            &temp.path().join("math"),
+            "Math",
+            "1.0.0",
+            "features:\n  default: [std]\n  std: []\n  simd: []\n",
+        );
+        write_package(
+            &temp.path().join("geometry"),
+            "Geometry",
+            "1.0.0",
+            "dependencies:\n  Math:\n    path: ../math\n    default_features: false\n    features: [simd]\n",
+        );
+        write_package(
+            &root,
+            "App",
+            "1.0.0",
+            "dependencies:\n  Math:\n    path: ../math\n  Geometry:\n    path: ../geometry\n  Json:\n    path: ../json\n    optional: true\n",
+        );
+        let outcome = resolve_root(&root, &temp.path().join("cache"), LockMode::Prefer);
+        assert!(codes(&outcome).is_empty(), "{:?}", outcome.diagnostics);
+        let math = outcome
+            .packages
+            .iter()
+            .find(|pkg| pkg.name == "Math")
+            .expect("Math resolved");
+        assert_eq!(
+            math.features,
+            BTreeSet::from(["simd".to_string(), "std".to_string()]),
+            "requests from both dependents are unified"
+        );
+        assert!(
+            !outcome.packages.iter().any(|pkg| pkg.name == "Json"),
+            "optional dependency stays out until a feature enables it"
+        );
+
+        write_package(
+            &root,
+            "App",
+            "1.0.0",
+            "dependencies:\n  Math:\n    path: ../math\n    features: [gpu]\n  Json:\n    path: ../json\n    optional: true\nfeatures:\n  default: [serde]\n  serde: [\"dep:Json\"]\n",
+        );
+        let outcome = resolve_root(&root, &temp.path().join("cache"), LockMode::Prefer);
+        assert!(outcome.packages.iter().any(|pkg| pkg.name == "Json"));
+        assert_eq!(codes(&outcome), vec!["PKG2008".to_string()]);
+        assert!(
+            outcome.diagnostics[0]
+                .message
+                .contains("`App` enables feature `gpu` of `Math`"),
+            "{:?}",
+            outcome.diagnostics
+        );
+    }
+
+    #[test]
+    fn registry_versions_stay_pinned_until_updated() {
+        let dir = tempdir().expect("tempdir");
+        let cache_dir = dir.path().join("cache");
+        let registry = cache_dir.join("registry").join("Json");
+        write_package(&registry.join("1.0.0"), "Json", "1.0.0", "");
+        let root_dir = dir.path().join("root");
+        write_package(
+            &root_dir,
+            "Root",
+            "1.0.0",
+            "dependencies:\n  Json: { version: \"^1.0\" }\n",
+        );
+
+        let first = resolve_root(&root_dir, &cache_dir, LockMode::Prefer);
+        assert_eq!(resolved_version(&first, "Json"), "1.0.0");
+
+        // A newer compatible release appears in the cache; the lock keeps 1.0.0.
+        write_package(&registry.join("1.1.0"), "Json", "1.1.0", "");
+        let pinned = resolve_root(&root_dir, &cache_dir, LockMode::Prefer);
+        assert_eq!(resolved_version(&pinned, "Json"), "1.0.0");
+        let locked = resolve_root(&root_dir, &cache_dir, LockMode::Locked);
+        assert!(locked.diagnostics.is_empty(), "{:?}", locked.diagnostics);
+
+        let unknown = resolve_root(
+            &root_dir,
+            &cache_dir,
+            LockMode::Update(vec![String::from("Missing")]),
+        );
+        assert_eq!(codes(&unknown), vec![PKG_RESOLVE_MISSING.to_string()]);
+
+        let updated = resolve_root(
+            &root_dir,
+            &cache_dir,
+            LockMode::Update(vec![String::from("Json")]),
+        );
+        assert_eq!(resolved_version(&updated, "Json"), "1.1.0");
+        let lock = fs::read_to_string(root_dir.join("manifest.lock")).expect("read lockfile");
+        assert!(lock.contains("1.1.0"), "lock should be rewritten: {lock}");
+    }
+
+    #[test]
+    fn selects_highest_registry_version_allowed_by_every_dependent() {
+        let dir = tempdir().expect("tempdir");
+        let cache_dir = dir.path().join("cache");
+        let registry = cache_dir.join("registry");
+        for version in ["1.0.0", "1.4.0", "2.0.0"] {
+            write_package(&registry.join("Json").join(version), "Json", version, "");
+        }
+        write_package(
+            &registry.join("Widget").join("1.0.0"),
+            "Widget",
+            "1.0.0",
+            "dependencies:\n  Json: { version: \"^1.0\" }\n",
+        );
+        let root_dir = dir.path().join("root");
+        write_package(
+            &root_dir,
+            "Root",
+            "1.0.0",
+            "dependencies:\n  Json: { version: \">=1.0.0\" }\n  Widget: { version: \"^1.0\" }\n",
+        );
+
+        let outcome = resolve_root(&root_dir, &cache_dir, LockMode::Prefer);
+        assert!(outcome.diagnostics.is_empty(), "{:?}", outcome.diagnostics);
+        assert_eq!(resolved_version(&outcome, "Json"), "1.4.0");
+        assert_eq!(resolved_version(&outcome, "Widget"), "1.0.0");
+
+        write_package(
+            &root_dir,
+            "Root",
+            "1.0.0",
+            "dependencies:\n  Json: { version: \">=2.0.0\" }\n  Widget: { version: \"^1.0\" }\n",
+        );
+        let written = fs::read_to_string(root_dir.join("manifest.lock")).expect("read lockfile");
+        let conflict = resolve_root(&root_dir, &cache_dir, LockMode::Prefer);
+        assert_eq!(codes(&conflict), vec![PKG_RESOLVE_CONFLICT.to_string()]);
+        let notes = conflict.diagnostics[0].notes.join("\n");
+        assert!(
+            notes.contains("Widget 1.0.0 depends on Json >=1.0.0 <2.0.0"),
+            "{notes}"
+        );
+        assert!(
+            notes.contains("Root 1.0.0 depends on Json >=2.0.0"),
+            "{notes}"
+        );
+        assert_eq!(
+            fs::read_to_string(root_dir.join("manifest.lock")).expect("read lockfile"),
+            written,
+            "a failed resolution must not rewrite the lock"
+        );
+    }
+
+    #[test]
+    fn fetches_and_verifies_registry_archives() {
+        let dir = tempdir().expect("tempdir");
+        let cache_dir = dir.path().join("cache");
+        let registry_dir = dir.path().join("registry");
+        let registry = Registry::Directory(registry_dir.clone());
+        for version in ["1.0.0", "1.1.0"] {
+            let source = dir.path().join(format!("json-{version}"));
+            write_package(&source, "Json", version, "");
+            let manifest = Manifest::discover(&source.join("manifest.yaml"))
+                .expect("discover package manifest")
+                .expect("package manifest");
+            let packed = registry::pack(&manifest).expect("pack");
+            registry
+                .publish(&packed.name, &packed.version, &packed.archive)
+                .expect("publish");
+        }
+        // Yank 1.1.0: it is no longer selected for new resolutions.
+        let mut index = registry.index("Json").expect("index").expect("Json index");
+        index.versions[1].yanked = true;
+        fs::write(
+            registry_dir.join("index").join("Json.json"),
+            serde_json::to_vec(&index).expect("encode index"),
+        )
+        .expect("yank");
+
+        let root_dir = dir.path().join("root");
+        write_package(
+            &root_dir,
+            "Root",
+            "1.0.0",
+            "dependencies:\n  Json: { version: \"^1.0\", source: \"../registry\" }\n",
+        );
+        let outcome = resolve_root(&root_dir, &cache_dir, LockMode::Prefer);
+        assert!(outcome.diagnostics.is_empty(), "{:?}", outcome.diagnostics);
+        assert_eq!(resolved_version(&outcome, "Json"), "1.0.0");
+        let unpacked = cache_dir.join("registry").join("Json").join("1.0.0");
+        assert!(unpacked.join("manifest.yaml").is_file());
+        let lock = fs::read_to_string(root_dir.join("manifest.lock")).expect("read lockfile");
+        assert!(lock.contains(&index.versions[0].checksum), "{lock}");
+
+        // An archive that no longer matches its index entry is rejected.
+        fs::remove_dir_all(&unpacked).expect("clear cache");
+        let archive = registry_dir.join("packages").join("Json").join("1.0.0.tar");
+        let mut bytes = fs::read(&archive).expect("read archive");
+        bytes.extend_from_slice(b"tampered");
+        fs::write(&archive, bytes).expect("tamper");
+        let tampered = resolve_root(&root_dir, &cache_dir, LockMode::Prefer);
+        assert_eq!(codes(&tampered), vec![PKG_RESOLVE_CHECKSUM.to_string()]);
+        assert!(
+            !unpacked.exists(),
+            "a rejected archive must not be unpacked"
+        );
+    }
+
+    #[test]
+    fn registry_versions_stage_in_separate_directories() {
+        let base = Path::new("cache").join("registry").join("Json");
+        assert_eq!(staging_dir(&base.join("1.2.0")), base.join("1.2.0.partial"));
+        assert_ne!(
+            staging_dir(&base.join("1.2.0")),
+            staging_dir(&base.join("1.2.1"))
+        );
+    }
+
+    #[test]
+    fn locked_mode_reports_lock_drift_without_rewriting() {
+        let dir = tempdir().expect("tempdir");
+        let cache_dir = dir.path().join("cache");
+        write_package(&dir.path().join("dep"), "Dep", "1.0.0", "");
+        let root_dir = dir.path().join("root");
+        write_package(
+            &root_dir,
+            "Root",
+            "1.0.0",
+            "dependencies:\n  Dep: { path: \"../dep\" }\n",
+        );
+        let lockfile = root_dir.join("manifest.lock");
+
+        let missing = resolve_root(&root_dir, &cache_dir, LockMode::Locked);
+        assert_eq!(codes(&missing), vec![PKG_RESOLVE_LOCKED.to_string()]);
+        assert!(!lockfile.exists(), "--locked must not create the lock");
+
+        let first = resolve_root(&root_dir, &cache_dir, LockMode::Prefer);
+        assert!(first.diagnostics.is_empty(), "{:?}", first.diagnostics);
+        let written = fs::read_to_string(&lockfile).expect("read lockfile");
+
+        write_package(&dir.path().join("dep"), "Dep", "1.1.0", "");
+        let drifted = resolve_root(&root_dir, &cache_dir, LockMode::Locked);
+        assert_eq!(codes(&drifted), vec![PKG_RESOLVE_LOCKED.to_string()]);
+        assert!(
+            drifted.diagnostics[0].message.contains("1.1.0"),
+            "{}",
+            drifted.diagnostics[0].message
+        );
+        assert_eq!(
+            fs::read_to_string(&lockfile).expect("read lockfile"),
+            written,
+            "--locked must leave the lock untouched"
+        );
+    }
+
+    #[test]
+    fn git_branches_stay_at_locked_commit_until_updated() {
+        let dir = tempdir().expect("tempdir");
+        let repo_dir = dir.path().join("repo");
+        write_package(&repo_dir, "RepoDep", "0.1.0", "");
+        let git_init = Command::new("git")
+            .arg("init")
+            .arg(&repo_dir)
+            .status()
+            .expect("run git init");
+        assert!(git_init.success(), "git init failed");
+        git(&repo_dir, &["add", "manifest.yaml"]);
+        git(&repo_dir, &["commit", "-m", "v0.1.0"]);
+        git(&repo_dir, &["branch", "-M", "main"]);
+
+        let root_dir = dir.path().join("root");
+        write_package(
+            &root_dir,
+            "Root",
+            "1.0.0",
+            &format!(
+                "dependencies:\n  RepoDep: {{ git: \"{}\", branch: \"main\" }}\n",
+                repo_dir.display()
+            ),
+        );
+        let cache_dir = dir.path().join("cache");
+        let first = resolve_root(&root_dir, &cache_dir, LockMode::Prefer);
+        assert_eq!(resolved_version(&first, "RepoDep"), "0.1.0");
+
+        write_package(&repo_dir, "RepoDep", "0.2.0", "");
+        git(&repo_dir, &["commit", "-am", "v0.2.0"]);
+
+        let pinned = resolve_root(&root_dir, &cache_dir, LockMode::Prefer);
+        assert_eq!(resolved_version(&pinned, "RepoDep"), "0.1.0");
+        let updated = resolve_root(&root_dir, &cache_dir, LockMode::Update(Vec::new()));
+        assert_eq!(resolved_version(&updated, "RepoDep"), "0.2.0");
+        let locked = resolve_root(&root_dir, &cache_dir, LockMode::Locked);
+        assert!(locked.diagnostics.is_empty(), "{:?}", locked.diagnostics);
+    }
 }
@@ -0,0 +1,728 @@
+//! PubGrub-style version solving.
+//!
+//! The solver works over the versions each package makes available: a term is the set of
+//! those versions a package may take, optionally together with "not selected at all".
+//! Dependencies become incompatibilities ("`A 1.0.0` together with `B` outside `^2.0` is
+//! impossible"), unit propagation derives what the current decisions force, and conflicts
+//! are resolved by deriving new incompatibilities and backjumping. Decisions always try the
+//! preferred (locked) version first and then the highest allowed one, so the result is the
+//! highest set of versions that satisfies every requirement. When no such set exists the
+//! derivation tree of the final incompatibility explains why.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use super::version::{Version, VersionReq};
+
+/// Where the solver learns the candidate versions and dependencies of packages.
+pub(crate) trait VersionSource {
+    /// Every version of `package` that may be selected; empty when none is available.
+    fn versions(&mut self, package: &str) -> Vec<Version>;
+    /// The dependencies of `package` at `version`.
+    fn dependencies(&mut self, package: &str, version: &Version) -> Vec<(String, VersionReq)>;
+    /// A version to try before the highest one while it is still allowed.
+    fn preferred(&mut self, package: &str) -> Option<Version>;
+}
+
+/// Select one version for the root and every package it transitively depends on.
+///
+/// # Errors
+/// Returns the derivation chain, one sentence per step, when the requirements cannot all
+/// be satisfied.
+pub(crate) fn solve(
+    source: &mut impl VersionSource,
+    root: &str,
+    root_version: &Version,
+) -> Result<BTreeMap<String, Version>, Vec<String>> {
+    let mut solver = Solver {
+        source,
+        root: root.to_string(),
+        universes: HashMap::new(),
+        incompatibilities: Vec::new(),
+        by_package: HashMap::new(),
+        assignments: Vec::new(),
+        decisions: BTreeMap::new(),
+    };
+    solver
+        .universes
+        .insert(root.to_string(), BTreeSet::from([root_version.clone()]));
+    solver.add(Incompatibility {
+        terms: BTreeMap::from([(root.to_string(), Term::absent())]),
+        cause: Cause::Root,
+    });
+
+    let mut next = root.to_string();
+    loop {
+        if let Err(terminal) = solver.propagate(next) {
+            let mut lines = Vec::new();
+            solver.explain(terminal, &mut lines, &mut HashSet::new());
+            if lines.is_empty() {
+                lines.push(solver.describe(terminal));
+            }
+            return Err(lines);
+        }
+        match solver.decide() {
+            Some(package) => next = package,
+            None => return Ok(solver.decisions),
+        }
+    }
+}
+
+/// The versions a package may take, and whether it may also be left out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Term {
+    versions: BTreeSet<Version>,
+    absent: bool,
+}
+
+impl Term {
+    fn exactly(version: &Version) -> Self {
+        Self {
+            versions: BTreeSet::from([version.clone()]),
+            absent: false,
+        }
+    }
+
+    fn absent() -> Self {
+        Self {
+            versions: BTreeSet::new(),
+            absent: true,
+        }
+    }
+
+    fn any(universe: &BTreeSet<Version>) -> Self {
+        Self {
+            versions: universe.clone(),
+            absent: true,
+        }
+    }
+
+    fn intersect(&self, other: &Self) -> Self {
+        Self {
+            versions: self
+                .versions
+                .intersection(&other.versions)
+                .cloned()
+                .collect(),
+            absent: self.absent && other.absent,
+        }
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        Self {
+            versions: self.versions.union(&other.versions).cloned().collect(),
+            absent: self.absent || other.absent,
+        }
+    }
+
+    fn complement(&self, universe: &BTreeSet<Version>) -> Self {
+        Self {
+            versions: universe.difference(&self.versions).cloned().collect(),
+            absent: !self.absent,
+        }
+    }
+
+    fn is_subset(&self, other: &Self) -> bool {
+        self.versions.is_subset(&other.versions) && (!self.absent || other.absent)
+    }
+
+    fn is_disjoint(&self, other: &Self) -> bool {
+        self.versions.is_disjoint(&other.versions) && !(self.absent && other.absent)
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Cause {
+    /// The root package must be selected.
+    Root,
+    /// `package version` depends on `dependency requirement`.
+    Dependency {
+        package: String,
+        version: Version,
+        dependency: String,
+        requirement: VersionReq,
+    },
+    /// The package has no available version inside the allowed set.
+    NoVersions,
+    /// Derived from two earlier incompatibilities during conflict resolution.
+    Derived(usize, usize),
+}
+
+/// A set of terms that cannot all hold at once.
+#[derive(Clone, Debug)]
+struct Incompatibility {
+    terms: BTreeMap<String, Term>,
+    cause: Cause,
+}
+
+#[derive(Clone, Debug)]
+struct Assignment {
+    package: String,
+    term: Term,
+    level: usize,
+    /// The incompatibility that forced this assignment; `None` for decisions.
+    cause: Option<usize>,
+}
+
+enum Relation {
+    Satisfied,
+    AlmostSatisfied(String),
+    Contradicted,
+    Inconclusive,
+}
+
+struct Solver<'s, S> {
+    source: &'s mut S,
+    root: String,
+    universes: HashMap<String, BTreeSet<Version>>,
+    incompatibilities: Vec<Incompatibility>,
+    by_package: HashMap<String, Vec<usize>>,
+    assignments: Vec<Assignment>,
+    decisions: BTreeMap<String, Version>,
+}
+
+impl<S: VersionSource> Solver<'_, S> {
+    fn universe(&mut self, package: &str) -> BTreeSet<Version> {
+        if let Some(universe) = self.universes.get(package) {
+            return universe.clone();
+        }
+        let universe: BTreeSet<Version> = self.source.versions(package).into_iter().collect();
+        self.universes.insert(package.to_string(), universe.clone());
+        universe
+    }
+
+    fn push(&mut self, incompatibility: Incompatibility) -> usize {
+        self.incompatibilities.push(incompatibility);
+        self.incompatibilities.len() - 1
+    }
+
+    fn index(&mut self, id: usize) {
+        for package in self.incompatibilities[id].terms.keys() {
+            self.by_package.entry(package.clone()).or_default().push(id);
+        }
+    }
+
+    fn add(&mut self, incompatibility: Incompatibility) -> usize {
+        let id = self.push(incompatibility);
+        self.index(id);
+        id
+    }
+
+    /// The intersection of the first `count` assignments to `package`.
+    fn accumulated(&self, package: &str, count: usize) -> Term {
+        let mut term = Term::any(&self.universes[package]);
+        for assignment in &self.assignments[..count] {
+            if assignment.package == package {
+                term = term.intersect(&assignment.term);
+            }
+        }
+        term
+    }
+
+    fn relation(&self, id: usize) -> Relation {
+        let mut unsatisfied = None;
+        for (package, term) in &self.incompatibilities[id].terms {
+            let accumulated = self.accumulated(package, self.assignments.len());
+            if accumulated.is_subset(term) {
+                continue;
+            }
+            if accumulated.is_disjoint(term) {
+                return Relation::Contradicted;
+            }
+            if unsatisfied.is_some() {
+                return Relation::Inconclusive;
+            }
+            unsatisfied = Some(package.clone());
+        }
+        match unsatisfied {
+            Some(package) => Relation::AlmostSatisfied(package),
+            None => Relation::Satisfied,
+        }
+    }
+
+    /// Record that the unsatisfied term of incompatibility `id` must be false.
+    fn derive(&mut self, package: String, id: usize) {
+        let term = self.incompatibilities[id].terms[&package].complement(&self.universes[&package]);
+        self.assignments.push(Assignment {
+            package,
+            term,
+            level: self.decisions.len(),
+            cause: Some(id),
+        });
+    }
+
+    fn propagate(&mut self, package: String) -> Result<(), usize> {
+        let mut changed = vec![package];
+        while let Some(package) = changed.pop() {
+            let ids = self.by_package.get(&package).cloned().unwrap_or_default();
+            for id in ids.into_iter().rev() {
+                match self.relation(id) {
+                    Relation::Satisfied => {
+                        let root_cause = self.resolve_conflict(id)?;
+                        changed.clear();
+                        if let Relation::AlmostSatisfied(unsatisfied) = self.relation(root_cause) {
+                            self.derive(unsatisfied.clone(), root_cause);
+                            changed.push(unsatisfied);
+                        }
+                        break;
+                    }
+                    Relation::AlmostSatisfied(unsatisfied) => {
+                        self.derive(unsatisfied.clone(), id);
+                        changed.push(unsatisfied);
+                    }
+                    Relation::Contradicted | Relation::Inconclusive => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn is_terminal(&self, id: usize) -> bool {
+        let terms = &self.incompatibilities[id].terms;
+        terms.is_empty()
+            || (terms.len() == 1 && terms.get(&self.root).is_some_and(|term| !term.absent))
+    }
+
+    /// Index of the earliest assignment after which the assignments to `package`
+    /// (intersected with `extra`) satisfy `term`; `None` when no assignment is needed.
+    fn satisfier(&self, package: &str, term: &Term, extra: Option<&Term>) -> Option<usize> {
+        let mut accumulated = Term::any(&self.universes[package]);
+        if let Some(extra) = extra {
+            accumulated = accumulated.intersect(extra);
+        }
+        if accumulated.is_subset(term) {
+            return None;
+        }
+        for (index, assignment) in self.assignments.iter().enumerate() {
+            if assignment.package == package {
+                accumulated = accumulated.intersect(&assignment.term);
+                if accumulated.is_subset(term) {
+                    return Some(index);
+                }
+            }
+        }
+        None
+    }
+
+    /// Learn from the satisfied incompatibility `id` and backjump. Returns the learned
+    /// incompatibility, or the terminal one when the root itself is ruled out.
+    fn resolve_conflict(&mut self, mut id: usize) -> Result<usize, usize> {
+        let mut learned = false;
+        loop {
+            if self.is_terminal(id) {
+                return Err(id);
+            }
+            let terms = self.incompatibilities[id].terms.clone();
+            let mut satisfier: Option<(String, usize)> = None;
+            let mut others = Vec::new();
+            for (package, term) in &terms {
+                let Some(index) = self.satisfier(package, term, None) else {
+                    continue;
+                };
+                match &satisfier {
+                    Some((_, best)) if *best >= index => others.push(index),
+                    _ => {
+                        if let Some((_, previous)) = satisfier.replace((package.clone(), index)) {
+                            others.push(previous);
+                        }
+                    }
+                }
+            }
+            let Some((package, index)) = satisfier else {
+                return Err(id);
+            };
+            let satisfier = self.assignments[index].clone();
+            let term = &terms[&package];
+            let mut previous_level = others
+                .iter()
+                .map(|other| self.assignments[*other].level)
+                .max()
+                .unwrap_or(1)
+                .max(1);
+            if let Some(previous) = self.satisfier(&package, term, Some(&satisfier.term)) {
+                if previous < index {
+                    previous_level = previous_level.max(self.assignments[previous].level);
+                }
+            }
+
+            let cause = match satisfier.cause {
+                Some(cause) if previous_level == satisfier.level => cause,
+                _ => {
+                    if learned {
+                        self.index(id);
+                    }
+                    self.backtrack(previous_level);
+                    return Ok(id);
+                }
+            };
+
+            let mut merged: BTreeMap<String, Term> = BTreeMap::new();
+            let prior = self.incompatibilities[cause].terms.clone();
+            for (name, term) in terms.iter().chain(prior.iter()) {
+                if *name == package {
+                    continue;
+                }
+                merged
+                    .entry(name.clone())
+                    .and_modify(|existing| *existing = existing.intersect(term))
+                    .or_insert_with(|| term.clone());
+            }
+            if !satisfier.term.is_subset(term) {
+                let universe = &self.universes[&package];
+                merged.insert(
+                    package.clone(),
+                    term.union(&satisfier.term.complement(universe)),
+                );
+            }
+            merged.retain(|name, term| *term != Term::any(&self.universes[name]));
+            id = self.push(Incompatibility {
+                terms: merged,
+                cause: Cause::Derived(id, cause),
+            });
+            learned = true;
+        }
+    }
+
+    fn backtrack(&mut self, level: usize) {
+        self.assignments
+            .retain(|assignment| assignment.level <= level);
+        let decided: HashSet<&String> = self
+            .assignments
+            .iter()
+            .filter(|assignment| assignment.cause.is_none())
+            .map(|assignment| &assignment.package)
+            .collect();
+        self.decisions
+            .retain(|package, _| decided.contains(package));
+    }
+
+    /// Decide the next required but undecided package. Returns `None` once every required
+    /// package has a version.
+    fn decide(&mut self) -> Option<String> {
+        let mut pending: Vec<(usize, String, Term)> = Vec::new();
+        let mut seen = HashSet::new();
+        for assignment in &self.assignments {
+            let package = &assignment.package;
+            if self.decisions.contains_key(package) || !seen.insert(package.clone()) {
+                continue;
+            }
+            let accumulated = self.accumulated(package, self.assignments.len());
+            if !accumulated.absent {
+                pending.push((accumulated.versions.len(), package.clone(), accumulated));
+            }
+        }
+        let (_, package, allowed) = pending
+            .into_iter()
+            .min_by(|left, right| (left.0, &left.1).cmp(&(right.0, &right.1)))?;
+
+        let preferred = self
+            .source
+            .preferred(&package)
+            .filter(|version| allowed.versions.contains(version));
+        let Some(version) = preferred.or_else(|| allowed.versions.last().cloned()) else {
+            self.add(Incompatibility {
+                terms: BTreeMap::from([(package.clone(), allowed)]),
+                cause: Cause::NoVersions,
+            });
+            return Some(package);
+        };
+
+        for (dependency, requirement) in self.source.dependencies(&package, &version) {
+            if dependency == package {
+                continue;
+            }
+            let universe = self.universe(&dependency);
+            let matching = Term {
+                versions: universe
+                    .iter()
+                    .filter(|candidate| requirement.matches(candidate))
+                    .cloned()
+                    .collect(),
+                absent: false,
+            };
+            let mut terms = BTreeMap::from([(package.clone(), Term::exactly(&version))]);
+            // With no matching version the dependency term holds trivially, so the
+            // incompatibility rules out `package version` on its own.
+            if !matching.versions.is_empty() {
+                terms.insert(dependency.clone(), matching.complement(&universe));
+            }
+            self.add(Incompatibility {
+                terms,
+                cause: Cause::Dependency {
+                    package: package.clone(),
+                    version: version.clone(),
+                    dependency,
+                    requirement,
+                },
+            });
+        }
+        self.decisions.insert(package.clone(), version.clone());
+        self.assignments.push(Assignment {
+            package: package.clone(),
+            term: Term::exactly(&version),
+            level: self.decisions.len(),
+            cause: None,
+        });
+        Some(package)
+    }
+
+    /// Append "because ..." lines for the derivation of `id`, causes first.
+    fn explain(&self, id: usize, lines: &mut Vec<String>, seen: &mut HashSet<usize>) {
+        let Cause::Derived(left, right) = self.incompatibilities[id].cause else {
+            return;
+        };
+        for cause in [left, right] {
+            if seen.insert(cause) {
+                self.explain(cause, lines, seen);
+            }
+        }
+        lines.push(format!(
+            "because {} and {}, {}",
+            self.describe(left),
+            self.describe(right),
+            self.describe(id)
+        ));
+    }
+
+    fn describe(&self, id: usize) -> String {
+        let incompatibility = &self.incompatibilities[id];
+        match &incompatibility.cause {
+            Cause::Root => format!("{} is the package being resolved", self.root),
+            Cause::Dependency {
+                package,
+                version,
+                dependency,
+                requirement,
+            } => {
+                let mut text = format!("{package} {version} depends on {dependency} {requirement}");
+                let available = self.universes.get(dependency);
+                if !available
+                    .is_some_and(|universe| universe.iter().any(|v| requirement.matches(v)))
+                {
+                    text.push_str(" (no available version matches)");
+                }
+                text
+            }
+            Cause::NoVersions => {
+                let package = incompatibility
+                    .terms
+                    .keys()
+                    .next()
+                    .map_or("", String::as_str);
+                format!("no available version of {package} satisfies every requirement on it")
+            }
+            Cause::Derived(..) if self.is_terminal(id) => {
+                String::from("no set of versions satisfies every requirement")
+            }
+            Cause::Derived(..) => self.describe_terms(&incompatibility.terms),
+        }
+    }
+
+    fn describe_terms(&self, terms: &BTreeMap<String, Term>) -> String {
+        let mut selected = Vec::new();
+        let mut required = Vec::new();
+        for (package, term) in terms {
+            if term.absent {
+                let universe = &self.universes[package];
+                required.push(self.describe_set(package, &term.complement(universe).versions));
+            } else {
+                selected.push(self.describe_set(package, &term.versions));
+            }
+        }
+        match (selected.as_slice(), required.as_slice()) {
+            ([one], []) => format!("{one} cannot be selected"),
+            ([], [one]) => format!("{one} is required"),
+            ([one], [other]) => format!("{one} requires {other}"),
+            ([left, right], []) => format!("{left} is incompatible with {right}"),
+            (_, []) => format!("{} cannot be selected together", selected.join(" and ")),
+            ([], _) => format!("one of {} is required", required.join(" or ")),
+            _ => format!(
+                "{} require {}",
+                selected.join(" and "),
+                required.join(" or ")
+            ),
+        }
+    }
+
+    fn describe_set(&self, package: &str, versions: &BTreeSet<Version>) -> String {
+        let universe = &self.universes[package];
+        if versions.is_empty() {
+            return format!("no version of {package}");
+        }
+        if versions == universe && versions.len() > 1 {
+            return format!("any version of {package}");
+        }
+        let listed: Vec<String> = versions.iter().map(ToString::to_string).collect();
+        match listed.split_last() {
+            Some((last, rest)) if !rest.is_empty() => {
+                format!("{package} {} or {last}", rest.join(", "))
+            }
+            _ => format!("{package} {}", listed.join("")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Packages and their dependencies per version, all held in memory.
+    #[derive(Default)]
+    struct Index {
+        packages: HashMap<String, Vec<(Version, Vec<(String, VersionReq)>)>>,
+        preferred: HashMap<String, Version>,
+    }
+
+    impl Index {
+        fn add(&mut self, package: &str, version: &str, dependencies: &[(&str, &str)]) {
+            self.packages.entry(package.to_string()).or_default().push((
+                Version::parse(version).expect("version"),
+                dependencies
+                    .iter()
+                    .map(|(name, req)| {
+                        (
+                            name.to_string(),
+                            VersionReq::parse(req).expect("requirement"),
+                        )
+                    })
+                    .collect(),
+            ));
+        }
+
+        fn solve(&mut self) -> Result<Vec<String>, Vec<String>> {
+            solve(self, "root", &Version::new(1, 0, 0)).map(|solution| {
+                solution
+                    .into_iter()
+                    .map(|(name, version)| format!("{name} {version}"))
+                    .collect()
+            })
+        }
+    }
+
+    impl VersionSource for Index {
+        fn versions(&mut self, package: &str) -> Vec<Version> {
+            self.packages
+                .get(package)
+                .map(|versions| {
+                    versions
+                        .iter()
+                        .map(|(version, _)| version.clone())
+                        .collect()
+                })
+                .unwrap_or_default()
+        }
+
+        fn dependencies(&mut self, package: &str, version: &Version) -> Vec<(String, VersionReq)> {
+            self.packages
+                .get(package)
+                .and_then(|versions| versions.iter().find(|(candidate, _)| candidate == version))
+                .map(|(_, dependencies)| dependencies.clone())
+                .unwrap_or_default()
+        }
+
+        fn preferred(&mut self, package: &str) -> Option<Version> {
+            self.preferred.get(package).cloned()
+        }
+    }
+
+    #[test]
+    fn backtracks_to_an_older_version_that_satisfies_everyone() {
+        let mut index = Index::default();
+        index.add("root", "1.0.0", &[("app", "^1.0"), ("shared", ">=1.0.0")]);
+        index.add("app", "1.0.0", &[("shared", "^1.0")]);
+        index.add("app", "1.1.0", &[("shared", "^1.0")]);
+        index.add("shared", "1.0.0", &[]);
+        index.add("shared", "1.4.0", &[]);
+        index.add("shared", "2.0.0", &[("missing", "^1.0")]);
+        assert_eq!(
+            index.solve(),
+            Ok(vec![
+                String::from("app 1.1.0"),
+                String::from("root 1.0.0"),
+                String::from("shared 1.4.0"),
+            ])
+        );
+
+        index
+            .preferred
+            .insert(String::from("shared"), Version::new(1, 0, 0));
+        let solution = index.solve().expect("solution with preference");
+        assert!(
+            solution.contains(&String::from("shared 1.0.0")),
+            "{solution:?}"
+        );
+    }
+
+    #[test]
+    fn learns_from_conflicts_deep_in_the_graph() {
+        let mut index = Index::default();
+        index.add("root", "1.0.0", &[("left", "*"), ("right", "*")]);
+        index.add("left", "2.0.0", &[("shared", "^2.0")]);
+        index.add("left", "1.0.0", &[("shared", "^1.0")]);
+        index.add("right", "1.0.0", &[("shared", "^1.0")]);
+        index.add("shared", "1.0.0", &[]);
+        index.add("shared", "2.0.0", &[]);
+        assert_eq!(
+            index.solve(),
+            Ok(vec![
+                String::from("left 1.0.0"),
+                String::from("right 1.0.0"),
+                String::from("root 1.0.0"),
+                String::from("shared 1.0.0"),
+            ])
+        );
+    }
+
+    #[test]
+    fn explains_unsatisfiable_graphs() {
+        let mut index = Index::default();
+        index.add("root", "1.0.0", &[("left", "^1.0"), ("right", "^1.0")]);
+        index.add("left", "1.0.0", &[("shared", "^1.0")]);
+        index.add("right", "1.0.0", &[("shared", "^2.0")]);
+        index.add("shared", "1.0.0", &[]);
+        index.add("shared", "2.0.0", &[]);
+        let chain = index.solve().expect_err("conflicting requirements");
+        let text = chain.join("\n");
+        assert!(
+            text.contains("left 1.0.0 depends on shared >=1.0.0 <2.0.0"),
+            "{text}"
+        );
+        assert!(
+            text.contains("right 1.0.0 depends on shared >=2.0.0 <3.0.0"),
+            "{text}"
+        );
+        assert!(
+            chain.last().is_some_and(
+                |line| line.ends_with("no set of versions satisfies every requirement")
+            ),
+            "{text}"
+        );
+    }
+
+    #[test]
+    fn reports_requirements_no_available_version_matches() {
+        let mut index = Index::default();
+        index.add("root", "1.0.0", &[("left", "*"), ("right", "*")]);
+        index.add("left", "1.0.0", &[("shared", "1.0.0")]);
+        index.add("right", "1.0.0", &[("shared", "2.0.0")]);
+        index.add("shared", "1.0.0", &[]);
+        let chain = index.solve().expect_err("missing version");
+        let text = chain.join("\n");
+        assert!(
+            text.contains("right 1.0.0 depends on shared =2.0.0 (no available version matches)"),
+            "{text}"
+        );
+    }
+
+    #[test]
+    fn rejects_root_requirements_no_available_version_matches() {
+        let mut index = Index::default();
+        index.add("root", "1.0.0", &[("shared", "2.0.0")]);
+        index.add("shared", "1.0.0", &[]);
+        let chain = index.solve().expect_err("missing version");
+        let text = chain.join("\n");
+        assert!(
+            text.contains("root 1.0.0 depends on shared =2.0.0 (no available version matches)"),
+            "{text}"
+        );
+    }
+}
@@ -13,7 +13,7 @@ pub struct PerfMetadata {
 }
 
 /// Declared cost budgets for a function.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct CostModel {
     pub function: String,
     pub cpu_budget_us: Option<u64>,
@@ -23,7 +23,7 @@ pub struct CostModel {
 }
 
 /// Trace metadata associated with a function.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Tracepoint {
     pub function: String,
     pub label: String,
@@ -35,7 +35,7 @@ pub struct Tracepoint {
 }
 
 /// Tracepoint verbosity level.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum TraceLevel {
     Perf,
     Debug,
@@ -81,6 +81,9 @@ pub struct PerfRun {
     pub metrics: Vec<PerfMetric>,
     #[serde(default)]
     pub run_log: Option<RunLog>,
+    /// Target the run was measured on when it differs from the snapshot's.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
 }
 
 /// A single tracepoint measurement.
@@ -141,4 +144,18 @@ impl PerfSnapshot {
         }
         self.runs.first()
     }
+
+    /// Target `run` was measured on.
+    #[must_use]
+    pub fn run_target<'a>(&'a self, run: &'a PerfRun) -> &'a str {
+        run.target.as_deref().unwrap_or(&self.target)
+    }
+
+    /// Return the run with `profile` measured on `target`.
+    #[must_use]
+    pub fn run_for_target(&self, profile: &str, target: &str) -> Option<&PerfRun> {
+        self.runs
+            .iter()
+            .find(|run| run.profile == profile && self.run_target(run) == target)
+    }
 }
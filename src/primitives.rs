@@ -1,5 +1,8 @@
 use std::collections::{HashMap, HashSet};
 
+use serde::Serialize;
+use serde::ser::{SerializeMap, Serializer};
+
 use crate::frontend::ast::{Attribute, AttributeArgument};
 use crate::frontend::diagnostics::Span;
 use crate::mir::Ty;
@@ -7,7 +10,7 @@ use crate::mir::Ty;
 pub type PrimitiveId = usize;
 
 /// Semantic category for a primitive type.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub enum PrimitiveKind {
     Int {
         bits: u16,
@@ -67,6 +70,28 @@ pub struct PrimitiveRegistry {
     lookup: HashMap<String, PrimitiveId>,
 }
 
+/// Serialises each primitive by name; aliases are collected through a set, so they are
+/// sorted to keep the output independent of registration order.
+impl Serialize for PrimitiveRegistry {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.descriptors.len()))?;
+        for descriptor in &self.descriptors {
+            let mut aliases = descriptor.aliases.iter().collect::<Vec<_>>();
+            aliases.sort_unstable();
+            map.serialize_entry(
+                &descriptor.primitive_name,
+                &(
+                    aliases,
+                    &descriptor.kind,
+                    &descriptor.c_type,
+                    &descriptor.std_wrapper_type,
+                ),
+            )?;
+        }
+        map.end()
+    }
+}
+
 impl PrimitiveRegistry {
     #[must_use]
     pub fn new() -> Self {
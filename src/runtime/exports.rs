@@ -143,6 +143,6 @@ pub use crate::runtime::vec::{
 };
 pub use crate::runtime::wasm_executor::{
     WasmExecutionError, WasmExecutionOptions, WasmExecutionTrace, WasmProgram,
-    WasmProgramExportOutcome, WasmRunOutcome, WasmValue, execute_wasm, execute_wasm_with_options,
-    hooks,
+    WasmProgramExportOutcome, WasmRunOutcome, WasmSourceMap, WasmValue, execute_wasm,
+    execute_wasm_with_options, hooks,
 };
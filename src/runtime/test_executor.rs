@@ -6,8 +6,45 @@ const _: () = assert!(
 );
 
 mod native {
-    use crate::driver::{TestCaseResult, TestOptions, TestStatus};
-    use crate::mir::MirModule;
+    use std::collections::HashMap;
+    use std::ffi::CString;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use crate::driver::{
+        SnapshotObservation, TestCaseResult, TestEvent, TestOptions, TestStatus, check_snapshots,
+        skip_parameterized,
+    };
+    use crate::mir::{FunctionKind, MirModule, TestCaseMetadata};
+
+    const REPORT_STARTED: u32 = 0;
+    const REPORT_PASSED: u32 = 1;
+    const REPORT_SKIPPED: u32 = 3;
+    const REPORT_SNAPSHOT: u32 = 4;
+    const RUN_FAIL_FAST: u32 = 0x0000_0001;
+
+    /// Mirrors `Std.Runtime.Native.TestCaseReport`.
+    #[repr(C)]
+    struct NativeTestCaseReport {
+        index: usize,
+        status: u32,
+        flags: u32,
+        duration_ns: u64,
+        name_ptr: *const u8,
+        name_len: usize,
+        message_ptr: *const u8,
+        message_len: usize,
+    }
+
+    /// Mirrors `Std.Runtime.Native.TestRunOptions`.
+    #[repr(C)]
+    struct NativeTestRunOptions {
+        selection: *const u8,
+        flags: u32,
+        reserved: u32,
+    }
+
+    type NativeReportCallback = extern "C" fn(*const NativeTestCaseReport, *mut u8);
 
     #[derive(Debug)]
     pub struct TestExecutionError {
@@ -24,12 +61,33 @@ mod native {
 
     pub struct TestExecutor<'a> {
         module: &'a MirModule,
+        /// Declaring source file keyed by testcase id; snapshot files live next to it.
+        snapshot_sources: HashMap<String, PathBuf>,
+    }
+
+    struct RunContext<'a> {
+        cases: &'a HashMap<usize, TestCaseMetadata>,
+        options: &'a TestOptions,
+        snapshot_sources: &'a HashMap<String, PathBuf>,
+        /// Values recorded by snapshot assertions of the running testcase, keyed by index.
+        snapshots: HashMap<usize, Vec<SnapshotObservation>>,
+        results: Vec<TestCaseResult>,
     }
 
     impl<'a> TestExecutor<'a> {
         #[must_use]
         pub fn new(module: &'a MirModule) -> Self {
-            Self { module }
+            Self {
+                module,
+                snapshot_sources: HashMap::new(),
+            }
+        }
+
+        /// Resolve snapshot files against these testcase sources, keyed by testcase id.
+        #[must_use]
+        pub fn with_snapshot_sources(mut self, sources: HashMap<String, PathBuf>) -> Self {
+            self.snapshot_sources = sources;
+            self
         }
 
         #[must_use]
@@ -38,26 +96,169 @@ mod native {
         }
 
         pub fn run_all(&mut self) -> Vec<TestCaseResult> {
-            let status = unsafe { chic_rt_test_executor_run_all() };
-            if status == 0 {
-                // Native executor handled execution internally and reported success.
-                return Vec::new();
+            self.run(&TestOptions::default()).0
+        }
+
+        /// Runs the testcases matched by `options` through the native executor, returning one
+        /// result per executed (or skipped) testcase and the number filtered out. Cases whose
+        /// parameters need a runner are reported as skipped, as on the binary path.
+        pub fn run(&mut self, options: &TestOptions) -> (Vec<TestCaseResult>, usize) {
+            let testcases = self.descriptor_testcases();
+            let discovered = testcases.len();
+            let mut skipped = Vec::new();
+            let selected: HashMap<usize, TestCaseMetadata> = testcases
+                .into_iter()
+                .filter(|(_, meta)| !meta.kind.is_bench() && options.selection.matches(meta))
+                .filter(|(_, meta)| {
+                    if meta.runner_parameters().next().is_none() {
+                        return true;
+                    }
+                    let result = skip_parameterized(meta);
+                    options.emit(&TestEvent::Finished(&result));
+                    skipped.push(result);
+                    false
+                })
+                .collect();
+            let filtered_out = discovered - selected.len() - skipped.len();
+            if selected.is_empty() {
+                return (skipped, filtered_out);
+            }
+
+            let mut indexes: Vec<usize> = selected.keys().copied().collect();
+            indexes.sort_unstable();
+            let list = indexes
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
+            let selection = CString::new(list).expect("index list contains no NUL bytes");
+            let run_options = NativeTestRunOptions {
+                selection: selection.as_ptr().cast(),
+                flags: if options.fail_fast { RUN_FAIL_FAST } else { 0 },
+                reserved: 0,
+            };
+            let mut context = RunContext {
+                cases: &selected,
+                options,
+                snapshot_sources: &self.snapshot_sources,
+                snapshots: HashMap::new(),
+                results: skipped,
+            };
+            let status = unsafe {
+                chic_rt_test_executor_run(
+                    &run_options,
+                    collect_report,
+                    (&mut context as *mut RunContext<'_>).cast(),
+                )
+            };
+            let mut results = context.results;
+            if status != 0 && !results.iter().any(|case| case.status == TestStatus::Failed) {
+                results.push(TestCaseResult {
+                    id: "native".to_string(),
+                    name: "native_test_executor".to_string(),
+                    qualified_name: "native_test_executor".to_string(),
+                    namespace: None,
+                    categories: Vec::new(),
+                    is_async: false,
+                    status: TestStatus::Failed,
+                    timed_out: false,
+                    message: Some(format!(
+                        "native test executor returned error status {status}"
+                    )),
+                    wasm_trace: None,
+                    duration: None,
+                });
             }
-            vec![TestCaseResult {
-                id: "native".to_string(),
-                name: "native_test_executor".to_string(),
-                qualified_name: "native_test_executor".to_string(),
-                namespace: None,
-                categories: Vec::new(),
-                is_async: false,
-                status: TestStatus::Failed,
-                message: Some(format!(
-                    "native test executor returned error status {status}"
-                )),
-                wasm_trace: None,
-                duration: None,
-            }]
+            (results, filtered_out)
         }
+
+        /// Testcases keyed by their startup descriptor index, which follows declaration order.
+        fn descriptor_testcases(&self) -> Vec<(usize, TestCaseMetadata)> {
+            let mut metadata: HashMap<String, TestCaseMetadata> =
+                crate::mir::collect_test_metadata(self.module)
+                    .into_iter()
+                    .map(|meta| (meta.qualified_name.clone(), meta))
+                    .collect();
+            self.module
+                .functions
+                .iter()
+                .enumerate()
+                .filter(|(_, function)| matches!(function.kind, FunctionKind::Testcase))
+                .enumerate()
+                .filter_map(|(index, (function_index, function))| {
+                    let mut meta = metadata.remove(&function.name)?;
+                    meta.function_index = function_index;
+                    Some((index, meta))
+                })
+                .collect()
+        }
+    }
+
+    extern "C" fn collect_report(report: *const NativeTestCaseReport, context: *mut u8) {
+        // SAFETY: the runtime passes back the `RunContext` handed to `chic_rt_test_executor_run`
+        // and a report that stays valid for the duration of the callback.
+        let (Some(report), Some(context)) = (unsafe { report.as_ref() }, unsafe {
+            context.cast::<RunContext<'_>>().as_mut()
+        }) else {
+            return;
+        };
+        let Some(meta) = context.cases.get(&report.index) else {
+            return;
+        };
+        if report.status == REPORT_STARTED {
+            context.options.emit(&TestEvent::Started(meta));
+            return;
+        }
+        if report.status == REPORT_SNAPSHOT {
+            context
+                .snapshots
+                .entry(report.index)
+                .or_default()
+                .push(SnapshotObservation {
+                    name: report_text(report.name_ptr, report.name_len).unwrap_or_default(),
+                    value: report_text(report.message_ptr, report.message_len).unwrap_or_default(),
+                });
+            return;
+        }
+        let status = match report.status {
+            REPORT_PASSED => TestStatus::Passed,
+            REPORT_SKIPPED => TestStatus::Skipped,
+            _ => TestStatus::Failed,
+        };
+        let message = report_text(report.message_ptr, report.message_len);
+        let mut result = TestCaseResult {
+            id: meta.id.clone(),
+            name: meta.name.clone(),
+            qualified_name: meta.qualified_name.clone(),
+            namespace: meta.namespace.clone(),
+            categories: meta.categories.clone(),
+            is_async: meta.is_async,
+            status,
+            timed_out: false,
+            message,
+            wasm_trace: None,
+            duration: (status != TestStatus::Skipped)
+                .then(|| Duration::from_nanos(report.duration_ns)),
+        };
+        if let Some(observations) = context.snapshots.remove(&report.index) {
+            check_snapshots(
+                &mut result,
+                context.snapshot_sources.get(&meta.id).map(PathBuf::as_path),
+                &observations,
+                context.options.update_snapshots,
+            );
+        }
+        context.options.emit(&TestEvent::Finished(&result));
+        context.results.push(result);
+    }
+
+    /// A UTF-8 slice the runtime reported, valid for the duration of the callback.
+    fn report_text(ptr: *const u8, len: usize) -> Option<String> {
+        (!ptr.is_null() && len > 0).then(|| {
+            // SAFETY: the runtime reports a UTF-8 slice that outlives the callback.
+            let bytes = unsafe { std::slice::from_raw_parts(ptr, len) };
+            String::from_utf8_lossy(bytes).into_owned()
+        })
     }
 
     pub fn execute_main(_module: &MirModule, _entry: &str) -> Result<i32, TestExecutionError> {
@@ -70,14 +271,15 @@ mod native {
         module: &MirModule,
         options: &TestOptions,
     ) -> (Vec<TestCaseResult>, usize) {
-        let mut exec = TestExecutor::new(module);
-        let results = exec.run_all();
-        let _ = options;
-        (results, 0)
+        TestExecutor::new(module).run(options)
     }
 
     unsafe extern "C" {
-        fn chic_rt_test_executor_run_all() -> i32;
+        fn chic_rt_test_executor_run(
+            options: *const NativeTestRunOptions,
+            callback: NativeReportCallback,
+            context: *mut u8,
+        ) -> i32;
     }
 }
 
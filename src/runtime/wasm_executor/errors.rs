@@ -6,6 +6,15 @@ pub struct WasmExecutionError {
     pub message: String,
 }
 
+impl WasmExecutionError {
+    /// Whether execution was stopped by the watchdog step limit or timeout.
+    #[must_use]
+    pub fn is_watchdog_abort(&self) -> bool {
+        self.message.contains("watchdog timeout after")
+            || self.message.contains("watchdog step limit exceeded")
+    }
+}
+
 impl fmt::Display for WasmExecutionError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(&self.message)
@@ -25,4 +34,20 @@ mod tests {
         };
         assert_eq!(format!("{err}"), "example");
     }
+
+    #[test]
+    fn watchdog_aborts_are_recognised() {
+        let timeout = WasmExecutionError {
+            message: "watchdog timeout after 5ms".into(),
+        };
+        let steps = WasmExecutionError {
+            message: "watchdog step limit exceeded after 11 step(s) (limit=10)".into(),
+        };
+        let trap = WasmExecutionError {
+            message: "unreachable executed".into(),
+        };
+        assert!(timeout.is_watchdog_abort());
+        assert!(steps.is_watchdog_abort());
+        assert!(!trap.is_watchdog_abort());
+    }
 }
@@ -346,6 +346,36 @@ impl<'a> Executor<'a> {
                 };
                 return Ok(Some(Value::I32(1)));
             }
+            ("chic_rt", "test_snapshot") | ("chic_rt", "chic_rt_test_snapshot") => {
+                let [
+                    Value::I32(name_ptr),
+                    Value::I32(name_len),
+                    Value::I32(value_ptr),
+                    Value::I32(value_len),
+                ] = params.as_slice()
+                else {
+                    return Err(WasmExecutionError {
+                        message: "chic_rt.test_snapshot expects (i32 name_ptr, i32 name_len, i32 value_ptr, i32 value_len)".into(),
+                    });
+                };
+                if let Some(hook) = self.options.snapshot_hook.clone() {
+                    let read_text = |ptr: i32, len: i32| -> Result<String, WasmExecutionError> {
+                        let (Ok(ptr), Ok(len)) = (u32::try_from(ptr), u32::try_from(len)) else {
+                            return Err(WasmExecutionError {
+                                message:
+                                    "chic_rt.test_snapshot received a negative pointer or length"
+                                        .into(),
+                            });
+                        };
+                        let bytes = self.read_bytes(ptr, len)?;
+                        Ok(String::from_utf8_lossy(&bytes).into_owned())
+                    };
+                    let name = read_text(*name_ptr, *name_len)?;
+                    let value = read_text(*value_ptr, *value_len)?;
+                    hook(&name, &value);
+                }
+                Ok(None)
+            }
             ("chic_rt", name)
                 if name.starts_with("arc_")
                     || name.starts_with("weak_")
@@ -11,6 +11,7 @@ fn empty_module_with_memory() -> Module {
         imports: Vec::new(),
         functions: Vec::new(),
         function_names: Vec::new(),
+        debug: Default::default(),
         tables: Vec::new(),
         exports: HashMap::new(),
         memory_min_pages: Some(1),
@@ -58,6 +59,7 @@ fn await_future_reports_pending_then_completes() {
             ],
         }],
         function_names: Vec::new(),
+        debug: Default::default(),
         tables: Vec::new(),
         exports: HashMap::new(),
         memory_min_pages: Some(1),
@@ -6,6 +6,7 @@ use std::time::Duration;
 
 use crate::mir::{FloatStatusFlags, RoundingMode};
 use crate::runtime::error::RuntimeThrownException;
+use crate::runtime::wasm_executor::source_map::WasmSourceMap;
 
 /// Execution-time configuration for the WASM interpreter.
 #[derive(Clone)]
@@ -15,6 +16,9 @@ pub struct WasmExecutionOptions {
     pub feature_flags: Vec<String>,
     pub error_hook: Option<Arc<dyn Fn(RuntimeThrownException) + Send + Sync + 'static>>,
     pub coverage_hook: Option<Arc<dyn Fn(u64) + Send + Sync + 'static>>,
+    /// Receives `(name, value)` for each `chic_rt.test_snapshot` call; the name is empty for
+    /// unnamed snapshots.
+    pub snapshot_hook: Option<Arc<dyn Fn(&str, &str) + Send + Sync + 'static>>,
     /// Optional host IO hooks for files/sockets/clock/sleep.
     pub io_hooks: Option<super::host_io::IoHooks>,
     /// Optional layout overrides derived from the compiler's async type layouts.
@@ -43,6 +47,8 @@ pub struct WasmExecutionOptions {
     pub watchdog_step_limit: Option<u64>,
     /// Optional per-invocation watchdog timeout (wall-clock).
     pub watchdog_timeout: Option<Duration>,
+    /// Source map for the module, used to report traps as `file:line:column`.
+    pub source_map: Option<Arc<WasmSourceMap>>,
 }
 
 impl Default for WasmExecutionOptions {
@@ -53,6 +59,7 @@ impl Default for WasmExecutionOptions {
             feature_flags: Vec::new(),
             error_hook: None,
             coverage_hook: None,
+            snapshot_hook: None,
             io_hooks: None,
             async_layout: None,
             async_result_len: None,
@@ -67,6 +74,7 @@ impl Default for WasmExecutionOptions {
             rounding_mode: rounding_mode_from_env(),
             watchdog_step_limit: None,
             watchdog_timeout: None,
+            source_map: None,
         }
     }
 }
@@ -79,6 +87,7 @@ impl fmt::Debug for WasmExecutionOptions {
             .field("feature_flags", &self.feature_flags)
             .field("error_hook", &self.error_hook.is_some())
             .field("coverage_hook", &self.coverage_hook.is_some())
+            .field("snapshot_hook", &self.snapshot_hook.is_some())
             .field("io_hooks", &self.io_hooks.is_some())
             .field(
                 "async_layout",
@@ -96,6 +105,7 @@ impl fmt::Debug for WasmExecutionOptions {
             .field("rounding_mode", &self.rounding_mode)
             .field("watchdog_step_limit", &self.watchdog_step_limit)
             .field("watchdog_timeout", &self.watchdog_timeout)
+            .field("source_map", &self.source_map.is_some())
             .finish()
     }
 }
@@ -57,11 +57,12 @@ impl<'a> Executor<'a> {
         }
         let internal_index = func_index.saturating_sub(import_count) as usize;
         if let Some(function) = self.module.functions.get(internal_index) {
-            let export = self
-                .module
-                .exports
-                .iter()
-                .find_map(|(name, index)| (*index == func_index).then_some(name.as_str()));
+            let export = self.module.function_name(func_index).or_else(|| {
+                self.module
+                    .exports
+                    .iter()
+                    .find_map(|(name, index)| (*index == func_index).then_some(name.as_str()))
+            });
             if let Some(name) = export {
                 return format!(
                     "func {name} (idx={func_index} type={})",
@@ -76,6 +77,33 @@ impl<'a> Executor<'a> {
         format!("unknown func idx={func_index}")
     }
 
+    /// Append a `    at <function> (<file:line:col>)` frame for the instruction that trapped.
+    /// Frames accumulate innermost-first as the error unwinds through `invoke`.
+    fn with_trap_frame(
+        &self,
+        mut err: WasmExecutionError,
+        func_index: u32,
+        pc: usize,
+    ) -> WasmExecutionError {
+        let location = self
+            .options
+            .source_map
+            .as_ref()
+            .zip(self.module.instruction_offset(func_index, pc))
+            .and_then(|(map, offset)| map.lookup(offset).map(|location| location.to_string()));
+        let name = self.module.function_name(func_index);
+        if name.is_none() && location.is_none() {
+            return err;
+        }
+        let name = name.map_or_else(|| format!("func[{func_index}]"), str::to_string);
+        err.message.push_str("\n    at ");
+        err.message.push_str(&name);
+        if let Some(location) = location {
+            err.message.push_str(&format!(" ({location})"));
+        }
+        err
+    }
+
     pub(crate) fn invoke(
         &mut self,
         func_index: u32,
@@ -510,6 +538,7 @@ impl<'a> Executor<'a> {
                     );
                 }
             }
+            let instruction_pc = pc;
             let mut ctx = StepContext {
                 func_index,
                 pc: &mut pc,
@@ -522,7 +551,10 @@ impl<'a> Executor<'a> {
                 result_types: &result_types,
                 tracer: &mut tracer,
             };
-            match self.step_instruction(&mut ctx)? {
+            let outcome = self
+                .step_instruction(&mut ctx)
+                .map_err(|err| self.with_trap_frame(err, func_index, instruction_pc))?;
+            match outcome {
                 StepOutcome::Continue => {}
                 StepOutcome::Halt => break,
             }
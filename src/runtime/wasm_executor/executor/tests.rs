@@ -43,6 +43,7 @@ fn executor_errors_when_function_missing() {
         imports: Vec::new(),
         functions: Vec::new(),
         function_names: Vec::new(),
+        debug: Default::default(),
         tables: Vec::new(),
         exports: HashMap::new(),
         memory_min_pages: None,
@@ -129,6 +130,7 @@ fn span_copy_to_import_copies_payload() {
             ],
         }],
         function_names: Vec::new(),
+        debug: Default::default(),
         tables: Vec::new(),
         exports: HashMap::from([("chic_main".into(), 1)]),
         memory_min_pages: Some(1),
@@ -262,6 +264,7 @@ fn chic_rt_throw_invokes_exception_hook() {
             ],
         }],
         function_names: Vec::new(),
+        debug: Default::default(),
         tables: Vec::new(),
         exports: HashMap::from([("chic_main".into(), 1)]),
         memory_min_pages: None,
@@ -320,6 +323,7 @@ fn executor_handles_global_get_and_set() {
             ],
         }],
         function_names: Vec::new(),
+        debug: Default::default(),
         tables: Vec::new(),
         exports: HashMap::new(),
         memory_min_pages: Some(1),
@@ -367,6 +371,7 @@ fn executor_rejects_global_set_on_immutable() {
             ],
         }],
         function_names: Vec::new(),
+        debug: Default::default(),
         tables: Vec::new(),
         exports: HashMap::new(),
         memory_min_pages: None,
@@ -410,6 +415,7 @@ fn executor_handles_memory_load_and_store() {
             ],
         }],
         function_names: Vec::new(),
+        debug: Default::default(),
         tables: Vec::new(),
         exports: HashMap::new(),
         memory_min_pages: Some(1),
@@ -447,6 +453,7 @@ fn executor_reports_memory_out_of_bounds() {
             ],
         }],
         function_names: Vec::new(),
+        debug: Default::default(),
         tables: Vec::new(),
         exports: HashMap::new(),
         memory_min_pages: Some(1),
@@ -493,6 +500,7 @@ fn executor_propagates_runtime_panic() {
             ],
         }],
         function_names: Vec::new(),
+        debug: Default::default(),
         tables: Vec::new(),
         exports: HashMap::from([("chic_main".to_string(), 1)]),
         memory_min_pages: None,
@@ -527,6 +535,56 @@ fn executor_reports_unreachable() {
     assert!(err.message.contains("unreachable"));
 }
 
+#[test]
+fn executor_reports_traps_with_names_and_source_locations() {
+    use crate::runtime::wasm_executor::source_map::{MappedLocation, SourceMapping, WasmSourceMap};
+
+    let mut module = simple_module_with_body(
+        vec![FuncType {
+            params: Vec::new(),
+            results: Vec::new(),
+        }],
+        vec![
+            Function {
+                type_index: 0,
+                locals: Vec::new(),
+                code: vec![Instruction::Call { func: 1 }, Instruction::Return],
+            },
+            Function {
+                type_index: 0,
+                locals: Vec::new(),
+                code: vec![Instruction::Unreachable],
+            },
+        ],
+    );
+    module.function_names = vec!["Demo::Main".into(), "Demo::Boom".into()];
+    module.debug.code_offsets = vec![vec![10, 12], vec![20]];
+    let mapped = |offset, line, column| SourceMapping {
+        offset,
+        location: Some(MappedLocation {
+            source: 0,
+            line,
+            column,
+        }),
+    };
+    let options = WasmExecutionOptions {
+        source_map: Some(Arc::new(WasmSourceMap {
+            sources: vec!["src/demo.ch".into()],
+            mappings: vec![mapped(10, 7, 9), mapped(20, 3, 5)],
+        })),
+        ..WasmExecutionOptions::default()
+    };
+    let mut exec = Executor::with_options(&module, &options).expect("construct executor");
+    let err = expect_err(exec.call(0, &[]), "unreachable trap");
+    assert!(
+        err.message.ends_with(
+            "\n    at Demo::Boom (src/demo.ch:3:5)\n    at Demo::Main (src/demo.ch:7:9)"
+        ),
+        "unexpected trap report: {}",
+        err.message
+    );
+}
+
 #[test]
 fn call_indirect_dispatches_through_function_table() {
     use Instruction::{CallIndirect, I32Const, Return};
@@ -562,6 +620,7 @@ fn call_indirect_dispatches_through_function_table() {
             },
         ],
         function_names: Vec::new(),
+        debug: Default::default(),
         tables: vec![Table {
             element_type: TableElementType::FuncRef,
             min: 2,
@@ -667,6 +726,7 @@ fn object_new_allocates_zeroed_block() {
             code: vec![I64Const(type_id as i64), Call { func: 0 }, Return],
         }],
         function_names: Vec::new(),
+        debug: Default::default(),
         tables: Vec::new(),
         exports: HashMap::from([("chic_main".to_string(), 1)]),
         memory_min_pages: Some(1),
@@ -736,6 +796,7 @@ fn object_new_without_metadata_errors() {
             code: vec![I64Const(0x1234), Call { func: 0 }, Return],
         }],
         function_names: Vec::new(),
+        debug: Default::default(),
         tables: Vec::new(),
         exports: HashMap::from([("chic_main".to_string(), 1)]),
         memory_min_pages: Some(1),
@@ -758,6 +819,7 @@ fn simple_module_with_body(types: Vec<FuncType>, functions: Vec<Function>) -> Mo
         imports: Vec::new(),
         functions,
         function_names: Vec::new(),
+        debug: Default::default(),
         tables: Vec::new(),
         exports: HashMap::new(),
         memory_min_pages: None,
@@ -798,6 +860,7 @@ fn module_with_arithmetic_tests() -> (Module, i32) {
         imports: Vec::new(),
         functions,
         function_names: Vec::new(),
+        debug: Default::default(),
         tables: Vec::new(),
         exports: HashMap::new(),
         memory_min_pages: None,
@@ -882,6 +945,7 @@ fn module_with_control_flow() -> Module {
         imports: Vec::new(),
         functions,
         function_names: Vec::new(),
+        debug: Default::default(),
         tables: Vec::new(),
         exports: HashMap::new(),
         memory_min_pages: None,
@@ -936,6 +1000,7 @@ fn executor_handles_i64_core_ops_and_memory() {
             code,
         }],
         function_names: Vec::new(),
+        debug: Default::default(),
         tables: Vec::new(),
         exports: HashMap::new(),
         memory_min_pages: Some(1),
@@ -173,6 +173,7 @@ fn wasm_io_hooks_route_fopen() {
         imports: Vec::new(),
         functions: Vec::new(),
         function_names: Vec::new(),
+        debug: Default::default(),
         tables: Vec::new(),
         exports: HashMap::new(),
         memory_min_pages: Some(1),
@@ -49,6 +49,8 @@ pub fn abort_message(exit_code: i32) -> String {
 /// Attempt to recover runtime termination metadata from an error message.
 #[must_use]
 pub fn parse_runtime_termination(message: &str) -> Option<RuntimeTermination> {
+    // Trap frames follow the termination marker on later lines.
+    let message = message.lines().next().unwrap_or_default();
     if let Some(suffix) = message.strip_prefix(PANIC_PREFIX) {
         return suffix.parse::<i32>().ok().map(|code| RuntimeTermination {
             kind: RuntimeTerminationKind::Panic,
@@ -94,6 +96,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_ignores_appended_trap_frames() {
+        let message = format!(
+            "{}\n    at Demo::Main (src/main.ch:4:9)",
+            panic_message(PANIC_EXIT_CODE)
+        );
+        assert_eq!(
+            parse_runtime_termination(&message).map(|termination| termination.exit_code),
+            Some(PANIC_EXIT_CODE)
+        );
+    }
+
     #[test]
     fn parse_returns_none_for_unknown_message() {
         assert_eq!(parse_runtime_termination("unrelated error"), None);
@@ -10,6 +10,7 @@ mod executor;
 pub mod instructions;
 pub(crate) mod module;
 pub mod parser;
+pub mod source_map;
 pub(crate) mod types;
 
 pub use errors::WasmExecutionError;
@@ -20,6 +21,7 @@ pub use executor::{
 };
 pub mod hooks;
 pub use module::{WasmProgram, WasmProgramExportOutcome};
+pub use source_map::WasmSourceMap;
 pub use types::WasmValue;
 
 pub(super) const WASM_MAGIC: [u8; 4] = [0x00, 0x61, 0x73, 0x6D];
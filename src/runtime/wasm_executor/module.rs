@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 use super::errors::WasmExecutionError;
 use super::executor::{
@@ -64,6 +65,18 @@ pub struct Module {
     pub type_metadata: Vec<TypeMetadataRecord>,
     pub hash_glue: Vec<GlueRecord>,
     pub eq_glue: Vec<GlueRecord>,
+    pub debug: ModuleDebugInfo,
+}
+
+/// Names and code offsets used to describe traps in Chic terms.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleDebugInfo {
+    /// Local names from the `name` section, keyed by function index then local index.
+    pub local_names: HashMap<u32, HashMap<u32, String>>,
+    /// Module byte offset of every instruction, per defined function.
+    pub code_offsets: Vec<Vec<u32>>,
+    /// Target of the `sourceMappingURL` section, relative to the module.
+    pub source_mapping_url: Option<String>,
 }
 
 impl Module {
@@ -71,6 +84,21 @@ impl Module {
         self.function_names
             .get(func_index as usize)
             .map(String::as_str)
+            .filter(|name| !name.is_empty())
+    }
+
+    pub fn local_name(&self, func_index: u32, local_index: u32) -> Option<&str> {
+        self.debug
+            .local_names
+            .get(&func_index)?
+            .get(&local_index)
+            .map(String::as_str)
+    }
+
+    /// Module byte offset of instruction `pc` in function `func_index`.
+    pub fn instruction_offset(&self, func_index: u32, pc: usize) -> Option<u32> {
+        let defined = (func_index as usize).checked_sub(self.imports.len())?;
+        self.debug.code_offsets.get(defined)?.get(pc).copied()
     }
 }
 
@@ -185,6 +213,36 @@ impl WasmProgram {
             trace,
         })
     }
+
+    /// Instantiate the module once and call an export `iterations` times in a row.
+    ///
+    /// Returns the time spent inside the calls, excluding instantiation, together with the
+    /// value returned by the final call. Used by `chic bench` to time batches of iterations.
+    ///
+    /// # Errors
+    /// Returns `WasmExecutionError` if the export is missing or any call traps.
+    pub fn execute_export_repeated(
+        &self,
+        name: &str,
+        args: &[super::types::Value],
+        options: &WasmExecutionOptions,
+        iterations: u64,
+    ) -> Result<(Duration, Option<WasmValue>), WasmExecutionError> {
+        let export_index = *self
+            .module
+            .exports
+            .get(name)
+            .ok_or_else(|| WasmExecutionError {
+                message: format!("export `{name}` not found"),
+            })?;
+        let mut executor = DefaultExecutorFactory.create(&self.module, options)?;
+        let mut value = None;
+        let start = Instant::now();
+        for _ in 0..iterations {
+            value = executor.call_with_trace(export_index, args)?.0;
+        }
+        Ok((start.elapsed(), value.map(Into::into)))
+    }
 }
 
 #[cfg(test)]
@@ -290,4 +348,20 @@ mod tests {
         assert_eq!(outcome.value, Some(WasmValue::I32(7)));
         assert!(factory.constructed());
     }
+
+    #[test]
+    fn execute_export_repeated_reuses_one_instance() {
+        let wasm = simple_module(3);
+        let program = expect_ok(WasmProgram::from_bytes(&wasm), "parse ok");
+        let (_, value) = expect_ok(
+            program.execute_export_repeated("chic_main", &[], &WasmExecutionOptions::default(), 5),
+            "repeated execution",
+        );
+        assert_eq!(value, Some(WasmValue::I32(3)));
+        let err = expect_err(
+            program.execute_export_repeated("missing", &[], &WasmExecutionOptions::default(), 1),
+            "missing export",
+        );
+        assert!(err.message.contains("export `missing` not found"));
+    }
 }
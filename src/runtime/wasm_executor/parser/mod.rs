@@ -7,13 +7,13 @@ use std::collections::HashMap;
 
 use super::errors::WasmExecutionError;
 use super::module::{
-    DataSegment, Function, GlueRecord, InterfaceDefault, Module, Table, TableElementType,
-    TypeMetadataRecord, TypeVarianceRecord,
+    DataSegment, Function, GlueRecord, InterfaceDefault, Module, ModuleDebugInfo, Table,
+    TableElementType, TypeMetadataRecord, TypeVarianceRecord,
 };
 use super::types::{FuncType, ValueType};
 use super::{WASM_MAGIC, WASM_VERSION};
 use lexer::{read_init_expr, read_string, read_u64, read_uleb, read_value_type};
-use state::parse_instructions;
+use state::parse_instructions_with_offsets;
 
 #[expect(
     clippy::too_many_lines,
@@ -40,7 +40,7 @@ pub fn parse_module(bytes: &[u8]) -> Result<Module, WasmExecutionError> {
     let mut tables = Vec::new();
     let mut imports = Vec::new();
     let mut exports = HashMap::new();
-    let mut code_bodies: Vec<Vec<u8>> = Vec::new();
+    let mut code_bodies: Vec<(usize, Vec<u8>)> = Vec::new();
     let mut memory_min_pages: Option<u32> = None;
     let mut globals = Vec::new();
     let mut data_segments = Vec::new();
@@ -48,7 +48,8 @@ pub fn parse_module(bytes: &[u8]) -> Result<Module, WasmExecutionError> {
     let mut type_metadata = Vec::new();
     let mut hash_glue = Vec::new();
     let mut eq_glue = Vec::new();
-    let mut defined_function_names: Vec<String> = Vec::new();
+    let mut name_map: HashMap<u32, String> = HashMap::new();
+    let mut debug = ModuleDebugInfo::default();
 
     while cursor < bytes.len() {
         let section_id = bytes[cursor];
@@ -270,7 +271,7 @@ pub fn parse_module(bytes: &[u8]) -> Result<Module, WasmExecutionError> {
                             message: "function body exceeds module size".into(),
                         });
                     }
-                    code_bodies.push(bytes[cursor..cursor + body_size].to_vec());
+                    code_bodies.push((cursor, bytes[cursor..cursor + body_size].to_vec()));
                     cursor += body_size;
                 }
             }
@@ -334,13 +335,35 @@ pub fn parse_module(bytes: &[u8]) -> Result<Module, WasmExecutionError> {
             0 => {
                 let name = read_string(bytes, &mut cursor)?;
                 match name.as_str() {
-                    "chic.fn.names" => {
-                        let entry_count = read_uleb(bytes, &mut cursor)? as usize;
-                        defined_function_names = Vec::with_capacity(entry_count);
-                        for _ in 0..entry_count {
-                            defined_function_names.push(read_string(bytes, &mut cursor)?);
+                    "name" => {
+                        while cursor < end {
+                            let subsection = bytes[cursor];
+                            cursor += 1;
+                            let size = read_uleb(bytes, &mut cursor)? as usize;
+                            let subsection_end = cursor + size;
+                            if subsection_end > end {
+                                return Err(WasmExecutionError {
+                                    message: "name subsection exceeds section size".into(),
+                                });
+                            }
+                            match subsection {
+                                1 => name_map = read_name_map(bytes, &mut cursor)?,
+                                2 => {
+                                    let function_count = read_uleb(bytes, &mut cursor)?;
+                                    for _ in 0..function_count {
+                                        let func_index = read_uleb(bytes, &mut cursor)? as u32;
+                                        let locals = read_name_map(bytes, &mut cursor)?;
+                                        debug.local_names.insert(func_index, locals);
+                                    }
+                                }
+                                _ => {}
+                            }
+                            cursor = subsection_end;
                         }
                     }
+                    "sourceMappingURL" => {
+                        debug.source_mapping_url = Some(read_string(bytes, &mut cursor)?);
+                    }
                     "chic.iface.defaults" => {
                         let entry_count = read_uleb(bytes, &mut cursor)?;
                         for _ in 0..entry_count {
@@ -433,7 +456,7 @@ pub fn parse_module(bytes: &[u8]) -> Result<Module, WasmExecutionError> {
         });
     }
 
-    for (type_index, body) in func_type_indices.into_iter().zip(code_bodies.into_iter()) {
+    for (type_index, (body_start, body)) in func_type_indices.into_iter().zip(code_bodies) {
         let mut cursor = 0usize;
         let locals_count = read_uleb(&body, &mut cursor)?;
         let mut locals = Vec::new();
@@ -444,7 +467,14 @@ pub fn parse_module(bytes: &[u8]) -> Result<Module, WasmExecutionError> {
                 locals.push(ty);
             }
         }
-        let instructions = parse_instructions(&body[cursor..])?;
+        let (instructions, offsets) = parse_instructions_with_offsets(&body[cursor..])?;
+        let code_start = (body_start + cursor) as u32;
+        debug.code_offsets.push(
+            offsets
+                .into_iter()
+                .map(|offset| code_start + offset)
+                .collect(),
+        );
         functions.push(Function {
             type_index,
             locals,
@@ -456,9 +486,11 @@ pub fn parse_module(bytes: &[u8]) -> Result<Module, WasmExecutionError> {
     for import in &imports {
         function_names.push(format!("{}.{}", import.module, import.name));
     }
-    if defined_function_names.len() == functions.len() {
-        function_names.extend(defined_function_names);
-    }
+    let defined_start = imports.len() as u32;
+    function_names.extend(
+        (defined_start..defined_start + functions.len() as u32)
+            .map(|index| name_map.remove(&index).unwrap_or_default()),
+    );
 
     Ok(Module {
         types,
@@ -474,5 +506,20 @@ pub fn parse_module(bytes: &[u8]) -> Result<Module, WasmExecutionError> {
         type_metadata,
         hash_glue,
         eq_glue,
+        debug,
     })
 }
+
+/// A `name` section name map: `(index, name)` pairs.
+fn read_name_map(
+    bytes: &[u8],
+    cursor: &mut usize,
+) -> Result<HashMap<u32, String>, WasmExecutionError> {
+    let count = read_uleb(bytes, cursor)?;
+    let mut names = HashMap::new();
+    for _ in 0..count {
+        let index = read_uleb(bytes, cursor)? as u32;
+        names.insert(index, read_string(bytes, cursor)?);
+    }
+    Ok(names)
+}
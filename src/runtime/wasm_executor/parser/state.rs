@@ -29,16 +29,25 @@ impl<'a> ParserState<'a> {
     }
 }
 
+pub(crate) fn parse_instructions(bytes: &[u8]) -> Result<Vec<Instruction>, WasmExecutionError> {
+    parse_instructions_with_offsets(bytes).map(|(instructions, _)| instructions)
+}
+
+/// Decode `bytes`, also returning the byte offset each instruction starts at.
 #[expect(
     clippy::too_many_lines,
     reason = "Initial WASM instruction decoding remains monolithic until more helpers emerge."
 )]
-pub(crate) fn parse_instructions(bytes: &[u8]) -> Result<Vec<Instruction>, WasmExecutionError> {
+pub(crate) fn parse_instructions_with_offsets(
+    bytes: &[u8],
+) -> Result<(Vec<Instruction>, Vec<u32>), WasmExecutionError> {
     let mut cursor = 0usize;
     let mut instructions = Vec::new();
+    let mut offsets = Vec::new();
     let mut control_stack: Vec<(ControlKind, usize)> = Vec::new();
 
     while cursor < bytes.len() {
+        let start = cursor as u32;
         let opcode = *bytes.get(cursor).ok_or_else(|| WasmExecutionError {
             message: "unexpected end of instruction stream".into(),
         })?;
@@ -438,9 +447,10 @@ pub(crate) fn parse_instructions(bytes: &[u8]) -> Result<Vec<Instruction>, WasmE
             }
             _ => return Err(ParserDiagnostic::invalid_opcode(opcode)),
         }
+        offsets.resize(instructions.len(), start);
     }
 
-    Ok(instructions)
+    Ok((instructions, offsets))
 }
 
 pub(crate) fn expect_block_type(
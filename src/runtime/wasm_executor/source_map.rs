@@ -0,0 +1,308 @@
+//! Source maps (`<artifact>.wasm.map`) relating WebAssembly code offsets to Chic sources.
+//!
+//! Maps use the Source Map v3 JSON layout understood by browsers and engines: every mapping
+//! sits on generated line 0 and its generated column is a byte offset into the module.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Custom section naming the map file, resolved relative to the module.
+pub const SOURCE_MAPPING_URL_SECTION: &str = "sourceMappingURL";
+
+/// Where the source map for the module at `artifact` lives (`app.wasm` -> `app.wasm.map`).
+#[must_use]
+pub fn source_map_path(artifact: &Path) -> PathBuf {
+    let mut path = artifact.as_os_str().to_owned();
+    path.push(".map");
+    PathBuf::from(path)
+}
+
+/// Read the source map next to `artifact`, if there is a well-formed one.
+#[must_use]
+pub fn load_source_map(artifact: &Path) -> Option<WasmSourceMap> {
+    let text = std::fs::read_to_string(source_map_path(artifact)).ok()?;
+    WasmSourceMap::from_json(&text).ok()
+}
+
+/// Decoded source map for one WebAssembly module.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WasmSourceMap {
+    pub sources: Vec<String>,
+    /// Mappings ordered by module offset.
+    pub mappings: Vec<SourceMapping>,
+}
+
+/// Code from `offset` up to the next mapping belongs to `location` (or to no source).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceMapping {
+    pub offset: u32,
+    pub location: Option<MappedLocation>,
+}
+
+/// Index into [`WasmSourceMap::sources`] plus a 1-based line and column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MappedLocation {
+    pub source: u32,
+    pub line: u32,
+    pub column: u32,
+}
+
+/// A resolved `file:line:column`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation<'a> {
+    pub file: &'a str,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl fmt::Display for SourceLocation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawSourceMap {
+    version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    file: Option<String>,
+    sources: Vec<String>,
+    #[serde(default)]
+    names: Vec<String>,
+    mappings: String,
+}
+
+impl WasmSourceMap {
+    /// Serialise as Source Map v3 JSON; `file` names the module the map describes.
+    #[must_use]
+    pub fn to_json(&self, file: Option<&str>) -> String {
+        let mut mappings = String::new();
+        let (mut offset, mut source, mut line, mut column) = (0i64, 0i64, 0i64, 0i64);
+        for (index, mapping) in self.mappings.iter().enumerate() {
+            if index > 0 {
+                mappings.push(',');
+            }
+            push_vlq(&mut mappings, i64::from(mapping.offset) - offset);
+            offset = i64::from(mapping.offset);
+            if let Some(location) = mapping.location {
+                // Source map lines and columns are 0-based.
+                let (next_line, next_column) = (
+                    i64::from(location.line.saturating_sub(1)),
+                    i64::from(location.column.saturating_sub(1)),
+                );
+                push_vlq(&mut mappings, i64::from(location.source) - source);
+                push_vlq(&mut mappings, next_line - line);
+                push_vlq(&mut mappings, next_column - column);
+                (source, line, column) = (i64::from(location.source), next_line, next_column);
+            }
+        }
+        let raw = RawSourceMap {
+            version: 3,
+            file: file.map(str::to_string),
+            sources: self.sources.clone(),
+            names: Vec::new(),
+            mappings,
+        };
+        serde_json::to_string(&raw).unwrap_or_default()
+    }
+
+    /// Parse a Source Map v3 JSON document.
+    ///
+    /// # Errors
+    /// Returns a description of the problem when the JSON or the `mappings` string is malformed.
+    pub fn from_json(text: &str) -> Result<Self, String> {
+        let raw: RawSourceMap =
+            serde_json::from_str(text).map_err(|err| format!("invalid source map: {err}"))?;
+        if raw.version != 3 {
+            return Err(format!("unsupported source map version {}", raw.version));
+        }
+        let mut mappings = Vec::new();
+        let (mut offset, mut source, mut line, mut column) = (0i64, 0i64, 0i64, 0i64);
+        // Only generated line 0 is meaningful for WebAssembly.
+        let first_line = raw.mappings.split(';').next().unwrap_or_default();
+        for segment in first_line.split(',').filter(|segment| !segment.is_empty()) {
+            let fields = decode_vlq_segment(segment)?;
+            offset += fields[0];
+            let location = match fields.len() {
+                1 => None,
+                4 | 5 => {
+                    source += fields[1];
+                    line += fields[2];
+                    column += fields[3];
+                    Some(MappedLocation {
+                        source: to_u32(source)?,
+                        line: to_u32(line + 1)?,
+                        column: to_u32(column + 1)?,
+                    })
+                }
+                count => return Err(format!("source map segment has {count} fields")),
+            };
+            mappings.push(SourceMapping {
+                offset: to_u32(offset)?,
+                location,
+            });
+        }
+        mappings.sort_by_key(|mapping| mapping.offset);
+        Ok(Self {
+            sources: raw.sources,
+            mappings,
+        })
+    }
+
+    /// The source location covering module byte `offset`, if any.
+    #[must_use]
+    pub fn lookup(&self, offset: u32) -> Option<SourceLocation<'_>> {
+        let index = self
+            .mappings
+            .partition_point(|mapping| mapping.offset <= offset)
+            .checked_sub(1)?;
+        let location = self.mappings[index].location?;
+        Some(SourceLocation {
+            file: self.sources.get(location.source as usize)?,
+            line: location.line,
+            column: location.column,
+        })
+    }
+}
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn push_vlq(out: &mut String, value: i64) {
+    let mut remaining = if value < 0 {
+        ((-value) << 1) | 1
+    } else {
+        value << 1
+    };
+    loop {
+        let mut digit = remaining & 0x1f;
+        remaining >>= 5;
+        if remaining > 0 {
+            digit |= 0x20;
+        }
+        out.push(char::from(BASE64[digit as usize]));
+        if remaining == 0 {
+            break;
+        }
+    }
+}
+
+fn decode_vlq_segment(segment: &str) -> Result<Vec<i64>, String> {
+    let mut fields = Vec::new();
+    let (mut value, mut shift) = (0i64, 0u32);
+    for byte in segment.bytes() {
+        let digit = BASE64
+            .iter()
+            .position(|candidate| *candidate == byte)
+            .ok_or_else(|| format!("invalid base64 digit `{}` in source map", char::from(byte)))?
+            as i64;
+        if shift > 60 {
+            return Err("source map value overflows".into());
+        }
+        value |= (digit & 0x1f) << shift;
+        if digit & 0x20 != 0 {
+            shift += 5;
+            continue;
+        }
+        let magnitude = value >> 1;
+        fields.push(if value & 1 == 1 {
+            -magnitude
+        } else {
+            magnitude
+        });
+        (value, shift) = (0, 0);
+    }
+    if shift != 0 {
+        return Err("truncated source map segment".into());
+    }
+    Ok(fields)
+}
+
+fn to_u32(value: i64) -> Result<u32, String> {
+    u32::try_from(value).map_err(|_| format!("source map value {value} is out of range"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> WasmSourceMap {
+        WasmSourceMap {
+            sources: vec!["src/main.ch".into(), "src/util.ch".into()],
+            mappings: vec![
+                SourceMapping {
+                    offset: 40,
+                    location: Some(MappedLocation {
+                        source: 0,
+                        line: 3,
+                        column: 5,
+                    }),
+                },
+                SourceMapping {
+                    offset: 52,
+                    location: Some(MappedLocation {
+                        source: 1,
+                        line: 17,
+                        column: 1,
+                    }),
+                },
+                SourceMapping {
+                    offset: 90,
+                    location: None,
+                },
+                SourceMapping {
+                    offset: 120,
+                    location: Some(MappedLocation {
+                        source: 0,
+                        line: 2,
+                        column: 9,
+                    }),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let map = sample();
+        let json = map.to_json(Some("app.wasm"));
+        assert!(json.contains("\"version\":3"), "{json}");
+        assert!(json.contains("\"file\":\"app.wasm\""), "{json}");
+        assert_eq!(WasmSourceMap::from_json(&json).expect("parse"), map);
+    }
+
+    #[test]
+    fn encodes_offsets_as_vlq_columns() {
+        let map = WasmSourceMap {
+            sources: vec!["a.ch".into()],
+            mappings: vec![SourceMapping {
+                offset: 16,
+                location: Some(MappedLocation {
+                    source: 0,
+                    line: 1,
+                    column: 1,
+                }),
+            }],
+        };
+        // 16 -> "gB", then source 0, line 0, column 0.
+        assert!(map.to_json(None).contains("\"mappings\":\"gBAAA\""));
+    }
+
+    #[test]
+    fn lookup_uses_the_closest_preceding_mapping() {
+        let map = sample();
+        assert_eq!(map.lookup(39), None);
+        let location = map.lookup(60).expect("mapped");
+        assert_eq!(location.to_string(), "src/util.ch:17:1");
+        assert_eq!(map.lookup(100), None, "unmapped ranges stay unmapped");
+        assert_eq!(map.lookup(500).expect("mapped").line, 2);
+    }
+
+    #[test]
+    fn rejects_malformed_mappings() {
+        let err = WasmSourceMap::from_json(r#"{"version":3,"sources":[],"mappings":"g"}"#)
+            .expect_err("truncated");
+        assert!(err.contains("truncated"), "{err}");
+    }
+}
@@ -295,6 +295,7 @@ fn executor_with_linear_memory() -> Executor<'static> {
         imports: Vec::new(),
         functions: Vec::new(),
         function_names: Vec::new(),
+        debug: Default::default(),
         tables: Vec::new(),
         exports: HashMap::new(),
         memory_min_pages: Some(1),
@@ -6,9 +6,10 @@ use crate::frontend::diagnostics::Span;
 use crate::mir::{BinOp, ConstValue, UnOp};
 use crate::syntax::numeric::NumericLiteralMetadata;
 use crate::syntax::pattern::PatternAst;
+use serde::Serialize;
 
 /// Switch expression arm containing a pattern, optional guards, and the arm body.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct SwitchArm {
     pub pattern: PatternAst,
     pub guards: Vec<PatternGuardExpr>,
@@ -18,7 +19,7 @@ pub struct SwitchArm {
 }
 
 /// Switch expression representation mirroring C#-style `expr switch { .. }`.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct SwitchExpr {
     pub value: Box<ExprNode>,
     pub arms: Vec<SwitchArm>,
@@ -28,7 +29,7 @@ pub struct SwitchExpr {
 }
 
 /// Name specified for a call argument slot.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct CallArgumentName {
     pub text: String,
     pub span: Option<Span>,
@@ -45,7 +46,7 @@ impl CallArgumentName {
 }
 
 /// Modifier attached to a call argument (e.g. `ref`, `in`, `out`).
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
 pub enum CallArgumentModifier {
     In,
     Ref,
@@ -64,7 +65,7 @@ impl CallArgumentModifier {
 }
 
 /// Parsed argument supplied to a call expression.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct CallArgument {
     pub name: Option<CallArgumentName>,
     pub value: ExprNode,
@@ -125,7 +126,7 @@ impl CallArgument {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub enum InlineBindingKind {
     Var,
     Typed {
@@ -134,7 +135,7 @@ pub enum InlineBindingKind {
     },
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct InlineBinding {
     pub kind: InlineBindingKind,
     pub name: String,
@@ -145,14 +146,14 @@ pub struct InlineBinding {
 }
 
 /// Named inline assembly operand reference within a template string.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub enum InlineAsmTemplateOperandRef {
     Position(usize),
     Named(String),
 }
 
 /// Template fragment for inline assembly expressions.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub enum InlineAsmTemplatePiece {
     Literal(String),
     Placeholder {
@@ -163,14 +164,14 @@ pub enum InlineAsmTemplatePiece {
 }
 
 /// Inline assembly template made up of literal and placeholder pieces.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct InlineAsmTemplate {
     pub pieces: Vec<InlineAsmTemplatePiece>,
     pub span: Option<Span>,
 }
 
 /// Supported inline assembly register classes.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub enum InlineAsmRegisterClass {
     Reg,
     Reg8,
@@ -185,7 +186,7 @@ pub enum InlineAsmRegisterClass {
 }
 
 /// Register selection for inline assembly.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub enum InlineAsmRegister {
     Class(InlineAsmRegisterClass),
     Explicit(String),
@@ -193,7 +194,7 @@ pub enum InlineAsmRegister {
 
 /// Operand kinds supported by inline assembly.
 #[allow(clippy::large_enum_variant)] // AST fidelity trumps layout; variants mirror the surface syntax.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub enum InlineAsmOperandMode {
     In {
         expr: ExprNode,
@@ -216,7 +217,7 @@ pub enum InlineAsmOperandMode {
 }
 
 /// Operand supplied to an inline assembly expression.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct InlineAsmOperand {
     pub name: Option<String>,
     pub reg: InlineAsmRegister,
@@ -226,7 +227,7 @@ pub struct InlineAsmOperand {
 
 /// Inline assembly options mirroring Rust's `asm!` surface.
 #[allow(clippy::struct_excessive_bools)] // Options map 1:1 with the `asm!` surface; keep them explicit.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct InlineAsmOptions {
     pub volatile: bool,
     pub alignstack: bool,
@@ -240,7 +241,7 @@ pub struct InlineAsmOptions {
 }
 
 /// Inline assembly expression representation.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct InlineAsmExpr {
     pub template: InlineAsmTemplate,
     pub operands: Vec<InlineAsmOperand>,
@@ -250,7 +251,7 @@ pub struct InlineAsmExpr {
 }
 
 /// Endpoint within a range expression.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct RangeEndpoint {
     pub expr: Box<ExprNode>,
     pub from_end: bool,
@@ -269,7 +270,7 @@ impl RangeEndpoint {
 }
 
 /// Parsed representation of a range expression.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct RangeExpr {
     pub start: Option<Box<RangeEndpoint>>,
     pub end: Option<Box<RangeEndpoint>>,
@@ -278,14 +279,14 @@ pub struct RangeExpr {
 }
 
 /// Index-from-end unary expression (`^value`).
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct IndexFromEndExpr {
     pub expr: Box<ExprNode>,
     pub span: Option<Span>,
 }
 
 /// Field assignment within an object initializer.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ObjectInitializerField {
     pub name: String,
     pub name_span: Option<Span>,
@@ -295,7 +296,7 @@ pub struct ObjectInitializerField {
 }
 
 /// Supported initializer flavours for `new` expressions.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub enum NewInitializer {
     Object {
         fields: Vec<ObjectInitializerField>,
@@ -308,7 +309,7 @@ pub enum NewInitializer {
 }
 
 /// Parsed representation of a `new` expression.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct NewExpr {
     pub type_name: String,
     pub type_span: Option<Span>,
@@ -322,7 +323,7 @@ pub struct NewExpr {
 }
 
 /// Lambda expression representation.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct LambdaExpr {
     pub params: Vec<LambdaParam>,
     pub captures: Vec<String>,
@@ -332,7 +333,7 @@ pub struct LambdaExpr {
 }
 
 /// Lambda parameter description.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct LambdaParam {
     pub modifier: Option<LambdaParamModifier>,
     pub ty: Option<String>,
@@ -342,7 +343,7 @@ pub struct LambdaParam {
 }
 
 /// Optional modifier for a lambda parameter.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
 pub enum LambdaParamModifier {
     In,
     Ref,
@@ -361,21 +362,21 @@ impl LambdaParamModifier {
 }
 
 /// Lambda body can be either an expression or a block.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub enum LambdaBody {
     Expression(Box<ExprNode>),
     Block(LambdaBlock),
 }
 
 /// Block body captured from source text.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct LambdaBlock {
     pub text: String,
     pub span: Option<Span>,
 }
 
 /// Parsed interpolated string with structured segments.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct InterpolatedStringExpr {
     pub segments: Vec<InterpolatedStringSegment>,
     pub span: Option<Span>,
@@ -383,14 +384,14 @@ pub struct InterpolatedStringExpr {
 
 /// Segment within an interpolated string.
 #[allow(clippy::large_enum_variant)] // Maintain the natural representation of interpolated string pieces.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub enum InterpolatedStringSegment {
     Text(String),
     Expr(InterpolatedExprSegment),
 }
 
 /// Captured `quote(expr)` expression plus interpolation metadata.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct QuoteLiteral {
     pub expression: Box<ExprNode>,
     pub source: String,
@@ -401,7 +402,7 @@ pub struct QuoteLiteral {
 }
 
 /// Interpolation slot captured within a quoted expression.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct QuoteInterpolation {
     pub placeholder: String,
     pub expression: ExprNode,
@@ -410,7 +411,7 @@ pub struct QuoteInterpolation {
 }
 
 /// Span tracked relative to the containing expression text.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
 pub struct QuoteSourceSpan {
     pub start: usize,
     pub end: usize,
@@ -439,7 +440,7 @@ impl QuoteSourceSpan {
 }
 
 /// Metadata for an interpolated expression segment.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct InterpolatedExprSegment {
     pub expr: ExprNode,
     pub expr_text: String,
@@ -449,7 +450,7 @@ pub struct InterpolatedExprSegment {
 }
 
 /// Differentiates the syntax used to express a cast.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
 pub enum CastSyntax {
     /// Traditional parenthesised cast: `(T)expr`.
     Paren,
@@ -458,14 +459,14 @@ pub enum CastSyntax {
 }
 
 /// Operand accepted by the `sizeof`/`alignof` operators.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub enum SizeOfOperand {
     Type(String),
     Value(Box<ExprNode>),
 }
 
 /// Operand captured by the `nameof` operator.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct NameOfOperand {
     pub segments: Vec<String>,
     pub text: String,
@@ -480,7 +481,7 @@ impl NameOfOperand {
 }
 
 /// Assignment operators recognised by the expression parser.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
 pub enum AssignOp {
     Assign,
     NullCoalesceAssign,
@@ -497,7 +498,7 @@ pub enum AssignOp {
 }
 
 /// Literal value captured by the AST.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct LiteralConst {
     pub value: ConstValue,
     pub numeric: Option<NumericLiteralMetadata>,
@@ -518,7 +519,7 @@ impl LiteralConst {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ArrayLiteralExpr {
     pub explicit_type: Option<String>,
     pub explicit_type_span: Option<Span>,
@@ -530,7 +531,52 @@ pub struct ArrayLiteralExpr {
     pub span: Option<Span>,
 }
 
-#[derive(Clone, Debug)]
+impl ArrayLiteralExpr {
+    /// Returns the extent of each dimension when `elements` form a rectangular initializer of
+    /// `rank` dimensions (rows nested as untyped array literals), or `None` when rows are ragged
+    /// or not nested deeply enough.
+    #[must_use]
+    pub fn rectangular_shape(elements: &[ExprNode], rank: usize) -> Option<Vec<usize>> {
+        let mut shape = vec![elements.len()];
+        if rank <= 1 {
+            return Some(shape);
+        }
+        let mut inner: Option<Vec<usize>> = None;
+        for element in elements {
+            let ExprNode::ArrayLiteral(row) = element else {
+                return None;
+            };
+            if row.explicit_type.is_some() {
+                return None;
+            }
+            let row_shape = Self::rectangular_shape(&row.elements, rank - 1)?;
+            match &inner {
+                Some(existing) if *existing != row_shape => return None,
+                Some(_) => {}
+                None => inner = Some(row_shape),
+            }
+        }
+        shape.extend(inner.unwrap_or_else(|| vec![0; rank - 1]));
+        Some(shape)
+    }
+
+    /// Flattens a rectangular initializer into row-major element order.
+    #[must_use]
+    pub fn flatten_rectangular(elements: Vec<ExprNode>, rank: usize) -> Vec<ExprNode> {
+        if rank <= 1 {
+            return elements;
+        }
+        elements
+            .into_iter()
+            .flat_map(|element| match element {
+                ExprNode::ArrayLiteral(row) => Self::flatten_rectangular(row.elements, rank - 1),
+                other => vec![other],
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
 pub struct DefaultExpr {
     pub explicit_type: Option<String>,
     pub keyword_span: Option<Span>,
@@ -538,7 +584,7 @@ pub struct DefaultExpr {
 }
 
 /// Parsed expression tree used during MIR lowering.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub enum ExprNode {
     Literal(LiteralConst),
     Identifier(String),
@@ -625,7 +671,7 @@ impl ExprNode {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct PatternGuardExpr {
     pub expr: ExprNode,
     pub span: Option<Span>,
@@ -201,18 +201,32 @@ impl ExprParser {
         };
 
         let mut saw_array_rank = false;
-        while let (Some(open), Some(close)) =
-            (self.tokens.get(next_index), self.tokens.get(next_index + 1))
-        {
-            if matches!(open.kind, TokenKind::Punctuation('['))
-                && matches!(close.kind, TokenKind::Punctuation(']'))
-            {
-                type_name.push_str("[]");
-                next_index += 2;
-                saw_array_rank = true;
-            } else {
+        while matches!(
+            self.tokens.get(next_index).map(|token| &token.kind),
+            Some(TokenKind::Punctuation('['))
+        ) {
+            let mut commas = 0usize;
+            while matches!(
+                self.tokens
+                    .get(next_index + 1 + commas)
+                    .map(|token| &token.kind),
+                Some(TokenKind::Punctuation(','))
+            ) {
+                commas += 1;
+            }
+            if !matches!(
+                self.tokens
+                    .get(next_index + 1 + commas)
+                    .map(|token| &token.kind),
+                Some(TokenKind::Punctuation(']'))
+            ) {
                 break;
             }
+            type_name.push('[');
+            type_name.push_str(&",".repeat(commas));
+            type_name.push(']');
+            next_index += commas + 2;
+            saw_array_rank = true;
         }
 
         if !saw_array_rank {
@@ -500,7 +514,14 @@ impl ExprParser {
                 }
                 fields.push(field);
             } else {
-                let element = self.parse_expression()?;
+                let element = if self.peek_punctuation('{') {
+                    let open = self.advance().ok_or_else(|| {
+                        ExprError::new("expected `{` to start initializer row", Some(open_span))
+                    })?;
+                    self.parse_initializer_row(open.span)?
+                } else {
+                    self.parse_expression()?
+                };
                 match mode {
                     Some(InitializerMode::Object) => {
                         return Err(ExprError::new(
@@ -541,6 +562,59 @@ impl ExprParser {
         }
     }
 
+    /// Parses a nested `{ ... }` row of a rectangular array initializer into an untyped
+    /// array literal so `new T[,] { { 1, 2 }, { 3, 4 } }` shares the `[[1, 2], [3, 4]]` shape.
+    fn parse_initializer_row(&mut self, open_span: Span) -> Result<ExprNode, ExprError> {
+        let open_index = self.index.saturating_sub(1);
+        let mut elements = Vec::new();
+        let mut element_spans = Vec::new();
+        let mut trailing_comma = false;
+        loop {
+            if self.expect_punctuation('}') {
+                break;
+            }
+            let elem_start = self.index;
+            let element = if self.peek_punctuation('{') {
+                let open = self.advance().ok_or_else(|| {
+                    ExprError::new("expected `{` to start initializer row", Some(open_span))
+                })?;
+                self.parse_initializer_row(open.span)?
+            } else {
+                self.parse_expression()?
+            };
+            elements.push(element);
+            element_spans.push(self.span_for_range(elem_start, self.index));
+            if self.expect_punctuation('}') {
+                break;
+            }
+            if self.expect_punctuation(',') {
+                if self.expect_punctuation('}') {
+                    trailing_comma = true;
+                    break;
+                }
+                continue;
+            }
+            return Err(ExprError::new(
+                "expected `,` or `}` after initializer row element",
+                self.peek().map(|token| token.span).or(Some(open_span)),
+            ));
+        }
+        let close_span = self
+            .tokens
+            .get(self.index.saturating_sub(1))
+            .map(|token| token.span);
+        Ok(ExprNode::ArrayLiteral(ArrayLiteralExpr {
+            explicit_type: None,
+            explicit_type_span: None,
+            elements,
+            element_spans,
+            open_span: Some(open_span),
+            close_span,
+            trailing_comma,
+            span: self.span_for_range(open_index, self.index),
+        }))
+    }
+
     fn try_parse_object_initializer_field(
         &mut self,
     ) -> Result<Option<ObjectInitializerField>, ExprError> {
@@ -2,9 +2,9 @@ use super::*;
 use crate::frontend::diagnostics::Span;
 use crate::mir::{BinOp, ConstValue};
 use crate::syntax::expr::builders::{
-    AssignOp, CallArgumentModifier, CallArgumentName, InlineAsmOperandMode, InlineAsmRegister,
-    InlineAsmRegisterClass, InlineAsmTemplateOperandRef, InlineAsmTemplatePiece, InlineBindingKind,
-    LambdaBody, LiteralConst, NewInitializer,
+    ArrayLiteralExpr, AssignOp, CallArgumentModifier, CallArgumentName, InlineAsmOperandMode,
+    InlineAsmRegister, InlineAsmRegisterClass, InlineAsmTemplateOperandRef, InlineAsmTemplatePiece,
+    InlineBindingKind, LambdaBody, LiteralConst, NewInitializer,
 };
 use crate::syntax::pattern::PatternNode;
 
@@ -631,6 +631,39 @@ fn parses_array_literal_basic() {
     }
 }
 
+#[test]
+fn parses_rectangular_initializer_rows_as_nested_literals() {
+    let expr = parse_expression("new int[,] { { 1, 2 }, { 3, 4 } }")
+        .expect("rectangular initializer should parse");
+    let ExprNode::New(new_expr) = expr else {
+        panic!("expected new expression");
+    };
+    assert_eq!(new_expr.type_name, "int[,]");
+    let Some(NewInitializer::Collection { elements, .. }) = new_expr.initializer else {
+        panic!("expected collection initializer");
+    };
+    assert_eq!(
+        ArrayLiteralExpr::rectangular_shape(&elements, 2),
+        Some(vec![2, 2])
+    );
+}
+
+#[test]
+fn parses_typed_rectangular_array_literal() {
+    let expr = parse_expression("int[,] [[1, 2], [3, 4]]")
+        .expect("typed rectangular literal should parse");
+    match expr {
+        ExprNode::ArrayLiteral(array) => {
+            assert_eq!(array.explicit_type.as_deref(), Some("int[,]"));
+            assert_eq!(
+                ArrayLiteralExpr::rectangular_shape(&array.elements, 2),
+                Some(vec![2, 2])
+            );
+        }
+        other => panic!("expected typed array literal, found {other:?}"),
+    }
+}
+
 #[test]
 fn parses_array_literal_with_trailing_comma() {
     let expr = parse_expression("[a, b,]").expect("array literal should parse");
@@ -3,6 +3,7 @@
 use crate::frontend::lexer::{
     NumericBase, NumericLiteral, NumericLiteralKind, NumericLiteralSuffix,
 };
+use serde::Serialize;
 
 /// Parsed representation of an integer literal.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -13,7 +14,7 @@ pub struct IntegerLiteral {
     pub suffix: Option<NumericLiteralSuffix>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum IntegerWidth {
     W8,
     W16,
@@ -37,7 +38,7 @@ impl IntegerWidth {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum NumericLiteralType {
     Signed(IntegerWidth),
     Unsigned(IntegerWidth),
@@ -48,7 +49,7 @@ pub enum NumericLiteralType {
     Decimal,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct NumericLiteralMetadata {
     pub literal_type: NumericLiteralType,
     pub suffix_text: Option<String>,
@@ -7,19 +7,20 @@ use crate::frontend::lexer::{Keyword, NumericLiteral, NumericLiteralKind, Token,
 use crate::frontend::literals::{StringLiteralContents, StringSegment};
 use crate::mir::{ConstValue, PatternBindingMode, PatternBindingMutability};
 use crate::syntax::numeric;
+use serde::Serialize;
 
 mod composite;
 mod guard;
 mod primitive;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PatternAst {
     pub node: PatternNode,
     pub span: Option<Span>,
     pub metadata: PatternMetadata,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum PatternNode {
     Wildcard,
     Literal(ConstValue),
@@ -56,7 +57,7 @@ pub enum PatternNode {
     Record(RecordPatternNode),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BindingPatternNode {
     pub name: String,
     pub mutability: PatternBindingMutability,
@@ -64,7 +65,7 @@ pub struct BindingPatternNode {
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PatternFieldNode {
     pub name: String,
     pub pattern: PatternNode,
@@ -72,14 +73,14 @@ pub struct PatternFieldNode {
     pub name_span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum VariantPatternFieldsNode {
     Unit,
     Tuple(Vec<PatternNode>),
     Struct(Vec<PatternFieldNode>),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ListPatternNode {
     pub prefix: Vec<PatternNode>,
     pub slice: Option<Box<PatternNode>>,
@@ -88,14 +89,14 @@ pub struct ListPatternNode {
     pub slice_span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct RecordPatternNode {
     pub path: Option<Vec<String>>,
     pub fields: Vec<PatternFieldNode>,
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum RelationalOp {
     Less,
     LessEqual,
@@ -103,38 +104,38 @@ pub enum RelationalOp {
     GreaterEqual,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum PatternBinaryOp {
     And,
     Or,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PatternExpression {
     pub text: String,
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct PatternMetadata {
     pub bindings: Vec<PatternBindingMetadata>,
     pub list_slices: Vec<ListSliceMetadata>,
     pub record_fields: Vec<RecordFieldMetadata>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PatternBindingMetadata {
     pub name: String,
     pub span: Option<Span>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ListSliceMetadata {
     pub span: Option<Span>,
     pub binding: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct RecordFieldMetadata {
     pub name: String,
     pub name_span: Option<Span>,
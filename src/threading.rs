@@ -1,6 +1,13 @@
-//! Global thread runtime configuration and capability checks.
+//! Global thread runtime configuration, capability checks, and the compiler's own
+//! worker pool.
 
-use std::sync::{OnceLock, RwLock};
+use std::cell::Cell;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, OnceLock, RwLock};
+use std::thread;
+
+use crate::mir::{configure_pointer_width, pointer_align, pointer_size};
 
 /// Availability of the native threading runtime for the current compilation.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -45,3 +52,134 @@ pub fn thread_runtime_mode() -> ThreadRuntimeMode {
 pub fn threads_supported() -> bool {
     matches!(thread_runtime_mode(), ThreadRuntimeMode::Supported)
 }
+
+/// Stack size of compiler worker threads; matches the driver thread so deeply nested
+/// bodies behave the same at every job count.
+const WORKER_STACK_SIZE: usize = 32 * 1024 * 1024;
+
+thread_local! {
+    /// Zero means "not configured"; [`jobs`] then falls back to the host's parallelism.
+    /// Scoped to the thread (like the MIR pointer width) so concurrent compilations in one
+    /// process, e.g. parallel tests, cannot observe each other's `--jobs`.
+    static JOBS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Configure how many worker threads the current thread's compilation may use
+/// (`-j/--jobs`). `None` restores the default of one job per available CPU.
+pub fn configure_jobs(jobs: Option<usize>) {
+    JOBS.with(|cell| cell.set(jobs.unwrap_or(0)));
+}
+
+/// The `--jobs` value configured on the current thread, if any. Threads that run part of
+/// a compilation on behalf of another re-apply it with [`configure_jobs`].
+#[must_use]
+pub fn configured_jobs() -> Option<usize> {
+    match JOBS.with(Cell::get) {
+        0 => None,
+        jobs => Some(jobs),
+    }
+}
+
+/// Number of worker threads the compiler may use.
+#[must_use]
+pub fn jobs() -> usize {
+    configured_jobs()
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, NonZeroUsize::get))
+}
+
+/// Apply `f` to every item using up to [`jobs`] threads and return the results in input
+/// order, so callers observe the same output as a serial loop.
+///
+/// Workers inherit the calling thread's pointer width and run nested calls serially, so a
+/// compilation never occupies more than [`jobs`] threads. A panic in any worker is resumed
+/// on the calling thread once the others have finished.
+pub fn parallel_map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let workers = jobs().min(items.len());
+    if workers <= 1 {
+        return items.iter().map(f).collect();
+    }
+    let (size, align) = (pointer_size(), pointer_align());
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<R>>> = Mutex::new((0..items.len()).map(|_| None).collect());
+    thread::scope(|scope| {
+        let handles = (0..workers)
+            .map(|index| {
+                let (next, results, f) = (&next, &results, &f);
+                thread::Builder::new()
+                    .name(format!("chic-job-{index}"))
+                    .stack_size(WORKER_STACK_SIZE)
+                    .spawn_scoped(scope, move || {
+                        configure_pointer_width(size, align);
+                        configure_jobs(Some(1));
+                        loop {
+                            let index = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(item) = items.get(index) else {
+                                break;
+                            };
+                            let result = f(item);
+                            if let Ok(mut results) = results.lock() {
+                                results[index] = Some(result);
+                            }
+                        }
+                    })
+                    .expect("failed to spawn compiler worker thread")
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            if let Err(payload) = handle.join() {
+                std::panic::resume_unwind(payload);
+            }
+        }
+    });
+    results
+        .into_inner()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .into_iter()
+        .map(|result| result.expect("every item is processed by a worker"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parallel_map_preserves_input_order() {
+        configure_jobs(Some(4));
+        let items = (0..257).collect::<Vec<u32>>();
+        let squares = parallel_map(&items, |value| value * value);
+        configure_jobs(None);
+        assert_eq!(
+            squares,
+            items.iter().map(|value| value * value).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn parallel_map_workers_inherit_pointer_width() {
+        configure_jobs(Some(2));
+        configure_pointer_width(4, 4);
+        let widths = parallel_map(&[0, 1, 2, 3], |_| pointer_size());
+        configure_pointer_width(8, 8);
+        configure_jobs(None);
+        assert_eq!(widths, vec![4; 4]);
+    }
+
+    #[test]
+    fn configured_jobs_are_scoped_to_the_thread() {
+        configure_jobs(Some(3));
+        let other = thread::spawn(configured_jobs)
+            .join()
+            .expect("probe thread panicked");
+        let workers = parallel_map(&[0, 1, 2], |_| jobs());
+        configure_jobs(None);
+        assert_eq!(other, None);
+        assert_eq!(workers, vec![1; 3], "nested maps run serially on workers");
+        assert_eq!(configured_jobs(), None);
+    }
+}
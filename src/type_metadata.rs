@@ -1,3 +1,4 @@
+use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 
 use crate::drop_glue::SynthesisedDropGlue;
@@ -7,7 +8,7 @@ use crate::mir::table::{MIN_ALIGN, align_to};
 use crate::mir::{MirModule, TypeLayout};
 use crate::type_identity::type_identity_for_name;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum TypeVariance {
     Invariant,
     Covariant,
@@ -37,7 +38,7 @@ impl From<AstVariance> for TypeVariance {
 
 /// Bitflags describing additional semantic properties of a type.
 #[repr(transparent)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
 pub struct TypeFlags {
     bits: u32,
 }
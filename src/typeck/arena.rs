@@ -199,6 +199,9 @@ pub struct PackageContext {
     pub item_units: Option<Vec<usize>>,
     pub unit_packages: Vec<Option<String>>,
     pub unit_import_resolvers: Option<Vec<ImportResolver>>,
+    /// Units whose function bodies an earlier build already checked cleanly and whose
+    /// inputs have not changed since; their bodies are not validated again.
+    pub unchanged_units: HashSet<usize>,
 }
 
 impl PackageContext {
@@ -604,6 +607,13 @@ pub(super) struct ImplInfo {
     pub(super) span: Option<Span>,
 }
 
+impl ImplInfo {
+    /// The generic parameter targeted by a blanket impl (`impl<T> Trait for T`), if any.
+    pub(super) fn blanket_param(&self) -> Option<&GenericParam> {
+        self.generics.as_ref()?.type_param_named_by(&self.target)
+    }
+}
+
 #[derive(Clone)]
 pub(super) struct OperatorSignatureInfo {
     pub(super) kind: OperatorKind,
@@ -617,6 +627,7 @@ pub(super) struct PendingBody<'a> {
     pub(super) body: &'a Block,
     pub(super) namespace: Option<String>,
     pub(super) context_type: Option<String>,
+    pub(super) unit: Option<usize>,
 }
 
 pub(super) struct TypeChecker<'a> {
@@ -2,8 +2,8 @@
 
 use crate::frontend::ast::Module;
 use crate::frontend::ast::{
-    ClassDecl, ClassMember, ExtensionDecl, ExtensionMember, InterfaceDecl, InterfaceMember, Item,
-    StructDecl, TraitDecl, TraitMember,
+    ClassDecl, ClassMember, ExtensionDecl, ExtensionMember, ImplDecl, ImplMember, InterfaceDecl,
+    InterfaceMember, Item, StructDecl, TraitDecl, TraitMember,
 };
 
 const ALLOCATION_CATEGORY_COUNT: usize = 3;
@@ -118,13 +118,13 @@ fn visit_item_for_budgets(item: &Item, counters: &mut AllocationCounters) {
         Item::Interface(decl) => add_interface_budgets(decl, counters),
         Item::Trait(decl) => add_trait_budgets(decl, counters),
         Item::Extension(decl) => add_extension_budgets(decl, counters),
+        Item::Impl(decl) => add_impl_budgets(decl, counters),
         Item::Delegate(_) => {
             counters.type_infos += 1;
             counters.signatures += 1;
         }
         Item::Namespace(ns) => visit_items_for_budgets(&ns.items, counters),
         Item::TestCase(_)
-        | Item::Impl(_)
         | Item::Import(_)
         | Item::Const(_)
         | Item::Static(_)
@@ -176,3 +176,11 @@ fn add_extension_budgets(decl: &ExtensionDecl, counters: &mut AllocationCounters
         .filter(|member| matches!(member, ExtensionMember::Method(_)))
         .count();
 }
+
+fn add_impl_budgets(decl: &ImplDecl, counters: &mut AllocationCounters) {
+    counters.signatures += decl
+        .members
+        .iter()
+        .filter(|member| matches!(member, ImplMember::Method(_)))
+        .count();
+}
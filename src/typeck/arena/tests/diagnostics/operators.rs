@@ -19,6 +19,13 @@ const CASES: &[ArenaDiagnosticCase] = &[
         binary_operator_requires_owner_parameter,
         Expectation::contains(&["must have at least one parameter of type `Value`"]),
     ),
+    ArenaDiagnosticCase::custom(
+        "compound_assignment_operator_requires_owner_target",
+        compound_assignment_operator_requires_owner_target,
+        Expectation::contains(&[
+            "operator += in `Numbers::Value` must take `Value` as its first (target) parameter",
+        ]),
+    ),
     ArenaDiagnosticCase::custom(
         "conversion_operator_requires_owner_participation",
         conversion_operator_requires_owner_participation,
@@ -99,6 +106,37 @@ fn binary_operator_requires_owner_parameter(fixture: &ArenaDiagnosticFixture) ->
     fixture.check_module(&module, &[], &TypeLayoutTable::default())
 }
 
+fn compound_assignment_operator_requires_owner_target(
+    fixture: &ArenaDiagnosticFixture,
+) -> TypeCheckResult {
+    let mut module = Module::new(Some("Numbers".into()));
+    module.push_item(Item::Class(ClassDecl {
+        visibility: Visibility::Public,
+        kind: ClassKind::Class,
+        name: "Value".into(),
+        bases: Vec::new(),
+        members: vec![ClassMember::Method(operator_function(
+            OperatorKind::CompoundAssignment(BinaryOperator::Add),
+            &["int", "Value"],
+            "Value",
+        ))],
+        nested_types: Vec::new(),
+        thread_safe_override: None,
+        shareable_override: None,
+        copy_override: None,
+        doc: None,
+        attributes: Vec::new(),
+        generics: None,
+        di_service: None,
+        di_module: false,
+        is_static: false,
+        is_abstract: false,
+        is_sealed: false,
+    }));
+
+    fixture.check_module(&module, &[], &TypeLayoutTable::default())
+}
+
 fn conversion_operator_requires_owner_participation(
     fixture: &ArenaDiagnosticFixture,
 ) -> TypeCheckResult {
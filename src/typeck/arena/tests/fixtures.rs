@@ -311,6 +311,7 @@ pub(super) fn parameterless_ctor(visibility: Visibility) -> ClassMember {
         doc: None,
         span: None,
         attributes: Vec::new(),
+        name_span: None,
         di_inject: None,
     })
 }
@@ -1,12 +1,14 @@
 use super::arena::{EffectConstraintRecord, TypeChecker, TypeKind};
 use super::diagnostics::{self, codes};
 use super::helpers::{base_type_name, canonical_type_name, type_expr_path};
+use super::impl_predicate::{ImplFact, ImplFacts, implements_trait, layout_has_auto_trait};
 use super::traits::AutoTraitCheck;
 use super::{ConstraintKind, TypeConstraint};
 use crate::frontend::ast::{GenericConstraintKind, GenericParam, Parameter, TypeExpr};
 use crate::frontend::diagnostics::Span;
 use crate::frontend::import_resolver::Resolution as ImportResolution;
 use crate::frontend::parser::parse_type_expression_text;
+use crate::primitives::PrimitiveKind;
 use crate::threading;
 use std::collections::HashSet;
 
@@ -583,25 +585,8 @@ impl<'a> TypeChecker<'a> {
         );
     }
 
-    fn trait_impl_for(&self, ty: &str, trait_name: &str) -> bool {
-        let target = ty.replace('.', "::");
-        let target_base = target.rsplit("::").next().unwrap_or(target.as_str());
-        let trait_key = trait_name.replace('.', "::");
-        let trait_base = trait_key.rsplit("::").next().unwrap_or(trait_key.as_str());
-        self.impls.iter().any(|info| {
-            let impl_trait = info.trait_name.as_ref().map(|name| name.replace('.', "::"));
-            let impl_trait_base = impl_trait
-                .as_deref()
-                .and_then(|name| name.rsplit("::").next());
-            let impl_target = canonical_type_name(&info.target);
-            let impl_target_base = impl_target
-                .rsplit("::")
-                .next()
-                .unwrap_or(impl_target.as_str());
-            (impl_trait.as_deref() == Some(trait_key.as_str())
-                || impl_trait_base == Some(trait_base))
-                && (impl_target == target || impl_target_base == target_base)
-        })
+    pub(super) fn trait_impl_for(&self, ty: &str, trait_name: &str) -> bool {
+        implements_trait(self, ty, trait_name, &mut HashSet::new())
     }
 
     pub(super) fn lookup_context_generic_param(
@@ -625,6 +610,43 @@ impl<'a> TypeChecker<'a> {
     }
 }
 
+impl ImplFacts for TypeChecker<'_> {
+    fn impl_facts(&self) -> impl Iterator<Item = ImplFact<'_>> {
+        self.impls.iter().filter_map(|info| {
+            Some(ImplFact {
+                trait_name: info.trait_name.as_ref()?.replace('.', "::"),
+                target: canonical_type_name(&info.target),
+                blanket_param: info.blanket_param(),
+            })
+        })
+    }
+
+    fn implements_interface(&self, ty: &str, interface: &str) -> bool {
+        self.type_implements_interface_by_name(ty, interface)
+    }
+
+    fn satisfies_constraint(&self, ty: &str, constraint: &GenericConstraintKind) -> bool {
+        let expr = TypeExpr::simple(ty);
+        let primitive = self.type_layouts.primitive_registry.kind_for_name(ty);
+        match constraint {
+            GenericConstraintKind::Struct => {
+                self.type_is_value_type(&expr, None)
+                    || primitive.is_some_and(|kind| {
+                        !matches!(kind, PrimitiveKind::String | PrimitiveKind::Str)
+                    })
+            }
+            GenericConstraintKind::Class => self.type_is_reference_type(&expr, None),
+            GenericConstraintKind::DefaultConstructor => {
+                primitive.is_some() || self.type_has_public_default_constructor(&expr, None)
+            }
+            GenericConstraintKind::AutoTrait(required) => {
+                layout_has_auto_trait(self.type_layouts, ty, *required)
+            }
+            GenericConstraintKind::NotNull | GenericConstraintKind::Type(_) => true,
+        }
+    }
+}
+
 pub(super) fn parse_type_text(text: &str) -> Option<TypeExpr> {
     let trimmed = text.trim();
     if let Some(expr) = parse_type_expression_text(trimmed) {
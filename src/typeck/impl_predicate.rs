@@ -0,0 +1,137 @@
+//! The "does a type implement a trait" predicate shared by type checking and MIR lowering, so
+//! the set of types a blanket impl covers is the same in coherence checks and codegen.
+
+use std::collections::HashSet;
+
+use crate::frontend::ast::{
+    AutoTraitConstraint, GenericConstraintKind, GenericParam, GenericParams, TypeExpr,
+};
+use crate::mir::{AutoTraitStatus, Ty, TypeLayoutTable};
+
+/// A trait impl as seen by [`implements_trait`].
+pub(crate) struct ImplFact<'a> {
+    /// Implemented trait, `::`-qualified.
+    pub(crate) trait_name: String,
+    /// Canonical impl target; unused for blanket impls.
+    pub(crate) target: String,
+    /// Type parameter a blanket impl (`impl<T> Trait for T`) targets.
+    pub(crate) blanket_param: Option<&'a GenericParam>,
+}
+
+/// Source of the impls and interface relationships the predicate consults.
+pub(crate) trait ImplFacts {
+    fn impl_facts(&self) -> impl Iterator<Item = ImplFact<'_>>;
+
+    /// Whether `ty` names `interface` or lists it among its (transitive) bases.
+    fn implements_interface(&self, ty: &str, interface: &str) -> bool;
+
+    /// Whether `ty` meets a `struct`, `class`, `new()` or auto-trait constraint.
+    fn satisfies_constraint(&self, ty: &str, constraint: &GenericConstraintKind) -> bool;
+}
+
+/// Checks whether `ty` implements `trait_name` through a concrete impl or through a blanket
+/// impl whose bounds `ty` satisfies. `visiting` breaks cycles between blanket impls that bound
+/// on each other.
+pub(crate) fn implements_trait(
+    facts: &impl ImplFacts,
+    ty: &str,
+    trait_name: &str,
+    visiting: &mut HashSet<String>,
+) -> bool {
+    let target = ty.replace('.', "::");
+    let trait_key = trait_name.replace('.', "::");
+    let goal = format!("{target}: {trait_key}");
+    if !visiting.insert(goal.clone()) {
+        return false;
+    }
+    let found = facts.impl_facts().any(|fact| {
+        if !names_match(&fact.trait_name, &trait_key) {
+            return false;
+        }
+        match fact.blanket_param {
+            Some(param) => satisfies_blanket_bounds(facts, &target, param, visiting),
+            None => names_match(&fact.target, &target),
+        }
+    });
+    visiting.remove(&goal);
+    found
+}
+
+/// Whether `ty` meets every constraint declared on a blanket impl parameter. Trait and
+/// interface bounds are expected to be qualified already.
+pub(crate) fn satisfies_blanket_bounds(
+    facts: &impl ImplFacts,
+    ty: &str,
+    param: &GenericParam,
+    visiting: &mut HashSet<String>,
+) -> bool {
+    let Some(data) = param.as_type() else {
+        return false;
+    };
+    data.constraints
+        .iter()
+        .all(|constraint| match &constraint.kind {
+            GenericConstraintKind::Type(bound) => {
+                facts.implements_interface(ty, &bound.name)
+                    || implements_trait(facts, ty, &bound.name, visiting)
+            }
+            GenericConstraintKind::NotNull => !ty.ends_with('?'),
+            kind => facts.satisfies_constraint(ty, kind),
+        })
+}
+
+/// Rewrites the trait and interface bounds on the blanket parameter `target` names to the
+/// qualified names `resolve` finds, so [`names_match`] compares them in full. Bounds `resolve`
+/// cannot place keep the name they were written with.
+pub(crate) fn qualify_blanket_bounds(
+    generics: &mut GenericParams,
+    target: &TypeExpr,
+    mut resolve: impl FnMut(&TypeExpr) -> Option<String>,
+) {
+    let Some(name) = generics
+        .type_param_named_by(target)
+        .map(|param| param.name.clone())
+    else {
+        return;
+    };
+    let Some(data) = generics
+        .params
+        .iter_mut()
+        .find(|param| param.name == name)
+        .and_then(GenericParam::as_type_mut)
+    else {
+        return;
+    };
+    for constraint in &mut data.constraints {
+        if let GenericConstraintKind::Type(bound) = &mut constraint.kind
+            && let Some(qualified) = resolve(bound)
+        {
+            bound.name = qualified;
+        }
+    }
+}
+
+/// Whether the layout of `ty` proves the auto trait `required`.
+pub(crate) fn layout_has_auto_trait(
+    layouts: &TypeLayoutTable,
+    ty: &str,
+    required: AutoTraitConstraint,
+) -> bool {
+    let traits = layouts.auto_traits_for_type(&Ty::named(ty));
+    let status = match required {
+        AutoTraitConstraint::ThreadSafe => traits.thread_safe,
+        AutoTraitConstraint::Shareable => traits.shareable,
+    };
+    status == AutoTraitStatus::Yes
+}
+
+/// Names match when equal. A bare name (a primitive, or an impl target recorded without its
+/// namespace) matches a qualified name ending in it; two qualified names must agree in full,
+/// so `A::Display` and `B::Display` stay distinct.
+pub(crate) fn names_match(left: &str, right: &str) -> bool {
+    match (left.rsplit_once("::"), right.rsplit_once("::")) {
+        (Some(_), Some(_)) | (None, None) => left == right,
+        (Some((_, base)), None) => base == right,
+        (None, Some((_, base))) => left == base,
+    }
+}
@@ -3,6 +3,7 @@ mod coercions;
 mod diagnostics;
 mod generics;
 mod helpers;
+mod impl_predicate;
 mod layout_driver;
 mod queries;
 mod registry;
@@ -15,5 +16,9 @@ pub use arena::{
     ConstraintKind, PackageContext, TraitFulfillmentReport, TypeCheckResult, TypeConstraint,
     check_module, check_module_with_context,
 };
+pub(crate) use impl_predicate::{
+    ImplFact, ImplFacts, layout_has_auto_trait, names_match, qualify_blanket_bounds,
+    satisfies_blanket_bounds,
+};
 pub use queries::TypeckQueries;
 pub use trait_solver::TraitSolverMetrics;
@@ -32,7 +32,9 @@ use crate::frontend::diagnostics::Span;
 use crate::frontend::import_resolver::Resolution as ImportResolution;
 use crate::frontend::parser::parse_type_expression_text_with_span;
 use crate::mir::{AutoTraitStatus, ConstValue};
-use crate::syntax::expr::{ExprNode, LiteralConst, NewExpr, NewInitializer, SizeOfOperand};
+use crate::syntax::expr::{
+    ArrayLiteralExpr, ExprNode, LiteralConst, NewExpr, NewInitializer, SizeOfOperand,
+};
 use std::collections::{HashSet, VecDeque};
 
 impl<'a> TypeChecker<'a> {
@@ -277,12 +279,19 @@ impl<'a> TypeChecker<'a> {
             body,
             namespace: namespace.map(str::to_string),
             context_type: context_type.map(str::to_string),
+            unit: self.current_unit,
         });
     }
 
     pub(super) fn run_pending_body_validations(&mut self) {
         let pending = std::mem::take(&mut self.pending_bodies);
         for pending in pending {
+            if pending
+                .unit
+                .is_some_and(|unit| self.package_context.unchanged_units.contains(&unit))
+            {
+                continue;
+            }
             self.validate_function_body(
                 &pending.name,
                 pending.body,
@@ -1001,6 +1010,10 @@ impl<'a> TypeChecker<'a> {
                 return None;
             }
             ImportResolution::NotFound => {
+                if self.is_enum_variant_new_target(&type_expr, namespace, context_type) {
+                    // Payload fields are checked against the variant layout during lowering.
+                    return None;
+                }
                 if treat_as_value_type {
                     self.canonical_type_name(&type_expr)
                 } else {
@@ -1143,6 +1156,26 @@ impl<'a> TypeChecker<'a> {
         })
     }
 
+    /// `new Enum.Variant { ... }` constructs a payload variant of a visible enum.
+    fn is_enum_variant_new_target(
+        &mut self,
+        type_expr: &TypeExpr,
+        namespace: Option<&str>,
+        context_type: Option<&str>,
+    ) -> bool {
+        if type_expr.generic_arguments().is_some() || type_expr.base.len() < 2 {
+            return false;
+        }
+        let owner = type_expr.base[..type_expr.base.len() - 1].join(".");
+        let ImportResolution::Found(owner_name) =
+            self.resolve_type_for_expr(&TypeExpr::simple(owner), namespace, context_type)
+        else {
+            return false;
+        };
+        self.resolve_type_info(&owner_name)
+            .is_some_and(|info| matches!(info.kind, TypeKind::Enum))
+    }
+
     fn check_array_new(
         &mut self,
         new_expr: &NewExpr,
@@ -1152,23 +1185,31 @@ impl<'a> TypeChecker<'a> {
     ) -> Option<ConstructedTypeInfo> {
         let type_span = new_expr.type_span.or(new_expr.span).or(span);
         let ranks: Vec<_> = type_expr.array_ranks().collect();
-        if ranks.len() > 1 || ranks.first().is_some_and(|rank| rank.dimensions > 1) {
+        if ranks.len() > 1 && ranks.iter().any(|rank| rank.dimensions > 1) {
             self.emit_error(
                 codes::ARRAY_RANK_UNSUPPORTED,
                 type_span,
-                "multi-dimensional array ranks are not supported; use jagged arrays (`T[][]`) instead",
+                "arrays mixing rectangular (`T[,]`) and jagged (`T[][]`) ranks are not supported",
             );
             return None;
         }
-        if let Some(lengths) = new_expr.array_lengths.as_ref() {
-            if lengths.len() > 1 {
-                self.emit_error(
-                    codes::ARRAY_RANK_UNSUPPORTED,
-                    type_span,
-                    "multi-dimensional array lengths are not supported; use jagged arrays (`T[][]`) instead",
-                );
-                return None;
-            }
+        let rank = ranks.last().map_or(1, |rank| rank.dimensions);
+        let explicit_lengths = new_expr
+            .array_lengths
+            .as_deref()
+            .filter(|lengths| !lengths.is_empty());
+        if let Some(lengths) = explicit_lengths
+            && lengths.len() != rank
+        {
+            self.emit_error(
+                codes::ARRAY_RANK_UNSUPPORTED,
+                type_span,
+                format!(
+                    "array of rank {rank} requires {rank} length expression(s) but {} were supplied",
+                    lengths.len()
+                ),
+            );
+            return None;
         }
         if !new_expr.args.is_empty() {
             self.emit_error(
@@ -1179,7 +1220,7 @@ impl<'a> TypeChecker<'a> {
             return None;
         }
 
-        let mut initializer_len = None;
+        let mut initializer_shape = None;
         if let Some(initializer) = &new_expr.initializer {
             match initializer {
                 NewInitializer::Object {
@@ -1192,18 +1233,26 @@ impl<'a> TypeChecker<'a> {
                     );
                     return None;
                 }
-                NewInitializer::Collection { elements, .. } => {
-                    initializer_len = Some(elements.len());
+                NewInitializer::Collection {
+                    elements,
+                    span: init_span,
+                } => {
+                    let Some(shape) = ArrayLiteralExpr::rectangular_shape(elements, rank) else {
+                        self.emit_error(
+                            codes::ARRAY_LENGTH_MISMATCH,
+                            init_span.or(type_span),
+                            format!(
+                                "initializer for an array of rank {rank} must nest rows of equal length"
+                            ),
+                        );
+                        return None;
+                    };
+                    initializer_shape = Some(shape);
                 }
             }
         }
 
-        let explicit_length_expr = new_expr
-            .array_lengths
-            .as_ref()
-            .and_then(|lengths| lengths.get(0));
-
-        if initializer_len.is_none() && explicit_length_expr.is_none() {
+        if initializer_shape.is_none() && explicit_lengths.is_none() {
             self.emit_error(
                 codes::ARRAY_LENGTH_REQUIRED,
                 type_span,
@@ -1212,18 +1261,21 @@ impl<'a> TypeChecker<'a> {
             return None;
         }
 
-        if let Some(init_count) = initializer_len {
-            if let Some(length_expr) = explicit_length_expr {
+        if let (Some(shape), Some(lengths)) = (initializer_shape, explicit_lengths) {
+            for (dimension, (length_expr, &extent)) in lengths.iter().zip(&shape).enumerate() {
                 match self.eval_array_length_literal(length_expr) {
-                    Some(value) if value == init_count => {}
+                    Some(value) if value == extent => {}
                     Some(_) => {
-                        self.emit_error(
-                            codes::ARRAY_LENGTH_MISMATCH,
-                            type_span,
+                        let message = if rank == 1 {
                             format!(
-                                "array length does not match initializer element count ({init_count})"
-                            ),
-                        );
+                                "array length does not match initializer element count ({extent})"
+                            )
+                        } else {
+                            format!(
+                                "array length for dimension {dimension} does not match initializer extent ({extent})"
+                            )
+                        };
+                        self.emit_error(codes::ARRAY_LENGTH_MISMATCH, type_span, message);
                         return None;
                     }
                     None => {
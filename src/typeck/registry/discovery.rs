@@ -2,9 +2,11 @@ use super::super::arena::{
     ImplInfo, ObjectSafetyViolation, ObjectSafetyViolationKind, TraitAssociatedTypeInfo, TraitInfo,
     TraitMethodInfo, TraitObjectSafety, TypeChecker, TypeInfo, TypeKind,
 };
+use super::super::helpers::{base_type_name, canonical_type_name};
+use super::super::impl_predicate::qualify_blanket_bounds;
 use super::diagnostics::codes;
 use super::hooks::RegisteredItemKind;
-use super::{qualify, returns_self_value, signature_from};
+use super::{ImportResolution, qualify, returns_self_value, signature_from};
 use crate::frontend::ast::{ImplDecl, ImplMember, TraitDecl, TraitMember};
 use crate::mir::AutoTraitOverride;
 use std::collections::HashSet;
@@ -199,10 +201,107 @@ impl<'a> TypeChecker<'a> {
     }
 
     pub(super) fn register_impl(&mut self, impl_decl: &'a ImplDecl, namespace: Option<&str>) {
-        if let Some(trait_ref) = &impl_decl.trait_ref {
-            self.ensure_type_expr(trait_ref, namespace, None, impl_decl.span);
+        if impl_decl.trait_ref.is_none() {
+            self.register_inherent_impl(impl_decl, namespace);
+            return;
+        }
+
+        if impl_decl.blanket_param().is_none()
+            && impl_decl
+                .generics
+                .as_ref()
+                .is_some_and(|params| !params.params.is_empty())
+        {
+            let trait_name = impl_decl
+                .trait_ref
+                .as_ref()
+                .map_or_else(String::new, |trait_ref| trait_ref.name.clone());
+            self.emit_error(
+                codes::TRAIT_IMPL_SPECIALIZATION_FORBIDDEN,
+                impl_decl.span,
+                format!(
+                    "generic impl of `{trait_name}` for `{}` is not supported; only blanket impls over a bare type parameter (`impl<T> {trait_name} for T`) are instantiated",
+                    canonical_type_name(&impl_decl.target)
+                ),
+            );
+            return;
         }
+
+        // Impl generics (including the parameter a blanket impl targets) are scoped to the
+        // impl's target so member signatures can name them.
+        let impl_owner = qualify(namespace, &impl_decl.target.name);
+        let generic_context = impl_decl
+            .generics
+            .as_ref()
+            .is_some_and(|params| !params.params.is_empty())
+            .then_some(impl_owner.as_str());
+        self.push_pending_generics(&impl_owner, impl_decl.generics.as_ref());
+        self.register_trait_impl(impl_decl, namespace, generic_context);
+        self.pop_pending_generics(&impl_owner);
+    }
+
+    /// Inherent `impl Type { ... }` blocks contribute methods to the target exactly like
+    /// extension members do; they never participate in trait coherence.
+    fn register_inherent_impl(&mut self, impl_decl: &'a ImplDecl, namespace: Option<&str>) {
         self.ensure_type_expr(&impl_decl.target, namespace, None, impl_decl.span);
+        let ImportResolution::Found(target_name) =
+            self.resolve_type_for_expr(&impl_decl.target, namespace, None)
+        else {
+            return;
+        };
+        if self.is_interface(&target_name) || self.traits.contains_key(&target_name) {
+            self.emit_error(
+                codes::TRAIT_FEATURE_UNAVAILABLE,
+                impl_decl.span,
+                format!(
+                    "inherent `impl` blocks cannot target `{target_name}`; use an extension with default members instead"
+                ),
+            );
+            return;
+        }
+
+        let owner_key = base_type_name(&target_name).to_string();
+        for member in &impl_decl.members {
+            match member {
+                ImplMember::Method(method) => {
+                    self.register_extension_method(
+                        method,
+                        &impl_decl.target,
+                        &owner_key,
+                        &target_name,
+                        namespace,
+                    );
+                }
+                ImplMember::AssociatedType(assoc) => self.emit_error(
+                    codes::TRAIT_FEATURE_UNAVAILABLE,
+                    assoc.span,
+                    format!(
+                        "inherent `impl` for `{target_name}` cannot declare associated type `{}`",
+                        assoc.name
+                    ),
+                ),
+                ImplMember::Const(const_member) => self.emit_error(
+                    codes::TRAIT_FEATURE_UNAVAILABLE,
+                    const_member.declaration.span,
+                    format!(
+                        "inherent `impl` for `{target_name}` cannot declare constants; declare them on the type instead"
+                    ),
+                ),
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn register_trait_impl(
+        &mut self,
+        impl_decl: &'a ImplDecl,
+        namespace: Option<&str>,
+        context: Option<&str>,
+    ) {
+        if let Some(trait_ref) = &impl_decl.trait_ref {
+            self.ensure_type_expr(trait_ref, namespace, context, impl_decl.span);
+        }
+        self.ensure_type_expr(&impl_decl.target, namespace, context, impl_decl.span);
 
         let qualified_trait = impl_decl
             .trait_ref
@@ -232,11 +331,11 @@ impl<'a> TypeChecker<'a> {
                     self.ensure_type_expr(
                         &method.signature.return_type,
                         namespace,
-                        None,
+                        context,
                         return_span,
                     );
                     for param in &method.signature.parameters {
-                        self.ensure_type_expr(&param.ty, namespace, None, None);
+                        self.ensure_type_expr(&param.ty, namespace, context, None);
                     }
                     let clause_span = method
                         .signature
@@ -274,14 +373,14 @@ impl<'a> TypeChecker<'a> {
                 }
                 ImplMember::AssociatedType(assoc) => {
                     if let Some(default) = &assoc.default {
-                        self.ensure_type_expr(default, namespace, None, assoc.span);
+                        self.ensure_type_expr(default, namespace, context, assoc.span);
                     }
                 }
                 ImplMember::Const(const_member) => {
                     self.ensure_type_expr(
                         &const_member.declaration.ty,
                         namespace,
-                        None,
+                        context,
                         const_member.declaration.span,
                     );
                     self.validate_const_declaration(namespace, None, &const_member.declaration);
@@ -291,19 +390,25 @@ impl<'a> TypeChecker<'a> {
 
         let qualified_target = qualify(namespace, &impl_decl.target.name);
 
+        let mut generics = impl_decl.generics.clone();
+        if impl_decl.trait_ref.is_some()
+            && let Some(generics) = generics.as_mut()
+        {
+            qualify_blanket_bounds(generics, &impl_decl.target, |bound| {
+                match self.resolve_type_for_expr(bound, namespace, None) {
+                    ImportResolution::Found(name) => Some(name),
+                    _ => None,
+                }
+            });
+        }
         self.impls.push(ImplInfo {
             trait_name: qualified_trait.clone(),
             target: impl_decl.target.clone(),
-            generics: impl_decl.generics.clone(),
+            generics,
             span: impl_decl.span,
         });
 
         let Some(trait_name) = qualified_trait else {
-            self.emit_error(
-                codes::TRAIT_FEATURE_UNAVAILABLE,
-                impl_decl.span,
-                "inherent `impl` blocks are not supported yet",
-            );
             return;
         };
 
@@ -316,18 +421,6 @@ impl<'a> TypeChecker<'a> {
             return;
         };
 
-        if impl_decl
-            .generics
-            .as_ref()
-            .is_some_and(|params| !params.params.is_empty())
-        {
-            self.emit_error(
-                codes::TRAIT_IMPL_SPECIALIZATION_FORBIDDEN,
-                impl_decl.span,
-                "blanket trait implementations are not supported (no specialization or negative reasoning)",
-            );
-        }
-
         let mut method_impls = HashSet::new();
         let mut assoc_impls = HashSet::new();
 
@@ -1326,114 +1326,18 @@ impl<'a> TypeChecker<'a> {
 
         for crate::frontend::ast::ExtensionMember::Method(method) in &ext.members {
             let function = &method.function;
-            let method_name = format!("{display_owner}::{}", function.name);
-            self.record_function_package(&method_name);
-            self.validate_generics(&method_name, function.generics.as_ref());
-            self.register_function_generics(&method_name, function.generics.as_ref());
-            self.push_pending_generics(&method_name, function.generics.as_ref());
-            self.ensure_unique_parameter_names(&function.signature.parameters, &method_name, None);
-            self.validate_parameter_defaults(
-                &method_name,
-                &function.signature.parameters,
-                namespace,
-                Some(method_name.as_str()),
-            );
-            let return_span = function.body.as_ref().and_then(|body| body.span);
-            self.ensure_type_expr(
-                &function.signature.return_type,
-                namespace,
-                Some(method_name.as_str()),
-                return_span,
-            );
-
-            if !function
-                .signature
-                .parameters
-                .first()
-                .is_some_and(|param| param.is_extension_this)
-            {
-                self.emit_error(
-                    codes::MISSING_EXTENSION_RECEIVER,
-                    None,
-                    format!(
-                        "extension method `{}` must declare a leading `this` receiver parameter",
-                        method_name
-                    ),
-                );
-                self.pop_pending_generics(&method_name);
-                continue;
-            }
-
-            let receiver = &function.signature.parameters[0].ty;
-            let receiver_base = receiver.base.last();
-            let target_base = ext.target.base.last();
-            let is_self = receiver_base.is_some_and(|segment| segment == "Self");
-            let matches_target = receiver_base
-                .zip(target_base)
-                .is_some_and(|(recv, target)| recv == target);
-            if !is_self && !matches_target {
-                self.emit_error(
-                    codes::INVALID_EXTENSION_RECEIVER,
-                    None,
-                    format!(
-                        "receiver parameter on extension method `{}` must be typed as `Self` or `{}`",
-                        method_name,
-                        ext.target.name
-                    ),
-                );
-            }
-
-            let substituted = signature_from_extension(
-                &function.signature,
-                method_name.clone(),
-                None,
-                &ext.target,
-            );
-            let sig_id = self.allocate_signature(substituted);
-            self.record_signature_generics(sig_id, function.generics.as_ref());
-            self.methods
-                .entry(owner_key.clone())
-                .or_default()
-                .push(sig_id);
-            self.validate_operator(&display_owner, function);
-            if function.is_async {
-                if let Some(result_ty) = self.validate_async_return_type(
-                    &method_name,
-                    &function.signature,
-                    namespace,
-                    Some(method_name.as_str()),
-                    return_span,
-                ) {
-                    self.async_signatures.insert(sig_id, result_ty);
-                }
-            }
-            let clause_span = function
-                .signature
-                .throws
-                .as_ref()
-                .and_then(|clause| clause.span);
-            self.record_declared_effects(
-                &method_name,
-                &function.signature,
-                namespace,
-                Some(method_name.as_str()),
-                clause_span,
-            );
-            self.validate_const_function(
-                &method_name,
+            if !self.register_extension_method(
                 function,
+                &ext.target,
+                &owner_key,
+                &display_owner,
                 namespace,
-                Some(method_name.as_str()),
-            );
-            if let Some(body) = function.body.as_ref() {
-                self.queue_body_validation(
-                    &method_name,
-                    body,
-                    namespace,
-                    Some(method_name.as_str()),
-                );
+            ) {
+                continue;
             }
             if method.is_default {
+                let method_name = format!("{display_owner}::{}", function.name);
+                let return_span = function.body.as_ref().and_then(|body| body.span);
                 if !is_interface_target {
                     self.emit_error(
                         codes::DEFAULT_TARGET_INVALID,
@@ -1467,8 +1371,121 @@ impl<'a> TypeChecker<'a> {
                     );
                 }
             }
+        }
+    }
+
+    /// Registers a method declared against `target` the way extension members are: the
+    /// signature is keyed on the target type and its body is queued for validation. Returns
+    /// `false` when an instance method lacks the leading `this` receiver.
+    #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+    pub(super) fn register_extension_method(
+        &mut self,
+        function: &'a FunctionDecl,
+        target: &TypeExpr,
+        owner_key: &str,
+        display_owner: &str,
+        namespace: Option<&str>,
+    ) -> bool {
+        let method_name = format!("{display_owner}::{}", function.name);
+        self.record_function_package(&method_name);
+        self.validate_generics(&method_name, function.generics.as_ref());
+        self.register_function_generics(&method_name, function.generics.as_ref());
+        self.push_pending_generics(&method_name, function.generics.as_ref());
+        self.ensure_unique_parameter_names(&function.signature.parameters, &method_name, None);
+        self.validate_parameter_defaults(
+            &method_name,
+            &function.signature.parameters,
+            namespace,
+            Some(method_name.as_str()),
+        );
+        let return_span = function.body.as_ref().and_then(|body| body.span);
+        self.ensure_type_expr(
+            &function.signature.return_type,
+            namespace,
+            Some(method_name.as_str()),
+            return_span,
+        );
 
+        let has_receiver = function
+            .signature
+            .parameters
+            .first()
+            .is_some_and(|param| param.is_extension_this);
+        // Static members (operators, derived `Default`) are keyed on the target without one.
+        if !has_receiver && !Self::method_is_static(function) {
+            self.emit_error(
+                codes::MISSING_EXTENSION_RECEIVER,
+                None,
+                format!(
+                    "extension method `{method_name}` must declare a leading `this` receiver parameter"
+                ),
+            );
             self.pop_pending_generics(&method_name);
+            return false;
+        }
+
+        if has_receiver {
+            let receiver = &function.signature.parameters[0].ty;
+            let receiver_base = receiver.base.last();
+            let target_base = target.base.last();
+            let is_self = receiver_base.is_some_and(|segment| segment == "Self");
+            let matches_target = receiver_base
+                .zip(target_base)
+                .is_some_and(|(recv, target)| recv == target);
+            if !is_self && !matches_target {
+                self.emit_error(
+                    codes::INVALID_EXTENSION_RECEIVER,
+                    None,
+                    format!(
+                        "receiver parameter on extension method `{}` must be typed as `Self` or `{}`",
+                        method_name, target.name
+                    ),
+                );
+            }
+        }
+
+        let substituted =
+            signature_from_extension(&function.signature, method_name.clone(), None, target);
+        let sig_id = self.allocate_signature(substituted);
+        self.record_signature_generics(sig_id, function.generics.as_ref());
+        self.methods
+            .entry(owner_key.to_string())
+            .or_default()
+            .push(sig_id);
+        self.validate_operator(display_owner, function);
+        if function.is_async
+            && let Some(result_ty) = self.validate_async_return_type(
+                &method_name,
+                &function.signature,
+                namespace,
+                Some(method_name.as_str()),
+                return_span,
+            )
+        {
+            self.async_signatures.insert(sig_id, result_ty);
+        }
+        let clause_span = function
+            .signature
+            .throws
+            .as_ref()
+            .and_then(|clause| clause.span);
+        self.record_declared_effects(
+            &method_name,
+            &function.signature,
+            namespace,
+            Some(method_name.as_str()),
+            clause_span,
+        );
+        self.validate_const_function(
+            &method_name,
+            function,
+            namespace,
+            Some(method_name.as_str()),
+        );
+        if let Some(body) = function.body.as_ref() {
+            self.queue_body_validation(&method_name, body, namespace, Some(method_name.as_str()));
         }
+        self.pop_pending_generics(&method_name);
+        true
     }
 }
@@ -1,6 +1,7 @@
 #![cfg(test)]
 
 use super::super::arena::{BaseTypeBinding, TypeChecker, TypeInfo, TypeKind};
+use super::super::trait_solver::TraitSolver;
 use super::{signature_from_extension, type_expr_mentions_parameter};
 use crate::frontend::ast::expressions::Expression;
 use crate::frontend::ast::patterns::{CasePattern, PatternGuard};
@@ -19,7 +20,8 @@ use crate::frontend::ast::{
     Visibility,
 };
 use crate::frontend::ast::{
-    GenericArgument, GenericParam, GenericParamKind, GenericParams, ThrowsClause, TypeSuffix,
+    GenericArgument, GenericConstraint, GenericConstraintKind, GenericParam, GenericParamKind,
+    GenericParams, ThrowsClause, TypeSuffix,
 };
 use crate::frontend::diagnostics::Diagnostic;
 use crate::frontend::parser::{parse_module, parse_type_expression_text};
@@ -302,19 +304,70 @@ fn impl_discovery_reports_missing_associated_type() {
 }
 
 #[test]
-fn impl_discovery_rejects_inherent_impls() {
+fn impl_discovery_registers_inherent_impl_methods() {
+    let mut receiver = parameter("this", TypeExpr::simple("Widget"));
+    receiver.is_extension_this = true;
     let module = Module::with_items(
         None,
         vec![
             Item::Struct(struct_def("Widget")),
-            Item::Impl(impl_def(None, "Widget", Vec::new(), None)),
+            Item::Impl(impl_def(
+                None,
+                "Widget",
+                vec![ImplMember::Method(simple_method(
+                    "Area",
+                    vec![receiver],
+                    TypeExpr::simple("int"),
+                ))],
+                None,
+            )),
+        ],
+    );
+    with_manual_module(module, |checker, module| {
+        checker.visit_items(&module.items, module.namespace.as_deref());
+        assert!(
+            checker.diagnostics.is_empty(),
+            "unexpected diagnostics: {:?}",
+            checker.diagnostics
+        );
+        assert!(
+            checker.impls.is_empty(),
+            "inherent impls must not take part in trait coherence"
+        );
+        let registered = checker.methods.get("Widget").map_or(0, Vec::len);
+        assert_eq!(registered, 1, "inherent method should register on `Widget`");
+    });
+}
+
+#[test]
+fn impl_discovery_rejects_inherent_impl_constants() {
+    let constant = ConstMemberDecl {
+        visibility: Visibility::Public,
+        modifiers: Vec::new(),
+        declaration: ConstDeclaration {
+            ty: TypeExpr::simple("int"),
+            declarators: Vec::new(),
+            doc: None,
+            span: None,
+        },
+    };
+    let module = Module::with_items(
+        None,
+        vec![
+            Item::Struct(struct_def("Widget")),
+            Item::Impl(impl_def(
+                None,
+                "Widget",
+                vec![ImplMember::Const(constant)],
+                None,
+            )),
         ],
     );
     with_manual_module(module, |checker, module| {
         checker.visit_items(&module.items, module.namespace.as_deref());
         let rendered = render_diagnostics(&checker.diagnostics);
         expect![[r#"
-[Error]: [TCK099] inherent `impl` blocks are not supported yet
+[Error]: [TCK099] inherent `impl` for `Widget` cannot declare constants; declare them on the type instead
 [Note]: See SPEC.md#4-2-traits--generic-associated-types, docs/compiler/traits.md#5-trait-objects-dyn-trait for specification details.
 "#]]
         .assert_eq(&rendered);
@@ -381,13 +434,216 @@ fn impl_discovery_rejects_impl_generics() {
         checker.visit_items(&module.items, module.namespace.as_deref());
         let rendered = render_diagnostics(&checker.diagnostics);
         expect![[r#"
-[Error]: [TCK095] blanket trait implementations are not supported (no specialization or negative reasoning)
+[Error]: [TCK095] generic impl of `Drawable` for `Widget` is not supported; only blanket impls over a bare type parameter (`impl<T> Drawable for T`) are instantiated
+[Note]: See SPEC.md#4-2-traits--generic-associated-types, docs/compiler/traits.md#3-trait-solver-architecture for specification details.
+"#]]
+        .assert_eq(&rendered);
+    });
+}
+
+#[test]
+fn trait_solver_accepts_blanket_impl_without_overlap() {
+    let module = blanket_display_module(Vec::new());
+    with_manual_module(module, |checker, module| {
+        checker.visit_items(&module.items, module.namespace.as_deref());
+        let metrics = TraitSolver::run(checker);
+        assert!(
+            checker.diagnostics.is_empty(),
+            "unexpected diagnostics: {:?}",
+            checker.diagnostics
+        );
+        assert_eq!(metrics.overlaps_detected, 0);
+        assert!(checker.trait_impl_for("Widget", "Display"));
+        assert!(!checker.trait_impl_for("Gadget", "Display"));
+    });
+}
+
+#[test]
+fn trait_solver_rejects_concrete_impl_overlapping_blanket() {
+    let module = blanket_display_module(vec![Item::Impl(impl_def(
+        Some("Display"),
+        "Widget",
+        vec![ImplMember::Method(simple_method(
+            "Show",
+            vec![parameter("self", TypeExpr::self_type())],
+            TypeExpr::simple("void"),
+        ))],
+        None,
+    ))]);
+    with_manual_module(module, |checker, module| {
+        checker.visit_items(&module.items, module.namespace.as_deref());
+        let metrics = TraitSolver::run(checker);
+        let rendered = render_diagnostics(&checker.diagnostics);
+        expect![[r#"
+[Error]: [TCK091] implementation of `Display` for `Widget` overlaps the blanket implementation for `T` (blanket implementation here: None)
+[Note]: See SPEC.md#4-2-traits--generic-associated-types, docs/compiler/traits.md#3-trait-solver-architecture for specification details.
+"#]]
+        .assert_eq(&rendered);
+        assert_eq!(metrics.overlaps_detected, 1);
+    });
+}
+
+#[test]
+fn trait_solver_rejects_conflicting_blanket_impls() {
+    let module = blanket_display_module(vec![Item::Impl(blanket_display_impl())]);
+    with_manual_module(module, |checker, module| {
+        checker.visit_items(&module.items, module.namespace.as_deref());
+        let metrics = TraitSolver::run(checker);
+        let rendered = render_diagnostics(&checker.diagnostics);
+        expect![[r#"
+[Error]: [TCK091] conflicting blanket implementations of `Display` (previous implementation here: None)
+[Note]: See SPEC.md#4-2-traits--generic-associated-types, docs/compiler/traits.md#3-trait-solver-architecture for specification details.
+"#]]
+        .assert_eq(&rendered);
+        assert_eq!(metrics.overlaps_detected, 1);
+    });
+}
+
+#[test]
+fn registry_rejects_generic_impl_over_constructed_type() {
+    let mut wrapper_impl = blanket_display_impl();
+    wrapper_impl.target = parse_type_expression_text("Wrapper<T>").expect("wrapper type");
+    let mut wrapper = struct_def("Wrapper");
+    wrapper.generics = Some(GenericParams::new(
+        None,
+        vec![GenericParam::type_param("T", None)],
+    ));
+    let module = blanket_display_module(vec![Item::Struct(wrapper), Item::Impl(wrapper_impl)]);
+    with_manual_module(module, |checker, module| {
+        checker.visit_items(&module.items, module.namespace.as_deref());
+        let metrics = TraitSolver::run(checker);
+        let rendered = render_diagnostics(&checker.diagnostics);
+        expect![[r#"
+[Error]: [TCK095] generic impl of `Display` for `Wrapper` is not supported; only blanket impls over a bare type parameter (`impl<T> Display for T`) are instantiated
+[Note]: See SPEC.md#4-2-traits--generic-associated-types, docs/compiler/traits.md#3-trait-solver-architecture for specification details.
+"#]]
+        .assert_eq(&rendered);
+        assert_eq!(metrics.overlaps_detected, 0);
+    });
+}
+
+#[test]
+fn trait_solver_checks_kind_constraints_for_blanket_overlap() {
+    let handle = ClassDecl {
+        visibility: Visibility::Public,
+        kind: ClassKind::Class,
+        name: "Handle".into(),
+        bases: Vec::new(),
+        members: Vec::new(),
+        nested_types: Vec::new(),
+        thread_safe_override: None,
+        shareable_override: None,
+        copy_override: None,
+        doc: None,
+        generics: None,
+        attributes: Vec::new(),
+        di_service: None,
+        di_module: false,
+        is_static: false,
+        is_abstract: false,
+        is_sealed: false,
+    };
+    let show = || {
+        vec![ImplMember::Method(simple_method(
+            "Show",
+            vec![parameter("self", TypeExpr::self_type())],
+            TypeExpr::simple("void"),
+        ))]
+    };
+    let mut blanket = blanket_display_impl();
+    if let Some(GenericParamKind::Type(data)) = blanket
+        .generics
+        .as_mut()
+        .and_then(|generics| generics.params.first_mut())
+        .map(|param| &mut param.kind)
+    {
+        data.constraints = vec![GenericConstraint::new(GenericConstraintKind::Struct, None)];
+    }
+    let module = Module::with_items(
+        None,
+        vec![
+            Item::Struct(struct_def("Widget")),
+            Item::Class(handle),
+            Item::Trait(trait_def("Display", Vec::new())),
+            Item::Impl(blanket),
+            Item::Impl(impl_def(Some("Display"), "Handle", show(), None)),
+            Item::Impl(impl_def(Some("Display"), "Widget", show(), None)),
+        ],
+    );
+    with_manual_module(module, |checker, module| {
+        checker.visit_items(&module.items, module.namespace.as_deref());
+        let metrics = TraitSolver::run(checker);
+        let rendered = render_diagnostics(&checker.diagnostics);
+        expect![[r#"
+[Error]: [TCK091] implementation of `Display` for `Widget` overlaps the blanket implementation for `T` (blanket implementation here: None)
 [Note]: See SPEC.md#4-2-traits--generic-associated-types, docs/compiler/traits.md#3-trait-solver-architecture for specification details.
 "#]]
         .assert_eq(&rendered);
+        assert_eq!(metrics.overlaps_detected, 1);
     });
 }
 
+/// `Debug` and `Display` traits, `Widget: Debug`, an unrelated `Gadget`, and
+/// `impl<T> Display for T where T: Debug`, followed by `extra` items.
+fn blanket_display_module(extra: Vec<Item>) -> Module {
+    let debug_trait = trait_def(
+        "Debug",
+        vec![TraitMember::Method(simple_method(
+            "Describe",
+            vec![parameter("self", TypeExpr::self_type())],
+            TypeExpr::simple("void"),
+        ))],
+    );
+    let display_trait = trait_def(
+        "Display",
+        vec![TraitMember::Method(simple_method(
+            "Show",
+            vec![parameter("self", TypeExpr::self_type())],
+            TypeExpr::simple("void"),
+        ))],
+    );
+    let debug_widget = impl_def(
+        Some("Debug"),
+        "Widget",
+        vec![ImplMember::Method(simple_method(
+            "Describe",
+            vec![parameter("self", TypeExpr::self_type())],
+            TypeExpr::simple("void"),
+        ))],
+        None,
+    );
+    let mut items = vec![
+        Item::Struct(struct_def("Widget")),
+        Item::Struct(struct_def("Gadget")),
+        Item::Trait(debug_trait),
+        Item::Trait(display_trait),
+        Item::Impl(debug_widget),
+        Item::Impl(blanket_display_impl()),
+    ];
+    items.extend(extra);
+    Module::with_items(None, items)
+}
+
+fn blanket_display_impl() -> ImplDecl {
+    let mut param = GenericParam::type_param("T", None);
+    if let GenericParamKind::Type(data) = &mut param.kind {
+        data.constraints.push(GenericConstraint::new(
+            GenericConstraintKind::Type(TypeExpr::simple("Debug")),
+            None,
+        ));
+    }
+    impl_def(
+        Some("Display"),
+        "T",
+        vec![ImplMember::Method(simple_method(
+            "Show",
+            vec![parameter("self", TypeExpr::simple("T"))],
+            TypeExpr::simple("void"),
+        ))],
+        Some(GenericParams::new(None, vec![param])),
+    )
+}
+
 #[test]
 fn impl_discovery_requires_trait_methods() {
     let sampler_trait = trait_def(
@@ -1386,6 +1642,7 @@ fn constructor_mismatch_reports_error() {
             doc: None,
             span: None,
             attributes: Vec::new(),
+            name_span: None,
             di_inject: None,
         }],
         consts: Vec::new(),
@@ -1508,13 +1765,34 @@ fn array_creation_requires_length_or_initializer() {
 }
 
 #[test]
-fn array_multi_rank_rejected_with_diagnostic() {
+fn array_rectangular_creation_is_accepted() {
     with_registry(
         r#"
         namespace Arrays {
             public class Sample {
                 public void Run() {
-                    var xs = new int[2, 3];
+                    var grid = new int[2, 3];
+                    var seeded = new int[2, 2] { { 1, 2 }, { 3, 4 } };
+                }
+            }
+        }
+        "#,
+        |checker, module| {
+            checker.visit_items(&module.items, module.namespace.as_deref());
+            let rendered = render_diagnostics(&checker.diagnostics);
+            expect![[r#""#]].assert_eq(&rendered);
+        },
+    );
+}
+
+#[test]
+fn array_rank_length_count_mismatch_reported() {
+    with_registry(
+        r#"
+        namespace Arrays {
+            public class Sample {
+                public void Run() {
+                    var xs = new int[2,];
                 }
             }
         }
@@ -1523,7 +1801,53 @@ fn array_multi_rank_rejected_with_diagnostic() {
             checker.visit_items(&module.items, module.namespace.as_deref());
             let rendered = render_diagnostics(&checker.diagnostics);
             expect![[r#"
-[Error] @ 4..13: [TCK144] multi-dimensional array ranks are not supported; use jagged arrays (`T[][]`) instead
+[Error] @ 4..11: [TCK144] array of rank 2 requires 2 length expression(s) but 1 were supplied
+"#]]
+            .assert_eq(&rendered);
+        },
+    );
+}
+
+#[test]
+fn array_ragged_rectangular_initializer_reported() {
+    with_registry(
+        r#"
+        namespace Arrays {
+            public class Sample {
+                public void Run() {
+                    var xs = new int[,] { { 1, 2 }, { 3 } };
+                }
+            }
+        }
+        "#,
+        |checker, module| {
+            checker.visit_items(&module.items, module.namespace.as_deref());
+            let rendered = render_diagnostics(&checker.diagnostics);
+            expect![[r#"
+[Error] @ 11..30: [TCK140] initializer for an array of rank 2 must nest rows of equal length
+"#]]
+            .assert_eq(&rendered);
+        },
+    );
+}
+
+#[test]
+fn array_rectangular_extent_mismatch_reported() {
+    with_registry(
+        r#"
+        namespace Arrays {
+            public class Sample {
+                public void Run() {
+                    var xs = new int[2, 3] { { 1, 2 }, { 3, 4 } };
+                }
+            }
+        }
+        "#,
+        |checker, module| {
+            checker.visit_items(&module.items, module.namespace.as_deref());
+            let rendered = render_diagnostics(&checker.diagnostics);
+            expect![[r#"
+[Error] @ 4..13: [TCK140] array length for dimension 1 does not match initializer extent (2)
 "#]]
             .assert_eq(&rendered);
         },
@@ -3548,6 +3872,7 @@ fn aggregates_walk_struct_union_enum_and_class_paths() {
         doc: None,
         span: None,
         attributes: Vec::new(),
+        name_span: None,
         di_inject: None,
     });
 
@@ -3662,6 +3987,7 @@ fn aggregates_walk_struct_union_enum_and_class_paths() {
             doc: None,
             span: None,
             attributes: Vec::new(),
+            name_span: None,
             di_inject: None,
         }));
     class_decl.members.push(ClassMember::Method(simple_method(
@@ -34,7 +34,7 @@ impl<'a> TypeChecker<'a> {
                     ),
                 );
             }
-            OperatorKind::Binary(_) if param_count != 2 => {
+            OperatorKind::Binary(_) | OperatorKind::CompoundAssignment(_) if param_count != 2 => {
                 self.emit_error(
                     codes::OPERATOR_SIGNATURE_INVALID,
                     span,
@@ -47,8 +47,10 @@ impl<'a> TypeChecker<'a> {
             _ => {}
         }
 
-        if matches!(operator.kind, OperatorKind::Conversion(_))
-            && method.signature.return_type.name == "void"
+        if matches!(
+            operator.kind,
+            OperatorKind::Conversion(_) | OperatorKind::CompoundAssignment(_)
+        ) && method.signature.return_type.name == "void"
         {
             self.emit_error(
                 codes::OPERATOR_SIGNATURE_INVALID,
@@ -141,6 +143,23 @@ impl<'a> TypeChecker<'a> {
                     );
                 }
             }
+            OperatorKind::CompoundAssignment(_) if param_count == 2 => {
+                let target_matches = method
+                    .signature
+                    .parameters
+                    .first()
+                    .is_some_and(|param| matches_owner_type_name(&param.ty.name, owner));
+                if !target_matches {
+                    self.emit_error(
+                        codes::OPERATOR_SIGNATURE_INVALID,
+                        span,
+                        format!(
+                            "{} in `{owner}` must take `{owner_short}` as its first (target) parameter",
+                            operator_display(&operator.kind)
+                        ),
+                    );
+                }
+            }
             OperatorKind::Conversion(_) if param_count == 1 => {
                 let source_matches = method
                     .signature
@@ -321,6 +340,24 @@ fn operator_display(kind: &OperatorKind) -> &'static str {
             BinaryOperator::GreaterThan => "operator >",
             BinaryOperator::GreaterThanOrEqual => "operator >=",
         },
+        OperatorKind::CompoundAssignment(op) => match op {
+            BinaryOperator::Add => "operator +=",
+            BinaryOperator::Subtract => "operator -=",
+            BinaryOperator::Multiply => "operator *=",
+            BinaryOperator::Divide => "operator /=",
+            BinaryOperator::Remainder => "operator %=",
+            BinaryOperator::BitAnd => "operator &=",
+            BinaryOperator::BitOr => "operator |=",
+            BinaryOperator::BitXor => "operator ^=",
+            BinaryOperator::ShiftLeft => "operator <<=",
+            BinaryOperator::ShiftRight => "operator >>=",
+            BinaryOperator::Equal
+            | BinaryOperator::NotEqual
+            | BinaryOperator::LessThan
+            | BinaryOperator::LessThanOrEqual
+            | BinaryOperator::GreaterThan
+            | BinaryOperator::GreaterThanOrEqual => "compound assignment operator",
+        },
         OperatorKind::Conversion(ConversionKind::Implicit) => "implicit operator",
         OperatorKind::Conversion(ConversionKind::Explicit) => "explicit operator",
     }
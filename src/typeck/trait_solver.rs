@@ -7,6 +7,7 @@ use crate::mir::AutoTraitOverride;
 use super::arena::{ImplInfo, TypeChecker};
 use super::diagnostics::codes;
 use super::helpers::canonical_type_name;
+use super::impl_predicate::satisfies_blanket_bounds;
 use super::traits::AutoTraitCheck;
 use super::{AutoTraitConstraintOrigin, AutoTraitKind};
 
@@ -130,6 +131,55 @@ impl<'a, 'tcx> TraitSolver<'a, 'tcx> {
         for impl_info in &impls {
             self.check_impl(impl_info, &mut seen);
         }
+        self.check_blanket_overlaps(&impls);
+    }
+
+    /// Coherence for blanket impls. Without specialization or negative reasoning, two blanket
+    /// impls of the same trait always overlap, and a blanket impl overlaps any concrete impl
+    /// whose target already satisfies the blanket's bounds.
+    fn check_blanket_overlaps(&mut self, impls: &[ImplInfo]) {
+        for (index, blanket) in impls.iter().enumerate() {
+            let (Some(trait_name), Some(param)) = (&blanket.trait_name, blanket.blanket_param())
+            else {
+                continue;
+            };
+            for (other_index, other) in impls.iter().enumerate() {
+                if other_index == index || other.trait_name.as_ref() != Some(trait_name) {
+                    continue;
+                }
+                if other.blanket_param().is_some() {
+                    if other_index < index {
+                        self.metrics.overlaps_detected += 1;
+                        self.emit_error(
+                            codes::TRAIT_IMPL_OVERLAP,
+                            blanket.span,
+                            format!(
+                                "conflicting blanket implementations of `{trait_name}` (previous implementation here: {:?})",
+                                other.span
+                            ),
+                        );
+                    }
+                    continue;
+                }
+                let target_name = canonical_type_name(&other.target);
+                if satisfies_blanket_bounds(
+                    &*self.checker,
+                    &target_name,
+                    param,
+                    &mut HashSet::new(),
+                ) {
+                    self.metrics.overlaps_detected += 1;
+                    self.emit_error(
+                        codes::TRAIT_IMPL_OVERLAP,
+                        other.span,
+                        format!(
+                            "implementation of `{trait_name}` for `{target_name}` overlaps the blanket implementation for `{}` (blanket implementation here: {:?})",
+                            param.name, blanket.span
+                        ),
+                    );
+                }
+            }
+        }
     }
 
     fn check_impl(
@@ -140,20 +190,16 @@ impl<'a, 'tcx> TraitSolver<'a, 'tcx> {
         self.metrics.impls_checked += 1;
         let span = impl_info.span;
         let Some(trait_name) = impl_info.trait_name.as_ref() else {
-            self.emit_error(
-                codes::TRAIT_FEATURE_UNAVAILABLE,
-                span,
-                "inherent `impl` blocks are not supported yet",
-            );
             return;
         };
-
-        if impl_info
-            .generics
-            .as_ref()
-            .is_some_and(|params| !params.params.is_empty())
+        let is_blanket = impl_info.blanket_param().is_some();
+        if !is_blanket
+            && impl_info
+                .generics
+                .as_ref()
+                .is_some_and(|params| !params.params.is_empty())
         {
-            // `registry` already emits TCK095 for blanket impls; skip solver work.
+            // `registry` already emits TCK095 for generic impls over constructed types.
             return;
         }
 
@@ -171,6 +217,12 @@ impl<'a, 'tcx> TraitSolver<'a, 'tcx> {
             return;
         };
 
+        if is_blanket {
+            // Blanket impls target a type parameter; overlap is checked once every concrete
+            // impl has been seen and the trait itself is known to be local.
+            return;
+        }
+
         let target_name = canonical_type_name(&impl_info.target);
         if !self.checker.is_local_type(&target_name)
             && !self.checker.traits.contains_key(trait_name)
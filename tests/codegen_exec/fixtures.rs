@@ -124,6 +124,10 @@ pub(crate) fn wasm_properties_program() -> &'static str {
     fixture!("wasm_properties.ch")
 }
 
+pub(crate) fn derived_enum_glue_program() -> &'static str {
+    fixture!("derived_enum_glue.ch")
+}
+
 pub(crate) fn simple_return_module(value: i32) -> Vec<u8> {
     let mut bytes = Vec::new();
     bytes.extend_from_slice(&[0x00, 0x61, 0x73, 0x6D]);
@@ -269,6 +269,11 @@ fn wasm_executes_virtual_dispatch_program() -> Result<(), Box<dyn Error>> {
     build_and_execute_wasm(virtual_dispatch_program(), 0)
 }
 
+#[test]
+fn wasm_derived_enum_eq_and_hash_match_glue() -> Result<(), Box<dyn Error>> {
+    build_and_execute_wasm(derived_enum_glue_program(), 0)
+}
+
 #[test]
 fn wasm_span_program_executes() -> Result<(), Box<dyn Error>> {
     build_and_execute_wasm(span_program(), 0)
@@ -793,6 +798,68 @@ fn llvm_executes_array_initializers_when_available() -> Result<(), Box<dyn Error
     build_and_execute_llvm(array_initializer_program(), 0)
 }
 
+fn rectangular_array_program() -> &'static str {
+    r#"
+namespace Grids;
+
+public int Main()
+{
+    var grid = new int[2, 3];
+    for (var row = 0; row < 2; row += 1)
+    {
+        for (var col = 0; col < 3; col += 1)
+        {
+            grid[row, col] = row * 10 + col;
+        }
+    }
+    if (grid[0, 1] != 1 || grid[1, 0] != 10 || grid[1, 2] != 12)
+    {
+        return 1;
+    }
+    if (grid.GetLength(0) != 2 || grid.GetLength(1) != 3)
+    {
+        return 2;
+    }
+
+    var literal = new int[,] { { 1, 2, 3 }, { 4, 5, 6 } };
+    if (literal[0, 2] != 3 || literal[1, 0] != 4)
+    {
+        return 3;
+    }
+
+    var total = 0;
+    var weight = 1;
+    foreach (var value in literal)
+    {
+        total += value * weight;
+        weight += 1;
+    }
+    if (total != 91)
+    {
+        return 4;
+    }
+
+    var pairs = int[,] [[7, 8], [9, 10]];
+    if (pairs[1, 1] != 10 || pairs.GetLength(0) != 2)
+    {
+        return 5;
+    }
+
+    return 0;
+}
+"#
+}
+
+#[test]
+fn wasm_executes_rectangular_arrays() -> Result<(), Box<dyn Error>> {
+    build_and_execute_wasm(rectangular_array_program(), 0)
+}
+
+#[test]
+fn llvm_executes_rectangular_arrays_when_available() -> Result<(), Box<dyn Error>> {
+    build_and_execute_llvm(rectangular_array_program(), 0)
+}
+
 #[test]
 fn wasm_executes_null_conditional_assignment_program() -> Result<(), Box<dyn Error>> {
     build_and_execute_wasm(null_conditional_assignment_program(), 0)
@@ -233,6 +233,7 @@ impl<R: BuildRunner> ExecHarness<R> {
             bin_dir: None,
             no_dependencies: false,
             no_restore: false,
+            locked: false,
             no_incremental: false,
             rebuild: false,
             incremental_validate: false,
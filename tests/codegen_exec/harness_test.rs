@@ -82,6 +82,7 @@ impl TestRunner for RealRunner {
                     bin_dir: None,
                     no_dependencies: false,
                     no_restore: false,
+                    locked: false,
                     no_incremental: false,
                     rebuild: false,
                     incremental_validate: false,
@@ -100,6 +100,7 @@ fn run_suite_interpreter(
         bin_dir: None,
         no_dependencies: false,
         no_restore: false,
+        locked: false,
         no_incremental: false,
         rebuild: false,
         incremental_validate: false,
@@ -220,6 +221,7 @@ fn run_suite_native(driver: &CompilerDriver, sources: &[PathBuf]) -> Result<(),
         bin_dir: None,
         no_dependencies: false,
         no_restore: false,
+        locked: false,
         no_incremental: false,
         rebuild: false,
         incremental_validate: false,
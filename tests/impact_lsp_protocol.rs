@@ -391,3 +391,1036 @@ public int Main()
     println!("shutdown sent");
     let _ = child.wait().expect("wait for server");
 }
+
+fn request(
+    stdin: &mut ChildStdin,
+    rx: &Receiver<Value>,
+    id: u64,
+    method: &str,
+    params: Value,
+) -> Value {
+    write_message(
+        stdin,
+        &json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": params,
+        }),
+    );
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < deadline {
+        if let Ok(message) = rx.recv_timeout(Duration::from_millis(200)) {
+            if message.get("id") == Some(&json!(id)) {
+                return message;
+            }
+        }
+    }
+    panic!("{method} response missing");
+}
+
+#[test]
+fn completion_references_rename_and_symbols() {
+    let (mut child, mut stdin, rx) = spawn_lsp();
+
+    let init = request(
+        &mut stdin,
+        &rx,
+        1,
+        "initialize",
+        json!({ "processId": null, "rootUri": null, "capabilities": {} }),
+    );
+    let capabilities = &init["result"]["capabilities"];
+    assert_eq!(capabilities["referencesProvider"], json!(true));
+    assert_eq!(
+        capabilities["renameProvider"]["prepareProvider"],
+        json!(true)
+    );
+    assert_eq!(capabilities["documentSymbolProvider"], json!(true));
+    assert_eq!(
+        capabilities["completionProvider"]["triggerCharacters"],
+        json!(["."])
+    );
+
+    let dir = tempdir().expect("create temp dir");
+    let file_path = dir.path().join("counter.ch");
+    let uri = Url::from_file_path(&file_path).expect("file URI");
+    let source = r#"namespace Demo;
+
+public class Counter
+{
+    public int Value;
+
+    public int Next(int step)
+    {
+        return Value + step;
+    }
+}
+
+public int Main()
+{
+    var counter = new Counter();
+    counter.Value = 1;
+    return counter.Next(2);
+}
+"#;
+    write_message(
+        &mut stdin,
+        &json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": {
+                "textDocument": {
+                    "uri": uri,
+                    "languageId": "chic",
+                    "version": 1,
+                    "text": source,
+                }
+            }
+        }),
+    );
+    let deadline = Instant::now() + Duration::from_secs(30);
+    let mut published = false;
+    while !published && Instant::now() < deadline {
+        if let Ok(message) = rx.recv_timeout(Duration::from_millis(200)) {
+            published = message.get("method").and_then(Value::as_str)
+                == Some("textDocument/publishDiagnostics");
+        }
+    }
+    assert!(published, "publishDiagnostics not received");
+
+    let symbols = request(
+        &mut stdin,
+        &rx,
+        10,
+        "textDocument/documentSymbol",
+        json!({ "textDocument": { "uri": uri } }),
+    );
+    let names = symbols.to_string();
+    for expected in ["Demo", "Counter", "Value", "Next", "Main"] {
+        assert!(
+            names.contains(&format!("\"{expected}\"")),
+            "document symbols should include {expected}: {names}"
+        );
+    }
+
+    let completion = request(
+        &mut stdin,
+        &rx,
+        11,
+        "textDocument/completion",
+        json!({
+            "textDocument": { "uri": uri },
+            "position": { "line": 16, "character": 19 }
+        }),
+    );
+    let labels = completion["result"]["items"]
+        .as_array()
+        .expect("completion items")
+        .iter()
+        .filter_map(|item| item["label"].as_str())
+        .collect::<Vec<_>>();
+    assert!(labels.contains(&"Next"), "member completion: {labels:?}");
+    assert!(labels.contains(&"Value"), "member completion: {labels:?}");
+
+    let references = request(
+        &mut stdin,
+        &rx,
+        12,
+        "textDocument/references",
+        json!({
+            "textDocument": { "uri": uri },
+            "position": { "line": 6, "character": 16 },
+            "context": { "includeDeclaration": true }
+        }),
+    );
+    let lines = references["result"]
+        .as_array()
+        .expect("reference locations")
+        .iter()
+        .filter_map(|location| location["range"]["start"]["line"].as_u64())
+        .collect::<Vec<_>>();
+    assert_eq!(lines, vec![6, 16], "references to Next");
+
+    let rename = request(
+        &mut stdin,
+        &rx,
+        13,
+        "textDocument/rename",
+        json!({
+            "textDocument": { "uri": uri },
+            "position": { "line": 14, "character": 9 },
+            "newName": "tally"
+        }),
+    );
+    let edits = rename["result"]["changes"][uri.as_str()]
+        .as_array()
+        .expect("rename edits for the document");
+    assert_eq!(edits.len(), 3, "rename should touch every use of the local");
+    assert!(edits.iter().all(|edit| edit["newText"] == json!("tally")));
+
+    let invalid = request(
+        &mut stdin,
+        &rx,
+        14,
+        "textDocument/rename",
+        json!({
+            "textDocument": { "uri": uri },
+            "position": { "line": 14, "character": 9 },
+            "newName": "class"
+        }),
+    );
+    assert!(
+        invalid.get("error").is_some(),
+        "keywords are not valid names"
+    );
+
+    let _ = request(&mut stdin, &rx, 2, "shutdown", Value::Null);
+    write_message(
+        &mut stdin,
+        &json!({ "jsonrpc": "2.0", "method": "exit", "params": {} }),
+    );
+    let _ = child.wait().expect("wait for server");
+}
+
+fn write_file(root: &std::path::Path, relative: &str, contents: &str) -> std::path::PathBuf {
+    let path = root.join(relative);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).expect("create parent directories");
+    }
+    std::fs::write(&path, contents).expect("write file");
+    path
+}
+
+fn did_open(stdin: &mut ChildStdin, uri: &Url, text: &str) {
+    write_message(
+        stdin,
+        &json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": {
+                "textDocument": {
+                    "uri": uri,
+                    "languageId": "chic",
+                    "version": 1,
+                    "text": text,
+                }
+            }
+        }),
+    );
+}
+
+/// Collect `publishDiagnostics` notifications until one arrives for each of `uris`.
+fn collect_diagnostics(rx: &Receiver<Value>, uris: &[&Url]) -> Vec<(String, Value)> {
+    let mut received = Vec::new();
+    let deadline = Instant::now() + Duration::from_secs(60);
+    while Instant::now() < deadline
+        && !uris
+            .iter()
+            .all(|uri| received.iter().any(|(got, _)| got == uri.as_str()))
+    {
+        if let Ok(message) = rx.recv_timeout(Duration::from_millis(200)) {
+            if message.get("method").and_then(Value::as_str)
+                == Some("textDocument/publishDiagnostics")
+            {
+                let uri = message["params"]["uri"].as_str().unwrap_or_default();
+                received.push((uri.to_string(), message["params"]["diagnostics"].clone()));
+            }
+        }
+    }
+    received
+}
+
+#[test]
+fn rename_only_touches_the_resolved_symbol() {
+    let (mut child, mut stdin, rx) = spawn_lsp();
+    let _ = request(
+        &mut stdin,
+        &rx,
+        1,
+        "initialize",
+        json!({ "processId": null, "rootUri": null, "capabilities": {} }),
+    );
+
+    let dir = tempdir().expect("create temp dir");
+    let uri = Url::from_file_path(dir.path().join("shapes.ch")).expect("file URI");
+    let source = r#"namespace Demo;
+
+public class Left
+{
+    public int Count;
+
+    public int Twice()
+    {
+        return Count + Count;
+    }
+}
+
+public class Right
+{
+    public int Count;
+}
+
+public int Main()
+{
+    var left = new Left();
+    var right = new Right();
+    left.Count = 1;
+    right.Count = 2;
+    return left.Count + right.Count;
+}
+"#;
+    did_open(&mut stdin, &uri, source);
+    let published = collect_diagnostics(&rx, &[&uri]);
+    assert!(!published.is_empty(), "publishDiagnostics not received");
+
+    let rename = request(
+        &mut stdin,
+        &rx,
+        2,
+        "textDocument/rename",
+        json!({
+            "textDocument": { "uri": uri },
+            "position": { "line": 4, "character": 16 },
+            "newName": "Total"
+        }),
+    );
+    let edits = rename["result"]["changes"][uri.as_str()]
+        .as_array()
+        .expect("rename edits for the document");
+    let mut positions = edits
+        .iter()
+        .map(|edit| {
+            (
+                edit["range"]["start"]["line"].as_u64().unwrap_or_default(),
+                edit["range"]["start"]["character"]
+                    .as_u64()
+                    .unwrap_or_default(),
+            )
+        })
+        .collect::<Vec<_>>();
+    positions.sort_unstable();
+    assert_eq!(
+        positions,
+        vec![(4, 15), (8, 15), (8, 23), (21, 9), (23, 16)],
+        "only Left.Count should be renamed: {rename}"
+    );
+
+    let references = request(
+        &mut stdin,
+        &rx,
+        3,
+        "textDocument/references",
+        json!({
+            "textDocument": { "uri": uri },
+            "position": { "line": 22, "character": 11 },
+            "context": { "includeDeclaration": true }
+        }),
+    );
+    let lines = references["result"]
+        .as_array()
+        .expect("reference locations")
+        .iter()
+        .filter_map(|location| location["range"]["start"]["line"].as_u64())
+        .collect::<Vec<_>>();
+    assert_eq!(lines, vec![14, 22, 23], "references to Right.Count");
+
+    let _ = request(&mut stdin, &rx, 4, "shutdown", Value::Null);
+    write_message(
+        &mut stdin,
+        &json!({ "jsonrpc": "2.0", "method": "exit", "params": {} }),
+    );
+    let _ = child.wait().expect("wait for server");
+}
+
+#[test]
+fn locals_and_same_named_members_rename_separately() {
+    let (mut child, mut stdin, rx) = spawn_lsp();
+    let _ = request(
+        &mut stdin,
+        &rx,
+        1,
+        "initialize",
+        json!({ "processId": null, "rootUri": null, "capabilities": {} }),
+    );
+
+    let dir = tempdir().expect("create temp dir");
+    let uri = Url::from_file_path(dir.path().join("counter.ch")).expect("file URI");
+    let source = r#"namespace Demo;
+
+public class Counter
+{
+    public int count;
+
+    public init(int count)
+    {
+        self.count = count;
+    }
+
+    public int Merge(Counter other)
+    {
+        var total = 0;
+        {
+            let step = 1;
+            total += step;
+        }
+        {
+            let step = 2;
+            total += step;
+        }
+        return total + other.count + count;
+    }
+}
+"#;
+    did_open(&mut stdin, &uri, source);
+    let published = collect_diagnostics(&rx, &[&uri]);
+    assert!(!published.is_empty(), "publishDiagnostics not received");
+
+    let edit_positions = |response: &Value| {
+        let mut positions = response["result"]["changes"][uri.as_str()]
+            .as_array()
+            .expect("rename edits for the document")
+            .iter()
+            .map(|edit| {
+                (
+                    edit["range"]["start"]["line"].as_u64().unwrap_or_default(),
+                    edit["range"]["start"]["character"]
+                        .as_u64()
+                        .unwrap_or_default(),
+                )
+            })
+            .collect::<Vec<_>>();
+        positions.sort_unstable();
+        positions
+    };
+
+    let parameter = request(
+        &mut stdin,
+        &rx,
+        2,
+        "textDocument/rename",
+        json!({
+            "textDocument": { "uri": uri },
+            "position": { "line": 6, "character": 20 },
+            "newName": "initial"
+        }),
+    );
+    assert_eq!(
+        edit_positions(&parameter),
+        vec![(6, 20), (8, 21)],
+        "renaming the parameter must leave self.count alone: {parameter}"
+    );
+
+    let field = request(
+        &mut stdin,
+        &rx,
+        3,
+        "textDocument/rename",
+        json!({
+            "textDocument": { "uri": uri },
+            "position": { "line": 4, "character": 15 },
+            "newName": "total"
+        }),
+    );
+    assert_eq!(
+        edit_positions(&field),
+        vec![(4, 15), (8, 13), (22, 29), (22, 37)],
+        "renaming the field must include member accesses but not the parameter: {field}"
+    );
+
+    let references = request(
+        &mut stdin,
+        &rx,
+        4,
+        "textDocument/references",
+        json!({
+            "textDocument": { "uri": uri },
+            "position": { "line": 15, "character": 16 },
+            "context": { "includeDeclaration": true }
+        }),
+    );
+    let lines = references["result"]
+        .as_array()
+        .expect("reference locations")
+        .iter()
+        .filter_map(|location| location["range"]["start"]["line"].as_u64())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        lines,
+        vec![15, 16],
+        "the first `step` only spans its own block"
+    );
+
+    let _ = request(&mut stdin, &rx, 5, "shutdown", Value::Null);
+    write_message(
+        &mut stdin,
+        &json!({ "jsonrpc": "2.0", "method": "exit", "params": {} }),
+    );
+    let _ = child.wait().expect("wait for server");
+}
+
+#[test]
+fn workspace_analysis_spans_project_files_and_dependencies() {
+    let temp = tempdir().expect("temp dir");
+    let root = temp.path().canonicalize().expect("canonical temp dir");
+    write_file(
+        &root.join("pkg.a"),
+        "manifest.yaml",
+        "package:\n  name: pkg.a\n  namespace: Shared\n  version: 0.0.1\n\nbuild:\n  kind: lib\n\nsources:\n  - path: ./src\n",
+    );
+    let shared_source = "namespace Shared;\n\npublic int Seven()\n{\n    return 7;\n}\n";
+    let shared = write_file(&root.join("pkg.a"), "src/lib.ch", shared_source);
+    write_file(
+        &root.join("pkg.b"),
+        "manifest.yaml",
+        "package:\n  name: pkg.b\n  namespace: Consumer\n  version: 0.0.1\n\nbuild:\n  kind: lib\n\nsources:\n  - path: ./src\n\ndependencies:\n  pkg.a:\n    path: ../pkg.a\n",
+    );
+    write_file(
+        &root.join("pkg.b"),
+        "src/util.ch",
+        "namespace Consumer;\n\npublic int Helper()\n{\n    return 1;\n}\n",
+    );
+    let main_source = "namespace Consumer;\nimport Shared;\n\npublic int Total()\n{\n    return Helper() + Seven();\n}\n";
+    let main = write_file(&root.join("pkg.b"), "src/main.ch", main_source);
+    let main_uri = Url::from_file_path(&main).expect("main URI");
+    let shared_uri = Url::from_file_path(&shared).expect("shared URI");
+
+    let (mut child, mut stdin, rx) = spawn_lsp();
+    let _ = request(
+        &mut stdin,
+        &rx,
+        1,
+        "initialize",
+        json!({ "processId": null, "rootUri": null, "capabilities": {} }),
+    );
+
+    did_open(&mut stdin, &main_uri, main_source);
+    let published = collect_diagnostics(&rx, &[&main_uri]);
+    let (_, diagnostics) = published
+        .iter()
+        .find(|(uri, _)| uri == main_uri.as_str())
+        .expect("diagnostics for main.ch");
+    let errors = diagnostics
+        .as_array()
+        .expect("diagnostic array")
+        .iter()
+        .filter(|diagnostic| diagnostic["severity"] == json!(1))
+        .collect::<Vec<_>>();
+    assert!(
+        errors.is_empty(),
+        "symbols from sibling files and dependencies should resolve: {errors:?}"
+    );
+
+    for (id, character, expected) in [(10, 12, "util.ch"), (11, 24, "lib.ch")] {
+        let definition = request(
+            &mut stdin,
+            &rx,
+            id,
+            "textDocument/definition",
+            json!({
+                "textDocument": { "uri": main_uri },
+                "position": { "line": 5, "character": character }
+            }),
+        );
+        let target = definition["result"]["uri"].as_str().unwrap_or_default();
+        assert!(
+            target.ends_with(expected),
+            "definition should resolve into {expected}: {definition}"
+        );
+    }
+
+    // Breaking the dependency's buffer re-checks the project that consumes it.
+    did_open(
+        &mut stdin,
+        &shared_uri,
+        "namespace Shared;\n\npublic int Seven()\n{\n    return 7\n}\n",
+    );
+    let published = collect_diagnostics(&rx, &[&shared_uri, &main_uri]);
+    assert!(
+        published.iter().any(|(uri, _)| uri == main_uri.as_str()),
+        "dependent project should be re-analysed: {published:?}"
+    );
+
+    let _ = request(&mut stdin, &rx, 2, "shutdown", Value::Null);
+    write_message(
+        &mut stdin,
+        &json!({ "jsonrpc": "2.0", "method": "exit", "params": {} }),
+    );
+    let _ = child.wait().expect("wait for server");
+}
+
+#[test]
+fn code_actions_offer_diagnostic_fix_its() {
+    let temp = tempdir().expect("temp dir");
+    let root = temp.path().canonicalize().expect("canonical temp dir");
+    write_file(
+        &root,
+        "manifest.yaml",
+        "package:\n  name: acme\n  namespace: Acme\n  version: 0.0.1\n\nbuild:\n  kind: lib\n\nsources:\n  - path: ./src\n",
+    );
+    let source = "namespace Other;\n\npublic int Seven()\n{\n    return 7;\n}\n";
+    let path = write_file(&root, "src/lib.ch", source);
+    let uri = Url::from_file_path(&path).expect("source URI");
+
+    let (mut child, mut stdin, rx) = spawn_lsp();
+    let initialize = request(
+        &mut stdin,
+        &rx,
+        1,
+        "initialize",
+        json!({ "processId": null, "rootUri": null, "capabilities": {} }),
+    );
+    assert_eq!(
+        initialize["result"]["capabilities"]["codeActionProvider"]["codeActionKinds"],
+        json!(["quickfix", "source.fixAll.chic"])
+    );
+
+    did_open(&mut stdin, &uri, source);
+    let published = collect_diagnostics(&rx, &[&uri]);
+    let (_, diagnostics) = published
+        .iter()
+        .find(|(published_uri, _)| published_uri == uri.as_str())
+        .expect("diagnostics for lib.ch");
+    let namespace_error = diagnostics
+        .as_array()
+        .expect("diagnostic array")
+        .iter()
+        .find(|diagnostic| diagnostic["code"] == json!("PKG0003"))
+        .cloned()
+        .expect("out-of-scope namespace diagnostic");
+
+    let actions = request(
+        &mut stdin,
+        &rx,
+        2,
+        "textDocument/codeAction",
+        json!({
+            "textDocument": { "uri": uri },
+            "range": namespace_error["range"],
+            "context": { "diagnostics": [namespace_error] }
+        }),
+    );
+    let actions = actions["result"].as_array().expect("code actions");
+    let quick_fix = actions
+        .iter()
+        .find(|action| action["kind"] == json!("quickfix"))
+        .expect("quick fix for PKG0003");
+    assert_eq!(quick_fix["isPreferred"], json!(true));
+    assert_eq!(quick_fix["diagnostics"][0]["code"], json!("PKG0003"));
+    let edits = &quick_fix["edit"]["changes"][uri.as_str()];
+    assert_eq!(edits[0]["newText"], json!("Acme.Other"), "{quick_fix}");
+    assert!(
+        actions
+            .iter()
+            .any(|action| action["kind"] == json!("source.fixAll.chic")),
+        "fix-all action should be offered: {actions:?}"
+    );
+
+    let only_source = request(
+        &mut stdin,
+        &rx,
+        3,
+        "textDocument/codeAction",
+        json!({
+            "textDocument": { "uri": uri },
+            "range": namespace_error["range"],
+            "context": { "diagnostics": [], "only": ["source"] }
+        }),
+    );
+    let only_source = only_source["result"].as_array().expect("code actions");
+    assert!(
+        only_source
+            .iter()
+            .all(|action| action["kind"] == json!("source.fixAll.chic")),
+        "`only` should filter quick fixes: {only_source:?}"
+    );
+
+    let _ = request(&mut stdin, &rx, 4, "shutdown", Value::Null);
+    write_message(
+        &mut stdin,
+        &json!({ "jsonrpc": "2.0", "method": "exit", "params": {} }),
+    );
+    let _ = child.wait().expect("wait for server");
+}
+
+#[test]
+fn code_actions_rename_type_named_constructors() {
+    let temp = tempdir().expect("temp dir");
+    let root = temp.path().canonicalize().expect("canonical temp dir");
+    write_file(
+        &root,
+        "manifest.yaml",
+        "package:\n  name: acme\n  namespace: Acme\n  version: 0.0.1\n\nbuild:\n  kind: lib\n\nsources:\n  - path: ./src\n",
+    );
+    let source = "namespace Acme;\n\npublic class Widget\n{\n    public int Size;\n\n    public Widget(int size)\n    {\n        Size = size;\n    }\n}\n";
+    let path = write_file(&root, "src/lib.ch", source);
+    let uri = Url::from_file_path(&path).expect("source URI");
+
+    let (mut child, mut stdin, rx) = spawn_lsp();
+    let _ = request(
+        &mut stdin,
+        &rx,
+        1,
+        "initialize",
+        json!({ "processId": null, "rootUri": null, "capabilities": {} }),
+    );
+    did_open(&mut stdin, &uri, source);
+    let published = collect_diagnostics(&rx, &[&uri]);
+    let (_, diagnostics) = published
+        .iter()
+        .find(|(published_uri, _)| published_uri == uri.as_str())
+        .expect("diagnostics for lib.ch");
+    let constructor_error = diagnostics
+        .as_array()
+        .expect("diagnostic array")
+        .iter()
+        .find(|diagnostic| diagnostic["range"]["start"]["line"] == json!(6))
+        .cloned()
+        .expect("type-named constructor diagnostic");
+
+    let actions = request(
+        &mut stdin,
+        &rx,
+        2,
+        "textDocument/codeAction",
+        json!({
+            "textDocument": { "uri": uri },
+            "range": constructor_error["range"],
+            "context": { "diagnostics": [constructor_error] }
+        }),
+    );
+    let actions = actions["result"].as_array().expect("code actions");
+    let quick_fix = actions
+        .iter()
+        .find(|action| action["kind"] == json!("quickfix"))
+        .expect("quick fix for the constructor");
+    let edits = &quick_fix["edit"]["changes"][uri.as_str()];
+    assert_eq!(edits.as_array().map(Vec::len), Some(1), "{quick_fix}");
+    assert_eq!(
+        edits[0]["range"],
+        json!({
+            "start": { "line": 6, "character": 11 },
+            "end": { "line": 6, "character": 17 }
+        })
+    );
+    assert!(
+        apply_edits(source, edits).contains("    public init(int size)\n"),
+        "{quick_fix}"
+    );
+
+    let _ = request(&mut stdin, &rx, 3, "shutdown", Value::Null);
+    write_message(
+        &mut stdin,
+        &json!({ "jsonrpc": "2.0", "method": "exit", "params": {} }),
+    );
+    let _ = child.wait().expect("wait for server");
+}
+
+fn apply_edits(text: &str, edits: &Value) -> String {
+    let offset = |position: &Value| {
+        let line = usize::try_from(position["line"].as_u64().expect("line")).expect("line");
+        let character =
+            usize::try_from(position["character"].as_u64().expect("character")).expect("char");
+        let line_start: usize = text.split_inclusive('\n').take(line).map(str::len).sum();
+        line_start + character
+    };
+    let mut edits = edits.as_array().expect("edit array").clone();
+    edits.sort_by_key(|edit| std::cmp::Reverse(offset(&edit["range"]["start"])));
+    let mut result = text.to_string();
+    for edit in &edits {
+        let start = offset(&edit["range"]["start"]);
+        let end = offset(&edit["range"]["end"]);
+        result.replace_range(start..end, edit["newText"].as_str().expect("new text"));
+    }
+    result
+}
+
+#[test]
+fn formatting_uses_manifest_config_and_minimal_edits() {
+    let temp = tempdir().expect("temp dir");
+    let root = temp.path().canonicalize().expect("canonical temp dir");
+    write_file(
+        &root,
+        "manifest.yaml",
+        "package:\n  name: fmt\n  namespace: Fmt\n  version: 0.0.1\n\nbuild:\n  kind: lib\n\nsources:\n  - path: ./src\n\nformat:\n  indent:\n    size: 2\n",
+    );
+    let source = "namespace Fmt;\n\npublic int Seven()\n{\nreturn 7;\n}\n\npublic int Eight()\n{\n        return 8;\n}\n";
+    let path = write_file(&root, "src/lib.ch", source);
+    let uri = Url::from_file_path(&path).expect("source URI");
+
+    let (mut child, mut stdin, rx) = spawn_lsp();
+    let initialize = request(
+        &mut stdin,
+        &rx,
+        1,
+        "initialize",
+        json!({ "processId": null, "rootUri": null, "capabilities": {} }),
+    );
+    let capabilities = &initialize["result"]["capabilities"];
+    assert_eq!(capabilities["documentFormattingProvider"], json!(true));
+    assert_eq!(capabilities["documentRangeFormattingProvider"], json!(true));
+    assert_eq!(
+        capabilities["documentOnTypeFormattingProvider"]["firstTriggerCharacter"],
+        json!("}")
+    );
+    did_open(&mut stdin, &uri, source);
+    let _ = collect_diagnostics(&rx, &[&uri]);
+
+    let options = json!({ "tabSize": 8, "insertSpaces": true });
+    let formatted = request(
+        &mut stdin,
+        &rx,
+        2,
+        "textDocument/formatting",
+        json!({ "textDocument": { "uri": uri }, "options": options }),
+    );
+    let edits = &formatted["result"];
+    let result = apply_edits(source, edits);
+    assert!(result.contains("\n  return 7;\n"), "{result}");
+    assert!(result.contains("\n  return 8;\n"), "{result}");
+    for edit in edits.as_array().expect("edit array") {
+        assert!(
+            !edit["newText"]
+                .as_str()
+                .unwrap_or_default()
+                .contains("return"),
+            "edits should only touch whitespace: {edit}"
+        );
+    }
+
+    let ranged = request(
+        &mut stdin,
+        &rx,
+        3,
+        "textDocument/rangeFormatting",
+        json!({
+            "textDocument": { "uri": uri },
+            "range": { "start": { "line": 8, "character": 0 }, "end": { "line": 10, "character": 1 } },
+            "options": options
+        }),
+    );
+    let result = apply_edits(source, &ranged["result"]);
+    assert!(
+        result.contains("\nreturn 7;\n"),
+        "outside the range: {result}"
+    );
+    assert!(
+        result.contains("\n  return 8;\n"),
+        "inside the range: {result}"
+    );
+
+    let on_type = request(
+        &mut stdin,
+        &rx,
+        4,
+        "textDocument/onTypeFormatting",
+        json!({
+            "textDocument": { "uri": uri },
+            "position": { "line": 5, "character": 1 },
+            "ch": "}",
+            "options": options
+        }),
+    );
+    let result = apply_edits(source, &on_type["result"]);
+    assert!(result.contains("\n  return 7;\n"), "closed block: {result}");
+    assert!(
+        result.contains("\n        return 8;\n"),
+        "other block: {result}"
+    );
+
+    let _ = request(&mut stdin, &rx, 5, "shutdown", Value::Null);
+    write_message(
+        &mut stdin,
+        &json!({ "jsonrpc": "2.0", "method": "exit", "params": {} }),
+    );
+    let _ = child.wait().expect("wait for server");
+}
+
+/// Decode semantic token data into absolute `(line, start, length, type, modifiers)`.
+fn decode_tokens(data: &Value) -> Vec<[u64; 5]> {
+    let data: Vec<u64> = data
+        .as_array()
+        .expect("token data")
+        .iter()
+        .map(|value| value.as_u64().expect("token integer"))
+        .collect();
+    let mut tokens = Vec::new();
+    let (mut line, mut start) = (0, 0);
+    for chunk in data.chunks(5) {
+        if chunk[0] > 0 {
+            line += chunk[0];
+            start = chunk[1];
+        } else {
+            start += chunk[1];
+        }
+        tokens.push([line, start, chunk[2], chunk[3], chunk[4]]);
+    }
+    tokens
+}
+
+#[test]
+fn semantic_tokens_inlay_hints_and_signature_help() {
+    let temp = tempdir().expect("temp dir");
+    let root = temp.path().canonicalize().expect("canonical temp dir");
+    let source = "namespace Demo;\n\npublic int Scale(int value, int factor = 2)\n{\n    return value * factor;\n}\n\npublic int Scale(int value, int factor, int offset)\n{\n    return value * factor + offset;\n}\n\npublic int Run(ref int counter)\n{\n    let doubled = Scale(counter, 3);\n    var total = doubled;\n    total = total + Scale(4);\n    return total;\n}\n";
+    let path = write_file(&root, "demo.ch", source);
+    let uri = Url::from_file_path(&path).expect("source URI");
+
+    let (mut child, mut stdin, rx) = spawn_lsp();
+    let initialize = request(
+        &mut stdin,
+        &rx,
+        1,
+        "initialize",
+        json!({ "processId": null, "rootUri": null, "capabilities": {} }),
+    );
+    let capabilities = &initialize["result"]["capabilities"];
+    let legend = &capabilities["semanticTokensProvider"]["legend"];
+    let token_type = |name: &str| {
+        legend["tokenTypes"]
+            .as_array()
+            .expect("token types")
+            .iter()
+            .position(|ty| ty == name)
+            .expect("legend token type") as u64
+    };
+    let modifier = |name: &str| {
+        1u64 << legend["tokenModifiers"]
+            .as_array()
+            .expect("token modifiers")
+            .iter()
+            .position(|modifier| modifier == name)
+            .expect("legend modifier")
+    };
+    assert_eq!(capabilities["inlayHintProvider"], json!(true));
+    assert_eq!(
+        capabilities["signatureHelpProvider"]["triggerCharacters"],
+        json!(["(", ","])
+    );
+    did_open(&mut stdin, &uri, source);
+    let _ = collect_diagnostics(&rx, &[&uri]);
+
+    let full = request(
+        &mut stdin,
+        &rx,
+        2,
+        "textDocument/semanticTokens/full",
+        json!({ "textDocument": { "uri": uri } }),
+    );
+    let result_id = full["result"]["resultId"].clone();
+    let tokens = decode_tokens(&full["result"]["data"]);
+    let at = |line: u64, start: u64| {
+        tokens
+            .iter()
+            .find(|token| token[0] == line && token[1] == start)
+            .map(|token| (token[3], token[4]))
+            .unwrap_or_else(|| panic!("no semantic token at {line}:{start}: {tokens:?}"))
+    };
+    assert_eq!(at(12, 15), (token_type("modifier"), 0), "`ref` qualifier");
+    assert_eq!(
+        at(14, 8),
+        (token_type("variable"), modifier("readonly")),
+        "`let` binding"
+    );
+    assert_eq!(
+        at(15, 8),
+        (token_type("variable"), modifier("mutable")),
+        "`var` binding"
+    );
+    assert_eq!(at(14, 18), (token_type("function"), 0), "call");
+    assert_eq!(
+        at(14, 24),
+        (token_type("parameter"), modifier("mutable")),
+        "`ref` parameter use"
+    );
+    assert_eq!(
+        at(2, 11),
+        (token_type("function"), modifier("declaration")),
+        "declaration"
+    );
+
+    let hints = request(
+        &mut stdin,
+        &rx,
+        3,
+        "textDocument/inlayHint",
+        json!({
+            "textDocument": { "uri": uri },
+            "range": { "start": { "line": 14, "character": 0 }, "end": { "line": 17, "character": 0 } }
+        }),
+    );
+    let hints: Vec<(u64, u64, String)> = hints["result"]
+        .as_array()
+        .expect("inlay hints")
+        .iter()
+        .map(|hint| {
+            (
+                hint["position"]["line"].as_u64().expect("line"),
+                hint["position"]["character"].as_u64().expect("character"),
+                hint["label"].as_str().expect("label").to_string(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        hints,
+        vec![
+            (14, 15, String::from(": int")),
+            (14, 24, String::from("value:")),
+            (14, 33, String::from("factor:")),
+            (15, 13, String::from(": int")),
+            (16, 26, String::from("value:")),
+        ]
+    );
+
+    let help = request(
+        &mut stdin,
+        &rx,
+        4,
+        "textDocument/signatureHelp",
+        json!({ "textDocument": { "uri": uri }, "position": { "line": 14, "character": 34 } }),
+    );
+    let help = &help["result"];
+    assert_eq!(
+        help["signatures"][0]["label"],
+        json!("int Scale(int value, int factor = 2)")
+    );
+    assert_eq!(
+        help["signatures"][1]["label"],
+        json!("int Scale(int value, int factor, int offset)")
+    );
+    assert_eq!(help["activeParameter"], json!(1));
+    assert_eq!(
+        help["signatures"][0]["parameters"][1]["label"],
+        json!([21, 35])
+    );
+
+    write_message(
+        &mut stdin,
+        &json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didChange",
+            "params": {
+                "textDocument": { "uri": uri, "version": 2 },
+                "contentChanges": [{ "text": source.replace("var total", "var sum").replace("total", "sum") }]
+            }
+        }),
+    );
+    let _ = collect_diagnostics(&rx, &[&uri]);
+    let delta = request(
+        &mut stdin,
+        &rx,
+        5,
+        "textDocument/semanticTokens/full/delta",
+        json!({ "textDocument": { "uri": uri }, "previousResultId": result_id }),
+    );
+    let edits = delta["result"]["edits"].as_array().expect("delta edits");
+    assert_eq!(edits.len(), 1, "{delta}");
+    assert_ne!(delta["result"]["resultId"], result_id);
+
+    let _ = request(&mut stdin, &rx, 9, "shutdown", Value::Null);
+    write_message(
+        &mut stdin,
+        &json!({ "jsonrpc": "2.0", "method": "exit", "params": {} }),
+    );
+    let _ = child.wait().expect("wait for server");
+}
@@ -111,6 +111,7 @@ public int Main()
             bin_dir: None,
             no_dependencies: false,
             no_restore: false,
+            locked: false,
             no_incremental: false,
             rebuild: false,
             incremental_validate: false,
@@ -53,6 +53,7 @@ fn wasm_readonly_span_smoke() -> Result<(), Box<dyn std::error::Error>> {
         bin_dir: None,
         no_dependencies: false,
         no_restore: false,
+        locked: false,
         no_incremental: false,
         rebuild: false,
         incremental_validate: false,